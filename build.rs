@@ -0,0 +1,51 @@
+use std::{env, fmt::Write, fs, path::Path};
+
+#[path = "src/day11/rules.rs"]
+mod rules;
+
+#[path = "src/day11/lut.rs"]
+mod lut;
+
+/// Day 11 lookup table size, see the `[features]` section of Cargo.toml for how to change it
+const DEFAULT_VALUES: usize = 1000;
+const DEFAULT_BLINKS: usize = 75;
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/day11/lut.rs");
+    println!("cargo:rerun-if-changed=src/day11/rules.rs");
+    println!("cargo:rerun-if-env-changed=DAY11_LUT_VALUES");
+    println!("cargo:rerun-if-env-changed=DAY11_LUT_BLINKS");
+
+    let var = |name, default| match env::var(name) {
+        Ok(v) => v
+            .parse()
+            .unwrap_or_else(|_| panic!("{name} must be a number, got {v:?}")),
+        Err(_) => default,
+    };
+    let feature = |name| env::var_os(format!("CARGO_FEATURE_{name}")).is_some();
+    let default_values = if feature("DAY11_LUT_SMALL") {
+        100
+    } else if feature("DAY11_LUT_LARGE") {
+        10_000
+    } else {
+        DEFAULT_VALUES
+    };
+    let values = var("DAY11_LUT_VALUES", default_values);
+    let blinks = var("DAY11_LUT_BLINKS", DEFAULT_BLINKS);
+    assert!(
+        values > 0 && blinks > 0,
+        "day 11 LUT dimensions must be non-zero"
+    );
+
+    let mut out = String::new();
+    writeln!(out, "pub const LUT_VALUES: usize = {values};").unwrap();
+    writeln!(out, "pub const LUT_BLINKS: usize = {blinks};").unwrap();
+    writeln!(out, "static LUT: [[usize; LUT_BLINKS]; LUT_VALUES] = [").unwrap();
+    for row in lut::generate(values, blinks, &rules::StoneRules::PUZZLE) {
+        writeln!(out, "{row:?},").unwrap();
+    }
+    writeln!(out, "];").unwrap();
+
+    let path = Path::new(&env::var("OUT_DIR").unwrap()).join("day11_lut.rs");
+    fs::write(path, out).unwrap();
+}
@@ -0,0 +1,66 @@
+//! Compares the day 12 flood fill ([`regions`]) with union-find labelling ([`regions_by_label`])
+//! on generated gardens.
+//!
+//! ```text
+//! cargo run --release --example day12_labels
+//! ```
+
+use advent_of_codspeed_2024::day12::{regions, regions_by_label, Garden, Labels, Region};
+use std::time::{Duration, Instant};
+
+/// Square garden of 4 plants. Each plot copies its north or west neighbor most of the time, so
+/// regions have irregular shapes and holes like the puzzle input.
+fn garden(size: usize, mut seed: u64) -> String {
+    let mut rand = move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        seed
+    };
+    let mut plots = vec![0u8; size * size];
+    for i in 0..size * size {
+        plots[i] = match rand() % 10 {
+            0..=3 if i >= size => plots[i - size],
+            4..=7 if i % size > 0 => plots[i - 1],
+            _ => b'A' + (rand() % 4) as u8,
+        };
+    }
+    plots
+        .chunks(size)
+        .flat_map(|row| row.iter().map(|&b| b as char).chain(['\n']))
+        .collect()
+}
+
+/// Median time of `f` over `runs` runs
+fn time<T>(runs: usize, mut f: impl FnMut() -> T) -> Duration {
+    let mut times = (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            std::hint::black_box(f());
+            start.elapsed()
+        })
+        .collect::<Vec<_>>();
+    times.sort_unstable();
+    times[runs / 2]
+}
+
+fn main() {
+    for (size, runs) in [(140, 101), (2000, 5)] {
+        let input = garden(size, 0x9e3779b97f4a7c15);
+        // Same regions in the same order, only the order of `cells` differs
+        let summary = |regions: Vec<Region>| {
+            regions
+                .into_iter()
+                .map(|r| (r.plant, r.area, r.perimeter, r.sides, r.bounds))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(summary(regions(&input)), summary(regions_by_label(&input)));
+
+        let flood = time(runs, || regions(&input));
+        let union_find = time(runs, || regions_by_label(&input));
+        let labels = time(runs, || Labels::new(&Garden::new(&input)));
+        println!(
+            "{size}x{size}: flood fill {flood:?}, union-find {union_find:?} (labelling alone {labels:?})"
+        );
+    }
+}
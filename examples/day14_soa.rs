@@ -0,0 +1,70 @@
+//! Compares the day 14 robot layouts ([`Layout::Aos`] and [`Layout::Soa`]) on generated robots.
+//!
+//! ```text
+//! cargo run --release --example day14_soa
+//! ```
+
+use advent_of_codspeed_2024::day14::{
+    parse_robots, safety_factor_with, Layout, Room, Simulation, SoaRobots,
+};
+use std::{
+    fmt::Write,
+    time::{Duration, Instant},
+};
+
+/// `count` robots in `room` in the puzzle input format, with velocities in `-99..=99`
+fn robots(count: usize, room: Room, mut seed: u64) -> String {
+    let mut rand = move |m: i32| {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        (seed % m as u64) as i32
+    };
+    let mut input = String::new();
+    for _ in 0..count {
+        let (px, py) = (rand(room.width), rand(room.height));
+        let (vx, vy) = (rand(199) - 99, rand(199) - 99);
+        writeln!(input, "p={px},{py} v={vx},{vy}").unwrap();
+    }
+    input
+}
+
+/// Median time of `f` over `runs` runs
+fn time<T>(runs: usize, mut f: impl FnMut() -> T) -> Duration {
+    let mut times = (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            std::hint::black_box(f());
+            start.elapsed()
+        })
+        .collect::<Vec<_>>();
+    times.sort_unstable();
+    times[runs / 2]
+}
+
+fn main() {
+    const RUNS: usize = 21;
+    let room = Room::PUZZLE;
+    let input = robots(1_000_000, room, 0x9e3779b97f4a7c15);
+    assert_eq!(
+        safety_factor_with(&input, room, Layout::Aos),
+        safety_factor_with(&input, room, Layout::Soa)
+    );
+
+    let aos = time(RUNS, || safety_factor_with(&input, room, Layout::Aos));
+    let soa = time(RUNS, || safety_factor_with(&input, room, Layout::Soa));
+    let parse = time(RUNS, || SoaRobots::parse(&input, room));
+    println!("safety factor: Aos {aos:?}, Soa {soa:?} (parsing alone {parse:?})");
+
+    // Timed in place, each run moves the robots further
+    let mut robots = SoaRobots::parse(&input, room);
+    let mut simulation = Simulation::from_robots(parse_robots(&input), room);
+    let soa_jump = time(RUNS, || robots.jump(100));
+    let sim_jump = time(RUNS, || simulation.jump_to(simulation.step() + 100));
+    let soa_step = time(RUNS, || robots.step());
+    let sim_step = time(RUNS, || simulation.advance());
+    let quadrants = time(RUNS, || robots.quadrants());
+    println!("jump 100: SoaRobots {soa_jump:?}, Simulation {sim_jump:?}");
+    println!("one step: SoaRobots {soa_step:?}, Simulation {sim_step:?}");
+    println!("quadrants: SoaRobots {quadrants:?}");
+}
@@ -1,95 +1,727 @@
+use fxhash::FxHashSet;
+
 pub fn part1(input: &str) -> impl std::fmt::Display {
     let dim = input.find('\n').unwrap() + 1;
     let input = input.as_bytes();
+    let levels = Levels::new(input);
 
-    let mut visited = vec![false; dim * (dim - 1)];
+    // Each 9 is assigned one of 181 colors such that two 9s of the same color are more than 18
+    // steps apart (a perfect Lee code of radius 9). A trail is 9 steps long, so the 9s that can be
+    // reached from a single 0 all have different colors, and a bitset of colors per cell is enough
+    // to count distinct summits without tracking every 9 individually.
+    let summits = propagate(
+        input,
+        dim,
+        &levels,
+        (0..=9).rev(),
+        |i| {
+            let (row, col) = (i / dim, i % dim);
+            let color = (col + 19 * row) % 181;
+            let mut bits = [0u64; 3];
+            bits[color / 64] = 1 << (color % 64);
+            bits
+        },
+        |acc, bits| {
+            for (a, b) in acc.iter_mut().zip(bits) {
+                *a |= b;
+            }
+        },
+    );
 
-    let north = -(dim as i32);
-    let south = dim as i32;
-    let west = -1;
-    let east = 1;
+    summits
+        .iter()
+        .map(|bits| bits.iter().map(|b| b.count_ones() as usize).sum::<usize>())
+        .sum::<usize>()
+}
 
-    let neighbors = [north, south, west, east];
+pub fn part2(input: &str) -> impl std::fmt::Display {
+    let dim = input.find('\n').unwrap() + 1;
+    let input = input.as_bytes();
+    let levels = Levels::new(input);
+
+    // Number of trails from any 0 to each cell, one height at a time
+    let paths = propagate(input, dim, &levels, 0..=9, |_| 1u64, |acc, n| *acc += n);
+
+    paths.iter().sum::<u64>()
+}
 
-    let mut stack = vec![];
-    let mut result = 0;
+/// Cell indices grouped by height, along with each cell's position within its group
+struct Levels {
+    cells: [Vec<u32>; 10],
+    slot: Vec<u32>,
+}
 
-    for (i, &b) in input.iter().enumerate() {
-        // Only want to find 9s
-        if b != b'9' {
-            continue;
+impl Levels {
+    fn new(input: &[u8]) -> Self {
+        let mut cells: [Vec<u32>; 10] = Default::default();
+        let mut slot = vec![0; input.len()];
+        for (i, &b) in input.iter().enumerate() {
+            if b.is_ascii_digit() {
+                let level = &mut cells[(b - b'0') as usize];
+                slot[i] = level.len() as u32;
+                level.push(i as u32);
+            }
         }
-        stack.push(i);
-        visited.fill(false);
+        Levels { cells, slot }
+    }
+}
+
+/// Seeds every cell of the first height in `order` with `init`, then walks the remaining heights,
+/// merging into each cell the values of its neighbors at the previous height. Only two heights
+/// are kept in memory at a time. Returns the values of the last height, in `Levels` order.
+#[inline(always)]
+fn propagate<T: Copy + Default>(
+    input: &[u8],
+    dim: usize,
+    levels: &Levels,
+    mut order: impl Iterator<Item = u8>,
+    init: impl Fn(usize) -> T,
+    merge: impl Fn(&mut T, &T),
+) -> Vec<T> {
+    // The newline column separates rows, so west/east never wrap onto another row
+    let neighbors = [dim.wrapping_neg(), dim, 1usize.wrapping_neg(), 1];
 
-        // Work thru all paths starting from this 9
-        while let Some(s) = stack.pop() {
-            visited[s] = true;
+    let mut from = order.next().unwrap();
+    let mut prev = levels.cells[from as usize]
+        .iter()
+        .map(|&i| init(i as usize))
+        .collect::<Vec<_>>();
+
+    for to in order {
+        let cells = &levels.cells[to as usize];
+        let mut cur = vec![T::default(); cells.len()];
+        for (v, &i) in cur.iter_mut().zip(cells) {
             for nx in neighbors {
-                let n = s as i32 + nx;
+                let n = (i as usize).wrapping_add(nx);
                 // Bounds check
-                if n < 0 {
+                if n >= input.len() || input[n] != b'0' + from {
                     continue;
                 }
-                let n = n as usize;
-                if n >= input.len() || visited[n] {
+                merge(v, &prev[levels.slot[n] as usize]);
+            }
+        }
+        prev = cur;
+        from = to;
+    }
+    prev
+}
+
+/// Score, rating and reachable summits of every trailhead using the puzzle rules
+pub fn trailheads(input: &str) -> Vec<Trailhead> {
+    TrailQuery::default().trailheads(input)
+}
+
+/// Describes which trails to look for on a topographic map. The default query matches the puzzle:
+/// trails go from height 0 to height 9, climbing exactly 1 per step in the 4 cardinal directions.
+#[derive(Debug, Clone)]
+pub struct TrailQuery {
+    /// Height of a trailhead
+    pub start: u8,
+    /// Height of a summit. A trail ends as soon as it reaches this height.
+    pub end: u8,
+    /// Allowed height differences for a single step (e.g. `[-1, 1]`, or `[-9, ..., 1]` for "at most +1")
+    pub steps: Vec<i8>,
+    /// Also allow moving diagonally
+    pub diagonal: bool,
+}
+
+impl Default for TrailQuery {
+    fn default() -> Self {
+        TrailQuery {
+            start: 0,
+            end: 9,
+            steps: vec![1],
+            diagonal: false,
+        }
+    }
+}
+
+/// Totals over all trailheads
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TrailCounts {
+    /// Sum of distinct summits reachable from each trailhead (part 1)
+    pub summits: usize,
+    /// Number of distinct trails (part 2)
+    pub paths: u64,
+}
+
+/// A single trailhead and the trails starting from it
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Trailhead {
+    pub row: usize,
+    pub col: usize,
+    /// Number of distinct summits reachable from this trailhead
+    pub score: usize,
+    /// Number of distinct trails starting at this trailhead
+    pub rating: u64,
+    /// `(row, col)` of every reachable summit, in row-major order
+    pub summits: Vec<(usize, usize)>,
+}
+
+/// Rectangular grid of heights. Any byte that is not a digit is treated as impassable.
+#[derive(Debug, Clone, Copy)]
+pub struct TopoMap<'a> {
+    cells: &'a [u8],
+    pub width: usize,
+    pub height: usize,
+}
+
+impl<'a> TopoMap<'a> {
+    pub fn new(input: &'a str) -> Self {
+        let cells = input.as_bytes();
+        let width = input.find('\n').unwrap_or(input.len());
+        let height = input.lines().count();
+        TopoMap {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Height at `(row, col)`, or `None` if the cell is impassable
+    #[inline(always)]
+    pub fn get(&self, row: usize, col: usize) -> Option<u8> {
+        let b = self.cells[row * (self.width + 1) + col];
+        b.is_ascii_digit().then(|| b - b'0')
+    }
+
+    /// Height of cell `i` in row-major order, not counting the newline column
+    #[inline(always)]
+    fn at(&self, i: usize) -> Option<u8> {
+        self.get(i / self.width, i % self.width)
+    }
+
+    #[inline(always)]
+    fn index(&self, row: usize, col: usize) -> usize {
+        row * self.width + col
+    }
+
+    /// Calls `f` with the index of every in-bounds neighbor of cell `i`
+    #[inline(always)]
+    fn for_each_neighbor(&self, i: usize, diagonal: bool, mut f: impl FnMut(usize)) {
+        const CARDINAL: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+        const DIAGONAL: [(isize, isize); 4] = [(-1, 1), (1, 1), (1, -1), (-1, -1)];
+
+        let (row, col) = (i / self.width, i % self.width);
+        let offsets = if diagonal {
+            CARDINAL.iter().chain(DIAGONAL.iter())
+        } else {
+            CARDINAL.iter().chain([].iter())
+        };
+        for &(dr, dc) in offsets {
+            let r = row.wrapping_add_signed(dr);
+            let c = col.wrapping_add_signed(dc);
+            // Wrapping below zero gives a huge value, so a single comparison covers both sides
+            if r < self.height && c < self.width {
+                f(self.index(r, c));
+            }
+        }
+    }
+}
+
+impl TrailQuery {
+    pub fn run(&self, input: &str) -> TrailCounts {
+        self.trailheads(input)
+            .iter()
+            .fold(TrailCounts::default(), |acc, t| TrailCounts {
+                summits: acc.summits + t.score,
+                paths: acc.paths + t.rating,
+            })
+    }
+
+    /// Score, rating and reachable summits of every trailhead, in row-major order
+    pub fn trailheads(&self, input: &str) -> Vec<Trailhead> {
+        if self.steps == [1] && !self.diagonal && self.start <= self.end {
+            return self.trailheads_by_level(input);
+        }
+        let map = TopoMap::new(input);
+        let len = map.width * map.height;
+        let allowed = self.step_table();
+
+        // Summit `i` was reached from the current trailhead if `seen[i] == stamp`
+        let mut seen = vec![0u32; len];
+        let mut on_path = vec![false; len];
+
+        // stack elements -> (index, neighbors still to try)
+        let mut stack: Vec<(usize, Vec<usize>)> = vec![];
+        let mut result = vec![];
+
+        for head in (0..len).filter(|&i| map.at(i) == Some(self.start)) {
+            let stamp = head as u32 + 1;
+            let mut trailhead = Trailhead {
+                row: head / map.width,
+                col: head % map.width,
+                ..Default::default()
+            };
+
+            // Trailheads that already are summits count as a trail of length 0
+            if self.start == self.end {
+                trailhead.score = 1;
+                trailhead.rating = 1;
+                trailhead.summits.push((trailhead.row, trailhead.col));
+                result.push(trailhead);
+                continue;
+            }
+
+            // Backtracking DFS. Trails may not revisit a cell, which keeps the search finite when
+            // `steps` allows walking both up and down.
+            on_path[head] = true;
+            stack.push((head, self.next_cells(&map, &allowed, head)));
+
+            while let Some((s, todo)) = stack.last_mut() {
+                let Some(n) = todo.pop() else {
+                    on_path[*s] = false;
+                    stack.pop();
+                    continue;
+                };
+                if on_path[n] {
                     continue;
                 }
-                if input[n] == input[s] - 1 {
-                    if input[n] == b'0' {
-                        visited[n] = true;
-                        result += 1;
-                        continue;
+                if map.at(n) == Some(self.end) {
+                    trailhead.rating += 1;
+                    if seen[n] != stamp {
+                        seen[n] = stamp;
+                        trailhead.summits.push((n / map.width, n % map.width));
                     }
-                    stack.push(n);
+                    continue;
                 }
+                on_path[n] = true;
+                let next = self.next_cells(&map, &allowed, n);
+                stack.push((n, next));
             }
+            trailhead.summits.sort_unstable();
+            trailhead.score = trailhead.summits.len();
+            result.push(trailhead);
         }
+        result
+    }
+
+    /// Same as `trailheads` for trails that climb exactly 1 per step in the cardinal directions,
+    /// using the level DP from the parts instead of walking every trail. Linear in the map size.
+    fn trailheads_by_level(&self, input: &str) -> Vec<Trailhead> {
+        let dim = input.find('\n').unwrap_or(input.len()) + 1;
+        let bytes = input.as_bytes();
+        let levels = Levels::new(bytes);
+        let descending = || (self.start..=self.end).rev();
+
+        // Same coloring as part 1. A trail is at most 9 steps long, so the cells within 9 steps of
+        // a trailhead all have different colors, and `offset[color]` recovers the summit.
+        let color = |row: usize, col: usize| (col + 19 * row) % 181;
+        let mut offset = [(0, 0); 181];
+        for dr in -9isize..=9 {
+            for dc in -(9 - dr.abs())..=9 - dr.abs() {
+                offset[(dc + 19 * dr).rem_euclid(181) as usize] = (dr, dc);
+            }
+        }
+        let summits = propagate(
+            bytes,
+            dim,
+            &levels,
+            descending(),
+            |i| {
+                let c = color(i / dim, i % dim);
+                let mut bits = [0u64; 3];
+                bits[c / 64] = 1 << (c % 64);
+                bits
+            },
+            |acc, bits| {
+                for (a, b) in acc.iter_mut().zip(bits) {
+                    *a |= b;
+                }
+            },
+        );
+        // Number of trails from each cell to any summit, one height at a time
+        let ratings = propagate(
+            bytes,
+            dim,
+            &levels,
+            descending(),
+            |_| 1u64,
+            |acc, n| *acc += n,
+        );
+
+        let heads = &levels.cells[self.start as usize];
+        heads
+            .iter()
+            .zip(summits.iter().zip(ratings))
+            .map(|(&i, (bits, rating))| {
+                let (row, col) = (i as usize / dim, i as usize % dim);
+                let base = color(row, col);
+                let mut summits = (0..181)
+                    .filter(|c| bits[c / 64] >> (c % 64) & 1 == 1)
+                    .map(|c| {
+                        let (dr, dc) = offset[(c + 181 - base) % 181];
+                        (row.wrapping_add_signed(dr), col.wrapping_add_signed(dc))
+                    })
+                    .collect::<Vec<_>>();
+                summits.sort_unstable();
+                Trailhead {
+                    row,
+                    col,
+                    score: summits.len(),
+                    rating,
+                    summits,
+                }
+            })
+            .collect()
     }
 
-    result
+    /// Lazily yields every trail starting at `(row, col)` as a list of `(row, col)` coordinates.
+    /// Yields nothing if `(row, col)` is not a trailhead.
+    pub fn trails<'a>(&'a self, map: TopoMap<'a>, row: usize, col: usize) -> Trails<'a> {
+        let head = map.index(row, col);
+        let mut trails = Trails {
+            query: self,
+            map,
+            allowed: self.step_table(),
+            on_path: FxHashSet::default(),
+            stack: vec![],
+            single: None,
+        };
+        if map.get(row, col) == Some(self.start) {
+            if self.start == self.end {
+                trails.single = Some((row, col));
+            } else {
+                trails.on_path.insert(head);
+                trails
+                    .stack
+                    .push((head, self.next_cells(&map, &trails.allowed, head)));
+            }
+        }
+        trails
+    }
+
+    /// Number of trails passing through each cell, in row-major order. This walks every trail
+    /// individually, so it is only practical when the total rating is reasonably small.
+    pub fn traffic(&self, input: &str) -> Vec<u64> {
+        let map = TopoMap::new(input);
+        let mut traffic = vec![0; map.width * map.height];
+        for row in 0..map.height {
+            for col in 0..map.width {
+                for trail in self.trails(map, row, col) {
+                    for (r, c) in trail {
+                        traffic[map.index(r, c)] += 1;
+                    }
+                }
+            }
+        }
+        traffic
+    }
+
+    /// Which height differences are allowed, indexed by `delta + 128`
+    fn step_table(&self) -> [bool; 256] {
+        let mut allowed = [false; 256];
+        for &s in &self.steps {
+            allowed[(s as i16 + 128) as usize] = true;
+        }
+        allowed
+    }
+
+    /// Indices of the neighbors of `i` that can be stepped onto
+    fn next_cells(&self, map: &TopoMap, allowed: &[bool; 256], i: usize) -> Vec<usize> {
+        let h = map.at(i).unwrap() as i16;
+        let mut next = vec![];
+        map.for_each_neighbor(i, self.diagonal, |n| {
+            if map
+                .at(n)
+                .is_some_and(|nh| allowed[(nh as i16 - h + 128) as usize])
+            {
+                next.push(n);
+            }
+        });
+        next
+    }
 }
 
-pub fn part2(input: &str) -> impl std::fmt::Display {
-    let dim = input.find('\n').unwrap() + 1;
-    let input = input.as_bytes();
+/// Iterator over the trails from a single trailhead, see [`TrailQuery::trails`]
+pub struct Trails<'a> {
+    query: &'a TrailQuery,
+    map: TopoMap<'a>,
+    allowed: [bool; 256],
+    on_path: FxHashSet<usize>,
+    // stack elements -> (index, neighbors still to try)
+    stack: Vec<(usize, Vec<usize>)>,
+    // Set if the trailhead is also a summit, in which case it is the only trail
+    single: Option<(usize, usize)>,
+}
 
-    let north = -(dim as i32);
-    let south = dim as i32;
-    let west = -1;
-    let east = 1;
+impl Iterator for Trails<'_> {
+    type Item = Vec<(usize, usize)>;
 
-    let neighbors = [north, south, west, east];
+    fn next(&mut self) -> Option<Self::Item> {
+        let width = self.map.width;
+        if let Some(head) = self.single.take() {
+            return Some(vec![head]);
+        }
+        while let Some((s, todo)) = self.stack.last_mut() {
+            let Some(n) = todo.pop() else {
+                self.on_path.remove(s);
+                self.stack.pop();
+                continue;
+            };
+            if self.on_path.contains(&n) {
+                continue;
+            }
+            if self.map.at(n) == Some(self.query.end) {
+                let trail = self
+                    .stack
+                    .iter()
+                    .map(|&(i, _)| i)
+                    .chain([n])
+                    .map(|i| (i / width, i % width))
+                    .collect();
+                return Some(trail);
+            }
+            self.on_path.insert(n);
+            let next = self.query.next_cells(&self.map, &self.allowed, n);
+            self.stack.push((n, next));
+        }
+        None
+    }
+}
 
-    let mut stack = vec![];
-    let mut result = 0;
+/// What to draw on top of the map in [`render`]
+#[derive(Debug, Clone, Copy)]
+pub enum Overlay<'a> {
+    /// Highlight a single trail, as yielded by [`TrailQuery::trails`]
+    Trail(&'a [(usize, usize)]),
+    /// Shade each cell by the number of trails passing through it, see [`TrailQuery::traffic`]
+    Heat(&'a [u64]),
+}
 
-    for (i, &b) in input.iter().enumerate() {
-        // Only want to find 9s
-        if b != b'9' {
-            continue;
+/// Draws the map with `overlay` on top. Plain ASCII hides everything that is not on the trail, or
+/// replaces heights with a density ramp for heat maps. With `ansi` the heights are kept and the
+/// overlay is drawn with terminal colors instead.
+pub fn render(map: &TopoMap, overlay: Overlay, ansi: bool) -> String {
+    const RAMP: &[u8] = b" .:-=+*#%@";
+    // xterm-256 background colors from cold to hot
+    const HEAT: [u8; 10] = [236, 17, 19, 27, 33, 45, 48, 190, 214, 196];
+    const RESET: &str = "\x1b[0m";
+
+    let mut on_trail = vec![false; map.width * map.height];
+    let mut max_heat = 0;
+    match overlay {
+        Overlay::Trail(trail) => {
+            for &(r, c) in trail {
+                on_trail[map.index(r, c)] = true;
+            }
         }
-        stack.push(i);
+        Overlay::Heat(heat) => max_heat = heat.iter().copied().max().unwrap_or(0).max(1),
+    }
+    // Scales a traffic count to 1..=9, leaving 0 for cells no trail goes through
+    let level = |v: u64| (v * 9).div_ceil(max_heat) as usize;
 
-        // Work thru all paths starting from this 9
-        while let Some(s) = stack.pop() {
-            for nx in neighbors {
-                let n = s as i32 + nx;
-                // Bounds check
-                if n < 0 || n as usize >= input.len() {
-                    continue;
+    let mut out = String::new();
+    for row in 0..map.height {
+        for col in 0..map.width {
+            let i = map.index(row, col);
+            let c = map.get(row, col).map_or('.', |h| (b'0' + h) as char);
+            match (overlay, ansi) {
+                (Overlay::Trail(_), false) => out.push(if on_trail[i] { c } else { '.' }),
+                (Overlay::Trail(_), true) => {
+                    let style = if on_trail[i] { "\x1b[1;32m" } else { "\x1b[2m" };
+                    out.push_str(style);
+                    out.push(c);
+                    out.push_str(RESET);
                 }
-                let n = n as usize;
-                if input[n] == input[s] - 1 {
-                    if input[n] == b'0' {
-                        result += 1;
-                        continue;
-                    }
-                    stack.push(n);
+                (Overlay::Heat(heat), false) => out.push(RAMP[level(heat[i])] as char),
+                (Overlay::Heat(heat), true) => {
+                    out.push_str(&format!("\x1b[48;5;{}m", HEAT[level(heat[i])]));
+                    out.push(c);
+                    out.push_str(RESET);
                 }
             }
         }
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+";
+
+    #[test]
+    fn example_trailheads() {
+        let heads = trailheads(EXAMPLE);
+        let scores = heads.iter().map(|t| t.score).collect::<Vec<_>>();
+        let ratings = heads.iter().map(|t| t.rating).collect::<Vec<_>>();
+        assert_eq!(scores, [5, 6, 5, 3, 1, 3, 5, 3, 5]);
+        assert_eq!(ratings, [20, 24, 10, 4, 1, 4, 5, 8, 5]);
+        assert_eq!((heads[0].row, heads[0].col), (0, 2));
+        assert_eq!(part1(EXAMPLE).to_string(), "36");
+        assert_eq!(part2(EXAMPLE).to_string(), "81");
+    }
+
+    #[test]
+    fn level_dp_matches_trails() {
+        let map = TopoMap::new(EXAMPLE);
+        for (start, end) in [(0, 9), (2, 7), (5, 5)] {
+            let query = TrailQuery {
+                start,
+                end,
+                ..Default::default()
+            };
+            for t in query.trailheads(EXAMPLE) {
+                let trails = query.trails(map, t.row, t.col).collect::<Vec<_>>();
+                let mut summits = trails
+                    .iter()
+                    .map(|t| *t.last().unwrap())
+                    .collect::<Vec<_>>();
+                summits.sort_unstable();
+                summits.dedup();
+                assert_eq!(t.rating, trails.len() as u64);
+                assert_eq!(t.summits, summits);
+            }
+        }
+    }
+
+    fn query(start: u8, end: u8, steps: &[i8], diagonal: bool) -> TrailQuery {
+        TrailQuery {
+            start,
+            end,
+            steps: steps.to_vec(),
+            diagonal,
+        }
+    }
+
+    /// `(row, col, score, rating)` of every trailhead
+    fn summary(query: &TrailQuery, input: &str) -> Vec<(usize, usize, usize, u64)> {
+        query
+            .trailheads(input)
+            .iter()
+            .map(|t| (t.row, t.col, t.score, t.rating))
+            .collect()
+    }
+
+    #[test]
+    fn diagonal_steps() {
+        let corners = "1.1\n.0.\n1.1\n";
+        assert_eq!(summary(&query(0, 1, &[1], true), corners), [(1, 1, 4, 4)]);
+        assert_eq!(summary(&query(0, 1, &[1], false), corners), [(1, 1, 0, 0)]);
+
+        let heads = query(0, 1, &[1], true).trailheads("0.\n.1\n");
+        assert_eq!(heads[0].summits, [(1, 1)]);
+    }
+
+    #[test]
+    fn other_steps() {
+        // Only every other height is present, so climbing 1 at a time never gets anywhere
+        assert_eq!(summary(&query(0, 6, &[2], false), "0246\n"), [(0, 0, 1, 1)]);
+        assert_eq!(summary(&query(0, 6, &[1], false), "0246\n"), [(0, 0, 0, 0)]);
+
+        // Going down is allowed, so trails may detour through the other trailhead
+        let valley = "010\n121\n";
+        assert_eq!(
+            summary(&query(0, 2, &[-1, 1], false), valley),
+            [(0, 0, 1, 3), (0, 2, 1, 3)]
+        );
+        assert_eq!(
+            summary(&query(0, 2, &[1], false), valley),
+            [(0, 0, 1, 2), (0, 2, 1, 2)]
+        );
+    }
+
+    #[test]
+    fn rectangular_maps() {
+        // One trail along the top row, and one for each place the trail can step down a row
+        let wide = "0123456789\n1234567898\n";
+        let tall = "01\n12\n23\n34\n45\n56\n67\n78\n89\n98\n";
+        let query = TrailQuery::default();
+        for (input, summits) in [(wide, [(0, 9), (1, 8)]), (tall, [(8, 1), (9, 0)])] {
+            let heads = query.trailheads(input);
+            assert_eq!(heads.len(), 1);
+            assert_eq!((heads[0].score, heads[0].rating), (2, 10));
+            assert_eq!(heads[0].summits, summits);
+            assert_eq!(part1(input).to_string(), "2");
+            assert_eq!(part2(input).to_string(), "10");
+            // The DFS gives the same answer as the level DP
+            let dfs = TrailQuery {
+                steps: vec![1, 1],
+                ..Default::default()
+            };
+            assert_eq!(dfs.trailheads(input), heads);
+        }
+    }
+
+    #[test]
+    fn traffic_and_heat_map() {
+        // The top row trail, plus one trail stepping down at each column 0..=8
+        let wide = "0123456789\n1234567898\n";
+        let traffic = TrailQuery::default().traffic(wide);
+        assert_eq!(traffic[..10], [10, 9, 8, 7, 6, 5, 4, 3, 2, 1]);
+        assert_eq!(traffic[10..], [1, 2, 3, 4, 5, 6, 7, 8, 9, 0]);
+        let map = TopoMap::new(wide);
+        assert_eq!(
+            render(&map, Overlay::Heat(&traffic), false),
+            "@@%#*+=-:.\n.:-=+*#%@ \n"
+        );
+
+        // Every trail has 10 cells and starts on a trailhead
+        let traffic = TrailQuery::default().traffic(EXAMPLE);
+        let map = TopoMap::new(EXAMPLE);
+        assert_eq!(traffic.iter().sum::<u64>(), 81 * 10);
+        let at_heads = (0..64).filter(|&i| map.at(i) == Some(0));
+        assert_eq!(at_heads.map(|i| traffic[i]).sum::<u64>(), 81);
+        let heat = render(&map, Overlay::Heat(&traffic), false);
+        assert_eq!(heat.lines().count(), 8);
+        let max = *traffic.iter().max().unwrap();
+        let hottest = traffic.iter().filter(|&&t| t * 9 > max * 8).count();
+        assert_eq!(heat.matches('@').count(), hottest);
+        let unused = traffic.iter().filter(|&&t| t == 0).count();
+        assert_eq!(heat.matches(' ').count(), unused);
+
+        let ansi = render(&map, Overlay::Heat(&traffic), true);
+        assert_eq!(ansi.matches("\x1b[48;5;").count(), 64);
+        assert_eq!(strip_ansi(&ansi), EXAMPLE);
     }
 
-    result
-}
\ No newline at end of file
+    #[test]
+    fn trail_overlay() {
+        let map = TopoMap::new(EXAMPLE);
+        let trail = TrailQuery::default().trails(map, 0, 2).next().unwrap();
+        let plain = render(&map, Overlay::Trail(&trail), false);
+        assert_eq!(plain.len(), EXAMPLE.len());
+        assert_eq!(plain.matches('.').count(), 64 - 10);
+        assert_eq!(plain.bytes().filter(u8::is_ascii_digit).count(), 10);
+        for &(r, c) in &trail {
+            assert_eq!(plain.as_bytes()[r * 9 + c], EXAMPLE.as_bytes()[r * 9 + c]);
+        }
+
+        let top_row = (0..10).map(|c| (0, c)).collect::<Vec<_>>();
+        let wide = TopoMap::new("0123456789\n1234567898\n");
+        assert_eq!(
+            render(&wide, Overlay::Trail(&top_row), false),
+            "0123456789\n..........\n"
+        );
+        let ansi = render(&wide, Overlay::Trail(&top_row), true);
+        assert_eq!(ansi.matches("\x1b[1;32m").count(), 10);
+        assert_eq!(ansi.matches("\x1b[2m").count(), 10);
+        assert_eq!(strip_ansi(&ansi), "0123456789\n1234567898\n");
+    }
+
+    /// Removes the `ESC [ ... m` sequences `render` emits with `ansi`
+    fn strip_ansi(s: &str) -> String {
+        let mut out = String::new();
+        let mut chars = s.chars();
+        while let Some(c) = chars.next() {
+            if c == '\x1b' {
+                chars.by_ref().find(|&c| c == 'm');
+            } else {
+                out.push(c);
+            }
+        }
+        out
+    }
+}
@@ -1,22 +1,218 @@
+use fxhash::{FxHashMap, FxHashSet};
+use itertools::Itertools;
+
+mod lut;
+mod rules;
+mod stone;
+
+pub use lut::generate as generate_lut;
+pub use rules::{Blink, StoneRules};
+pub use stone::{BigStone, Stone};
+
+use stone::split_usize;
+
 pub fn part1(input: &str) -> impl std::fmt::Display {
-    input
-        .split_ascii_whitespace()
-        .map(|n| n.parse::<usize>().unwrap())
-        .fold(0, |acc, n| acc + process_usize(n, 25))
+    solve(input, 25)
 }
 
 pub fn part2(input: &str) -> impl std::fmt::Display {
-    input
-        .split_ascii_whitespace()
-        .map(|n| n.parse::<usize>().unwrap())
-        .fold(0, |acc, n| acc + process_usize(n, 75))
+    solve(input, 75)
+}
+
+#[inline(always)]
+fn solve(input: &str, blinks: usize) -> u128 {
+    input.split_ascii_whitespace().fold(0, |acc, s| {
+        let count = match s
+            .parse::<usize>()
+            .ok()
+            .and_then(|n| process_usize(n, blinks))
+        {
+            Some(count) => count as u128,
+            // Seed is too big for the fast path, or one of its stones would overflow a `usize`
+            None => blink_count(&[s.parse::<BigStone>().unwrap()], blinks).unwrap(),
+        };
+        acc + count
+    })
+}
+
+/// Something in [`blink_count`] got too big to represent
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BlinkOverflow {
+    /// A stone's engraving no longer fits in its integer type after multiplying. Use a wider
+    /// [`Stone`] type such as `u128` or [`BigStone`].
+    Stone { blink: usize, stone: String },
+    /// The total number of stones no longer fits in a `u128`
+    Count { blink: usize },
+}
+
+impl std::fmt::Display for BlinkOverflow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BlinkOverflow::Stone { blink, stone } => {
+                write!(
+                    f,
+                    "stone {stone} overflows when multiplied on blink {blink}"
+                )
+            }
+            BlinkOverflow::Count { blink } => {
+                write!(f, "stone count overflows u128 on blink {blink}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BlinkOverflow {}
+
+/// Number of stones after any number of blinks. Only the count of each distinct engraving is
+/// tracked, and the set of distinct engravings stays small, so each blink is cheap regardless of
+/// how many stones there are.
+///
+/// The count itself grows by ~1.5x per blink, so with the puzzle rules it leaves the `u128` range
+/// after a bit over 200 blinks (211 is the last one that fits for the example `125 17`). Past that
+/// this returns [`BlinkOverflow::Count`] instead of wrapping.
+pub fn blink_count<S: Stone>(stones: &[S], blinks: usize) -> Result<u128, BlinkOverflow> {
+    blink_count_with(stones, blinks, &StoneRules::PUZZLE)
+}
+
+/// Same as [`blink_count`] with custom rules. Panics if `rules` aren't valid, see
+/// [`StoneRules::validate`].
+pub fn blink_count_with<S: Stone>(
+    stones: &[S],
+    blinks: usize,
+    rules: &StoneRules,
+) -> Result<u128, BlinkOverflow> {
+    stone_distribution(stones, blinks, rules)?
+        .values()
+        .try_fold(0u128, |acc, &c| acc.checked_add(c))
+        .ok_or(BlinkOverflow::Count { blink: blinks })
+}
+
+/// Number of stones with each engraving after `blinks` blinks. Fails as soon as the total number
+/// of stones overflows a `u128`, even though the individual counts might still fit.
+///
+/// Panics if `rules` aren't valid, see [`StoneRules::validate`].
+pub fn stone_distribution<S: Stone>(
+    stones: &[S],
+    blinks: usize,
+    rules: &StoneRules,
+) -> Result<FxHashMap<S, u128>, BlinkOverflow> {
+    rules.validate();
+    let mut counts = FxHashMap::default();
+    for n in stones {
+        *counts.entry(n.clone()).or_insert(0u128) += 1;
+    }
+    let mut next = FxHashMap::default();
+
+    for blink in 1..=blinks {
+        let mut total = 0u128;
+        for (n, &c) in &counts {
+            let mut add = |n: S| -> Result<(), BlinkOverflow> {
+                // No single count can overflow if the total doesn't
+                total = total.checked_add(c).ok_or(BlinkOverflow::Count { blink })?;
+                *next.entry(n).or_insert(0u128) += c;
+                Ok(())
+            };
+            match n.blink(rules) {
+                Some(Blink::One(n)) => add(n)?,
+                Some(Blink::Split(a, b)) => {
+                    add(a)?;
+                    add(b)?;
+                }
+                None => {
+                    let stone = n.to_string();
+                    return Err(BlinkOverflow::Stone { blink, stone });
+                }
+            }
+        }
+        std::mem::swap(&mut counts, &mut next);
+        next.clear();
+    }
+    Ok(counts)
+}
+
+/// How the set of engravings evolves, see [`analyze_stones`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoneAnalysis<S> {
+    /// Number of distinct engravings after each blink, starting with the initial stones
+    pub distinct: Vec<usize>,
+    /// Every engraving that appears at some point, sorted
+    pub reached: Vec<S>,
+    /// First blink after which the set of engravings repeats itself
+    pub settled_at: usize,
+    /// Number of blinks before the set of engravings repeats
+    pub period: usize,
+    /// Engravings present at blink `settled_at`, sorted. With a period of 1 the system stays in
+    /// exactly this set forever.
+    pub closed_set: Vec<S>,
+}
+
+/// Tracks which engravings are present (ignoring how many stones have them) until the set of
+/// engravings repeats. Returns `None` if that doesn't happen within `max_blinks`. Panics if
+/// `rules` aren't valid, see [`StoneRules::validate`].
+///
+/// For the puzzle rules this settles quickly into a small set of values (54 of them for the puzzle
+/// example from blink 17 on, all but 5 below 100k), which is why a LUT of the first 1000 values
+/// answers almost every lookup.
+pub fn analyze_stones<S: Stone + Ord>(
+    stones: &[S],
+    max_blinks: usize,
+    rules: &StoneRules,
+) -> Result<Option<StoneAnalysis<S>>, BlinkOverflow> {
+    rules.validate();
+    let mut set = stones.iter().cloned().collect::<FxHashSet<_>>();
+    let mut reached = set.clone();
+    let mut distinct = vec![set.len()];
+
+    // Sorted set of engravings -> first blink it appeared at
+    let mut history = FxHashMap::default();
+    let sorted = |set: &FxHashSet<S>| set.iter().cloned().sorted_unstable().collect_vec();
+    history.insert(sorted(&set), 0);
+
+    for blink in 1..=max_blinks {
+        let mut next = FxHashSet::default();
+        for n in &set {
+            match n.blink(rules) {
+                Some(Blink::One(n)) => {
+                    next.insert(n);
+                }
+                Some(Blink::Split(a, b)) => {
+                    next.insert(a);
+                    next.insert(b);
+                }
+                None => {
+                    let stone = n.to_string();
+                    return Err(BlinkOverflow::Stone { blink, stone });
+                }
+            }
+        }
+        set = next;
+        reached.extend(set.iter().cloned());
+        distinct.push(set.len());
+
+        let key = sorted(&set);
+        if let Some(&settled_at) = history.get(&key) {
+            return Ok(Some(StoneAnalysis {
+                distinct,
+                reached: sorted(&reached),
+                settled_at,
+                period: blink - settled_at,
+                closed_set: key,
+            }));
+        }
+        history.insert(key, blink);
+    }
+    Ok(None)
 }
 
 /// With LUT of only the first 100 numbers, part 2 takes ~11 ms. With LUT of the first 1000 numbers it
 /// takes ~25 microseconds on the first run, ~200 ns on subsequent runs after cache is hot (on my 7800X3D).
+/// Returns `None` if a stone's engraving would overflow a `usize`.
 #[inline(always)]
-fn process_usize(num: usize, blinks: usize) -> usize {
-    let mut stack = [(0, 0); 48];
+fn process_usize(num: usize, blinks: usize) -> Option<usize> {
+    // 48 is enough when the LUT covers all 75 blinks. Every blink the LUT can't answer may leave
+    // one more half of a split stone waiting on the stack.
+    const STACK: usize = 48 + 75usize.saturating_sub(LUT_BLINKS);
+    let mut stack = [(0, 0); STACK];
     stack[0] = (blinks, num);
     let mut stack_len = 1;
 
@@ -29,1062 +225,250 @@ fn process_usize(num: usize, blinks: usize) -> usize {
             stack_len -= 1;
             continue;
         }
-        if n < 1000 {
+        if n < LUT_VALUES && i <= LUT_BLINKS {
             result += LUT[n][i - 1];
             stack_len -= 1;
             continue;
         }
-        if let Some((a, b)) = split_usize(n) {
+        if n == 0 {
+            stack[stack_len - 1] = (i - 1, 1);
+        } else if let Some((a, b)) = split_usize(n) {
             stack[stack_len - 1] = (i - 1, a);
             stack[stack_len] = (i - 1, b);
             stack_len += 1;
         } else {
-            stack[stack_len - 1] = (i - 1, n * 2024);
+            stack[stack_len - 1] = (i - 1, n.checked_mul(2024)?);
         }
     }
-    result
+    Some(result)
 }
 
-/// Splits a number in half if it has an even number of digits
-#[inline(always)]
-fn split_usize(n: usize) -> Option<(usize, usize)> {
-    let mag = if n < 10 {
-        return None;
-    } else if n < 100 {
-        10
-    } else if n < 1000 {
-        return None;
-    } else if n < 10_000 {
-        100
-    } else if n < 100_000 {
-        return None;
-    } else if n < 1_000_000 {
-        1000
-    } else if n < 10_000_000 {
-        return None;
-    } else if n < 100_000_000 {
-        10_000
-    } else if n < 1_000_000_000 {
-        return None;
-    } else if n < 10_000_000_000 {
-        100_000
-    } else if n < 100_000_000_000 {
-        return None;
-    } else if n < 1_000_000_000_000 {
-        1_000_000
-    } else if n < 10_000_000_000_000 {
-        return None;
-    } else if n < 100_000_000_000_000 {
-        10_000_000
-    } else if n < 1_000_000_000_000_000 {
-        return None;
-    } else if n < 10_000_000_000_000_000 {
-        100_000_000
-    } else {
-        unreachable!();
-    };
-    Some((n / mag, n % mag))
-}
+// Generated by `build.rs`, see `lut::generate`. Defines `LUT_VALUES`, `LUT_BLINKS` and `LUT`.
+include!(concat!(env!("OUT_DIR"), "/day11_lut.rs"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        assert_eq!(part1("125 17").to_string(), "55312");
+        assert_eq!(part2("125 17").to_string(), "65601038650482");
+    }
+
+    #[test]
+    fn blink_count_limits() {
+        let example = [125usize, 17];
+        assert_eq!(blink_count(&example, 0), Ok(2));
+        assert_eq!(blink_count(&example, 6), Ok(22));
+        assert_eq!(blink_count(&example, 75), Ok(65601038650482));
+        assert_eq!(
+            blink_count(&example, 200),
+            Ok(3228697720950807773236428359413636851)
+        );
+        assert!(blink_count(&example, 211).is_ok());
+        assert_eq!(
+            blink_count(&example, 212),
+            Err(BlinkOverflow::Count { blink: 212 })
+        );
+        // Stops at the first blink that overflows
+        assert_eq!(
+            blink_count(&example, 1000),
+            Err(BlinkOverflow::Count { blink: 212 })
+        );
+    }
+
+    #[test]
+    fn example_settles() {
+        let analysis = analyze_stones(&[125usize, 17], 100, &StoneRules::PUZZLE)
+            .unwrap()
+            .unwrap();
+        assert_eq!((analysis.settled_at, analysis.period), (17, 1));
+        assert_eq!(analysis.distinct[..6], [2, 3, 4, 5, 8, 12]);
+        assert_eq!(analysis.distinct[17..], [54, 54]);
+        assert_eq!(analysis.reached.len(), 76);
+
+        let closed = &analysis.closed_set;
+        assert_eq!(closed.len(), 54);
+        assert!(closed.is_sorted());
+        assert_eq!(closed[..10], [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        let large = closed.iter().filter(|&&n| n >= 100_000).copied();
+        assert!(large.eq([20482880, 24579456, 28676032, 32772608, 36869184]));
+
+        // Period 1, so any later blink has exactly these engravings
+        let distribution = stone_distribution(&[125usize, 17], 40, &StoneRules::PUZZLE).unwrap();
+        let mut keys = distribution.keys().copied().collect::<Vec<_>>();
+        keys.sort_unstable();
+        assert_eq!(&keys, closed);
+
+        // Never settles within 10 blinks
+        assert_eq!(
+            analyze_stones(&[125usize, 17], 10, &StoneRules::PUZZLE),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn stone_types_agree() {
+        let big = ["125".parse::<BigStone>().unwrap(), "17".parse().unwrap()];
+        let base3 = StoneRules {
+            base: 3,
+            ..StoneRules::PUZZLE
+        };
+        for (rules, blinks, expected) in [
+            (StoneRules::PUZZLE, 75, 65601038650482),
+            (base3, 25, 51742),
+            (base3, 75, 50089036602036),
+        ] {
+            assert_eq!(
+                blink_count_with(&[125usize, 17], blinks, &rules),
+                Ok(expected)
+            );
+            assert_eq!(
+                blink_count_with(&[125u128, 17], blinks, &rules),
+                Ok(expected)
+            );
+            assert_eq!(blink_count_with(&big, blinks, &rules), Ok(expected));
+        }
+
+        // 10^18 has 19 digits, and times 2024 it no longer fits in a `usize`
+        let seed = 10usize.pow(18);
+        let stone = seed.to_string();
+        assert_eq!(
+            blink_count(&[seed], 1),
+            Err(BlinkOverflow::Stone { blink: 1, stone })
+        );
+        let wide = blink_count(&[seed as u128], 25).unwrap();
+        assert_eq!(
+            blink_count(&[BigStone::new(seed as u128, 10)], 25),
+            Ok(wide)
+        );
+        assert_eq!(part1("1000000000000000000").to_string(), wide.to_string());
+
+        // Past the `u128` range only `BigStone` keeps going. 40 ones split into two halves that fit.
+        let huge = "1".repeat(40).parse::<BigStone>().unwrap();
+        let half = "1".repeat(20).parse::<u128>().unwrap();
+        assert!(matches!(
+            blink_count(&[u128::MAX / 3], 1),
+            Err(BlinkOverflow::Stone { .. })
+        ));
+        assert_eq!(blink_count(&[huge], 30), blink_count(&[half, half], 29));
+    }
+
+    #[test]
+    fn big_stones_compare_by_value() {
+        use std::hash::{BuildHasher, BuildHasherDefault};
+        let hash = |s: &BigStone| BuildHasherDefault::<fxhash::FxHasher>::default().hash_one(s);
+
+        let (decimal, binary) = (BigStone::new(5, 10), BigStone::new(5, 2));
+        assert_eq!(decimal, binary);
+        assert_eq!(decimal.cmp(&binary), std::cmp::Ordering::Equal);
+        assert_eq!(hash(&decimal), hash(&binary));
+        assert_ne!(decimal, BigStone::new(6, 2));
+        assert_eq!(BigStone::new(0, 3), "0".parse().unwrap());
 
-// Forgive me for my sins
-#[rustfmt::skip]
-static LUT: [[usize; 75]; 1000] = [[1, 1, 2, 4, 4, 7, 14, 16, 20, 39, 62, 81, 110, 200, 328, 418, 667, 1059, 1546, 2377, 3572, 5602, 8268, 12343, 19778, 29165, 43726, 67724, 102131, 156451, 234511, 357632, 549949, 819967, 1258125, 1916299, 2886408, 4414216, 6669768, 10174278, 15458147, 23333796, 35712308, 54046805, 81997335, 125001266, 189148778, 288114305, 437102505, 663251546, 1010392024, 1529921658, 2327142660, 3537156082, 5362947711, 8161193535, 12380334344, 18810542532, 28593873985, 43369895096, 65961219181, 100139236592, 152044639077, 231153279853, 350788203951, 533109439771, 809736749749, 1229368413225, 1868593299929, 2836699741490, 4309440263048, 6547241478879, 9940362821695, 15104984244538, 22938365706844],
-[1, 2, 4, 4, 7, 14, 16, 20, 39, 62, 81, 110, 200, 328, 418, 667, 1059, 1546, 2377, 3572, 5602, 8268, 12343, 19778, 29165, 43726, 67724, 102131, 156451, 234511, 357632, 549949, 819967, 1258125, 1916299, 2886408, 4414216, 6669768, 10174278, 15458147, 23333796, 35712308, 54046805, 81997335, 125001266, 189148778, 288114305, 437102505, 663251546, 1010392024, 1529921658, 2327142660, 3537156082, 5362947711, 8161193535, 12380334344, 18810542532, 28593873985, 43369895096, 65961219181, 100139236592, 152044639077, 231153279853, 350788203951, 533109439771, 809736749749, 1229368413225, 1868593299929, 2836699741490, 4309440263048, 6547241478879, 9940362821695, 15104984244538, 22938365706844, 34840149002654],
-[1, 2, 4, 4, 6, 12, 16, 19, 30, 57, 92, 111, 181, 295, 414, 661, 977, 1501, 2270, 3381, 5463, 7921, 11819, 18712, 27842, 42646, 64275, 97328, 150678, 223730, 343711, 525238, 784952, 1208065, 1824910, 2774273, 4230422, 6365293, 9763578, 14777945, 22365694, 34205743, 51643260, 78678894, 119550250, 181040219, 276213919, 417940971, 635526775, 967190364, 1464254721, 2230181584, 3383369064, 5136784035, 7815929513, 11846276236, 18021119171, 27366673929, 41525589303, 63172165494, 95834918754, 145631430697, 221285376505, 335810508406, 510578405753, 775057917791, 1177233081218, 1789105460648, 2715548351448, 4126944742410, 6267603928109, 9517722204783, 14463411318409, 21959000077642, 33361879939123],
-[1, 2, 4, 4, 5, 10, 16, 26, 35, 52, 79, 114, 202, 294, 401, 642, 987, 1556, 2281, 3347, 5360, 7914, 12116, 18714, 27569, 42628, 64379, 98160, 150493, 223231, 344595, 524150, 788590, 1210782, 1821382, 2779243, 4230598, 6382031, 9778305, 14761601, 22417792, 34225846, 51690137, 78827911, 119542610, 181315830, 276460158, 418258991, 636641443, 967436144, 1466002423, 2232993017, 3385449247, 5144151283, 7821403715, 11858275227, 18043579638, 27385205762, 41578776877, 63230023120, 95919165597, 145810654517, 221464625286, 336184217342, 511098463417, 775739293861, 1178609585462, 1790704917622, 2718330612121, 4131363673883, 6273232027849, 9528181900890, 14477384086838, 21980614313123, 33397574184836],
-[1, 2, 4, 4, 4, 8, 16, 27, 30, 47, 82, 115, 195, 269, 390, 637, 951, 1541, 2182, 3204, 5280, 7721, 11820, 17957, 26669, 41994, 62235, 95252, 146462, 216056, 336192, 508191, 766555, 1178119, 1761823, 2709433, 4110895, 6188994, 9515384, 14316637, 21807800, 33255984, 50149950, 76709712, 116004670, 176169842, 268815408, 405925411, 618986605, 939523808, 1424046245, 2170908709, 3286453556, 4999818380, 7599067248, 11516149089, 17538646495, 26595346202, 40399586486, 61439053769, 93157147262, 141707341785, 215121357662, 326594116560, 496648284472, 753474466137, 1145237350863, 1739662144358, 2640672818870, 4014395018228, 6093717587578, 9257463114209, 14065709226166, 21352521089525, 32450231489345],
-[1, 1, 2, 4, 8, 8, 11, 22, 32, 45, 67, 109, 163, 223, 383, 597, 808, 1260, 1976, 3053, 4529, 6675, 10627, 15847, 23822, 37090, 55161, 84208, 128121, 194545, 298191, 444839, 681805, 1042629, 1565585, 2396146, 3626619, 5509999, 8396834, 12678459, 19375816, 29353796, 44468482, 67897697, 102728207, 156270923, 237448520, 359945119, 548404180, 830902728, 1262810287, 1920773112, 2910904745, 4429562772, 6723617334, 10208238298, 15523686504, 23546156396, 35800677601, 54373596862, 82527381401, 125485168005, 190450830578, 289353664282, 439635296562, 667354407235, 1014322133136, 1540093078996, 2339196397813, 3554443567139, 5396279119470, 8199376885602, 12453343570608, 18912305922966, 28735448607417],
-[1, 1, 2, 4, 8, 8, 11, 22, 32, 54, 68, 103, 183, 250, 401, 600, 871, 1431, 2033, 3193, 4917, 7052, 11371, 16815, 25469, 39648, 57976, 90871, 136703, 205157, 319620, 473117, 727905, 1110359, 1661899, 2567855, 3849988, 5866379, 8978479, 13464170, 20675981, 31260715, 47355277, 72483044, 109181845, 166707433, 253134047, 382914632, 585166040, 884539345, 1345613788, 2047645435, 3098356892, 4724206377, 7160643536, 10872985370, 16552155417, 25070272242, 38158143567, 57933790448, 87899668107, 133766380622, 202834276931, 308334249199, 468500329699, 710790290058, 1081062428072, 1640664018003, 2492210120859, 3787836798694, 5748162028732, 8737608441604, 13268147991514, 20148203460634, 30621612661601],
-[1, 1, 2, 4, 8, 8, 11, 22, 32, 52, 72, 106, 168, 242, 413, 602, 832, 1369, 2065, 3165, 4762, 6994, 11170, 16509, 25071, 39034, 57254, 88672, 134638, 203252, 312940, 465395, 716437, 1092207, 1637097, 2519878, 3794783, 5771904, 8814021, 13273744, 20328089, 30742356, 46604985, 71251415, 107529561, 163892525, 248939400, 377032515, 575197697, 870467992, 1323890750, 2013655038, 3049406611, 4645433144, 7045685002, 10698321736, 16276414188, 24671676894, 37533047662, 56988774248, 86488411300, 131561415506, 199575001348, 303311548004, 460837978219, 699372964501, 1063331414844, 1614094135973, 2451830128654, 3725902804244, 5655408093234, 8595002059570, 13052617650290, 19822054252432, 30121450842288],
-[1, 1, 2, 4, 7, 7, 11, 22, 31, 48, 69, 103, 161, 239, 393, 578, 812, 1322, 2011, 3034, 4580, 6798, 10738, 16018, 24212, 37525, 55534, 85483, 130183, 196389, 301170, 450896, 691214, 1054217, 1583522, 2428413, 3669747, 5573490, 8505207, 12835708, 19605147, 29704486, 45028011, 68747690, 103934296, 158185825, 240410770, 364289594, 555105869, 841069902, 1278299521, 1944190437, 2946226464, 4484326329, 6805591722, 10332097590, 15714538160, 23833683818, 36238383740, 55037373871, 83534994701, 127024485591, 192770563390, 292891568245, 445020687753, 675489979403, 1026733290935, 1558911226168, 2367783267114, 3597921540463, 5462124639438, 8299712231826, 12605551395981, 19143219233647, 29087020205323],
-[1, 1, 2, 4, 8, 8, 11, 22, 32, 54, 70, 103, 183, 262, 419, 586, 854, 1468, 2131, 3216, 4888, 7217, 11617, 17059, 25793, 40124, 58820, 92114, 139174, 208558, 322818, 480178, 740365, 1126352, 1685448, 2602817, 3910494, 5953715, 9102530, 13675794, 20970437, 31705673, 48074246, 73537869, 110811935, 169092081, 256830213, 388727714, 593534295, 897592763, 1365513047, 2077309718, 3144423073, 4792957707, 7266163233, 11033309991, 16792025848, 25442648670, 38716940493, 58780298247, 89198234571, 135718181667, 205824986739, 312858253566, 475352922955, 721283109638, 1096862549472, 1664773930182, 2528876426307, 3843251145519, 5832794158307, 8865669337036, 13462884780433, 20444566374734, 31069966778992],
-[2, 2, 3, 6, 8, 11, 21, 30, 36, 59, 101, 143, 191, 310, 528, 746, 1085, 1726, 2605, 3923, 5949, 9174, 13870, 20611, 32121, 48943, 72891, 111450, 169855, 258582, 390962, 592143, 907581, 1369916, 2078092, 3174424, 4802707, 7300624, 11083984, 16844046, 25632425, 38791943, 59046104, 89759113, 136044140, 206998601, 314150044, 477263083, 725216810, 1100354051, 1673643570, 2540313682, 3857064318, 5864298742, 8900103793, 13524141246, 20541527879, 31190876876, 47404416517, 71963769081, 109331114277, 166100455773, 252183875669, 383197918930, 581941483804, 883897643722, 1342846189520, 2039105162974, 3097961713154, 4705293041419, 7146140004538, 10856681741927, 16487604300574, 25045347066233, 38043349951382],
-[2, 2, 4, 8, 8, 14, 28, 32, 40, 78, 124, 162, 220, 400, 656, 836, 1334, 2118, 3092, 4754, 7144, 11204, 16536, 24686, 39556, 58330, 87452, 135448, 204262, 312902, 469022, 715264, 1099898, 1639934, 2516250, 3832598, 5772816, 8828432, 13339536, 20348556, 30916294, 46667592, 71424616, 108093610, 163994670, 250002532, 378297556, 576228610, 874205010, 1326503092, 2020784048, 3059843316, 4654285320, 7074312164, 10725895422, 16322387070, 24760668688, 37621085064, 57187747970, 86739790192, 131922438362, 200278473184, 304089278154, 462306559706, 701576407902, 1066218879542, 1619473499498, 2458736826450, 3737186599858, 5673399482980, 8618880526096, 13094482957758, 19880725643390, 30209968489076, 45876731413688],
-[2, 2, 4, 8, 8, 13, 26, 32, 39, 69, 119, 173, 221, 381, 623, 832, 1328, 2036, 3047, 4647, 6953, 11065, 16189, 24162, 38490, 57007, 86372, 131999, 199459, 307129, 458241, 701343, 1075187, 1604919, 2466190, 3741209, 5660681, 8644638, 13035061, 19937856, 30236092, 45699490, 69918051, 105690065, 160676229, 244551516, 370188997, 564328224, 855043476, 1298778321, 1977582388, 2994176379, 4557324244, 6920525146, 10499731746, 15977123048, 24226610580, 36831661703, 55960547914, 84895484399, 129133384675, 195974155346, 297676069774, 452438656358, 686598712357, 1043687845524, 1584794667540, 2406601494443, 3657698760577, 5552248092938, 8436385005458, 12814845406988, 19458085026478, 29568395562947, 44897365784486],
-[2, 2, 4, 8, 8, 12, 24, 32, 46, 74, 114, 160, 224, 402, 622, 819, 1309, 2046, 3102, 4658, 6919, 10962, 16182, 24459, 38492, 56734, 86354, 132103, 200291, 306944, 457742, 702227, 1074099, 1608557, 2468907, 3737681, 5665651, 8644814, 13051799, 19952583, 30219748, 45751588, 69938154, 105736942, 160825246, 244543876, 370464608, 564574463, 855361496, 1299892989, 1977828168, 2995924081, 4560135677, 6922605329, 10507098994, 15982597250, 24238609571, 36854122170, 55979079747, 84948671973, 129191242301, 196058402189, 297855293594, 452617905139, 686972421293, 1044207903188, 1585476043610, 2407977998687, 3659298217551, 5555030353611, 8440803936931, 12820473506728, 19468544722585, 29582368331376, 44918980019967],
-[2, 2, 4, 8, 8, 11, 22, 32, 47, 69, 109, 163, 225, 395, 597, 808, 1304, 2010, 3087, 4559, 6776, 10882, 15989, 24163, 37735, 55834, 85720, 129959, 197383, 302913, 450567, 693824, 1058140, 1586522, 2436244, 3678122, 5595841, 8525111, 12858762, 19689662, 29774784, 45141596, 68968292, 104196755, 158707047, 241005936, 365318620, 556929713, 843027916, 1282238151, 1949915832, 2953967903, 4498051369, 6823609638, 10362766091, 15760260783, 23896483433, 36349189027, 55189220187, 83769481582, 127400272950, 193296383854, 293751980862, 446274637515, 677382320511, 1029757724243, 1563211215886, 2374605764088, 3608255444287, 5477372560360, 8323835281276, 12640959066457, 19197825935904, 29170693470704, 44290886796369],
-[2, 2, 3, 6, 8, 15, 22, 27, 42, 71, 107, 148, 219, 363, 551, 801, 1264, 1867, 2806, 4353, 6625, 10131, 14943, 22970, 35625, 52987, 80816, 122885, 186339, 284572, 429056, 655823, 994788, 1501772, 2300754, 3481884, 5282554, 8040835, 12179767, 18571112, 28136606, 42709612, 65066104, 98515287, 149895032, 227729473, 345419701, 525562825, 797047624, 1211655726, 1841294752, 2792731945, 4247915772, 6448060827, 9792510483, 14884810869, 22588572642, 34334229036, 52140030381, 79170572697, 120334816043, 182666617993, 277529807082, 421604110431, 640141868233, 972744736333, 1477091156984, 2243690546361, 3408686378925, 5175896139303, 7863883830187, 11943520598349, 18139739707297, 27558327815146, 41850671629810],
-[2, 2, 3, 6, 8, 15, 22, 27, 42, 71, 116, 149, 213, 383, 578, 819, 1267, 1930, 2977, 4410, 6765, 10519, 15320, 23714, 36593, 54634, 83374, 125700, 193002, 293154, 439668, 677252, 1023066, 1547872, 2368484, 3578198, 5454263, 8264204, 12536147, 19152757, 28922317, 44009777, 66973023, 101402082, 154480379, 234183111, 355856211, 541248352, 820017137, 1248417586, 1894931369, 2875535446, 4374788095, 6635512974, 10087154088, 15321837071, 23253319714, 35362697949, 53664146227, 81528038663, 123895009629, 188038904699, 285811019699, 433987556784, 659122453150, 1001609769470, 1520527039807, 2310430841297, 3509257317932, 5328909862349, 8097277061742, 12295403507611, 18677971263299, 28373132236052, 43086569167478],
-[2, 2, 3, 6, 8, 15, 22, 27, 42, 71, 114, 153, 216, 368, 570, 831, 1269, 1891, 2915, 4442, 6737, 10364, 15262, 23513, 36287, 54236, 82760, 124978, 190803, 291089, 437763, 670572, 1015344, 1536404, 2350332, 3553396, 5406286, 8208999, 12441672, 18988299, 28731891, 43661885, 66454664, 100651790, 153248750, 232530827, 353041303, 537053705, 814135020, 1238449243, 1880860016, 2853812408, 4340797698, 6586562693, 10008380855, 15206878537, 23078656080, 35086956720, 53265550879, 80902942758, 122949993429, 186627647892, 283606054583, 430728281201, 654099751955, 993947417990, 1509109714250, 2292699828069, 3482687435902, 5288529870144, 8035343067292, 12202649572113, 18535364881265, 28157601894828, 42760419959276],
-[2, 2, 3, 6, 8, 14, 21, 27, 42, 70, 110, 150, 213, 361, 567, 811, 1245, 1871, 2868, 4388, 6606, 10182, 15066, 23081, 35796, 53377, 81251, 123258, 187614, 286634, 430900, 658802, 1000845, 1511181, 2312342, 3499821, 5314821, 8083963, 12243258, 18679485, 28293855, 42938943, 65416794, 99074816, 150745025, 228935562, 347334603, 528525075, 801392099, 1218357415, 1851461926, 2808221179, 4271333097, 6483382546, 9847274040, 14966785257, 22712431934, 34525080692, 52427557803, 79608278836, 120998593052, 183674231293, 279069124668, 423923843243, 643679772196, 978130127524, 1485226729152, 2256101704160, 3427504526097, 5204483008604, 7907361803511, 12009366118317, 18240075053521, 27710535640519, 42081584940491],
-[2, 2, 3, 6, 8, 15, 22, 27, 42, 71, 116, 151, 213, 383, 590, 837, 1253, 1913, 3014, 4508, 6788, 10490, 15485, 23960, 36837, 54958, 83850, 126544, 194245, 295625, 443069, 680450, 1030127, 1560332, 2384477, 3601747, 5489225, 8324710, 12623483, 19276808, 29133941, 44304233, 67417981, 102121051, 155535204, 235813201, 358240859, 544944518, 825830219, 1256785841, 1907984787, 2895434705, 4404452378, 6681579155, 10155905418, 15427356768, 23413644335, 35602568380, 54036522655, 82086835589, 124741517428, 189337471163, 287762820744, 436978266592, 663646457517, 1008462362726, 1531019859387, 2326230962697, 3533367230111, 5365576167797, 8152691408567, 12380035637186, 18806032158731, 28567869024971, 43382932081578],
-[2, 2, 3, 6, 8, 10, 19, 30, 35, 50, 96, 154, 192, 291, 495, 742, 1079, 1644, 2560, 3816, 5758, 9035, 13523, 20087, 31055, 47620, 71811, 108001, 165052, 252809, 380181, 578222, 882870, 1334901, 2028032, 3083035, 4690572, 7116830, 10779509, 16433346, 24952223, 37823841, 57539539, 87355568, 132725699, 201547585, 306041485, 465362697, 706055276, 1072629280, 1630441910, 2474646745, 3760103242, 5710511724, 8673940117, 13178877224, 20007469771, 30401453515, 46177216461, 70119463288, 106542060590, 161796137935, 245770667289, 373330015582, 566963788259, 861366609704, 1308167357562, 1986969830967, 3018473873873, 4584141651377, 6963644483900, 10577044191157, 16064963683662, 24403774140104, 37063984322180],
-[2, 2, 4, 8, 8, 13, 26, 32, 39, 69, 119, 173, 221, 381, 623, 832, 1328, 2036, 3047, 4647, 6953, 11065, 16189, 24162, 38490, 57007, 86372, 131999, 199459, 307129, 458241, 701343, 1075187, 1604919, 2466190, 3741209, 5660681, 8644638, 13035061, 19937856, 30236092, 45699490, 69918051, 105690065, 160676229, 244551516, 370188997, 564328224, 855043476, 1298778321, 1977582388, 2994176379, 4557324244, 6920525146, 10499731746, 15977123048, 24226610580, 36831661703, 55960547914, 84895484399, 129133384675, 195974155346, 297676069774, 452438656358, 686598712357, 1043687845524, 1584794667540, 2406601494443, 3657698760577, 5552248092938, 8436385005458, 12814845406988, 19458085026478, 29568395562947, 44897365784486],
-[2, 2, 4, 8, 8, 12, 24, 32, 38, 60, 114, 184, 222, 362, 590, 828, 1322, 1954, 3002, 4540, 6762, 10926, 15842, 23638, 37424, 55684, 85292, 128550, 194656, 301356, 447460, 687422, 1050476, 1569904, 2416130, 3649820, 5548546, 8460844, 12730586, 19527156, 29555890, 44731388, 68411486, 103286520, 157357788, 239100500, 362080438, 552427838, 835881942, 1271053550, 1934380728, 2928509442, 4460363168, 6766738128, 10273568070, 15631859026, 23692552472, 36042238342, 54733347858, 83051178606, 126344330988, 191669837508, 291262861394, 442570753010, 671621016812, 1021156811506, 1550115835582, 2354466162436, 3578210921296, 5431096702896, 8253889484820, 12535207856218, 19035444409566, 28926822636818, 43918000155284],
-[2, 2, 4, 8, 8, 11, 22, 32, 45, 65, 109, 171, 225, 383, 589, 815, 1303, 1964, 3057, 4551, 6728, 10823, 15835, 23935, 37426, 55411, 85274, 128654, 195488, 301171, 446961, 688306, 1049388, 1573542, 2418847, 3646292, 5553516, 8461020, 12747324, 19541883, 29539546, 44783486, 68431589, 103333397, 157506805, 239092860, 362356049, 552674077, 836199962, 1272168218, 1934626508, 2930257144, 4463174601, 6768818311, 10280935318, 15637333228, 23704551463, 36064698809, 54751879691, 83104366180, 126402188614, 191754084351, 291442085214, 442750001791, 671994725748, 1021676869170, 1550797211652, 2355842666680, 3579810378270, 5433878963569, 8258308416293, 12540835955958, 19045904105673, 28940795405247, 43939614390765],
-[2, 2, 4, 8, 8, 10, 20, 32, 46, 60, 104, 174, 226, 376, 564, 804, 1298, 1928, 3042, 4452, 6585, 10743, 15642, 23639, 36669, 54511, 84640, 126510, 192580, 297140, 439786, 679903, 1033429, 1551507, 2386184, 3586733, 5483706, 8341317, 12554287, 19278962, 29094582, 44173494, 67461727, 101793210, 155388606, 235554920, 357210061, 545029327, 823866382, 1254513380, 1906714172, 2888300966, 4401090293, 6669822620, 10136602415, 15414996761, 23362425325, 35559765666, 53962020131, 81925175789, 124611219263, 188992066016, 287338772482, 436406734167, 662404624966, 1007226690225, 1528532383928, 2322470432081, 3528767605006, 5356221170318, 8141339760638, 12361321515687, 18775185318992, 28529120544575, 43311521167167],
-[2, 2, 3, 6, 8, 14, 20, 27, 41, 62, 102, 159, 220, 344, 518, 797, 1258, 1785, 2761, 4246, 6434, 9992, 14596, 22446, 34559, 51664, 79736, 119436, 181536, 278799, 418275, 641902, 970077, 1466757, 2250694, 3390495, 5170419, 7857041, 11875292, 18160412, 27456404, 41741510, 63559539, 96111742, 146576591, 222278457, 337311142, 513662439, 777886090, 1183930955, 1798093092, 2727065008, 4150954696, 6294273809, 9566346807, 14539546847, 22054514534, 33544805675, 50912830325, 77326266904, 117545762356, 178362300155, 271116598702, 411736207083, 625164172688, 950213702315, 1442412325026, 2191555214354, 3329198539644, 5054744749261, 7681388309549, 11663883047579, 17717099090385, 26916754889017, 40871306000608],
-[2, 2, 3, 6, 8, 14, 20, 27, 41, 62, 111, 160, 214, 364, 545, 815, 1261, 1848, 2932, 4303, 6574, 10380, 14973, 23190, 35527, 53311, 82294, 122251, 188199, 287381, 428887, 663331, 998355, 1512857, 2318424, 3486809, 5342128, 8080410, 12231672, 18742057, 28242115, 43041675, 65466458, 98998537, 151161938, 228732095, 347747652, 529347966, 800855603, 1220692815, 1851729709, 2809868509, 4277827019, 6481725956, 9860990412, 14976573049, 22719261606, 34573274588, 52436946171, 79683732870, 121105955942, 183734586861, 279397811319, 424119653436, 644144757605, 979078735452, 1485848207849, 2258295509290, 3429769478651, 5207758472307, 7914781541104, 12015765956841, 18255330646387, 27731559309923, 42107203538276],
-[2, 2, 3, 6, 8, 14, 20, 27, 41, 62, 109, 164, 217, 349, 537, 827, 1263, 1809, 2870, 4335, 6546, 10225, 14915, 22989, 35221, 52913, 81680, 121529, 186000, 285316, 426982, 656651, 990633, 1501389, 2300272, 3462007, 5294151, 8025205, 12137197, 18577599, 28051689, 42693783, 64948099, 98248245, 149930309, 227079811, 344932744, 525153319, 794973486, 1210724472, 1837658356, 2788145471, 4243836622, 6432775675, 9782217179, 14861614515, 22544597972, 34297533359, 52038350823, 79058636965, 120160939742, 182323330054, 277192846203, 420860377853, 639122056410, 971416383972, 1474430882292, 2240564496062, 3403199596621, 5167378480102, 7852847546654, 11923012021343, 18112724264353, 27516028968699, 41781054330074],
-[2, 2, 3, 6, 8, 13, 19, 27, 41, 61, 105, 161, 214, 342, 534, 807, 1239, 1789, 2823, 4281, 6415, 10043, 14719, 22557, 34730, 52054, 80171, 119809, 182811, 280861, 420119, 644881, 976134, 1476166, 2262282, 3408432, 5202686, 7900169, 11938783, 18268785, 27613653, 41970841, 63910229, 96671271, 147426584, 223484546, 339226044, 516624689, 782230565, 1190632644, 1808260266, 2742554242, 4174372021, 6329595528, 9621110364, 14621521235, 22178373826, 33735657331, 51200357747, 77763973043, 118209539365, 179369913455, 272655916288, 414055939895, 628702076651, 955599093506, 1450547897194, 2203966372153, 3348016686816, 5083331618562, 7724866282873, 11729728567547, 17817434436609, 27068962714390, 41102219311289],
-[2, 2, 3, 6, 8, 14, 20, 27, 41, 62, 111, 162, 214, 364, 557, 833, 1247, 1831, 2969, 4401, 6597, 10351, 15138, 23436, 35771, 53635, 82770, 123095, 189442, 289852, 432288, 666529, 1005416, 1525317, 2334417, 3510358, 5377090, 8140916, 12319008, 18866108, 28453739, 43336131, 65911416, 99717506, 152216763, 230362185, 350132300, 533044132, 806668685, 1229061070, 1864783127, 2829767768, 4307491302, 6527792137, 9929741742, 15082092746, 22879586227, 34813145019, 52809322599, 80242529796, 121952463741, 185033153325, 281349612364, 427110363244, 648668761972, 985931328708, 1496341027429, 2274095630690, 3453879390830, 5244424777755, 7970195887929, 12100398086416, 18383391541819, 27926296098842, 42403566452376],
-[2, 2, 3, 6, 8, 9, 17, 30, 42, 55, 91, 141, 195, 312, 494, 729, 1060, 1654, 2615, 3827, 5724, 8932, 13516, 20384, 31057, 47347, 71793, 108105, 165884, 252624, 379682, 579106, 881782, 1338539, 2030749, 3079507, 4695542, 7117006, 10796247, 16448073, 24935879, 37875939, 57559642, 87402445, 132874716, 201539945, 306317096, 465608936, 706373296, 1073743948, 1630687690, 2476394447, 3762914675, 5712591907, 8681307365, 13184351426, 20019468762, 30423913982, 46195748294, 70172650862, 106599918216, 161880384778, 245949891109, 373509264363, 567337497195, 861886667368, 1308848733632, 1988346335211, 3020073330847, 4586923912050, 6968063415373, 10582672290897, 16075423379769, 24417746908533, 37085598557661],
-[2, 2, 4, 8, 8, 12, 24, 32, 46, 74, 114, 160, 224, 402, 622, 819, 1309, 2046, 3102, 4658, 6919, 10962, 16182, 24459, 38492, 56734, 86354, 132103, 200291, 306944, 457742, 702227, 1074099, 1608557, 2468907, 3737681, 5665651, 8644814, 13051799, 19952583, 30219748, 45751588, 69938154, 105736942, 160825246, 244543876, 370464608, 564574463, 855361496, 1299892989, 1977828168, 2995924081, 4560135677, 6922605329, 10507098994, 15982597250, 24238609571, 36854122170, 55979079747, 84948671973, 129191242301, 196058402189, 297855293594, 452617905139, 686972421293, 1044207903188, 1585476043610, 2407977998687, 3659298217551, 5555030353611, 8440803936931, 12820473506728, 19468544722585, 29582368331376, 44918980019967],
-[2, 2, 4, 8, 8, 11, 22, 32, 45, 65, 109, 171, 225, 383, 589, 815, 1303, 1964, 3057, 4551, 6728, 10823, 15835, 23935, 37426, 55411, 85274, 128654, 195488, 301171, 446961, 688306, 1049388, 1573542, 2418847, 3646292, 5553516, 8461020, 12747324, 19541883, 29539546, 44783486, 68431589, 103333397, 157506805, 239092860, 362356049, 552674077, 836199962, 1272168218, 1934626508, 2930257144, 4463174601, 6768818311, 10280935318, 15637333228, 23704551463, 36064698809, 54751879691, 83104366180, 126402188614, 191754084351, 291442085214, 442750001791, 671994725748, 1021676869170, 1550797211652, 2355842666680, 3579810378270, 5433878963569, 8258308416293, 12540835955958, 19045904105673, 28940795405247, 43939614390765],
-[2, 2, 4, 8, 8, 10, 20, 32, 52, 70, 104, 158, 228, 404, 588, 802, 1284, 1974, 3112, 4562, 6694, 10720, 15828, 24232, 37428, 55138, 85256, 128758, 196320, 300986, 446462, 689190, 1048300, 1577180, 2421564, 3642764, 5558486, 8461196, 12764062, 19556610, 29523202, 44835584, 68451692, 103380274, 157655822, 239085220, 362631660, 552920316, 836517982, 1273282886, 1934872288, 2932004846, 4465986034, 6770898494, 10288302566, 15642807430, 23716550454, 36087159276, 54770411524, 83157553754, 126460046240, 191838331194, 291621309034, 442929250572, 672368434684, 1022196926834, 1551478587722, 2357219170924, 3581409835244, 5436661224242, 8262727347766, 12546464055698, 19056363801780, 28954768173676, 43961228626246],
-[2, 2, 4, 8, 8, 9, 18, 32, 53, 65, 99, 161, 229, 397, 563, 791, 1279, 1938, 3097, 4463, 6551, 10640, 15635, 23936, 36671, 54238, 84622, 126614, 193412, 296955, 439287, 680787, 1032341, 1555145, 2388901, 3583205, 5488676, 8341493, 12571025, 19293689, 29078238, 44225592, 67481830, 101840087, 155537623, 235547280, 357485672, 545275566, 824184402, 1255628048, 1906959952, 2890048668, 4403901726, 6671902803, 10143969663, 15420470963, 23374424316, 35582226133, 53980551964, 81978363363, 124669076889, 189076312859, 287517996302, 436585982948, 662778333902, 1007746747889, 1529213759998, 2323846936325, 3530367061980, 5359003430991, 8145758692111, 12366949615427, 18785645015099, 28543093313004, 43333135402648],
-[2, 2, 3, 6, 8, 13, 18, 27, 48, 67, 97, 146, 223, 365, 517, 784, 1239, 1795, 2816, 4257, 6400, 9889, 14589, 22743, 34561, 51391, 79718, 119540, 182368, 278614, 417776, 642786, 968989, 1470395, 2253411, 3386967, 5175389, 7857217, 11892030, 18175139, 27440060, 41793608, 63579642, 96158619, 146725608, 222270817, 337586753, 513908678, 778204110, 1185045623, 1798338872, 2728812710, 4153766129, 6296353992, 9573714055, 14545021049, 22066513525, 33567266142, 50931362158, 77379454478, 117603619982, 178446546998, 271295822522, 411915455864, 625537881624, 950733759979, 1443093701096, 2192931718598, 3330797996618, 5057527009934, 7685807241022, 11669511147319, 17727558786492, 26930727657446, 40892920236089],
-[2, 2, 3, 6, 8, 13, 18, 27, 48, 67, 106, 147, 217, 385, 544, 802, 1242, 1858, 2987, 4314, 6540, 10277, 14966, 23487, 35529, 53038, 82276, 122355, 189031, 287196, 428388, 664215, 997267, 1516495, 2321141, 3483281, 5347098, 8080586, 12248410, 18756784, 28225771, 43093773, 65486561, 99045414, 151310955, 228724455, 348023263, 529594205, 801173623, 1221807483, 1851975489, 2811616211, 4280638452, 6483806139, 9868357660, 14982047251, 22731260597, 34595735055, 52455478004, 79736920444, 121163813568, 183818833704, 279577035139, 424298902217, 644518466541, 979598793116, 1486529583919, 2259672013534, 3431368935625, 5210540732980, 7919200472577, 12021394056581, 18265790342494, 27745532078352, 42128817773757],
-[2, 2, 3, 6, 8, 13, 18, 27, 48, 67, 104, 151, 220, 370, 536, 814, 1244, 1819, 2925, 4346, 6512, 10122, 14908, 23286, 35223, 52640, 81662, 121633, 186832, 285131, 426483, 657535, 989545, 1505027, 2302989, 3458479, 5299121, 8025381, 12153935, 18592326, 28035345, 42745881, 64968202, 98295122, 150079326, 227072171, 345208355, 525399558, 795291506, 1211839140, 1837904136, 2789893173, 4246648055, 6434855858, 9789584427, 14867088717, 22556596963, 34319993826, 52056882656, 79111824539, 120218797368, 182407576897, 277372070023, 421039626634, 639495765346, 971936441636, 1475112258362, 2241941000306, 3404799053595, 5170160740775, 7857266478127, 11928640121083, 18123183960460, 27530001737128, 41802668565555],
-[2, 2, 3, 6, 8, 12, 17, 27, 48, 66, 100, 148, 217, 363, 533, 794, 1220, 1799, 2878, 4292, 6381, 9940, 14712, 22854, 34732, 51781, 80153, 119913, 183643, 280676, 419620, 645765, 975046, 1479804, 2264999, 3404904, 5207656, 7900345, 11955521, 18283512, 27597309, 42022939, 63930332, 96718148, 147575601, 223476906, 339501655, 516870928, 782548585, 1191747312, 1808506046, 2744301944, 4177183454, 6331675711, 9628477612, 14626995437, 22190372817, 33758117798, 51218889580, 77817160617, 118267396991, 179454160298, 272835140108, 414235188676, 629075785587, 956119151170, 1451229273264, 2205342876397, 3349616143790, 5086113879235, 7729285214346, 11735356667287, 17827894132716, 27082935482819, 41123833546770],
-[2, 2, 3, 6, 8, 13, 18, 27, 48, 67, 106, 149, 217, 385, 556, 820, 1228, 1841, 3024, 4412, 6563, 10248, 15131, 23733, 35773, 53362, 82752, 123199, 190274, 289667, 431789, 667413, 1004328, 1528955, 2337134, 3506830, 5382060, 8141092, 12335746, 18880835, 28437395, 43388229, 65931519, 99764383, 152365780, 230354545, 350407911, 533290371, 806986705, 1230175738, 1865028907, 2831515470, 4310302735, 6529872320, 9937108990, 15087566948, 22891585218, 34835605486, 52827854432, 80295717370, 122010321367, 185117400168, 281528836184, 427289612025, 649042470908, 986451386372, 1497022403499, 2275472134934, 3455478847804, 5247207038428, 7974614819402, 12106026186156, 18393851237926, 27940268867271, 42425180687857],
-[2, 2, 3, 6, 8, 8, 15, 30, 43, 50, 86, 144, 196, 305, 469, 718, 1055, 1618, 2600, 3728, 5581, 8852, 13323, 20088, 30300, 46447, 71159, 105961, 162976, 248593, 372507, 570703, 865823, 1316504, 1998086, 3019948, 4625732, 6997303, 10603210, 16185152, 24490915, 37265947, 56589780, 85862258, 130756517, 198002005, 301171108, 457964186, 694039716, 1056089110, 1602775354, 2434438269, 3700830367, 5613596216, 8536974462, 12962014959, 19677342624, 29918980839, 45405888734, 68993460471, 104808948865, 159118366443, 241846578377, 367165996739, 557747396413, 847436488423, 1286583905908, 1954974100612, 2969030557583, 4509266118799, 6851094759718, 10403157850626, 15804704593088, 24006072047861, 36457505334063],
-[2, 2, 4, 8, 8, 11, 22, 32, 47, 69, 109, 163, 225, 395, 597, 808, 1304, 2010, 3087, 4559, 6776, 10882, 15989, 24163, 37735, 55834, 85720, 129959, 197383, 302913, 450567, 693824, 1058140, 1586522, 2436244, 3678122, 5595841, 8525111, 12858762, 19689662, 29774784, 45141596, 68968292, 104196755, 158707047, 241005936, 365318620, 556929713, 843027916, 1282238151, 1949915832, 2953967903, 4498051369, 6823609638, 10362766091, 15760260783, 23896483433, 36349189027, 55189220187, 83769481582, 127400272950, 193296383854, 293751980862, 446274637515, 677382320511, 1029757724243, 1563211215886, 2374605764088, 3608255444287, 5477372560360, 8323835281276, 12640959066457, 19197825935904, 29170693470704, 44290886796369],
-[2, 2, 4, 8, 8, 10, 20, 32, 46, 60, 104, 174, 226, 376, 564, 804, 1298, 1928, 3042, 4452, 6585, 10743, 15642, 23639, 36669, 54511, 84640, 126510, 192580, 297140, 439786, 679903, 1033429, 1551507, 2386184, 3586733, 5483706, 8341317, 12554287, 19278962, 29094582, 44173494, 67461727, 101793210, 155388606, 235554920, 357210061, 545029327, 823866382, 1254513380, 1906714172, 2888300966, 4401090293, 6669822620, 10136602415, 15414996761, 23362425325, 35559765666, 53962020131, 81925175789, 124611219263, 188992066016, 287338772482, 436406734167, 662404624966, 1007226690225, 1528532383928, 2322470432081, 3528767605006, 5356221170318, 8141339760638, 12361321515687, 18775185318992, 28529120544575, 43311521167167],
-[2, 2, 4, 8, 8, 9, 18, 32, 53, 65, 99, 161, 229, 397, 563, 791, 1279, 1938, 3097, 4463, 6551, 10640, 15635, 23936, 36671, 54238, 84622, 126614, 193412, 296955, 439287, 680787, 1032341, 1555145, 2388901, 3583205, 5488676, 8341493, 12571025, 19293689, 29078238, 44225592, 67481830, 101840087, 155537623, 235547280, 357485672, 545275566, 824184402, 1255628048, 1906959952, 2890048668, 4403901726, 6671902803, 10143969663, 15420470963, 23374424316, 35582226133, 53980551964, 81978363363, 124669076889, 189076312859, 287517996302, 436585982948, 662778333902, 1007746747889, 1529213759998, 2323846936325, 3530367061980, 5359003430991, 8145758692111, 12366949615427, 18785645015099, 28543093313004, 43333135402648],
-[2, 2, 4, 8, 8, 8, 16, 32, 54, 60, 94, 164, 230, 390, 538, 780, 1274, 1902, 3082, 4364, 6408, 10560, 15442, 23640, 35914, 53338, 83988, 124470, 190504, 292924, 432112, 672384, 1016382, 1533110, 2356238, 3523646, 5418866, 8221790, 12377988, 19030768, 28633274, 43615600, 66511968, 100299900, 153419424, 232009340, 352339684, 537630816, 811850822, 1237973210, 1879047616, 2848092490, 4341817418, 6572907112, 9999636760, 15198134496, 23032298178, 35077292990, 53190692404, 80799172972, 122878107538, 186314294524, 283414683570, 430242715324, 653188233120, 993296568944, 1506948932274, 2290474701726, 3479324288716, 5281345637740, 8028790036456, 12187435175156, 18514926228418, 28131418452332, 42705042179050],
-[2, 2, 3, 6, 8, 12, 16, 27, 49, 62, 92, 149, 224, 358, 492, 773, 1234, 1759, 2801, 4158, 6257, 9809, 14396, 22447, 33804, 50491, 79084, 117396, 179460, 274583, 410601, 634383, 953030, 1448360, 2220748, 3327408, 5105579, 7737514, 11698993, 17912218, 26995096, 41183616, 62609780, 94618432, 144607409, 218732877, 332440765, 506263928, 765870530, 1167390785, 1770426536, 2686856532, 4091681821, 6197358301, 9429381152, 14322684582, 21724387387, 33062332999, 50141502598, 76200264087, 115812650631, 175684528663, 267192509790, 405572188240, 615947780842, 936283581034, 1420828873372, 2159559483999, 3279755223354, 4979869216683, 7568838585367, 11489996707048, 17456839999811, 26519052796774, 40264827012491],
-[2, 2, 3, 6, 8, 12, 16, 27, 49, 62, 101, 150, 218, 378, 519, 791, 1237, 1822, 2972, 4215, 6397, 10197, 14773, 23191, 34772, 52138, 81642, 120211, 186123, 283165, 421213, 655812, 981308, 1494460, 2288478, 3423722, 5277288, 7960883, 12055373, 18493863, 27780807, 42483781, 64516699, 97505227, 149192756, 225186515, 342877275, 521949455, 788840043, 1204152645, 1824063153, 2769660033, 4218554144, 6384810448, 9724024757, 14759710784, 22389134459, 34090801912, 51665618444, 78557730053, 119372844217, 181056815369, 275473722407, 417955634593, 634928365759, 965148614171, 1464264756195, 2226299778935, 3380326162361, 5132882939729, 7802231816922, 11841879616310, 17995071555813, 27333857217680, 41500724550159],
-[2, 2, 3, 6, 8, 12, 16, 27, 49, 62, 99, 154, 221, 363, 511, 803, 1239, 1783, 2910, 4247, 6369, 10042, 14715, 22990, 34466, 51740, 81028, 119489, 183924, 281100, 419308, 649132, 973586, 1482992, 2270326, 3398920, 5229311, 7905678, 11960898, 18329405, 27590381, 42135889, 63998340, 96754935, 147961127, 223534231, 340062367, 517754808, 782957926, 1194184302, 1809991800, 2747936995, 4184563747, 6335860167, 9645251524, 14644752250, 22214470825, 33815060683, 51267023096, 77932634148, 118427828017, 179645558562, 273268757291, 414696359010, 629905664564, 957486262691, 1452847430638, 2208568765707, 3353756280331, 5092502947524, 7740297822472, 11749125680812, 17852465173779, 27118326876456, 41174575341957],
-[2, 2, 3, 6, 8, 11, 15, 27, 49, 61, 95, 151, 218, 356, 508, 783, 1215, 1763, 2863, 4193, 6238, 9860, 14519, 22558, 33975, 50881, 79519, 117769, 180735, 276645, 412445, 637362, 959087, 1457769, 2232336, 3345345, 5137846, 7780642, 11762484, 18020591, 27152345, 41412947, 62960470, 95177961, 145457402, 219938966, 334355667, 509226178, 770215005, 1174092474, 1780593710, 2702345766, 4115099146, 6232680020, 9484144709, 14404658970, 21848246679, 33253184655, 50429030020, 76637970226, 116476427640, 176692141963, 268731827376, 407891921052, 619485684805, 941668972225, 1428964445540, 2171970641798, 3298573370526, 5008456085984, 7612316558691, 11555842227016, 17557175346035, 26671260622147, 40495740323172],
-[2, 2, 3, 6, 8, 12, 16, 27, 49, 62, 101, 152, 218, 378, 531, 809, 1223, 1805, 3009, 4313, 6420, 10168, 14938, 23437, 35016, 52462, 82118, 121055, 187366, 285636, 424614, 659010, 988369, 1506920, 2304471, 3447271, 5312250, 8021389, 12142709, 18617914, 27992431, 42778237, 64961657, 98224196, 150247581, 226816605, 345261923, 525645621, 794653125, 1212520900, 1837116571, 2789559292, 4248218427, 6430876629, 9792776087, 14865230481, 22549459080, 34330672343, 52037994872, 79116526979, 120219352016, 182355381833, 277425523452, 420946344401, 639452370126, 972001207427, 1474757575775, 2242099900335, 3404436074540, 5169549245177, 7857646163747, 11926511745885, 18123132451245, 27528594006599, 41797087464259],
-[2, 2, 2, 4, 8, 12, 15, 25, 38, 52, 84, 129, 190, 273, 423, 711, 1015, 1475, 2319, 3522, 5430, 8101, 12277, 18895, 28190, 43600, 66255, 98887, 151932, 230252, 350996, 532702, 802471, 1231754, 1862596, 2823710, 4312445, 6513027, 9924215, 15066602, 22852737, 34833963, 52687592, 80180790, 121944502, 184725542, 281272189, 426597298, 648059424, 985506685, 1494154274, 2273202311, 3450694770, 5238047405, 7966718854, 12086565045, 18369431833, 27904020848, 42356698928, 64394551586, 97743491958, 148488600582, 225624404597, 342495469655, 520506944135, 790423500513, 1200463847006, 1824058882885, 2769461492221, 4207789697742, 6391143308629, 9705719382518, 14746618364481, 22393706392303, 34017290167504],
-[2, 2, 3, 6, 8, 15, 22, 27, 42, 71, 107, 148, 219, 363, 551, 801, 1264, 1867, 2806, 4353, 6625, 10131, 14943, 22970, 35625, 52987, 80816, 122885, 186339, 284572, 429056, 655823, 994788, 1501772, 2300754, 3481884, 5282554, 8040835, 12179767, 18571112, 28136606, 42709612, 65066104, 98515287, 149895032, 227729473, 345419701, 525562825, 797047624, 1211655726, 1841294752, 2792731945, 4247915772, 6448060827, 9792510483, 14884810869, 22588572642, 34334229036, 52140030381, 79170572697, 120334816043, 182666617993, 277529807082, 421604110431, 640141868233, 972744736333, 1477091156984, 2243690546361, 3408686378925, 5175896139303, 7863883830187, 11943520598349, 18139739707297, 27558327815146, 41850671629810],
-[2, 2, 3, 6, 8, 14, 20, 27, 41, 62, 102, 159, 220, 344, 518, 797, 1258, 1785, 2761, 4246, 6434, 9992, 14596, 22446, 34559, 51664, 79736, 119436, 181536, 278799, 418275, 641902, 970077, 1466757, 2250694, 3390495, 5170419, 7857041, 11875292, 18160412, 27456404, 41741510, 63559539, 96111742, 146576591, 222278457, 337311142, 513662439, 777886090, 1183930955, 1798093092, 2727065008, 4150954696, 6294273809, 9566346807, 14539546847, 22054514534, 33544805675, 50912830325, 77326266904, 117545762356, 178362300155, 271116598702, 411736207083, 625164172688, 950213702315, 1442412325026, 2191555214354, 3329198539644, 5054744749261, 7681388309549, 11663883047579, 17717099090385, 26916754889017, 40871306000608],
-[2, 2, 3, 6, 8, 13, 18, 27, 48, 67, 97, 146, 223, 365, 517, 784, 1239, 1795, 2816, 4257, 6400, 9889, 14589, 22743, 34561, 51391, 79718, 119540, 182368, 278614, 417776, 642786, 968989, 1470395, 2253411, 3386967, 5175389, 7857217, 11892030, 18175139, 27440060, 41793608, 63579642, 96158619, 146725608, 222270817, 337586753, 513908678, 778204110, 1185045623, 1798338872, 2728812710, 4153766129, 6296353992, 9573714055, 14545021049, 22066513525, 33567266142, 50931362158, 77379454478, 117603619982, 178446546998, 271295822522, 411915455864, 625537881624, 950733759979, 1443093701096, 2192931718598, 3330797996618, 5057527009934, 7685807241022, 11669511147319, 17727558786492, 26930727657446, 40892920236089],
-[2, 2, 3, 6, 8, 12, 16, 27, 49, 62, 92, 149, 224, 358, 492, 773, 1234, 1759, 2801, 4158, 6257, 9809, 14396, 22447, 33804, 50491, 79084, 117396, 179460, 274583, 410601, 634383, 953030, 1448360, 2220748, 3327408, 5105579, 7737514, 11698993, 17912218, 26995096, 41183616, 62609780, 94618432, 144607409, 218732877, 332440765, 506263928, 765870530, 1167390785, 1770426536, 2686856532, 4091681821, 6197358301, 9429381152, 14322684582, 21724387387, 33062332999, 50141502598, 76200264087, 115812650631, 175684528663, 267192509790, 405572188240, 615947780842, 936283581034, 1420828873372, 2159559483999, 3279755223354, 4979869216683, 7568838585367, 11489996707048, 17456839999811, 26519052796774, 40264827012491],
-[2, 2, 2, 4, 8, 16, 16, 22, 44, 64, 90, 134, 218, 326, 446, 766, 1194, 1616, 2520, 3952, 6106, 9058, 13350, 21254, 31694, 47644, 74180, 110322, 168416, 256242, 389090, 596382, 889678, 1363610, 2085258, 3131170, 4792292, 7253238, 11019998, 16793668, 25356918, 38751632, 58707592, 88936964, 135795394, 205456414, 312541846, 474897040, 719890238, 1096808360, 1661805456, 2525620574, 3841546224, 5821809490, 8859125544, 13447234668, 20416476596, 31047373008, 47092312792, 71601355202, 108747193724, 165054762802, 250970336010, 380901661156, 578707328564, 879270593124, 1334708814470, 2028644266272, 3080186157992, 4678392795626, 7108887134278, 10792558238940, 16398753771204, 24906687141216, 37824611845932],
-[2, 2, 2, 4, 8, 16, 16, 22, 44, 64, 99, 135, 212, 346, 473, 784, 1197, 1679, 2691, 4009, 6246, 9446, 13727, 21998, 32662, 49291, 76738, 113137, 175079, 264824, 399702, 617811, 917956, 1409710, 2152988, 3227484, 4964001, 7476607, 11376378, 17375313, 26142629, 40051797, 60614511, 91823759, 140380741, 211910052, 322978356, 490582567, 742859751, 1133570220, 1715442073, 2608424075, 3968418547, 6009261637, 9153769149, 13884260870, 21081223668, 32075841921, 48616428638, 73958821168, 112307387310, 170427049508, 259251548627, 393285107509, 597687913481, 908135626261, 1378144697293, 2095384561208, 3180757096999, 4831406518672, 7342280365833, 11144441148202, 16936985327206, 25721491562122, 39060509383600],
-[2, 2, 2, 4, 8, 16, 16, 22, 44, 64, 97, 139, 215, 331, 465, 796, 1199, 1640, 2629, 4041, 6218, 9291, 13669, 21797, 32356, 48893, 76124, 112415, 172880, 262759, 397797, 611131, 910234, 1398242, 2134836, 3202682, 4916024, 7421402, 11281903, 17210855, 25952203, 39703905, 60096152, 91073467, 139149112, 210257768, 320163448, 486387920, 736977634, 1123601877, 1701370720, 2586701037, 3934428150, 5960311356, 9074995916, 13769302336, 20906560034, 31800100692, 48217833290, 73333725263, 111362371110, 169015792701, 257046583511, 390025831926, 592665212286, 900473274781, 1366727371736, 2077653547980, 3154187214969, 4791026526467, 7280346371383, 11051687212704, 16794378945172, 25505961220898, 38734360175398],
-[2, 2, 2, 4, 8, 15, 15, 22, 44, 63, 93, 136, 212, 324, 462, 776, 1175, 1620, 2582, 3987, 6087, 9109, 13473, 21365, 31865, 48034, 74615, 110695, 169691, 258304, 390934, 599361, 895735, 1373019, 2096846, 3149107, 4824559, 7296366, 11083489, 16902041, 25514167, 38980963, 59058282, 89496493, 136645387, 206662503, 314456748, 477859290, 724234713, 1103510049, 1671972630, 2541109808, 3864963549, 5857131209, 8913889101, 13529209056, 20540335888, 31238224664, 47379840214, 72039061341, 109410970733, 166062376102, 252509653596, 383221393968, 582245232527, 884655984315, 1342844386638, 2041055424071, 3099004305164, 4706979664927, 7152365107602, 10858403758908, 16499089117428, 25058894966589, 38055525156613],
-[2, 2, 2, 4, 8, 16, 16, 22, 44, 64, 99, 137, 212, 346, 485, 802, 1183, 1662, 2728, 4107, 6269, 9417, 13892, 22244, 32906, 49615, 77214, 113981, 176322, 267295, 403103, 621009, 925017, 1422170, 2168981, 3251033, 4998963, 7537113, 11463714, 17499364, 26354253, 40346253, 61059469, 92542728, 141435566, 213540142, 325363004, 494278733, 748672833, 1141938475, 1728495491, 2628323334, 3998082830, 6055327818, 9222520479, 13989780567, 21241548289, 32315712352, 48988805066, 74517618094, 113153895109, 171725615972, 261203349672, 396275817317, 602211917848, 914988219517, 1388637516873, 2111184682608, 3204867009178, 4868072824120, 7397694712658, 11229073277777, 17065046222638, 25916228351041, 39356872297700],
-[2, 2, 2, 4, 8, 12, 15, 25, 38, 52, 93, 130, 184, 293, 450, 729, 1018, 1538, 2490, 3579, 5570, 8489, 12654, 19639, 29158, 45247, 68813, 101702, 158595, 238834, 361608, 554131, 830749, 1277854, 1930326, 2920024, 4484154, 6736396, 10280595, 15648247, 23638448, 36134128, 54594511, 83067585, 126529849, 191179180, 291708699, 442282825, 671028937, 1022268545, 1547790891, 2356005812, 3577567093, 5425499552, 8261362459, 12523591247, 19034178905, 28932489761, 43880814774, 66752017552, 101303685544, 153860887288, 233905617214, 354878916008, 539487529052, 819288533650, 1243899729829, 1890799177821, 2870032431228, 4360803420788, 6624536540184, 10057602291780, 15284849920483, 23208510813209, 35253187705172],
-[2, 2, 3, 6, 8, 15, 22, 27, 42, 71, 116, 149, 213, 383, 578, 819, 1267, 1930, 2977, 4410, 6765, 10519, 15320, 23714, 36593, 54634, 83374, 125700, 193002, 293154, 439668, 677252, 1023066, 1547872, 2368484, 3578198, 5454263, 8264204, 12536147, 19152757, 28922317, 44009777, 66973023, 101402082, 154480379, 234183111, 355856211, 541248352, 820017137, 1248417586, 1894931369, 2875535446, 4374788095, 6635512974, 10087154088, 15321837071, 23253319714, 35362697949, 53664146227, 81528038663, 123895009629, 188038904699, 285811019699, 433987556784, 659122453150, 1001609769470, 1520527039807, 2310430841297, 3509257317932, 5328909862349, 8097277061742, 12295403507611, 18677971263299, 28373132236052, 43086569167478],
-[2, 2, 3, 6, 8, 14, 20, 27, 41, 62, 111, 160, 214, 364, 545, 815, 1261, 1848, 2932, 4303, 6574, 10380, 14973, 23190, 35527, 53311, 82294, 122251, 188199, 287381, 428887, 663331, 998355, 1512857, 2318424, 3486809, 5342128, 8080410, 12231672, 18742057, 28242115, 43041675, 65466458, 98998537, 151161938, 228732095, 347747652, 529347966, 800855603, 1220692815, 1851729709, 2809868509, 4277827019, 6481725956, 9860990412, 14976573049, 22719261606, 34573274588, 52436946171, 79683732870, 121105955942, 183734586861, 279397811319, 424119653436, 644144757605, 979078735452, 1485848207849, 2258295509290, 3429769478651, 5207758472307, 7914781541104, 12015765956841, 18255330646387, 27731559309923, 42107203538276],
-[2, 2, 3, 6, 8, 13, 18, 27, 48, 67, 106, 147, 217, 385, 544, 802, 1242, 1858, 2987, 4314, 6540, 10277, 14966, 23487, 35529, 53038, 82276, 122355, 189031, 287196, 428388, 664215, 997267, 1516495, 2321141, 3483281, 5347098, 8080586, 12248410, 18756784, 28225771, 43093773, 65486561, 99045414, 151310955, 228724455, 348023263, 529594205, 801173623, 1221807483, 1851975489, 2811616211, 4280638452, 6483806139, 9868357660, 14982047251, 22731260597, 34595735055, 52455478004, 79736920444, 121163813568, 183818833704, 279577035139, 424298902217, 644518466541, 979598793116, 1486529583919, 2259672013534, 3431368935625, 5210540732980, 7919200472577, 12021394056581, 18265790342494, 27745532078352, 42128817773757],
-[2, 2, 3, 6, 8, 12, 16, 27, 49, 62, 101, 150, 218, 378, 519, 791, 1237, 1822, 2972, 4215, 6397, 10197, 14773, 23191, 34772, 52138, 81642, 120211, 186123, 283165, 421213, 655812, 981308, 1494460, 2288478, 3423722, 5277288, 7960883, 12055373, 18493863, 27780807, 42483781, 64516699, 97505227, 149192756, 225186515, 342877275, 521949455, 788840043, 1204152645, 1824063153, 2769660033, 4218554144, 6384810448, 9724024757, 14759710784, 22389134459, 34090801912, 51665618444, 78557730053, 119372844217, 181056815369, 275473722407, 417955634593, 634928365759, 965148614171, 1464264756195, 2226299778935, 3380326162361, 5132882939729, 7802231816922, 11841879616310, 17995071555813, 27333857217680, 41500724550159],
-[2, 2, 2, 4, 8, 16, 16, 22, 44, 64, 99, 135, 212, 346, 473, 784, 1197, 1679, 2691, 4009, 6246, 9446, 13727, 21998, 32662, 49291, 76738, 113137, 175079, 264824, 399702, 617811, 917956, 1409710, 2152988, 3227484, 4964001, 7476607, 11376378, 17375313, 26142629, 40051797, 60614511, 91823759, 140380741, 211910052, 322978356, 490582567, 742859751, 1133570220, 1715442073, 2608424075, 3968418547, 6009261637, 9153769149, 13884260870, 21081223668, 32075841921, 48616428638, 73958821168, 112307387310, 170427049508, 259251548627, 393285107509, 597687913481, 908135626261, 1378144697293, 2095384561208, 3180757096999, 4831406518672, 7342280365833, 11144441148202, 16936985327206, 25721491562122, 39060509383600],
-[2, 2, 2, 4, 8, 16, 16, 22, 44, 64, 108, 136, 206, 366, 500, 802, 1200, 1742, 2862, 4066, 6386, 9834, 14104, 22742, 33630, 50938, 79296, 115952, 181742, 273406, 410314, 639240, 946234, 1455810, 2220718, 3323798, 5135710, 7699976, 11732758, 17956958, 26928340, 41351962, 62521430, 94710554, 144966088, 218363690, 333414866, 506268094, 765829264, 1170332080, 1769078690, 2691227576, 4095290870, 6196713784, 9448412754, 14321287072, 21745970740, 33104310834, 50140544484, 76316287134, 115867580896, 175799336214, 267532761244, 405668553862, 616668498398, 937000659398, 1421580580116, 2162124856144, 3281328036006, 4984420241718, 7575673597388, 11496324057464, 17475216883208, 26536295983028, 40296406921268],
-[2, 2, 2, 4, 8, 16, 16, 22, 44, 64, 106, 140, 209, 351, 492, 814, 1202, 1703, 2800, 4098, 6358, 9679, 14046, 22541, 33324, 50540, 78682, 115230, 179543, 271341, 408409, 632560, 938512, 1444342, 2202566, 3298996, 5087733, 7644771, 11638283, 17792500, 26737914, 41004070, 62003071, 93960262, 143734459, 216711406, 330599958, 502073447, 759947147, 1160363737, 1755007337, 2669504538, 4061300473, 6147763503, 9369639521, 14206328538, 21571307106, 32828569605, 49741949136, 75691191229, 114922564696, 174388079407, 265327796128, 402409278279, 611645797203, 929338307918, 1410163254559, 2144393842916, 3254758153976, 4944040249513, 7513739602938, 11403570121966, 17332610501174, 26320765641804, 39970257713066],
-[2, 2, 2, 4, 8, 15, 15, 22, 44, 63, 102, 137, 206, 344, 489, 794, 1178, 1683, 2753, 4044, 6227, 9497, 13850, 22109, 32833, 49681, 77173, 113510, 176354, 266886, 401546, 620790, 924013, 1419119, 2164576, 3245421, 4996268, 7519735, 11439869, 17483686, 26299878, 40281128, 60965201, 92383288, 141230734, 213116141, 324893258, 493544817, 747204226, 1140271909, 1725609247, 2623913309, 3991835872, 6044583356, 9208532706, 13966235258, 21205082960, 32266693577, 48903956060, 74396527307, 112971164319, 171434662808, 260790866213, 395604840321, 601225817444, 913521017452, 1386280269461, 2107795719007, 3199575244171, 4859993387973, 7385758339157, 11210286668170, 17037320673430, 25873699387495, 39291422694281],
-[2, 2, 2, 4, 8, 16, 16, 22, 44, 64, 108, 138, 206, 366, 512, 820, 1186, 1725, 2899, 4164, 6409, 9805, 14269, 22988, 33874, 51262, 79772, 116796, 182985, 275877, 413715, 642438, 953295, 1468270, 2236711, 3347347, 5170672, 7760482, 11820094, 18081009, 27139964, 41646418, 62966388, 95429523, 146020913, 219993780, 335799514, 509964260, 771642346, 1178700335, 1782132108, 2711126835, 4124955153, 6242779965, 9517164084, 14426806769, 21906295361, 33344181265, 50512920912, 76875084060, 116714088695, 177097902678, 269484562289, 408659263670, 621192502765, 943853252654, 1432073399696, 2177924977544, 3305437948185, 5021086547166, 7631087944213, 11580956187039, 17603277778640, 26731032771947, 40592769835368],
-[2, 2, 2, 4, 8, 12, 15, 25, 38, 52, 91, 134, 187, 278, 442, 741, 1020, 1499, 2428, 3611, 5542, 8334, 12596, 19438, 28852, 44849, 68199, 100980, 156396, 236769, 359703, 547451, 823027, 1266386, 1912174, 2895222, 4436177, 6681191, 10186120, 15483789, 23448022, 35786236, 54076152, 82317293, 125298220, 189526896, 288893791, 438088178, 665146820, 1012300202, 1533719538, 2334282774, 3543576696, 5376549271, 8182589226, 12408632713, 18859515271, 28656748532, 43482219426, 66126921647, 100358669344, 152449630481, 231700652098, 351619640425, 534464827857, 811626182170, 1232482404272, 1873068164593, 2843462549198, 4320423428583, 6562602545734, 9964848356282, 15142243538449, 22992980471985, 34927038496970],
-[2, 2, 3, 6, 8, 15, 22, 27, 42, 71, 114, 153, 216, 368, 570, 831, 1269, 1891, 2915, 4442, 6737, 10364, 15262, 23513, 36287, 54236, 82760, 124978, 190803, 291089, 437763, 670572, 1015344, 1536404, 2350332, 3553396, 5406286, 8208999, 12441672, 18988299, 28731891, 43661885, 66454664, 100651790, 153248750, 232530827, 353041303, 537053705, 814135020, 1238449243, 1880860016, 2853812408, 4340797698, 6586562693, 10008380855, 15206878537, 23078656080, 35086956720, 53265550879, 80902942758, 122949993429, 186627647892, 283606054583, 430728281201, 654099751955, 993947417990, 1509109714250, 2292699828069, 3482687435902, 5288529870144, 8035343067292, 12202649572113, 18535364881265, 28157601894828, 42760419959276],
-[2, 2, 3, 6, 8, 14, 20, 27, 41, 62, 109, 164, 217, 349, 537, 827, 1263, 1809, 2870, 4335, 6546, 10225, 14915, 22989, 35221, 52913, 81680, 121529, 186000, 285316, 426982, 656651, 990633, 1501389, 2300272, 3462007, 5294151, 8025205, 12137197, 18577599, 28051689, 42693783, 64948099, 98248245, 149930309, 227079811, 344932744, 525153319, 794973486, 1210724472, 1837658356, 2788145471, 4243836622, 6432775675, 9782217179, 14861614515, 22544597972, 34297533359, 52038350823, 79058636965, 120160939742, 182323330054, 277192846203, 420860377853, 639122056410, 971416383972, 1474430882292, 2240564496062, 3403199596621, 5167378480102, 7852847546654, 11923012021343, 18112724264353, 27516028968699, 41781054330074],
-[2, 2, 3, 6, 8, 13, 18, 27, 48, 67, 104, 151, 220, 370, 536, 814, 1244, 1819, 2925, 4346, 6512, 10122, 14908, 23286, 35223, 52640, 81662, 121633, 186832, 285131, 426483, 657535, 989545, 1505027, 2302989, 3458479, 5299121, 8025381, 12153935, 18592326, 28035345, 42745881, 64968202, 98295122, 150079326, 227072171, 345208355, 525399558, 795291506, 1211839140, 1837904136, 2789893173, 4246648055, 6434855858, 9789584427, 14867088717, 22556596963, 34319993826, 52056882656, 79111824539, 120218797368, 182407576897, 277372070023, 421039626634, 639495765346, 971936441636, 1475112258362, 2241941000306, 3404799053595, 5170160740775, 7857266478127, 11928640121083, 18123183960460, 27530001737128, 41802668565555],
-[2, 2, 3, 6, 8, 12, 16, 27, 49, 62, 99, 154, 221, 363, 511, 803, 1239, 1783, 2910, 4247, 6369, 10042, 14715, 22990, 34466, 51740, 81028, 119489, 183924, 281100, 419308, 649132, 973586, 1482992, 2270326, 3398920, 5229311, 7905678, 11960898, 18329405, 27590381, 42135889, 63998340, 96754935, 147961127, 223534231, 340062367, 517754808, 782957926, 1194184302, 1809991800, 2747936995, 4184563747, 6335860167, 9645251524, 14644752250, 22214470825, 33815060683, 51267023096, 77932634148, 118427828017, 179645558562, 273268757291, 414696359010, 629905664564, 957486262691, 1452847430638, 2208568765707, 3353756280331, 5092502947524, 7740297822472, 11749125680812, 17852465173779, 27118326876456, 41174575341957],
-[2, 2, 2, 4, 8, 16, 16, 22, 44, 64, 97, 139, 215, 331, 465, 796, 1199, 1640, 2629, 4041, 6218, 9291, 13669, 21797, 32356, 48893, 76124, 112415, 172880, 262759, 397797, 611131, 910234, 1398242, 2134836, 3202682, 4916024, 7421402, 11281903, 17210855, 25952203, 39703905, 60096152, 91073467, 139149112, 210257768, 320163448, 486387920, 736977634, 1123601877, 1701370720, 2586701037, 3934428150, 5960311356, 9074995916, 13769302336, 20906560034, 31800100692, 48217833290, 73333725263, 111362371110, 169015792701, 257046583511, 390025831926, 592665212286, 900473274781, 1366727371736, 2077653547980, 3154187214969, 4791026526467, 7280346371383, 11051687212704, 16794378945172, 25505961220898, 38734360175398],
-[2, 2, 2, 4, 8, 16, 16, 22, 44, 64, 106, 140, 209, 351, 492, 814, 1202, 1703, 2800, 4098, 6358, 9679, 14046, 22541, 33324, 50540, 78682, 115230, 179543, 271341, 408409, 632560, 938512, 1444342, 2202566, 3298996, 5087733, 7644771, 11638283, 17792500, 26737914, 41004070, 62003071, 93960262, 143734459, 216711406, 330599958, 502073447, 759947147, 1160363737, 1755007337, 2669504538, 4061300473, 6147763503, 9369639521, 14206328538, 21571307106, 32828569605, 49741949136, 75691191229, 114922564696, 174388079407, 265327796128, 402409278279, 611645797203, 929338307918, 1410163254559, 2144393842916, 3254758153976, 4944040249513, 7513739602938, 11403570121966, 17332610501174, 26320765641804, 39970257713066],
-[2, 2, 2, 4, 8, 16, 16, 22, 44, 64, 104, 144, 212, 336, 484, 826, 1204, 1664, 2738, 4130, 6330, 9524, 13988, 22340, 33018, 50142, 78068, 114508, 177344, 269276, 406504, 625880, 930790, 1432874, 2184414, 3274194, 5039756, 7589566, 11543808, 17628042, 26547488, 40656178, 61484712, 93209970, 142502830, 215059122, 327785050, 497878800, 754065030, 1150395394, 1740935984, 2647781500, 4027310076, 6098813222, 9290866288, 14091370004, 21396643472, 32552828376, 49343353788, 75066095324, 113977548496, 172976822600, 263122831012, 399150002696, 606623096008, 921675956438, 1398745929002, 2126662829688, 3228188271946, 4903660257308, 7451805608488, 11310816186468, 17190004119140, 26105235300580, 39644108504864],
-[2, 2, 2, 4, 8, 15, 15, 22, 44, 63, 100, 141, 209, 329, 481, 806, 1180, 1644, 2691, 4076, 6199, 9342, 13792, 21908, 32527, 49283, 76559, 112788, 174155, 264821, 399641, 614110, 916291, 1407651, 2146424, 3220619, 4948291, 7464530, 11345394, 17319228, 26109452, 39933236, 60446842, 91632996, 139999105, 211463857, 322078350, 489350170, 741322109, 1130303566, 1711537894, 2602190271, 3957845475, 5995633075, 9129759473, 13851276724, 21030419326, 31990952348, 48505360712, 73771431402, 112026148119, 170023406001, 258585901097, 392345564738, 596203116249, 905858665972, 1374862943904, 2090064705779, 3173005362141, 4819613395768, 7323824344707, 11117532732672, 16894714291396, 25658169046271, 38965273486079],
-[2, 2, 2, 4, 8, 16, 16, 22, 44, 64, 106, 142, 209, 351, 504, 832, 1188, 1686, 2837, 4196, 6381, 9650, 14211, 22787, 33568, 50864, 79158, 116074, 180786, 273812, 411810, 635758, 945573, 1456802, 2218559, 3322545, 5122695, 7705277, 11725619, 17916551, 26949538, 41298526, 62448029, 94679231, 144789284, 218341496, 332984606, 505769613, 765760229, 1168731992, 1768060755, 2689403797, 4090964756, 6193829684, 9438390851, 14311848235, 21731631727, 33068440036, 50114325564, 76249988155, 115769072495, 175686645871, 267279597173, 405399988087, 616169801570, 936190901174, 1420656074139, 2160193964316, 3278868066155, 4980706554961, 7569153949763, 11488202251541, 17460671396606, 26515502430723, 40266620627166],
-[2, 2, 2, 4, 8, 11, 14, 25, 38, 51, 87, 131, 184, 271, 439, 721, 996, 1479, 2381, 3557, 5411, 8152, 12400, 19006, 28361, 43990, 66690, 99260, 153207, 232314, 352840, 535681, 808528, 1241163, 1874184, 2841647, 4344712, 6556155, 9987706, 15174975, 23009986, 35063294, 53038282, 80740319, 122794495, 185931631, 283187091, 429559548, 652403899, 992208374, 1504321448, 2288691545, 3474112095, 5273369124, 8021482411, 12168539433, 18493291125, 28094872504, 42644226350, 64832257725, 98407268967, 149496213882, 227163722183, 344815202467, 524044848098, 795808891704, 1208599419174, 1836470040684, 2788279639393, 4236376567043, 6434621281953, 9771564902486, 14846953710705, 22545914217676, 34248203478185],
-[2, 2, 3, 6, 8, 14, 21, 27, 42, 70, 110, 150, 213, 361, 567, 811, 1245, 1871, 2868, 4388, 6606, 10182, 15066, 23081, 35796, 53377, 81251, 123258, 187614, 286634, 430900, 658802, 1000845, 1511181, 2312342, 3499821, 5314821, 8083963, 12243258, 18679485, 28293855, 42938943, 65416794, 99074816, 150745025, 228935562, 347334603, 528525075, 801392099, 1218357415, 1851461926, 2808221179, 4271333097, 6483382546, 9847274040, 14966785257, 22712431934, 34525080692, 52427557803, 79608278836, 120998593052, 183674231293, 279069124668, 423923843243, 643679772196, 978130127524, 1485226729152, 2256101704160, 3427504526097, 5204483008604, 7907361803511, 12009366118317, 18240075053521, 27710535640519, 42081584940491],
-[2, 2, 3, 6, 8, 13, 19, 27, 41, 61, 105, 161, 214, 342, 534, 807, 1239, 1789, 2823, 4281, 6415, 10043, 14719, 22557, 34730, 52054, 80171, 119809, 182811, 280861, 420119, 644881, 976134, 1476166, 2262282, 3408432, 5202686, 7900169, 11938783, 18268785, 27613653, 41970841, 63910229, 96671271, 147426584, 223484546, 339226044, 516624689, 782230565, 1190632644, 1808260266, 2742554242, 4174372021, 6329595528, 9621110364, 14621521235, 22178373826, 33735657331, 51200357747, 77763973043, 118209539365, 179369913455, 272655916288, 414055939895, 628702076651, 955599093506, 1450547897194, 2203966372153, 3348016686816, 5083331618562, 7724866282873, 11729728567547, 17817434436609, 27068962714390, 41102219311289],
-[2, 2, 3, 6, 8, 12, 17, 27, 48, 66, 100, 148, 217, 363, 533, 794, 1220, 1799, 2878, 4292, 6381, 9940, 14712, 22854, 34732, 51781, 80153, 119913, 183643, 280676, 419620, 645765, 975046, 1479804, 2264999, 3404904, 5207656, 7900345, 11955521, 18283512, 27597309, 42022939, 63930332, 96718148, 147575601, 223476906, 339501655, 516870928, 782548585, 1191747312, 1808506046, 2744301944, 4177183454, 6331675711, 9628477612, 14626995437, 22190372817, 33758117798, 51218889580, 77817160617, 118267396991, 179454160298, 272835140108, 414235188676, 629075785587, 956119151170, 1451229273264, 2205342876397, 3349616143790, 5086113879235, 7729285214346, 11735356667287, 17827894132716, 27082935482819, 41123833546770],
-[2, 2, 3, 6, 8, 11, 15, 27, 49, 61, 95, 151, 218, 356, 508, 783, 1215, 1763, 2863, 4193, 6238, 9860, 14519, 22558, 33975, 50881, 79519, 117769, 180735, 276645, 412445, 637362, 959087, 1457769, 2232336, 3345345, 5137846, 7780642, 11762484, 18020591, 27152345, 41412947, 62960470, 95177961, 145457402, 219938966, 334355667, 509226178, 770215005, 1174092474, 1780593710, 2702345766, 4115099146, 6232680020, 9484144709, 14404658970, 21848246679, 33253184655, 50429030020, 76637970226, 116476427640, 176692141963, 268731827376, 407891921052, 619485684805, 941668972225, 1428964445540, 2171970641798, 3298573370526, 5008456085984, 7612316558691, 11555842227016, 17557175346035, 26671260622147, 40495740323172],
-[2, 2, 2, 4, 8, 15, 15, 22, 44, 63, 93, 136, 212, 324, 462, 776, 1175, 1620, 2582, 3987, 6087, 9109, 13473, 21365, 31865, 48034, 74615, 110695, 169691, 258304, 390934, 599361, 895735, 1373019, 2096846, 3149107, 4824559, 7296366, 11083489, 16902041, 25514167, 38980963, 59058282, 89496493, 136645387, 206662503, 314456748, 477859290, 724234713, 1103510049, 1671972630, 2541109808, 3864963549, 5857131209, 8913889101, 13529209056, 20540335888, 31238224664, 47379840214, 72039061341, 109410970733, 166062376102, 252509653596, 383221393968, 582245232527, 884655984315, 1342844386638, 2041055424071, 3099004305164, 4706979664927, 7152365107602, 10858403758908, 16499089117428, 25058894966589, 38055525156613],
-[2, 2, 2, 4, 8, 15, 15, 22, 44, 63, 102, 137, 206, 344, 489, 794, 1178, 1683, 2753, 4044, 6227, 9497, 13850, 22109, 32833, 49681, 77173, 113510, 176354, 266886, 401546, 620790, 924013, 1419119, 2164576, 3245421, 4996268, 7519735, 11439869, 17483686, 26299878, 40281128, 60965201, 92383288, 141230734, 213116141, 324893258, 493544817, 747204226, 1140271909, 1725609247, 2623913309, 3991835872, 6044583356, 9208532706, 13966235258, 21205082960, 32266693577, 48903956060, 74396527307, 112971164319, 171434662808, 260790866213, 395604840321, 601225817444, 913521017452, 1386280269461, 2107795719007, 3199575244171, 4859993387973, 7385758339157, 11210286668170, 17037320673430, 25873699387495, 39291422694281],
-[2, 2, 2, 4, 8, 15, 15, 22, 44, 63, 100, 141, 209, 329, 481, 806, 1180, 1644, 2691, 4076, 6199, 9342, 13792, 21908, 32527, 49283, 76559, 112788, 174155, 264821, 399641, 614110, 916291, 1407651, 2146424, 3220619, 4948291, 7464530, 11345394, 17319228, 26109452, 39933236, 60446842, 91632996, 139999105, 211463857, 322078350, 489350170, 741322109, 1130303566, 1711537894, 2602190271, 3957845475, 5995633075, 9129759473, 13851276724, 21030419326, 31990952348, 48505360712, 73771431402, 112026148119, 170023406001, 258585901097, 392345564738, 596203116249, 905858665972, 1374862943904, 2090064705779, 3173005362141, 4819613395768, 7323824344707, 11117532732672, 16894714291396, 25658169046271, 38965273486079],
-[2, 2, 2, 4, 8, 14, 14, 22, 44, 62, 96, 138, 206, 322, 478, 786, 1156, 1624, 2644, 4022, 6068, 9160, 13596, 21476, 32036, 48424, 75050, 111068, 170966, 260366, 392778, 602340, 901792, 1382428, 2108434, 3167044, 4856826, 7339494, 11146980, 17010414, 25671416, 39210294, 59408972, 90056022, 137495380, 207868592, 316371650, 480821540, 728579188, 1110211738, 1682139804, 2556599042, 3888380874, 5892452928, 8968652658, 13611183444, 20664195180, 31429076320, 47667367636, 72476767480, 110074747742, 167069989402, 254048971182, 385541126780, 585783136490, 890041375506, 1350979958806, 2053466581870, 3117822452336, 4735566534228, 7195843080926, 10924249278876, 16599424463652, 25211102791962, 38286438467294],
-[2, 2, 2, 4, 8, 15, 15, 22, 44, 63, 102, 139, 206, 344, 501, 812, 1164, 1666, 2790, 4142, 6250, 9468, 14015, 22355, 33077, 50005, 77649, 114354, 177597, 269357, 404947, 623988, 931074, 1431579, 2180569, 3268970, 5031230, 7580241, 11527205, 17607737, 26511502, 40575584, 61410159, 93102257, 142285559, 214746231, 327277906, 497240983, 753017308, 1148640164, 1738662665, 2643812568, 4021500155, 6090649537, 9277284036, 14071754955, 21365407581, 32506564008, 49276332488, 74955324233, 113817672118, 172733229272, 262742667258, 398595550129, 605749821811, 920373610708, 1396773089041, 2123595840407, 3223685156350, 4896659693421, 7441172685982, 11294918797745, 17165381568862, 26068436176414, 39587785608381],
-[2, 2, 2, 4, 8, 12, 15, 25, 38, 52, 93, 132, 184, 293, 462, 747, 1004, 1521, 2527, 3677, 5593, 8460, 12819, 19885, 29402, 45571, 69289, 102546, 159838, 241305, 365009, 557329, 837810, 1290314, 1946319, 2943573, 4519116, 6796902, 10367931, 15772298, 23850072, 36428584, 55039469, 83786554, 127584674, 192809270, 294093347, 445978991, 676842019, 1030636800, 1560844309, 2375905071, 3607231376, 5471565733, 8330113789, 12629110944, 19194503526, 29172360192, 44253191202, 67310814478, 102150193343, 155159453752, 235857418259, 357869625816, 544011533419, 826141126906, 1254392549409, 1906599299221, 2894142343407, 4397469726236, 6679950887009, 10142234421355, 15412910815915, 23403247602128, 35549550619272],
-[2, 2, 3, 6, 8, 15, 22, 27, 42, 71, 116, 151, 213, 383, 590, 837, 1253, 1913, 3014, 4508, 6788, 10490, 15485, 23960, 36837, 54958, 83850, 126544, 194245, 295625, 443069, 680450, 1030127, 1560332, 2384477, 3601747, 5489225, 8324710, 12623483, 19276808, 29133941, 44304233, 67417981, 102121051, 155535204, 235813201, 358240859, 544944518, 825830219, 1256785841, 1907984787, 2895434705, 4404452378, 6681579155, 10155905418, 15427356768, 23413644335, 35602568380, 54036522655, 82086835589, 124741517428, 189337471163, 287762820744, 436978266592, 663646457517, 1008462362726, 1531019859387, 2326230962697, 3533367230111, 5365576167797, 8152691408567, 12380035637186, 18806032158731, 28567869024971, 43382932081578],
-[2, 2, 3, 6, 8, 14, 20, 27, 41, 62, 111, 162, 214, 364, 557, 833, 1247, 1831, 2969, 4401, 6597, 10351, 15138, 23436, 35771, 53635, 82770, 123095, 189442, 289852, 432288, 666529, 1005416, 1525317, 2334417, 3510358, 5377090, 8140916, 12319008, 18866108, 28453739, 43336131, 65911416, 99717506, 152216763, 230362185, 350132300, 533044132, 806668685, 1229061070, 1864783127, 2829767768, 4307491302, 6527792137, 9929741742, 15082092746, 22879586227, 34813145019, 52809322599, 80242529796, 121952463741, 185033153325, 281349612364, 427110363244, 648668761972, 985931328708, 1496341027429, 2274095630690, 3453879390830, 5244424777755, 7970195887929, 12100398086416, 18383391541819, 27926296098842, 42403566452376],
-[2, 2, 3, 6, 8, 13, 18, 27, 48, 67, 106, 149, 217, 385, 556, 820, 1228, 1841, 3024, 4412, 6563, 10248, 15131, 23733, 35773, 53362, 82752, 123199, 190274, 289667, 431789, 667413, 1004328, 1528955, 2337134, 3506830, 5382060, 8141092, 12335746, 18880835, 28437395, 43388229, 65931519, 99764383, 152365780, 230354545, 350407911, 533290371, 806986705, 1230175738, 1865028907, 2831515470, 4310302735, 6529872320, 9937108990, 15087566948, 22891585218, 34835605486, 52827854432, 80295717370, 122010321367, 185117400168, 281528836184, 427289612025, 649042470908, 986451386372, 1497022403499, 2275472134934, 3455478847804, 5247207038428, 7974614819402, 12106026186156, 18393851237926, 27940268867271, 42425180687857],
-[2, 2, 3, 6, 8, 12, 16, 27, 49, 62, 101, 152, 218, 378, 531, 809, 1223, 1805, 3009, 4313, 6420, 10168, 14938, 23437, 35016, 52462, 82118, 121055, 187366, 285636, 424614, 659010, 988369, 1506920, 2304471, 3447271, 5312250, 8021389, 12142709, 18617914, 27992431, 42778237, 64961657, 98224196, 150247581, 226816605, 345261923, 525645621, 794653125, 1212520900, 1837116571, 2789559292, 4248218427, 6430876629, 9792776087, 14865230481, 22549459080, 34330672343, 52037994872, 79116526979, 120219352016, 182355381833, 277425523452, 420946344401, 639452370126, 972001207427, 1474757575775, 2242099900335, 3404436074540, 5169549245177, 7857646163747, 11926511745885, 18123132451245, 27528594006599, 41797087464259],
-[2, 2, 2, 4, 8, 16, 16, 22, 44, 64, 99, 137, 212, 346, 485, 802, 1183, 1662, 2728, 4107, 6269, 9417, 13892, 22244, 32906, 49615, 77214, 113981, 176322, 267295, 403103, 621009, 925017, 1422170, 2168981, 3251033, 4998963, 7537113, 11463714, 17499364, 26354253, 40346253, 61059469, 92542728, 141435566, 213540142, 325363004, 494278733, 748672833, 1141938475, 1728495491, 2628323334, 3998082830, 6055327818, 9222520479, 13989780567, 21241548289, 32315712352, 48988805066, 74517618094, 113153895109, 171725615972, 261203349672, 396275817317, 602211917848, 914988219517, 1388637516873, 2111184682608, 3204867009178, 4868072824120, 7397694712658, 11229073277777, 17065046222638, 25916228351041, 39356872297700],
-[2, 2, 2, 4, 8, 16, 16, 22, 44, 64, 108, 138, 206, 366, 512, 820, 1186, 1725, 2899, 4164, 6409, 9805, 14269, 22988, 33874, 51262, 79772, 116796, 182985, 275877, 413715, 642438, 953295, 1468270, 2236711, 3347347, 5170672, 7760482, 11820094, 18081009, 27139964, 41646418, 62966388, 95429523, 146020913, 219993780, 335799514, 509964260, 771642346, 1178700335, 1782132108, 2711126835, 4124955153, 6242779965, 9517164084, 14426806769, 21906295361, 33344181265, 50512920912, 76875084060, 116714088695, 177097902678, 269484562289, 408659263670, 621192502765, 943853252654, 1432073399696, 2177924977544, 3305437948185, 5021086547166, 7631087944213, 11580956187039, 17603277778640, 26731032771947, 40592769835368],
-[2, 2, 2, 4, 8, 16, 16, 22, 44, 64, 106, 142, 209, 351, 504, 832, 1188, 1686, 2837, 4196, 6381, 9650, 14211, 22787, 33568, 50864, 79158, 116074, 180786, 273812, 411810, 635758, 945573, 1456802, 2218559, 3322545, 5122695, 7705277, 11725619, 17916551, 26949538, 41298526, 62448029, 94679231, 144789284, 218341496, 332984606, 505769613, 765760229, 1168731992, 1768060755, 2689403797, 4090964756, 6193829684, 9438390851, 14311848235, 21731631727, 33068440036, 50114325564, 76249988155, 115769072495, 175686645871, 267279597173, 405399988087, 616169801570, 936190901174, 1420656074139, 2160193964316, 3278868066155, 4980706554961, 7569153949763, 11488202251541, 17460671396606, 26515502430723, 40266620627166],
-[2, 2, 2, 4, 8, 15, 15, 22, 44, 63, 102, 139, 206, 344, 501, 812, 1164, 1666, 2790, 4142, 6250, 9468, 14015, 22355, 33077, 50005, 77649, 114354, 177597, 269357, 404947, 623988, 931074, 1431579, 2180569, 3268970, 5031230, 7580241, 11527205, 17607737, 26511502, 40575584, 61410159, 93102257, 142285559, 214746231, 327277906, 497240983, 753017308, 1148640164, 1738662665, 2643812568, 4021500155, 6090649537, 9277284036, 14071754955, 21365407581, 32506564008, 49276332488, 74955324233, 113817672118, 172733229272, 262742667258, 398595550129, 605749821811, 920373610708, 1396773089041, 2123595840407, 3223685156350, 4896659693421, 7441172685982, 11294918797745, 17165381568862, 26068436176414, 39587785608381],
-[2, 2, 2, 4, 8, 16, 16, 22, 44, 64, 108, 140, 206, 366, 524, 838, 1172, 1708, 2936, 4262, 6432, 9776, 14434, 23234, 34118, 51586, 80248, 117640, 184228, 278348, 417116, 645636, 960356, 1480730, 2252704, 3370896, 5205634, 7820988, 11907430, 18205060, 27351588, 41940874, 63411346, 96148492, 147075738, 221623870, 338184162, 513660426, 777455428, 1187068590, 1795185526, 2731026094, 4154619436, 6288846146, 9585915414, 14532326466, 22066619982, 33584051696, 50885297340, 77433880986, 117560596494, 178396469142, 271436363334, 411649973478, 625716507132, 950705845910, 1442566219276, 2193725098944, 3329547860364, 5057752852614, 7686502291038, 11665588316614, 17731338674072, 26925769560866, 40889132749468],
-[1, 2, 2, 4, 4, 5, 8, 8, 15, 26, 48, 55, 82, 138, 205, 328, 445, 687, 1093, 1599, 2585, 3758, 5477, 8769, 13127, 20250, 30229, 45068, 71295, 105819, 160864, 246774, 367858, 568945, 857454, 1300338, 1993771, 2983084, 4582855, 6957483, 10492634, 16077787, 24239430, 36953522, 56232247, 84916300, 129820444, 196377242, 298277031, 454671862, 687488401, 1047686920, 1589595238, 2411451268, 3673592121, 5563199676, 8463174493, 12859655526, 19498504565, 29679921620, 45015063356, 68393909097, 103971729761, 157699732573, 239848644654, 364099415937, 552865602370, 840524544901, 1275442106178, 1938489965764, 2944327374200, 4470079502733, 6794451362821, 10314428191680, 15669924873442],
-[1, 2, 2, 4, 4, 6, 8, 10, 15, 23, 40, 49, 83, 128, 177, 283, 433, 672, 980, 1464, 2347, 3495, 5270, 8075, 12094, 18692, 28138, 42610, 65338, 97885, 150525, 228553, 343801, 526957, 795679, 1212769, 1844321, 2784027, 4258858, 6443819, 9782650, 14914700, 22551729, 34361602, 52145635, 79102464, 120505185, 182476035, 277584816, 421871167, 639581248, 973513366, 1476630392, 2243382572, 3410550405, 5172821860, 7867407110, 11943494237, 18134378208, 27571125465, 41837690538, 63584702805, 96580725099, 146625748904, 222871442448, 338335956963, 513994836024, 780905351643, 1185581571711, 1801614512999, 2735897520281, 4155397905934, 6313438621939, 9586456391881, 14564550862910],
-[1, 2, 2, 4, 4, 5, 8, 8, 14, 23, 46, 54, 83, 129, 181, 319, 446, 685, 1051, 1550, 2499, 3615, 5428, 8480, 12528, 19628, 29527, 44003, 68489, 101972, 156904, 238837, 356443, 551834, 829129, 1261660, 1930372, 2894326, 4443909, 6722699, 10184076, 15586580, 23470856, 35829800, 54443006, 82343010, 125795186, 190181658, 289289775, 440299766, 666180921, 1015673080, 1539692179, 2337549188, 3558864643, 5390652020, 8203803579, 12456305611, 18898492884, 28760780506, 43612281639, 66290674709, 100732407144, 152824598594, 232430269260, 352762323588, 535830668230, 814410797464, 1235895715605, 1878609983004, 2852787603111, 4331940493713, 6583803693246, 9994440249688, 15185633954217],
-[1, 2, 2, 4, 4, 6, 9, 10, 18, 26, 50, 71, 109, 164, 237, 392, 589, 861, 1281, 1936, 3095, 4683, 6852, 10520, 15984, 24446, 37285, 55773, 85459, 129313, 196635, 301253, 452182, 689482, 1048864, 1589492, 2425884, 3663372, 5576625, 8485790, 12843397, 19584477, 29678726, 45055414, 68583665, 103930909, 158183765, 240064635, 364276611, 554425632, 840711729, 1277940191, 1941620362, 2945999154, 4480452808, 6799725935, 10329806425, 15698580094, 23823123891, 36213867236, 54991037809, 83507647284, 126910299166, 192655569662, 292734933735, 444644621140, 675171748571, 1025973214807, 1557863553555, 2366504571653, 3595069427189, 5459133058501, 8294199650425, 12596537096142, 19132603354548],
-[1, 2, 2, 3, 5, 7, 11, 17, 25, 31, 50, 95, 132, 177, 289, 444, 680, 986, 1495, 2369, 3446, 5391, 8232, 12088, 18788, 28287, 43338, 65795, 98206, 152494, 229393, 346832, 533106, 800561, 1223611, 1855902, 2810453, 4303056, 6477767, 9874822, 15042959, 22719264, 34687056, 52522522, 79799162, 121516238, 183838294, 280206224, 425097849, 644770810, 982100766, 1488056852, 2263043630, 3438047592, 5214793619, 7936141308, 12037051842, 18288875470, 27801577540, 42173012326, 64130399559, 97361683922, 147849173748, 224742908119, 341065610397, 518357835453, 787313699804, 1195346559853, 1816783522766, 2758184614143, 4190203900008, 6365720633622, 9665257770844, 14686754659507, 22302954762578],
-[1, 2, 2, 3, 5, 6, 8, 15, 28, 35, 46, 83, 133, 188, 288, 437, 674, 1007, 1533, 2392, 3473, 5301, 8309, 12417, 18828, 28388, 43459, 66584, 99485, 152655, 231714, 349379, 535676, 809747, 1231580, 1870173, 2831150, 4330096, 6546307, 9936609, 15147625, 22924808, 34909650, 52976777, 80397167, 122391919, 185406787, 282088738, 428614011, 649891316, 988929421, 1500441717, 2279670645, 3464709328, 5256293472, 7993375516, 12134632651, 18426727535, 28011552314, 42511060471, 64603616368, 98125692617, 148989628843, 226436386880, 343764646754, 522251631327, 793406215373, 1204646869824, 1830477626953, 2779779166218, 4222163112603, 6414526991691, 9740452857492, 14798188218129, 22476111901101],
-[1, 2, 2, 3, 4, 6, 8, 15, 24, 26, 38, 63, 96, 149, 220, 331, 528, 756, 1170, 1775, 2598, 4078, 6106, 9401, 14304, 21278, 32938, 49535, 75306, 115484, 172720, 264914, 402346, 608311, 930750, 1400964, 2138768, 3255375, 4914245, 7510496, 11362196, 17267234, 26303401, 39780120, 60644549, 91967012, 139539682, 212578813, 321933238, 489770100, 744024008, 1128404006, 1717546561, 2604593982, 3958006058, 6016323372, 9125448003, 13879408739, 21068247868, 31994165194, 48636669597, 73803457375, 112178464880, 170371898269, 258670381454, 393171016323, 596849053466, 906783099075, 1377561137488, 2091524570475, 3178312744935, 4826294309880, 7330778526513, 11137542425520, 16911554918969],
-[1, 2, 2, 3, 4, 6, 7, 13, 19, 19, 33, 52, 78, 111, 193, 281, 399, 634, 953, 1496, 2142, 3299, 5179, 7564, 11770, 17710, 26753, 41140, 61546, 94953, 143286, 215282, 332772, 500493, 760813, 1160169, 1749540, 2680143, 4047332, 6147357, 9389298, 14156321, 21606219, 32801996, 49695711, 75778681, 114655656, 174543191, 265272178, 401793008, 612257864, 928155880, 1409910662, 2144604163, 3250881683, 4946366313, 7507638070, 11397707672, 17336451745, 26294516978, 39971015406, 60718959890, 92157224723, 140118598256, 212668372888, 323099304140, 490934611259, 745185883587, 1132611356907, 1719821595900, 2611994460078, 3969023457676, 6025832715697, 9155717894388, 13906163320021],
-[1, 2, 2, 3, 4, 6, 7, 11, 19, 21, 35, 58, 89, 107, 167, 286, 427, 609, 901, 1460, 2175, 3241, 5045, 7560, 11242, 17471, 26721, 40154, 60545, 92300, 142155, 213148, 323206, 494848, 747103, 1137746, 1727595, 2624459, 3989079, 6034762, 9207600, 13983999, 21169163, 32239624, 48924562, 74338697, 112938543, 171329854, 260706061, 395502549, 600662953, 913515135, 1385744738, 2106150811, 3198915145, 4857460566, 7383096773, 11205273688, 17027383746, 25867878877, 39269419190, 59680835234, 90622743533, 137649634145, 209127819150, 317537133061, 482476285956, 732739397480, 1112851178324, 1690794194980, 2567539725544, 3900403258917, 5924534142247, 8997738805836, 13669332060601],
-[1, 2, 2, 3, 4, 6, 7, 10, 19, 21, 32, 60, 102, 128, 181, 304, 457, 745, 1057, 1527, 2413, 3673, 5839, 8476, 12509, 19903, 29788, 45520, 68935, 103166, 159950, 239927, 366202, 560022, 835816, 1286297, 1950529, 2952492, 4512540, 6790461, 10387064, 15774711, 23840986, 36455963, 55074712, 83782756, 127561587, 192983344, 294269171, 445838509, 677097233, 1031221280, 1561223926, 2376442311, 3608218920, 5474055988, 8331923811, 12631913742, 19203643705, 29179457917, 44264031632, 67334676250, 102181922677, 155210646596, 235916482297, 357984942156, 544203269750, 826348683754, 1254803786510, 1907206668931, 2894973198286, 4398879681897, 6681903735083, 10145501563681, 15417623960658],
-[1, 2, 2, 3, 4, 7, 10, 12, 20, 25, 43, 76, 118, 156, 244, 384, 573, 876, 1275, 1994, 3046, 4602, 7084, 10501, 16062, 24578, 37033, 56690, 85411, 129929, 198565, 299285, 457447, 693200, 1049250, 1602814, 2424293, 3691443, 5606165, 8489659, 12951051, 19618110, 29808051, 45343303, 68699720, 104589434, 158710120, 240954194, 366535023, 555736086, 845127414, 1283668801, 1948023991, 2962461038, 4495411142, 6830691961, 10378916543, 15752190144, 23945752731, 36355471159, 55217843694, 83911190304, 127380123480, 193561856278, 293976669848, 446433466572, 678365716477, 1030011773736, 1564775413069, 2376949801596, 3609545377057, 5484126574918, 8328507099383, 12650600005892, 19217733182271],
-[1, 2, 2, 3, 4, 6, 9, 13, 25, 29, 41, 76, 123, 170, 237, 371, 608, 923, 1325, 2024, 3059, 4764, 7246, 10788, 16540, 24805, 38145, 58342, 87355, 133357, 201856, 307513, 469476, 707098, 1078296, 1636843, 2481764, 3788574, 5727695, 8709069, 13243252, 20072530, 30589128, 46344541, 70392164, 107108373, 162349891, 247038176, 374952505, 569144951, 865732795, 1313162111, 1996304726, 3032332544, 4601885805, 6997580278, 10620972208, 16135375419, 24517308756, 37213686377, 56562552153, 85887702188, 130441644492, 198215579545, 300923806472, 457230895415, 694479083485, 1054627282889, 1602441240899, 2433282588517, 3696401642149, 5615069962084, 8527003493919, 12954820835054, 19674819054048],
-[1, 2, 2, 3, 4, 6, 8, 13, 23, 26, 38, 70, 109, 152, 218, 349, 544, 820, 1272, 1838, 2764, 4417, 6635, 9976, 14988, 22858, 35437, 52903, 80528, 122820, 184703, 283629, 429541, 651367, 991043, 1496954, 2291617, 3472775, 5254790, 8019152, 12138688, 18474388, 28071297, 42537462, 64801643, 98190636, 149252155, 227045757, 344023233, 523443792, 794598875, 1206516507, 1834790996, 2782722530, 4230895542, 6426176578, 9753536222, 14830698175, 22508412099, 34196473979, 51958111423, 78871221844, 119878682432, 182015673913, 276452996419, 420082326007, 637759601765, 969040703412, 1471796741178, 2235132156185, 3396095640042, 5156894734079, 7834041554508, 11900036822653, 18071573265548],
-[1, 2, 2, 3, 4, 6, 8, 12, 19, 24, 38, 58, 89, 133, 211, 322, 485, 762, 1111, 1712, 2600, 3893, 6025, 9069, 13781, 20968, 31672, 48613, 73354, 110986, 169884, 256593, 390984, 593744, 899022, 1372058, 2075408, 3158069, 4803771, 7269902, 11078692, 16804026, 25517065, 38818816, 58829824, 89521191, 135912679, 206252605, 313788241, 475942464, 723358495, 1099067645, 1667899193, 2536070159, 3849262736, 5847305795, 8886252961, 13487087405, 20498566315, 31128979012, 47272961408, 71837973962, 109063047038, 165706593226, 251700240597, 382209036524, 580759177646, 881893673188, 1339620171378, 2035044717845, 3090356330437, 4695065995043, 7130666315556, 10830570665269, 16453199047630],
-[1, 2, 2, 3, 4, 5, 7, 11, 18, 24, 42, 54, 80, 135, 199, 283, 424, 683, 1066, 1493, 2246, 3577, 5334, 8075, 12261, 18653, 28381, 42564, 65750, 99524, 149129, 229291, 347876, 527763, 800996, 1213354, 1855238, 2804070, 4257191, 6495610, 9821355, 14945460, 22707949, 34459538, 52433195, 79428827, 120860072, 183684601, 278424976, 423704181, 642997143, 976618459, 1484486615, 2252323628, 3424847832, 5199319440, 7894623295, 12002572669, 18215310637, 27678387573, 42045114518, 63836822179, 97018393748, 147294573883, 223762078033, 339954358236, 516144381056, 784283492696, 1191076042440, 1809017589945, 2748401512329, 4173484793552, 6340439977441, 9630356229846, 14625909131602],
-[1, 2, 2, 3, 4, 5, 7, 11, 18, 23, 39, 55, 77, 125, 179, 246, 396, 668, 961, 1342, 2073, 3348, 5001, 7374, 11281, 17145, 26237, 39865, 60697, 91505, 137796, 212275, 323031, 486657, 739145, 1124305, 1712745, 2596465, 3937519, 5997090, 9086997, 13807874, 21015356, 31867289, 48411740, 73504093, 111721381, 169809062, 257524565, 391489262, 594738028, 902832341, 1372219696, 2083350943, 3165001116, 4807645811, 7300022004, 11094372173, 16845268674, 25584763819, 38873814515, 59029055620, 89678937058, 136204048089, 206867261304, 314282445757, 477264093245, 725017202765, 1101287172938, 1672528580033, 2540835267747, 3858985546181, 5861619204496, 8903847187368, 13522870216285],
-[1, 2, 2, 3, 4, 5, 8, 13, 21, 25, 41, 69, 99, 149, 226, 342, 530, 797, 1204, 1814, 2696, 4259, 6457, 9589, 14773, 22272, 34119, 51565, 77818, 119789, 179887, 274156, 418893, 631160, 963263, 1458949, 2218005, 3380239, 5103369, 7784877, 11820757, 17908664, 27298530, 41348248, 62878256, 95554819, 144861743, 220603576, 334489010, 508059926, 772757012, 1171796659, 1782107398, 2705630194, 4107875471, 6246199124, 9476763046, 14403836487, 21880501083, 33212463948, 50488085609, 76645182069, 116433195023, 176903809301, 268560815773, 408130758038, 619772612992, 941291245760, 1430234611554, 2171606021249, 3299293388226, 5011279614400, 7610466432222, 11562732741305, 17559074907186],
-[1, 2, 2, 3, 4, 5, 8, 13, 23, 27, 42, 64, 98, 178, 241, 337, 541, 827, 1340, 1908, 2784, 4519, 6685, 10336, 15543, 22986, 36260, 53900, 82488, 126413, 187284, 290946, 438604, 663831, 1019526, 1524133, 2342271, 3553613, 5360164, 8227463, 12379531, 18873557, 28752126, 43387396, 66355274, 100332163, 152418570, 232426580, 351193655, 535502220, 812426631, 1231997833, 1877631105, 2842733774, 4325143107, 6571975509, 9962647325, 15169772297, 23002901042, 34949253696, 53138133977, 80581813349, 122575566801, 186065454703, 282518571872, 429564142169, 651746016161, 990642369932, 1504685899651, 2284227824284, 3472309188928, 5270867339510, 8007754706310, 12166110220461, 18469905981951],
-[1, 2, 2, 3, 4, 5, 7, 10, 19, 22, 32, 54, 88, 131, 176, 282, 436, 668, 1021, 1485, 2292, 3553, 5342, 8151, 12063, 18470, 28565, 42934, 65540, 98550, 149799, 230075, 346270, 527819, 800344, 1212023, 1854723, 2802602, 4261656, 6479957, 9808294, 14966943, 22677744, 34429972, 52403091, 79376879, 120858747, 183454370, 278341220, 423567127, 642217981, 976474178, 1483575555, 2250713257, 3423286556, 5194974516, 7892243510, 11994642424, 18201373165, 27669002860, 42013293427, 63801967270, 96967375490, 147191389528, 223658474619, 339721679730, 515847740957, 783887871418, 1190239301446, 1808087249186, 2746752583597, 4170890336915, 6337096053460, 9624102631381, 14617832469541],
-[1, 2, 2, 3, 4, 5, 7, 10, 19, 23, 38, 56, 90, 132, 179, 301, 452, 664, 1021, 1540, 2384, 3570, 5307, 8363, 12462, 18773, 29049, 43509, 66688, 100565, 152680, 234585, 350995, 537149, 817697, 1232849, 1886985, 2851144, 4338839, 6603091, 9975610, 15243837, 23086867, 35019537, 53388738, 80797546, 123016175, 186760339, 283249350, 431411630, 653648553, 993832394, 1510722735, 2290559818, 3485322073, 5288615587, 8033032873, 12212405905, 18525499129, 28168581242, 42772674525, 64938549460, 98722767832, 149831464030, 227675599749, 345856656547, 525073474293, 798037133844, 1211628522241, 1840517577790, 2796345912680, 4245649009291, 6451158659921, 9797342332796, 14880101266932],
-[1, 2, 2, 3, 4, 5, 7, 10, 20, 24, 38, 59, 85, 138, 199, 326, 462, 706, 1137, 1602, 2507, 3833, 5731, 8897, 13135, 20280, 31051, 45945, 71498, 107537, 162434, 250127, 374480, 575280, 869800, 1313479, 2021288, 3035532, 4628746, 7051107, 10639898, 16279389, 24601019, 37397190, 57006098, 86092724, 131385459, 199272388, 302144165, 460523679, 697122009, 1061020745, 1611779676, 2443288715, 3721179411, 5641071369, 8572137373, 13033545574, 19762783287, 30065478555, 45632111014, 69293814197, 105362226373, 159839016468, 242982124688, 369042500235, 560232662838, 851667335644, 1292713074018, 1964073950737, 2983917938341, 4529929132617, 6884511923578, 10453628328261, 15877937277075],
-[1, 2, 2, 3, 4, 6, 9, 12, 22, 28, 41, 66, 104, 159, 221, 340, 535, 793, 1244, 1877, 2739, 4279, 6479, 9955, 14979, 22358, 34864, 52411, 79358, 121496, 182451, 279812, 423615, 641847, 981773, 1477377, 2256083, 3432169, 5186371, 7918908, 11980544, 18222455, 27732377, 41956435, 63970112, 96986613, 147201535, 224161207, 339574766, 516640579, 784538208, 1190312575, 1811505055, 2746924450, 4174912648, 6344810094, 9625513972, 14638678001, 22219249731, 33747909792, 51294387055, 77841769512, 118319907437, 179683879653, 272835826055, 414669488839, 629499369779, 956429111651, 1452864884631, 2206010282809, 3352199001939, 5090248533077, 7732053183236, 11746617987805, 17836973083967],
-[1, 2, 2, 3, 4, 4, 8, 13, 24, 30, 49, 74, 104, 180, 237, 381, 625, 878, 1399, 2054, 3136, 4949, 7071, 11193, 16951, 25105, 39601, 58314, 89707, 137226, 203856, 317926, 474735, 721152, 1110586, 1656529, 2551111, 3857520, 5828793, 8954992, 13442457, 20552850, 31255801, 47138672, 72214902, 109042193, 165834572, 252710519, 381656065, 582758903, 883054991, 1339751571, 2042312525, 3089803615, 4704904552, 7145308810, 10832862405, 16499961747, 25004919352, 38011865820, 57783817566, 87611176825, 133316337603, 202294858472, 307232104869, 467139211888, 708614530402, 1077384809700, 1636074802042, 2483847180298, 3776132999078, 5731002286537, 8708297796340, 13229269557228, 20083553287738],
-[1, 2, 2, 3, 4, 4, 8, 12, 23, 30, 49, 72, 106, 188, 249, 389, 617, 879, 1446, 2088, 3130, 4973, 7198, 11413, 17088, 25287, 40106, 58923, 90701, 138796, 205854, 321402, 479511, 729707, 1123089, 1671926, 2579952, 3899199, 5890905, 9053794, 13581597, 20780243, 31587526, 47638166, 73024601, 110178664, 167614637, 255465662, 385711450, 589134505, 892408070, 1354171933, 2064563711, 3122508993, 4756082970, 7222035737, 10948627082, 16679062909, 25271831094, 38422767245, 58405725059, 88547744760, 134761390038, 204461476121, 310537255329, 472180114557, 716202879993, 1089019142247, 1653646304107, 2510538636339, 3816876439825, 5792448074346, 8802149931413, 13371616857070, 20299224275802],
-[1, 2, 2, 3, 4, 4, 7, 12, 19, 25, 44, 57, 81, 144, 193, 281, 433, 695, 1088, 1521, 2315, 3641, 5433, 8378, 12578, 18903, 29085, 43810, 67640, 101668, 152440, 235989, 355865, 540747, 822225, 1241631, 1902677, 2873066, 4366083, 6661868, 10049036, 15333690, 23282181, 35294267, 53772457, 81375802, 123910623, 188283125, 285254514, 434538524, 658806166, 1000903693, 1522086648, 2307826005, 3511053512, 5328633275, 8091050634, 12305152950, 18665368678, 28372713274, 43096918316, 65420227364, 99456437996, 150956976905, 229351903084, 348462370772, 528969446634, 803948933653, 1220759431957, 1854115563533, 2817226687932, 4277356037330, 6499004007370, 9870778782637, 14990465393726],
-[1, 2, 2, 3, 5, 7, 9, 15, 26, 38, 56, 82, 126, 189, 283, 467, 682, 978, 1575, 2395, 3625, 5390, 8173, 12846, 19025, 28994, 44502, 66490, 102144, 154793, 235088, 358137, 538264, 826739, 1254365, 1892247, 2894453, 4376459, 6661849, 10127226, 15327410, 23392904, 35404018, 53792008, 81940347, 124021962, 188800659, 286580440, 434982052, 661984358, 1003250369, 1525859985, 2318120687, 3516361647, 5349632837, 8117092665, 12331673127, 18741264140, 28438499824, 43238211640, 65643483781, 99687544766, 151520096151, 229980279033, 349479789769, 530816616399, 806006300464, 1224871593078, 1859703047639, 2825215245946, 4291892983004, 6516901296167, 9901996917007, 15037655430517, 22840618691206],
-[1, 2, 3, 4, 6, 11, 12, 15, 28, 45, 69, 86, 150, 250, 328, 537, 807, 1168, 1851, 2772, 4362, 6430, 9468, 15287, 22585, 33978, 52482, 78323, 121346, 181866, 276208, 425910, 632627, 974078, 1482537, 2229636, 3420834, 5150161, 7865199, 11969839, 18031088, 27636834, 41774737, 63398205, 96757496, 146159832, 222904139, 338074758, 512669799, 781831762, 1182795259, 1799880210, 2735929657, 4145866984, 6314016917, 9573062951, 14545768081, 22118585494, 33532228998, 51020010790, 77442830808, 117571188276, 178797463761, 271243184712, 412308537414, 626256955629, 950628607616, 1445261806668, 2193653698566, 3332729753310, 5063715906893, 7686803994560, 11682360192685, 17739365891192, 26943077101926],
-[1, 2, 3, 4, 5, 9, 13, 17, 25, 46, 77, 93, 149, 243, 350, 544, 807, 1250, 1905, 2817, 4493, 6595, 9825, 15474, 23150, 35374, 53326, 80678, 124875, 186094, 284626, 435161, 652151, 1001244, 1514374, 2300431, 3507789, 5283741, 8091026, 12263043, 18555890, 28351168, 42857202, 65246062, 99160612, 150187130, 229007690, 346786771, 527061867, 802085805, 1214849962, 1849319190, 2806577464, 4260728808, 6481906024, 9827473433, 14944953023, 22699523191, 34445611459, 52389642295, 79497064263, 120785586475, 183536045921, 278552223705, 423451424772, 642894118150, 976427264166, 1483869272671, 2252502418017, 3422840852835, 5198616125435, 7894422301398, 11995912863404, 18214183416737, 27670684503426],
-[1, 2, 3, 4, 5, 9, 12, 21, 30, 42, 63, 90, 154, 235, 319, 503, 803, 1191, 1829, 2698, 4171, 6385, 9474, 14857, 22109, 33351, 51558, 77322, 118731, 178866, 270773, 416771, 625092, 954901, 1453335, 2191117, 3354651, 5070555, 7712735, 11744335, 17732920, 27091572, 41064139, 62268351, 94916375, 143643729, 218703523, 332129511, 503567436, 767000693, 1162299671, 1766822083, 2686182577, 4072686616, 6196623598, 9403460856, 14281656990, 21714482612, 32938974391, 50080293373, 76052527524, 115457875069, 175527228089, 266404325233, 404791828242, 614944187260, 933564419888, 1418883087630, 2154315801152, 3272345452320, 4971900969658, 7548743600190, 11469942933344, 17419805579141, 26456326973197],
-[1, 2, 3, 4, 4, 7, 12, 21, 24, 33, 64, 90, 147, 195, 293, 506, 703, 1106, 1651, 2415, 3952, 5776, 8766, 13500, 19803, 31425, 46809, 70338, 109461, 161794, 250768, 379850, 569497, 881545, 1316563, 2015693, 3076666, 4612167, 7096212, 10698820, 16253014, 24852258, 37389782, 57209036, 86706468, 131300487, 200683906, 302990954, 461598504, 701689816, 1062033599, 1620288369, 2453343421, 3728548185, 5673977838, 8592334445, 13085686988, 19853593284, 30136204807, 45860360796, 69516448074, 105725170406, 160581457853, 243660450565, 370643042665, 562331283010, 854461588544, 1298439327960, 1970381323863, 2995659113061, 4547894730516, 6907307250835, 10497435493905, 15933866565317, 24214285986333],
-[1, 2, 2, 4, 4, 6, 8, 10, 14, 20, 40, 48, 80, 123, 174, 265, 399, 668, 921, 1378, 2243, 3255, 4984, 7596, 11488, 17656, 26133, 40493, 62004, 91629, 141795, 214831, 324985, 496788, 746544, 1146999, 1733778, 2619583, 4022835, 6057681, 9222686, 14039627, 21235393, 32426194, 49024681, 74551367, 113577487, 171697870, 261724509, 397143071, 602477882, 917382747, 1389791644, 2114678183, 3211796232, 4870847166, 7414548697, 11244875409, 17085609233, 25970747448, 39397415527, 59915374308, 90946600028, 138119929291, 209963977436, 318606935241, 484253199043, 735492468649, 1116691232878, 1697249417521, 2576577771904, 3914562956927, 5946798579326, 9029017215594, 13720514536261],
-[1, 2, 2, 4, 4, 7, 8, 8, 15, 19, 35, 53, 96, 118, 161, 274, 396, 654, 937, 1365, 2208, 3271, 5058, 7666, 11141, 17485, 26441, 40232, 61796, 90871, 141128, 214486, 322473, 496634, 741700, 1136932, 1732919, 2606647, 4003342, 6026266, 9167149, 14008382, 21107893, 32261225, 48855246, 74089578, 113135106, 170893323, 260383835, 395525569, 599075228, 913573998, 1383544369, 2103243398, 3198551496, 4846205801, 7379681460, 11194719240, 16997859479, 25857489583, 39204703532, 59625058047, 90545207785, 137426660934, 209000201655, 317108856521, 481894601685, 732164334777, 1111232374726, 1689303081447, 2564601331167, 3895499315306, 5919462780126, 8985821029151, 13655317942585],
-[1, 2, 2, 4, 5, 7, 10, 15, 19, 26, 47, 70, 112, 150, 236, 371, 521, 856, 1272, 1856, 2911, 4393, 6803, 10072, 15054, 23818, 35354, 53607, 82407, 123463, 189836, 285555, 434910, 666275, 996143, 1529136, 2323432, 3507379, 5364425, 8096612, 12347613, 18766368, 28361692, 43353967, 65587138, 99614488, 151774806, 229667824, 349868296, 530719157, 805478570, 1226619691, 1857877318, 2826294601, 4294057871, 6512452888, 9910203085, 15032581027, 22842214678, 34717211061, 52666316094, 80094901599, 121586806596, 184637825435, 280670022471, 425946294281, 647354640771, 983200863460, 1492838187357, 2268909931139, 3444472923620, 5232987444532, 7949813646894, 12070431085823, 18341510822376],
-[1, 2, 2, 4, 4, 6, 8, 9, 16, 25, 47, 57, 93, 141, 208, 347, 487, 754, 1156, 1707, 2698, 3981, 6044, 9380, 13808, 21487, 32496, 48690, 75344, 112378, 172777, 262919, 393681, 607375, 913941, 1390401, 2124107, 3193975, 4894568, 7406405, 11226903, 17160439, 25882411, 39475746, 59970134, 90780373, 138538302, 209620380, 318813063, 484997976, 734325957, 1118871219, 1696692907, 2576246071, 3920371780, 5941291740, 9039024533, 13724735502, 20827966771, 31685738774, 48061459334, 73046105895, 110986741885, 168420529065, 256089277099, 388719454737, 590460390147, 897325102831, 1361948636042, 2069957058391, 3143455146846, 4773623595053, 7254261421555, 11013365385200, 16732977547001],
-[1, 2, 2, 4, 4, 6, 8, 11, 15, 22, 42, 46, 76, 129, 208, 297, 400, 672, 1053, 1558, 2328, 3479, 5434, 8267, 12351, 18918, 28436, 43456, 66600, 99872, 152551, 231074, 351030, 536240, 807826, 1233323, 1872683, 2833788, 4327085, 6548527, 9958111, 15137948, 22930996, 34955255, 52982147, 80446679, 122425266, 185539763, 282296341, 428588898, 650468393, 989490871, 1500765809, 2281351053, 3466028497, 5259388509, 7997367427, 12139042835, 18440183459, 28021901896, 42531245164, 64644295649, 98163959910, 149075750104, 226544199715, 343930001750, 522556817459, 793734638499, 1205313223067, 1831435062605, 2781027267349, 4224537139256, 6417545897981, 9745447239787, 14805973565692],
-[1, 2, 2, 4, 4, 5, 8, 10, 19, 27, 47, 64, 102, 168, 228, 373, 538, 786, 1290, 1918, 2941, 4413, 6509, 10406, 15382, 23182, 36048, 53407, 82738, 124750, 188287, 290881, 432915, 665222, 1013227, 1521099, 2339720, 3523063, 5364385, 8184171, 12322922, 18875837, 28554954, 43294427, 66142421, 99857038, 152233699, 231147941, 350164890, 534159971, 808350793, 1229484321, 1869849387, 2832215799, 4313979858, 6542444744, 9935979536, 15114950329, 22911876922, 34855826414, 52921504609, 80322636065, 122172542584, 185337838184, 281683798169, 427947560464, 649494908178, 987478238124, 1498937278574, 2276968035193, 3460037618054, 5252039047146, 7981911393660, 12121312717005, 18408322207485],
-[1, 2, 2, 4, 4, 5, 9, 9, 15, 28, 47, 54, 85, 146, 205, 307, 452, 694, 1091, 1632, 2504, 3786, 5553, 8782, 13353, 19839, 30298, 45843, 70996, 106478, 160190, 247506, 372134, 566613, 862955, 1303431, 1991661, 3008661, 4581675, 6983112, 10532315, 16075371, 24401212, 36996769, 56358295, 85324875, 129904797, 197275832, 299057127, 455572322, 690530280, 1049203998, 1595341592, 2419388650, 3680413002, 5585224178, 8482323087, 12897762929, 19565310255, 29743126086, 45174125037, 68578141605, 104250148755, 158238310720, 240426614611, 365253296904, 554496769732, 842734955533, 1279615671623, 1943602880602, 2953066564202, 4483718694216, 6812540475333, 10346707555851, 15713897661957],
-[1, 2, 2, 4, 4, 4, 8, 9, 15, 25, 47, 55, 74, 128, 185, 284, 399, 629, 998, 1457, 2217, 3357, 4987, 7740, 11654, 17717, 26978, 40230, 62449, 93974, 142022, 218195, 327268, 500812, 759896, 1150305, 1759942, 2649912, 4041185, 6155396, 9296350, 14189534, 21487219, 32649050, 49720334, 75219311, 114627178, 173910954, 263806534, 401813823, 608796418, 925832409, 1406582547, 2133536861, 3246833923, 4924668601, 7482368787, 11374073785, 17254114829, 26237224903, 39832896788, 60488176422, 91946632145, 139538870509, 212071277938, 322106653119, 489044225607, 743283256727, 1128437345408, 1714305203322, 2604353809517, 3954279347496, 6008656463538, 9124645238691, 13859296455087],
-[1, 2, 2, 4, 4, 5, 8, 8, 15, 26, 50, 58, 87, 141, 206, 356, 470, 722, 1179, 1711, 2734, 3966, 5909, 9397, 13773, 21557, 32509, 47974, 75597, 112510, 172036, 263109, 390797, 607566, 913195, 1383671, 2127308, 3178481, 4883804, 7406795, 11181821, 17153548, 25801095, 39375160, 59950306, 90449598, 138367061, 209208986, 317878720, 484555695, 732251797, 1116820111, 1693796682, 2569008600, 3915314886, 5927184215, 9019109597, 13702754386, 20774672439, 31632125651, 47960723595, 72879538312, 110799978479, 168022707726, 255597037101, 387962411764, 589113417512, 895701134049, 1358969817182, 2065714945082, 3137422897698, 4763009658761, 7240388823469, 10990407093767, 16697720839119],
-[1, 2, 2, 4, 5, 5, 9, 12, 17, 29, 48, 60, 98, 149, 221, 338, 478, 784, 1167, 1738, 2711, 3994, 6187, 9353, 14001, 21741, 32490, 49490, 75852, 113354, 174602, 263869, 399099, 611762, 919433, 1407195, 2135949, 3225606, 4938218, 7455937, 11348828, 17273150, 26110856, 39857513, 60371593, 91671008, 139636264, 211324027, 321825500, 488548502, 741068774, 1128310373, 1710021591, 2600160191, 3951119057, 5992565980, 9117672099, 13833891985, 21014816920, 31944651994, 48465135560, 73686725430, 111882885383, 169888937590, 258242091278, 391947526146, 595602032173, 904707631634, 1373605908879, 2087566627536, 3169529038029, 4814827855924, 7314808718097, 11106535680859, 16875878232440],
-[1, 2, 2, 4, 4, 4, 8, 10, 19, 30, 54, 65, 94, 167, 236, 386, 546, 794, 1335, 1961, 2987, 4461, 6577, 10664, 15752, 23664, 36692, 54164, 84551, 127291, 191507, 296366, 440190, 678264, 1033419, 1548454, 2384738, 3584813, 5465300, 8345758, 12543108, 19232579, 29082251, 44098948, 67418211, 101670155, 155102733, 235475466, 356600473, 544358489, 823267990, 1252422767, 1905019763, 2884495344, 4395578728, 6664003815, 10120728968, 15399671980, 23336176280, 35510270369, 53910176620, 81815473603, 124468314923, 188782747797, 286953569359, 435957594534, 661571265004, 1005994260205, 1526880415010, 2319471517479, 3524816551306, 5349833961887, 8131295132624, 12347625116466, 18751693450833],
-[1, 2, 2, 4, 4, 5, 8, 10, 16, 31, 54, 55, 85, 154, 223, 350, 521, 765, 1210, 1773, 2847, 4277, 6059, 9802, 14773, 22295, 34109, 50401, 79124, 118543, 178789, 277452, 411057, 631643, 963457, 1449351, 2227226, 3340222, 5104082, 7794714, 11706901, 17952856, 27150317, 41177778, 62904543, 94911228, 144875264, 219747922, 332841004, 508233779, 768568321, 1169234669, 1778043066, 2692988656, 4103629139, 6219998258, 9449010918, 14375832183, 21783538454, 33150619717, 50324380740, 76381069275, 116189673802, 176225054909, 267894931998, 406957837979, 617591195431, 939130812446, 1425334453467, 2165326639423, 3290412137932, 4994191833597, 7590852548013, 11526403401314, 17505374204192],
-[1, 2, 2, 4, 4, 4, 8, 8, 13, 25, 48, 51, 72, 121, 179, 289, 390, 609, 969, 1433, 2261, 3301, 4901, 7775, 11606, 17798, 26850, 40232, 62886, 93358, 142447, 218730, 326242, 502448, 758899, 1152160, 1762810, 2644014, 4054833, 6153854, 9293154, 14224018, 21468750, 32699980, 49747856, 75215197, 114856960, 173833902, 264064139, 402295363, 608706182, 927146135, 1407010793, 2134888663, 3250530507, 4925090975, 7490877364, 11381069369, 17261442493, 26266078957, 39847020094, 60538817099, 92017151541, 139601960347, 212277163871, 322272160874, 489385179297, 743902570472, 1128998964162, 1715750242038, 2606026426093, 3956795132179, 6013553470016, 9129812732210, 13869855066510],
-[1, 2, 2, 4, 4, 5, 8, 9, 17, 24, 44, 58, 87, 133, 191, 316, 452, 676, 1040, 1540, 2454, 3727, 5395, 8436, 12704, 19428, 29819, 43974, 68250, 102884, 155807, 240333, 357762, 548596, 834520, 1259790, 1932863, 2904102, 4428962, 6754836, 10182880, 15579230, 23564585, 35767718, 54566843, 82451168, 125737484, 190751153, 289126516, 440890240, 667435805, 1015259501, 1543069654, 2338686091, 3561457756, 5400455908, 8204382429, 12476444194, 18916453251, 28776027064, 43685468598, 66322013966, 100850729937, 153006323401, 232565719429, 353266794225, 536236423645, 815200640651, 1237438411499, 1879894128866, 2856291166944, 4336050969902, 6589611040920, 10006513680924, 15197901393590],
-[1, 2, 2, 4, 4, 4, 8, 8, 14, 27, 54, 54, 74, 137, 200, 331, 436, 675, 1105, 1600, 2581, 3770, 5463, 8723, 13049, 20257, 30399, 44733, 70923, 105633, 160393, 246690, 366122, 567324, 855714, 1295501, 1991751, 2972912, 4566000, 6943851, 10460042, 16043394, 24165774, 36831984, 56122666, 84646370, 129477471, 195883673, 297329550, 453594769, 685488874, 1044816032, 1585599967, 2404021688, 3664381595, 5547960955, 8438869187, 12826915185, 19441808556, 29601121574, 44893877337, 68198337704, 103702139736, 157254151980, 239192258995, 363131550641, 551300552020, 838287357348, 1271920367832, 1933134693339, 2936466834268, 4457566382838, 6776098754362, 10286297506860, 15626477181282],
-[1, 2, 2, 4, 5, 5, 10, 14, 18, 31, 61, 71, 100, 168, 257, 399, 547, 894, 1391, 1960, 3144, 4670, 7006, 10876, 16259, 25362, 37549, 56511, 88770, 131648, 200918, 306675, 461150, 710183, 1065646, 1626314, 2484499, 3724415, 5720975, 8663999, 13115455, 20038349, 30234975, 46169621, 70057041, 106013725, 161961834, 244909321, 372469264, 566759167, 858041092, 1307504014, 1982049040, 3010485092, 4581766921, 6940680073, 10562266592, 16037651382, 24335939789, 37025455153, 56153873940, 85357728304, 129684685412, 196779374764, 299256910005, 454191898171, 689923063041, 1048524149616, 1591345310562, 2418763340716, 3672944036241, 5577769894379, 8476658941169, 12868345211290, 19552208054672],
-[1, 2, 2, 3, 5, 5, 7, 12, 22, 29, 45, 65, 95, 150, 223, 338, 491, 751, 1206, 1811, 2684, 4049, 6185, 9604, 14387, 21708, 33091, 50024, 76992, 116555, 175609, 267719, 405938, 620076, 939734, 1420310, 2168563, 3286992, 4996503, 7597588, 11506453, 17519630, 26580808, 40376198, 61421976, 93075034, 141566838, 215049065, 326378077, 496322966, 752985896, 1144352050, 1738728222, 2638802041, 4011847253, 6090156286, 9250197508, 14057351218, 21339109638, 32427972310, 49246494200, 74787736394, 113646578026, 172548552814, 262143214447, 398195967837, 604681392053, 918742626458, 1395209814225, 2119311610584, 3219454168134, 4889102627788, 7427575006069, 11281041105084, 17134280906977],
-[1, 2, 2, 3, 4, 5, 8, 12, 21, 28, 42, 64, 94, 155, 208, 316, 512, 762, 1175, 1720, 2654, 4114, 6092, 9459, 14176, 21286, 32988, 49613, 75850, 114705, 172885, 266645, 400945, 609626, 930456, 1401830, 2145210, 3247749, 4929053, 7517513, 11348388, 17317440, 26295486, 39822789, 60716986, 91936101, 139848099, 212591557, 322118755, 490596533, 743926466, 1129946835, 1718843772, 2605797088, 3963534917, 6017688416, 9135330356, 13892954463, 21076146448, 32033659918, 48664561116, 73862865549, 112292518084, 170459066208, 258943414868, 393456677256, 597272124436, 907705866306, 1378408320526, 2093417614030, 3180962934445, 4829656600654, 7337755989618, 11145405619985, 16925606933572],
-[1, 2, 2, 3, 4, 6, 10, 14, 21, 32, 52, 77, 108, 179, 272, 397, 633, 922, 1409, 2176, 3234, 5064, 7436, 11287, 17665, 26222, 40254, 60720, 91793, 141820, 211981, 324350, 493359, 741991, 1141241, 1721586, 2615267, 3988210, 6015485, 9204354, 13935827, 21121706, 32245710, 48728774, 74243070, 112777851, 170849553, 260438617, 394425001, 599737648, 912065409, 1381833878, 2103853024, 3191875943, 4846927523, 7372326466, 11178475972, 17000506586, 25816015566, 39184692602, 59590627421, 90417747782, 137398987478, 208752469508, 316850368799, 481660335603, 731228328476, 1110711443644, 1687768464924, 2562116567579, 3893455156899, 5912961049010, 8979801012147, 13644680613385, 20717627047604],
-[1, 2, 2, 3, 4, 5, 8, 14, 24, 29, 50, 74, 101, 178, 265, 388, 591, 869, 1395, 2090, 3095, 4869, 7110, 11040, 17008, 25150, 38905, 58025, 89144, 137020, 203143, 313520, 473972, 717337, 1101631, 1652167, 2528313, 3838035, 5796790, 8889996, 13398137, 20385893, 31067399, 46931739, 71679547, 108516192, 164754417, 251123519, 379784605, 578659394, 878414301, 1331851379, 2028795688, 3073942949, 4674576407, 7103832985, 10770975792, 16393635301, 24869401751, 37776720788, 57435212018, 87119116085, 132475955616, 201141366024, 305399448544, 464292751091, 704580391468, 1070749237729, 1626508289734, 2469263154686, 3753087962201, 5697921417795, 8655743635735, 13150661548255, 19966038526064],
-[1, 2, 2, 3, 4, 5, 8, 13, 25, 27, 42, 76, 111, 167, 241, 384, 585, 843, 1376, 2023, 2972, 4738, 7102, 10837, 16139, 24499, 38558, 56799, 86397, 133014, 199224, 306239, 461452, 702006, 1073064, 1609130, 2472311, 3748143, 5657070, 8658819, 13078278, 19930383, 30278705, 45799257, 69975952, 105851359, 160840390, 244994805, 370720199, 564694686, 856744104, 1300469037, 1979686014, 2999030490, 4562677463, 6931141143, 10513019631, 15996877282, 24266337301, 36873183662, 56038169757, 85020863317, 129290350235, 196261507410, 298058369717, 453056723614, 687584182859, 1044975965846, 1587083341566, 2409850011215, 3662465469306, 5560177230526, 8447358681789, 12832589396939, 19484668488431],
-[1, 2, 2, 3, 4, 5, 7, 10, 19, 22, 35, 52, 80, 121, 161, 267, 413, 607, 899, 1385, 2114, 3170, 4745, 7337, 11191, 16711, 25742, 38766, 58835, 89662, 135667, 207560, 312554, 475607, 726755, 1096377, 1670004, 2535633, 3848398, 5859479, 8868767, 13504579, 20516692, 31085443, 47348135, 71811348, 109081209, 165830203, 251514529, 382639111, 580690559, 881741150, 1340877200, 2034181858, 3091875240, 4696400001, 7129699095, 10837747030, 16450567182, 24994362450, 37973354743, 57646132981, 87608246891, 133033694690, 202057698992, 307000885069, 466135366542, 708230767850, 1075664265119, 1633616896392, 2482013240916, 3769074655674, 5725565112622, 8697138184318, 13208249413793],
-[1, 2, 2, 3, 4, 5, 7, 10, 19, 22, 33, 52, 81, 119, 170, 286, 405, 618, 974, 1429, 2172, 3250, 4989, 7740, 11367, 17437, 26689, 39927, 61507, 92567, 140920, 214853, 322930, 496738, 750084, 1134894, 1737358, 2621175, 3998634, 6067526, 9188321, 14032917, 21210811, 32276698, 49111664, 74320527, 113267805, 171743475, 260842368, 396896259, 601281553, 915195052, 1389405776, 2108257383, 3207896237, 4864979715, 7394962543, 11235230700, 17048676301, 25926919647, 39347848977, 59773187521, 90842069769, 137866008816, 209552882477, 318210059856, 483240302392, 734384963413, 1114856406043, 1693951423286, 2573015494359, 3907050177770, 5936900162226, 9014991048828, 13694273749452],
-[1, 2, 2, 3, 4, 5, 7, 10, 19, 21, 34, 53, 84, 129, 166, 268, 412, 654, 965, 1410, 2187, 3326, 5095, 7658, 11501, 17559, 26774, 40584, 61816, 93201, 141711, 216327, 327476, 498780, 754608, 1146594, 1747151, 2646000, 4027596, 6109623, 9271650, 14114141, 21404151, 32537623, 49413960, 74979509, 114071488, 173104350, 262944584, 399591843, 606412464, 921903021, 1399800137, 2125740879, 3230607385, 4903877733, 7451924284, 11318812990, 17186685562, 26116648356, 39655219275, 60241865397, 91514494184, 138962548612, 211135496988, 320647609934, 487030283407, 739887951997, 1123593371970, 1706916215025, 2592584911750, 3937639321422, 5981840688567, 9084761496566, 13799923912178],
-[1, 2, 2, 3, 4, 5, 8, 13, 23, 30, 44, 66, 104, 170, 229, 364, 573, 822, 1295, 1932, 2929, 4485, 6592, 10467, 15762, 23301, 36198, 54367, 83447, 126548, 189585, 293129, 440077, 669957, 1025906, 1538369, 2356553, 3570947, 5415676, 8273715, 12463168, 19031523, 28920765, 43738830, 66785989, 101043938, 153655976, 233778867, 353921540, 539431879, 817737315, 1241551830, 1890056631, 2863603553, 4356786398, 6615593453, 10038789924, 15274043801, 23164511489, 35209154521, 53499890959, 81173658647, 123442128070, 187367870037, 284600904626, 432531096476, 656447645570, 997763686806, 1515182999702, 2300860794956, 3496730621708, 5308455452912, 8065433802079, 12251419137797, 18603270246431],
-[1, 2, 2, 3, 4, 4, 8, 12, 24, 32, 51, 70, 110, 198, 260, 406, 605, 894, 1531, 2157, 3241, 5005, 7396, 12049, 17481, 26069, 41061, 60520, 94693, 142331, 212369, 331405, 492245, 757717, 1155047, 1721544, 2665691, 4007504, 6095581, 9328981, 13980357, 21476927, 32497801, 49187984, 75332668, 113423761, 173117571, 263119594, 397838957, 607960224, 919002607, 1397745354, 2128044447, 3218892065, 4907939124, 7440751598, 11294897879, 17198788360, 26048115988, 39642598128, 60196785117, 91316618351, 138985942548, 210749723731, 320325134227, 486790278579, 738470489112, 1123189122005, 1704678702541, 2589212912806, 3935606434423, 5972142226921, 9077964184282, 13785796493005, 20932826359521],
-[1, 2, 2, 3, 4, 4, 7, 10, 19, 25, 44, 56, 80, 139, 190, 286, 430, 657, 1041, 1502, 2277, 3544, 5184, 8075, 12218, 18323, 28324, 41956, 64962, 98660, 147330, 228119, 341865, 521144, 796339, 1196046, 1836162, 2771082, 4206059, 6435611, 9687952, 14795086, 22461511, 34001109, 51928688, 78481821, 119470323, 181697511, 274974976, 419335997, 635443177, 965103448, 1468830094, 2225069227, 3387035939, 5140801670, 7801783417, 11872055232, 18000731942, 27366889685, 41576920907, 63085866174, 95946252099, 145601735625, 221201065766, 336162009087, 510150253879, 775494502287, 1177516243878, 1788226607838, 2717657247853, 4125443093744, 6268628035155, 9521375957361, 14458021593159],
-[1, 2, 2, 3, 4, 4, 7, 11, 22, 28, 46, 64, 92, 166, 224, 356, 538, 777, 1265, 1829, 2799, 4321, 6258, 9939, 14998, 22361, 34836, 51302, 79654, 121239, 180471, 280508, 418662, 639711, 980094, 1465092, 2256170, 3402054, 5160730, 7911526, 11880618, 18176894, 27586423, 41709812, 63825212, 96319158, 146682082, 223194249, 337440097, 515167752, 780098632, 1184781728, 1804357572, 2730990489, 4159745278, 6312725805, 9577372738, 14581773271, 22098143513, 33605309114, 51058147802, 77445658844, 117833124702, 178769540160, 271597952056, 412821453000, 626321069330, 952305817179, 1445851548812, 2195592729557, 3337320610374, 5065178447678, 7697378614617, 11691557750799, 17751721430485],
-[1, 2, 2, 3, 4, 4, 7, 10, 18, 23, 36, 47, 73, 135, 160, 250, 410, 632, 962, 1355, 2144, 3342, 4805, 7649, 11499, 17090, 26621, 39364, 61261, 92386, 137784, 215145, 320462, 489781, 749183, 1120697, 1726484, 2600938, 3953640, 6052035, 9078560, 13913690, 21108704, 31917157, 48817143, 73659227, 112279872, 170717027, 258139133, 394221626, 596634530, 906531346, 1380403092, 2089263575, 3182789131, 4828432540, 7327950285, 11156353340, 16903825775, 25712238749, 39058880195, 59249988573, 90151385963, 136755209594, 207800116391, 315813493249, 479149845034, 728599253172, 1106075744895, 1679765936321, 2553188914898, 3874972637903, 5889056506816, 8944223715706, 13580945144041],
-[1, 2, 2, 3, 4, 5, 10, 12, 19, 29, 46, 68, 101, 161, 217, 331, 535, 786, 1195, 1867, 2762, 4200, 6338, 9743, 14919, 21933, 34056, 51843, 77826, 119394, 179723, 274856, 416817, 629659, 966359, 1455572, 2211831, 3377329, 5102355, 7779852, 11788004, 17900312, 27285475, 41252829, 62839478, 95459215, 144659437, 220350404, 334011544, 507738817, 771679357, 1170033817, 1780823902, 2701900608, 4103046873, 6239344612, 9464533486, 14389413671, 21851180816, 33174848302, 50435496056, 76540982046, 116308558929, 176696192642, 268233048546, 407681721221, 618991790061, 940244909265, 1428562983994, 2168921607767, 3295676717182, 5005174624954, 7601549836009, 11549506216840, 17537757774212],
-[1, 2, 2, 3, 4, 4, 8, 12, 23, 30, 49, 71, 105, 183, 243, 364, 585, 858, 1380, 1985, 2981, 4760, 6867, 10809, 16335, 24146, 38075, 56176, 86511, 132241, 195994, 305766, 457793, 694913, 1068992, 1594917, 2456095, 3715619, 5613083, 8623880, 12946273, 19783590, 30102121, 45405686, 69534084, 105000981, 159677990, 243377579, 367552888, 561115735, 850422752, 1290112995, 1966626961, 2975628277, 4530533271, 6880862655, 10431626983, 15888781264, 24080531450, 36603050894, 55644405764, 84369218453, 128377303222, 194809798881, 295853652886, 449842603077, 682389053860, 1037474659611, 1575526722782, 2391884149730, 3636285608353, 5518893059926, 8385803016255, 12739534311112, 19340036441914],
-[1, 2, 2, 3, 4, 4, 8, 12, 22, 27, 44, 69, 95, 163, 218, 331, 539, 796, 1265, 1807, 2703, 4346, 6339, 9838, 14847, 22037, 34727, 51445, 78819, 120530, 178912, 278481, 418334, 633668, 973534, 1455702, 2239033, 3390690, 5118674, 7857621, 11815892, 18034968, 27450386, 41422521, 63374786, 95786401, 145599680, 221919269, 335288334, 511491899, 775654235, 1176567899, 1793047980, 2714144903, 4130763667, 6275011017, 9513720198, 14486915425, 21962790694, 33376624880, 50741068198, 76946186447, 117056830423, 177662354233, 269792597589, 410197523639, 622333659955, 946029696762, 1436781450646, 2181255500253, 3315819971518, 5033035617766, 7646954810000, 11617356384785, 17637036885251],
-[1, 2, 2, 3, 4, 4, 7, 10, 19, 27, 46, 59, 84, 145, 210, 322, 470, 707, 1152, 1680, 2537, 3893, 5731, 8986, 13538, 20503, 31499, 46613, 72351, 109896, 164637, 253473, 380411, 581810, 886358, 1333473, 2046759, 3085992, 4689499, 7167636, 10803188, 16493311, 25006860, 37918315, 57871997, 87452430, 133184003, 202415432, 306562170, 467309355, 708059517, 1075987315, 1636517457, 2479989381, 3775279075, 5728433184, 8696602111, 13229535841, 20062021171, 30503971250, 46329827933, 70316976214, 106929262903, 162263525002, 246551477025, 374618815467, 568588511719, 864293679633, 1312260453797, 1993122820987, 3028676200817, 4597813858662, 6986585966366, 10611053366801, 16114079316970],
-[1, 2, 2, 3, 4, 4, 7, 10, 20, 27, 43, 57, 82, 148, 201, 319, 473, 710, 1133, 1622, 2566, 3848, 5617, 9027, 13402, 20257, 31079, 46136, 72360, 108096, 163019, 252540, 375417, 578297, 878291, 1320772, 2032097, 3050221, 4662123, 7105936, 10682524, 16387155, 24766759, 37598198, 57394776, 86589107, 132215677, 200500097, 303841184, 463714138, 701189152, 1067234151, 1622324080, 2457611476, 3744807094, 5675281536, 8623637798, 13117271817, 19878869498, 30253228807, 45917151574, 69705730128, 106026089491, 160807673641, 244476310645, 371339032593, 563596765498, 856993378812, 1300619740650, 1976032740317, 3002512372260, 4557403231387, 6927049200772, 10517949780664, 15974712391300],
-[1, 2, 2, 3, 4, 4, 7, 11, 21, 25, 42, 63, 83, 135, 196, 319, 467, 672, 1106, 1629, 2418, 3813, 5637, 8646, 13027, 19721, 30732, 45126, 69322, 106680, 159080, 244825, 368538, 561388, 857801, 1286349, 1977349, 2991673, 4521466, 6927286, 10449325, 15927895, 24193307, 36607877, 55941936, 84566280, 128576743, 195799419, 296210077, 451429640, 684662564, 1039359265, 1582167730, 2396581815, 3647231614, 5538886235, 8401757674, 12786073492, 19391948659, 29471233643, 44785684182, 67947053426, 103336070547, 156842888020, 238219657137, 362093035975, 549494436030, 835191082349, 1268377005654, 1925975680812, 2927129707204, 4443587712956, 6751389384194, 10255791480560, 15572136536890],
-[1, 2, 2, 3, 4, 4, 8, 13, 25, 30, 49, 78, 109, 178, 254, 402, 613, 861, 1416, 2120, 3073, 4878, 7251, 11217, 16886, 25095, 39635, 58609, 89180, 137733, 204774, 316167, 476508, 722508, 1110116, 1658843, 2547611, 3869918, 5830101, 8945274, 13485016, 20542037, 31275025, 47204872, 72209509, 109230506, 165811981, 252896019, 382267463, 582599784, 884154508, 1340750809, 2043074319, 3093595265, 4706052012, 7152410707, 10841738155, 16505388528, 25033981276, 38030878918, 57823887873, 87692092692, 133378984804, 202485654655, 307428626540, 467440209575, 709264822257, 1077964770245, 1637411563015, 2485717236084, 3778471641732, 5735886943025, 8713812336054, 13239174701601, 20099161873850],
-[1, 2, 2, 3, 5, 5, 9, 16, 27, 30, 49, 86, 124, 183, 269, 429, 643, 945, 1510, 2234, 3270, 5159, 7880, 11866, 17800, 27036, 42025, 62611, 95101, 146117, 219289, 335157, 508608, 772608, 1176287, 1772641, 2713040, 4120153, 6224806, 9502151, 14394842, 21886408, 33267716, 50403814, 76816061, 116404049, 176784679, 269126977, 407781840, 620234977, 941844666, 1429707294, 2174600243, 3298001953, 5013521259, 7617237845, 11558238490, 17575348904, 26678367559, 40524316346, 61580439292, 93470893518, 142067770503, 215729034360, 327611651750, 497866372516, 755848148765, 1148388002218, 1744351692283, 2648871818491, 4024833769343, 6111736189798, 9284073446390, 14103548774057, 21417081016199],
-[1, 2, 2, 3, 4, 7, 11, 12, 18, 34, 51, 73, 108, 159, 240, 376, 600, 886, 1255, 2004, 3109, 4662, 7025, 10465, 16289, 24742, 37306, 57162, 85514, 130642, 199863, 301910, 459929, 693903, 1057771, 1614757, 2435825, 3713494, 5633184, 8545244, 13026538, 19720181, 30008906, 45565201, 69089342, 105268159, 159555590, 242398928, 368542293, 558940902, 850354406, 1290471268, 1959690004, 2979678807, 4520247400, 6871930695, 10437025068, 15843839691, 24085109145, 36558614872, 55547578140, 84387191781, 128108377778, 194700190750, 295636183838, 449043988035, 682265998188, 1035896833764, 1573934265592, 2390459146184, 3630478216293, 5515881872661, 8376024362167, 12724256315553, 19327885581031],
-[1, 2, 3, 4, 6, 11, 12, 16, 30, 44, 68, 93, 156, 245, 334, 557, 838, 1209, 1891, 2832, 4460, 6574, 9811, 15635, 22956, 34985, 53882, 80459, 124093, 186064, 284227, 435716, 648908, 1000014, 1517087, 2288222, 3506307, 5282104, 8069040, 12253869, 18505711, 28339994, 42803468, 65049281, 99147712, 149893376, 228579213, 346480642, 525939436, 801296189, 1212678413, 1846075494, 2804135419, 4251798033, 6473209240, 9814319800, 14917505102, 22672558399, 34385168647, 52312944077, 79389785831, 120567802852, 183301170289, 278111315426, 422770002468, 642027323445, 974791539730, 1481762630658, 2249091813839, 3417328313733, 5191424610148, 7881689404483, 11977967222946, 18187498985115, 27626430719574],
-[1, 2, 3, 4, 4, 7, 12, 21, 25, 36, 69, 93, 147, 218, 331, 542, 745, 1180, 1849, 2643, 4167, 6177, 9485, 14719, 21457, 33642, 50596, 75828, 118295, 175522, 269308, 410012, 615400, 951664, 1424575, 2169786, 3322930, 4985322, 7650313, 11568438, 17533122, 26819000, 40401708, 61711405, 93694170, 141709821, 216531580, 327406097, 498066730, 757733873, 1146755281, 1748479205, 2650068230, 4024190798, 6125901988, 9278886880, 14122007902, 21440727650, 32533708630, 49506091979, 75068361764, 114116685458, 173390864958, 263068863397, 400097605834, 607213015784, 922384569433, 1401857793343, 2127426798803, 3233814166828, 4910523161518, 7456942237557, 11333033628399, 17203878634537, 26140209355717],
-[1, 2, 3, 4, 4, 7, 12, 16, 22, 39, 61, 79, 133, 195, 282, 449, 693, 1077, 1548, 2321, 3723, 5522, 8332, 12701, 19145, 29641, 44457, 67698, 103303, 154438, 238241, 361408, 545177, 832895, 1257062, 1922258, 2916321, 4406296, 6739422, 10187166, 15490610, 23589127, 35687612, 54390036, 82445223, 125222384, 190672159, 288663473, 439349033, 667261458, 1012222495, 1540437913, 2335863868, 3550669905, 5395317396, 8184958469, 12450017798, 18894433272, 28697933249, 43621457355, 66196208951, 100620985680, 152796147696, 232019254522, 352643689321, 535303534805, 813352907272, 1235515498083, 1875955113926, 2850718789939, 4328666990079, 6575364361102, 9989257389164, 15168245266397, 23045869164536],
-[1, 2, 2, 4, 4, 6, 9, 13, 20, 28, 43, 59, 90, 152, 226, 312, 485, 756, 1145, 1727, 2545, 3983, 6032, 9085, 13977, 20791, 31935, 48708, 73387, 112338, 168944, 258086, 393502, 592435, 906478, 1372216, 2081973, 3175832, 4799908, 7318584, 11102716, 16828070, 25665727, 38852292, 59085307, 89820277, 136134695, 207294593, 314347282, 477538746, 726226023, 1101055732, 1674917652, 2542904061, 3860288486, 5870070779, 8906191616, 13536747653, 20562651340, 31212161276, 47449919164, 72028296955, 109420522358, 166256043127, 252387913023, 383555200675, 582449945099, 884614287360, 1344128575561, 2040814548443, 3100646881169, 4709553584644, 7152144824593, 10866552434087, 16501738593083],
-[1, 2, 2, 4, 5, 7, 9, 14, 19, 30, 47, 62, 95, 137, 229, 343, 461, 739, 1163, 1776, 2674, 3934, 6121, 9305, 13963, 21573, 32149, 48985, 75110, 113144, 173429, 260606, 396421, 607517, 913589, 1395758, 2116847, 3205679, 4896828, 7400225, 11273673, 17126742, 25918695, 39555028, 59920656, 91017394, 138483338, 209793328, 319456557, 484692969, 735731495, 1119550919, 1697148934, 2580868576, 3920470696, 5948849933, 9047679963, 13728394410, 20860243518, 31698685717, 48103538088, 73130305281, 111030339338, 168627695291, 256267911144, 389004024966, 591132714680, 897805266994, 1363348438281, 2071744172884, 3145588249120, 4778704677358, 7259210258943, 11023222269523, 16748412462938],
-[1, 2, 2, 4, 4, 6, 8, 9, 14, 20, 39, 50, 80, 110, 165, 270, 384, 627, 875, 1325, 2168, 3167, 4833, 7149, 10787, 17198, 25295, 38428, 58758, 87663, 136702, 204816, 309939, 475832, 711668, 1097434, 1660027, 2502606, 3842451, 5780844, 8832019, 13428685, 20258979, 31013533, 46856033, 71250080, 108589782, 164022333, 250266279, 379445568, 575736015, 877371662, 1327685926, 2021181967, 3070120738, 4654719667, 7088379865, 10744733396, 16330837043, 24825592512, 37645903039, 57275826520, 86919572303, 132000739463, 200699435540, 304478244798, 462875878727, 702949325955, 1067211325199, 1622353531273, 2462423343458, 3741464576074, 5683948805148, 8629098856000, 13114384786108],
-[1, 2, 2, 4, 4, 7, 8, 10, 16, 19, 35, 55, 95, 118, 177, 285, 416, 690, 987, 1519, 2321, 3422, 5446, 8023, 11981, 18724, 27949, 43195, 65183, 97600, 151342, 226276, 346115, 528399, 791101, 1217610, 1838085, 2792040, 4265931, 6415186, 9821545, 14891293, 22540790, 34448319, 52016049, 79241125, 120471277, 182335298, 278163579, 421161183, 640052623, 974170854, 1475145024, 2246364750, 3408392531, 5173625749, 7873269100, 11934316045, 18150104788, 27568386425, 41829885571, 63630455398, 96540421496, 146688093998, 222910753166, 338266003473, 514282013039, 780769479892, 1185803151269, 1802161057329, 2735464324696, 4156979072907, 6313563206794, 9587159901903, 14568964553083],
-[1, 2, 2, 4, 4, 5, 8, 9, 17, 26, 48, 61, 92, 157, 217, 360, 507, 778, 1252, 1769, 2815, 4200, 6241, 9958, 14563, 22374, 34147, 50679, 79684, 118391, 179577, 277238, 412299, 637616, 962062, 1452023, 2235819, 3347494, 5130128, 7798538, 11743359, 18024282, 27177299, 41363314, 63052762, 95106055, 145408633, 220176453, 334048440, 509563530, 770186851, 1173582594, 1781976472, 2700880168, 4116232803, 6234179810, 9479961984, 14412229810, 21843912920, 33253649704, 50443969371, 76615190528, 116512379684, 176689039571, 268709386587, 408004685718, 619382356462, 941806522127, 1429087570583, 2171773083096, 3299248494183, 5008145980251, 7612772139206, 11557229260977, 17556008307277],
-[1, 2, 2, 4, 4, 6, 9, 9, 18, 30, 50, 62, 100, 153, 227, 365, 517, 833, 1240, 1823, 2915, 4303, 6507, 9948, 14923, 23293, 34733, 52448, 81040, 120896, 185930, 282062, 424790, 652535, 980990, 1499703, 2281991, 3435005, 5265591, 7960851, 12091461, 18435982, 27840900, 42496495, 64431455, 97702898, 148999019, 225400303, 343064619, 521344316, 790152916, 1203472157, 1824134476, 2772251634, 4215485953, 6390441001, 9723721254, 14757946474, 22407846424, 34075542844, 51690704496, 78581999243, 119348486390, 181169606892, 275443375285, 418055242564, 635168253810, 965024196732, 1464937905182, 2226478438556, 3380681733207, 5134857785168, 7802092066450, 11845532252960, 17998451423253],
-[1, 2, 2, 4, 4, 4, 8, 8, 14, 28, 53, 56, 84, 153, 217, 350, 483, 714, 1176, 1717, 2723, 4023, 5798, 9464, 14033, 21315, 32584, 47970, 75729, 113134, 171076, 264779, 391355, 605467, 919613, 1382467, 2129263, 3186079, 4879904, 7440876, 11175442, 17164972, 25895074, 39342521, 60102162, 90575815, 138432209, 209763614, 317910148, 485521733, 733546800, 1117134762, 1697786594, 2571301019, 3920330454, 5938192235, 9025308246, 13728854241, 20799478263, 31667200766, 48049832028, 72946096765, 110972314254, 168265940718, 255876076572, 388614760443, 589781502101, 896953926154, 1361023191801, 2068043273881, 3142304962074, 4769151457871, 7249816838081, 11006930931237, 16717965383257],
-[1, 2, 2, 4, 4, 4, 8, 10, 19, 30, 52, 63, 96, 172, 230, 358, 548, 804, 1292, 1881, 2892, 4429, 6416, 10328, 15447, 22945, 35720, 53014, 82314, 124178, 186042, 289367, 430761, 658930, 1008482, 1510178, 2322664, 3500130, 5322709, 8142900, 12228600, 18730560, 28400140, 42969183, 65708043, 99184254, 151135561, 229728880, 347581432, 530622618, 803137177, 1220423765, 1857882482, 2812704102, 4284228440, 6499539609, 9865309630, 15015810517, 22755794922, 34612670532, 52574914189, 79762704022, 121347714676, 184091876274, 279731399818, 425100835652, 645024269956, 980758715396, 1488891310630, 2261238636950, 3436784318322, 5216286617398, 7927337742398, 12039803711687, 18282038351446],
-[1, 2, 2, 4, 4, 5, 8, 9, 16, 29, 54, 62, 88, 151, 221, 382, 522, 759, 1237, 1808, 2930, 4215, 6182, 10129, 14860, 22821, 34528, 51226, 81024, 119857, 182750, 281729, 416840, 647341, 976509, 1474960, 2269896, 3390435, 5211614, 7913851, 11911794, 18309053, 27555466, 41980434, 63999281, 96489987, 147653214, 223299168, 338999520, 517324135, 781282166, 1191311520, 1808109935, 2740606356, 4178288999, 6324826401, 9622090470, 14625593877, 22163559635, 33753517791, 51184332011, 77752196620, 118244955818, 179283174191, 272725208013, 414020623510, 628542497553, 955834092989, 1450114227618, 2204076258960, 3348094295711, 5082116177983, 7726004715713, 11727743119346, 17816465685388],
-[1, 2, 2, 4, 4, 4, 8, 8, 12, 23, 41, 45, 68, 110, 153, 257, 358, 527, 827, 1275, 2004, 2803, 4217, 6873, 10166, 15260, 23196, 35127, 54343, 80768, 123949, 189632, 281964, 435563, 660382, 998380, 1523726, 2294872, 3520249, 5330471, 8053174, 12335372, 18618373, 28334179, 43127387, 65250928, 99526446, 150662658, 229010669, 348774196, 527664223, 803640049, 1219963600, 1851000984, 2817370104, 4270188737, 6494407369, 9865606772, 14965178170, 22770688210, 34545982699, 52481471230, 79768839453, 121035856176, 184022727478, 279381945781, 424279285074, 644896160675, 978783053862, 1487419802282, 2259242117632, 3430352351814, 5213179923802, 7915044451594, 12024315413041],
-[1, 2, 2, 4, 4, 5, 8, 9, 15, 28, 49, 54, 87, 138, 199, 341, 482, 690, 1098, 1671, 2598, 3785, 5608, 8962, 13331, 20359, 31104, 46166, 71496, 107527, 164161, 251248, 373835, 576455, 874892, 1321251, 2022722, 3042561, 4651211, 7069417, 10676989, 16338974, 24671781, 37514813, 57179045, 86436686, 131816429, 199768032, 303261215, 462078693, 699320396, 1064559459, 1616779351, 2451750990, 3732957052, 5659173646, 8602054232, 13073569431, 19827742647, 30166192620, 45778330582, 69525317571, 105697261114, 160366486466, 243790461497, 370214604891, 562101527211, 854438942481, 1296900080735, 1970584657521, 2993548089221, 4544871396246, 6906984645209, 10487504159448, 15930517586438],
-[1, 2, 2, 4, 4, 4, 8, 8, 12, 24, 48, 48, 68, 118, 172, 294, 386, 572, 940, 1390, 2216, 3190, 4690, 7616, 11236, 17260, 25990, 38406, 60912, 90684, 137646, 211168, 313392, 487578, 734814, 1108532, 1705958, 2550668, 3917658, 5951482, 8962900, 13758838, 20714724, 31572344, 48129786, 72556704, 110978958, 167925556, 254931984, 388903238, 587522738, 895710142, 1359454316, 2060756194, 3141496446, 4756090372, 7234378008, 10996358824, 16666426878, 25377869440, 38485719172, 58462321780, 88905446944, 134808539170, 205054406174, 311304276126, 472610304846, 718661345606, 1090368761486, 1657233212020, 2517400068576, 3821296133944, 5809050822036, 8818197692290, 13396159742174],
-[1, 2, 2, 4, 4, 5, 8, 8, 14, 24, 44, 51, 79, 125, 180, 298, 401, 627, 981, 1451, 2317, 3383, 5047, 7946, 11783, 18277, 27478, 40962, 64230, 95541, 145977, 222935, 332913, 515310, 774840, 1176324, 1802254, 2701709, 4147094, 6283610, 9499738, 14545178, 21919983, 33437748, 50844588, 76841220, 117413924, 177601651, 269951067, 411117339, 621889627, 947896974, 1437630868, 2181747240, 3322461165, 5032414810, 7656731523, 11630026396, 17640319255, 26847179507, 40715831783, 61874466321, 94040764390, 142658665671, 216962240334, 329328888105, 500154774126, 760271238270, 1153727738265, 1753578034645, 2663202322961, 4043698583304, 6145951131610, 9330008932630, 14175113365321],
-[1, 2, 2, 4, 4, 4, 8, 8, 16, 31, 61, 62, 88, 175, 246, 395, 541, 820, 1384, 1935, 3121, 4569, 6553, 10931, 16011, 24346, 37117, 54598, 87367, 128913, 194637, 303391, 446171, 693579, 1050078, 1577129, 2438918, 3631311, 5582948, 8510990, 12742880, 19640820, 29566960, 44963665, 68727775, 103353605, 158344819, 239626509, 363088741, 555256831, 837545274, 1276920059, 1940170948, 2936840236, 4482233395, 6782212360, 10313387377, 15691710903, 23756941547, 36196565227, 54896473680, 83344615307, 126833634175, 192217070993, 292427346050, 444049711872, 673816124358, 1025106255374, 1554946635965, 2363156832854, 3590768058598, 5448799713441, 8285039296997, 12576245622884, 19102528051595],
-[1, 2, 2, 4, 4, 4, 8, 8, 13, 25, 49, 50, 74, 132, 181, 285, 399, 609, 975, 1417, 2251, 3279, 4713, 7764, 11576, 17382, 26480, 39201, 62117, 92220, 139217, 216234, 319114, 493712, 750103, 1127602, 1735639, 2595724, 3982121, 6069038, 9104178, 13994555, 21116926, 32077311, 48996258, 73833324, 112884175, 170993748, 259154268, 395915726, 597968543, 910714762, 1384180613, 2096283240, 3196297989, 4840672243, 7358135573, 11193230920, 16955705588, 25817995220, 39172878228, 59468877257, 90473150076, 137175947165, 208611515751, 316819287103, 480811370095, 731273066331, 1109565657735, 1685990263254, 2561810188757, 3888031158622, 5910566645835, 8973393083407, 13629436047096],
-[1, 2, 2, 4, 5, 6, 10, 16, 18, 27, 52, 64, 92, 144, 233, 365, 490, 799, 1211, 1786, 2794, 4097, 6360, 9627, 14400, 22625, 33398, 50704, 78390, 116841, 180026, 271401, 411321, 631853, 945120, 1451150, 2204331, 3319251, 5090827, 7681351, 11700287, 17806494, 26878996, 41110414, 62209750, 94425973, 143986071, 217687711, 331706317, 503473411, 763524566, 1163269295, 1761620058, 2679613996, 4072743676, 6173893613, 9397865198, 14255194417, 21655596202, 32925229179, 49935690097, 75946313567, 115300070338, 175057748999, 266163206492, 403880872107, 613805611799, 932368921493, 1415438562799, 2151498860466, 3266170047413, 4961800886470, 7538586473556, 11445004558370, 17391916485436],
-[1, 2, 2, 4, 5, 5, 9, 12, 18, 34, 58, 66, 102, 173, 252, 394, 560, 868, 1336, 2022, 3152, 4570, 6896, 10874, 16392, 24849, 37293, 56670, 87626, 131092, 200080, 304674, 458223, 703310, 1064242, 1616699, 2459404, 3712733, 5682494, 8609619, 13041543, 19893323, 30100311, 45830805, 69615013, 105522335, 160750639, 243524716, 370247393, 563086729, 853356948, 1298534825, 1970681694, 2992821672, 4550969916, 6902220362, 10495178964, 15938416263, 24193810715, 36786639003, 55827969214, 84826859290, 128873645699, 195634216521, 297355788378, 451462206305, 685744259229, 1041965157908, 1581900876324, 2403689765729, 3650593080326, 5544160703056, 8423758771853, 12791135033375, 19432076595574],
-[1, 2, 2, 3, 4, 5, 8, 13, 23, 27, 40, 67, 102, 153, 213, 329, 537, 782, 1216, 1842, 2675, 4305, 6413, 9678, 14770, 21938, 34614, 51706, 77652, 120166, 179261, 275862, 418306, 629808, 968827, 1454145, 2222034, 3388598, 5094665, 7807362, 11807069, 17930066, 27357910, 41277627, 63027540, 95592964, 144851087, 221032163, 334323729, 508766487, 773321633, 1171704112, 1785208763, 2705584110, 4111001858, 6253165226, 9477400553, 14421709251, 21889776217, 33231534657, 50543390062, 76662519963, 116546799122, 177025896325, 268684538841, 408538251162, 620048684181, 942024166558, 1431346816991, 2172659349478, 3302256869283, 5014177630117, 7615558753220, 11572091656604, 17568595426649],
-[1, 2, 2, 3, 4, 5, 8, 11, 18, 27, 39, 54, 80, 116, 178, 271, 421, 618, 920, 1451, 2172, 3305, 4912, 7455, 11620, 17317, 26473, 40123, 60468, 93006, 140203, 213318, 324236, 489375, 750116, 1134535, 1719661, 2623451, 3966200, 6046564, 9175390, 13908232, 21200967, 32092270, 48826919, 74229535, 112476798, 171245238, 259727865, 394592441, 600018862, 909749045, 1383874866, 2100946797, 3189526110, 4850184511, 7358758473, 11184168928, 16989158468, 25789441319, 39204859203, 59511532796, 90407068208, 137366017859, 208532752803, 316905422731, 481240541052, 730901599881, 1110554296190, 1686197233717, 2561871384414, 3891179102610, 5909350835319, 8978297033457, 13634330983118],
-[1, 2, 2, 3, 4, 5, 7, 11, 17, 25, 39, 48, 71, 111, 163, 249, 412, 579, 858, 1313, 2003, 3168, 4482, 6920, 10808, 15956, 24776, 36748, 56124, 86564, 128569, 198854, 299519, 451813, 697109, 1045140, 1597368, 2426273, 3659992, 5619644, 8465599, 12879679, 19646307, 29631652, 45288527, 68597573, 104079254, 158711538, 239897197, 365632841, 555177271, 841285444, 1282160513, 1942214715, 2953177083, 4489431803, 6804822447, 10359025888, 15713853339, 23866400153, 36296003582, 55041913103, 83703956869, 127099702598, 192949899255, 293383663709, 445179230419, 676536223019, 1027767981902, 1560107046532, 2371468959565, 3600266505980, 5468930017716, 8309586838643, 12615178256850],
-[1, 2, 2, 3, 4, 5, 8, 11, 20, 25, 43, 64, 92, 147, 208, 325, 483, 708, 1161, 1731, 2519, 3990, 5911, 9122, 13769, 20512, 32317, 47728, 72845, 112223, 166996, 257778, 388223, 589282, 904337, 1353261, 2077672, 3152212, 4754650, 7292127, 10993144, 16748362, 25486882, 38493488, 58862954, 89032055, 135209659, 206119991, 311641770, 474975399, 720690698, 1093158805, 1665333269, 2521969240, 3836801952, 5830009578, 8839076065, 13455090814, 20407255376, 31005385624, 47135448938, 71490731865, 108732576026, 165061137233, 250634039658, 381048897877, 578204216007, 878788633802, 1334791172596, 2026452964664, 3080208538779, 4675932658239, 7103769828396, 10792457887396, 16385339395347],
-[1, 2, 2, 3, 4, 5, 8, 13, 24, 28, 45, 71, 109, 183, 258, 380, 591, 901, 1403, 2047, 3094, 4857, 7234, 11126, 16810, 25170, 38931, 58531, 89534, 136050, 203951, 314431, 475030, 720302, 1099496, 1657080, 2534559, 3841801, 5820373, 8888067, 13424001, 20447956, 31093481, 47073930, 71756051, 108695920, 165221721, 251370423, 380744863, 579691074, 879645333, 1335304144, 2031642464, 3080409528, 4684030958, 7114204690, 10796509897, 16420761715, 24915738573, 37857168022, 57525869471, 87305489465, 132719173862, 201500484141, 306041630558, 465078764735, 705988443614, 1072818572027, 1629382570013, 2474306465044, 3759830017091, 5708817222982, 8672723274620, 13174236236940, 20005656360333],
-[1, 2, 2, 3, 4, 5, 8, 12, 23, 28, 45, 72, 110, 169, 237, 375, 560, 830, 1359, 1989, 2908, 4547, 6859, 10585, 15802, 23811, 37043, 55135, 84654, 128983, 193007, 296955, 448175, 683227, 1040308, 1563540, 2399995, 3633692, 5502816, 8405796, 12692098, 19352516, 29384203, 44511632, 67916849, 102735628, 156258932, 237749302, 360035208, 548352350, 831606686, 1263038202, 1921574496, 2912290398, 4430820615, 6727532252, 10209765803, 15530817080, 23559925951, 35806706076, 54400006111, 82559574512, 125530372111, 190541594157, 289435246565, 439841969332, 667618650446, 1014645094074, 1540842254202, 2339995465630, 3555843004179, 5398560150938, 8202235961765, 12458858343659, 18919206125740],
-[1, 2, 2, 3, 4, 5, 7, 9, 16, 21, 33, 48, 77, 113, 158, 244, 361, 569, 875, 1287, 1938, 3050, 4585, 6882, 10451, 15907, 24355, 36647, 55904, 85034, 128143, 195725, 297254, 450878, 685019, 1038109, 1583227, 2401029, 3639754, 5544377, 8404531, 12773572, 19412632, 29454600, 44797469, 67956302, 103268475, 156988591, 238124583, 362016812, 549765365, 834825984, 1268799750, 1925836676, 2926647806, 4445202373, 6749168065, 10257483379, 15573932779, 23657479643, 35940712553, 54573901284, 82920651188, 125926125525, 191266143092, 290582241208, 441257624281, 670352444258, 1018193897790, 1546395727499, 2349249108999, 3567814111140, 5419610874789, 8232272258220, 12502848256137],
-[1, 2, 2, 3, 4, 5, 7, 9, 16, 20, 30, 47, 76, 114, 154, 249, 374, 577, 872, 1284, 1993, 3017, 4562, 7029, 10520, 15878, 24292, 36839, 56382, 84666, 128546, 196976, 296922, 452887, 687019, 1041180, 1587694, 2402208, 3659422, 5557015, 8414002, 12829000, 19451735, 29543982, 44922912, 68104025, 103651016, 157289260, 238800221, 363241552, 550865255, 837462508, 1272161502, 1930896387, 2935704026, 4455380054, 6769703931, 10285973981, 15612324345, 23730229596, 36032111343, 54725690725, 83156998257, 126248379581, 191829405440, 291349422133, 442453712466, 672286955258, 1020840476385, 1550780625727, 2355689253507, 3577391145298, 5435026561370, 8254183029921, 12537643387713],
-[1, 2, 2, 3, 4, 4, 7, 9, 16, 22, 38, 47, 69, 115, 151, 244, 377, 566, 837, 1256, 1979, 2943, 4368, 6822, 10278, 15447, 23774, 35872, 54730, 82331, 125347, 192915, 288494, 439891, 671040, 1014446, 1547335, 2338969, 3563702, 5420018, 8190541, 12506080, 18959367, 28753718, 43792999, 66356043, 100985454, 153268659, 232542280, 354073749, 536678070, 815723093, 1239904071, 1880752422, 2860471478, 4341222946, 6594674883, 10023657697, 15208362801, 23120732299, 35110875774, 53310062583, 81028498516, 122999620507, 186890830882, 283888018084, 431038483034, 655046259042, 994608459810, 1510825837532, 2295323130993, 3485267834593, 5295369849541, 8042253929070, 12214817846493],
-[1, 2, 2, 3, 4, 5, 10, 13, 24, 36, 56, 82, 118, 204, 296, 434, 664, 1005, 1596, 2335, 3522, 5498, 8150, 12545, 19039, 28618, 44119, 66128, 101231, 154234, 231266, 355886, 537354, 815177, 1245355, 1877182, 2870128, 4348443, 6588219, 10065603, 15203787, 23151451, 35198186, 53297977, 81256335, 123080796, 187069205, 284605171, 431097021, 656373471, 996028649, 1511921329, 2300289260, 3487795126, 5303664207, 8055312331, 12224397223, 18592451314, 28211296934, 42864555417, 65134734867, 98852952689, 150272966325, 228151717228, 346520731915, 526594508994, 799366318802, 1214711820198, 1844894423027, 2801576383433, 4257128499164, 6463894411109, 9819820245058, 14916742405311, 22651718779746],
-[1, 2, 2, 3, 4, 4, 8, 12, 24, 32, 53, 76, 105, 180, 263, 421, 619, 886, 1477, 2150, 3272, 5052, 7356, 11697, 17492, 26324, 40862, 60160, 93668, 142167, 212208, 328971, 491598, 752441, 1148717, 1720513, 2651300, 3993364, 6062567, 9283778, 13955690, 21354447, 32369422, 49004803, 74941243, 113079728, 172308281, 262010259, 396346749, 604923204, 915870201, 1391749360, 2118346122, 3207062656, 4885315038, 7411686192, 11248452348, 17121633716, 25949259000, 39466796595, 59948391052, 90952369747, 138369528396, 209913878555, 318962933580, 484736061012, 735507852955, 1118304427883, 1697749890250, 2578429445518, 3918835353605, 5947975859380, 9039258538837, 13728686093970, 20846365418484],
-[1, 2, 2, 3, 4, 4, 8, 11, 20, 28, 49, 69, 92, 156, 218, 352, 562, 793, 1241, 1837, 2845, 4455, 6350, 9929, 15183, 22726, 35365, 52142, 80207, 122746, 183088, 284096, 425170, 645439, 992171, 1486777, 2282604, 3449659, 5220131, 8008986, 12046603, 18390129, 27959609, 42231478, 64590231, 97636265, 148475193, 226078343, 341776498, 521438377, 790506911, 1199533408, 1827200813, 2766562937, 4210982644, 6394932557, 9698818059, 14765186600, 22384888240, 34024454920, 51714399308, 78435813432, 119312624088, 181076764868, 275022370523, 418080785484, 634346549437, 964301612753, 1464406160011, 2223444969929, 3379676016118, 5130071560055, 7794681902214, 11840895774769, 17977720226795],
-[1, 2, 2, 3, 4, 4, 7, 10, 19, 27, 43, 60, 83, 146, 210, 334, 479, 705, 1157, 1678, 2560, 3926, 5800, 9052, 13560, 20619, 31809, 46888, 72667, 110492, 165713, 255472, 382678, 585805, 891895, 1340762, 2061543, 3105976, 4718920, 7212565, 10869838, 16608707, 25163343, 38152461, 58255743, 87995123, 134054475, 203719874, 308492889, 470363854, 712490984, 1082968102, 1647142605, 2495516686, 3799816871, 5764959743, 8752271071, 13315104074, 20188960772, 30701258661, 46626241202, 70765140616, 107621140035, 163295757433, 248135009015, 377025130210, 572215558986, 869864781061, 1320638350630, 2005897504265, 3048150993281, 4627157983145, 7031506008172, 10679004682182, 16217215682850],
-[1, 2, 2, 3, 4, 4, 7, 9, 16, 23, 40, 48, 70, 112, 167, 257, 366, 550, 859, 1323, 1992, 3006, 4464, 6904, 10650, 15883, 24192, 36429, 55863, 85146, 127926, 195661, 295801, 449651, 686289, 1036685, 1579101, 2394501, 3634364, 5542534, 8385233, 12751374, 19384668, 29388616, 44749928, 67849444, 103077084, 156733609, 237660848, 361579783, 548850554, 833210535, 1267002891, 1922395911, 2922047535, 4438158205, 6737101109, 10241981820, 15546680506, 23618919100, 35885398912, 54476830594, 82789662909, 125718037072, 190945563304, 290123630758, 440498717676, 669273902598, 1016531093918, 1543778526162, 2345512571831, 3561821123405, 5410694855928, 8218916867733, 12481857814335],
-[1, 2, 2, 3, 4, 4, 7, 10, 18, 25, 41, 56, 81, 124, 184, 293, 410, 631, 1005, 1500, 2228, 3325, 5154, 7979, 11808, 17980, 27547, 41543, 63503, 95714, 145645, 221870, 334836, 512621, 775881, 1175126, 1793664, 2714386, 4132649, 6272438, 9510805, 14495446, 21950178, 33376145, 50758565, 76919521, 117058547, 177630693, 269805024, 410237125, 622102369, 946129924, 1436736941, 2180851321, 3316057771, 5032226143, 7646789560, 11616780501, 17634544312, 26805188400, 40694258459, 61814843359, 93925883098, 142593446115, 216676699181, 329065676830, 499763819328, 759342626522, 1152997077960, 1751665667918, 2660686077188, 4040630972835, 6138910436301, 9322889435375, 14161443726977],
-[1, 2, 2, 3, 4, 4, 8, 12, 23, 30, 51, 74, 102, 177, 254, 385, 572, 861, 1406, 2037, 3039, 4735, 7056, 10975, 16511, 24699, 38294, 57184, 88029, 133836, 199792, 308707, 465232, 707738, 1080189, 1622948, 2490544, 3767843, 5709200, 8730356, 13161717, 20076485, 30503320, 46175009, 70476915, 106593193, 162146811, 246724998, 373465827, 569101381, 862950983, 1310300280, 1994126566, 3021685278, 4597673922, 6980738193, 10592906123, 16117048841, 24444744197, 37152830389, 56451049949, 85659828749, 130257503587, 197708932502, 300318266083, 456411063771, 692701638669, 1052840059357, 1598837606255, 2427946440678, 3689738109187, 5601572756110, 8510817749611, 12927780282635, 19630536539813],
-[1, 2, 2, 3, 4, 4, 8, 11, 19, 28, 48, 65, 86, 149, 223, 356, 511, 737, 1248, 1812, 2757, 4243, 6194, 9841, 14661, 22216, 34408, 50537, 78894, 119624, 178710, 276700, 413284, 633758, 965896, 1447608, 2232153, 3357890, 5102262, 7810170, 11741803, 17972848, 27220413, 41241665, 63058355, 95116351, 145012123, 220406217, 333486352, 508997581, 770441071, 1171191358, 1782110140, 2698167911, 4110845002, 6235114180, 9464709926, 14405269936, 21831493195, 33208801520, 50434014435, 76526092684, 116421601819, 176602065082, 268378568763, 407824070617, 618825080514, 940924737361, 1428355292663, 2169461521547, 3297116894680, 5004303624290, 7605490887543, 11550445242630, 17539514208903],
-[1, 2, 2, 3, 4, 4, 7, 9, 15, 23, 39, 50, 72, 128, 171, 278, 408, 605, 981, 1401, 2223, 3340, 4826, 7814, 11655, 17467, 26927, 39972, 62547, 93638, 140644, 219012, 325057, 499054, 760840, 1141875, 1758483, 2639457, 4028182, 6153271, 9236324, 14171974, 21441070, 32496552, 49662767, 74906782, 114335550, 173519734, 262697894, 401183346, 606668762, 922821475, 1403796818, 2125523392, 3239148860, 4910149226, 7457758534, 11348717355, 17194676351, 26166523810, 39726205744, 60287370896, 91719152806, 139105730051, 211453357584, 321250227829, 487481686293, 741312861834, 1125118931223, 1709147143430, 2597382134259, 3942131870877, 5991829329120, 9098633477240, 13817593100911],
-[1, 2, 2, 3, 4, 4, 7, 9, 18, 25, 43, 53, 74, 141, 194, 310, 441, 664, 1098, 1572, 2439, 3613, 5299, 8647, 12795, 19154, 29479, 43786, 68752, 102688, 154429, 239780, 355787, 548615, 834339, 1251605, 1927910, 2892773, 4421892, 6745290, 10126997, 15546279, 23495211, 35650695, 54468981, 82119327, 125402462, 190217906, 288141479, 439981809, 665060238, 1012194349, 1539187293, 2330795295, 3552505283, 5383474754, 8179060281, 12444157105, 18854522638, 28697662395, 43557726182, 66113195765, 100580159928, 152531293443, 231895336126, 352256973995, 534570388309, 812941180238, 1233714997445, 1874322291522, 2848192283568, 4322810612400, 6570783321805, 9977049814987, 15152520130001],
-[1, 2, 2, 3, 5, 5, 9, 17, 26, 31, 51, 79, 115, 188, 274, 420, 607, 948, 1526, 2159, 3280, 5114, 7716, 11841, 17616, 26997, 41395, 61695, 95418, 144132, 216855, 333355, 502759, 768063, 1163281, 1757446, 2696863, 4067171, 6184170, 9425972, 14241447, 21731371, 32928905, 50006711, 76172332, 115209565, 175524323, 266555586, 404101672, 615361299, 932716391, 1418117369, 2155066318, 3268099247, 4973234845, 7545216406, 11460341616, 17424501443, 26433133764, 40187897092, 61023061282, 92656009623, 140853234913, 213766402791, 324838805849, 493455592069, 749149652780, 1138582374686, 1728705930401, 2625995183401, 3989620105493, 6057491561579, 9204273784699, 13978316865774, 21230182353501],
-[1, 2, 2, 3, 5, 6, 10, 14, 26, 39, 56, 90, 126, 190, 315, 453, 661, 1008, 1566, 2496, 3572, 5394, 8420, 12481, 19430, 29205, 43977, 67656, 101297, 156588, 236580, 354670, 547215, 824463, 1255792, 1911917, 2881775, 4415425, 6671415, 10134500, 15471234, 23334335, 35602256, 54049062, 81925214, 124896871, 188950749, 287634579, 437186459, 662298884, 1008990994, 1529625709, 2323798661, 3534315505, 5357972319, 8152164630, 12373113746, 18784874031, 28570969337, 43337552741, 65877010805, 100067110460, 151887847789, 230926924356, 350499666866, 532504647304, 809094258994, 1228160440520, 1866640655070, 2834423586959, 4304888736579, 6541257212484, 9931219401780, 15089550285232, 22918615512487],
-[1, 2, 3, 4, 5, 9, 12, 20, 29, 38, 61, 93, 152, 199, 288, 503, 746, 1076, 1667, 2552, 3885, 5764, 8868, 13731, 20100, 31029, 47698, 71401, 109024, 164539, 252051, 382390, 575162, 884673, 1336266, 2023268, 3092142, 4675480, 7124534, 10796299, 16383975, 25003676, 37788289, 57505069, 87466093, 132493435, 201761469, 305932839, 464920363, 706895127, 1071420848, 1630701092, 2475170375, 3756941070, 5714203142, 8668660209, 13176927091, 20013830339, 30379568464, 46191163868, 70103654737, 106505879213, 161835112420, 245653433091, 373349922392, 566920676827, 861055617315, 1308357414895, 1986360192527, 3018147125963, 4584100302411, 6961478649187, 10577337182725, 16061877592998, 24399559937137],
-[1, 2, 3, 4, 5, 9, 12, 13, 22, 40, 62, 72, 123, 201, 282, 433, 650, 1014, 1486, 2284, 3613, 5225, 7886, 12309, 18648, 28247, 42396, 64953, 99294, 148615, 228411, 346642, 522056, 798639, 1210070, 1843676, 2793194, 4227891, 6468525, 9782858, 14853711, 22623537, 34258397, 52159509, 79129434, 120171885, 182862304, 276994525, 421484109, 640345623, 971243147, 1477457424, 2241669807, 3406600316, 5176197471, 7854056277, 11943451926, 18129726955, 27533868680, 41850382024, 63519522538, 96530123334, 146603001909, 222622178158, 338321976103, 513627144159, 780339508533, 1185413155473, 1799957796203, 2734974102607, 4153300471511, 6308682018377, 9583964218449, 14553678888743, 22110719420025],
-[1, 2, 3, 4, 5, 9, 12, 16, 24, 39, 72, 92, 151, 224, 324, 565, 772, 1185, 1858, 2706, 4419, 6309, 9544, 15213, 21998, 34584, 51957, 77613, 121883, 179271, 277133, 422719, 627895, 978235, 1463100, 2226810, 3415759, 5103276, 7867399, 11875859, 17967875, 27584905, 41422540, 63339531, 96233209, 145339767, 222500958, 335822396, 511149626, 778455791, 1176108513, 1795576008, 2720196935, 4129215594, 6291293258, 9520091576, 14498938182, 22011137493, 33381573381, 50836330451, 77040595188, 117132811707, 178014290248, 269962395012, 410771422512, 623245428622, 946707614812, 1439227048119, 2183359254282, 3319662811254, 5040654830909, 7653499567200, 11634403307042, 17657711309932, 26832452227993],
-[1, 2, 3, 4, 4, 7, 12, 19, 22, 35, 69, 93, 145, 215, 318, 510, 741, 1199, 1777, 2519, 4138, 6175, 9288, 14226, 21059, 33244, 49476, 74598, 116101, 171561, 264616, 402801, 605035, 931791, 1395057, 2135917, 3259208, 4888136, 7511808, 11345562, 17215219, 26307602, 39650347, 60593395, 91848299, 139122487, 212558267, 321145242, 488885103, 743354864, 1125542180, 1715991409, 2599620577, 3950309328, 6010477111, 9104589695, 13861023173, 21036002621, 31929166734, 48578554952, 73659817278, 112001992634, 170124237682, 258162646307, 392638245364, 595803982232, 905226411774, 1375575581462, 2087658621729, 3173514115703, 4818368310249, 7317987165344, 11120997755820, 16881780931976, 25652783107277],
-[1, 2, 2, 4, 4, 6, 8, 11, 16, 26, 47, 56, 84, 144, 219, 320, 464, 741, 1138, 1676, 2648, 3901, 5802, 9139, 13762, 20891, 31435, 47672, 73924, 110305, 167889, 256852, 385830, 591700, 895536, 1359452, 2071705, 3123418, 4780407, 7250081, 10966743, 16742941, 25336256, 38557661, 58597119, 88763223, 135292813, 204976334, 311454769, 473947936, 718047495, 1092641626, 1658617536, 2518027272, 3830119560, 5808096804, 8830843429, 13414380815, 20356910521, 30956633313, 46981591112, 71376208672, 108456896482, 164618423859, 250223937598, 379927285763, 577016180188, 876858118090, 1331165709318, 2022654777368, 3072115289336, 4665249801736, 7088749290307, 10763864129467, 16351686916541],
-[1, 2, 2, 4, 4, 7, 8, 10, 16, 19, 32, 50, 86, 119, 173, 273, 403, 604, 904, 1372, 2145, 3221, 4846, 7545, 11125, 17044, 26145, 39200, 60101, 90411, 138594, 210820, 315980, 485631, 735512, 1113945, 1699511, 2567699, 3919321, 5942207, 9003285, 13746989, 20784395, 31615237, 48094954, 72849635, 110958876, 168205488, 255587202, 388799313, 589117247, 896536290, 1361079861, 2065746893, 3142167462, 4766286793, 7245309103, 11005675059, 16703093406, 25398997274, 38548604183, 58560629489, 88988331970, 135071708011, 205292093324, 311730851649, 473440047659, 719431793306, 1092211395507, 1659527217349, 2520653197389, 3827764164534, 5816082665034, 8831746382317, 13416156640563],
-[1, 2, 2, 4, 4, 6, 8, 8, 15, 22, 41, 53, 86, 123, 177, 310, 417, 626, 977, 1464, 2349, 3346, 5064, 7979, 11609, 18266, 27455, 40862, 63938, 94512, 146049, 222106, 330468, 514563, 769695, 1171669, 1795768, 2686231, 4135894, 6244041, 9457776, 14502637, 21786961, 33312390, 50606442, 76466672, 116983617, 176637089, 268871637, 409305538, 618683535, 944301213, 1430573044, 2171904879, 3308300101, 5007578913, 7625289717, 11575320909, 17558529027, 26734116713, 40519196252, 61605596352, 93617228376, 141991716622, 216027381603, 327784511867, 497924476473, 756891730933, 1148340696234, 1745899947799, 2650990397679, 4025347782273, 6118693749536, 9286904050050, 14112089276290],
-[1, 2, 2, 4, 4, 7, 8, 8, 15, 18, 35, 57, 105, 123, 171, 322, 461, 721, 1009, 1541, 2542, 3629, 5676, 8476, 12422, 20023, 29724, 45027, 68718, 102058, 160600, 239830, 361219, 559273, 832410, 1282253, 1944952, 2928342, 4506266, 6756300, 10332204, 15752209, 23683764, 36315773, 54872774, 83359570, 127210428, 191891884, 293081296, 444353984, 673456968, 1027770151, 1554116390, 2365658403, 3595304368, 5447206265, 8300661565, 12577984943, 19114180843, 29072736734, 44060088839, 67055676111, 101766877880, 154502806030, 234996903597, 356408064821, 541879341463, 823026594715, 1249174967366, 1899461982733, 2882673934496, 4379810319227, 6654730191418, 10101106172607, 15353420523475],
-[1, 2, 2, 4, 4, 5, 9, 10, 16, 25, 47, 62, 90, 147, 202, 302, 480, 721, 1076, 1590, 2496, 3909, 5625, 8663, 13234, 19757, 30663, 46034, 70316, 106398, 160016, 248286, 372602, 563349, 863357, 1302171, 1990694, 3012512, 4567385, 6980608, 10526427, 16055959, 24417298, 36917817, 56314961, 85311551, 129714569, 197280531, 298673221, 455119799, 690361783, 1047805116, 1594782368, 2417234316, 3676321033, 5583304660, 8472948824, 12889361287, 19551395566, 29711442107, 45151682147, 68513143704, 104166939557, 158136191687, 240186764868, 365021639477, 554037216581, 842012105927, 1278768923641, 1941814620810, 2950930821626, 4480265214330, 6806609830677, 10339499497807, 15700452930236],
-[1, 2, 2, 4, 4, 5, 8, 9, 15, 25, 44, 50, 76, 131, 193, 279, 395, 652, 1016, 1430, 2265, 3440, 5030, 7962, 11873, 18095, 27481, 41058, 64592, 95832, 144761, 224015, 334423, 514192, 777375, 1175851, 1805396, 2706687, 4144903, 6307060, 9496343, 14549154, 21987728, 33438310, 50934378, 76911636, 117496097, 178007385, 270002336, 411750898, 622843922, 948285865, 1440290424, 2183698833, 3326249428, 5039875060, 7661962053, 11648763461, 17659333408, 26872977473, 40778058761, 61929221039, 94164370172, 142834615304, 217177233238, 329793490581, 500661791912, 761186627983, 1155221268436, 1755351752236, 2666695349339, 4048277319042, 6152959666981, 9341947721063, 14190309546183],
-[1, 2, 2, 4, 4, 5, 8, 10, 16, 27, 52, 54, 81, 156, 231, 337, 475, 742, 1177, 1710, 2731, 4052, 5867, 9465, 14253, 21568, 32418, 48475, 76570, 113993, 172203, 265736, 395967, 609483, 924169, 1396732, 2140341, 3209360, 4919572, 7489492, 11270246, 17262315, 26091171, 39675848, 60454322, 91295457, 139422365, 211215621, 320414495, 488710202, 739204056, 1125238155, 1709246836, 2591549402, 3947392834, 5981237808, 9092594855, 13823987119, 20956996376, 31891357431, 48394750524, 73492480111, 111747465358, 169510863807, 257732798324, 391383384125, 594153596626, 903330548843, 1370964111436, 2083136619265, 3164704062672, 4804276876403, 7301936004388, 11086565337021, 16840234258348],
-[1, 2, 2, 4, 4, 4, 8, 8, 14, 27, 52, 54, 74, 141, 206, 338, 454, 676, 1124, 1615, 2604, 3806, 5494, 9001, 13246, 20345, 30963, 45406, 72171, 107106, 162419, 251734, 370844, 576036, 871385, 1312239, 2025593, 3018785, 4636827, 7062813, 10603091, 16314496, 24555754, 37368950, 57072802, 85918183, 131535607, 199047300, 301794617, 461108726, 696011223, 1061069076, 1611515894, 2440598088, 3723266781, 5635303049, 8569839611, 13034137702, 19741690924, 30071456167, 45607162309, 69254914436, 105363649203, 159712673547, 242957709137, 368906527205, 559888869796, 851622936967, 1291926310011, 1963464803115, 2983139679316, 4527324967446, 6883268773863, 10448691084189, 15871642231129],
-[1, 2, 2, 4, 4, 5, 8, 11, 18, 26, 47, 64, 91, 144, 219, 357, 501, 766, 1211, 1764, 2775, 4157, 6178, 9601, 14369, 22306, 33535, 49947, 77865, 116957, 177799, 271321, 407410, 626630, 945684, 1435205, 2195956, 3300831, 5047647, 7667481, 11598468, 17713082, 26761696, 40765649, 61997999, 93782541, 143072196, 216752804, 329210242, 501154830, 759008550, 1155515705, 1753617842, 2661365249, 4050613960, 6140274398, 9335939150, 14183986129, 21520026854, 32733132482, 49669019049, 75460479894, 114681828085, 174024386314, 264563030920, 401697166562, 610022777340, 927127789352, 1407322151540, 2138514561506, 3248138904271, 4932122884161, 7495035600381, 11380076779921, 17287776757513],
-[1, 2, 2, 4, 4, 4, 8, 8, 15, 29, 56, 58, 86, 163, 219, 347, 497, 766, 1237, 1748, 2826, 4204, 6038, 9831, 14558, 22094, 33759, 49976, 79041, 117218, 177100, 275615, 407216, 628834, 954145, 1436209, 2213697, 3307925, 5072030, 7731688, 11601200, 17834656, 26907329, 40878939, 62426202, 94065515, 143868051, 217911221, 330214116, 504463853, 761968776, 1160602468, 1763779209, 2671171116, 4073075740, 6168154425, 9376414232, 14263511290, 21606084208, 32899287598, 49916058180, 75781295368, 115289090115, 174796435926, 265831387552, 403714866475, 612686729420, 931849652410, 1413883379831, 2148433155106, 3264445814255, 4954415564751, 7531761714671, 11434523831639, 17367695364329],
-[1, 2, 2, 4, 4, 5, 8, 8, 13, 21, 39, 48, 78, 108, 150, 254, 356, 575, 865, 1295, 2004, 2951, 4559, 6909, 10273, 16046, 24111, 36424, 55971, 83656, 128987, 194861, 294099, 452068, 678600, 1037825, 1578378, 2380315, 3644705, 5503992, 8374917, 12758249, 19265135, 29417265, 44582804, 67641304, 103095442, 155988057, 237523602, 360698784, 546892929, 832990522, 1262325745, 1918931811, 2917066458, 4423060310, 6730209251, 10212290251, 15510330448, 23582515130, 35773245542, 54390012486, 82593973259, 125393598376, 190629519026, 289320522310, 439626241739, 667850348872, 1013886410754, 1540961672485, 2339654012021, 3553932198873, 5399644383146, 8198199646144, 12456879645781],
-[1, 2, 2, 4, 5, 5, 10, 14, 18, 30, 59, 71, 99, 160, 251, 421, 553, 868, 1358, 1985, 3189, 4649, 7038, 10860, 16148, 25454, 37792, 56447, 88254, 131528, 202072, 306518, 459710, 710899, 1065143, 1626424, 2484575, 3723544, 5720942, 8651858, 13123318, 20049734, 30199562, 46163953, 70047671, 106006677, 161944636, 244762316, 372515656, 566591545, 857692567, 1307662121, 1981276731, 3009822701, 4580997423, 6938742162, 10561800529, 16031868882, 24330952940, 37021800189, 56135214632, 85346524428, 129655364007, 196731450402, 299212694394, 454065316747, 689812955710, 1048307617332, 1590930175821, 2418398610698, 3672053876083, 5576624089701, 8475047251056, 12865219725974, 19548760176699],
-[1, 2, 2, 4, 4, 4, 8, 8, 14, 26, 46, 48, 65, 126, 190, 318, 399, 594, 1019, 1472, 2424, 3418, 4945, 8226, 12013, 18675, 28003, 40968, 65988, 97412, 148153, 228496, 335969, 526349, 791490, 1193313, 1844254, 2738646, 4225955, 6420871, 9639675, 14856301, 22291885, 34023936, 51921535, 78073243, 119747929, 180876753, 274568660, 419540407, 632552949, 965711147, 1465092487, 2219492334, 3387908572, 5122640907, 7796822272, 11853619427, 17951338597, 27360021411, 41466094276, 62995035535, 95835888036, 145225381210, 221023020310, 335469812274, 509216673611, 774640230125, 1174799492366, 1786013402670, 2713032725744, 4117347671375, 6261012241157, 9502003581411, 14436037560933],
-[1, 2, 2, 4, 4, 5, 8, 10, 14, 26, 49, 49, 74, 138, 199, 316, 454, 673, 1061, 1590, 2495, 3686, 5399, 8619, 12953, 19639, 29864, 44451, 69267, 104162, 157836, 242453, 361376, 556055, 845085, 1274843, 1952498, 2936660, 4487849, 6832473, 10301447, 15761073, 23831356, 36208540, 55202852, 83424626, 127222772, 192914095, 292642852, 446091353, 675179109, 1027334036, 1560952719, 2366579684, 3603452309, 5463315562, 8302132942, 12621818571, 19139661198, 29117041663, 44195170942, 67107570712, 102033485659, 154806363400, 235318652464, 357397659436, 542571563160, 824796374882, 1251963556171, 1902115854652, 2889800548960, 4387148883265, 6667262141327, 10123997854153, 15377314379911],
-[1, 2, 2, 4, 5, 5, 9, 12, 19, 37, 65, 72, 103, 185, 285, 439, 595, 946, 1539, 2212, 3487, 5088, 7632, 12095, 17981, 27802, 41498, 62318, 97900, 145458, 221631, 338689, 507439, 783430, 1178934, 1791525, 2741140, 4110444, 6310184, 9566271, 14459553, 22118192, 33365189, 50899707, 77357907, 116954230, 178656121, 270286074, 410832628, 625562312, 946590478, 1442333653, 2187645302, 3320703151, 5055727217, 7658700715, 11651780283, 17698160714, 26847534466, 40853089651, 61963562800, 94163796217, 143103973877, 217110457871, 330168111102, 501175705121, 761157567746, 1156944981770, 1755815535673, 2668591446764, 4052823985209, 6153907751049, 9352766057411, 14198663118495, 21571915931214],
-[1, 2, 2, 4, 5, 6, 9, 13, 21, 31, 52, 77, 129, 175, 267, 451, 640, 999, 1470, 2216, 3540, 5160, 7984, 12047, 17954, 28310, 42062, 63792, 97851, 146405, 226687, 340723, 515269, 792766, 1187501, 1820609, 2763816, 4169386, 6391547, 9632979, 14685775, 22357707, 33737797, 51581106, 78074324, 118554056, 180687550, 273197786, 416405829, 631866323, 958273079, 1460050883, 2211156939, 3363287476, 5111167723, 7749478311, 11795959771, 17890689859, 27181003409, 41324891521, 62675461224, 95321599474, 144711712175, 219725829341, 334059612291, 506910636060, 770419079160, 1170212886260, 1776547462516, 2700382080499, 4099405238601, 6227721118513, 9461674392182, 14364885570969, 21829029667009],
-[1, 2, 2, 3, 4, 6, 10, 13, 20, 30, 48, 80, 110, 159, 247, 388, 603, 841, 1285, 2054, 3063, 4703, 7030, 10571, 16443, 24634, 37733, 56988, 85686, 132704, 199517, 303082, 462176, 696481, 1067397, 1613916, 2449006, 3738755, 5639358, 8607268, 13072620, 19794440, 30186097, 45682460, 69520120, 105697726, 160075371, 243884801, 369774747, 561643066, 854458540, 1295114841, 1970331115, 2991145149, 4540516204, 6906517374, 10475693768, 15922908933, 24190236412, 36713248255, 55820204093, 84726808305, 128711714178, 195582501455, 296871854559, 451203937743, 685162780906, 1040553247673, 1581196157002, 2400612204176, 3647411901973, 5540062091507, 8413021557001, 12782994133011, 19411259663478],
-[1, 2, 2, 3, 4, 5, 8, 13, 23, 26, 43, 72, 107, 155, 233, 363, 562, 814, 1234, 1893, 2797, 4367, 6633, 9985, 15150, 22816, 35293, 53175, 80173, 123148, 185671, 282788, 430176, 650986, 993090, 1500744, 2285466, 3481573, 5259790, 8015872, 12166643, 18466889, 28105648, 42574101, 64797386, 98392318, 149241373, 227186081, 344514270, 523481514, 795622700, 1207157997, 1835823098, 2786195367, 4231895129, 6432920352, 9761569782, 14837085645, 22533457635, 34214500271, 52000371207, 78940674380, 119939943935, 182195873746, 276634260736, 420376958024, 638341705920, 969612495936, 1473061361186, 2236810457989, 3398403791620, 5161403586493, 7839190840482, 11909463548063, 18085914195842],
-[1, 2, 2, 3, 4, 5, 8, 13, 24, 28, 44, 70, 107, 166, 231, 368, 564, 806, 1307, 1954, 2840, 4434, 6625, 10357, 15551, 23076, 36168, 53654, 82215, 126196, 187789, 289429, 436276, 664297, 1017081, 1520995, 2335456, 3544104, 5353643, 8198086, 12358355, 18841411, 28653617, 43309774, 66199102, 100104826, 152093441, 231749905, 350560975, 534222716, 810313601, 1229635219, 1872720539, 2836385312, 4315433042, 6555649286, 9941922237, 15131527861, 22950007580, 34873539795, 53005112496, 80404859422, 122286635802, 185625757843, 281892708378, 428519811238, 650279801332, 988350781511, 1501091422573, 2279129605744, 3464062875718, 5258697323885, 7989420762894, 12137265464957, 18428025631205],
-[1, 2, 2, 3, 4, 5, 7, 11, 18, 23, 37, 57, 83, 106, 168, 301, 429, 594, 925, 1441, 2179, 3310, 4940, 7491, 11425, 17576, 26982, 39753, 60528, 93383, 141274, 214605, 323591, 493650, 752109, 1136081, 1735183, 2628173, 3980965, 6072063, 9207280, 14002046, 21239254, 32223469, 49104444, 74414484, 113063326, 171904412, 260692942, 396537432, 601900824, 914216301, 1389615886, 2108063518, 3205274262, 4868043751, 7389790924, 11232955761, 17051477471, 25908252139, 39357062342, 59752741233, 90810358559, 137883061375, 209438550877, 318213033273, 483157076239, 734088166775, 1114910822096, 1693312357280, 2572625008843, 3906626753175, 5934724430364, 9014595728911, 13690531609600],
-[1, 2, 2, 3, 4, 5, 7, 11, 19, 25, 42, 59, 86, 125, 195, 317, 459, 673, 1038, 1616, 2437, 3668, 5562, 8512, 12747, 19532, 29912, 44857, 68359, 103860, 158190, 240209, 362861, 554189, 840406, 1274187, 1942161, 2942475, 4471306, 6793139, 10312488, 15694029, 23783292, 36142954, 54969017, 83365782, 126756664, 192443438, 292260693, 444235927, 674123280, 1024720777, 1556253375, 2362582462, 3591259679, 5452131564, 8282653443, 12582494828, 19104644134, 29031735518, 44084357245, 66960080756, 101733655104, 154474589585, 234690003161, 356457968934, 541375523098, 822472451838, 1249023328704, 1897382297717, 2882067458887, 4377066473244, 6649450604842, 10099028993995, 15339874096220],
-[1, 2, 2, 3, 4, 5, 7, 11, 19, 25, 40, 59, 89, 139, 214, 328, 461, 732, 1142, 1684, 2568, 3873, 5993, 9070, 13568, 20910, 31483, 47758, 73214, 110532, 168322, 254819, 387123, 591636, 892072, 1358440, 2067349, 3129092, 4769486, 7227311, 10985388, 16704385, 25304189, 38546502, 58481458, 88746782, 135041358, 204788078, 311381216, 472887420, 717698891, 1091586636, 1656178831, 2516537040, 3824175477, 5803521705, 8822145772, 13394619208, 20344489957, 30915769673, 46929167381, 71315602209, 108312686479, 164476898479, 249932951576, 379490747388, 576513619571, 875745134109, 1329865319384, 2020550071996, 3068477498688, 4660860860249, 7080496409318, 10752579410239, 16335012191103],
-[1, 2, 2, 3, 4, 6, 10, 13, 22, 28, 46, 79, 114, 168, 255, 395, 613, 923, 1361, 2076, 3204, 4931, 7435, 11099, 17039, 25915, 39354, 59885, 90457, 137866, 209254, 317926, 484157, 732118, 1113637, 1693758, 2569164, 3908438, 5926324, 9005842, 13692018, 20768482, 31588334, 47944967, 72794681, 110683607, 167976377, 255285329, 387693966, 588625039, 894771500, 1358289775, 2063503072, 3134935697, 4759607952, 7232781348, 10983414886, 16682565829, 25345581336, 38485090288, 58471333251, 88808657307, 134875700733, 204910977739, 311182737658, 472710361461, 718036866803, 1090499377593, 1656645414570, 2516059148637, 3821754334342, 5805382509541, 8816991126090, 13393541282720, 20343103355998],
-[1, 2, 2, 3, 4, 5, 8, 12, 22, 24, 38, 70, 105, 147, 208, 339, 531, 792, 1184, 1763, 2684, 4206, 6310, 9447, 14350, 21792, 33640, 50568, 76544, 116956, 176458, 269853, 409374, 619814, 944335, 1428861, 2179242, 3310350, 5008826, 7633535, 11574460, 17591011, 26749814, 40535736, 61693380, 93620109, 142160848, 216253832, 327897357, 498502262, 757238089, 1149419784, 1747682098, 2652057137, 4029711307, 6122909084, 9293539369, 14126396626, 21448373647, 32576308389, 49501252523, 75150884775, 114192492109, 173430703168, 263376836198, 400195278732, 607670808980, 923141527781, 1402269482500, 2129495615990, 3235333155376, 4913442343904, 7463296249739, 11337451483376, 17217785854889],
-[1, 2, 2, 3, 4, 4, 8, 13, 24, 30, 49, 74, 105, 189, 257, 376, 601, 897, 1433, 2022, 3056, 4949, 7166, 11102, 16836, 25051, 39350, 58162, 89463, 136781, 202645, 316069, 474657, 718438, 1103816, 1651426, 2541264, 3843483, 5804289, 8917872, 13401012, 20454092, 31136817, 46986511, 71891302, 108627691, 165171258, 251741276, 380242004, 580234579, 879831218, 1334535078, 2033859091, 3078449384, 4685847243, 7117480886, 10790839717, 16433440036, 24911133131, 37858908402, 57555845373, 87277402668, 132780032681, 201514908082, 306024572861, 465292229725, 705885946026, 1073089798459, 1629721330269, 2474155485499, 3761147452144, 5708834641113, 8673940698748, 13177444355232, 20005299898326],
-[1, 2, 2, 3, 4, 4, 7, 10, 17, 25, 45, 56, 77, 129, 191, 320, 443, 660, 1064, 1568, 2451, 3635, 5358, 8517, 12641, 19435, 29718, 43836, 68387, 102740, 156042, 239653, 356594, 550233, 833987, 1258133, 1932285, 2900654, 4432959, 6747340, 10175554, 15585700, 23527229, 35772150, 54558531, 82377505, 125732636, 190572544, 289096614, 440783498, 666817965, 1015245781, 1542124137, 2337536894, 3560788236, 5396688244, 8202462464, 12470224295, 18906465068, 28770129839, 43658209248, 66297960887, 100812752345, 152922982783, 232496575234, 353083988459, 536012019641, 814904379207, 1236785052346, 1879243662350, 2854994196023, 4334056974936, 6587195005850, 10001627820181, 15191929577708],
-[1, 2, 2, 3, 4, 4, 7, 10, 16, 23, 41, 51, 74, 125, 178, 254, 377, 610, 944, 1331, 2025, 3231, 4756, 7222, 10992, 16667, 25445, 38103, 58965, 89091, 133464, 205774, 311352, 472369, 717781, 1086478, 1662433, 2510867, 3812503, 5820385, 8790905, 13389729, 20342326, 30850870, 46971112, 71127127, 108258571, 164526998, 249294959, 379569044, 575838006, 874616993, 1329741992, 2016938514, 3067610876, 4656582375, 7070153987, 10751007085, 16312343483, 24790008075, 37658206491, 57169412163, 86897452034, 131915385235, 200405075707, 304482541417, 462245066735, 702449127898, 1066744577472, 1620156583167, 2461616530132, 3737748354973, 5678708468003, 8625204979265, 13098994873768],
-[1, 2, 2, 3, 4, 4, 7, 11, 21, 28, 48, 64, 92, 163, 222, 328, 518, 804, 1255, 1787, 2699, 4298, 6346, 9746, 14766, 22107, 34328, 51256, 78693, 119731, 178715, 276606, 416969, 632305, 966798, 1453943, 2228369, 3373347, 5108272, 7816071, 11782118, 17961101, 27318459, 41324516, 63063687, 95434091, 145113444, 220871599, 334251915, 509323029, 772589241, 1172607635, 1784954846, 2704879702, 4114680360, 6248919701, 9480953162, 14425971392, 21881248035, 33250940715, 50531714116, 76671531508, 116584505353, 176975019938, 268793204050, 408530909034, 620025840154, 942333754365, 1431142197740, 2173126347190, 3302585480442, 5013942245325, 7617574305579, 11571575779852, 17570636806232],
-[1, 2, 2, 3, 4, 4, 8, 12, 22, 29, 51, 74, 101, 168, 251, 396, 574, 851, 1374, 2000, 3039, 4686, 6988, 10832, 16246, 24686, 38007, 56362, 87077, 132386, 198718, 305620, 459655, 702277, 1068206, 1608743, 2469178, 3726685, 5658604, 8640155, 13041523, 19901462, 30173629, 45760677, 69802295, 105543623, 160691910, 244223910, 369992927, 563702994, 854408298, 1298422593, 1974432198, 2992755518, 4554745020, 6912164519, 10494266078, 15961133972, 24209344979, 36805206516, 55900098922, 84851413378, 129014080902, 195797070778, 297491031589, 452002370253, 686105153668, 1042826967126, 1583402978174, 2404973039191, 3654316581681, 5547957517392, 8429973575736, 12803375987657, 19443845342562],
-[1, 2, 2, 3, 4, 4, 8, 12, 22, 29, 45, 66, 100, 178, 233, 355, 565, 814, 1321, 1910, 2886, 4561, 6604, 10458, 15710, 23231, 36650, 54134, 83271, 127146, 189083, 294661, 440078, 669313, 1029767, 1536028, 2364648, 3576011, 5408527, 8302387, 12463804, 19058695, 28976324, 43723266, 66959728, 101103928, 153783714, 234292509, 353957662, 540382924, 818737013, 1242421528, 1893633166, 2865230139, 4362843068, 6625096315, 10045697689, 15299292006, 23186331501, 35248870990, 53578142283, 81242054835, 123619203987, 187579132578, 284897322623, 433148680952, 657087383125, 999033786810, 1517051199719, 2303255079717, 3501436711381, 5314195768453, 8075034739511, 12266900554563, 18623158807715],
-[1, 2, 2, 3, 4, 4, 7, 10, 19, 26, 41, 55, 84, 155, 198, 305, 488, 713, 1115, 1632, 2542, 3903, 5575, 8961, 13515, 19860, 31161, 46437, 71527, 107951, 161819, 252852, 375442, 571628, 879460, 1315987, 2020941, 3050119, 4632144, 7093597, 10645475, 16304024, 24757208, 37385915, 57206539, 86413523, 131555575, 200098976, 302584102, 462041231, 699562238, 1062259550, 1618192929, 2449270907, 3729690603, 5660855404, 8589076008, 13076142116, 19815650908, 30134551197, 45788667249, 69448063267, 105661933317, 160316534581, 243552249623, 370189712716, 561652494993, 853974512961, 1296570724169, 1968849184945, 2992725343965, 4542233945060, 6902472587811, 10484353322956, 15918788565366],
-[1, 2, 2, 3, 4, 4, 7, 10, 18, 24, 40, 51, 74, 128, 171, 274, 420, 638, 970, 1403, 2225, 3390, 4979, 7848, 11698, 17646, 27219, 40697, 62856, 94418, 142743, 220376, 329347, 504167, 766971, 1155499, 1772223, 2671995, 4070790, 6200631, 9348121, 14302532, 21659155, 32851186, 50098836, 75745052, 115437722, 175221770, 265604846, 404838398, 613052697, 932273442, 1417348079, 2148269503, 3270218557, 4960464148, 7534970210, 11458266153, 17375366657, 26426027884, 40124024848, 60912094955, 92619140307, 140538822185, 213582220643, 324450795118, 492512754794, 748669920818, 1136554576285, 1726508905668, 2623276392787, 3982495004133, 6051870579188, 9190508852456, 13958204955159],
-[1, 2, 2, 2, 4, 5, 9, 14, 25, 32, 46, 79, 109, 176, 254, 382, 591, 893, 1394, 2044, 3083, 4843, 7257, 10934, 16676, 25199, 38795, 58281, 88490, 135668, 203540, 311887, 473176, 715557, 1093042, 1650538, 2518802, 3825215, 5784384, 8833766, 13373840, 20320808, 30927104, 46832531, 71332065, 108193636, 164257575, 250027868, 378842482, 576235063, 875317877, 1328068128, 2020307813, 3064780393, 4657463569, 7077482645, 10738874577, 16329007195, 24788454754, 37647649692, 57218398679, 86846560949, 131985167433, 200447241620, 304379157049, 462568746818, 702276320515, 1066932315806, 1620750481160, 2461035932494, 3739436391160, 5678634184301, 8625631145741, 13103820246940, 19898790447252],
-[1, 2, 2, 2, 4, 5, 8, 14, 27, 30, 40, 74, 104, 175, 252, 374, 590, 864, 1403, 2048, 2963, 4792, 7152, 10924, 16571, 24581, 38682, 57594, 87411, 135032, 200188, 309074, 468371, 707003, 1086531, 1626343, 2493038, 3793990, 5709234, 8760945, 13216808, 20099609, 30653994, 46254090, 70687409, 107039686, 162365759, 247762443, 374509185, 570429106, 866396088, 1313072838, 2001049953, 3031123924, 4608516613, 7006970776, 10619582709, 16165854643, 24526405651, 37245778292, 56643276059, 85902802784, 130632163300, 198364669608, 301115801439, 457867951623, 694792314423, 1055802453333, 1604008388911, 2434791822585, 3700985356806, 5618825477848, 8534976399213, 12968493513426, 19687918218592],
-[1, 2, 2, 2, 4, 4, 7, 14, 28, 28, 42, 78, 107, 173, 227, 354, 617, 852, 1336, 2014, 2902, 4763, 6912, 10585, 16306, 23696, 38057, 56386, 84629, 132119, 194517, 302761, 457256, 685361, 1064746, 1583439, 2429089, 3710045, 5551480, 8558801, 12880196, 19584659, 29966397, 45001789, 68979432, 104462832, 158137164, 241938730, 364877532, 556335310, 845498051, 1279132942, 1953173718, 2955114984, 4492480590, 6837889181, 10349737292, 15770453845, 23918734461, 36308006922, 55266841925, 83741432161, 127401001366, 193483976627, 293548692924, 446642705979, 677469477477, 1029555687121, 1564537130604, 2373843577204, 3609739756498, 5479417268546, 8322342891003, 12648870246968, 19197104758181],
-[1, 2, 2, 2, 4, 4, 7, 14, 27, 28, 42, 80, 110, 183, 252, 363, 613, 894, 1412, 2079, 2982, 4941, 7290, 10993, 16994, 24794, 39231, 58815, 88481, 137668, 202350, 313851, 478252, 714743, 1105234, 1651943, 2527737, 3863671, 5786265, 8905541, 13430840, 20373996, 31200351, 46936486, 71761156, 108820726, 164710598, 251891287, 380253057, 579043660, 880773095, 1332483786, 2033088551, 3079177351, 4677963783, 7120717124, 10781450904, 16419669047, 24917150632, 37810954853, 57550112379, 87235967437, 132657947417, 201522781014, 305739304191, 465102468796, 705668143907, 1072145831916, 1629437549578, 2472482230538, 3759000196975, 5707153012544, 8667266457162, 13172971151996, 19994592025923],
-[1, 2, 2, 2, 4, 4, 6, 12, 24, 24, 37, 62, 83, 134, 189, 293, 459, 697, 1068, 1579, 2324, 3657, 5607, 8446, 12765, 19098, 29632, 44795, 67617, 103582, 155377, 237866, 362265, 547361, 834988, 1260024, 1922267, 2927838, 4420500, 6743200, 10221790, 15521924, 23639576, 35785826, 54484986, 82693941, 125455021, 191059595, 289551350, 440133332, 668882259, 1014667998, 1543647653, 2342071598, 3557818527, 5408273430, 8205277080, 12475096977, 18942702294, 28762498258, 43719135866, 66358837213, 100837655146, 153168483717, 232552998765, 353426334245, 536615450792, 815155268023, 1238407058677, 1880376615386, 2857095819629, 4339020986401, 6590289157987, 10012333266722, 15204100775208],
-[1, 2, 2, 2, 4, 6, 7, 13, 26, 28, 39, 73, 103, 146, 208, 351, 564, 760, 1202, 1860, 2714, 4261, 6366, 9760, 14687, 21974, 34825, 51472, 77501, 120298, 179973, 276074, 416580, 631678, 970990, 1452047, 2225144, 3389149, 5099410, 7811654, 11805851, 17964868, 27350697, 41286128, 63121786, 95597453, 144970080, 221131855, 334484299, 509297079, 773384999, 1172675055, 1786539578, 2706209181, 4114673169, 6256057133, 9483088619, 14431819134, 21897992387, 33258416233, 50569776180, 76701190719, 116634599238, 177106731171, 268861831985, 408785925145, 620370201502, 942681402883, 1432076030605, 2173994022742, 3304371570798, 5016771613439, 7620553516162, 11578661674256, 17578797030195],
-[1, 2, 3, 3, 6, 10, 11, 16, 32, 44, 68, 96, 145, 235, 323, 528, 798, 1162, 1836, 2737, 4216, 6356, 9535, 14941, 22059, 33532, 51982, 77416, 118845, 179722, 272586, 418233, 625699, 959986, 1459429, 2197448, 3369745, 5088051, 7742033, 11785261, 17801698, 27213498, 41185875, 62498984, 95318967, 144149224, 219549326, 333331150, 505487825, 770004732, 1166360427, 1773848490, 2696273226, 4087320138, 6220962832, 9438113109, 14335963077, 21796259430, 33060078956, 50275105739, 76333082298, 115890935974, 176199624164, 267387046451, 406334550164, 617250568162, 937060075752, 1424286167688, 2162310943624, 3284752901147, 4990659666239, 7576898966421, 11513499488436, 17484987089299, 26555985160351],
-[1, 2, 3, 3, 5, 8, 11, 17, 26, 38, 67, 93, 137, 208, 307, 506, 723, 1094, 1714, 2546, 3984, 5922, 8863, 13822, 20661, 31700, 48009, 71999, 111568, 167411, 254597, 389041, 584432, 897265, 1356267, 2056450, 3143899, 4736517, 7234555, 10984459, 16622007, 25381182, 38379263, 58404027, 88834627, 134464949, 204983453, 310687228, 471852339, 718151123, 1087972953, 1655746359, 2513553482, 3814592713, 5804124330, 8801425850, 13379620180, 20327789356, 30844638891, 46908069447, 71190692152, 108146888996, 164353061344, 249431003521, 379143511316, 575718428069, 874296764340, 1328694360217, 2017053690275, 3064794960375, 4655206456866, 7068857521471, 10741399831876, 16310257442276, 24776481184767],
-[1, 2, 3, 3, 5, 8, 12, 22, 36, 43, 64, 108, 173, 267, 354, 560, 908, 1336, 2079, 3013, 4601, 7244, 10757, 16651, 24724, 37325, 58418, 87069, 132957, 201686, 303753, 469107, 704416, 1072280, 1636328, 2460160, 3775441, 5713010, 8657642, 13218626, 19945871, 30458212, 46228039, 69975157, 106803324, 161579174, 245833274, 373870053, 566147864, 862608439, 1307728663, 1986439989, 3022490177, 4579936583, 6968725416, 10580070605, 16058165480, 24427669108, 37049609796, 56317649670, 85558876970, 129841042441, 197435150692, 299663336546, 455218096513, 691762153307, 1049966297890, 1595846832721, 2423299683075, 3680201554677, 5592556653959, 8490413951638, 12900255869511, 19594485904776, 29754993019219],
-[1, 2, 3, 3, 4, 6, 12, 24, 31, 37, 68, 112, 171, 234, 348, 582, 867, 1327, 1988, 2900, 4591, 7014, 10614, 16015, 23834, 37280, 56494, 84677, 130037, 195414, 298959, 454986, 686557, 1051116, 1583234, 2411660, 3682779, 5553210, 8472282, 12842478, 19496859, 29722588, 44939845, 68456022, 103952973, 157557367, 240103859, 363819934, 552964058, 840592467, 1274548101, 1939901297, 2942925685, 4469332361, 6796701104, 10308775625, 15674534355, 23802723360, 36135125081, 54938354501, 83373545616, 126695025456, 192470810286, 292170670388, 444088981449, 674249226018, 1024176468796, 1556117655862, 2362527829924, 3589920986240, 5451994566828, 8280153311097, 12580729865133, 19103232776874, 29021525513890],
-[1, 2, 2, 3, 4, 5, 8, 13, 23, 26, 46, 76, 102, 161, 243, 364, 553, 844, 1320, 1978, 2883, 4544, 6943, 10339, 15813, 23812, 36706, 55356, 83628, 128770, 192841, 294175, 449108, 678129, 1034650, 1563428, 2381493, 3628522, 5476801, 8357872, 12680155, 19224711, 29295769, 44366622, 67515533, 102519838, 155459738, 236802435, 358928877, 545366830, 829215485, 1257566018, 1912926624, 2903240331, 4409165666, 6703588733, 10169803673, 15460245124, 23480810677, 35646012487, 54186244498, 82251118696, 124969764932, 189850221462, 288223982650, 438035116443, 665121314638, 1010259321229, 1534948301887, 2330582824242, 3541020432047, 5378044375505, 8167891782934, 12409525715547, 18844443901740],
-[1, 2, 2, 3, 4, 6, 8, 12, 24, 24, 38, 75, 118, 152, 216, 356, 559, 827, 1247, 1896, 2818, 4444, 6800, 10124, 15167, 23007, 35954, 54124, 81148, 124296, 188027, 286792, 435762, 659552, 1004658, 1518861, 2316162, 3527790, 5327182, 8111682, 12319178, 18711618, 28461131, 43112987, 65616361, 99630078, 151161120, 230068549, 348905002, 530115990, 805609396, 1222663452, 1859175067, 2821438097, 4285729057, 6514298816, 9886130569, 15025497741, 22818885178, 34650519863, 52658680729, 79944413454, 121466139683, 184504782097, 280153422258, 425709383838, 646451910436, 981945549512, 1491735225913, 2265253626859, 3441561912370, 5226918824270, 7938871649833, 12060585298673, 18315729616141],
-[1, 2, 2, 3, 5, 6, 10, 19, 28, 32, 54, 92, 132, 188, 299, 467, 690, 1054, 1630, 2402, 3587, 5614, 8631, 12816, 19351, 29797, 45356, 68325, 103891, 158516, 239706, 363882, 555287, 842662, 1275453, 1938396, 2953180, 4482401, 6793406, 10330577, 15704857, 23833115, 36200142, 55009169, 83550288, 126847805, 192706200, 292859627, 444634957, 675226716, 1025983754, 1558383042, 2366806878, 3594793413, 5460808314, 8294864221, 12597364780, 19136457640, 29068034243, 44147616249, 67059618281, 101860081742, 154719597396, 235002197098, 356941579361, 542200300446, 823537972477, 1250870211037, 1900042754909, 2885966215414, 4383596304713, 6658341189721, 10113509157391, 15361890457268, 23333081368227],
-[1, 2, 2, 3, 4, 5, 8, 13, 24, 29, 50, 80, 109, 174, 270, 409, 606, 901, 1448, 2165, 3145, 4975, 7530, 11312, 17326, 26086, 40178, 60101, 91467, 141212, 210551, 321694, 490292, 741597, 1132369, 1707138, 2606264, 3964638, 5982381, 9147461, 13855465, 21020733, 32023654, 48481470, 73865001, 112026490, 169949773, 258951552, 392216740, 596411198, 906431257, 1374657635, 2091707104, 3172955677, 4821148452, 7328648017, 11115855335, 16904734948, 25666122309, 38970576912, 59241338617, 89906766804, 136636591300, 207534005905, 315086357051, 478909072867, 727053598729, 1104500873039, 1678022193162, 2547741118658, 3871358934141, 5879046225843, 8929505980445, 13566552216887, 20600258346726],
-[1, 2, 2, 3, 4, 7, 10, 15, 22, 28, 45, 71, 102, 154, 266, 364, 566, 889, 1301, 2050, 2928, 4632, 7194, 10362, 16337, 24409, 37051, 57337, 84773, 131455, 198110, 298250, 462702, 689728, 1054379, 1608548, 2418584, 3719130, 5595723, 8513973, 13012450, 19574568, 29968276, 45395484, 68773214, 105049700, 158634692, 241847374, 367326185, 556097611, 848524221, 1284541145, 1952786976, 2970685181, 4499439811, 6852868097, 10394376419, 15782760014, 24014176701, 36399238104, 55366288347, 84080995179, 127598982039, 194083809407, 294442363478, 447466703658, 679884918860, 1031780956258, 1568701373037, 2381365120088, 3617095386690, 5496793557798, 8343602708844, 12679995882427, 19256738953650],
-[1, 2, 2, 3, 4, 5, 7, 10, 19, 23, 42, 65, 91, 130, 201, 319, 453, 691, 1081, 1619, 2416, 3745, 5708, 8518, 12968, 19795, 30216, 45383, 69090, 105619, 159323, 242548, 368928, 559649, 849584, 1288991, 1964698, 2980373, 4515380, 6876830, 10440912, 15850968, 24083668, 36562056, 55589050, 84361847, 128146125, 194816347, 295600900, 449182522, 682385851, 1036190156, 1574440876, 2390371016, 3631939599, 5517142085, 8376756737, 12728807915, 19330561714, 29360237432, 44603927445, 67735616010, 102905163716, 156290900575, 237377486341, 360626339701, 547668951757, 831929572072, 1263678286483, 1919253731326, 2915527633663, 4428098619893, 6726105560441, 10216931904600, 15517427811183],
-[1, 2, 2, 3, 4, 5, 8, 11, 20, 26, 39, 57, 87, 140, 191, 286, 465, 690, 1053, 1612, 2357, 3695, 5534, 8437, 12935, 19076, 29689, 44998, 67783, 103973, 155782, 239107, 363268, 547423, 839955, 1264820, 1925540, 2938338, 4432920, 6771249, 10249818, 15562618, 23740080, 35870964, 54662993, 82997174, 125798990, 191725556, 290391253, 441588034, 671211313, 1017421350, 1549022685, 2349554480, 3568522904, 5426786515, 8229909446, 12516179531, 19003527162, 28850086346, 43867369158, 66562547887, 101158480376, 153672019340, 233269085779, 354586075306, 538307924167, 817734135517, 1242456782331, 1886220703669, 2866347549692, 4352910378278, 6611008207693, 10044853767956, 15252031973929],
-[1, 2, 2, 3, 4, 4, 7, 11, 21, 28, 47, 69, 92, 142, 213, 347, 523, 742, 1175, 1799, 2648, 4137, 6171, 9396, 14276, 21526, 33534, 49715, 75424, 116324, 174569, 267144, 403951, 612825, 937483, 1410566, 2157210, 3277154, 4946176, 7563883, 11447358, 17404950, 26472244, 40053995, 61115119, 92608347, 140562333, 214096961, 324209945, 493377516, 749225655, 1136735646, 1729959982, 2622712380, 3987176919, 6059386495, 9191076251, 13979818024, 21217533643, 32227826353, 48984649268, 74331796379, 112994787915, 171583943235, 260539574486, 396004997522, 601121860349, 913349708079, 1387409591200, 2106601036918, 3201253714635, 4860823234049, 7383754773003, 11217517007322, 17033099958039],
-[1, 2, 2, 3, 4, 5, 7, 10, 20, 24, 42, 64, 89, 127, 200, 342, 459, 672, 1106, 1653, 2477, 3763, 5757, 8759, 12998, 20213, 30953, 45684, 70297, 107294, 162551, 246661, 372064, 571719, 863978, 1306104, 2001547, 3022149, 4593940, 6988520, 10595495, 16146805, 24423662, 37154177, 56571389, 85605146, 130339050, 197870683, 300333717, 456859068, 692669635, 1053657438, 1600016556, 2427558451, 3693369286, 5603804040, 8513471860, 12937201874, 19634632240, 29849895753, 45314489014, 68826115094, 104601751063, 158767918773, 241271074355, 366449968414, 556458570040, 845582081877, 1283864697886, 1950489006190, 2962880640985, 4499088082977, 6836002612683, 10381350605879, 15768561527529],
-[1, 2, 2, 3, 5, 5, 8, 14, 22, 28, 44, 68, 100, 144, 225, 346, 508, 813, 1186, 1802, 2735, 4107, 6479, 9536, 14419, 22366, 33505, 51274, 77389, 117295, 180249, 270425, 413466, 628946, 947267, 1451691, 2195074, 3336342, 5079828, 7673612, 11724770, 17763283, 26938256, 41074772, 62149101, 94615041, 143704578, 217890620, 331845707, 502856700, 764476093, 1162254487, 1762012854, 2681070942, 4068656548, 6179104729, 9394984943, 14251542451, 21668331299, 32904900382, 49953962381, 75945675738, 115263352984, 175137834464, 266065493401, 403920655149, 613901476186, 932091381948, 1415829968602, 2151174669924, 3266056284143, 4962644238102, 7536945769420, 11446694803654, 17391408404671],
-[1, 2, 2, 3, 4, 4, 7, 11, 21, 26, 42, 64, 87, 137, 196, 323, 501, 690, 1103, 1671, 2477, 3896, 5678, 8762, 13392, 19975, 31397, 46138, 70205, 108853, 162080, 249944, 375578, 569608, 877205, 1311146, 2011591, 3053354, 4601149, 7064531, 10647851, 16217831, 24689472, 37257594, 57012440, 86246953, 130906364, 199641896, 301745678, 459979229, 698055495, 1058427366, 1612951529, 2442184113, 3715299865, 5646758285, 8558990292, 13030574398, 19762894976, 30023936473, 45651330448, 69228323230, 105298061919, 159854704541, 242702342690, 369032784136, 559931822706, 851015724099, 1292680963335, 1962364621967, 2982995945858, 4528254646313, 6879219188223, 10451888495789, 15867460613231],
-[1, 2, 2, 3, 4, 5, 7, 12, 20, 27, 41, 53, 82, 130, 182, 283, 471, 668, 1028, 1542, 2320, 3714, 5322, 8230, 12674, 18803, 29415, 43458, 66386, 102377, 152195, 235522, 354418, 535737, 825625, 1236090, 1893772, 2874827, 4334441, 6656472, 10025036, 15266672, 23266824, 35095543, 53674908, 81234313, 123305677, 188035778, 284167655, 433217738, 657548590, 996734111, 1519102464, 2300415765, 3498981349, 5318253014, 8061137208, 12272944684, 18613858655, 28275826812, 42997683935, 65202667895, 99169767933, 150561076279, 228583870796, 347568743844, 527365446438, 801505888138, 1217521981365, 1848202081510, 2809473643620, 4264943710987, 6479018085714, 9844019408177, 14944543672559],
-[1, 2, 2, 3, 4, 4, 7, 10, 17, 26, 44, 57, 80, 126, 187, 299, 434, 647, 1020, 1561, 2343, 3498, 5243, 8183, 12398, 18622, 28525, 43004, 65743, 99491, 150683, 230559, 347235, 529122, 806345, 1219157, 1858118, 2814508, 4278573, 6512499, 9855981, 15013973, 22783670, 34565207, 52627094, 79774448, 121289083, 184238515, 279539134, 425323502, 645198905, 980219107, 1489922452, 2260654855, 3436993845, 5218261227, 7924709576, 12044652662, 18281147447, 27781957522, 42197020765, 64070022583, 97368852182, 147834978855, 224584638303, 341173540015, 518046647627, 787147300481, 1195381109781, 1815659130799, 2758389036746, 4188741681340, 6363543599430, 9665314578363, 14679604584959],
-[1, 2, 2, 3, 4, 5, 8, 13, 25, 27, 44, 80, 114, 159, 228, 385, 618, 856, 1342, 2035, 3029, 4847, 7101, 10818, 16395, 24672, 38994, 57382, 86780, 134253, 200763, 309465, 465749, 705035, 1083735, 1623798, 2490836, 3782679, 5696284, 8735911, 13187849, 20081040, 30564714, 46134858, 70545146, 106792235, 162080024, 247126186, 373648807, 569289588, 864217603, 1310541153, 1996533315, 3023815661, 4599031497, 6990509043, 10597319051, 16129884300, 24468271975, 37168827298, 56512815282, 85712396778, 130350048928, 197904248892, 300469297747, 456836693400, 693236164449, 1053519893811, 1600327175668, 2429472224328, 3692787696676, 5606140377615, 8516377306291, 12939211161877, 19644372452244],
-[1, 2, 2, 3, 4, 4, 8, 12, 23, 29, 48, 70, 99, 164, 229, 374, 565, 806, 1295, 1934, 2950, 4523, 6574, 10361, 15686, 23520, 36413, 53847, 83243, 126869, 189730, 292944, 438911, 670099, 1024611, 1536895, 2360009, 3564604, 5406530, 8272372, 12458410, 19027790, 28876653, 43706443, 66782251, 100924460, 153591342, 233627553, 353581094, 539180145, 817047574, 1240941927, 1888729316, 2860882212, 4354993575, 6610530812, 10031456874, 15264977059, 23145720761, 35188937873, 53460872414, 81115039600, 123370103237, 187218970596, 284418759589, 432246338096, 655960192428, 997138486364, 1514072576833, 2299317130009, 3494431404464, 5304548162218, 8060254272381, 12242818612379, 18590281231318],
-[1, 2, 2, 3, 4, 4, 8, 11, 19, 28, 49, 66, 91, 165, 234, 346, 517, 788, 1301, 1835, 2770, 4353, 6366, 9990, 15027, 22579, 34924, 51705, 80391, 121998, 181597, 281744, 422709, 644424, 984185, 1476627, 2271512, 3424844, 5197193, 7960866, 11970396, 18290091, 27766128, 42030416, 64218784, 96978310, 147698923, 224667892, 339851651, 518477207, 785486290, 1193048509, 1816014151, 2750266751, 4187700708, 6355060889, 9643821157, 14678418130, 22251313263, 33832586481, 51399634245, 77983522258, 118620824124, 179988494441, 273454364588, 415591556759, 630627290636, 958725353154, 1455668589458, 2210619130122, 3359757700576, 5099818991364, 7749603932863, 11770669634809, 17873023861116],
-[1, 2, 2, 2, 4, 4, 7, 14, 27, 28, 41, 72, 103, 174, 229, 350, 592, 837, 1319, 1938, 2892, 4638, 6675, 10442, 16014, 23433, 36876, 54845, 83833, 129016, 190430, 296410, 446607, 674050, 1040273, 1551535, 2382133, 3620185, 5451845, 8381648, 12598792, 19203046, 29298383, 44130906, 67552492, 102180193, 155085913, 236697014, 357387097, 545136887, 827308841, 1253580902, 1911912107, 2893730106, 4402063059, 6691836570, 10139521894, 15443916953, 23417064321, 35571558915, 54103871071, 82020073993, 124777321835, 189427195720, 287559913862, 437326573678, 663433881604, 1008408467890, 1531852299983, 2325075156523, 3534871963485, 5365636920560, 8151243374274, 12385475955766, 18801013593234],
-[1, 2, 2, 2, 4, 4, 7, 14, 26, 26, 41, 77, 99, 152, 226, 349, 560, 788, 1265, 1900, 2700, 4463, 6624, 9881, 15161, 22568, 35759, 52836, 79482, 124449, 183806, 283084, 430769, 646966, 997402, 1491687, 2285994, 3489332, 5224419, 8036813, 12142411, 18418340, 28146892, 42402861, 64842495, 98274252, 148802547, 227463475, 343539260, 523001211, 795367999, 1204065906, 1836072681, 2780943873, 4226071219, 6431224714, 9739566047, 14829731172, 22505836126, 34157793328, 51975302460, 78801221423, 119827915948, 182012559703, 276180839704, 420085899957, 637413970990, 968446091679, 1471704393465, 2233421232644, 3395272597537, 5154932450224, 7829046990526, 11898137094055, 18060679093424],
-[1, 2, 2, 2, 4, 6, 12, 16, 26, 36, 54, 104, 136, 210, 316, 458, 772, 1114, 1686, 2626, 3810, 6108, 9036, 13436, 21282, 31300, 48410, 73494, 109594, 171150, 254200, 388616, 596258, 888794, 1372922, 2069120, 3136016, 4810824, 7214650, 11059766, 16772836, 25333326, 38823962, 58532522, 89170012, 135713850, 205051446, 313288372, 474098878, 720161292, 1097260146, 1659608164, 2529025012, 3837565042, 5821299176, 8866030628, 13430923760, 20429855022, 31040256574, 47071695350, 71640828468, 108666306676, 165099292816, 250972540526, 380701704128, 578947329090, 878911438420, 1334634280828, 2028895420314, 3078912699956, 4679325913218, 7107370471680, 10790747901906, 16400897427492, 24898792278766],
-[1, 2, 2, 2, 4, 5, 9, 16, 31, 36, 47, 86, 132, 209, 282, 438, 724, 1012, 1607, 2425, 3568, 5621, 8314, 12936, 19589, 28852, 45509, 67961, 102889, 158567, 236185, 364611, 550176, 832234, 1281384, 1915291, 2933379, 4466717, 6729289, 10314031, 15553930, 23684464, 36099012, 54450201, 83245364, 126063580, 191224667, 291685689, 441032262, 671890200, 1020060460, 1546288663, 2356607276, 3569276818, 5427074693, 8250904101, 12506420175, 19036951461, 28879691968, 43863810703, 66702114900, 101156014905, 153835681692, 233587785168, 354602457126, 539173926885, 818173876582, 1243350295669, 1888817635385, 2867217766805, 4358308591997, 6616601841177, 10050888875237, 15271456950567, 23184479933384],
-[1, 2, 2, 2, 4, 4, 7, 14, 25, 26, 41, 78, 105, 163, 227, 348, 583, 819, 1318, 1984, 2818, 4675, 6874, 10303, 15879, 23477, 37491, 55204, 82801, 130211, 191885, 296188, 449489, 674485, 1044394, 1556970, 2386601, 3646770, 5452487, 8401759, 12676442, 19233486, 29420039, 44250103, 67755696, 102660791, 155352549, 237681702, 358684573, 546347298, 830865499, 1257134163, 1918400148, 2904153236, 4413666539, 6718658200, 10170325312, 15491583635, 23505793738, 35673241223, 54296186374, 82292634982, 125163261627, 190115462440, 288431276331, 438814300384, 665714195437, 1011511498654, 1537236595600, 2332544024292, 3546484191190, 5384063317749, 8176951892537, 12427887852302, 18862855225467],
-[1, 2, 2, 2, 4, 4, 6, 12, 24, 24, 36, 62, 87, 141, 189, 290, 479, 688, 1075, 1614, 2352, 3768, 5526, 8506, 13049, 19165, 30280, 44978, 68105, 105457, 156270, 241932, 365075, 550577, 849915, 1269395, 1944639, 2961333, 4454156, 6842286, 10309219, 15686113, 23940240, 36069236, 55179991, 83556975, 126685962, 193406822, 292181209, 445276633, 676242002, 1024439737, 1562130937, 2365321383, 3596407492, 5469284933, 8286611640, 12617951113, 19140144445, 29065924555, 44212803118, 67033064590, 101953540819, 154815678931, 234983218143, 357363875358, 542208655026, 823989409602, 1251871124385, 1900067230141, 2888522204015, 4385050063973, 6660835065195, 10121425511458, 15364518039428],
-[1, 2, 2, 2, 4, 4, 6, 11, 21, 22, 31, 53, 72, 110, 158, 262, 395, 557, 886, 1317, 1933, 3033, 4531, 6951, 10445, 15614, 24511, 36205, 55208, 85176, 126666, 194940, 294335, 447453, 684781, 1024534, 1574059, 2389438, 3603726, 5520276, 8330458, 12689888, 19301846, 29173069, 44585703, 67441967, 102430826, 156132145, 236146330, 359750838, 545910614, 828267578, 1261404507, 1910610098, 2906520031, 4416249888, 6696540300, 10191858161, 15460072097, 23488565543, 35704093665, 54160671970, 82367257718, 125039844557, 189870810639, 288646552995, 438030307388, 665709811582, 1011138964849, 1535172270641, 2333311376020, 3542248891896, 5381422716989, 8175576821367, 12412815158444],
-[1, 2, 2, 2, 4, 4, 6, 12, 24, 24, 33, 56, 85, 150, 183, 271, 453, 678, 1059, 1533, 2284, 3634, 5355, 8305, 12640, 18408, 28932, 43635, 66413, 101450, 150245, 233782, 353200, 532578, 819396, 1225739, 1877969, 2856877, 4309423, 6606525, 9944753, 15151382, 23117670, 34856863, 53261559, 80650083, 122412431, 186699268, 282181478, 430061025, 652820678, 989461355, 1508252923, 2284313497, 3473404593, 5279863203, 8003502799, 12184685386, 18481284983, 28071200045, 42688906448, 64735960035, 98453378776, 149486982799, 226939409931, 345063892677, 523590265261, 795734780275, 1208802072402, 1834918121630, 2789245145121, 4234411242223, 6432390205579, 9773351816816, 14837320374703],
-[1, 2, 2, 2, 4, 4, 7, 14, 27, 28, 39, 70, 101, 173, 230, 353, 578, 832, 1346, 1938, 2861, 4599, 6748, 10539, 15862, 23408, 37029, 54898, 83929, 128814, 190661, 296622, 446248, 675866, 1039925, 1550798, 2386130, 3620562, 5457448, 8382553, 12602158, 19230045, 29292134, 44165831, 67610207, 102185009, 155227483, 236777846, 357610208, 545531648, 827440862, 1254663984, 1912855740, 2894890193, 4405439435, 6694217927, 10146110808, 15452620540, 23427166725, 35596943274, 54126745072, 82066508178, 124855494081, 189510408627, 287742583598, 437549254515, 663785184866, 1009027052940, 1532575730754, 2326461689493, 3536808241093, 5368369078767, 8156141222675, 12391775077351, 18811564517722],
-[1, 2, 2, 2, 4, 4, 7, 14, 28, 29, 39, 72, 112, 176, 231, 356, 598, 841, 1352, 2014, 2899, 4700, 6937, 10786, 16218, 23756, 38108, 56425, 85244, 131975, 195448, 303400, 456868, 690273, 1066142, 1585826, 2437752, 3713718, 5577546, 8573883, 12903310, 19665792, 29998932, 45153469, 69179982, 104641475, 158688663, 242391231, 365935090, 558006620, 847011907, 1283294134, 1957779168, 2962336432, 4506075710, 6852404293, 10379809253, 15810895271, 23975534724, 36415470214, 55394261503, 83966330498, 127746224715, 193945224507, 294375478916, 447746579396, 679222010922, 1032363236251, 1568343209334, 2380302196045, 3619023055800, 5493307732294, 8344881150748, 12680500232169, 19247909533660],
-[1, 2, 2, 2, 4, 4, 6, 12, 23, 23, 37, 64, 86, 126, 182, 291, 453, 659, 1022, 1545, 2250, 3581, 5364, 8049, 12384, 18484, 28863, 42960, 64823, 100796, 149852, 229678, 349351, 526763, 808823, 1214440, 1855223, 2829162, 4254459, 6519131, 9865059, 14966590, 22836718, 34483867, 52625564, 79820957, 120948658, 184558375, 279214535, 424761612, 645782359, 978489313, 1490444327, 2259411722, 3432791878, 5221449700, 7914091867, 12041481947, 18279739065, 27748914360, 42202729676, 64018655310, 97315172729, 147821631567, 224360925157, 341128473966, 517770922682, 786590541817, 1195204767927, 1814260198178, 2757377372479, 4187013646523, 6359136794851, 9662856484670, 14670382489802],
-[1, 2, 2, 2, 4, 4, 7, 13, 26, 26, 32, 60, 93, 160, 200, 316, 517, 730, 1223, 1735, 2556, 4113, 6002, 9527, 14074, 20827, 33426, 48835, 75140, 115203, 170258, 266252, 397252, 605611, 931760, 1381655, 2139257, 3235028, 4878590, 7505202, 11247202, 17225776, 26181835, 39455221, 60554850, 91265705, 138862744, 211797896, 319540819, 488277229, 739414231, 1122030167, 1711400754, 2586803865, 3941524411, 5985230015, 9071041929, 13823788685, 20940084951, 31839755654, 48402640811, 73366355576, 111685781395, 169425842372, 257316967949, 391314715972, 593447467389, 902470747649, 1370334476863, 2080296347608, 3163119932858, 4799787481159, 7294104273933, 11080943669290, 16820549221536],
-[1, 2, 2, 2, 4, 4, 7, 14, 28, 28, 39, 72, 105, 180, 224, 339, 603, 868, 1360, 1932, 2861, 4799, 6929, 10600, 16133, 23718, 37996, 55949, 84947, 131448, 193235, 302418, 455706, 685242, 1059476, 1576370, 2428585, 3692190, 5538927, 8538832, 12824522, 19548105, 29857060, 44888762, 68809667, 104020838, 157821588, 241199346, 363670073, 555000213, 842505280, 1275857148, 1947422969, 2945408079, 4481490557, 6815086177, 10319947405, 15727331009, 23841098557, 36210562909, 55095902707, 83492092946, 127050085489, 192869318585, 292727834752, 445321444766, 675403075370, 1026678972208, 1559736626449, 2366968577133, 3599229987763, 5462753855003, 8298702089913, 12610847314745, 19140454370031],
-[1, 2, 2, 2, 4, 5, 10, 16, 28, 33, 46, 87, 133, 214, 280, 420, 723, 1019, 1600, 2404, 3525, 5628, 8298, 12891, 19553, 28473, 45284, 67966, 102357, 157780, 234393, 363465, 548775, 826432, 1276444, 1905408, 2917648, 4451206, 6692786, 10269685, 15476353, 23559542, 35968226, 54160111, 82844124, 125510260, 190237381, 290443308, 438848897, 668657805, 1015556464, 1538373529, 2346099255, 3552485738, 5400298914, 8213857099, 12445046525, 18948702904, 28745092798, 43649029454, 66398687775, 100669032551, 153106497598, 232507864106, 352884938893, 536672424369, 814292516086, 1237426164615, 1880052110792, 2853451292471, 4337875433596, 6585466103828, 10002913813172, 15200180640566, 23074138386296],
-[1, 2, 2, 2, 4, 4, 8, 16, 31, 32, 46, 88, 124, 207, 281, 420, 697, 990, 1621, 2367, 3407, 5598, 8178, 12593, 19269, 28224, 44827, 66271, 100856, 156692, 229889, 357589, 541004, 814518, 1258518, 1873137, 2880092, 4385005, 6578543, 10136976, 15243308, 23196552, 35441559, 53329269, 81695181, 123564211, 187348967, 286371295, 432015193, 658859917, 1000613463, 1515066910, 2312012421, 3498350162, 5321010469, 8093137008, 12255262634, 18672570615, 28315119080, 42996893551, 65422151958, 99152870836, 150852987108, 229042240222, 347603927007, 528781467473, 802079026740, 1219072297109, 1852183084410, 2810775211193, 4273777116934, 6487148632622, 9854200464591, 14974992534923, 22729330468648],
-[1, 2, 2, 2, 4, 4, 8, 16, 32, 32, 49, 98, 128, 207, 296, 437, 722, 1017, 1672, 2499, 3493, 5821, 8564, 12941, 19981, 29154, 46589, 69026, 103822, 163000, 238476, 369737, 562750, 842167, 1305356, 1942822, 2980582, 4558372, 6807792, 10503807, 15827717, 24010871, 36779018, 55262701, 84648041, 128236419, 194004882, 297038169, 447948247, 682503099, 1038100004, 1569897142, 2396933931, 3627718583, 5513117948, 8393946957, 12702216161, 19354210758, 29363644961, 44557377196, 67834342428, 102789423204, 156355044599, 237500436424, 360274292633, 548201380159, 831564001404, 1263546780496, 1920398293756, 2913596760035, 4430367286377, 6725661805137, 10214248222768, 15525340229021, 23562335330147],
-[1, 2, 2, 2, 4, 4, 7, 13, 25, 26, 37, 66, 96, 147, 207, 327, 514, 747, 1196, 1790, 2600, 4104, 6171, 9456, 14266, 21328, 33511, 49764, 75467, 116506, 173775, 266904, 403763, 612279, 938263, 1406176, 2154936, 3277308, 4939171, 7562876, 11427097, 17385106, 26466033, 39993552, 61087956, 92504394, 140376879, 214013051, 323800259, 492994670, 748552482, 1135313578, 1728977902, 2619777961, 3983403936, 6054642535, 9180301463, 13969368775, 21196640603, 32195368256, 48945736734, 74250800343, 112898712438, 171427064729, 260271869218, 395676255021, 600513078065, 912511205684, 1386172761046, 2104472458752, 3198468611352, 4856128690397, 7376737038494, 11207545287009, 17016293097015],
-[1, 2, 2, 2, 4, 5, 9, 14, 26, 33, 43, 75, 113, 174, 241, 367, 603, 900, 1331, 2040, 3054, 4707, 7123, 10766, 16533, 24616, 37938, 57769, 86814, 133019, 200602, 305872, 465561, 702852, 1075161, 1625378, 2467126, 3765958, 5692624, 8669258, 13156761, 19959162, 30418488, 46046242, 70041475, 106468987, 161375708, 245681678, 372650088, 566081571, 860671933, 1305269283, 1985429416, 3014058503, 4575848756, 6957823897, 10557574529, 16045366742, 24372824691, 36999610879, 56242538480, 85378198539, 129704020220, 197066808562, 299173414968, 454636703732, 690407038020, 1048595391740, 1593214844668, 2419107076855, 3675345087518, 5582396346795, 8477873738098, 12880481475835, 19560402621364],
-[1, 2, 2, 2, 4, 4, 7, 12, 23, 24, 36, 65, 89, 142, 199, 304, 469, 676, 1110, 1677, 2354, 3792, 5717, 8644, 13300, 19480, 30690, 45847, 69096, 107910, 158932, 244604, 373068, 560776, 864178, 1291531, 1975781, 3020793, 4528144, 6957438, 10510756, 15937138, 24370731, 36722918, 56121144, 85092659, 128802213, 196888009, 297485597, 452741972, 688579267, 1042310629, 1589383883, 2407953759, 3658267621, 5567480973, 8432128914, 12837307767, 19485089146, 29570318649, 44994833871, 68222271737, 103730476608, 157577951708, 239095551567, 363660642756, 551838970794, 838375743442, 1274090444032, 1933529892560, 2939256740000, 4462817007067, 6777631186662, 10300356663298, 15635669153735],
-[1, 2, 2, 2, 4, 4, 7, 14, 28, 28, 40, 74, 107, 168, 231, 353, 562, 815, 1323, 1957, 2775, 4491, 6796, 10359, 15627, 23058, 36473, 54454, 82329, 127402, 188673, 290929, 442077, 667063, 1024437, 1531926, 2348714, 3581623, 5381956, 8255191, 12464317, 18944621, 28907166, 43606382, 66634733, 100936180, 153021124, 233622839, 353141447, 537658264, 816908957, 1237846661, 1886601302, 2857897372, 4344142430, 6606752293, 10011572908, 15239881041, 23125259145, 35111671643, 53403281723, 80987721012, 123151512466, 187025709302, 283869978358, 431668213690, 655054301204, 995340069072, 1512271670464, 2295429889890, 3489160062769, 5297423791585, 8046327271898, 12226602557193, 18561278135800],
-[1, 2, 2, 2, 4, 4, 7, 14, 28, 28, 39, 72, 105, 169, 226, 352, 577, 837, 1325, 1953, 2850, 4542, 6821, 10470, 15799, 23377, 36841, 55048, 83282, 128249, 190963, 294716, 445873, 673939, 1034998, 1549738, 2374204, 3614276, 5444507, 8340631, 12589237, 19158672, 29200569, 44073685, 67330857, 101985298, 154722818, 235979236, 356878106, 543478798, 825299965, 1251197168, 1906314217, 2888006622, 4390692854, 6675060072, 10119026560, 15400673836, 23366581114, 35487758205, 53961897196, 81846450164, 124455548890, 188984520857, 286897319734, 436201118410, 661968880828, 1005910340499, 1528136779232, 2319785313151, 3525969811105, 5353257155467, 8131668203899, 12355183466981, 18757747712538],
-[1, 2, 2, 2, 4, 6, 10, 13, 22, 34, 48, 80, 111, 160, 253, 392, 616, 909, 1328, 2093, 3148, 4812, 7382, 10885, 16822, 25557, 38832, 59370, 88379, 136103, 207286, 312381, 478257, 720237, 1098330, 1673322, 2526743, 3861885, 5838249, 8867450, 13528905, 20450998, 31157522, 47274987, 71711336, 109279587, 165466361, 251704663, 382493631, 579857764, 882803376, 1338937050, 2034090888, 3092429576, 4690122128, 7133858928, 10829571174, 16442703891, 24999889534, 37934065529, 57654743024, 87573882151, 132947170898, 202085369387, 306774314020, 466054880385, 708075749284, 1074982821326, 1633585947090, 2480702560074, 3767795200588, 5724616760648, 8692227666488, 13206107655092, 20058173880604],
-[1, 2, 3, 3, 6, 10, 11, 17, 33, 43, 61, 94, 150, 235, 321, 537, 812, 1151, 1847, 2743, 4218, 6398, 9554, 15072, 22105, 33661, 52466, 77630, 119357, 180670, 273830, 420914, 628111, 965032, 1468175, 2205809, 3389974, 5113826, 7777623, 11852278, 17882332, 27366010, 41396469, 62788334, 95863597, 144836740, 220679984, 335112592, 507911699, 774171794, 1172160216, 1782851145, 2710700384, 4107183158, 6253748955, 9486583129, 14407690285, 21911436780, 33225057518, 50535255389, 76727870337, 116471708716, 177122537245, 268741151096, 408411068754, 620453458559, 941788299405, 1431665125780, 2173359190407, 3301484312293, 5016507201698, 7615342638109, 11572747922184, 17574772226260, 26691169322107],
-[1, 2, 3, 3, 4, 6, 11, 22, 28, 34, 62, 96, 140, 202, 309, 498, 713, 1129, 1739, 2476, 3890, 5956, 9077, 13726, 20235, 31809, 48166, 72078, 111214, 166537, 254757, 387957, 585014, 897647, 1348633, 2054980, 3143589, 4730910, 7226358, 10950778, 16617178, 25354365, 38293458, 58383877, 88654534, 134263585, 204793169, 310152269, 471444326, 716845019, 1086371237, 1654353991, 2509018626, 3810116075, 5796243119, 8787724658, 13365060935, 20295416152, 30805644096, 46847257274, 71078181647, 108022222986, 164114404290, 249081734983, 378664059408, 574857316813, 873195461147, 1326842065284, 2014187287195, 3060910748984, 4648438136201, 7059465942856, 10726962911262, 16287045278466, 24744180101449],
-[1, 2, 3, 3, 4, 6, 11, 17, 26, 37, 56, 83, 129, 194, 271, 423, 690, 1059, 1533, 2284, 3584, 5532, 8204, 12424, 18953, 28790, 44113, 66766, 101052, 153062, 232873, 356311, 538186, 815220, 1242570, 1886426, 2868269, 4350840, 6606721, 10048036, 15236213, 23175700, 35210592, 53403061, 81195598, 123291600, 187326865, 284555562, 431877529, 656558775, 996924125, 1513974550, 2300764067, 3492876846, 5306877192, 8060613361, 12241479490, 18600055693, 28241928066, 42903521835, 65175731140, 98972725119, 150365308344, 228364918578, 346868634922, 526930051313, 800232745828, 1215662522920, 1846420347725, 2804395297252, 4260182877265, 6470226836514, 9828270182452, 14928622138621, 22674035287262],
-[1, 2, 2, 3, 4, 5, 9, 15, 27, 33, 48, 77, 114, 204, 278, 388, 645, 951, 1529, 2212, 3219, 5309, 7729, 11896, 18136, 26600, 42254, 62684, 95326, 147220, 217093, 338132, 510215, 768002, 1186316, 1769438, 2717431, 4133337, 6211822, 9561660, 14378300, 21892727, 33430957, 50325933, 77055439, 116574031, 176827691, 270083814, 407628076, 621674098, 943896327, 1429633527, 2181013639, 3300773921, 5020476662, 7634360791, 11564127656, 17616170171, 26712946189, 40569294615, 61718594406, 93552710592, 142322600390, 216083753616, 327975540175, 498857417984, 756744789471, 1150179175852, 1747393512216, 2651969729799, 4032062931334, 6120386706172, 9297306953004, 14127896099766, 21444822352839],
-[1, 2, 2, 3, 5, 6, 9, 16, 25, 35, 51, 83, 117, 174, 272, 418, 630, 921, 1483, 2221, 3293, 5129, 7720, 11746, 17718, 26981, 41572, 62006, 94492, 144833, 217926, 332628, 503967, 765956, 1166937, 1761102, 2691207, 4082606, 6178398, 9428434, 14280176, 21715108, 32992249, 50013699, 76198210, 115481042, 175432080, 266899119, 404560860, 615382185, 934297180, 1418497095, 2157024867, 3271972843, 4974077458, 7555879661, 11467604229, 17435116023, 26465523257, 40205218044, 61088000089, 92732722606, 140937682073, 214008436414, 325029693670, 493892385938, 749853014381, 1139288292309, 1730445353355, 2627920892300, 3992804066068, 6063196090131, 9210520729063, 13991197807561, 21247408684723],
-[1, 2, 2, 3, 4, 5, 8, 13, 23, 26, 40, 64, 97, 159, 217, 323, 525, 787, 1218, 1792, 2662, 4226, 6287, 9637, 14544, 21673, 33910, 50840, 77100, 117858, 176414, 272156, 411292, 621993, 952836, 1432019, 2191145, 3329742, 5028440, 7689663, 11613953, 17681415, 26920607, 40685867, 62083347, 94067028, 142822761, 217563790, 329297673, 501369231, 761161729, 1154629169, 1757992170, 2664503720, 4050848677, 6156009675, 9337140536, 14205361300, 21554646289, 32741537295, 49770961726, 75512967282, 114805012393, 174323692230, 264694537172, 402347239812, 610688650979, 927968257280, 1409589664981, 2140157357985, 3252499431909, 4938363819923, 7501700411953, 11396853674429, 17304702389090],
-[1, 2, 2, 3, 4, 6, 8, 14, 25, 25, 40, 74, 114, 168, 247, 365, 566, 863, 1329, 2010, 2918, 4569, 7024, 10572, 16049, 23973, 36996, 56245, 84881, 130042, 195230, 297594, 454780, 687026, 1047259, 1582798, 2408160, 3674471, 5549409, 8455043, 12833729, 19462513, 29658064, 44920926, 68323830, 103794431, 157375562, 239657002, 363435336, 552029796, 839340518, 1273093422, 1936277935, 2939311152, 4463014092, 6785579075, 10295887279, 15648844927, 23770213187, 36084594444, 54849341070, 83266199245, 126499516735, 192186671058, 291772609266, 443394303230, 673320754767, 1022660217601, 1553792200290, 2359275813170, 3584427061194, 5444231899639, 8268230908221, 12561822176762, 19076411076853],
-[1, 2, 2, 3, 4, 5, 8, 12, 23, 26, 42, 73, 107, 160, 222, 360, 573, 840, 1282, 1896, 2867, 4491, 6716, 10251, 15459, 23209, 36133, 54202, 82300, 125455, 188549, 290200, 438354, 664583, 1014993, 1528918, 2338417, 3549257, 5369802, 8196370, 12392901, 18873753, 28698849, 43431599, 66214327, 100342252, 152443247, 231992613, 351415871, 534875050, 811851409, 1232282311, 1874991506, 2842860481, 4322126748, 6566109425, 9963685604, 15153127198, 22995118718, 34935175895, 53089493514, 80571162035, 122477160392, 185965554778, 282422325509, 429202608321, 651546926849, 990028443649, 1503721446945, 2283415797815, 3469772626120, 5268534754647, 8003521153106, 12158120325526, 18462427473247],
-[1, 2, 2, 3, 4, 6, 9, 13, 26, 30, 41, 76, 123, 177, 247, 374, 593, 915, 1382, 2096, 3062, 4796, 7398, 11061, 16773, 25089, 38885, 59275, 88819, 136014, 205095, 312439, 477168, 719942, 1097932, 1661465, 2524598, 3855930, 5822131, 8861481, 13465477, 20414953, 31111005, 47117476, 71635116, 108907293, 165063731, 251334768, 381280037, 578904795, 880428868, 1335371573, 2030762857, 3083408983, 4680531207, 7117444172, 10800139060, 16412057374, 24933914155, 37847257712, 57529527300, 87341550975, 132674055840, 201590162849, 306034106273, 465050655269, 706270390787, 1072612367662, 1629758866533, 2474634583268, 3759531620578, 5710537214014, 8672245081341, 13175820283039, 20009285421360],
-[1, 2, 2, 3, 4, 5, 10, 11, 17, 30, 49, 69, 100, 173, 249, 380, 584, 832, 1337, 2027, 3056, 4684, 6807, 10788, 16476, 24344, 37689, 56233, 86477, 132151, 197220, 304642, 457562, 695333, 1067870, 1600689, 2450620, 3713102, 5623200, 8610988, 12967918, 19776691, 30079824, 45463152, 69463862, 105097531, 159715172, 243168362, 367932079, 560893051, 850604169, 1290676210, 1965544376, 2977599734, 4530025458, 6880587321, 10436873535, 15883355805, 24088847684, 36607532391, 55638512616, 84402376882, 128358688327, 194844807436, 295914862477, 449797716862, 682588056029, 1037476937672, 1575648372455, 2392406168948, 3636142555203, 5520003937675, 8386479871364, 12740073617862, 19343914283365],
-[1, 2, 2, 3, 4, 5, 10, 12, 20, 32, 51, 69, 108, 184, 243, 369, 590, 869, 1350, 1984, 3087, 4735, 6876, 10919, 16483, 24456, 37826, 56793, 87524, 132061, 198335, 307486, 460333, 700618, 1072362, 1611630, 2468593, 3731055, 5669574, 8660839, 13037576, 19924439, 30255974, 45781442, 69885523, 105709987, 160889072, 244579248, 370323769, 564645670, 855607003, 1299487041, 1977722247, 2996592899, 4560153428, 6921821283, 10506553034, 15984820585, 24238644273, 36850066013, 55983331112, 84949927663, 129185920149, 196061731415, 297857671680, 452626161597, 686951670518, 1044207924375, 1585542177802, 2407912977042, 3659291067070, 5555121342064, 8440759401537, 12820568823991, 19468403439138],
-[1, 2, 2, 3, 4, 5, 7, 10, 18, 26, 41, 54, 86, 141, 190, 320, 469, 681, 1102, 1617, 2502, 3714, 5550, 8974, 13000, 19738, 30619, 45446, 70752, 105557, 160384, 247457, 367446, 568107, 860302, 1293158, 1991968, 2992477, 4572607, 6954156, 10473234, 16079404, 24259443, 36855892, 56260564, 84873244, 129612576, 196468211, 297940561, 454499207, 687083295, 1046457824, 1590061058, 2408886224, 3670768103, 5562380580, 8454343367, 12856014988, 19484999964, 29657885430, 45002154957, 68329892541, 103927729761, 157617666825, 239650241073, 363961337905, 552461991689, 840040606963, 1274795533226, 1937017286602, 2942988027092, 4467159310861, 6790069891272, 10309361657594, 15658905647010],
-[1, 2, 2, 3, 4, 4, 7, 10, 17, 22, 36, 51, 68, 125, 177, 265, 382, 606, 964, 1346, 2068, 3185, 4805, 7419, 11099, 16814, 25632, 38508, 59759, 89818, 134915, 208097, 313657, 478679, 725195, 1096331, 1681099, 2533592, 3857876, 5878937, 8874815, 13546125, 20532199, 31181302, 47481012, 71820624, 109447087, 166158788, 251915999, 383687889, 581467865, 884058590, 1343496325, 2037536621, 3100587818, 4703517833, 7144927209, 10863265543, 16478652927, 25054802688, 38043339948, 57764897487, 87811958238, 133266336461, 202519847921, 307631153017, 467037550140, 709837852087, 1077717669313, 1637132101024, 2487242966694, 3776410415079, 5738273888636, 8714419598870, 13235582541713],
-[1, 2, 2, 3, 4, 5, 7, 11, 20, 26, 41, 60, 87, 134, 200, 328, 481, 702, 1097, 1647, 2530, 3838, 5761, 8807, 13307, 20424, 31044, 46415, 71156, 108229, 163951, 249848, 377337, 575840, 873653, 1323433, 2020513, 3055489, 4645426, 7068299, 10711506, 16308530, 24721896, 37561437, 57146540, 86604687, 131757123, 200058743, 303630935, 461805742, 700612416, 1064895880, 1617627642, 2455049260, 3732991844, 5666281735, 8607162230, 13079250283, 19853609133, 30173229309, 45819422261, 69586686345, 105741044300, 160538119879, 243914000651, 370490954935, 562614772624, 854838550591, 1298116847985, 1971908142218, 2995475263317, 4548945853425, 6910950029115, 10496084227820, 15942379786846],
-[1, 2, 2, 3, 4, 4, 7, 10, 18, 24, 41, 54, 76, 130, 182, 295, 433, 642, 1016, 1475, 2288, 3505, 5135, 8077, 12114, 18293, 28126, 41683, 64890, 98007, 146946, 227044, 339959, 520667, 791959, 1190925, 1830910, 2757185, 4195104, 6406828, 9648263, 14754055, 22351504, 33894174, 51736150, 78139157, 119101198, 180915778, 273995082, 417838166, 632732179, 961940254, 1462936571, 2216513534, 3375244241, 5119703252, 7774181723, 11826705155, 17930497234, 27270252930, 41411247076, 62853682784, 95590026203, 145031572813, 220401956999, 334862029877, 508229996369, 772631856863, 1172944024382, 1781651169450, 2707332031874, 4109746297412, 6245463108257, 9484804568863, 14404090339782],
-[1, 2, 2, 3, 4, 5, 7, 10, 18, 22, 36, 52, 76, 118, 170, 270, 386, 613, 921, 1337, 2138, 3223, 4776, 7308, 11106, 17122, 25681, 38564, 59851, 89855, 136302, 208118, 313917, 480510, 725535, 1103285, 1684067, 2537213, 3875309, 5884446, 8910704, 13586150, 20564142, 31306143, 47562168, 72048851, 109814894, 166440389, 252813009, 384598765, 583005557, 887011624, 1346255414, 2044009922, 3108991020, 4715129419, 7168018159, 10888786698, 16526213060, 25127018873, 38137381327, 57941711673, 88037355594, 133631462419, 203112123010, 308410616644, 468401885301, 711759063574, 1080599953817, 1641881156374, 2493751454165, 3787071585427, 5754190229255, 8737643480416, 13273417948273],
-[1, 2, 2, 3, 4, 4, 7, 9, 18, 25, 43, 54, 73, 135, 194, 313, 436, 662, 1067, 1542, 2425, 3552, 5247, 8443, 12594, 19011, 28899, 43134, 67575, 101076, 152594, 235371, 350724, 540419, 819743, 1235318, 1896512, 2847675, 4356603, 6632291, 9986367, 15299739, 23119398, 35136399, 53579484, 80898335, 123483023, 187185528, 283892064, 432969150, 654933577, 996894856, 1514689636, 2295861352, 3497154226, 5300154440, 8055329300, 12248354035, 18568228972, 28254932566, 42880146218, 65110381627, 99011621263, 150191706850, 228337925630, 346781354399, 526416291082, 800346068547, 1214703794319, 1845609329694, 2804007488110, 4256576343791, 6469431358557, 9822978781168, 14920206004714],
-[1, 2, 2, 3, 4, 4, 7, 10, 18, 23, 38, 52, 76, 123, 167, 263, 399, 589, 934, 1365, 2052, 3156, 4667, 7354, 10954, 16376, 25539, 37816, 58324, 88451, 132675, 205288, 306840, 468931, 716023, 1073955, 1650993, 2491949, 3781912, 5781344, 8705794, 13311185, 20184172, 30555215, 46691119, 70537727, 107401634, 163305929, 247187012, 376990779, 571040877, 867648784, 1320413832, 1999824676, 3044813088, 4620760322, 7013288523, 10671801898, 16179324792, 24602261241, 37371381003, 56705917873, 86250184315, 130873516378, 198841360810, 302169467590, 458562273680, 697112931561, 1058414657017, 1607444420128, 2442907526516, 3708224307857, 5634947602270, 8558550323131, 12996193271143],
-[1, 2, 2, 3, 4, 5, 8, 14, 22, 26, 44, 67, 93, 160, 237, 336, 512, 794, 1255, 1832, 2665, 4276, 6466, 9717, 14752, 22196, 34367, 51578, 78396, 120211, 179570, 275654, 419531, 633272, 966501, 1458797, 2228934, 3387353, 5112724, 7816463, 11833002, 17968383, 27369456, 41425083, 63110395, 95707035, 145264064, 221270889, 335099897, 509689460, 774460726, 1174677660, 1787426078, 2711137711, 4119876857, 6261757766, 9498659457, 14445924307, 21929897408, 33301137870, 50620841661, 76823951153, 116758985135, 177329272194, 269245373076, 409224321389, 621247559452, 943818550409, 1433829740410, 2177028715860, 3308080260618, 5023522360301, 7630334272439, 11592405371487, 17602712740439],
-[1, 2, 2, 3, 4, 4, 8, 12, 23, 30, 48, 71, 105, 183, 240, 369, 601, 879, 1388, 2028, 3048, 4798, 7025, 11018, 16647, 24566, 38568, 57612, 88107, 134295, 200136, 310944, 466947, 707805, 1087383, 1627683, 2497674, 3786233, 5724373, 8770995, 13198937, 20145115, 30658172, 46283767, 70756854, 107023977, 162651237, 247777849, 374641589, 571288463, 866355820, 1314344616, 2002459146, 3032294022, 4613670162, 7008536459, 10628561631, 16179505111, 24533556870, 37282595038, 56673233407, 85956199548, 130741543020, 198452003137, 301370833914, 458151879534, 695184632316, 1056687892142, 1604847166944, 2436547969268, 3703580582376, 5622038015000, 8541579616444, 12976187869818, 19701159324641],
-[1, 2, 2, 2, 4, 4, 8, 16, 32, 32, 46, 92, 128, 206, 273, 422, 741, 1010, 1617, 2412, 3472, 5796, 8354, 12808, 19626, 28562, 46178, 68081, 102270, 159891, 234717, 366404, 552969, 828296, 1287931, 1911627, 2939796, 4487654, 6705650, 10355193, 15566024, 23682170, 36241474, 54381910, 83442316, 126266772, 191176059, 292644224, 440997504, 672760534, 1022264539, 1546331457, 2362141490, 3572123765, 5432132525, 8268236344, 12511303197, 19070889209, 28917089550, 43897995988, 66827791967, 101237200349, 154051624295, 233930706906, 354902499782, 540070548207, 819048234984, 1244846515505, 1891669593484, 2870001156193, 4364685341209, 6624759136142, 10062343162484, 15293828660000, 23209704548340],
-[1, 2, 2, 2, 4, 4, 7, 12, 23, 24, 37, 67, 84, 127, 191, 305, 465, 656, 1050, 1601, 2319, 3690, 5465, 8220, 12764, 19001, 29604, 43908, 66477, 103823, 153681, 235667, 358491, 540184, 830998, 1245923, 1904853, 2903867, 4361998, 6697336, 10125641, 15355918, 23443077, 35378960, 54036430, 81922908, 124105047, 189488862, 286491661, 436001451, 662913097, 1004079380, 1530003293, 2318694547, 3523384355, 5359874921, 8121304052, 12360391288, 18761502762, 28478926942, 43320212411, 65700689026, 99885832745, 151722099329, 230262942688, 350152278668, 531398403008, 807333602530, 1226768465796, 1862011951621, 2830210558428, 4297357458921, 6526744076110, 9918008382650, 15056734338510],
-[1, 2, 2, 2, 4, 4, 7, 13, 25, 26, 37, 67, 96, 153, 205, 310, 511, 732, 1160, 1721, 2457, 3961, 5928, 9073, 13792, 20113, 31961, 47790, 72046, 111665, 164686, 255044, 387110, 582502, 898069, 1339810, 2053980, 3135495, 4705925, 7228517, 10898808, 16567029, 25315071, 38123772, 58304547, 88322757, 133822191, 204475509, 308837386, 470416031, 714839461, 1082491517, 1651009354, 2500059880, 3800025252, 5781074692, 8756783057, 13334507360, 20231025794, 30713434020, 46728215254, 70843026203, 107741635617, 163630033231, 248317038859, 377681779236, 573043491395, 870761410845, 1323099368086, 2007990922164, 3052650021505, 4634427047852, 7039072231960, 10696999103566, 16237594256716],
-[1, 2, 2, 2, 4, 4, 8, 14, 27, 28, 44, 84, 111, 175, 250, 373, 601, 859, 1396, 2134, 2968, 4870, 7257, 10889, 16955, 24686, 39117, 58472, 87639, 137626, 201811, 311280, 476054, 711382, 1100929, 1644024, 2512769, 3850873, 5753379, 8859623, 13383084, 20261302, 31055119, 46706089, 71416556, 108365561, 163810270, 250745052, 378526373, 576044281, 876881416, 1325900861, 2023352240, 3064917169, 4654469980, 7088585382, 10729039656, 16339242664, 24803252111, 37624646999, 57279634760, 86820958356, 132012233197, 200586795906, 304250216069, 462892286593, 702341823577, 1066927809580, 1621781064024, 2460614296329, 3740992137457, 5680202553682, 8625380482065, 13110620448533, 19899137624629],
-[1, 2, 2, 2, 4, 5, 10, 16, 30, 36, 51, 99, 138, 215, 311, 455, 741, 1085, 1690, 2535, 3665, 5918, 8950, 13321, 20464, 30379, 47483, 71688, 107263, 166425, 247862, 379879, 580710, 870742, 1337860, 2013535, 3067585, 4691040, 7046043, 10787894, 16349309, 24763595, 37845672, 57126717, 87076295, 132285526, 200238876, 305656920, 462590082, 703095216, 1069931580, 1620279837, 2468027872, 3743344975, 5683160836, 8648727265, 13107676481, 19938840118, 30281242872, 45947814570, 69896442823, 106035780764, 161140707597, 244851525184, 371552090295, 564936225945, 857595247774, 1302566697230, 1979580694766, 3004670324465, 4566360584927, 6934901313208, 10531000153826, 16003453497894, 24296956783577],
-[1, 2, 2, 2, 4, 4, 7, 14, 28, 28, 40, 78, 104, 168, 251, 375, 563, 832, 1369, 1995, 2848, 4607, 6995, 10530, 15951, 23836, 37244, 55551, 84350, 130574, 193472, 297275, 452920, 683760, 1046640, 1570606, 2406365, 3663510, 5512034, 8449238, 12772928, 19394580, 29574582, 44687464, 68203795, 103338017, 156732399, 239124192, 361684469, 550405685, 836419051, 1267836797, 1930984540, 2926667875, 4448325553, 6763599464, 10252712324, 15601930190, 23679491914, 35953280421, 54671959898, 82935985927, 126087618315, 191490069219, 290681848597, 441948715000, 670755798201, 1019120343478, 1548363175976, 2350473493421, 3572355643267, 5424185221632, 8238850749488, 12518355358553, 19005840051557],
-[1, 2, 2, 2, 4, 4, 6, 11, 21, 23, 32, 56, 77, 127, 179, 277, 430, 642, 1018, 1486, 2163, 3493, 5238, 7894, 12066, 17869, 27957, 41976, 63415, 97829, 145261, 224068, 340652, 512311, 786790, 1181941, 1807058, 2752715, 4142169, 6349578, 9595420, 14569746, 22238321, 33568403, 51227544, 77680485, 117765424, 179675935, 271744678, 413569230, 628605290, 952455785, 1451031044, 2199345879, 3341957597, 5082530837, 7703842052, 11723330447, 17793266797, 27012983788, 41083850735, 62317573097, 94737438425, 143894289565, 218410203146, 332084107052, 504010722703, 765747354367, 1163483087024, 1766095971236, 2684271794472, 4075830181865, 6190502419412, 9406456652090, 14280931767990],
-[1, 2, 2, 2, 4, 4, 6, 11, 21, 23, 30, 54, 79, 131, 174, 271, 445, 635, 996, 1445, 2193, 3505, 5092, 7844, 11940, 17791, 27881, 41272, 63035, 96934, 144052, 223277, 335972, 508430, 782086, 1170136, 1795953, 2723819, 4109724, 6307298, 9495243, 14475003, 22044775, 33265630, 50871352, 76953206, 116885297, 178189630, 269297017, 410603849, 622997854, 944727094, 1439642274, 2179857475, 3316357901, 5039358626, 7639721838, 11631383576, 17638500989, 26798867374, 40745525687, 61791212360, 93986682396, 142674146775, 216635016878, 329379191695, 499762813222, 759606732938, 1153773444592, 1751542163796, 2662499621516, 4041695780468, 6140243041399, 9328790451033, 14162693612983],
-[1, 2, 2, 2, 4, 4, 6, 11, 20, 21, 32, 50, 66, 110, 156, 256, 379, 591, 882, 1264, 2017, 3043, 4551, 6964, 10537, 16112, 24287, 36648, 56565, 84890, 128693, 197459, 297491, 453879, 687122, 1043604, 1593328, 2402223, 3665959, 5570483, 8428950, 12855310, 19474165, 29611459, 45008031, 68192302, 103898125, 157548659, 239193219, 363967989, 551786899, 839183616, 1274263697, 1934224459, 2941800277, 4462581340, 6782457596, 10305207450, 15639051846, 23776157385, 36094324097, 54827279774, 83312584973, 126464062790, 192196008544, 291872494873, 443245915968, 673546762551, 1022638627734, 1553665618298, 2359965222628, 3583786786065, 5445181293714, 8268891390642, 12560577678164],
-[1, 2, 2, 2, 4, 5, 9, 14, 26, 32, 44, 78, 112, 177, 260, 385, 610, 911, 1407, 2103, 3083, 4913, 7390, 11088, 16996, 25390, 39468, 59426, 89460, 137994, 206361, 316193, 481512, 725036, 1111387, 1674840, 2552627, 3892542, 5864553, 8967329, 13584468, 20606616, 31433490, 47511200, 72400628, 109916362, 166586860, 253966025, 384581088, 584721111, 889059946, 1347515826, 2051404172, 3111642996, 4726071836, 7187655923, 10899032092, 16575969230, 25169388716, 38207041623, 58097576629, 88155819734, 133971734859, 203523195175, 308931108079, 469616573688, 712934649592, 1082969750065, 1645518507251, 2498077537359, 3796135069310, 5764974536629, 8755448950046, 13303380212382, 20199506987456],
-[1, 2, 2, 2, 4, 4, 7, 14, 27, 27, 41, 78, 104, 155, 232, 363, 561, 793, 1287, 1935, 2737, 4460, 6676, 10011, 15380, 22841, 35914, 53294, 80429, 125683, 185666, 285293, 434937, 654005, 1006384, 1507184, 2306732, 3520970, 5278971, 8111450, 12262484, 18593203, 28407445, 42835486, 65448324, 99222510, 150258569, 229580444, 346943553, 527992669, 802981360, 1215849491, 1853336352, 2808149649, 4266808934, 6492401778, 9834675061, 14970820757, 22723551669, 34488205990, 52470744430, 79567373708, 120975118583, 183763723846, 278856312315, 424100944962, 643581537319, 977759725561, 1485837587087, 2255031261831, 3427814566821, 5204666110430, 7904499417989, 12012352484564, 18235164151367],
-[1, 2, 2, 2, 4, 4, 7, 13, 25, 26, 37, 69, 95, 149, 201, 327, 552, 754, 1203, 1776, 2604, 4284, 6193, 9484, 14449, 21362, 34209, 50175, 75881, 118138, 174292, 271119, 408287, 615043, 951676, 1416027, 2179202, 3315183, 4970810, 7660823, 11522650, 17549760, 26788438, 40293703, 61772459, 93412228, 141645264, 216479447, 326540900, 498134934, 756340354, 1145409823, 1747973419, 2644186145, 4022668347, 6118171257, 9264527699, 14116314530, 21403260304, 32505624012, 49457111917, 74952588804, 114044894526, 173140723806, 262775785318, 399745439942, 606325226866, 921599418482, 1400148561810, 2124825505521, 3230886939051, 4903918354076, 7449490537403, 11320492916322, 17182325524653],
-[1, 2, 2, 2, 4, 4, 6, 11, 21, 23, 31, 56, 75, 120, 170, 275, 431, 607, 968, 1409, 2096, 3386, 4952, 7555, 11480, 17179, 27040, 39791, 60711, 93726, 138892, 215169, 324505, 490891, 754156, 1127657, 1733909, 2630250, 3960424, 6084759, 9164716, 13961977, 21266935, 32087006, 49088485, 74233659, 112730462, 171959823, 259773510, 396055998, 601081961, 911347561, 1388938793, 2102761659, 3199245059, 4862104010, 7369264854, 11221212273, 17016772799, 25851561657, 39309242697, 59608527641, 90671295953, 137641870705, 208978466059, 317769133239, 482120926005, 732784704698, 1113086030976, 1689686154682, 2568574334795, 3899052749042, 5923458143901, 8999769018886, 13662625224808],
-[1, 2, 2, 2, 4, 4, 7, 13, 25, 27, 41, 68, 93, 152, 221, 327, 511, 769, 1216, 1772, 2591, 4196, 6304, 9455, 14430, 21584, 33598, 50304, 76323, 117595, 174829, 268960, 409494, 617083, 944491, 1421397, 2173819, 3307449, 4982628, 7631034, 11539973, 17521092, 26719420, 40385136, 61585514, 93371456, 141640839, 215969141, 326791531, 497224428, 755660759, 1145511013, 1744205284, 2644446699, 4018627591, 6109816449, 9263885519, 14094184619, 21393171670, 32481709441, 49389694446, 74933175169, 113904689543, 172997791701, 262623363069, 399247242456, 606004608826, 920696048723, 1398817118761, 2123556971065, 3227261309943, 4900482302728, 7443259555242, 11309199951389, 17170912779547],
-[1, 2, 2, 2, 4, 4, 7, 14, 28, 29, 40, 75, 110, 171, 234, 360, 593, 845, 1334, 1991, 2879, 4607, 6874, 10585, 15998, 23563, 37282, 55709, 84135, 129820, 192892, 297864, 450948, 680812, 1047478, 1565720, 2398921, 3657140, 5500794, 8433913, 12725898, 19362577, 29533931, 44534330, 68074829, 103117480, 156353639, 238623209, 360725721, 549383520, 834432131, 1264546143, 1927446676, 2919399076, 4438072172, 6748956360, 10227974462, 15569377400, 23622151566, 35871307523, 54555995527, 82732859571, 125812786130, 191055865031, 290002239526, 440982408003, 669173910936, 1016853270312, 1544871127720, 2344960834898, 3564506481272, 5411643813620, 8220075874002, 12490337664544, 18961764732285],
-[1, 2, 2, 2, 4, 4, 8, 16, 32, 32, 47, 94, 128, 201, 293, 436, 696, 999, 1635, 2419, 3389, 5588, 8430, 12672, 19277, 28466, 45155, 67265, 101210, 157789, 232698, 358850, 547218, 821904, 1265339, 1891572, 2899229, 4428864, 6633986, 10196098, 15402540, 23368841, 35720482, 53820009, 82268627, 124691309, 188818457, 288624370, 435981897, 663630395, 1009190359, 1527878083, 2329793860, 3529040375, 5362464947, 8160314680, 12359145006, 18817461253, 28558623598, 43343815948, 65950708297, 99994440628, 152050569800, 230960656449, 350455809322, 533044277394, 808837092162, 1228881041810, 1867468586924, 2834061424227, 4308282629061, 6541236784977, 9934461052062, 15097700848403, 22917750150652],
-[1, 2, 2, 2, 4, 4, 8, 15, 27, 29, 42, 79, 112, 182, 250, 378, 621, 887, 1451, 2086, 3024, 5007, 7342, 11245, 16982, 25191, 40122, 59031, 89890, 139204, 205083, 319009, 481701, 726820, 1119567, 1668434, 2570297, 3905100, 5863615, 9027892, 13580914, 20689243, 31557962, 47531185, 72805859, 110054456, 167026795, 255114827, 384968811, 587173469, 891313684, 1350515712, 2059957017, 3116876506, 4742410700, 7210093836, 10921772062, 16638681634, 25227872916, 38319485446, 58288292356, 88355973703, 134429687170, 204070280742, 309770046090, 471160745348, 714702968038, 1086349557676, 1650300867687, 2504739487550, 3808218859469, 5780326856966, 8781278014177, 13343216419615, 20253844285044],
-[1, 2, 2, 2, 4, 4, 7, 13, 23, 24, 33, 64, 96, 159, 205, 304, 507, 741, 1206, 1724, 2502, 4072, 5999, 9359, 14071, 20530, 32718, 48809, 74308, 114034, 168232, 262585, 395917, 597358, 920669, 1371530, 2110271, 3208442, 4827031, 7419472, 11150894, 17007938, 25946502, 39070404, 59822860, 90454398, 137311562, 209623876, 316435138, 482694023, 732449086, 1109969882, 1693116460, 2561996114, 3897819551, 5925264440, 8977540447, 13675521660, 20733783957, 31496667741, 47906155954, 72620950574, 110488169974, 167724298446, 254612729339, 387235017039, 587418059026, 892901621791, 1356360376509, 2058687701567, 3129976861967, 4750874237883, 7217481217318, 10966674794637, 16646956587182],
-[1, 2, 2, 2, 4, 5, 9, 13, 25, 32, 41, 66, 97, 167, 242, 349, 563, 830, 1283, 1998, 2890, 4461, 6680, 10323, 15998, 23264, 36062, 54957, 82721, 127418, 190200, 291484, 443596, 667494, 1027939, 1545588, 2347036, 3589724, 5411869, 8273284, 12519561, 18985600, 29006638, 43791998, 66745871, 101404303, 153527372, 234157752, 354610327, 539162171, 819903027, 1241937992, 1891693428, 2869492041, 4356622516, 6628130355, 10048405216, 15283243092, 23208359642, 35223848798, 53573761432, 81277305289, 123516870984, 187673096692, 284821196510, 433007105440, 657345755653, 998479499408, 1517296777937, 2303172722472, 3500147359177, 5315585686676, 8072338757126, 12266430194693, 18624179484742],
-[1, 2, 2, 2, 4, 5, 9, 14, 26, 32, 42, 75, 112, 188, 261, 368, 612, 911, 1391, 2122, 3094, 4875, 7287, 11156, 17310, 25112, 39080, 59715, 89686, 138156, 205431, 316247, 482348, 722600, 1114015, 1674541, 2545073, 3895384, 5861874, 8972457, 13570896, 20572689, 31472412, 47461061, 72351244, 109942965, 166400924, 253970933, 384347802, 584442076, 889105353, 1346062117, 2051174106, 3110857326, 4722493943, 7186835288, 10891986841, 16570287793, 25161862733, 38181484532, 58087468297, 88109103176, 133906295285, 203472497227, 308752735322, 469463326861, 712626605223, 1082437644829, 1645031796229, 2496769213793, 3794670920780, 5762788154130, 8751081598604, 13298808746364, 20190231138618],
-[1, 2, 2, 2, 4, 5, 9, 14, 27, 34, 45, 76, 114, 190, 267, 373, 609, 931, 1399, 2134, 3142, 4900, 7416, 11184, 17322, 25532, 39261, 60235, 90359, 138846, 208158, 317753, 486048, 729915, 1119095, 1691618, 2563785, 3922552, 5917901, 9024826, 13695276, 20737857, 31677631, 47903587, 72848580, 110827147, 167796874, 255725771, 387705767, 588764588, 895923562, 1357485243, 2065845532, 3136536701, 4759455888, 7241017827, 10982518285, 16693738146, 25362999361, 38486182870, 58525165307, 88828027677, 134934096947, 205064089072, 311228169782, 473042433766, 718337811954, 1090881932849, 1657800615835, 2516740080935, 3823913452012, 5808378205946, 8819965378465, 13401945005208, 20350754878960],
-[1, 2, 3, 3, 5, 8, 11, 21, 32, 38, 58, 95, 152, 212, 296, 523, 789, 1136, 1763, 2633, 4112, 6106, 9240, 14458, 21208, 32701, 50248, 74887, 115119, 173206, 264706, 403971, 604805, 931413, 1408276, 2128649, 3261457, 4917672, 7502400, 11384112, 17232475, 26350481, 39798127, 60536146, 92175556, 139443904, 212572700, 322244765, 489364646, 744906440, 1128073573, 1717346316, 2607430616, 3955335077, 6019840516, 9128502576, 13876010048, 21083506869, 31986853980, 48652999517, 73833853432, 112153434171, 170468943067, 258682264036, 393214274565, 597106822331, 906726398361, 1378075830505, 2091883929617, 3178543166469, 4828197914671, 7331043548946, 11140337163927, 16915840569272, 25695909026206],
-[1, 2, 3, 3, 5, 8, 11, 14, 27, 42, 60, 81, 128, 217, 296, 449, 702, 1063, 1600, 2403, 3765, 5601, 8320, 13029, 19788, 29677, 45168, 68604, 105053, 158236, 240171, 368037, 553600, 843672, 1285396, 1945477, 2961374, 4483674, 6831637, 10384325, 15707883, 23946083, 36333437, 55139384, 83889889, 127204321, 193492333, 293704716, 445817146, 678332440, 1028744420, 1563296360, 2375682216, 3605384415, 5481163774, 8320081770, 12639207834, 19206898384, 29151382871, 44306509187, 67286396805, 102177276034, 155269937437, 235742445542, 358167042166, 544033617720, 826133303510, 1255282746945, 1906190067071, 2895507281934, 4398664855296, 6679743721362, 10148074313114, 15412673322599, 23409910181014],
-[1, 2, 3, 3, 5, 8, 11, 17, 25, 37, 61, 85, 135, 212, 296, 493, 714, 1079, 1689, 2449, 3937, 5772, 8637, 13692, 20106, 31006, 46989, 70394, 109779, 163073, 249247, 382084, 570082, 880432, 1327126, 2011166, 3082995, 4626775, 7094369, 10754019, 16245797, 24890923, 37534875, 57187141, 87010700, 131503070, 200876842, 303990127, 461854239, 703548761, 1064239232, 1621670878, 2460674730, 3733154258, 5684880221, 8612442285, 13100725053, 19903170047, 30185396495, 45937811070, 69680155707, 105870282280, 160926918549, 244125894579, 371241107016, 563579157668, 855834986197, 1300976085719, 1974324270102, 3000567657746, 4557431345618, 6919522034336, 10516741437589, 15965986892806, 24255747625672],
-[1, 2, 3, 3, 4, 6, 11, 20, 26, 34, 62, 92, 140, 210, 301, 459, 698, 1118, 1667, 2384, 3760, 5815, 8648, 13120, 19860, 30538, 46049, 69503, 107297, 160868, 244092, 373652, 564890, 860775, 1301004, 1980334, 3020456, 4557230, 6950065, 10557152, 15992738, 24365392, 36925704, 56168352, 85311377, 129324469, 196996546, 298728417, 453647831, 689936623, 1046420419, 1591330209, 2415811234, 3668222277, 5577523213, 8461938912, 12861407484, 19538156299, 29657617177, 45080900107, 68438437371, 103969979963, 157961924042, 239806975289, 364433575856, 553417140549, 840514016601, 1277092051409, 1939120214330, 2946056418600, 4474700307469, 6795688349941, 10324753785409, 15679100257439, 23817373324248],
-[1, 2, 2, 3, 4, 5, 7, 12, 20, 25, 39, 56, 79, 137, 207, 308, 443, 682, 1098, 1628, 2445, 3667, 5551, 8724, 13094, 19637, 29980, 45159, 69680, 105521, 158759, 243007, 366557, 560540, 852303, 1285081, 1963437, 2971754, 4521331, 6884473, 10403759, 15860715, 24061331, 36520469, 55621294, 84213103, 128131523, 194651308, 295248537, 449401366, 681390483, 1035488370, 1574020936, 2387604366, 3631433346, 5511742101, 8370570985, 12724931054, 19309194400, 29349817910, 44573383077, 67675705159, 102865620448, 156152419790, 237245310435, 360406854361, 547200974657, 831547920016, 1262694949179, 1917960332198, 2913899722729, 4424544083727, 6722334185050, 10209830822889, 15506444982284],
-[1, 2, 2, 3, 4, 6, 7, 11, 21, 21, 32, 58, 90, 124, 173, 284, 450, 647, 968, 1467, 2240, 3470, 5138, 7892, 11925, 17989, 27945, 41646, 63285, 96752, 146068, 223656, 336797, 512675, 783341, 1179476, 1801714, 2734232, 4141874, 6316660, 9554615, 14555557, 22109817, 33480249, 51049676, 77353824, 117524763, 178769110, 270933560, 412354964, 625703609, 950059291, 1445205870, 2191377201, 3331942627, 5061090485, 7681307136, 11679986003, 17725059127, 26932465823, 40921154472, 62109955925, 94411870261, 143346378929, 217716846672, 330836804991, 502251612491, 763178497682, 1159095781417, 1760233246041, 2674641209669, 4061217378889, 6169633966523, 9371856087339, 14232023954665],
-[1, 2, 2, 3, 4, 5, 8, 12, 23, 28, 47, 74, 109, 174, 249, 394, 588, 868, 1403, 2023, 3083, 4812, 7121, 11050, 16456, 25048, 38819, 57410, 88739, 134545, 201947, 311708, 467699, 714680, 1087460, 1635812, 2515524, 3792849, 5758387, 8798111, 13263922, 20265795, 30716123, 46559979, 71072408, 107387782, 163583387, 248622112, 376478451, 573907276, 869508911, 1321540889, 2010034639, 3045501489, 4636569784, 7035225171, 10680617184, 16248245268, 24637906140, 37463436672, 56898981675, 86357231075, 131327917254, 199277321654, 302796813982, 460085761488, 698294602187, 1061485647053, 1611614231878, 2447810440914, 3719658806653, 5646663029157, 8580526613901, 13031780999314, 19790114140476],
-[1, 2, 2, 3, 4, 6, 8, 11, 22, 22, 33, 63, 115, 158, 207, 343, 522, 810, 1210, 1780, 2786, 4171, 6415, 9837, 14459, 22295, 34036, 51423, 78697, 117962, 180711, 274853, 414057, 635904, 958559, 1455246, 2221687, 3357358, 5125853, 7756741, 11770710, 17964848, 27157413, 41354708, 62817867, 95201793, 145050414, 219798510, 334166646, 507968062, 769918560, 1172047961, 1778381504, 2700379953, 4106499822, 6228518343, 9470935571, 14381798026, 21832332456, 33195803194, 50374610905, 76547157517, 116295010281, 176532789154, 268322572190, 407381738541, 618811745044, 940237342858, 1427438640099, 2169054027002, 3294169572340, 5002884734160, 7601402398087, 11542310009657, 17535033095792],
-[1, 2, 2, 3, 4, 5, 9, 14, 25, 29, 48, 83, 119, 196, 272, 396, 652, 964, 1476, 2168, 3239, 5273, 7730, 11644, 17934, 26537, 41557, 62385, 94229, 145188, 215698, 333853, 506619, 760076, 1169942, 1757838, 2686312, 4091831, 6156744, 9443687, 14259079, 21659608, 33079659, 49886062, 76150839, 115496045, 175088374, 267151306, 403895530, 614908985, 934626157, 1415766723, 2157483441, 3269625243, 4968238753, 7556466780, 11452484962, 17430250058, 26451933126, 40157864290, 61083477648, 92641813889, 140846057207, 213927908275, 324696655952, 493722167082, 749282101510, 1138429605620, 1729766064519, 2625535718885, 3990753262566, 6059437486335, 9203224028511, 13984640629437, 21230829028146],
-[1, 2, 2, 3, 4, 5, 8, 13, 22, 26, 39, 65, 101, 156, 213, 310, 510, 775, 1184, 1743, 2583, 4058, 6065, 9406, 14155, 20844, 32693, 49473, 75155, 114034, 170616, 263960, 398901, 603565, 923861, 1388018, 2123079, 3228238, 4880702, 7454337, 11251626, 17144867, 26107076, 39451057, 60181702, 91192013, 138493263, 210906377, 319282636, 486151036, 737860307, 1119450709, 1704455304, 2583431189, 3927478526, 5967856174, 9053178709, 13772702899, 20897040562, 31745150443, 48253133198, 73212389335, 111307605510, 169009373465, 256636903391, 390077782710, 592078479888, 899712671749, 1366613691654, 2074966475902, 3153382775578, 4787866652400, 7273208527075, 11049444759557, 16777539464559],
-[1, 2, 2, 3, 4, 5, 8, 14, 23, 28, 46, 64, 98, 178, 254, 356, 552, 842, 1342, 1965, 2921, 4549, 6745, 10635, 16096, 23708, 36613, 55178, 85034, 129094, 192337, 297214, 448534, 681095, 1042137, 1564649, 2394710, 3631011, 5502904, 8414152, 12677626, 19326742, 29406941, 44482065, 67877664, 102731842, 156161942, 237701910, 359818596, 548229539, 831542420, 1261978360, 1921205301, 2911589345, 4428535387, 6725893839, 10204642124, 15526584851, 23551982601, 35788727955, 54388878729, 82523040640, 125479967138, 190488332338, 289308059174, 439706606379, 667355694752, 1014248749255, 1540387350686, 2338987794059, 3554636107211, 5396680363911, 8198915453831, 12454815601766, 18911807623825],
-[1, 2, 2, 3, 4, 4, 8, 12, 23, 30, 51, 75, 103, 178, 260, 390, 579, 860, 1414, 2054, 3070, 4803, 7053, 11062, 16701, 24971, 38619, 57400, 88979, 135358, 201283, 311595, 468991, 714036, 1090492, 1636296, 2514547, 3799053, 5757359, 8816559, 13272047, 20256235, 30774364, 46573000, 71136717, 107501391, 163598479, 248964500, 376651249, 574316483, 870559756, 1321829426, 2012136333, 3047922820, 4639234973, 7042685573, 10685695954, 16262685700, 24659141418, 37484040627, 56955158859, 86412745622, 131426358801, 199454277227, 302984043907, 460492260320, 698803130876, 1062241959722, 1613026999339, 2449445967653, 3722680644659, 5651089188823, 8586580070368, 13042726646376, 19804316532169],
-[1, 2, 2, 3, 4, 6, 9, 11, 18, 28, 40, 57, 94, 135, 196, 312, 465, 741, 1050, 1614, 2586, 3694, 5745, 8703, 13147, 20421, 30049, 46543, 70738, 105592, 164109, 245603, 373372, 571655, 858362, 1319930, 1989455, 3015528, 4624949, 6953248, 10622768, 16133028, 24398251, 37297755, 56335533, 85778355, 130508988, 197332522, 301128174, 456284922, 692816886, 1054847209, 1597244856, 2432023187, 3691397997, 5600606345, 8525599498, 12923285806, 19648341624, 29855284629, 45290122799, 68894359205, 104538739275, 158812292556, 241384334702, 366256451907, 556814875757, 845467988590, 1283863358403, 1951368970501, 2961940924585, 4500831800465, 6836480822239, 10380313796928, 15774862924304],
-[1, 2, 2, 3, 4, 4, 7, 10, 20, 28, 49, 62, 88, 160, 221, 349, 515, 768, 1225, 1777, 2769, 4196, 6093, 9704, 14660, 21973, 33707, 50065, 78129, 117884, 176612, 273476, 408320, 625423, 954078, 1433157, 2200905, 3313663, 5047702, 7712326, 11597458, 17745157, 26892929, 40757891, 62238006, 93989148, 143261989, 217604508, 329504853, 502721272, 761057915, 1156857762, 1759873990, 2665975196, 4059991053, 6158112092, 9350556566, 14226762967, 21565821029, 32801796091, 49813643690, 75597645609, 114982236600, 174447247242, 265105110572, 402794483251, 611290166968, 929370293307, 1410860552254, 2142976133126, 3256555012974, 4943252371393, 7512267865560, 11408722877542, 17325436912614],
-[1, 2, 2, 3, 4, 5, 7, 9, 17, 20, 32, 47, 75, 108, 142, 236, 360, 585, 845, 1236, 1950, 2920, 4489, 6716, 10040, 15634, 23552, 35822, 54698, 81740, 125648, 190746, 288497, 440857, 663512, 1014562, 1542177, 2329670, 3560938, 5382407, 8185775, 12463456, 18856517, 28749043, 43566963, 66149599, 100753034, 152563458, 232164059, 352550226, 534849263, 814046493, 1234288119, 1876163317, 2851056365, 4324933554, 6578586528, 9984203105, 15164563697, 23050011908, 34977811814, 53169820222, 80740683055, 122599699733, 186340886438, 282861079734, 429784018393, 652861085254, 991272447968, 1506359225038, 2287309289285, 3474478669333, 5278469529594, 8015055668707, 12177670885231],
-[1, 2, 2, 3, 5, 5, 9, 16, 22, 28, 48, 71, 99, 153, 241, 389, 530, 838, 1296, 1872, 2936, 4377, 6703, 10232, 15332, 23756, 35419, 53556, 83054, 124277, 189411, 287893, 435633, 667998, 1003906, 1531438, 2334925, 3517404, 5381149, 8149927, 12368629, 18846194, 28493894, 43466044, 65930680, 99928764, 152352343, 230706857, 350863951, 533264441, 808451426, 1230778168, 1866358152, 2835658993, 4312043054, 6538200856, 9944626990, 15098323638, 22923207270, 34853420664, 52882824939, 80378285005, 122091127654, 185335467516, 281738303801, 427685777128, 649718623823, 987148523110, 1498643860614, 2277437857934, 3458385396669, 5252665118858, 7980926062909, 12117904410585, 18410783800177],
-[1, 2, 2, 3, 4, 4, 7, 10, 20, 28, 47, 58, 84, 151, 214, 341, 470, 707, 1194, 1738, 2661, 3899, 5798, 9458, 13928, 20991, 32069, 47736, 75195, 112249, 169019, 260979, 388738, 600696, 909943, 1367712, 2105093, 3159669, 4833385, 7361159, 11070658, 16980616, 25645222, 38973382, 59478118, 89701806, 136998474, 207702093, 314893647, 480475403, 726404373, 1105986915, 1680714962, 2546462284, 3880483628, 5879753681, 8935874500, 13590361812, 20597226738, 31348906873, 47571723326, 72227551942, 109857365771, 166612235982, 253322996250, 384739266099, 583971022807, 887968567860, 1347566937415, 2047513152788, 3110933310789, 4722048678230, 7177484623882, 10897722389682, 16552232630994],
-[1, 2, 2, 3, 4, 5, 7, 12, 19, 24, 35, 47, 75, 126, 174, 280, 432, 627, 971, 1454, 2198, 3353, 5040, 7860, 11807, 17674, 27324, 40962, 62500, 95272, 143627, 220007, 331225, 505164, 771303, 1160234, 1772876, 2689306, 4079904, 6219178, 9399396, 14327908, 21756236, 32961183, 50254820, 76115754, 115691749, 175929587, 266679808, 405947852, 615740453, 935160154, 1422442781, 2156837477, 3279919404, 4980862800, 7560686591, 11496565651, 17445058709, 26510873392, 40275330809, 61132360660, 92929812740, 141084147409, 214301406075, 325625651801, 494339290370, 751198770856, 1140821486392, 1732569879093, 2632565913113, 3997235800926, 6072718515972, 9224234858638, 14008162492735],
-[1, 2, 2, 3, 4, 4, 7, 10, 17, 25, 45, 56, 77, 132, 189, 325, 470, 686, 1081, 1599, 2531, 3720, 5474, 8776, 13026, 19902, 30450, 45152, 70302, 105380, 160185, 246337, 366311, 565075, 857095, 1292337, 1983940, 2980181, 4555954, 6930442, 10449329, 16012337, 24175149, 36744630, 56039822, 84632400, 129164560, 195760880, 296988004, 452826538, 684992108, 1042892593, 1584258055, 2401374115, 3657783147, 5543875044, 8426383534, 12810439951, 19422039147, 29554912554, 44849717000, 68106094755, 103562266776, 157096396581, 238838637754, 362714237328, 550634897459, 837136860597, 1270526419514, 1930500634351, 2932881473587, 4452302116735, 6766872486633, 10274479184044, 15606375287860],
-[1, 2, 2, 3, 4, 5, 7, 10, 20, 25, 41, 59, 102, 157, 209, 335, 505, 773, 1190, 1733, 2690, 4117, 6177, 9546, 14141, 21611, 33344, 49861, 76489, 115179, 175033, 268760, 402857, 616669, 935716, 1413966, 2165300, 3267515, 4976385, 7568428, 11442037, 17479780, 26459818, 40180403, 61203019, 92621572, 141096945, 214113383, 324840023, 494613726, 749428426, 1139856959, 1731888606, 2626621601, 3996720320, 6063276555, 9212111567, 14002296583, 21242422854, 32301061914, 49039430687, 74468136960, 113197420878, 171795583104, 261079187244, 396550417824, 602090085492, 915066130112, 1389253026739, 2110517015931, 3206288294924, 4868263793372, 7397328191981, 11233670164303, 17062590586902],
-[1, 2, 2, 2, 4, 5, 10, 16, 29, 34, 49, 97, 134, 214, 297, 444, 773, 1064, 1658, 2522, 3681, 6032, 8763, 13322, 20587, 30060, 47875, 71503, 106910, 166746, 246586, 382144, 579384, 866218, 1343924, 2006732, 3068585, 4692562, 7024439, 10811717, 16307461, 24754607, 37889419, 56953594, 87152890, 132181330, 199993965, 305843656, 461693962, 703218740, 1069485488, 1617912066, 2469090198, 3738663124, 5680145113, 8647270067, 13091641849, 19938545630, 30255008599, 45913931867, 69886138482, 105923898955, 161088246552, 244708928285, 371247347447, 564781391917, 856872011597, 1301908302035, 1978610143553, 3002248475986, 4564621145925, 6930071277832, 10524502299105, 15995961547905, 24278953236522],
-[1, 2, 2, 2, 4, 4, 8, 16, 32, 32, 50, 100, 128, 199, 304, 448, 717, 1035, 1647, 2429, 3449, 5783, 8649, 12716, 19612, 29199, 46014, 68575, 102844, 160840, 237270, 365366, 559276, 835981, 1287122, 1931327, 2954032, 4514798, 6754824, 10386171, 15714978, 23793425, 36408721, 54862730, 83775189, 127129264, 192382793, 294127259, 444357500, 675931452, 1028836235, 1556893030, 2373601480, 3597193752, 5463252095, 8316595264, 12594856264, 19172647264, 29107954554, 44161948180, 67207123091, 101908833575, 154922219944, 235380312377, 357111394111, 543173934286, 824300675264, 1252150208975, 1903117304496, 2888010836442, 4390048794909, 6666179802318, 10123050814976, 15385225778230, 23354427174898],
-[1, 2, 2, 2, 4, 4, 8, 16, 32, 32, 41, 82, 128, 205, 266, 412, 687, 955, 1557, 2318, 3318, 5299, 7885, 12433, 18585, 26994, 43248, 64449, 97676, 150534, 222718, 346017, 521280, 788804, 1216848, 1808581, 2781016, 4237307, 6370519, 9784124, 14717582, 22446761, 34231893, 51538945, 78951348, 119393626, 181119983, 276582000, 417637260, 636880612, 966441909, 1464589513, 2234209222, 3380618300, 5142723831, 7819204312, 11846045895, 18043673962, 27359751556, 41560199054, 63213271758, 95823082464, 145788011278, 221323792162, 335955559416, 510958879985, 775124949472, 1178170821224, 1789751557386, 2716459174470, 4130038190367, 6268908163259, 9523404172197, 14470815617849, 21965955450972],
-[1, 2, 2, 2, 4, 4, 7, 14, 27, 27, 40, 78, 107, 162, 228, 364, 586, 822, 1312, 1969, 2851, 4614, 6813, 10340, 15877, 23450, 37123, 55003, 82971, 129362, 191114, 295143, 447516, 673435, 1039052, 1552069, 2378899, 3627842, 5441770, 8366986, 12623841, 19176598, 29290838, 44120886, 67492298, 102243546, 154897401, 236670642, 357450340, 544464756, 827496488, 1253033312, 1910884132, 2893668886, 4398534197, 6691809667, 10135761268, 15434053662, 23417395972, 35550130376, 54087369104, 82000749001, 124708198736, 189398728011, 287422412907, 437164039790, 663293717559, 1007881900599, 1531470562869, 2324228082368, 3533389734222, 5364295290472, 8147599059500, 12381628708243, 18794789471543],
-[1, 2, 2, 2, 4, 4, 7, 14, 26, 28, 42, 73, 107, 167, 231, 351, 563, 823, 1311, 1944, 2816, 4523, 6743, 10322, 15590, 23176, 36498, 54319, 82595, 127111, 189005, 291616, 441361, 667724, 1024029, 1534136, 2352167, 3577205, 5389177, 8259431, 12464234, 18968525, 28901811, 43639329, 66671053, 100942888, 153199550, 233630446, 353293743, 538091102, 817013499, 1238817233, 1887241903, 2859014884, 4347344463, 6608137117, 10018027359, 15247531719, 23132681396, 35135141912, 53422201875, 81029973281, 123218129155, 187091979429, 284042197910, 431851457358, 655355047894, 995897441965, 1512867412496, 2296669227333, 3490816641106, 5299777918693, 8050698926095, 12231840611995, 18570622111498],
-[1, 2, 2, 2, 4, 4, 7, 14, 27, 29, 41, 79, 114, 179, 254, 377, 593, 900, 1435, 2079, 2987, 4867, 7448, 11074, 16745, 25152, 39304, 58933, 88957, 137407, 204578, 313597, 478806, 721778, 1103153, 1660356, 2538631, 3869350, 5822946, 8909394, 13493768, 20471942, 31221177, 47203597, 71951817, 109139768, 165481000, 252377574, 382014590, 580868563, 883121560, 1338733142, 2038078161, 3090696096, 4695487756, 7140345455, 10826330060, 16468259339, 25003031476, 37956299020, 57714668110, 87572226407, 133099613803, 202175825272, 306895110031, 466542454701, 708218408000, 1075868116677, 1634678096747, 2481619744736, 3771235849573, 5726882505102, 8697969160621, 13215905587508, 20066236109687],
-[1, 2, 2, 2, 4, 6, 12, 16, 26, 37, 55, 95, 131, 206, 322, 455, 732, 1093, 1627, 2529, 3716, 5865, 8823, 13064, 20559, 30446, 46555, 71342, 106560, 164918, 246955, 375846, 577713, 863212, 1325262, 2008443, 3036715, 4653568, 7004181, 10693747, 16257729, 24550843, 37561151, 56781393, 86293066, 131454628, 198778885, 303159143, 459597917, 697422554, 1062514483, 1608638196, 2448155759, 3718595736, 5639238411, 8584585803, 13016228875, 19782939360, 30069078063, 45604910694, 69372412216, 105290654851, 159903483870, 243088275949, 368843430252, 560672815439, 851477339640, 1292792545271, 1965079924857, 2982865384754, 4532065144861, 6884871261463, 10452991360920, 15885140260671, 24120738561035],
-[1, 2, 2, 2, 4, 4, 8, 14, 25, 27, 37, 73, 110, 179, 236, 354, 589, 837, 1331, 1969, 2866, 4622, 6813, 10530, 15988, 23364, 37024, 55364, 83945, 129394, 191213, 296801, 449212, 676814, 1043019, 1556911, 2388417, 3639403, 5470970, 8401984, 12655402, 19264059, 29409449, 44300387, 67763574, 102596896, 155585035, 237562118, 358827823, 546836529, 830468021, 1258104711, 1918593703, 2904864160, 4416787670, 6716833162, 10176644065, 15496923968, 23506267019, 35695834197, 54298602573, 82323249810, 125212963904, 190131256861, 288583722062, 438887837415, 665888270487, 1011960181970, 1537448089250, 2333483113450, 3547457871602, 5385323503912, 8180286418779, 12430326393294, 18869282726497],
-[1, 2, 2, 2, 4, 4, 7, 14, 27, 29, 40, 74, 107, 178, 244, 371, 592, 857, 1401, 2003, 2963, 4820, 7131, 10925, 16392, 24626, 38770, 57173, 87424, 134509, 199747, 309209, 466294, 706958, 1083580, 1620997, 2494183, 3780514, 5699972, 8747393, 13177166, 20087707, 30568324, 46164054, 70597775, 106725137, 162171829, 247240321, 373654090, 569637422, 864213608, 1310972829, 1997275360, 3024052480, 4601613808, 6991233086, 10599431979, 16137347620, 24471385822, 37182902005, 56526361412, 85730463599, 130402607623, 197936869894, 300566957378, 456976675005, 693369438147, 1053897083108, 1600700783495, 2430146448184, 3693941784379, 5607356413174, 8519158732384, 12942596926464, 19649394340292],
-[1, 2, 2, 2, 4, 4, 6, 12, 22, 23, 33, 56, 78, 128, 178, 293, 448, 658, 1022, 1518, 2329, 3533, 5266, 8237, 12287, 18619, 28858, 42815, 65817, 99901, 150759, 231809, 347205, 531503, 809507, 1217540, 1867925, 2821455, 4286530, 6536906, 9866743, 15075037, 22840411, 34626626, 52839136, 79900138, 121646118, 184827658, 280084180, 426761465, 646616361, 982882382, 1494672353, 2265249385, 3447706707, 5231966914, 7944170534, 12081725179, 18323977522, 27861550070, 42312948567, 64226894758, 97659494794, 148205878571, 225190189543, 342137458150, 519344362669, 789389440843, 1198524718481, 1820455730944, 2766157662963, 4199473986182, 6381138017672, 9691418472947, 14718068762830],
-[1, 2, 2, 2, 4, 4, 6, 12, 20, 22, 38, 56, 74, 126, 184, 256, 382, 640, 1008, 1358, 2024, 3368, 5074, 7416, 11256, 17336, 26462, 39496, 60914, 92698, 137912, 212286, 324410, 489950, 741640, 1124736, 1724296, 2606652, 3943450, 6028074, 9123130, 13859176, 21082612, 31985298, 48655094, 73709452, 112125372, 170584566, 258367206, 393036200, 596965746, 906273890, 1377727830, 2090331262, 3178049468, 4826248146, 7325456332, 11139304634, 16907481710, 25682603856, 39023313766, 59244643816, 90036045082, 136707799006, 207644268196, 315516968980, 479020240640, 727813069344, 1105468673040, 1678810188018, 2550680302286, 3873348671126, 5884072371642, 8937905286706, 13573436582240],
-[1, 2, 2, 2, 4, 4, 6, 12, 23, 24, 37, 60, 81, 144, 195, 280, 458, 733, 1094, 1511, 2333, 3832, 5673, 8405, 12875, 19488, 30008, 45086, 68823, 104853, 156376, 241784, 367505, 553275, 844148, 1276132, 1951504, 2958405, 4472531, 6840668, 10337503, 15715311, 23943308, 36232586, 55178955, 83654240, 127105159, 193509735, 292939884, 445833139, 677201270, 1027277072, 1563156077, 2370705278, 3603397840, 5475021171, 8306656825, 12634812981, 19175166076, 29123219480, 44267781022, 67181396440, 102111951350, 155062413745, 235466604749, 357871006455, 543251756514, 825418361118, 1253849937465, 1903811816789, 2892974298034, 4392939315341, 6672961203666, 10137402013362, 15393441942297],
-[1, 2, 2, 2, 4, 4, 7, 14, 26, 26, 41, 74, 101, 163, 228, 334, 546, 829, 1256, 1820, 2687, 4413, 6586, 9724, 15008, 22458, 34883, 52349, 79127, 122154, 181498, 279241, 426358, 640156, 980585, 1477912, 2256501, 3438406, 5174085, 7924822, 11994155, 18188071, 27772104, 41954621, 63938765, 97036190, 147099273, 224376783, 339501800, 516329920, 785236931, 1189772630, 1811751751, 2747584301, 4173518845, 6347675592, 9623100523, 14639607769, 22226347981, 33736589616, 51308382385, 77843445826, 118310494359, 179726272553, 272793468037, 414732336102, 629544385983, 956335908266, 1453160738535, 2205883602187, 3352348537998, 5090823795537, 7731602132607, 11748126470656, 17837041400870],
-[1, 2, 2, 2, 4, 4, 7, 14, 26, 27, 39, 70, 98, 175, 238, 340, 536, 814, 1339, 1874, 2739, 4518, 6685, 10162, 15445, 22982, 35957, 53370, 81904, 126160, 185640, 288108, 437025, 659469, 1011298, 1513642, 2327270, 3531396, 5317294, 8173345, 12306356, 18726765, 28560073, 43105225, 65889936, 99653934, 151332934, 230920325, 348795061, 531649758, 807163848, 1223468511, 1864642037, 2823619649, 4295134327, 6527972951, 9893608692, 15065599809, 22849219500, 34705673103, 52777461508, 80034078090, 121730678121, 184806935936, 280571735127, 426640563945, 647313175077, 983810225643, 1494493087035, 2268581848438, 3448531025517, 5235038542262, 7952773494087, 12083307964640, 18343648655792],
-[1, 2, 2, 2, 4, 4, 6, 12, 24, 24, 34, 59, 86, 147, 193, 286, 470, 695, 1108, 1595, 2345, 3808, 5618, 8630, 12995, 19203, 30456, 45342, 68823, 105584, 156696, 243789, 367407, 554266, 852979, 1275334, 1958829, 2975741, 4481450, 6879419, 10357362, 15786172, 24064893, 36278352, 55489479, 83962778, 127465992, 194451619, 293761369, 447855071, 679727158, 1030378097, 1570748223, 2378045186, 3617189004, 5498179412, 8333213439, 12688934720, 19242859586, 29231160890, 44452723472, 67404269585, 102527203087, 155652138063, 236305152423, 359330672803, 545184380743, 828616606010, 1258700034288, 1910648066972, 2904525411018, 4409098846145, 6698079871087, 10176994565954, 15449586824890],
-[1, 2, 2, 2, 4, 4, 7, 14, 27, 28, 41, 72, 102, 163, 223, 352, 576, 818, 1285, 1895, 2815, 4510, 6616, 10228, 15485, 22912, 36244, 53864, 81658, 125703, 187007, 289816, 436651, 659689, 1015581, 1518418, 2330062, 3541581, 5333432, 8184039, 12331652, 18789162, 28632066, 43174649, 66037631, 99947478, 151688629, 231382451, 349664879, 532960608, 808968231, 1226348460, 1869231340, 2830281932, 4304718691, 6543629170, 9918020885, 15100111915, 22902146248, 34788954555, 52902799706, 80221675559, 122015754202, 185247642100, 281233415665, 427636394418, 648853997199, 986136133841, 1497998874965, 2273935826602, 3456677661413, 5247425558134, 7971472442969, 12111782510610, 18387101821440],
-[1, 2, 2, 2, 4, 4, 7, 14, 26, 27, 39, 72, 102, 174, 234, 347, 563, 838, 1347, 1921, 2839, 4632, 6809, 10417, 15805, 23419, 36932, 54853, 83612, 128671, 190228, 295802, 446662, 673682, 1036134, 1549474, 2381253, 3615098, 5442824, 8363390, 12589045, 19176190, 29240472, 44090223, 67436609, 102016841, 154887476, 236327495, 356939220, 544207236, 826070449, 1252067002, 1908700979, 2889701805, 4395719694, 6681458419, 10125772835, 15419799548, 23384019798, 35520373179, 54019018149, 81908128383, 124590210150, 189145589154, 287151290362, 436662022800, 662492586882, 1006916099386, 1529570493552, 2321773710604, 3529549367862, 5357856436866, 8139390299581, 12367009486748, 18773962775391],
-[1, 2, 2, 2, 4, 5, 9, 16, 30, 33, 43, 82, 124, 211, 282, 405, 690, 998, 1604, 2337, 3349, 5517, 8153, 12533, 19049, 27790, 44281, 66058, 99815, 154559, 227756, 354030, 536315, 805923, 1245111, 1855914, 2847840, 4343911, 6517093, 10026077, 15094752, 22960531, 35094352, 52807160, 80827169, 122377813, 185459072, 283440952, 427837842, 652082046, 990669687, 1499919024, 2288538433, 3464131706, 5266646637, 8012092512, 12133987968, 18482649745, 28034601327, 42563828349, 64764356488, 98168066782, 149325595017, 226762264881, 344120266953, 523449644234, 794100632319, 1206792048049, 1833646760570, 2782664151800, 4230744860086, 6422441253423, 9755192214088, 14824809168109, 22502198577112],
-[1, 2, 2, 2, 4, 4, 8, 16, 32, 32, 45, 90, 128, 204, 272, 423, 731, 996, 1603, 2372, 3425, 5695, 8215, 12654, 19318, 28234, 45471, 66805, 100945, 157523, 231292, 360805, 543932, 817444, 1268314, 1882322, 2897990, 4416583, 6609131, 10201683, 15328928, 23341003, 35679790, 53585790, 82220082, 124327676, 188393565, 288223371, 434418571, 662878495, 1006732630, 1523622030, 2326769690, 3518472089, 5352314457, 8143522723, 12325681418, 18787195304, 28482880638, 43249666653, 65825283923, 99729047583, 151765118638, 230421045029, 349640113593, 532004633470, 806817678729, 1226362417889, 1863354091019, 2827326833241, 4299629667532, 6525772488517, 9912805692738, 15065332954505, 22863927869868],
-[1, 2, 2, 2, 4, 4, 8, 16, 31, 32, 47, 90, 124, 214, 295, 422, 698, 1025, 1677, 2394, 3430, 5726, 8446, 12832, 19539, 28758, 45698, 67827, 102881, 159552, 234298, 364562, 553013, 830610, 1281330, 1911161, 2937826, 4473849, 6709207, 10332970, 15553235, 23652082, 36146838, 54409817, 83283573, 126033504, 191080526, 292055858, 440685808, 671781942, 1020606467, 1545309886, 2357638359, 3568426066, 5426315145, 8253939507, 12499795451, 19042099542, 28880632020, 43849842392, 66720182903, 101132187572, 153842127665, 233602763457, 354516486494, 539273547348, 818065723801, 1243262887289, 1889022087295, 2866711892934, 4358585484622, 6616321275384, 10049989446972, 15272592147870, 23181658000588],
-[1, 2, 2, 2, 4, 4, 7, 14, 28, 28, 42, 77, 106, 171, 236, 354, 578, 844, 1354, 1983, 2816, 4643, 6987, 10488, 15958, 23579, 37427, 55758, 83906, 130418, 192609, 297575, 453049, 680454, 1047361, 1566806, 2401526, 3665377, 5494861, 8442757, 12749820, 19354820, 29576499, 44566674, 68116259, 103234249, 156384072, 238961418, 360982598, 549560699, 835550136, 1265199791, 1929087788, 2922055219, 4440527834, 6756344730, 10234126384, 15581636311, 23645835129, 35891322295, 54607155785, 82798195812, 125903612865, 191233526904, 290195067936, 441366432835, 669727673731, 1017566794638, 1546268786196, 2346694221398, 3567355597997, 5416227536631, 8226097819296, 12501049501268, 18976483258474],
-[1, 2, 2, 2, 4, 7, 10, 15, 29, 38, 50, 92, 137, 190, 283, 457, 719, 985, 1545, 2479, 3588, 5490, 8401, 12761, 19337, 28897, 45175, 68035, 101408, 157345, 237443, 360158, 547632, 828617, 1270558, 1912168, 2908209, 4448500, 6700490, 10218391, 15520901, 23537479, 35859344, 54232621, 82640578, 125559318, 190111302, 289773736, 439308810, 667330654, 1014795629, 1538575881, 2341437558, 3552656446, 5394154725, 8205491900, 12444249681, 18917472938, 28735484662, 43617685281, 66315581639, 100644095851, 152922737338, 232346838562, 352669367826, 536046857496, 813937098105, 1236215049944, 1878420652190, 2851842802871, 4333286986747, 6581317613519, 9994681179159, 15186312749627, 23059906450661],
-[1, 2, 3, 3, 6, 10, 11, 16, 31, 43, 62, 88, 145, 240, 313, 510, 777, 1140, 1808, 2626, 4115, 6194, 9210, 14619, 21458, 32630, 50524, 75062, 116175, 174490, 264560, 407763, 607399, 934735, 1419539, 2135283, 3281945, 4940364, 7535176, 11471310, 17289288, 26490787, 40041889, 60783110, 92753080, 140079267, 213662148, 324145716, 491443208, 749322766, 1133840711, 1725466297, 2622599521, 3974032500, 6052544462, 9176826459, 13942904489, 21202602146, 32144582549, 48905853114, 74234532301, 112703273872, 171394006963, 260008923403, 395226965746, 600326034329, 911262481984, 1385391718424, 2102814593871, 3194704666235, 4853987042357, 7368457680883, 11198519446744, 17004749028530, 25827135852476],
-[1, 2, 3, 3, 5, 8, 11, 17, 27, 40, 71, 97, 138, 219, 324, 510, 752, 1161, 1792, 2639, 4110, 6235, 9316, 14289, 21520, 33094, 50166, 75187, 115995, 174874, 265420, 405399, 611120, 934332, 1414227, 2146394, 3277634, 4945642, 7538287, 11457616, 17351779, 26449894, 40055373, 60915108, 92628558, 140319401, 213713992, 324170950, 492185517, 748784481, 1135310227, 1726653197, 2621794473, 3979499593, 6052535356, 9182463188, 13954376302, 21201883157, 32178454882, 48918642160, 74263704740, 112805496772, 171414058206, 260202807022, 395430969244, 600528587020, 911983073870, 1385788048711, 2104080815215, 3196638911015, 4855596427207, 7373614369867, 11203271939398, 17013272314930, 25843161584078],
-[1, 2, 3, 3, 5, 8, 11, 20, 32, 39, 61, 98, 151, 231, 324, 505, 765, 1182, 1831, 2641, 4041, 6280, 9535, 14490, 21557, 33041, 50737, 76157, 116621, 176177, 266807, 408465, 617194, 941291, 1425321, 2158657, 3301970, 4992569, 7589104, 11542178, 17481517, 26643382, 40386968, 61349003, 93334598, 141392971, 215225660, 326789046, 495860662, 754295184, 1144074099, 1739297865, 2642236061, 4009365882, 6097223723, 9253701560, 14057432154, 21363258199, 32423555985, 49281495636, 74832866361, 113648628198, 172709431866, 262186463717, 398373566371, 605104103190, 918856498608, 1396201284279, 2120093928079, 3220580914741, 4892390421208, 7429343293289, 11287420649484, 17142493918761, 26037445308073],
-[1, 2, 3, 3, 4, 6, 11, 22, 28, 34, 61, 93, 151, 201, 290, 508, 741, 1137, 1683, 2472, 3986, 5885, 9021, 13843, 20238, 31864, 48010, 72297, 111490, 165496, 255960, 388389, 583362, 899887, 1347649, 2058263, 3142600, 4725490, 7247274, 10936641, 16617521, 25394439, 38263572, 58444655, 88640553, 134293882, 205020729, 309923656, 471854356, 717174367, 1086102201, 1655809106, 2508816160, 3811812813, 5798943923, 8786873492, 13374965295, 20297429057, 30812297099, 46874308409, 71080464918, 108074532929, 164159711593, 249130336304, 378856510188, 574929674888, 873527431704, 1327325992126, 2014542696428, 3062252220982, 4649522772579, 7061633591205, 10730982107860, 16290497524696, 24753704911584],
-[1, 2, 2, 3, 4, 5, 7, 11, 17, 21, 38, 50, 76, 118, 166, 273, 400, 638, 944, 1396, 2202, 3252, 4961, 7602, 11417, 17550, 26375, 40009, 61508, 92071, 140872, 214179, 323132, 495374, 746865, 1137595, 1731174, 2613941, 3998395, 6049453, 9181560, 13996253, 21172315, 32262035, 48948122, 74241921, 113108609, 171311367, 260566456, 395988595, 600376942, 913792998, 1386113064, 2105870296, 3201452252, 4855654658, 7384764393, 11211418346, 17022863915, 25880114487, 39272588172, 59685889833, 90659288531, 137636475708, 209204442701, 317593717809, 482478016208, 733022290863, 1112899811945, 1691142158581, 2568150430363, 3900617773171, 5926326671783, 8998719623260, 13671493312148],
-[1, 2, 2, 3, 4, 6, 7, 10, 20, 20, 32, 57, 97, 124, 170, 280, 421, 670, 985, 1450, 2210, 3430, 5404, 7934, 11698, 18167, 27854, 42260, 63757, 96224, 147525, 223220, 339663, 517663, 779196, 1188708, 1808446, 2744403, 4173337, 6312233, 9620173, 14619070, 22152043, 33732096, 51139184, 77698202, 118145917, 179214674, 272589648, 413583276, 628126361, 955304771, 1449167171, 2202594679, 3345409138, 5079051726, 7720841927, 11719126615, 17805907518, 27050575415, 41065463708, 62412268895, 94770581918, 143942764400, 218698930546, 332064688874, 504538634251, 766277390704, 1163761840384, 1768140702614, 2684999332390, 4078820477845, 6195666739647, 9409314080035, 14294627588366],
-[1, 2, 2, 3, 5, 6, 9, 17, 23, 27, 48, 74, 113, 154, 242, 383, 549, 873, 1322, 1938, 2950, 4558, 7051, 10406, 15635, 24431, 36818, 55495, 84683, 128292, 195613, 295613, 450624, 686601, 1033332, 1578540, 2401766, 3635601, 5531720, 8384148, 12769224, 19385519, 29375630, 44763975, 67863500, 103057970, 156761072, 237772355, 361561284, 548744233, 833437820, 1267400880, 1922382004, 2922021962, 4439006592, 6738180333, 10242492367, 15548444206, 23622606818, 35888120260, 54481418505, 82801968169, 125733893983, 190960914544, 290152148598, 440558691221, 669350672495, 1016625157021, 1543955363413, 2345789841051, 3562196791957, 5411279799131, 8219861730807, 12483260387177, 18964485065938],
-[1, 2, 2, 3, 4, 5, 8, 13, 23, 28, 46, 72, 105, 175, 253, 383, 591, 874, 1392, 2039, 3027, 4817, 7171, 10951, 16565, 24875, 38712, 57684, 88040, 134979, 201560, 310443, 469326, 711284, 1087906, 1635281, 2505158, 3800594, 5741889, 8786885, 13269079, 20198592, 30733303, 46483370, 70939093, 107414776, 163165034, 248515863, 376120128, 572755781, 869377038, 1319076669, 2007989829, 3043282536, 4627947440, 7031396873, 10665496462, 16226971845, 24620201196, 37402443605, 56849703090, 86256769468, 131145298472, 199114306680, 302365793610, 459593788892, 697560964292, 1060028385250, 1610098756582, 2444699575204, 3715282418141, 5640854562577, 8569252128713, 13018206124942, 19766777351560],
-[1, 2, 2, 3, 4, 6, 8, 15, 23, 23, 39, 66, 99, 143, 240, 345, 513, 783, 1226, 1910, 2652, 4204, 6595, 9702, 14840, 22177, 34297, 52272, 77933, 120760, 181168, 274054, 422453, 634833, 968708, 1467085, 2223990, 3409709, 5128358, 7815880, 11907009, 17981226, 27462984, 41581959, 63180083, 96183711, 145492037, 221862484, 336553128, 510374752, 777429603, 1177921746, 1791616718, 2721648096, 4128000449, 6282695175, 9528275820, 14478049102, 22009261660, 33384175724, 50767521844, 77072184073, 117041944890, 177915597757, 269987268424, 410349344973, 623255426727, 946254342314, 1438229053522, 2183416622404, 3317075431079, 5039254768692, 7651173739601, 11626512760033, 17655400302104],
-[1, 2, 2, 3, 4, 5, 8, 12, 23, 27, 40, 66, 112, 180, 225, 354, 574, 838, 1347, 1953, 2936, 4612, 6795, 10729, 15956, 23647, 37352, 55492, 85128, 129362, 193127, 300365, 449536, 684419, 1049014, 1567734, 2412958, 3650904, 5525458, 8461857, 12723971, 19458135, 29560633, 44651268, 68306655, 103186837, 156991852, 239031706, 361407234, 551395675, 835462055, 1268415427, 1932157112, 2924726693, 4452619708, 6760552531, 10254884006, 15611885197, 23665247265, 35977496201, 54673717179, 82926653269, 126158157288, 191440361591, 290787862723, 442026404652, 670672708630, 1019601971967, 1548234801348, 2350860788020, 3573353272748, 5423802076679, 8241501572360, 12519077198309, 19007658232652],
-[1, 2, 2, 3, 4, 5, 9, 13, 24, 30, 45, 74, 108, 173, 239, 355, 594, 853, 1300, 2013, 2941, 4663, 6816, 10430, 16361, 23743, 37009, 56036, 84423, 130581, 193493, 298410, 454364, 680585, 1051301, 1577504, 2400936, 3672251, 5519844, 8465835, 12789897, 19392847, 29677108, 44713678, 68238318, 103627098, 156838878, 239505315, 362163668, 551045270, 838214089, 1268659304, 1934072517, 2931922799, 4452082309, 6775726184, 10265709673, 15623057526, 23717939607, 35991664756, 54764408533, 83048768256, 126241274244, 191808194404, 291038484733, 442596867269, 671731978565, 1020419253508, 1550784728046, 2353530641656, 3577380666751, 5432307017982, 8249468021376, 12536978638038, 19032179151330],
-[1, 2, 2, 3, 4, 4, 8, 13, 25, 30, 52, 85, 107, 176, 269, 400, 596, 880, 1458, 2173, 3075, 4990, 7485, 11208, 17184, 25667, 40188, 59659, 90423, 140640, 208187, 319808, 487069, 733696, 1126187, 1690422, 2587388, 3940926, 5920770, 9089773, 13742857, 20844755, 31814114, 48035187, 73346131, 111164898, 168483889, 257204286, 388876212, 591808476, 899714863, 1363052984, 2076644803, 3147233303, 4782868974, 7274604584, 11023834637, 16777679412, 25465460268, 38657499774, 58797856274, 89181271791, 135583384058, 205932747568, 312558638614, 475273587054, 721293717134, 1095858227166, 1665123231717, 2527458685274, 3841575656126, 5832984219390, 8859275536891, 13462057695516, 20437451033673],
-[1, 2, 2, 3, 4, 5, 8, 12, 23, 27, 45, 74, 111, 171, 246, 392, 573, 858, 1400, 2040, 3035, 4738, 7133, 10971, 16350, 24861, 38543, 57118, 87858, 134027, 201049, 308609, 465157, 710506, 1080481, 1625457, 2495603, 3773545, 5720199, 8734009, 13192912, 20120177, 30519374, 46270264, 70587288, 106751133, 162438157, 247025504, 374201909, 569914602, 864121563, 1312919754, 1996760977, 3026537398, 4605337605, 6990856055, 10611684831, 16140010536, 24483905157, 37216164751, 56531045111, 85805102719, 130461207313, 198011654806, 300819054144, 457094027417, 693837935574, 1054511653452, 1601270797373, 2431972971617, 3695407217563, 5610469141895, 8524535288315, 12947673981452, 19662414619898],
-[1, 2, 2, 3, 5, 6, 11, 16, 26, 39, 51, 83, 137, 199, 291, 449, 683, 1057, 1556, 2400, 3665, 5418, 8481, 12727, 19217, 29417, 44326, 68140, 102529, 155348, 238715, 358840, 546939, 832465, 1258858, 1922083, 2905185, 4422883, 6733239, 10173468, 15522297, 23541543, 35718864, 54389260, 82379661, 125395561, 190373052, 288772466, 439647361, 666512127, 1012992839, 1539669302, 2335581489, 3552347640, 5391128868, 8188905591, 12447867765, 18887040234, 28711045618, 43601437253, 66202430687, 100623846023, 152744342654, 232083697806, 352545342691, 535273964639, 813445068066, 1235148790175, 1876182511071, 2850388011789, 4328106698649, 6575929120483, 9987155094821, 15168629551239, 23044807205294],
-[1, 2, 2, 3, 4, 4, 7, 12, 23, 28, 46, 65, 93, 163, 222, 342, 542, 792, 1238, 1817, 2736, 4262, 6294, 9857, 14807, 22068, 34581, 51273, 78557, 119881, 179314, 277623, 416064, 633416, 970646, 1453647, 2232227, 3378753, 5114997, 7829368, 11790501, 18005079, 27355093, 41349688, 63206568, 95556287, 145314935, 221214899, 334653144, 510277640, 773509382, 1174264886, 1788128406, 2707955901, 4121281058, 6258274464, 9493849822, 14449256087, 21909509007, 33302815363, 50608338289, 76772624611, 116770914446, 177223246584, 269183046672, 409155551704, 620881899215, 943780957367, 1433199690415, 2176239979813, 3307644690244, 5020984319979, 7628918998281, 11588653832355, 17595757362052],
-[1, 2, 2, 3, 4, 5, 7, 12, 20, 27, 39, 51, 81, 141, 194, 284, 477, 695, 1061, 1571, 2434, 3808, 5397, 8544, 13189, 19322, 29886, 44799, 69036, 105025, 156208, 243047, 364688, 551507, 849700, 1273862, 1949126, 2952854, 4471661, 6856841, 10305640, 15722458, 23951763, 36147462, 55242003, 83595701, 127039648, 193478861, 292525399, 446200226, 676709550, 1026249807, 1563725213, 2368341857, 3602961606, 5473237439, 8300286041, 12635647361, 19159465584, 29114462122, 44261204776, 67127635634, 102098162263, 154984177533, 235355864080, 357794780120, 542899761038, 825211420125, 1253329939671, 1902794642052, 2892299137763, 4390579750077, 6670466601504, 10133778456305, 15385590125974],
-[1, 2, 2, 3, 4, 4, 7, 10, 18, 24, 40, 53, 78, 124, 167, 280, 418, 613, 970, 1462, 2208, 3314, 4974, 7860, 11673, 17543, 27298, 40688, 62365, 94264, 142685, 219508, 328178, 502642, 766120, 1152235, 1765841, 2668512, 4056914, 6181788, 9330938, 14261558, 21604116, 32750997, 49977315, 75584810, 115063591, 174789844, 264950407, 403689738, 611560419, 929680238, 1413760186, 2142713005, 3261011829, 4948567977, 7514546420, 11427379959, 17331811985, 26354093142, 40021746241, 60750537288, 92372030184, 140183298951, 213003483764, 323609215470, 491235151383, 746661935259, 1133631829409, 1721924872700, 2616401763748, 3972152495764, 6035723065740, 9166719883275, 13921453403681],
-[1, 2, 2, 3, 4, 5, 7, 11, 21, 23, 37, 60, 92, 137, 184, 295, 452, 683, 1045, 1532, 2353, 3652, 5437, 8312, 12510, 19003, 29303, 43760, 67021, 101615, 153429, 235716, 354755, 540548, 822099, 1241787, 1900366, 2872936, 4363074, 6649278, 10053528, 15330453, 23254164, 35268009, 53732474, 81364151, 123809935, 188107981, 285176962, 434151254, 658384860, 1000440872, 1520884456, 2306509919, 3508368544, 5325514239, 8086830352, 12294862633, 18654910645, 28354843891, 43066529459, 65382807603, 99385291708, 150864993550, 229205151066, 348218267868, 528665822790, 803395681712, 1219959400150, 1852980025962, 2815300900113, 4274721266548, 6494706010867, 9864289995300, 14981203749189],
-[1, 2, 2, 3, 4, 4, 7, 10, 18, 24, 42, 52, 76, 134, 179, 289, 420, 657, 1028, 1460, 2322, 3534, 5187, 8063, 12075, 18583, 28245, 41804, 65422, 98057, 148053, 228267, 341201, 524474, 793177, 1199155, 1842341, 2763879, 4223578, 6431940, 9697667, 14840622, 22428911, 34106182, 51966930, 78485648, 119823257, 181642246, 275466066, 419944275, 635549925, 967402162, 1469377949, 2227748217, 3393003635, 5142514718, 7816062309, 11883220438, 18017574080, 27413138498, 41602923925, 63179300371, 96062020831, 145725899058, 221547092028, 336459483710, 510780962478, 776512746916, 1178584070250, 1790752050691, 2720516940703, 4130075753425, 6276967317854, 9530746460359, 14476644175113],
-[1, 2, 2, 3, 4, 4, 7, 10, 19, 28, 47, 62, 88, 147, 211, 326, 490, 732, 1161, 1710, 2618, 4016, 5864, 9151, 13979, 21002, 32134, 47905, 74064, 112656, 168751, 259808, 390713, 595209, 909141, 1369143, 2094811, 3165902, 4808481, 7347614, 11080195, 16895412, 25658557, 38875127, 59294005, 89721445, 136502912, 207532610, 314350683, 478962853, 726216740, 1102881351, 1677765957, 2543328279, 3869447181, 5874012230, 8916193029, 13562168933, 20572071975, 31268984509, 47503687041, 72095786835, 109614923713, 166382929744, 252759823583, 384075648153, 582982750797, 886042292735, 1345493746895, 2043399365497, 3105061941933, 4714211723859, 7162585796279, 10879300452948, 16521123806689],
-[1, 2, 2, 2, 4, 4, 6, 12, 23, 25, 39, 63, 86, 140, 206, 306, 462, 706, 1110, 1622, 2410, 3826, 5712, 8589, 13248, 19952, 30482, 45743, 70001, 107549, 160182, 245371, 373839, 564959, 862253, 1301636, 1986704, 3017792, 4560720, 6973066, 10552179, 16017012, 24401185, 36951074, 56272242, 85337601, 129542781, 197284403, 298816811, 454503138, 690608060, 1047481698, 1593673168, 2417663507, 3673871502, 5583329636, 8470182638, 12881160342, 19555103107, 29695154915, 45137532461, 68506015529, 104112211220, 158123334931, 240093102395, 364899411116, 553970909301, 841603473091, 1278540261222, 1941289799611, 2949781793534, 4479500377098, 6804019447895, 10336863320404, 15696514964759],
-[1, 2, 2, 2, 4, 4, 6, 12, 24, 24, 32, 56, 87, 145, 180, 282, 475, 676, 1083, 1573, 2318, 3713, 5475, 8592, 12787, 18827, 30065, 44587, 67882, 104020, 154498, 240523, 360843, 547233, 841997, 1254043, 1931354, 2931510, 4418278, 6781704, 10195352, 15573776, 23706692, 35733928, 54728746, 82697072, 125637383, 191625675, 289426999, 441576591, 669552294, 1015494665, 1548315068, 2342698800, 3565601720, 5417741606, 8211769696, 12506771726, 18959136290, 28811539673, 43806333901, 66417077672, 101054811888, 153373391961, 232882650828, 354125480123, 537215619985, 816667790281, 1240341291757, 1882890090387, 2862516145315, 4344730249463, 6601154371197, 10029070294669, 15224814171558],
-[1, 2, 2, 2, 4, 5, 10, 16, 29, 34, 49, 96, 132, 200, 286, 441, 747, 1034, 1615, 2487, 3585, 5778, 8504, 12919, 20039, 29382, 46323, 69269, 103835, 162107, 239988, 369550, 562127, 842719, 1303561, 1950109, 2976506, 4553590, 6825079, 10487030, 15843886, 24025231, 36755660, 55336663, 84574238, 128334707, 194166076, 296775782, 448427464, 682488081, 1038188716, 1571057960, 2396057729, 3630247373, 5514009151, 8393547673, 12711557370, 19351604592, 29374082137, 44574200544, 67833547755, 102844002189, 156364881396, 237558724201, 360423308087, 548216182188, 831903181819, 1263811147865, 1920725351529, 2914716569458, 4430890169266, 6727786585781, 10216951686311, 15527851813897, 23570577964589],
-[1, 2, 2, 2, 4, 4, 8, 16, 31, 32, 46, 88, 124, 214, 287, 417, 705, 1021, 1650, 2356, 3430, 5716, 8309, 12712, 19473, 28595, 45373, 67124, 102208, 158468, 232394, 362418, 548343, 824038, 1273146, 1896849, 2917277, 4438049, 6659075, 10264895, 15429775, 23482221, 35886054, 53992945, 82697434, 125080921, 189695830, 289934271, 437315149, 667031353, 1013036340, 1533812912, 2340644092, 3541662059, 5387025102, 8193147845, 12406942032, 18904543775, 28665261707, 43528939677, 66232926771, 100380905432, 152722222900, 231876077068, 351910383825, 535333881727, 812003526066, 1234178639125, 1875122995312, 2845574278858, 4326719408684, 6567469224938, 9976280809153, 15160436612429, 23010744787529],
-[1, 2, 2, 2, 4, 4, 8, 16, 30, 30, 41, 82, 124, 204, 261, 394, 683, 968, 1580, 2258, 3249, 5444, 7973, 12271, 18421, 27088, 43748, 64276, 97361, 151060, 222213, 347075, 522618, 787717, 1217890, 1807958, 2789191, 4243097, 6359985, 9805349, 14728625, 22458076, 34282069, 51540155, 79061879, 119457721, 181218484, 277041169, 417683377, 637449795, 967492869, 1465321344, 2236855180, 3382348355, 5147116722, 7827429383, 11851715005, 18063141119, 27380519044, 41588720697, 63277471302, 95884889214, 145922761556, 221505969822, 336188903189, 511458868071, 775678286850, 1179147339242, 1791330296240, 2718413834815, 4133765285633, 6273797218845, 9531014437141, 14483542369645, 21982350753855],
-[1, 2, 2, 2, 4, 4, 7, 14, 28, 28, 43, 80, 108, 171, 240, 363, 599, 845, 1349, 2035, 2900, 4746, 6948, 10585, 16404, 23924, 37995, 56356, 85029, 132950, 195200, 302528, 459114, 688606, 1066547, 1589392, 2435845, 3720075, 5569758, 8582598, 12930592, 19631903, 30042395, 45176189, 69167693, 104781511, 158618847, 242632173, 366106500, 557867167, 848142315, 1283228719, 1958507218, 2964690658, 4506045074, 6858225827, 10382213015, 15815982561, 23994371790, 36417992044, 55429350243, 84006080818, 127777175309, 194075034097, 294451778889, 447969643344, 679572675195, 1032637872623, 1569282475075, 2381166695324, 3620496255254, 5496251611685, 8347586040048, 12687016102351, 19256070368268],
-[1, 2, 2, 2, 4, 4, 7, 14, 28, 28, 38, 74, 104, 168, 242, 373, 565, 816, 1379, 1994, 2828, 4603, 6949, 10616, 15799, 23616, 37461, 55214, 84140, 130196, 192615, 297368, 450100, 682605, 1045719, 1560863, 2404161, 3652714, 5493242, 8433764, 12715915, 19365792, 29486825, 44513647, 68101798, 102952971, 156310976, 238537908, 360415183, 549238380, 833632796, 1264181865, 1926480912, 2916564606, 4437324202, 6744087703, 10221275276, 15563132522, 23604221198, 35857006707, 54520297711, 82679581741, 125763481015, 190915183515, 289854313758, 440747819530, 668729523585, 1016372784593, 1543874055871, 2343663595429, 3562632627521, 5408101096434, 8215946026770, 12482908763835, 18950486241307],
-[1, 2, 2, 2, 4, 5, 9, 14, 26, 32, 41, 66, 104, 192, 251, 338, 578, 889, 1344, 2007, 2970, 4659, 6901, 10722, 16678, 23909, 37015, 57000, 86256, 131714, 195359, 302420, 460434, 689946, 1063818, 1598553, 2429454, 3713660, 5602535, 8573305, 12938219, 19640838, 30052296, 45315793, 69071483, 104908884, 158917808, 242424380, 366830935, 558152548, 848592232, 1284953974, 1958255133, 2969572053, 4509056788, 6859571737, 10398052042, 15820764418, 24017016009, 36452636791, 55451805045, 84108979867, 127840784572, 194228181332, 294764336185, 448168442826, 680260639045, 1033408296750, 1570354628621, 2383518649283, 3622620833493, 5501180131646, 8354433000807, 12695325814242, 19274242095871],
-[1, 2, 2, 2, 4, 5, 10, 16, 29, 36, 49, 90, 132, 214, 292, 431, 725, 1066, 1640, 2448, 3612, 5724, 8531, 13040, 19992, 29432, 45925, 69515, 104740, 161084, 240196, 369908, 561955, 845412, 1300173, 1953603, 2978620, 4544975, 6847895, 10479282, 15840796, 24057876, 36727342, 55435013, 84552559, 128317069, 194466344, 296584547, 448835447, 682881484, 1037964745, 1572732033, 2395791903, 3632339669, 5517744773, 8392186813, 12722597586, 19356015391, 29382321628, 44605338147, 67837689111, 102907570823, 156423779110, 237614334284, 360657684594, 548321754894, 832286447375, 1264412949841, 1921192816014, 2916323657697, 4432257987778, 6730401169231, 10221944981521, 15532195664934, 23581963954496],
-[1, 2, 2, 2, 4, 4, 7, 14, 27, 27, 34, 64, 103, 171, 221, 337, 525, 768, 1298, 1883, 2658, 4220, 6439, 10142, 14881, 21839, 34838, 51992, 79331, 121265, 179970, 279071, 420537, 639738, 980093, 1460045, 2248794, 3416664, 5153076, 7894792, 11883198, 18144223, 27604274, 41667589, 63757937, 96341398, 146385275, 223212260, 337387931, 514399990, 779990186, 1183492627, 1803584692, 2729978512, 4154493166, 6311659383, 9569263314, 14569951811, 22091802869, 33571688870, 51033265459, 77393579552, 117737658525, 178700026073, 271357522296, 412570863928, 625970869969, 951518503198, 1445123136135, 2193965682586, 3335058076629, 5062317788328, 7691369834605, 11684895198713, 17739728231239],
-[1, 2, 2, 2, 4, 4, 6, 12, 23, 24, 37, 59, 89, 144, 194, 273, 448, 706, 1079, 1579, 2258, 3654, 5625, 8373, 12768, 18870, 29416, 44730, 67141, 103266, 153846, 236189, 361596, 542659, 830866, 1252477, 1907227, 2915563, 4388336, 6705709, 10162858, 15400897, 23531122, 35551709, 54127860, 82241346, 124570312, 190015110, 287737258, 437231999, 665204884, 1007805480, 1534471508, 2328148028, 3534381293, 5377084622, 8152571547, 12398366341, 18830421311, 28575571655, 43460207794, 65945611281, 100204582794, 152256271065, 231073923789, 351281724174, 533320274023, 810030144281, 1230961166845, 1868587337833, 2839518038083, 4312555081464, 6548992634279, 9951474874388, 15109804579086],
-[1, 2, 2, 2, 4, 4, 6, 12, 24, 24, 34, 61, 83, 137, 190, 293, 460, 667, 1085, 1554, 2271, 3698, 5479, 8303, 12589, 18838, 29712, 43703, 66628, 103148, 152429, 236003, 356718, 539426, 828217, 1238125, 1904654, 2890744, 4348663, 6683471, 10070657, 15331340, 23361933, 35249858, 53922737, 81545811, 123803292, 188925194, 285367113, 434985612, 660338161, 1001042484, 1525703856, 2309884807, 3514068080, 5341237647, 8094492605, 12325909034, 18693600588, 28395522093, 43180658988, 65478253016, 99597521379, 151199954957, 229548401941, 349063221788, 529600418654, 804917518807, 1222717469473, 1856045618950, 2821481409184, 4283032167610, 6506617485557, 9886076416729, 15007883790005],
-[1, 2, 2, 2, 4, 4, 6, 12, 23, 25, 35, 55, 83, 142, 183, 282, 455, 686, 1065, 1511, 2301, 3671, 5435, 8342, 12584, 18904, 29375, 43594, 66980, 102224, 152467, 235544, 355136, 539881, 824547, 1237838, 1899897, 2876546, 4353983, 6661142, 10041653, 15314874, 23281823, 35221645, 53769004, 81329508, 123687260, 188282312, 284905923, 434151465, 658458311, 999575085, 1521552605, 2305313014, 3507473428, 5326426834, 8081141030, 12296710182, 18650379491, 28343448843, 43071414807, 65351160004, 99378451433, 150845096145, 229113310079, 348226315451, 528485886097, 803239036354, 1219853665047, 1852322673427, 2815084858664, 4273684041813, 6493118028016, 9863329076859, 14976688524277],
-[1, 2, 2, 2, 4, 5, 9, 14, 25, 30, 41, 73, 112, 188, 241, 358, 608, 873, 1375, 2063, 3031, 4786, 7082, 11070, 16766, 24377, 38558, 58123, 87855, 134805, 200532, 310751, 469126, 707646, 1091203, 1630762, 2495023, 3803672, 5730606, 8782498, 13234874, 20154104, 30751911, 46347362, 70846371, 107334586, 162766887, 248332278, 375424098, 571963182, 868464921, 1316046886, 2006312687, 3038742582, 4619419405, 7024210991, 10646029103, 16206254111, 24585485508, 37337466502, 56786099106, 86110190618, 130953489012, 198858988099, 301854223500, 458996068687, 696494443498, 1058419582706, 1607970141501, 2440733722439, 3710161246470, 5632669604536, 8555929028400, 13000532414028, 19736318943266],
-[1, 2, 2, 2, 4, 4, 7, 14, 28, 28, 38, 73, 106, 171, 232, 361, 582, 816, 1343, 1978, 2850, 4609, 6809, 10520, 15903, 23490, 37281, 54982, 83699, 129730, 191574, 296867, 448061, 677579, 1043891, 1555186, 2391957, 3637578, 5467053, 8407364, 12655124, 19275990, 29392686, 44289560, 67821861, 102554661, 155603507, 237605457, 358750918, 547019054, 830340422, 1258289364, 1918931336, 2904123301, 4418067211, 6717007542, 10176207308, 15499680547, 23504247491, 35701354689, 54301127150, 82320361292, 125235049452, 190123604026, 288603590290, 438932591780, 665874752849, 1012077544382, 1537471809608, 2333591154781, 3547808494789, 5385295045822, 8181012532598, 12430943288713, 18869800292192],
-[1, 2, 2, 2, 4, 4, 7, 14, 27, 27, 38, 72, 105, 167, 228, 353, 568, 826, 1326, 1899, 2766, 4541, 6764, 10305, 15429, 23007, 36567, 54077, 82082, 126453, 187704, 291045, 439275, 664294, 1019901, 1524129, 2344629, 3561358, 5359008, 8226402, 12396326, 18889887, 28774813, 43406185, 66400004, 100428918, 152483050, 232638256, 351474307, 535733053, 813107047, 1232879423, 1879051649, 2844726362, 4327616107, 6577618473, 9969370329, 15179704853, 23021105621, 34971585182, 53177466452, 80639228762, 122659314297, 186207134068, 282702938500, 429875707853, 652227336157, 991304440631, 1505797252561, 2285814483187, 3474761636123, 5274708588081, 8013221518147, 12174980607174, 18482933871892],
-[1, 2, 2, 2, 4, 4, 7, 14, 27, 28, 37, 68, 108, 182, 231, 342, 581, 853, 1378, 1952, 2864, 4720, 6918, 10702, 16028, 23735, 37912, 55894, 85220, 131083, 193738, 302182, 454457, 687233, 1058092, 1576233, 2429787, 3686580, 5546911, 8531766, 12820924, 19565748, 29814107, 44916748, 68811112, 103957579, 157907571, 241012867, 363757173, 555065970, 841957487, 1276393712, 1946671413, 2945002228, 4482328864, 6811952862, 10321703978, 15724291261, 23835726910, 36216401480, 55076977000, 83492432600, 127042726514, 192821628360, 292748483288, 445223209978, 675346810891, 1026652780266, 1559386116426, 2366977282664, 3598719550549, 5462023234347, 8298486114150, 12608564472579, 19139432194397],
-[1, 2, 2, 2, 4, 4, 7, 14, 28, 28, 40, 76, 104, 160, 229, 362, 571, 801, 1309, 1948, 2779, 4517, 6748, 10269, 15428, 23016, 36669, 53938, 81693, 126891, 188190, 290249, 438808, 664353, 1020806, 1523388, 2342458, 3564994, 5354219, 8222512, 12404939, 18881025, 28769494, 43388058, 66400034, 100453510, 152380403, 232639717, 351471431, 535522257, 813140822, 1232563536, 1878816792, 2844389695, 4326285219, 6577895117, 9967074509, 15176183316, 23020254414, 34963083439, 53172065571, 80625891591, 122634781019, 186193065741, 282639043442, 429818982278, 652146720991, 991094685326, 1505632220989, 2285410735947, 3474212252043, 5274026914679, 8011673068586, 12173444319377, 18479968034317],
-[1, 2, 2, 2, 4, 4, 7, 14, 27, 28, 39, 71, 102, 178, 240, 353, 559, 821, 1375, 1949, 2806, 4612, 6864, 10527, 15807, 23404, 37151, 54920, 83766, 129375, 190621, 296256, 447658, 676531, 1040101, 1550570, 2388337, 3627795, 5454694, 8389044, 12621337, 19230008, 29319742, 44197266, 67660462, 102270115, 155263108, 237052670, 357868953, 545735980, 828270122, 1255377116, 1914296085, 2897054204, 4407946738, 6700277209, 10151888735, 15463366391, 23446997377, 35617124898, 54169023429, 82123808825, 124939472373, 189659546802, 287921083535, 437879830120, 664267716471, 1009680225150, 1533758656497, 2328028689594, 3539317767224, 5372312233112, 8161594214190, 12401019718891, 18824600256754],
-[1, 2, 2, 2, 4, 4, 8, 16, 32, 32, 43, 86, 128, 210, 278, 416, 700, 994, 1611, 2346, 3367, 5538, 8187, 12619, 19048, 27866, 44579, 66205, 100203, 155101, 228547, 355628, 537667, 809649, 1249768, 1860479, 2861071, 4359203, 6540357, 10063719, 15143159, 23058951, 35213556, 52989402, 81156836, 122779612, 186169053, 284492580, 429331603, 654616303, 994070928, 1505548374, 2297162487, 3476093742, 5286782869, 8040896956, 12178059190, 18552083580, 28133821709, 42723444101, 65000165827, 98521817054, 149886072412, 227575293424, 345388307016, 525377088412, 796966486685, 1211277390320, 1840290712631, 2792850523416, 4246361267403, 6445672765195, 9791210126240, 14878939621433, 22584240796281],
-[1, 2, 2, 2, 4, 4, 8, 15, 29, 30, 43, 85, 118, 191, 269, 397, 653, 939, 1518, 2197, 3128, 5236, 7796, 11692, 17799, 26364, 41896, 62108, 93692, 145868, 214879, 332774, 506146, 759685, 1170604, 1748989, 2684958, 4094407, 6133749, 9439137, 14238032, 21622662, 33047985, 49767815, 76124737, 115298833, 174695176, 267033460, 403126703, 614041564, 933438318, 1413218206, 2155463705, 3263740271, 4961072920, 7548188180, 11431260129, 17409369923, 26413593042, 40094948654, 61008350252, 92488023410, 140662227412, 213628666843, 324176010765, 493101621904, 748128957236, 1136794414965, 1727413967579, 2621472880200, 3985409629465, 6050473134367, 9189722358857, 13965671867212, 21198611725172],
-[1, 2, 2, 2, 4, 6, 11, 15, 26, 37, 51, 88, 129, 207, 297, 432, 719, 1058, 1591, 2420, 3594, 5692, 8507, 12798, 19762, 29282, 45438, 69045, 103326, 159260, 238774, 365853, 557480, 836328, 1285506, 1939802, 2946903, 4503070, 6782956, 10365949, 15713053, 23813854, 36369786, 54935255, 83664819, 127183671, 192583661, 293638860, 444779617, 675954760, 1028344737, 1557862419, 2371931707, 3599007600, 5463362482, 8312199145, 12603261163, 19164904901, 29107924399, 44173800109, 67183285751, 101941526130, 154894970795, 235364509011, 357205922800, 543015046691, 824432368146, 1252167491463, 1902848684256, 2888534358876, 4389404752211, 6666534838672, 10123596873410, 15383233156320, 23357407680443],
-[1, 2, 3, 3, 6, 11, 14, 19, 36, 52, 71, 112, 179, 279, 382, 613, 979, 1372, 2133, 3286, 4991, 7534, 11210, 17651, 26492, 39482, 61460, 92294, 140141, 213581, 322892, 495351, 743783, 1133013, 1735077, 2607454, 3982536, 6046363, 9165812, 13976028, 21118296, 32205791, 48911564, 74026435, 112943317, 171103728, 259952969, 395390756, 599228913, 912349862, 1383833477, 2101225661, 3197167184, 4846802222, 7370286026, 11194637492, 16990050530, 25837129346, 39203296554, 59575162108, 90517871180, 137373559798, 208841919377, 317067395515, 481574527515, 731802183859, 1110904232020, 1688155511998, 2563816336878, 3893448401985, 5916287689467, 8982934035580, 13646917205739, 20729981798828, 31479853693483],
-[1, 2, 3, 3, 4, 6, 11, 22, 28, 35, 62, 95, 145, 204, 313, 497, 700, 1133, 1747, 2493, 3854, 5823, 9099, 13754, 20100, 31636, 47625, 71930, 110866, 165257, 253744, 384470, 582071, 894888, 1338701, 2044955, 3122105, 4704032, 7196753, 10872499, 16533200, 25209617, 38051154, 58113068, 88091253, 133521635, 203670284, 308211237, 469096120, 712537380, 1080056197, 1645548679, 2493720703, 3789700311, 5762923713, 8736174766, 13293085281, 20174614537, 30634626950, 46584320139, 70660307606, 107430215684, 163161855601, 247669335619, 376549796375, 571505688194, 868338422730, 1319251937332, 2002655889156, 3043811192772, 4621589509140, 7019714818080, 10666105943470, 16193646541764, 24605311026167],
-[1, 2, 3, 3, 4, 6, 11, 17, 26, 38, 54, 79, 133, 203, 279, 422, 684, 1075, 1548, 2290, 3592, 5421, 8215, 12586, 18953, 28753, 43610, 66858, 101695, 152273, 232918, 355151, 537397, 817732, 1238928, 1885973, 2863322, 4341650, 6619756, 10024814, 15218607, 23169133, 35142707, 53427491, 81072873, 123143228, 187297454, 284037675, 431792436, 655968284, 995530051, 1513492662, 2297594861, 3490630859, 5302936704, 8049866508, 12235728087, 18579142400, 28216179859, 42875210472, 65097423197, 98905831681, 150221824232, 228138316875, 346628933280, 526354559682, 799589804975, 1214619201998, 1844547661565, 2802280248467, 4255962403640, 6464526939085, 9820095353043, 14913807915634, 22655672467082],
-[1, 2, 2, 3, 4, 5, 8, 13, 23, 29, 42, 61, 92, 159, 221, 318, 511, 763, 1182, 1722, 2564, 4080, 5999, 9309, 14185, 20869, 32699, 48841, 74485, 114170, 169704, 263025, 396642, 599483, 921929, 1380750, 2114349, 3212097, 4848537, 7434250, 11197797, 17055298, 25996854, 39232056, 59949888, 90755722, 137780033, 210053776, 317579985, 483995438, 734594130, 1113720863, 1697089911, 2570593812, 3909258890, 5941355224, 9007521004, 13711478838, 20797693378, 31592862951, 48037229515, 72854521843, 110796677838, 168219950667, 255402796491, 388311227517, 589236898385, 895505083756, 1360296113642, 2065027770238, 3138878643643, 4765225035364, 7238941754742, 10998344215820, 16697676747021],
-[1, 2, 2, 3, 5, 6, 8, 15, 24, 33, 44, 68, 104, 157, 242, 373, 524, 810, 1306, 1893, 2859, 4302, 6623, 10253, 15117, 23240, 35316, 53040, 81747, 123117, 187159, 284757, 429929, 659932, 995700, 1509076, 2306620, 3482804, 5311471, 8060318, 12215721, 18628451, 28179514, 42900075, 65220794, 98759272, 150452916, 228159166, 346609401, 527121420, 799000543, 1215817308, 1845508594, 2801464063, 4261183642, 6463491737, 9824579031, 14924458997, 22652734067, 34441284733, 52273577556, 79415930471, 120673283833, 183168970523, 278389690569, 422733757390, 642029451682, 975579885267, 1481151375656, 2250463041446, 3418145933541, 5190801759700, 7887031697393, 11976585653600, 18193373506188],
-[1, 2, 2, 3, 4, 5, 7, 11, 19, 22, 36, 50, 75, 120, 168, 257, 400, 622, 908, 1354, 2103, 3212, 4780, 7315, 11105, 16767, 25650, 38839, 59049, 89236, 135494, 207421, 313200, 475396, 723658, 1097282, 1670271, 2532095, 3847828, 5850919, 8865972, 13495292, 20493070, 31091353, 47281090, 71749728, 109064152, 165646775, 251403711, 382283506, 580239006, 881405624, 1339415047, 2033147235, 3089773003, 4691993268, 7126248750, 10828465919, 16439508510, 24977840550, 37940102791, 57614528392, 87538902693, 132933120043, 201933164165, 306747645425, 465833152720, 707716284666, 1074842984479, 1632568786838, 2480060120983, 3766474085387, 5721588673838, 8690450265241, 13199427123579],
-[1, 2, 2, 3, 4, 6, 7, 12, 21, 21, 35, 63, 91, 134, 210, 316, 469, 727, 1099, 1683, 2513, 3832, 5900, 8851, 13477, 20394, 30930, 47348, 71631, 108929, 165091, 250257, 382395, 579011, 878425, 1336419, 2026705, 3085929, 4680388, 7104530, 10809926, 16388754, 24925222, 37860799, 57446830, 87359684, 132572693, 201464950, 306094896, 464515785, 706189321, 1072191214, 1628440265, 2474653949, 3756562108, 5708380057, 8669668282, 13165855529, 20006197343, 30375901285, 46146710664, 70099656220, 106449840629, 161733891881, 245616267261, 373081281026, 566756491197, 860689775638, 1307542227618, 1985936384387, 3016321727259, 4582152767591, 6959059975367, 10571073780310, 16056750638946],
-[1, 2, 2, 3, 4, 5, 7, 10, 19, 22, 37, 57, 86, 128, 181, 296, 431, 666, 1013, 1497, 2305, 3457, 5325, 8159, 12096, 18506, 28135, 42654, 65435, 98049, 149549, 227936, 344247, 527172, 795108, 1207943, 1842793, 2785555, 4250501, 6438385, 9767482, 14893562, 22539654, 34309627, 52105787, 78992520, 120313001, 182381437, 277203431, 421368314, 638894453, 972162047, 1475367104, 2240460061, 3406477464, 5167711996, 7856526056, 11931401185, 18113633804, 27536662863, 41794053044, 63503811715, 96475397726, 146461147420, 222592112120, 337976667050, 513373890295, 780000001995, 1184269913928, 1799415899578, 2732869458731, 4150521038117, 6306014826937, 9575814638243, 14547073884416],
-[1, 2, 2, 3, 4, 6, 9, 13, 26, 30, 40, 74, 123, 179, 242, 366, 604, 941, 1384, 2064, 3065, 4802, 7425, 11093, 16810, 25118, 38829, 59557, 89081, 135748, 205212, 313139, 478235, 720612, 1098389, 1664882, 2527207, 3859110, 5833735, 8869423, 13479649, 20441558, 31152281, 47191483, 71693389, 109047102, 165312209, 251590878, 381803534, 579607642, 881492078, 1337155413, 2033057419, 3087636638, 4686280874, 7125594754, 10814916633, 16431667557, 24965232642, 37895372261, 57598332775, 87456366154, 132834586858, 201840844333, 306429578046, 465608111503, 707173471866, 1073951486307, 1631766350125, 2477794560464, 3764122619135, 5717755475251, 8683139556751, 13192001077375, 20034723244829],
-[1, 2, 2, 3, 4, 4, 8, 12, 23, 30, 50, 74, 106, 187, 253, 394, 621, 884, 1447, 2094, 3147, 5016, 7225, 11393, 17185, 25475, 40241, 59060, 91010, 139554, 206682, 322423, 481792, 732350, 1127281, 1679554, 2590111, 3914976, 5912502, 9091042, 13641733, 20855750, 31716510, 47834540, 73308202, 110635881, 168272351, 256506336, 387268470, 591423363, 896133782, 1359555561, 2072739114, 3135281128, 4774913425, 7251426709, 10992438922, 16745528539, 25374976744, 38575081654, 58641446031, 88905579643, 135297700105, 205289794029, 311779249053, 474078433948, 719094498087, 1093363032319, 1660328668548, 2520616627471, 3832171490120, 5815831693622, 8837380672180, 13425456648287, 20380845198512],
-[1, 2, 2, 3, 4, 4, 8, 12, 23, 30, 49, 72, 105, 173, 235, 369, 585, 835, 1348, 1981, 2958, 4655, 6770, 10721, 16137, 23841, 37488, 55542, 85438, 130417, 193978, 301527, 451749, 686820, 1054965, 1575703, 2423538, 3668523, 5546828, 8508031, 12787717, 19537889, 29709836, 44850292, 68637607, 103694943, 157683065, 240219275, 363050621, 553974609, 839625236, 1274100565, 1941434963, 2938540709, 4473286989, 6793513750, 10301840424, 15686170957, 23778168756, 36143574369, 54937396074, 83313801663, 126751492412, 192354411920, 292140169237, 444137216517, 673827890208, 1024380986781, 1555621841849, 2361852712842, 3590283724407, 5449450461423, 8280144247165, 12578579241379, 19096941245619],
-[1, 2, 2, 3, 4, 5, 8, 13, 25, 31, 49, 77, 112, 188, 263, 413, 621, 895, 1494, 2153, 3220, 5066, 7463, 11768, 17440, 26174, 41051, 60493, 93650, 142435, 212257, 329451, 493265, 753489, 1150774, 1721092, 2654982, 4006068, 6068239, 9297188, 13978931, 21383101, 32439327, 49066465, 75062887, 113276650, 172513453, 262533324, 396962992, 605779671, 917480773, 1393710286, 2122096237, 3212136767, 4892370515, 7424819177, 11264971554, 17149478632, 25992449642, 39525225154, 60049489940, 91092656905, 138589274182, 210262855452, 319443275284, 485534205956, 736678662923, 1120047533833, 1700545857913, 2582420631116, 3925154674894, 5957531653100, 9053358643625, 13751087504949, 20879126018944],
-[1, 2, 2, 3, 4, 4, 8, 12, 22, 27, 44, 69, 94, 153, 221, 348, 524, 762, 1250, 1831, 2681, 4208, 6322, 9786, 14632, 21905, 34304, 50920, 77908, 119271, 177982, 274508, 413813, 629484, 962068, 1442073, 2215405, 3357284, 5073541, 7765120, 11713750, 17860274, 27143429, 41054403, 62718117, 94852147, 144175733, 219573802, 332230069, 506216542, 767820973, 1165486992, 1774383828, 2687937701, 4089687855, 6211665260, 9422358363, 14338468220, 21747974792, 33049114465, 50225408517, 76199737189, 115881787430, 175898560123, 267145852604, 406061950637, 616241850017, 936604567632, 1422439781357, 2159864400735, 3282585142294, 4983337954625, 7571200464967, 11501385223329, 17463402901427],
-[1, 2, 2, 3, 4, 5, 8, 13, 24, 30, 46, 74, 110, 168, 246, 392, 594, 856, 1376, 2063, 3038, 4722, 7127, 10915, 16447, 24814, 38487, 57402, 87361, 134032, 201127, 308071, 465995, 708085, 1080662, 1626464, 2488790, 3777306, 5711953, 8723789, 13197568, 20083273, 30525822, 46225761, 70491170, 106785698, 162202614, 246902179, 374024262, 569197834, 864018661, 1311510765, 1995328564, 3025374274, 4599947887, 6988204237, 10603374299, 16125935138, 24473179013, 37179734693, 56498785059, 85747202015, 130345255166, 197911611850, 300563183769, 456775792083, 693406107553, 1053614073956, 1600318548158, 2430117645551, 3692653650265, 5606969395388, 8517645362033, 12939165600724, 19648439461046],
-[1, 2, 2, 3, 4, 5, 10, 12, 21, 34, 51, 68, 102, 174, 253, 372, 571, 864, 1344, 2050, 3041, 4644, 6987, 10784, 16519, 24494, 37560, 57037, 86711, 132423, 198830, 303992, 461452, 699014, 1068685, 1612571, 2453181, 3734043, 5654736, 8621792, 13053829, 19830580, 30196112, 45728250, 69616340, 105658494, 160324303, 244002231, 369950208, 562517529, 854490387, 1296375462, 1972073873, 2992502149, 4546114808, 6908963221, 10484324337, 15938719297, 24200379452, 36751695317, 55855896066, 84784051664, 128835242821, 195688842517, 297140872078, 451539997895, 685599440705, 1041518385424, 1582200722912, 2402521077342, 3650354300920, 5543696673857, 8420270206928, 12791972678702, 19425803676899],
-[1, 2, 2, 3, 4, 5, 7, 10, 17, 22, 39, 65, 88, 127, 198, 297, 452, 678, 1054, 1589, 2342, 3759, 5572, 8262, 12792, 19361, 29803, 44568, 67598, 104342, 155790, 238125, 363378, 547332, 836556, 1264268, 1927660, 2933546, 4421610, 6763877, 10252080, 15539866, 23693742, 35852866, 54600581, 82880613, 125682405, 191523481, 290060120, 440963968, 670549581, 1016542895, 1546825870, 2346898143, 3564896101, 5420450881, 8220770319, 12501234345, 18983293928, 28817503403, 43814206933, 66493566406, 101041944569, 153492540929, 233013433048, 354178496402, 537714625775, 816792261816, 1241037525766, 1884161283932, 2863016100832, 4348027688101, 6603616165230, 10033322251861, 15235032334325],
-[1, 2, 2, 3, 4, 4, 7, 10, 19, 26, 45, 58, 85, 155, 208, 328, 488, 732, 1181, 1716, 2669, 3976, 5852, 9472, 14056, 20967, 32372, 48465, 75301, 112729, 170043, 263520, 392122, 602029, 917529, 1378097, 2116448, 3186612, 4862275, 7411675, 11147355, 17085611, 25858808, 39205959, 59860388, 90397082, 137852614, 209214440, 317011120, 483651703, 731758336, 1113013768, 1692713655, 2564304280, 3905552049, 5922201432, 8995760589, 13683847381, 20741579727, 31556103617, 47909283012, 72718264848, 110601975665, 167785799852, 255021024934, 387412093485, 587991915738, 893987409609, 1356981038473, 2061381514669, 3132365499357, 4754722402585, 7226162352545, 10973346577008, 16665382368553],
-[1, 2, 2, 3, 4, 4, 7, 10, 18, 23, 37, 52, 72, 127, 174, 262, 392, 606, 969, 1361, 2083, 3219, 4742, 7457, 11120, 16716, 25863, 38336, 59538, 89865, 134474, 208797, 311902, 476756, 726776, 1091314, 1679799, 2528422, 3844693, 5878757, 8840688, 13528800, 20504321, 31060986, 47449533, 71636108, 109193647, 165927959, 251130647, 383185478, 580150292, 881791884, 1341673140, 2031950636, 3094756446, 4694458254, 7126902044, 10845335449, 16438664802, 25002545458, 37972822612, 57622498169, 87650078381, 132974712986, 202070823587, 307049127856, 465951642374, 708425288188, 1075463674897, 1633463632543, 2482408141183, 3768010990124, 5726286260035, 8696598106584, 13206240765364],
-[1, 2, 2, 3, 4, 5, 7, 12, 19, 23, 42, 58, 73, 120, 192, 283, 414, 643, 997, 1485, 2166, 3434, 5221, 7702, 11825, 18082, 27591, 41376, 62742, 96494, 145066, 220635, 337091, 508884, 774185, 1175364, 1788950, 2718855, 4108143, 6267110, 9521786, 14424405, 21958221, 33306651, 50632328, 76904132, 116680658, 177572240, 269315476, 409068198, 622052145, 943752257, 1434502768, 2178223354, 3308040235, 5028076170, 7630754966, 11596480808, 17615499175, 26743355830, 40643876716, 61712936836, 93745735974, 142415803865, 216243664511, 328579906889, 498994493852, 757881302649, 1151443024079, 1748507002998, 2656279600916, 4034613312931, 6127619397032, 9309033132255, 14137502828578],
-[1, 2, 2, 3, 4, 4, 7, 10, 19, 26, 44, 61, 83, 147, 215, 326, 468, 721, 1158, 1694, 2555, 3907, 5869, 9092, 13713, 20681, 31601, 47460, 73233, 110917, 166582, 255882, 386239, 588674, 894874, 1351213, 2067348, 3124989, 4749256, 7241219, 10940884, 16674953, 25305814, 38395905, 58482653, 88538279, 134736477, 204727475, 310368227, 472513320, 716571116, 1088738344, 1655133420, 2510366029, 3818428170, 5795772583, 8800793466, 13381025831, 20303147292, 30859110884, 46870404200, 71158190723, 108163269422, 164190993828, 249451995413, 378976882843, 575358843008, 874355727359, 1327727298531, 2016653486373, 3063962132820, 4652306459166, 7068381226478, 10735603172252, 16304471638470],
-[1, 2, 2, 2, 4, 4, 7, 14, 28, 28, 40, 76, 105, 165, 228, 358, 591, 830, 1328, 1955, 2821, 4631, 6858, 10438, 15752, 23367, 37293, 55047, 83077, 129017, 190950, 295788, 447198, 674200, 1038833, 1549691, 2382393, 3627767, 5442637, 8368204, 12614300, 19196508, 29287143, 44114349, 67530134, 102196175, 154961674, 236706791, 357405765, 544691867, 827292444, 1253331748, 1911375184, 2893185078, 4399923647, 6691678604, 10136616336, 15437768185, 23415601821, 35558201339, 54090989724, 82003100163, 124736705078, 189397408407, 287463009598, 437217758867, 663313926644, 1008066604516, 1531539658932, 2324467486108, 3533886530986, 5364517467684, 8148797440355, 12382671000076, 18796344200315],
-[1, 2, 2, 2, 4, 4, 6, 11, 21, 23, 34, 57, 76, 112, 165, 268, 415, 582, 930, 1413, 2053, 3269, 4814, 7276, 11233, 16829, 26339, 38742, 58719, 91698, 136022, 208679, 316142, 477708, 734902, 1100595, 1685610, 2565183, 3855012, 5921459, 8946064, 13583482, 20712705, 31262489, 47789355, 72383660, 109722472, 167485220, 253175324, 385506232, 585791374, 887608607, 1352522372, 2048989155, 3114976505, 4737196657, 7178281820, 10926568076, 16580712810, 25175590963, 38289928752, 58069081227, 88298917079, 134094715121, 203537549715, 309507458623, 469674664647, 713654180290, 1084290166556, 1645841627371, 2501721094098, 3798242285323, 5769233528456, 8766426787507, 13308472695279],
-[1, 2, 2, 2, 4, 4, 6, 11, 21, 22, 32, 55, 75, 114, 163, 255, 397, 573, 897, 1296, 1897, 3121, 4589, 6894, 10522, 15622, 24690, 36462, 55311, 85816, 126505, 196137, 297054, 447148, 688278, 1028918, 1580595, 2403661, 3609317, 5551798, 8367106, 12722491, 19419759, 29266650, 44767916, 67759499, 102770196, 156954308, 236985945, 361154063, 548630477, 831083449, 1267210201, 1918617024, 2917607739, 4436886190, 6721579204, 10236358909, 15527043099, 23577554962, 35865994291, 54377751353, 82707902886, 125585760199, 190616302159, 289906633496, 439837984004, 668423468616, 1015541402561, 1541336937224, 2343211047838, 3557165546776, 5403338227800, 8210663296922, 12463665012917],
-[1, 2, 2, 2, 4, 4, 8, 14, 26, 27, 39, 74, 110, 173, 227, 351, 587, 836, 1340, 1999, 2851, 4652, 6933, 10604, 16056, 23482, 37610, 56079, 84233, 130632, 193149, 299587, 453784, 682201, 1053287, 1570606, 2409720, 3678887, 5515378, 8477091, 12780448, 19436264, 29695124, 44688167, 68391653, 103592901, 156939010, 239846486, 362150980, 551748433, 838389600, 1269482532, 1936633542, 2931811542, 4456687791, 6780719710, 10269542484, 15640021712, 23726581290, 36020968900, 54807307830, 83081874071, 126366874679, 191910429206, 291223241035, 442973594710, 672064192197, 1021263585461, 1551784527243, 2354964961762, 3580334658138, 5435320967888, 8255585115819, 12545947106431, 19043562005244],
-[1, 2, 2, 2, 4, 4, 8, 16, 32, 32, 45, 90, 128, 218, 295, 420, 693, 1014, 1666, 2424, 3423, 5664, 8415, 12841, 19602, 28568, 45445, 67792, 102601, 159410, 233742, 363092, 551958, 828505, 1279355, 1906538, 2926607, 4466895, 6693557, 10306042, 15519757, 23581919, 36072368, 54274992, 83066444, 125776118, 190540620, 291370260, 439689863, 670018245, 1018319051, 1541297043, 2351950797, 3560261133, 5412124812, 8234963776, 12468980937, 18994519383, 28813807535, 43738932257, 66561488490, 100887076661, 153457864313, 233055665926, 353634756690, 537965036521, 816106878945, 1240173415523, 1884519992239, 2859687163313, 4347937346268, 6600468083795, 10025174840778, 15235828234193, 23125336817237],
-[1, 2, 2, 2, 4, 4, 8, 15, 28, 30, 43, 81, 113, 185, 259, 386, 618, 883, 1484, 2156, 3049, 5021, 7453, 11444, 17251, 25468, 40616, 59869, 91055, 141440, 207940, 322691, 488407, 737180, 1135683, 1690499, 2603410, 3961702, 5943064, 9150633, 13771993, 20964230, 31993191, 48182470, 73806004, 111585543, 169253375, 258641653, 390299054, 595122165, 903637440, 1368885950, 2088217767, 3159799711, 4806865798, 7309688303, 11070865807, 16865859690, 25575865346, 38841960248, 59088784629, 89566450353, 136266658641, 206878602028, 314000174368, 477621165005, 724512201000, 1101191069753, 1672968836759, 2539023249544, 3860374092018, 5859646512751, 8901362608515, 13526296031963, 20531315222212],
-[1, 2, 2, 2, 4, 4, 7, 13, 25, 27, 37, 68, 101, 168, 226, 329, 541, 801, 1272, 1853, 2668, 4375, 6534, 9905, 15144, 22187, 34976, 52605, 79323, 122641, 181229, 280470, 426850, 640258, 986469, 1477245, 2259786, 3449307, 5179508, 7952828, 12002611, 18223460, 27859795, 41980385, 64126242, 97230936, 147300426, 224991882, 339977139, 517585193, 786886979, 1191456116, 1816584948, 2752228011, 4181777406, 6362307326, 9638748849, 14673700709, 22268569947, 33800950902, 51425750046, 77978275427, 118565589488, 180096299501, 273300508650, 415644585803, 630727789785, 958296866081, 1456198347220, 2210035376759, 3359511486263, 5100820255827, 7746965492623, 11772770444235, 17871431253591],
-[1, 2, 2, 2, 4, 4, 7, 13, 24, 25, 35, 69, 94, 162, 223, 320, 526, 774, 1252, 1792, 2595, 4301, 6309, 9559, 14745, 21650, 34063, 50681, 77175, 119759, 175396, 272636, 414517, 621966, 959451, 1432959, 2199075, 3349095, 5025545, 7740613, 11652444, 17703158, 27073870, 40764714, 62355625, 94405825, 143105954, 218717495, 330076133, 503062456, 764503987, 1157303954, 1765487613, 2672966617, 4063763901, 6181733409, 9361881671, 14260560896, 21631627585, 32839444222, 49969387700, 75746343049, 115211484422, 174961302698, 265513390880, 403876357571, 612704299089, 931101566864, 1414796469982, 2147022771401, 3264245718063, 4955383351895, 7526761080381, 11438289335508, 17361972734679],
-[1, 2, 2, 2, 4, 4, 7, 13, 26, 26, 40, 72, 94, 150, 210, 325, 535, 768, 1197, 1800, 2612, 4194, 6245, 9419, 14505, 21399, 33658, 50400, 75769, 117563, 174346, 268888, 408633, 613558, 945796, 1417677, 2166032, 3306381, 4965778, 7620487, 11513627, 17473626, 26700928, 40247772, 61469647, 93242633, 141221161, 215652909, 325990622, 496161121, 754417582, 1142376689, 1741356266, 2638752572, 4008923106, 6099614691, 9241536014, 14066327372, 21349872485, 32405680464, 49300434501, 74762375646, 113664521510, 172661924840, 262019911857, 398467201135, 604707499419, 918707838167, 1396056891570, 2118827926380, 3220710394295, 4890283587554, 7427039506773, 11286519384455, 17133806713870],
-[1, 2, 2, 2, 4, 6, 11, 16, 29, 38, 50, 90, 133, 216, 311, 446, 742, 1083, 1673, 2540, 3676, 5887, 8819, 13332, 20535, 30209, 47246, 71511, 107047, 165764, 247026, 379173, 578238, 867098, 1335654, 2007673, 3055679, 4675004, 7025861, 10758926, 16288462, 24686245, 37738556, 56926649, 86811632, 131879560, 199597666, 304686619, 461067989, 701016498, 1066534547, 1614907425, 2460552902, 3731388174, 5665171047, 8621605549, 13065683487, 19877117494, 30184019085, 45802594464, 69679673128, 105694493773, 160635301159, 244079499737, 370372536267, 563166451447, 854860159851, 1298473954543, 1973343371045, 2995105597559, 4552047573289, 6912917469060, 10497706866241, 15953078803202, 24219869598214],
-[1, 2, 2, 2, 4, 4, 7, 14, 28, 29, 40, 76, 107, 178, 252, 375, 610, 886, 1401, 2043, 2989, 4867, 7229, 10981, 16732, 24849, 39010, 58186, 88152, 136149, 202014, 312054, 473342, 713045, 1096033, 1642721, 2515999, 3829926, 5762184, 8841290, 13343859, 20283521, 30947795, 46692315, 71326205, 108062331, 163887313, 250081860, 378039442, 575691271, 874631941, 1325354839, 2019677350, 3059856725, 4651298296, 7072830857, 10719432603, 16316623401, 24757759001, 37593012314, 57174680962, 86711402829, 131850109332, 200231030145, 303935332104, 462153823469, 701325327296, 1065668802766, 1619072605666, 2457618202191, 3735611409678, 5671629637288, 8614850632412, 13090093190640, 19872638163460],
-[1, 2, 2, 2, 4, 4, 7, 13, 24, 26, 39, 69, 91, 146, 204, 330, 541, 749, 1191, 1785, 2649, 4243, 6128, 9422, 14482, 21510, 33886, 49953, 75890, 117826, 174518, 269984, 406927, 614305, 948358, 1416353, 2172801, 3302824, 4967214, 7638558, 11504789, 17510098, 26705720, 40245506, 61599424, 93219912, 141395659, 215839483, 325986879, 496972073, 754588850, 1143293033, 1743281880, 2639114050, 4013949285, 6103196610, 9246939202, 14081826345, 21357434582, 32437479420, 49338381818, 74801995298, 113780817752, 172754210026, 262230920098, 398805375016, 605046287669, 919552113296, 1396977227331, 2120354140524, 3223479085610, 4893287046662, 7433234312267, 11294808876357, 17145619100928],
-[1, 2, 2, 2, 4, 4, 6, 10, 18, 21, 27, 45, 66, 111, 145, 229, 362, 525, 825, 1195, 1804, 2814, 4190, 6505, 9761, 14567, 22666, 33846, 51852, 79020, 117975, 182173, 274694, 417578, 638047, 957126, 1468423, 2225551, 3368534, 5150394, 7765425, 11844722, 18006012, 27237786, 41581235, 62907098, 95645588, 145598870, 220364023, 335749933, 509215022, 773010413, 1176722815, 1782924491, 2712389990, 4119310915, 6249770082, 9509453171, 14423770170, 21919633857, 33309655928, 50540167759, 76853971513, 116660115370, 177186134030, 269300961692, 408715883971, 621187286292, 943389175355, 1432514074713, 2177060954782, 3305124448743, 5021480826646, 7627913389170, 11582429805527],
-[1, 2, 2, 2, 4, 4, 6, 11, 22, 23, 33, 52, 73, 122, 167, 250, 395, 609, 923, 1352, 2028, 3189, 4799, 7270, 11085, 16522, 25521, 38504, 58685, 89481, 133914, 205731, 312289, 472750, 721283, 1087978, 1660765, 2523811, 3818963, 5827807, 8815806, 13404928, 20407655, 30896674, 47055620, 71355657, 108359042, 164937904, 249904925, 380163984, 577333637, 876039218, 1332808403, 2021686195, 3072426104, 4668415207, 7083946914, 10772075179, 16351206395, 24835099932, 37744256259, 57287503621, 87067252250, 132224125729, 200787603985, 305136111544, 463251513295, 703824811888, 1069127592507, 1623431564522, 2466762909559, 3745897529409, 5690007159148, 8643984709967, 13126299557179],
-[1, 2, 2, 2, 4, 4, 6, 12, 23, 24, 34, 57, 88, 138, 188, 285, 439, 671, 1051, 1543, 2260, 3519, 5382, 8296, 12308, 18383, 28770, 43263, 65699, 99949, 150199, 230844, 348884, 530501, 808793, 1216073, 1861509, 2828124, 4279650, 6525918, 9867434, 15035504, 22850638, 34593726, 52747525, 79900869, 121400687, 184753903, 279924090, 426012201, 646437795, 981493873, 1493324924, 2264025699, 3442401016, 5229112128, 7935471675, 12068137142, 18313372650, 27824819772, 42279426684, 64167944877, 97546221888, 148103250940, 224931185034, 341819581347, 518903490900, 788493706505, 1197564127634, 1818584424950, 2763395782702, 4195907490520, 6374252482808, 9682868560881, 14703900688028],
-[1, 2, 2, 2, 4, 4, 7, 14, 28, 28, 37, 72, 106, 181, 240, 358, 581, 845, 1381, 2023, 2923, 4666, 6938, 10835, 16334, 23817, 37730, 56466, 85897, 132028, 195150, 303136, 457744, 691689, 1065036, 1588261, 2438997, 3711494, 5588142, 8578424, 12910274, 19673166, 30005782, 45223567, 69191556, 104683049, 158853508, 242445829, 366249359, 558339355, 847436020, 1284356404, 1958397902, 2964709662, 4509256067, 6855042423, 10388048451, 15819198043, 23990632083, 36440671314, 55420831706, 84028176530, 127818687431, 194056393344, 294585989964, 447981583003, 679667693837, 1033006892274, 1569239282212, 2381919423466, 3621060208699, 5496762045091, 8350240729955, 12687725551403, 19260523679688],
-[1, 2, 2, 2, 4, 4, 7, 13, 24, 26, 41, 71, 92, 148, 214, 325, 523, 750, 1202, 1757, 2559, 4222, 6167, 9307, 14257, 21304, 33635, 49448, 75097, 116837, 172522, 266893, 404121, 608882, 937567, 1401816, 2153304, 3273700, 4914439, 7562662, 11401583, 17332982, 26446885, 39865040, 60995686, 92310426, 139998800, 213806754, 322842316, 491996973, 747396524, 1132230842, 1726266783, 2613623032, 3974745379, 6044461668, 9156767054, 13944731939, 21152396978, 32120167651, 48859827810, 74078714955, 112673403115, 171083886898, 259677081857, 394940073188, 599191194661, 910592332599, 1383463184821, 2099771489024, 3192156392987, 4845906296101, 7360976938475, 11185360218454, 16979246185244],
-[1, 2, 2, 2, 4, 4, 6, 10, 16, 18, 24, 44, 66, 114, 138, 200, 336, 504, 844, 1160, 1696, 2782, 4040, 6404, 9582, 13880, 22240, 33136, 50792, 77436, 113756, 179274, 268872, 405596, 626364, 930688, 1436764, 2177774, 3279690, 5050952, 7563260, 11565860, 17639466, 26533088, 40686050, 61419032, 93350176, 142499238, 214869572, 328261352, 497599618, 754170990, 1151053392, 1740292552, 2649705550, 4026190044, 6099623592, 9296880292, 14085575232, 21406314838, 32558416734, 49340491996, 75100258754, 113963842452, 173027770422, 263181362054, 399121205516, 606866063428, 921702429504, 1398933836560, 2127246955524, 3228206362566, 4905023123792, 7452642488256, 11311997773442],
-[1, 2, 2, 2, 4, 4, 7, 13, 26, 26, 35, 65, 95, 162, 210, 323, 528, 763, 1256, 1817, 2613, 4181, 6228, 9787, 14600, 21356, 34082, 50585, 77111, 118441, 175159, 272608, 409841, 621562, 956753, 1423174, 2191796, 3329575, 5015535, 7702763, 11576537, 17673312, 26922097, 40572685, 62146614, 93898375, 142606732, 217615533, 328610768, 501337234, 760300002, 1152824520, 1758067378, 2659954333, 4048176333, 6151855113, 9322781996, 14200447738, 21527296223, 32710579499, 49740242540, 75407701816, 114736710105, 174147029784, 264403232286, 402086238938, 609951487004, 927224352840, 1408330055036, 2137781069687, 3250128943257, 4933040351393, 7494808977383, 11387250197263, 17286040617696],
-[1, 2, 2, 2, 4, 4, 7, 14, 28, 28, 39, 75, 108, 182, 244, 357, 592, 863, 1396, 2003, 2886, 4787, 7053, 10788, 16387, 24057, 38337, 56814, 86205, 133524, 196285, 305798, 462690, 695832, 1074073, 1600241, 2461144, 3746544, 5621059, 8656543, 13021430, 19819786, 30277630, 45563827, 69775942, 105554411, 160070159, 244626403, 369084213, 562818251, 854763398, 1294388470, 1975012044, 2988661613, 4545570834, 6913438514, 10469992916, 15951186134, 24188973599, 36731669427, 55886774995, 84706707180, 128868811835, 195663767555, 296955295899, 451715093856, 685207064974, 1041426079014, 1582258321342, 2401212498878, 3650940414068, 5541845086562, 8418254404544, 12792645664410, 19417316086489],
-[1, 2, 2, 2, 4, 5, 10, 16, 31, 38, 51, 94, 136, 219, 318, 454, 738, 1084, 1689, 2599, 3725, 5888, 8873, 13422, 20838, 30527, 47401, 72062, 108031, 167615, 249284, 381318, 583438, 874975, 1347175, 2026259, 3078229, 4715655, 7088312, 10848650, 16437436, 24880801, 38059536, 57437058, 87526530, 133045698, 201261712, 307259574, 465141022, 706833780, 1075826962, 1628679363, 2481173481, 3764070283, 5712881965, 8695620714, 13177977926, 20044419556, 30446065387, 46191341197, 70273760376, 106605727717, 161993322470, 246181381881, 373535235877, 567958170563, 862218352106, 1309499155897, 1990258834903, 3020752174762, 4590769833271, 6972290102977, 10587163180819, 16089441025144, 24427402006797],
-[1, 2, 3, 3, 5, 8, 12, 23, 37, 43, 63, 109, 178, 261, 343, 571, 929, 1326, 2086, 3045, 4643, 7228, 10752, 16821, 24766, 37469, 58890, 87272, 133479, 202540, 305425, 471376, 705592, 1078480, 1645107, 2467723, 3794185, 5736345, 8697369, 13276902, 20021330, 30616186, 46410112, 70261915, 107329916, 162223743, 246945315, 375480452, 568551036, 866642382, 1313013361, 1995348312, 3036049867, 4598843990, 7000481987, 10625329857, 16128101863, 24536466250, 37205803799, 56570783903, 85929292915, 130399055042, 198318001770, 300944674976, 457222235642, 694791158301, 1054490208377, 1602922643998, 2433751379543, 3696289548858, 5617154647733, 8527033481207, 12957065298802, 19679716518321, 29884490810613],
-[1, 2, 3, 3, 5, 8, 12, 16, 31, 46, 68, 100, 150, 242, 344, 529, 835, 1232, 1886, 2836, 4323, 6656, 9906, 15243, 23141, 34736, 53633, 80744, 122680, 186946, 282047, 432611, 652856, 990452, 1513965, 2284609, 3484319, 5288563, 8014561, 12222258, 18491099, 28145685, 42787601, 64798157, 98725892, 149701782, 227392408, 345857711, 524261669, 797716119, 1210865662, 1838081330, 2795863069, 4240799269, 6446104246, 9792402098, 14862699556, 22597399113, 34298125777, 52106438548, 79176083575, 120180169311, 182657256471, 277365455657, 421246011577, 640098916935, 971814247496, 1476568538683, 2242718437037, 3405787038394, 5174842751446, 7858074749618, 11937011373802, 18133033810083, 27537086328301],
-[1, 2, 3, 3, 5, 9, 13, 19, 28, 45, 76, 101, 156, 249, 366, 591, 850, 1288, 2011, 2973, 4704, 6921, 10390, 16345, 24207, 37224, 56369, 84748, 131349, 196058, 299667, 457954, 685852, 1055580, 1593724, 2417931, 3696697, 5562614, 8513793, 12907730, 19530305, 29860027, 45092915, 68672209, 104428999, 158030022, 241079967, 365075270, 554747809, 844409576, 1278527571, 1946914031, 2954513576, 4484067881, 6824125665, 10344274395, 15730993264, 23895898316, 36255392752, 55151504931, 83678423814, 127137370139, 193212526345, 293192939276, 445746997455, 676744318757, 1027778167955, 1562037949366, 2370976466482, 3603011646971, 5472363478336, 8309605687311, 12627645714241, 19172685895403, 29126709430741],
-[1, 2, 3, 3, 4, 6, 11, 20, 26, 34, 61, 94, 137, 205, 302, 473, 713, 1128, 1702, 2430, 3796, 5859, 8856, 13436, 20022, 30938, 47014, 70758, 108951, 163351, 248326, 379833, 573946, 876657, 1322053, 2010281, 3073044, 4635715, 7066349, 10726427, 16257856, 24788776, 37526112, 57102641, 86757257, 131447384, 200299069, 303707975, 461246076, 701473209, 1063623825, 1618174768, 2456189125, 3728921561, 5671091081, 8602596881, 13076365937, 19864226996, 30150606094, 45836718479, 69575807456, 105703453041, 160605791617, 243794321704, 370522613210, 562642713834, 854525269980, 1298436789740, 1971391132089, 2995255114635, 4549370507193, 6908878303428, 10497233089260, 15940401690313, 24214696204169],
-[1, 2, 2, 3, 4, 5, 7, 12, 20, 25, 40, 54, 81, 145, 205, 288, 440, 699, 1103, 1596, 2411, 3708, 5541, 8656, 13043, 19440, 29876, 45163, 69418, 104865, 157481, 242562, 365842, 556653, 848623, 1279375, 1954785, 2960337, 4498186, 6858034, 10352752, 15783475, 23979355, 36340997, 55366511, 83850462, 127539202, 193866781, 293841883, 447403612, 678530632, 1030554981, 1567392392, 2376976382, 3614839397, 5488089740, 8332110798, 12669962884, 19223673214, 29215506407, 44382062968, 67369716670, 102409246071, 155468280448, 236171057205, 358833831979, 544752142769, 827840745506, 1257165798168, 1909315560492, 2901064257668, 4404921491881, 6692269119349, 10164927219386, 15437064365011],
-[1, 2, 2, 3, 4, 6, 7, 11, 21, 21, 30, 56, 88, 125, 177, 289, 434, 646, 986, 1471, 2245, 3433, 5190, 8006, 11940, 18074, 27866, 41779, 63851, 96911, 146761, 224090, 337721, 516415, 784792, 1183968, 1808882, 2740987, 4165111, 6335184, 9589708, 14619627, 22167861, 33642863, 51232991, 77608444, 118044169, 179327695, 272081358, 413946529, 627779347, 954147699, 1450144155, 2199861113, 3345320736, 5078367958, 7712691713, 11722627033, 17791107548, 27040460453, 41065538037, 62354475127, 94769199529, 143871948704, 218578646053, 332042887164, 504176080815, 766107142042, 1163355453542, 1767096220235, 2684599431199, 4076546877487, 6193397038692, 9406573726276, 14286740845179],
-[1, 2, 2, 3, 4, 5, 7, 10, 19, 24, 43, 60, 90, 141, 206, 317, 442, 700, 1101, 1585, 2462, 3743, 5610, 8654, 12893, 19835, 30164, 45091, 70058, 104970, 159116, 244040, 366545, 562362, 849373, 1287394, 1972691, 2967399, 4530826, 6887643, 10411217, 15904926, 24050566, 36593794, 55682885, 84219830, 128458526, 194735440, 295578382, 450071144, 681694633, 1037477448, 1575222234, 2389854164, 3637414242, 5514947805, 8382928494, 12739442421, 19325921573, 29393536715, 44610132370, 67761479500, 102993851664, 156287973004, 237573735911, 360769608344, 547810146007, 832606873233, 1263899300746, 1920390710505, 2917106293239, 4429262495278, 6730800050561, 10220248402111, 15524802502839],
-[1, 2, 2, 3, 4, 6, 7, 10, 20, 20, 33, 61, 102, 131, 188, 323, 461, 734, 1083, 1600, 2513, 3692, 5889, 8761, 12809, 20324, 30493, 46309, 70448, 105445, 163617, 245485, 372370, 573040, 855544, 1311779, 1994269, 3012666, 4611548, 6940166, 10600044, 16134158, 24335399, 37222224, 56314461, 85534147, 130340085, 197101706, 300518173, 455706195, 691299939, 1053536239, 1595104293, 2426589727, 3687062453, 5590877774, 8510791810, 12905625602, 19611307190, 29813332104, 45213596669, 68773760156, 104397323950, 158523475928, 241004493492, 365691433883, 555844756457, 844215245054, 1281671007457, 1948222144392, 2957389201203, 4493046161689, 6826002472944, 10363357899114, 15748705468529],
-[1, 2, 2, 3, 4, 5, 8, 12, 22, 26, 43, 67, 97, 156, 209, 323, 540, 783, 1146, 1728, 2664, 4177, 6093, 9343, 14355, 21163, 33054, 49912, 75170, 114926, 172524, 266806, 402311, 605662, 932200, 1402674, 2138909, 3255289, 4915480, 7516384, 11353501, 17276643, 26338948, 39753051, 60648713, 92020727, 139586613, 212658104, 321876795, 490030526, 744291225, 1128327620, 1718506845, 2605072807, 3958641696, 6018405915, 9127068815, 13885049660, 21071536305, 32000399321, 48656775105, 73814489063, 112210658894, 170420502926, 258722841190, 393298534085, 596970332744, 907036828575, 1377961526290, 2091930231354, 3179295433770, 4827487203971, 7332553377270, 11140754080847, 16915374120563],
-[1, 2, 2, 3, 4, 5, 7, 11, 19, 23, 34, 49, 78, 131, 173, 243, 395, 656, 962, 1343, 2094, 3267, 4903, 7549, 11254, 16930, 26108, 39687, 60779, 90730, 137275, 212326, 319825, 485967, 737835, 1116980, 1707511, 2581433, 3929552, 5975655, 9028023, 13782660, 20915101, 31724497, 48269801, 73132214, 111362100, 169056417, 256408971, 390325314, 591782828, 899469290, 1367112727, 2073935554, 3154015371, 4786507495, 7271326389, 11052841462, 16770124387, 25492128139, 38713454452, 58783556740, 89344044645, 135623354020, 206070475928, 313023072173, 475279129246, 722266480342, 1096700830842, 1665883559268, 2530857642895, 3842998460628, 5838840579540, 8867639011298, 13468428454271],
-[1, 2, 2, 3, 4, 5, 7, 12, 19, 23, 40, 52, 80, 143, 201, 282, 433, 666, 1046, 1552, 2303, 3556, 5331, 8342, 12651, 18636, 28706, 43457, 66706, 101381, 151419, 232987, 352515, 535185, 818053, 1230283, 1878585, 2853048, 4324248, 6602025, 9965239, 15173682, 23092497, 34951576, 53280444, 80720791, 122631192, 186636308, 282724256, 430414405, 653162192, 991229506, 1508508969, 2287335841, 3477457222, 5282454354, 8015687291, 12191720104, 18500227779, 28106518077, 42713222675, 64820139230, 98537055869, 149616535163, 227219368577, 345310348009, 524178806652, 796517356095, 1209805516131, 1837065278232, 2791565649163, 4238688153750, 6439098480997, 9781651333029, 14853554422648],
-[1, 2, 2, 3, 4, 4, 8, 11, 20, 28, 46, 65, 95, 167, 233, 356, 540, 790, 1306, 1902, 2853, 4404, 6465, 10298, 15446, 23012, 35703, 53003, 82251, 124863, 185993, 288216, 432133, 659897, 1008569, 1510172, 2322725, 3506969, 5320698, 8145289, 12248082, 18722046, 28419400, 43004221, 65731446, 99263460, 151131920, 229926073, 347838772, 530647528, 803841262, 1220929339, 1858723523, 2814644286, 4285613537, 6504226108, 9869620067, 15022172996, 22772517196, 34625347137, 52604742798, 79807912596, 121400525544, 184208402029, 279855451286, 425329838800, 645399728542, 981185190422, 1489775830940, 2262384390555, 3438496266653, 5219280910856, 7931097333527, 12046508567509, 18291653146663],
-[1, 2, 2, 3, 4, 5, 8, 13, 22, 26, 41, 68, 104, 162, 225, 343, 532, 808, 1278, 1857, 2782, 4392, 6535, 10023, 15064, 22798, 35295, 52618, 80627, 122893, 184416, 283356, 427726, 650633, 991941, 1493867, 2287623, 3465064, 5249791, 8017090, 12110472, 18452484, 28032183, 42465395, 64752499, 98025220, 149050729, 226740763, 343462663, 522956540, 793352559, 1204717516, 1832634958, 2778369831, 4225806098, 6417096200, 9739222441, 14812620102, 22474271463, 34152222995, 51888970332, 78754176479, 119726554183, 181755451177, 276076470380, 419526544939, 636833942292, 967774730720, 1469749001779, 2232014966563, 3391611811934, 5149567507201, 7823501702600, 11883774468948, 18046309087311],
-[1, 2, 2, 3, 4, 4, 8, 12, 24, 32, 53, 74, 111, 202, 274, 408, 620, 928, 1529, 2187, 3280, 5126, 7564, 11965, 17855, 26568, 41482, 61657, 95338, 144665, 215602, 334812, 501649, 765029, 1170092, 1751455, 2694850, 4070365, 6171982, 9449977, 14207492, 21721079, 32981409, 49881847, 76258551, 115167764, 175325862, 266791053, 403525270, 615641307, 932629368, 1416373445, 2156654147, 3265410430, 4971834106, 7546415464, 11450010145, 17428993205, 26420068472, 40170094543, 61033288361, 92588459202, 140847085946, 213717696784, 324672081705, 493468567503, 748769294561, 1138344131133, 1728428772119, 2624709471542, 3989310913846, 6055279883658, 9201383952768, 13976247117475, 21221299685560],
-[1, 2, 2, 3, 4, 5, 8, 12, 22, 26, 39, 65, 107, 165, 210, 325, 539, 833, 1279, 1815, 2758, 4400, 6553, 10008, 14941, 22583, 35242, 52772, 80543, 121998, 183340, 283348, 427100, 648179, 988205, 1488839, 2282539, 3455635, 5235151, 7992487, 12066294, 18405035, 27962500, 42332976, 64552573, 97722917, 148647652, 226096211, 342396275, 521474082, 790966128, 1201163805, 1827472451, 2770063805, 4213488742, 6398008515, 9710685863, 14770449458, 22406826673, 34052382126, 51737926111, 78521166319, 119380118482, 181218478779, 275268058958, 418302977560, 634950402670, 964966340032, 1465430610413, 2225445981467, 3381732353551, 5134402814004, 7800657004308, 11848944328225, 17993247617573],
-[1, 2, 2, 3, 5, 5, 10, 18, 27, 32, 56, 93, 126, 195, 304, 461, 672, 1049, 1641, 2360, 3547, 5625, 8564, 12673, 19260, 29627, 45049, 67616, 103342, 157723, 237278, 361818, 551604, 836046, 1266910, 1923831, 2935305, 4447900, 6740508, 10270156, 15585551, 23659956, 35954137, 54604652, 82989965, 125904989, 191347740, 290881364, 441285372, 670605210, 1018750299, 1547074975, 2350467994, 3568665705, 5422769849, 8236285815, 12506003095, 19004355675, 28859234273, 43833995804, 66590002835, 101127683681, 153635945589, 233327907350, 354401392932, 538399795919, 817635222047, 1242051410939, 1886599092552, 2865384235558, 4352748966115, 6610899340896, 10041924816065, 15253315216084, 23166823214478],
-[1, 2, 2, 3, 4, 5, 10, 11, 18, 32, 52, 70, 97, 174, 259, 394, 583, 848, 1395, 2077, 3177, 4802, 7015, 11168, 16879, 25289, 38805, 57913, 89730, 135859, 203810, 314533, 471603, 719778, 1099016, 1653204, 2533856, 3824108, 5812997, 8882060, 13381483, 20436320, 31002290, 46973112, 71692459, 108396650, 165026994, 250800174, 379858202, 579084827, 877447857, 1333077127, 2027976019, 3073260503, 4677574083, 7098530110, 10776242837, 16393130419, 24859573765, 37796754601, 57411635433, 87130416829, 132495157925, 201073330815, 305502537878, 464202470332, 704556730921, 1070958999451, 1626092337116, 2469689089729, 3752929840962, 5697356841579, 8657131888398, 13148593115418, 19967369595523],
-[1, 2, 2, 3, 4, 6, 10, 14, 19, 29, 46, 67, 97, 161, 236, 345, 574, 841, 1254, 1924, 2865, 4535, 6759, 10131, 15724, 23367, 36118, 54744, 81917, 126623, 190042, 290461, 442589, 664167, 1021136, 1541835, 2340096, 3575884, 5387164, 8232756, 12485868, 18912040, 28882476, 43638268, 66447091, 101033140, 152958101, 233206759, 353308031, 536845906, 816838222, 1237403757, 1883783378, 2858758589, 4339257365, 6602213415, 10010713586, 15221195529, 23120531911, 35085618943, 53360882847, 80971906920, 123024183427, 186945943126, 283719450146, 431293253031, 654838156574, 994540238073, 1511381157333, 2294303243088, 3486317051552, 5295106524658, 8040819401684, 12218405189373, 18552289445243],
-[1, 2, 2, 3, 4, 4, 7, 9, 15, 24, 41, 48, 70, 128, 174, 290, 412, 607, 983, 1427, 2253, 3308, 4848, 7847, 11643, 17680, 26981, 40046, 62856, 93868, 141970, 219234, 325749, 502917, 762621, 1148200, 1765706, 2648010, 4052150, 6170339, 9284004, 14240929, 21500778, 32675689, 49858628, 75222333, 114889488, 174119627, 264020950, 402849218, 609069939, 927386714, 1409102233, 2135151507, 3253564584, 4929763369, 7492865420, 11394490894, 17269653310, 26284982776, 39886062249, 60561648051, 92108393733, 139695833719, 212404419889, 322579561639, 489641197311, 744520644805, 1129861689048, 1716770992518, 2608346631903, 3959254738023, 6018014051897, 9137148058919, 13878453527026],
-[1, 2, 2, 3, 4, 5, 7, 10, 20, 24, 42, 62, 94, 144, 207, 340, 485, 744, 1146, 1688, 2623, 3899, 5944, 9153, 13597, 20941, 31843, 47768, 73529, 110518, 168561, 257131, 386539, 593503, 896032, 1359457, 2077908, 3133123, 4783113, 7254650, 10993978, 16780615, 25367117, 38614853, 58709637, 88907659, 135495308, 205361604, 312005560, 474628764, 719220694, 1094668145, 1661435363, 2521907020, 3836481122, 5818309752, 8845439416, 13436273901, 20391603626, 31008525942, 47058828015, 71495818053, 108640831765, 164892743530, 250635981732, 380566428996, 577989718933, 878313492344, 1333384456366, 2026047085552, 3077255517645, 4673031026668, 7100590126778, 10781909335508, 16378976005420],
-[1, 2, 2, 2, 4, 5, 9, 14, 25, 30, 45, 86, 113, 165, 256, 393, 615, 857, 1353, 2122, 3026, 4812, 7247, 10812, 16866, 24898, 38799, 58412, 87209, 136602, 202952, 309524, 474149, 710527, 1094732, 1645551, 2501997, 3835380, 5750173, 8812177, 13366967, 20210122, 30921015, 46645595, 71121955, 108120145, 163441543, 249704882, 377875195, 574109924, 874247204, 1323060890, 2015904420, 3057883992, 4640755804, 7066057423, 10704853974, 16284252994, 24737395148, 37523222487, 57096521191, 86606804881, 131605062101, 200016267118, 303444996725, 461446754483, 700496152251, 1063819704196, 1617015368273, 2454055761686, 3729699693200, 5664556415844, 8600988774105, 13071919213944, 19845116163130],
-[1, 2, 2, 2, 4, 4, 7, 14, 28, 28, 39, 74, 107, 164, 226, 357, 579, 809, 1297, 1927, 2788, 4478, 6705, 10290, 15444, 22850, 36304, 54018, 81561, 126005, 187320, 289269, 437535, 661282, 1016324, 1518606, 2331001, 3550173, 5337832, 8186071, 12352643, 18803611, 28660572, 43225391, 66098237, 100072581, 151789280, 231646575, 350129393, 533332220, 809888824, 1227633947, 1871066537, 2833496915, 4308517637, 6551068253, 9928249504, 15114018373, 22928816422, 34822472915, 52956815075, 80307666048, 122134372760, 185450747075, 281511994287, 428070691057, 649557011157, 987098214936, 1499584845834, 2276285582086, 3460151739471, 5253002573181, 7979460132488, 12124405799741, 18406229850862],
-[1, 2, 2, 2, 4, 4, 7, 14, 28, 29, 40, 73, 109, 173, 225, 349, 593, 855, 1321, 1958, 2873, 4608, 6844, 10514, 16000, 23533, 37095, 55546, 84009, 129465, 192297, 297212, 450234, 678841, 1044378, 1563194, 2393004, 3646824, 5488409, 8414653, 12695939, 19311041, 29466504, 44437420, 67894281, 102873995, 155995923, 238038820, 359865299, 548044459, 832498023, 1261517618, 1922682439, 2912669925, 4427377226, 6732615201, 10203858524, 15531840686, 23566251249, 35784878446, 54425166531, 82537989031, 125508046812, 190600781276, 289312454286, 439921342079, 667583960128, 1014411830765, 1541185328102, 2339373060392, 3555933928895, 5398778273425, 8200404922260, 12460420346662, 18916602188306],
-[1, 2, 2, 2, 4, 4, 6, 11, 19, 20, 31, 57, 75, 103, 151, 258, 399, 546, 859, 1336, 1954, 3017, 4509, 6903, 10593, 15719, 24664, 36618, 55201, 85972, 128069, 196142, 297122, 449632, 691130, 1034874, 1582788, 2413656, 3630086, 5561083, 8411515, 12778510, 19476971, 29401742, 44918518, 68088228, 103181759, 157434353, 238170541, 362470880, 550777164, 834762986, 1271704798, 1927086026, 2928822956, 4454328685, 6751286336, 10273263312, 15592380665, 23674727268, 36003106925, 54609410519, 83026269027, 126099168294, 191403047497, 291021724568, 441685907195, 671071678406, 1019589040974, 1547723140184, 2352391859631, 3571770215002, 5425067840281, 8243308353999, 12515024992601],
-[1, 2, 2, 2, 4, 4, 6, 12, 22, 23, 37, 62, 86, 126, 186, 290, 440, 652, 1013, 1547, 2219, 3514, 5364, 7999, 12214, 18355, 28508, 42742, 64508, 99631, 149147, 227394, 346961, 524110, 800285, 1207541, 1840778, 2806348, 4229340, 6460141, 9801020, 14858221, 22643565, 34272401, 52195885, 79234715, 120111356, 183050944, 277355133, 421480048, 640878077, 971772883, 1478586142, 2243458302, 3407425821, 5181354768, 7858602628, 11948706147, 18146549608, 27547090255, 41879429493, 63561485732, 96582941609, 146724056927, 222734946415, 338542593226, 514010240952, 780751489158, 1186276378989, 1801074019410, 2736672282182, 4156252335912, 6312276582648, 9590646071365, 14563143809825],
-[1, 2, 2, 2, 4, 4, 7, 14, 27, 29, 38, 73, 114, 191, 253, 362, 587, 916, 1446, 2020, 2963, 4851, 7386, 11107, 16623, 24908, 38972, 58600, 88952, 136039, 202702, 312709, 475458, 717864, 1095970, 1648737, 2525984, 3839974, 5793008, 8859619, 13389492, 20364193, 31024264, 46912937, 71529096, 108383487, 164570701, 250780585, 379540032, 577600547, 877268468, 1330668506, 2025720619, 3070904774, 4667993549, 7094342516, 10760030187, 16369680468, 24842428082, 37729147097, 57354823050, 87026602106, 132297190355, 200897542668, 305031351530, 463663209698, 703785312107, 1069358085778, 1624455963336, 2466353549974, 3748109603895, 5691136848827, 8644914261446, 13133867486511, 19942218883609],
-[1, 2, 2, 2, 4, 5, 10, 16, 27, 32, 52, 95, 126, 188, 290, 437, 723, 1039, 1551, 2391, 3524, 5682, 8425, 12453, 19516, 28967, 44978, 67979, 101447, 157714, 235212, 360253, 551244, 823288, 1267733, 1912963, 2905936, 4447154, 6676086, 10225290, 15510383, 23459273, 35891201, 54153467, 82496507, 125477021, 189790676, 289738179, 438544155, 666312726, 1014635974, 1535745060, 2339295130, 3549337408, 5386205420, 8199642970, 12425770285, 18899132657, 28709377072, 43550728027, 66262630637, 100523713082, 152736301305, 232131524652, 352200297786, 535529584873, 813012101041, 1234685933932, 1876661034791, 2848269056424, 4328580109926, 6574355345659, 9982491020311, 15170857114618, 23032774202192],
-[1, 2, 2, 2, 4, 4, 8, 16, 31, 31, 45, 90, 128, 206, 280, 420, 709, 1003, 1595, 2343, 3397, 5628, 8228, 12513, 19155, 28117, 44781, 66451, 100396, 155983, 229625, 356946, 540911, 811756, 1254406, 1871037, 2872650, 4379561, 6563509, 10106935, 15221358, 23141826, 35376660, 53220812, 81474685, 123362707, 186930579, 285753676, 431203577, 657235391, 998723785, 1511859780, 2306867245, 3491693524, 5308621283, 8076740062, 12230290220, 18630670745, 28259287967, 42902036132, 65284439254, 98951671077, 150518833891, 228577840297, 346860556490, 527647887822, 800444810978, 1216419854800, 1848338694792, 2804862190124, 4264592049946, 6473797379492, 9833060974018, 14943497271781, 22681841047190],
-[1, 2, 2, 2, 4, 4, 8, 15, 29, 30, 42, 78, 114, 193, 258, 382, 631, 918, 1485, 2111, 3099, 5116, 7466, 11484, 17456, 25707, 40742, 60355, 92045, 141996, 209084, 325936, 492066, 741430, 1143245, 1704886, 2622584, 3984370, 5990252, 9220109, 13860487, 21116814, 32228228, 48535306, 74311625, 112367116, 170545275, 260431146, 393032502, 599513354, 910023691, 1378711960, 2103048380, 3182502435, 4841678509, 7360819190, 11150989668, 16987361776, 25756503270, 39122000982, 59510053260, 90208863686, 137242902295, 208348510694, 316265492252, 481026468754, 729681986191, 1109112152051, 1684888512839, 2557227974988, 3887984642202, 5901504106601, 8965266844694, 13622757048608, 20678376442426],
-[1, 2, 2, 2, 4, 4, 7, 14, 26, 27, 41, 75, 101, 163, 236, 363, 571, 840, 1321, 1929, 2855, 4594, 6857, 10359, 15736, 23629, 36889, 54935, 83459, 128497, 191416, 294845, 447048, 675408, 1034083, 1554076, 2380524, 3617050, 5452439, 8351145, 12618794, 19187423, 29228045, 44173332, 67416041, 102131745, 155021733, 236297683, 357496286, 544239641, 826607595, 1253523807, 1908733855, 2892854480, 4397955241, 6684714703, 10136378561, 15423380804, 23404413229, 35545363654, 54040779338, 81985588323, 124646356512, 189275742588, 287370692858, 436861054718, 663044377716, 1007488481450, 1530499152247, 2323579788315, 3531368350645, 5361791740086, 8144655946090, 12374251518808, 18787981808737],
-[1, 2, 2, 2, 4, 4, 7, 14, 25, 26, 40, 70, 97, 165, 232, 326, 512, 794, 1286, 1805, 2603, 4328, 6477, 9692, 14740, 22026, 34423, 51301, 78343, 120671, 177932, 275335, 419615, 631709, 966712, 1451259, 2227605, 3385274, 5093099, 7818673, 11798658, 17926881, 27354268, 41307501, 63058524, 95486038, 144924855, 221134536, 334207739, 508925957, 773240150, 1171916149, 1785382059, 2705177943, 4112761671, 6252428793, 9476969828, 14426013920, 21888381953, 33236549875, 50544889709, 76664562650, 116571242860, 177015241951, 268718203162, 408586695610, 620036109048, 942168783214, 1431397004953, 2172824044933, 3302613188577, 5014232678006, 7616549075503, 11572765602316, 17569442559921],
-[1, 2, 2, 2, 4, 5, 9, 14, 24, 30, 49, 86, 109, 170, 259, 382, 618, 912, 1377, 2094, 3073, 4950, 7446, 10904, 17036, 25415, 39305, 59569, 88861, 138120, 206164, 314889, 483089, 721785, 1108779, 1675926, 2544117, 3893978, 5848941, 8948687, 13589472, 20539438, 31416654, 47449148, 72215793, 109881954, 166208366, 253659322, 384146395, 583344618, 888480012, 1345056933, 2047977489, 3108556920, 4716416547, 7179640446, 10882315210, 16547207363, 25142059664, 38136850262, 58018739429, 88035255551, 133738129818, 203272573360, 308425445215, 468916080761, 711975467617, 1081150882365, 1643313467961, 2494265702270, 3790230708628, 5757138601073, 8741403896960, 13284374102149, 20169830455483],
-[1, 2, 2, 2, 4, 4, 7, 14, 26, 26, 41, 80, 99, 152, 243, 365, 537, 807, 1314, 1918, 2684, 4445, 6810, 9907, 15237, 22938, 35705, 53237, 80407, 125517, 185214, 283462, 435710, 654197, 1000205, 1505650, 2302773, 3514294, 5270140, 8084421, 12255018, 18541643, 28330677, 42808794, 65255359, 99022111, 149975518, 229046648, 346440871, 526568870, 801435339, 1213803431, 1848528184, 2803417782, 4257620734, 6478187936, 9816740207, 14935772632, 22681838890, 34416225836, 52352767075, 79423105940, 120706527349, 183391015769, 278301329270, 423170630002, 642343442364, 975666314966, 1482757812714, 2250587270495, 3420370730374, 5194307666511, 7888151101675, 11987079560147, 18198807936189],
-[1, 2, 2, 2, 4, 4, 7, 14, 27, 27, 40, 72, 104, 170, 225, 330, 550, 806, 1304, 1889, 2687, 4504, 6662, 10074, 15276, 22500, 36151, 53377, 80375, 125225, 184423, 286575, 433700, 651767, 1007162, 1499936, 2306179, 3516155, 5265279, 8110358, 12214368, 18575683, 28385620, 42697763, 65404932, 98983900, 149986299, 229345071, 346010812, 527441237, 801381557, 1213226552, 1851422356, 2801624759, 4260179129, 6481638667, 9813867511, 14951308696, 22676522919, 34428276245, 52389824215, 79401945731, 120793223243, 183424680407, 278340486534, 423435444746, 642318673981, 976158685727, 1483236215492, 2250793520324, 3422296549088, 5194941995925, 7890795270582, 11991850825667, 18201278627952],
-[1, 2, 2, 2, 4, 4, 6, 12, 23, 23, 32, 60, 84, 136, 190, 302, 467, 667, 1096, 1607, 2371, 3783, 5582, 8619, 12936, 19295, 30496, 45109, 68756, 105522, 157067, 243624, 366243, 554955, 852036, 1274794, 1960320, 2970887, 4480783, 6874766, 10354406, 15792750, 24028557, 36272022, 55485234, 83895522, 127468940, 194298367, 293656059, 447764077, 679214585, 1030317320, 1569851330, 2376728263, 3616617697, 5494779949, 8330820690, 12683418034, 19232598358, 29224486210, 44428086591, 67378889663, 102491126247, 155569158509, 236232484551, 359163696008, 544951894198, 828325082243, 1258072677847, 1909972342032, 2903297399709, 4407108764985, 6695677993806, 10172293315438, 15443498025724],
-[1, 2, 2, 2, 4, 4, 6, 11, 22, 22, 35, 52, 74, 119, 149, 239, 400, 601, 873, 1310, 1971, 3078, 4621, 7020, 10723, 15824, 24782, 37523, 56216, 86031, 129333, 199146, 301470, 454055, 696974, 1049791, 1599387, 2438459, 3679628, 5617841, 8502825, 12924183, 19704180, 29762440, 45363692, 68875629, 104427967, 159105107, 240970207, 366524334, 556968980, 844419478, 1285637883, 1949735100, 2961672624, 4503471179, 6830328804, 10387734132, 15769774100, 23944049766, 36405509784, 55239658136, 83955277053, 127528332791, 193595421405, 294272895743, 446735886480, 678665509336, 1031096388920, 1565388649966, 2378830821000, 3612440542164, 5486609128881, 8336202399481, 12657714695978],
-[1, 1, 2, 3, 6, 8, 13, 20, 30, 49, 69, 104, 155, 234, 362, 542, 822, 1256, 1897, 2920, 4412, 6598, 10145, 15377, 23454, 35567, 53642, 82265, 124377, 188906, 287779, 434938, 663320, 1006372, 1527281, 2325711, 3520286, 5358670, 8142469, 12343806, 18789889, 28494036, 43302291, 65824178, 99831844, 151859408, 230482031, 350003893, 532153530, 807430307, 1227265150, 1863913206, 2829917324, 4301641415, 6529580272, 9920557936, 15071369156, 22881463143, 34772619166, 52801617742, 80201083343, 121850279987, 185016236348, 281105379161, 426940980300, 648413661114, 985124790267, 1495995768567, 2272569652538, 3451960131401, 5242555933544, 7964387687132, 12095887909688, 18373165704478, 27909425652516],
-[1, 1, 2, 3, 6, 8, 13, 18, 28, 55, 70, 105, 166, 243, 398, 553, 845, 1355, 1960, 3107, 4619, 6863, 10902, 15978, 24690, 37548, 55810, 87541, 130099, 198217, 304695, 453919, 701555, 1057263, 1600383, 2459380, 3684573, 5646931, 8574434, 12936406, 19833345, 29898985, 45540765, 69356729, 104708860, 160034339, 242235545, 367776285, 560616657, 847711229, 1291800483, 1960590168, 2973123812, 4529540992, 6860854371, 10434819193, 15858125614, 24043285371, 36596530879, 55511301898, 84329651210, 128212784785, 194462608488, 295735657295, 448991723701, 681719415694, 1036444014233, 1572771458670, 2390246103805, 3630746531015, 5511957299333, 8378072641130, 12718990544129, 19321972790462, 29355833404817],
-[1, 1, 2, 2, 3, 6, 12, 12, 17, 32, 40, 67, 105, 162, 226, 345, 579, 809, 1194, 1916, 2898, 4359, 6571, 10071, 15516, 22829, 35272, 54233, 80593, 123680, 187499, 285532, 434105, 653133, 1003926, 1517320, 2295086, 3514032, 5307054, 8077785, 12269738, 18600187, 28376452, 42904259, 65263060, 99346288, 150372141, 229010297, 347462726, 527598661, 802597595, 1216472916, 1850952044, 2810430185, 4264287294, 6487640790, 9842126130, 14956134653, 22724255661, 34487061156, 52436280711, 79592884910, 120899115691, 183737284969, 278875165287, 423824136800, 643656259074, 977451460594, 1485340931152, 2255085850439, 3426199608764, 5204370529754, 7902815713813, 12007941795404, 18234860584965],
-[1, 1, 2, 2, 3, 6, 10, 11, 18, 34, 42, 67, 104, 150, 241, 355, 561, 827, 1193, 1992, 2957, 4333, 6745, 10065, 15742, 23569, 35308, 55067, 81477, 125450, 192088, 286428, 441174, 664140, 1013265, 1547902, 2318681, 3563000, 5394184, 8161673, 12492482, 18832136, 28727875, 43633084, 66025568, 100889592, 152492730, 231894246, 353059738, 534236785, 814244719, 1234543144, 1874418383, 2853407372, 4322151048, 6577793619, 9988224692, 15152634269, 23059147494, 34970723380, 53152244996, 80766005945, 122537273744, 186363176347, 282851126942, 429626089706, 652999587829, 990970421422, 1506250735774, 2287393264733, 3473409696892, 5278898669239, 8013654599282, 12175866732272, 18495474537856],
-[1, 1, 2, 2, 3, 6, 12, 12, 20, 34, 43, 67, 94, 148, 244, 348, 527, 824, 1169, 1862, 2804, 4184, 6507, 9515, 15027, 22653, 33604, 52487, 78072, 119867, 182709, 273283, 422531, 633913, 964509, 1478868, 2215725, 3397799, 5146973, 7792754, 11927404, 17962726, 27418524, 41674729, 62990001, 96267897, 145587226, 221338850, 336952245, 509810477, 777257281, 1178384207, 1788625991, 2723704295, 4125391500, 6277462874, 9533397818, 14461995745, 22009430010, 33376033628, 50727939822, 77091920939, 116948751355, 177868163699, 269970748514, 410038829044, 623254642985, 945798850239, 1437615146331, 2183206804594, 3315030451469, 5038426167588, 7648527869656, 11620857401909, 17652897933532],
-[1, 1, 2, 2, 3, 6, 12, 12, 16, 30, 42, 69, 96, 154, 243, 346, 547, 802, 1219, 1912, 2791, 4377, 6642, 9846, 15305, 22782, 35091, 53631, 79675, 123398, 185777, 282213, 432601, 647440, 994115, 1506228, 2278090, 3490638, 5253681, 8016114, 12195044, 18425305, 28160556, 42579694, 64730898, 98598206, 149098289, 227336180, 344744029, 523107060, 796783962, 1206676640, 1836161106, 2788856352, 4229690301, 6438425388, 9763093110, 14837121431, 22551606778, 34207020410, 52028561577, 78970836374, 119933385946, 182316655679, 276647763206, 420508177945, 638630990975, 969639726010, 1473809693464, 2237258234683, 3399143942117, 5163756626299, 7840080562081, 11914044088602, 18091239231025],
-[1, 1, 2, 2, 3, 6, 11, 12, 20, 36, 46, 75, 113, 157, 248, 378, 579, 885, 1264, 2047, 3074, 4521, 7196, 10500, 16119, 24686, 37011, 57687, 84977, 130117, 201091, 298887, 460397, 694760, 1053019, 1616415, 2423097, 3715418, 5635508, 8499294, 13043665, 19672316, 29936906, 45578548, 68857117, 105246351, 159252205, 241800442, 368618082, 557355513, 849297935, 1289152144, 1954969364, 2977858738, 4510787605, 6861323262, 10426770442, 15807856655, 24061537952, 36499177530, 55446669758, 84297272330, 127859996279, 194445135253, 295204568105, 448229158465, 681459389378, 1034089426115, 1571561306728, 2387187168736, 3624116722724, 5508507307470, 8362634207283, 12704135793489, 19301239730172],
-[1, 1, 2, 2, 3, 6, 12, 12, 20, 35, 42, 72, 107, 153, 237, 373, 584, 823, 1205, 1999, 3033, 4392, 6765, 10266, 15873, 23677, 35886, 55642, 82366, 126367, 193810, 291218, 444292, 670558, 1025527, 1561076, 2347357, 3596320, 5449867, 8254572, 12599361, 19056966, 29026010, 44039789, 66776647, 101865089, 154143334, 234329860, 356475606, 540220718, 822297056, 1247314440, 1894706533, 2881483359, 4368578556, 6645265329, 10090556836, 15315050834, 23289877564, 35340196083, 53706848318, 81586575450, 123839156256, 188265632339, 285795269491, 434125995016, 659665449782, 1001409321622, 1521775626924, 2311035872807, 3509837322902, 5333068876722, 8097370607204, 12302175751252, 18686358546104],
-[1, 1, 2, 2, 3, 6, 12, 12, 19, 33, 41, 63, 99, 158, 234, 353, 544, 818, 1211, 1876, 2888, 4329, 6553, 9955, 15234, 22959, 34802, 53386, 80438, 122175, 186373, 282688, 429343, 650071, 990580, 1506819, 2279561, 3470481, 5272164, 7999888, 12163246, 18456427, 28063226, 42604267, 64666184, 98373923, 149266916, 226726663, 344560392, 523061384, 794946004, 1206920648, 1833304593, 2785849336, 4228899615, 6426001160, 9760422235, 14821604118, 22520209937, 34197026082, 51950993330, 78912697531, 119835694919, 182070694644, 276507836143, 419992259515, 638017828222, 968942454736, 1471945162302, 2235658971813, 3395656721415, 5158326097524, 7834188897644, 11900306664896, 18075912577182],
-[1, 1, 2, 2, 3, 6, 11, 12, 19, 28, 39, 67, 94, 144, 220, 328, 516, 752, 1151, 1765, 2601, 4072, 6183, 9284, 14138, 21234, 32879, 49789, 74557, 114657, 173065, 263765, 401548, 605090, 925993, 1400566, 2128234, 3246748, 4899732, 7471594, 11344595, 17200498, 26211384, 39671973, 60371240, 91757465, 139051212, 211767404, 321108367, 487793275, 741804097, 1124827491, 1711083186, 2597279440, 3943320316, 5996737857, 9097630148, 13827916617, 21004566134, 31884214559, 48470846886, 73575827695, 111777906103, 169831049259, 257812757131, 391812575145, 594977068397, 903649087664, 1373033820409, 2084707330536, 3167392128804, 4810814268570, 7306010724525, 11100372024339, 16856606646147],
-[1, 1, 2, 2, 3, 5, 10, 10, 15, 24, 29, 48, 70, 112, 167, 260, 392, 563, 863, 1334, 2035, 3040, 4636, 7048, 10745, 16190, 24607, 37564, 56637, 86568, 131284, 199156, 302898, 458579, 699393, 1060621, 1608518, 2449379, 3713019, 5644897, 8577088, 13013024, 19792177, 30026909, 45625914, 69358968, 105212819, 159953418, 242895423, 368847936, 560607760, 850877485, 1293058220, 1964020026, 2982012153, 4532025925, 6880912499, 10452657335, 15879638124, 24112129220, 36636733407, 55637742487, 84506788021, 128387032165, 194960075407, 296181506189, 449865073031, 683240715463, 1037966389977, 1576358323928, 2394536102061, 3637246691904, 5524111471012, 8391716143349, 12745477739451],
-[1, 1, 2, 2, 3, 6, 12, 12, 19, 34, 41, 65, 98, 151, 236, 342, 535, 801, 1168, 1884, 2794, 4158, 6419, 9631, 15047, 22307, 33775, 52574, 77842, 119623, 182069, 274046, 421041, 632080, 967019, 1472850, 2213002, 3397438, 5136083, 7790590, 11892550, 17952332, 27409478, 41550648, 62972745, 96130589, 145335521, 221182774, 336282494, 509423509, 776147554, 1176287523, 1787530509, 2718901051, 4120140908, 6270625599, 9517658043, 14447873701, 21975902195, 33331295623, 50673380057, 76967494579, 116817192908, 177632645292, 269581997551, 409571963112, 622339280856, 944625609517, 1435778270869, 2180071871949, 3311113253114, 5031420195377, 7638434208500, 11606355180322, 17628147996660],
-[1, 1, 2, 2, 3, 6, 11, 12, 20, 30, 40, 64, 90, 147, 226, 322, 511, 779, 1166, 1812, 2632, 4063, 6264, 9389, 14517, 21445, 33007, 50705, 75538, 116366, 175276, 266228, 408119, 612719, 938509, 1421499, 2149671, 3294087, 4966784, 7565123, 11507726, 17399737, 26577830, 40217224, 61108871, 93061573, 140793636, 214561707, 325536090, 493909406, 752019857, 1139403882, 1733314239, 2632999833, 3993551645, 6077303820, 9218291987, 14006877336, 21289556119, 32297620146, 49113866324, 74558049868, 113227887894, 172112525531, 261196637114, 396969077281, 602924254314, 915446096425, 1391322884974, 2112240789389, 3208993144038, 4874942944271, 7401878097457, 11247411868483, 17079940062916],
-[1, 1, 2, 2, 3, 6, 12, 12, 22, 36, 41, 61, 97, 152, 234, 358, 538, 809, 1174, 1904, 2950, 4198, 6417, 9893, 15313, 22905, 34205, 53212, 80006, 121093, 185913, 280083, 426000, 645941, 983561, 1501318, 2258327, 3443944, 5247411, 7934536, 12091293, 18325490, 27857240, 42356014, 64160720, 97776230, 148277764, 224975405, 342494045, 519335110, 789559054, 1199019172, 1819872539, 2768290489, 4199360662, 6381262669, 9697653631, 14715340073, 22371062167, 33965760185, 51588404901, 78394980435, 118995777682, 180843833995, 274655450786, 417059477463, 633778559696, 962282469066, 1461892061233, 2220677796103, 3372159727798, 5123665573612, 7780799841359, 11818712954380, 17954534186807],
-[1, 1, 2, 2, 3, 6, 12, 12, 21, 38, 42, 56, 104, 170, 227, 316, 547, 884, 1195, 1876, 2895, 4296, 6624, 9983, 15520, 22896, 34549, 54641, 80923, 122251, 187575, 283747, 434316, 652059, 995675, 1522341, 2281966, 3498435, 5313426, 8031369, 12254987, 18533191, 28273433, 42901573, 64912456, 99152745, 150083597, 228004338, 347029696, 525679736, 800396845, 1213969299, 1843622986, 2805588653, 4251176987, 6466524640, 9822754300, 14904260810, 22669474745, 34393599443, 52268286554, 79417246783, 120513837769, 183241777612, 278170784404, 422483685040, 642078878528, 974609370228, 1481111673419, 2249343675756, 3415800555220, 5190808281744, 7880862819148, 11973047667750, 18187649572255],
-[1, 1, 2, 2, 3, 6, 11, 11, 18, 32, 42, 57, 89, 150, 215, 311, 499, 783, 1121, 1729, 2625, 3962, 6142, 9142, 14034, 21118, 32049, 49642, 73862, 112396, 171987, 259903, 397180, 598681, 911563, 1391172, 2096709, 3203817, 4859523, 7360591, 11226918, 16991080, 25874157, 39286988, 59526051, 90748262, 137496672, 208924945, 317757948, 481653885, 732959772, 1112246947, 1689025184, 2568745830, 3895475471, 5923130386, 8996392212, 13654884792, 20761662132, 31508918770, 47876633704, 72738861558, 110411683940, 167826507017, 254811079535, 387025387992, 588089244735, 892824318577, 1356633086340, 2060401435487, 3129083273185, 4754415215260, 7219305838732, 10967314911674, 16659377572283],
-[1, 1, 2, 2, 3, 6, 12, 12, 20, 34, 42, 64, 92, 142, 223, 328, 520, 782, 1081, 1782, 2718, 3966, 6123, 9095, 14420, 21456, 32022, 50259, 73965, 113717, 174519, 261018, 401250, 601561, 920760, 1409300, 2105016, 3235485, 4901578, 7415724, 11349705, 17101772, 26113965, 39636258, 59948257, 91699610, 138537246, 210643191, 320736964, 485301424, 739890558, 1121348498, 1702774597, 2592698418, 3925874596, 5975977546, 9074282133, 13765035194, 20949633240, 31766872477, 48289732263, 73375356746, 111311530971, 169315525534, 256954678864, 390295940314, 593244990562, 900226930838, 1368424013657, 2077987015004, 3155417654464, 4795866048148, 7279908713269, 11061452101791, 16802613462042],
-[1, 1, 2, 2, 4, 8, 16, 16, 23, 46, 64, 103, 137, 211, 371, 509, 816, 1204, 1731, 2909, 4211, 6426, 9809, 14336, 23197, 34156, 51277, 80157, 117753, 183791, 277434, 415629, 645695, 958771, 1475169, 2251003, 3363491, 5193421, 7808674, 11880613, 18176350, 27281374, 41855225, 63330929, 95902965, 146790242, 221215120, 337455120, 512755177, 775715517, 1184831821, 1791791112, 2724888794, 4147294930, 6275948301, 9566054593, 14505011824, 22020116311, 33520526147, 50782405476, 77274210950, 117340006492, 178025250310, 270901775454, 410844241480, 624430448749, 948868800783, 1439637058615, 2189357057338, 3323035471536, 5047408322857, 7671473883433, 11642268754866, 17695663980630, 26872958957653],
-[1, 1, 2, 2, 4, 8, 15, 16, 25, 46, 63, 101, 143, 212, 351, 501, 795, 1174, 1698, 2813, 4117, 6225, 9565, 14091, 22350, 33188, 50110, 77904, 114859, 178160, 270318, 405408, 626097, 935638, 1434617, 2187361, 3278918, 5046997, 7608027, 11557175, 17668304, 26594135, 40686343, 61635600, 93379875, 142719005, 215442170, 328247440, 498939567, 755282154, 1152143845, 1744462579, 2651650743, 4034518278, 6109918270, 9305569671, 14117508492, 21430490034, 32610151197, 49433334436, 75183617382, 114184569091, 173270522090, 263564799746, 399866885237, 607618470397, 923304043182, 1401151242857, 2130216126502, 3233955064163, 4911869459732, 7464652603696, 11330538688561, 17218903605383, 26151054115390],
-[1, 1, 2, 2, 4, 8, 15, 15, 24, 48, 63, 96, 142, 216, 355, 507, 802, 1175, 1692, 2850, 4205, 6210, 9565, 14292, 22620, 33415, 50265, 78618, 115974, 179109, 272842, 408678, 629939, 943390, 1446068, 2205863, 3301610, 5081996, 7676765, 11641579, 17799881, 26809604, 40986585, 62127844, 94082946, 143826154, 217164544, 330628207, 502905755, 761200962, 1160793577, 1758205526, 2671745951, 4065995660, 6157370271, 9375916591, 14228829720, 21594173236, 32860624704, 49819941396, 75756721591, 115072767710, 174604419160, 265589962348, 402983966031, 612264403208, 930454430228, 1411996008696, 2146556618643, 3259066763931, 4949641920920, 7522276589835, 11418247735250, 17351186601880, 26353672003263],
-[1, 1, 2, 2, 4, 7, 14, 14, 19, 38, 52, 82, 122, 195, 293, 411, 694, 1017, 1455, 2351, 3510, 5394, 8066, 12091, 19119, 28043, 42842, 66417, 98353, 151686, 229009, 347851, 533513, 795975, 1225897, 1860780, 2800100, 4301071, 6481811, 9874805, 15028470, 22688842, 34726826, 52480495, 79693511, 121594890, 183714275, 280048070, 424948362, 644481320, 982129923, 1486697702, 2262326900, 3437990231, 5210664789, 7934288252, 12032418143, 18280567822, 27794056711, 42148129704, 64115739777, 97323495794, 147768209439, 224692522762, 340903778188, 518153747500, 787040980206, 1194782730308, 1816233098800, 2756951181388, 4188491262974, 6363660883603, 9660756550960, 14681537808421, 22294245516719],
-[1, 1, 2, 2, 4, 8, 16, 16, 26, 52, 64, 102, 158, 226, 354, 518, 858, 1272, 1746, 2942, 4436, 6506, 10082, 14915, 23571, 35076, 52577, 82825, 121343, 186638, 286674, 428002, 660170, 988148, 1511693, 2315043, 3455513, 5322340, 8051409, 12173604, 18655871, 28087233, 42914514, 65122648, 98453640, 150709711, 227543418, 346094442, 527071320, 797042339, 1215804615, 1842219586, 2797385990, 4260300018, 6448624262, 9819173755, 14908673304, 22612466913, 34423461211, 52185640141, 79337653918, 120556407265, 182861756277, 278196099975, 422139189950, 641216820994, 974715639049, 1478915488598, 2248307358029, 3413970424768, 5183973844593, 7879596582930, 11959931978162, 18173417413074, 27605791238877],
-[1, 1, 2, 2, 4, 8, 15, 15, 20, 40, 63, 100, 132, 202, 323, 463, 791, 1136, 1595, 2639, 3972, 6120, 9004, 13396, 21623, 31654, 48135, 74523, 110083, 170904, 257446, 390743, 600285, 891893, 1378176, 2093664, 3144009, 4835347, 7274281, 11099455, 16900479, 25468678, 39042881, 58949648, 89518663, 136708075, 206323945, 314746044, 477433181, 723923893, 1104067773, 1669933396, 2541995601, 3863481912, 5853214067, 8917214756, 13517903084, 20539078707, 31234224572, 47348095648, 72050099329, 109351504364, 166015872070, 252495593886, 382997555589, 582223255723, 884343765548, 1342337198319, 2040895223530, 3097540964907, 4706156214976, 7150530664450, 10854100858536, 16496891971822, 25049387708592],
-[1, 1, 2, 2, 4, 8, 16, 16, 24, 48, 64, 98, 142, 219, 359, 496, 811, 1221, 1713, 2844, 4219, 6354, 9704, 14284, 22905, 33867, 50749, 79563, 117104, 181159, 275369, 412862, 638536, 951537, 1460448, 2232289, 3335431, 5138770, 7753178, 11767767, 18001089, 27069593, 41450587, 62798094, 95038454, 145422179, 219427531, 334232317, 508279846, 769068683, 1173686193, 1776622065, 2700129090, 4110277128, 6221748974, 9477536786, 14380015991, 21823604678, 33216872173, 50343291880, 76572262346, 116306444393, 176451282522, 268455231858, 407259651441, 618813998871, 940434673893, 1426966269511, 2169641544538, 3293784130931, 5002404815565, 7603018915835, 11539630861779, 17537048984472, 26635162472618],
-[1, 1, 2, 2, 4, 8, 15, 16, 26, 48, 61, 95, 139, 220, 365, 493, 794, 1192, 1736, 2896, 4117, 6212, 9716, 14392, 22865, 33296, 50491, 79457, 116351, 180713, 273374, 410036, 636110, 947007, 1455652, 2216537, 3314303, 5123371, 7708333, 11708725, 17909834, 26915822, 41271341, 62443969, 94577934, 144722860, 218134279, 332708905, 505678393, 765012106, 1167972631, 1766910113, 2687138104, 4089264255, 6188636252, 9431893209, 14303480681, 21713257245, 33050978574, 50078160906, 76193262480, 115700993875, 175549556288, 267116035692, 405124850249, 615708737033, 935625890426, 1419608460797, 2158753338938, 3276728153264, 4977046610476, 7564343473200, 11480107420184, 17448644790793, 26498260323269],
-[1, 1, 2, 2, 4, 8, 16, 16, 24, 48, 64, 95, 143, 222, 356, 497, 792, 1193, 1696, 2782, 4171, 6242, 9540, 14162, 22400, 33340, 50009, 78057, 115613, 177818, 271060, 406946, 626545, 938382, 1436093, 2194179, 3287099, 5048473, 7635817, 11579246, 17694431, 26669175, 40746820, 61798524, 93558488, 142966251, 216055744, 328746074, 500044318, 757086853, 1154128465, 1748717919, 2656672895, 4043066188, 6124155540, 9322137840, 14150529141, 21475057850, 32674449634, 49547137665, 75329811565, 114434394246, 173640409072, 264088985020, 400767970009, 608842887592, 925251215179, 1404203584756, 2134504490874, 3241012875988, 4922084547243, 7480201052785, 11355114145281, 17254081319228, 26207118921952],
-[1, 1, 2, 2, 4, 8, 15, 16, 27, 50, 60, 95, 152, 226, 348, 504, 817, 1217, 1733, 2851, 4253, 6292, 9747, 14548, 22737, 33891, 51089, 79771, 117710, 180931, 276847, 414740, 637703, 957855, 1464429, 2235274, 3350006, 5147817, 7789213, 11794892, 18035012, 27202987, 41522194, 62999153, 95380747, 145734052, 220262236, 335050466, 509849652, 771800530, 1176242404, 1782935263, 2708145184, 4121377186, 6243157934, 9502255966, 14426381032, 21890463593, 33307140487, 50512519555, 76785396857, 116656462284, 177011124749, 269203414154, 408557501104, 620627844004, 943211426273, 1431469160963, 2175823985043, 3303975330179, 5017505601911, 7625228834549, 11575569935746, 17588388173270, 26715847913076],
-[1, 1, 2, 2, 4, 8, 16, 16, 26, 52, 64, 103, 161, 226, 348, 521, 857, 1264, 1742, 2928, 4436, 6455, 10092, 14960, 23368, 34931, 52536, 82681, 121017, 185644, 286341, 427166, 657339, 986836, 1506768, 2307558, 3447822, 5305660, 8035092, 12132666, 18599653, 28033514, 42771520, 64952560, 98193722, 150255632, 226995019, 345040775, 525686394, 794966561, 1212061771, 1837651533, 2789505988, 4248238489, 6431824377, 9790530258, 14869612412, 22549815197, 34325600044, 52049598782, 79110721829, 120228516440, 182367183189, 277408588726, 421011968171, 639422108304, 972033749548, 1474922546631, 2241976364128, 3404739760799, 5169675963354, 7857734993905, 11927535072106, 18122768713820, 27530385742223],
-[1, 1, 2, 3, 6, 8, 13, 19, 29, 51, 63, 93, 160, 230, 373, 544, 803, 1283, 1847, 2974, 4461, 6409, 10318, 15377, 23507, 35817, 52905, 83256, 124257, 187921, 290936, 431816, 665441, 1009700, 1521887, 2341633, 3506475, 5365623, 8179739, 12298817, 18869287, 28491076, 43287380, 66064311, 99649574, 152252791, 230728624, 349722122, 533816591, 807026667, 1228722486, 1867098231, 2828751464, 4311166947, 6531703749, 9927313991, 15098632085, 22880359660, 34826659582, 52849439360, 80239710355, 122045038184, 185086797201, 281428769408, 427420815465, 648735649980, 986486768641, 1497028733467, 2274632889777, 3456045200118, 5245795102329, 7973747943489, 12106465238950, 18388238658904, 27941609349160],
-[1, 1, 2, 3, 6, 8, 14, 20, 30, 53, 67, 97, 161, 241, 395, 567, 840, 1329, 1919, 3091, 4652, 6733, 10661, 15933, 24629, 37314, 55049, 86428, 129392, 196192, 302102, 449898, 693160, 1049275, 1586174, 2436877, 3650771, 5585631, 8506184, 12818268, 19636072, 29643275, 45089450, 68733927, 103761918, 158487804, 240115757, 364205727, 555442925, 840187161, 1279330008, 1942915030, 2945323421, 4487209694, 6799072051, 10335553749, 15713515136, 23821253161, 36252107504, 55008258445, 83538893200, 127028133296, 192675485598, 292963897628, 444888015263, 675372891437, 1026822031574, 1558338525373, 2367920419324, 3597331575065, 5460928774348, 8300185563200, 12602008762232, 19142192943163, 29084657025364],
-[1, 1, 2, 3, 5, 6, 12, 19, 23, 37, 61, 82, 118, 188, 311, 455, 625, 1039, 1589, 2318, 3602, 5400, 8315, 12453, 18902, 29525, 43435, 66322, 102306, 153311, 234583, 353745, 539510, 823838, 1236141, 1897331, 2876157, 4343955, 6645653, 10045659, 15300648, 23232012, 35170224, 53720990, 81269653, 123481829, 188032327, 284662683, 433496694, 657702604, 998515446, 1519540942, 2302521954, 3502888606, 5320790897, 8071574708, 12279607271, 18629975499, 28309016014, 43017656331, 65274763187, 99253305312, 150669390356, 228829731807, 347797296643, 527878906280, 802223911282, 1218384044166, 1850117963167, 2811607571276, 4268612672648, 6485110892904, 9851430996687, 14958791380570, 22729273396017],
-[1, 1, 2, 2, 3, 6, 12, 12, 21, 37, 43, 66, 109, 156, 231, 363, 563, 815, 1161, 1958, 3009, 4220, 6622, 10049, 15450, 23276, 34762, 54363, 80630, 122817, 190213, 283043, 432615, 656686, 997900, 1526430, 2287011, 3503483, 5332640, 8034897, 12301550, 18600955, 28270591, 43037331, 65082438, 99382256, 150487456, 228310923, 348133936, 526867127, 801851635, 1217763381, 1846984753, 2812262383, 4262076377, 6479888214, 9849608552, 14934266040, 22722679410, 34484675295, 52373449600, 79619369067, 120792980988, 183651319763, 278875747707, 423398663038, 643652446329, 976919976961, 1484385435923, 2254972272703, 3423520079999, 5202989659275, 7899853505604, 12000026748614, 18231853295766],
-[1, 1, 2, 2, 3, 6, 11, 12, 22, 40, 46, 68, 114, 164, 257, 375, 591, 885, 1226, 2097, 3158, 4467, 7019, 10637, 16602, 24624, 36683, 58100, 85680, 130664, 201788, 300220, 461834, 696235, 1060907, 1625449, 2424860, 3730006, 5666366, 8539694, 13088142, 19738093, 30089742, 45753168, 69117261, 105748834, 159850646, 242754056, 370161104, 559712975, 852955516, 1293904511, 1963207990, 2990793842, 4528284028, 6890223792, 10469340362, 15872411590, 24161921034, 36645420109, 55680971124, 84640528683, 128374628355, 195265907079, 296401713647, 450073439179, 684269247496, 1038296984941, 1578108875388, 2396898047219, 3639009380500, 5531336271098, 8396614160520, 12756693475403, 19380487272636],
-[1, 1, 2, 2, 3, 6, 12, 12, 17, 29, 43, 72, 91, 142, 240, 344, 545, 799, 1171, 1893, 2799, 4330, 6456, 9540, 15270, 22632, 34278, 52699, 78393, 121812, 183019, 276998, 426489, 635874, 978273, 1486647, 2237846, 3434907, 5169990, 7889006, 12015310, 18107681, 27724737, 41922402, 63649889, 97110515, 146686675, 223697414, 339365606, 514564650, 784546592, 1187299993, 1806510050, 2745715573, 4161252898, 6337074802, 9608542219, 14598543895, 22199037859, 33657647505, 51204517995, 77726343602, 118005732795, 179448240842, 272242430766, 413813480368, 628555306145, 954129321721, 1450517581090, 2201735708354, 3344951923550, 5082209529854, 7715086204509, 11725061752218, 17804494828406],
-[1, 1, 2, 2, 3, 6, 10, 10, 16, 28, 36, 53, 80, 130, 201, 297, 443, 644, 976, 1573, 2353, 3446, 5293, 8087, 12506, 18595, 28064, 43325, 64966, 99533, 151375, 227961, 348317, 526990, 804309, 1221698, 1842099, 2817630, 4272014, 6480138, 9870020, 14938385, 22753418, 34530929, 52387789, 79798209, 120857729, 183769786, 279375107, 423664457, 644555647, 977843582, 1485645882, 2258502332, 3425803261, 5209389540, 7909648451, 12009197693, 18256543533, 27708019077, 42105952841, 63956323294, 97100763773, 147584354120, 224062286876, 340369906316, 517119644835, 785144725690, 1193025080454, 1811783472980, 2751800101118, 4180800991382, 6348458511318, 9644756497909, 14649351694667],
-[1, 1, 2, 2, 3, 6, 12, 14, 21, 36, 49, 67, 100, 173, 282, 375, 572, 899, 1317, 2097, 3117, 4689, 7153, 10760, 17001, 25099, 37486, 58555, 87755, 134125, 203349, 306953, 471922, 707714, 1082049, 1651776, 2479050, 3797925, 5754734, 8735692, 13315483, 20094833, 30696631, 46562105, 70520858, 107627974, 162834237, 247695838, 376575567, 570654075, 869288049, 1317443097, 2001747503, 3045327992, 4615227495, 7021994127, 10659959114, 16182459611, 24611824577, 37332185500, 56752249435, 86205744696, 130835554266, 198938188960, 301948445381, 458713004347, 697002553180, 1058005645178, 1608038869967, 2441716157194, 3708409685485, 5635120660527, 8555226219941, 12998830610815, 19743593509189],
-[1, 1, 2, 2, 3, 6, 11, 13, 21, 31, 41, 64, 94, 144, 233, 349, 532, 788, 1164, 1861, 2775, 4142, 6370, 9655, 14941, 22227, 33586, 51961, 77874, 118890, 180716, 273024, 417933, 630337, 961075, 1462866, 2205866, 3372963, 5111196, 7754560, 11814903, 17875395, 27240340, 41333702, 62680276, 95509605, 144662050, 219964560, 334359309, 506997185, 771538259, 1170332679, 1778020457, 2703308736, 4099983472, 6234943811, 9466819065, 14373207790, 21851436429, 33160867292, 50395357658, 76549249631, 116212383419, 176641109753, 268168760713, 407373783468, 618929516934, 939690073640, 1427906331551, 2168446529977, 3293479557228, 5003915460406, 7598145311429, 11543451876839, 17533280012445],
-[1, 1, 2, 2, 3, 6, 12, 12, 19, 34, 41, 56, 95, 159, 219, 303, 505, 774, 1089, 1735, 2709, 3961, 5973, 9195, 14306, 21118, 31729, 49682, 74444, 112366, 171928, 260775, 397031, 598299, 914164, 1394466, 2096266, 3202287, 4871122, 7375810, 11224725, 17009062, 25913217, 39321379, 59582196, 90846706, 137682480, 209081219, 318028046, 482368975, 733660736, 1113213835, 1690910140, 2571510018, 3899801698, 5928665415, 9006070609, 13670390642, 20780932144, 31543520056, 47928517656, 72811719225, 110529531335, 167997965277, 255087736126, 387430202343, 588680065570, 893801114048, 1358033474041, 2062540113709, 3132422030307, 4759312590879, 7226981911085, 10978709494077, 16676764860172],
-[1, 1, 2, 2, 3, 6, 12, 12, 19, 34, 41, 63, 101, 158, 225, 336, 547, 807, 1158, 1871, 2845, 4162, 6375, 9762, 15066, 22286, 33847, 52698, 78537, 119480, 182480, 275955, 420861, 635019, 970389, 1475830, 2222799, 3402282, 5159918, 7817683, 11908561, 18037173, 27480856, 41675737, 63207237, 96350848, 145915531, 221800523, 337247034, 511408848, 778057989, 1180332160, 1793391637, 2726547376, 4134908885, 6288205275, 9548686942, 14496241716, 22037732704, 33446283941, 50827550444, 77204685724, 117208213364, 178156310414, 270471178915, 410857722403, 624237753814, 947760120571, 1440128476784, 2187039436458, 3321721573921, 5046813807078, 7663296027158, 11642339272166, 17683696514386],
-[1, 1, 2, 2, 3, 6, 11, 11, 19, 31, 39, 59, 90, 134, 212, 320, 472, 723, 1046, 1658, 2542, 3764, 5782, 8674, 13399, 20268, 30400, 46901, 70470, 107352, 164219, 246862, 377185, 571259, 867948, 1325176, 1996833, 3046837, 4630401, 7009264, 10692459, 16183849, 24614226, 37427900, 56693878, 86387550, 130968865, 198866573, 302621854, 458705726, 697773622, 1059400064, 1607981818, 2445950471, 3710104722, 5639100166, 8567606246, 13001730335, 19768565886, 30007864668, 45583197277, 69267552184, 105138574852, 159794760570, 242660369170, 368512224280, 559994982405, 850198584205, 1291732014041, 1962076580356, 2979514423802, 4527172769318, 6874666967082, 10442870460293, 15863832589456],
-[1, 1, 2, 2, 3, 6, 12, 12, 19, 32, 43, 63, 83, 143, 241, 334, 509, 788, 1147, 1850, 2732, 4091, 6313, 9313, 14815, 22125, 32855, 51231, 76339, 117607, 178352, 267208, 413303, 619221, 945008, 1445381, 2166119, 3324690, 5028856, 7628369, 11662022, 17558362, 26830789, 40731492, 61624304, 94156853, 142316616, 216551941, 329419037, 498612783, 760286164, 1151999192, 1749655232, 2663390674, 4034168323, 6140310742, 9321116496, 14144964085, 21524287951, 32636591682, 49617406247, 75382954011, 114374226184, 173955977766, 263992450881, 401038315474, 609481657897, 924935221946, 1406006633074, 2134917951604, 3242110161712, 4927299393474, 7479672117175, 11365224980326, 17262956163595],
-[1, 1, 2, 2, 3, 5, 9, 10, 16, 27, 32, 42, 65, 117, 179, 235, 371, 578, 850, 1367, 1962, 2897, 4569, 6911, 10838, 15692, 23650, 37511, 55520, 85026, 128623, 193762, 299492, 447934, 686329, 1044911, 1564434, 2410963, 3643670, 5524084, 8431743, 12706751, 19451289, 29465560, 44613298, 68187268, 102966793, 156820086, 238457578, 361004812, 550437046, 833545917, 1267144106, 1928162549, 2919751881, 4445988595, 6746666101, 10240997113, 15582252385, 23623412536, 35925649703, 54564049690, 82798858820, 125944508187, 191088392454, 290342908660, 441203623695, 669572076696, 1017916021001, 1545389261559, 2347154043362, 3567017234326, 5414469252311, 8228024840339, 12496606486805],
-[1, 1, 2, 2, 3, 6, 12, 12, 20, 36, 44, 66, 101, 153, 242, 342, 537, 849, 1189, 1917, 2856, 4253, 6698, 9761, 15330, 23103, 34457, 54003, 79705, 122325, 187569, 279660, 432311, 648936, 987292, 1515105, 2265374, 3480475, 5272689, 7967666, 12214824, 18389722, 28065615, 42667712, 64452715, 98590491, 149020722, 226508940, 345070145, 521751047, 795654067, 1206399385, 1830701921, 2788716745, 4222330147, 6426057213, 9760526136, 14801678703, 22532595623, 34165696755, 51925623474, 78923739041, 119705782774, 182087796925, 276364136381, 419714317182, 638056191298, 968142345643, 1471639354925, 2234952611241, 3393333145290, 5157891719602, 7829416146914, 11895749650398, 18071323308905],
-[1, 1, 2, 2, 3, 6, 12, 12, 18, 30, 43, 64, 83, 140, 231, 323, 515, 759, 1094, 1804, 2670, 4026, 6079, 9101, 14575, 21281, 32089, 50105, 74262, 114762, 173250, 261683, 403172, 601257, 925064, 1407742, 2110451, 3246763, 4896678, 7450792, 11359815, 17114317, 26211924, 39652354, 60125594, 91852067, 138675618, 211355254, 320995462, 486359359, 741672861, 1122494019, 1707364631, 2596604918, 3933136759, 5990249509, 9085582835, 13797777710, 20988422275, 31818492171, 48402246089, 73488892206, 111541925906, 169653146087, 257378178014, 391156543165, 594266554638, 901947739196, 1371244590098, 2081527088583, 3161942654635, 4804764639485, 7293369932958, 11084039029819, 16832435788041],
-[1, 1, 2, 2, 3, 6, 12, 12, 18, 30, 44, 65, 87, 141, 223, 306, 499, 761, 1086, 1738, 2561, 3952, 5913, 8743, 14180, 20731, 31160, 48466, 72074, 111787, 167412, 253349, 391958, 582672, 896776, 1364492, 2047158, 3150004, 4742307, 7230667, 11017267, 16578611, 25430609, 38446475, 58301315, 89059268, 134419206, 205043884, 311165945, 471505462, 719381502, 1088114022, 1655674320, 2517728860, 3813335045, 5809260816, 8807845815, 13379730774, 20352418969, 30847155820, 46937351480, 71252727183, 108152367227, 164509024897, 249536823694, 379302289322, 576200528472, 874512085489, 1329678282890, 2018179583766, 3065949859496, 4658850339553, 7071558415573, 10747769654741, 16320573326403],
-[1, 1, 2, 2, 3, 6, 11, 14, 21, 32, 45, 71, 104, 157, 251, 365, 565, 875, 1278, 1987, 2942, 4512, 6963, 10280, 15941, 24100, 36368, 55897, 83703, 128335, 194768, 293578, 451209, 679276, 1033571, 1576670, 2378676, 3635953, 5501998, 8352831, 12742017, 19250333, 29345271, 44552803, 67520079, 102909007, 155851874, 237041190, 360292982, 546067642, 831464141, 1261079770, 1915441240, 2912948213, 4417236148, 6718225828, 10200006417, 15485247340, 23546641274, 35726573740, 54297437375, 82483818851, 125206186870, 190328443870, 288933337548, 438920985030, 666895223684, 1012420094445, 1538535371699, 2336419007913, 3548458740766, 5391630536550, 8186486556943, 12437552985461, 18891441254174],
-[1, 1, 2, 2, 4, 8, 16, 16, 21, 42, 64, 104, 125, 201, 376, 502, 782, 1168, 1725, 2815, 4007, 6338, 9664, 13856, 22487, 33310, 50178, 77767, 114395, 179668, 269218, 404353, 630249, 932615, 1435328, 2188651, 3278709, 5059913, 7585157, 11575634, 17700726, 26538995, 40761760, 61626199, 93389357, 142868595, 215251059, 328728955, 498930904, 755006449, 1153724346, 1743742086, 2653050733, 4036515740, 6109006219, 9314021297, 14115754434, 21438213917, 32631391491, 49425798468, 75232212364, 114211042758, 173302774962, 263715239067, 399883597152, 607911576107, 923611938466, 1401352216230, 2131340178923, 3234513800406, 4913541452144, 7467594842286, 11332615198592, 17226457517093, 26157722004017],
-[1, 1, 2, 2, 4, 8, 15, 15, 23, 46, 63, 91, 126, 211, 365, 484, 771, 1171, 1685, 2797, 4060, 6174, 9408, 13888, 22475, 32888, 49220, 77216, 114052, 177042, 266919, 401043, 622283, 924784, 1422151, 2170108, 3243103, 5001868, 7533113, 11459248, 17510885, 26304278, 40349034, 61063985, 92463684, 141467692, 213324504, 325287357, 494267395, 748023624, 1142042928, 1727440199, 2626957624, 3998006894, 6051204243, 9220921079, 13983947638, 21230108001, 32312435830, 48960701461, 74494152862, 113120197228, 171634054926, 261147850883, 396095292371, 601979895916, 914727734963, 1387953838904, 2110586973764, 3203619300273, 4866023005371, 7395514488020, 11224102245316, 17059259461828, 25906944060018],
-[1, 1, 2, 2, 4, 8, 15, 16, 26, 48, 62, 101, 148, 213, 347, 510, 808, 1189, 1718, 2838, 4158, 6232, 9733, 14332, 22398, 33485, 50748, 78915, 115947, 179340, 273799, 409595, 631597, 946937, 1447973, 2209299, 3313162, 5097628, 7693475, 11661906, 17849109, 26889240, 41072445, 62287191, 94326069, 144157509, 217720353, 331459638, 504204235, 763015756, 1163531270, 1762934929, 2678309121, 4075804948, 6172978128, 9398938582, 14264814398, 21647319191, 32942082341, 49946168297, 75940546779, 115361806773, 175042958269, 266241807707, 403994038565, 613777614477, 932778201307, 1415522150484, 2151841051744, 3267244414839, 4961920776024, 7540924520563, 11446818579139, 17394125496935, 26419402930625],
-[1, 1, 2, 2, 4, 8, 16, 16, 22, 44, 64, 99, 134, 212, 355, 483, 767, 1171, 1694, 2706, 3961, 6180, 9458, 13733, 21813, 32491, 49161, 76284, 112619, 174503, 263752, 397491, 614770, 915333, 1403256, 2141430, 3214734, 4942797, 7442875, 11325027, 17300116, 26033131, 39860154, 60351775, 91444728, 139729178, 210974362, 321564612, 488436806, 739604693, 1128429314, 1708057692, 2596926197, 3950576479, 5983280541, 9113121946, 13822938765, 20988490135, 31933576191, 48404460101, 73629860697, 111811268780, 169682049484, 258099308312, 391556960142, 595052247426, 904121089829, 1372092777169, 2086101349428, 3166767092531, 4810125329124, 7309778260986, 11095472253935, 16862173354723, 25608037086755],
-[1, 1, 2, 2, 4, 8, 16, 16, 21, 42, 64, 101, 132, 207, 350, 483, 780, 1161, 1670, 2690, 3974, 6211, 9383, 13661, 21827, 32412, 49112, 76023, 112196, 174276, 262952, 397071, 613294, 911820, 1401425, 2135795, 3207523, 4932183, 7419915, 11305195, 17255532, 25969081, 39783110, 60177124, 91249095, 139409019, 210436393, 320891455, 487142917, 737929078, 1125902632, 1703647045, 2591293592, 3940819618, 5968958712, 9092704759, 13788288650, 20941036146, 31857147567, 48287006875, 73463775808, 111536793844, 169286604349, 257494520546, 390606941026, 593686216785, 901943380526, 1368850524614, 2081239023437, 3159119477424, 4798941269689, 7292401088595, 11069059222192, 16822820747530, 25546675254744],
-[1, 1, 2, 2, 4, 7, 12, 14, 21, 38, 52, 91, 127, 182, 291, 431, 722, 1026, 1473, 2426, 3578, 5512, 8322, 12289, 19480, 28855, 44087, 67980, 100036, 155739, 235389, 355155, 546650, 815345, 1255188, 1906238, 2866192, 4410693, 6632564, 10104020, 15413485, 23226805, 35558212, 53752919, 81600686, 124595311, 188062371, 286830632, 435356897, 659716289, 1006125327, 1522632804, 2316634171, 3521563974, 5335303520, 8127349877, 12323063759, 18718568108, 28470817823, 43161049343, 65664009049, 99680828393, 151319991828, 230140809684, 349115296349, 530654662097, 806111578301, 1223520915186, 1860172854742, 2823533409278, 4289452193392, 6517667061628, 9893563728628, 15036293303140, 22832935124722],
-[1, 1, 2, 2, 4, 8, 16, 16, 23, 46, 64, 106, 141, 211, 368, 512, 811, 1211, 1748, 2878, 4168, 6430, 9869, 14257, 22897, 34143, 51331, 79795, 117265, 182989, 276684, 414206, 643309, 956576, 1467991, 2242567, 3356161, 5173790, 7781262, 11835454, 18116143, 27200076, 41687260, 63129369, 95581296, 146235671, 220517872, 336258100, 511005280, 773053735, 1180531709, 1786069140, 2715227943, 4132674084, 6255187661, 9531931179, 14456104364, 21944037927, 33403253960, 50611999659, 77000888170, 116940029863, 177418074005, 269949162178, 409452138576, 622260122724, 945597591581, 1434720470769, 2181710107973, 3311705376838, 5029965614193, 7644895128707, 11602520963326, 17634188654367, 26780670694317],
-[1, 1, 2, 2, 4, 8, 16, 16, 22, 44, 64, 107, 135, 206, 372, 509, 803, 1186, 1741, 2873, 4074, 6370, 9831, 14149, 22738, 33611, 50954, 79227, 115873, 181753, 273542, 410285, 638725, 946445, 1456140, 2219619, 3323611, 5134900, 7699060, 11731452, 17953788, 26930408, 41341108, 62508220, 94714673, 144939549, 218335885, 333369483, 506200474, 765787627, 1170108010, 1768902615, 2690979873, 4094557130, 6196201578, 9447179096, 14319434173, 21743642298, 33099304937, 50135859434, 76306474949, 115851368025, 175782007759, 267495524419, 405621124473, 616595892745, 936875231586, 1421426068859, 2161838143076, 3280929590024, 4983851245470, 7574661687196, 11495002868709, 17473045523822, 26532966654050],
-[1, 1, 2, 2, 4, 8, 16, 16, 22, 44, 64, 102, 138, 212, 360, 500, 800, 1160, 1686, 2810, 4102, 6289, 9473, 13986, 22470, 33086, 50029, 77578, 114532, 178437, 268980, 405076, 625720, 931205, 1434293, 2182388, 3272242, 5039961, 7581103, 11551769, 17629737, 26520399, 40648993, 61464384, 93226275, 142463225, 214909114, 327819712, 497694486, 753847712, 1150334152, 1740136202, 2647364408, 4026127511, 6097198870, 9289958519, 14085256820, 21392461209, 32546549051, 49326878897, 75053670484, 113941652808, 172935556063, 263067807038, 399022777143, 606510765315, 921422105881, 1398360857027, 2126232050982, 3227249340381, 4902551078905, 7449930861738, 11307723986012, 17186302421883, 26098019494269],
-[1, 1, 2, 2, 4, 8, 14, 14, 24, 48, 56, 80, 135, 207, 320, 444, 720, 1101, 1529, 2570, 3825, 5551, 8705, 13028, 20489, 30204, 45204, 71750, 105413, 161272, 247750, 369968, 570913, 855813, 1307721, 2002685, 2986573, 4601906, 6972421, 10529334, 16131582, 24295180, 37117758, 56351850, 85152658, 130352960, 196852585, 299303797, 456002393, 689501256, 1051526089, 1593643880, 2419561169, 3685509141, 5578313149, 8492656370, 12897293907, 19559249433, 29776264601, 45143573918, 68623397953, 104286216101, 158175106909, 240637659115, 365171540224, 554632611311, 843149087320, 1279291910923, 1944755016447, 2953196741870, 4484095066976, 6815927564201, 10345592888915, 15719824289063, 23879675580027],
-[1, 1, 2, 2, 4, 8, 15, 15, 22, 44, 64, 97, 134, 209, 339, 479, 772, 1171, 1665, 2654, 3988, 6132, 9303, 13631, 21598, 32303, 48734, 75534, 111877, 172431, 261741, 394924, 607919, 907946, 1390607, 2123421, 3189453, 4892240, 7385692, 11225529, 17136744, 25832068, 39488739, 59834252, 90661300, 138453922, 209271069, 318625062, 484155815, 733441773, 1118137031, 1693658533, 2574122816, 3915645451, 5932693816, 9031347007, 13704617737, 20806521042, 31649566065, 47992471887, 72979832099, 110839027849, 168217310454, 255817674500, 388192816605, 589839727564, 896216213946, 1360262093981, 2067742386944, 3139346724769, 4768243044013, 7245779672839, 10999527826459, 16714450522466, 25385405030438],
-[1, 1, 2, 3, 6, 8, 13, 18, 27, 52, 68, 105, 158, 229, 386, 557, 843, 1313, 1905, 3054, 4518, 6718, 10641, 15650, 24205, 36747, 54797, 85575, 127100, 194308, 298129, 444397, 686461, 1034560, 1568008, 2405412, 3607325, 5529883, 8386418, 12666663, 19411981, 29266261, 44585006, 67856925, 102525723, 156644186, 237049439, 360080646, 548630073, 829804082, 1264512506, 1918782521, 2910649588, 4433015314, 6715461880, 10214927511, 15520128287, 23535847675, 35820414234, 54333153338, 82549646408, 125486270263, 190350852064, 289473664545, 439455719210, 667317140414, 1014447710157, 1539456349978, 2339662956609, 3553685235840, 5395373950953, 8200448713746, 12449396139383, 18913117129012, 28733108100635],
-[1, 1, 2, 3, 6, 8, 13, 18, 26, 49, 67, 107, 154, 223, 383, 556, 835, 1260, 1862, 3022, 4480, 6656, 10376, 15324, 23920, 36208, 53964, 83797, 124850, 191859, 293045, 437367, 674735, 1016272, 1544959, 2364610, 3549008, 5439310, 8240396, 12473437, 19089476, 28778836, 43870234, 66702664, 100895005, 154070663, 233096284, 354315804, 539414798, 816341128, 1243958488, 1886779527, 2863643554, 4359538694, 6605467065, 10048997072, 15262253211, 23153485786, 35231268131, 53438564554, 81207185919, 123412036730, 187241124735, 284730231088, 432215336626, 656439411114, 997740184603, 1514224759546, 2301387735506, 3495175122429, 5307200563771, 8065753520834, 12245047483328, 18603708864145, 28260601443341],
-[1, 1, 2, 3, 5, 8, 16, 20, 23, 42, 72, 107, 140, 227, 380, 517, 805, 1250, 1855, 2848, 4266, 6692, 9979, 14763, 23377, 35025, 52630, 80822, 121861, 187105, 281299, 427336, 656921, 983066, 1502998, 2290147, 3457097, 5279965, 7977924, 12160052, 18497090, 27933415, 42676822, 64664864, 98105366, 149469463, 226311388, 344565574, 522926196, 793342079, 1208240456, 1830705442, 2783261143, 4230480099, 6415975590, 9760760332, 14810429277, 22499147289, 34200817037, 51884156532, 78889253376, 119787970119, 181877064422, 276471338643, 419627591387, 637651713209, 968579736438, 1470566979781, 2234979496329, 3393315006650, 5154676860080, 7831332616326, 11890686488661, 18067226810391, 27438361397421],
-[1, 1, 2, 2, 3, 6, 11, 12, 18, 28, 40, 65, 86, 138, 224, 315, 487, 708, 1102, 1760, 2485, 3865, 5923, 8845, 13861, 20290, 31235, 47958, 71233, 111048, 165811, 251424, 387510, 578971, 890687, 1345610, 2033348, 3126743, 4693781, 7170960, 10909999, 16454489, 25200565, 38061703, 57883296, 88206331, 133196156, 203380000, 308264922, 467596057, 712767221, 1078476774, 1642092200, 2493972879, 3780908818, 5758857183, 8727684594, 13266505369, 20168506670, 30579347477, 46529390848, 70607339068, 107231188431, 163044813772, 247327467388, 376027752286, 571043179778, 866924869735, 1317959025008, 2000298192013, 3039394307663, 4617383415515, 7009696392842, 10653704602029, 16175847563022],
-[1, 1, 2, 2, 3, 6, 10, 11, 19, 34, 45, 65, 94, 148, 241, 345, 532, 830, 1196, 1921, 2859, 4201, 6616, 9897, 15416, 22861, 34230, 53990, 80185, 122010, 186372, 279965, 431663, 648458, 986652, 1510257, 2264010, 3473995, 5267738, 7963942, 12178910, 18375285, 28035718, 42590874, 64397682, 98396884, 148853446, 226231860, 344445164, 521310796, 794321137, 1204609787, 1828711323, 2784109956, 4217459109, 6416527636, 9746009471, 14784878146, 22496660890, 34121837678, 51856242504, 78803882693, 119558826763, 181819308185, 275987684258, 419161694405, 637104924552, 966901937452, 1469560899686, 2231799064265, 3388884277818, 5150419797427, 7818946138573, 11879359927090, 18045722925359],
-[1, 1, 2, 2, 3, 6, 11, 11, 19, 31, 43, 66, 95, 136, 216, 341, 509, 727, 1071, 1771, 2672, 3867, 5982, 9096, 13986, 20947, 31671, 48878, 72915, 111524, 170944, 256776, 391432, 593168, 903787, 1375890, 2073109, 3169354, 4810091, 7281465, 11110447, 16821164, 25583622, 38861950, 58924163, 89799498, 136030257, 206659785, 314443703, 476644929, 725077351, 1100588878, 1671164017, 2541357658, 3854589896, 5860451281, 8901604826, 13509905384, 20541212953, 31178916935, 47368592768, 71968384697, 109247561979, 166047688682, 252124220000, 382927860889, 581870214057, 883405091001, 1342247159281, 2038658392710, 3096021340502, 4704069456291, 7143089808440, 10851245422300, 16483359528671],
-[1, 1, 2, 2, 3, 6, 12, 12, 18, 32, 43, 71, 96, 148, 243, 346, 558, 835, 1202, 1944, 2849, 4354, 6663, 9873, 15637, 23026, 34902, 54303, 80362, 124190, 187436, 283067, 436817, 651697, 999830, 1522031, 2286857, 3516399, 5298328, 8060471, 12298363, 18529918, 28364055, 42926929, 65085765, 99397243, 150113767, 228796310, 347467442, 526386476, 802721602, 1215198115, 1848028577, 2810421553, 4257500363, 6483721916, 9834504472, 14934827648, 22718101302, 34441734297, 52388633876, 79546881728, 120737477389, 183629326750, 278593136405, 423389794769, 643244409406, 976288185836, 1484226632412, 2253107509323, 3422512919267, 5200697742219, 7894542514271, 11997402688027, 18219704061055],
-[1, 1, 2, 2, 3, 6, 12, 12, 17, 30, 42, 69, 90, 146, 239, 332, 539, 798, 1161, 1879, 2711, 4207, 6429, 9453, 15037, 22032, 33629, 52181, 76833, 119598, 179762, 271659, 420001, 624595, 961574, 1460409, 2194271, 3381717, 5079828, 7743831, 11812487, 17777807, 27258841, 41188687, 62504499, 95477840, 144027616, 219819643, 333558887, 505322174, 771070266, 1166287400, 1774923121, 2698443868, 4086949144, 6227613217, 9440328328, 14340993778, 21815752509, 33062894424, 50312245108, 76370220912, 115926143669, 176337799326, 267454393554, 406571105329, 617613471205, 937341520041, 1425259972050, 2163169106221, 3286328828133, 4993665331033, 7579610237037, 11520390380905, 17493236857467],
-[1, 1, 2, 2, 3, 5, 9, 10, 16, 25, 32, 45, 64, 106, 172, 234, 345, 530, 777, 1258, 1830, 2716, 4213, 6254, 9936, 14571, 21827, 34292, 50636, 78094, 118351, 177759, 274656, 410307, 628660, 959188, 1436002, 2209935, 3337241, 5063799, 7739298, 11650426, 17825543, 27015614, 40899171, 62527827, 94405160, 143770357, 218624143, 330910692, 504730910, 764292969, 1161560365, 1767783707, 2676821998, 4076330713, 6185577519, 9388351051, 14286741922, 21657737379, 32936435025, 50027518525, 75907954365, 115468065952, 175191961995, 266186878046, 404512555175, 613852704218, 933239906043, 1416849883576, 2151854586637, 3270332069951, 4964004517249, 7543514734331, 11457135452771],
-[1, 1, 2, 2, 3, 6, 12, 12, 20, 35, 43, 66, 98, 157, 255, 353, 539, 822, 1213, 1973, 2851, 4243, 6720, 9994, 15556, 23036, 34787, 54499, 80401, 123760, 188463, 282285, 436059, 654133, 998806, 1523841, 2284534, 3515220, 5311634, 8046640, 12308529, 18545345, 28333917, 42985750, 65064835, 99427180, 150206548, 228633905, 347851424, 526392133, 802583670, 1216167625, 1847504974, 2811843969, 4258569738, 6483149220, 9840815295, 14933214927, 22724678641, 34455923536, 52384794516, 79583035972, 120751505545, 183661616559, 278700618386, 423398355221, 643469424472, 976496296638, 1484407886829, 2253914730365, 3422896871116, 5202031415522, 7896574482311, 11999022565275, 18225350443429],
-[1, 1, 2, 2, 3, 6, 12, 12, 17, 28, 44, 75, 90, 138, 235, 337, 530, 785, 1164, 1850, 2685, 4199, 6386, 9292, 14752, 22062, 33496, 51383, 76109, 118531, 178318, 269169, 415512, 619564, 950561, 1446195, 2178339, 3344577, 5029706, 7669532, 11699886, 17618979, 26966997, 40806973, 61920427, 94493253, 142719917, 217654710, 330300278, 500527484, 763386363, 1155467474, 1757486844, 2671846822, 4048732741, 6166026836, 9349928721, 14202905724, 21601916145, 32749027204, 49819030810, 75634815814, 114815534206, 174607989593, 264897907484, 402628182677, 611624991514, 928347975152, 1411364027690, 2142381250495, 3254557005122, 4945166664041, 7506827821082, 11408490193694, 17324359503009],
-[1, 1, 2, 2, 3, 6, 12, 12, 18, 30, 43, 72, 95, 140, 229, 351, 545, 785, 1152, 1887, 2822, 4242, 6452, 9611, 15077, 22576, 34165, 52534, 78167, 120753, 183279, 276014, 423271, 636067, 973279, 1480958, 2230909, 3418002, 5162610, 7846201, 11968771, 18073016, 27577913, 41799613, 63415244, 96710910, 146273505, 222663067, 338302746, 512791275, 781088058, 1183819507, 1799245801, 2735553350, 4147265134, 6311023306, 9577284155, 14542585357, 22114276585, 33545978281, 51000320765, 77453331787, 117577969454, 178759207104, 271304990698, 412225732182, 626280284328, 950720139168, 1444963290735, 2193980406825, 3332494266506, 5063418003604, 7687529806991, 11680850548121, 17740496212559],
-[1, 1, 2, 2, 3, 6, 12, 12, 17, 30, 41, 70, 94, 149, 242, 346, 563, 800, 1187, 1946, 2821, 4348, 6566, 9824, 15583, 22727, 34849, 53844, 79472, 123704, 185741, 281563, 433550, 645701, 996078, 1508412, 2270706, 3496191, 5251714, 8014985, 12204371, 18395384, 28193674, 42566154, 64681902, 98706014, 148952732, 227370658, 344803444, 522801614, 797259835, 1205964300, 1836079115, 2789762185, 4227197741, 6440334505, 9761314693, 14833713060, 22556751521, 34193987401, 52034207672, 78966489743, 119900664107, 182347430233, 276582912372, 420487687523, 638640290504, 969419444946, 1473907359795, 2236942341715, 3398787681214, 5163878299855, 7838628543730, 11913959748343, 18089845653373],
-[1, 1, 2, 2, 3, 6, 12, 13, 18, 30, 44, 67, 93, 148, 249, 353, 527, 818, 1220, 1909, 2795, 4294, 6651, 9800, 15283, 22931, 34536, 53318, 79854, 122537, 185444, 279582, 430703, 647185, 985290, 1503128, 2265769, 3466955, 5243081, 7962544, 12148202, 18336913, 27979578, 42465825, 64343020, 98107799, 148508658, 225960239, 343415716, 520386741, 792657596, 1201779546, 1825642783, 2776704469, 4209639705, 6403926787, 9721341876, 14758913134, 22444883453, 34048497686, 51754759345, 78617592363, 119330490650, 181417980345, 275375763676, 418350668099, 635645201956, 964917264073, 1466474699665, 2226845770387, 3382085360846, 5139015262806, 7802481057546, 11854741878295, 18005783590413],
-[1, 1, 2, 2, 3, 6, 12, 13, 20, 34, 48, 70, 99, 156, 266, 369, 562, 867, 1275, 2107, 3013, 4533, 7091, 10495, 16673, 24467, 36729, 57725, 85349, 131886, 199340, 298960, 464064, 692578, 1059992, 1617841, 2421761, 3732556, 5628603, 8544058, 13067155, 19647655, 30088788, 45599624, 69013408, 105538409, 159272192, 242690620, 369006804, 558309314, 851999260, 1289775824, 1960278076, 2983776460, 4517043899, 6880343255, 10438721933, 15843761295, 24114565936, 36547708135, 55588074134, 84432701125, 128101968984, 194887007723, 295654021592, 449244662453, 682712741777, 1035935798744, 1575086214861, 2391166661566, 3631602307675, 5519462875306, 8377426336623, 12731353314190, 19336051266290],
-[1, 1, 2, 2, 3, 6, 11, 13, 20, 33, 45, 70, 102, 161, 257, 365, 562, 861, 1276, 2037, 2987, 4488, 6946, 10400, 16265, 24179, 36381, 56472, 84381, 129625, 196306, 295508, 455026, 684055, 1044049, 1590219, 2392712, 3668410, 5548362, 8421980, 12848067, 19392519, 29604454, 44908382, 68055409, 103813930, 157044663, 238995693, 363294426, 550444300, 838505569, 1270999117, 1931223595, 2937355033, 4452241691, 6774431713, 10282806052, 15610873460, 23742504054, 36013828396, 54747103715, 83157625685, 126219033036, 191909358262, 291273599126, 442518390656, 672375470424, 1020632263168, 1551229455724, 2355444459157, 3577457418039, 5435979802149, 8253006964667, 12539749587805, 19045921393327],
-[1, 1, 2, 2, 3, 6, 11, 11, 18, 34, 43, 54, 88, 158, 228, 312, 492, 775, 1133, 1758, 2637, 3935, 6094, 9234, 14331, 21117, 31822, 49926, 74568, 113224, 172172, 260663, 399594, 600896, 917130, 1397890, 2102380, 3219015, 4883032, 7400021, 11274015, 17049787, 26011947, 39462908, 59782882, 91183621, 138061750, 209901124, 319176568, 483801896, 736453694, 1116834012, 1696765293, 2580707910, 3912333702, 5950501407, 9035790180, 13716532035, 20856834563, 31646223156, 48097135884, 73062922145, 110901495008, 168596498110, 255937707662, 388777818766, 590732015444, 896795001185, 1362819492712, 2069561474943, 3143158781201, 4775907575161, 7251433128603, 11016978875614, 16733978824608],
-[1, 1, 2, 2, 3, 6, 12, 12, 18, 33, 44, 71, 105, 157, 240, 351, 571, 843, 1213, 1982, 2918, 4440, 6885, 10120, 15854, 23547, 35770, 55770, 81995, 126735, 192605, 289397, 446630, 667684, 1022523, 1558474, 2338935, 3600142, 5427865, 8237802, 12593663, 18973039, 29013414, 43952543, 66586930, 101754751, 153654849, 234063594, 355804399, 538618360, 821446015, 1244085856, 1890954891, 2876865480, 4357132650, 6635288911, 10067614717, 15282285875, 23253010103, 35252527101, 53611156262, 81424539613, 123562328017, 187940925237, 285150449096, 433283993038, 658399870095, 999181772939, 1519009781321, 2306147012714, 3502643100665, 5322944707773, 8079899510780, 12278580222854, 18648270309267],
-[1, 1, 2, 2, 4, 7, 14, 14, 16, 32, 52, 93, 114, 175, 290, 408, 699, 991, 1447, 2327, 3409, 5506, 8026, 11787, 19054, 27912, 42969, 65652, 97050, 152169, 226452, 345779, 532376, 787305, 1220947, 1846509, 2785445, 4284716, 6412627, 9836776, 14944721, 22509004, 34575310, 52073264, 79256957, 120889173, 182339598, 278766397, 421905681, 640343655, 976974762, 1476093213, 2249807005, 3415849185, 5176830027, 7890742679, 11949617919, 18173286658, 27625735881, 41868511302, 63749099516, 96692082976, 146859723490, 223344788156, 338678488354, 515101156415, 782078175825, 1187270315281, 1805380017250, 2739279357946, 4163086244243, 6324287231699, 9599827944877, 14592977518097, 22153393313685],
-[1, 1, 2, 2, 4, 8, 15, 16, 24, 44, 60, 93, 131, 214, 359, 484, 773, 1145, 1693, 2772, 3996, 6159, 9333, 13865, 22172, 32497, 49221, 76357, 113078, 175911, 264350, 398858, 616336, 917919, 1413367, 2147228, 3223352, 4963790, 7466709, 11382808, 17354826, 26119694, 40039561, 60531753, 91845367, 140272280, 211656169, 322921961, 490106421, 742575539, 1132867618, 1713741092, 2607700383, 3964904820, 6005580998, 9149558702, 13871413042, 21071386698, 32053154287, 48582636771, 73921902684, 112213965471, 170333473544, 259087743070, 392992555418, 597370535139, 907465778819, 1377279418895, 2094112881809, 3178447913596, 4828635671138, 7337223739651, 11137051910504, 16926847359948, 25703363963707],
-[1, 1, 2, 2, 4, 8, 16, 16, 24, 48, 64, 101, 141, 216, 372, 513, 828, 1219, 1735, 2943, 4287, 6444, 9872, 14522, 23441, 34454, 51643, 81038, 118866, 185102, 280505, 419529, 650945, 967984, 1488891, 2273193, 3392345, 5239512, 7888131, 11983993, 18343776, 27541351, 42230845, 63930014, 96769260, 148162804, 223296364, 340454522, 517615428, 782895032, 1195625167, 1808600289, 2749732470, 4185999516, 6333954717, 9653508506, 14641114834, 22221654607, 33830289309, 51255088161, 77982550261, 118431682088, 179665156786, 273402241082, 414663433482, 630163826088, 957671604257, 1452949995179, 2209524159889, 3353877188336, 5093917377569, 7742453780904, 11750011645402, 17858760448747, 27122058591347],
-[1, 1, 2, 2, 4, 8, 15, 15, 21, 42, 60, 103, 141, 198, 323, 479, 775, 1126, 1603, 2628, 3928, 6009, 9122, 13305, 21073, 31688, 47920, 73991, 109056, 169153, 257180, 386295, 595151, 889223, 1362922, 2079969, 3122727, 4797013, 7231405, 10990388, 16798460, 25302690, 38680043, 58602371, 88808502, 135676794, 204932897, 312135308, 474351530, 718301418, 1095459960, 1659044821, 2521539438, 3835904978, 5810773989, 8848161416, 13424673960, 20379922655, 31006375758, 47009875969, 71491492399, 108577898244, 164775139095, 250610237655, 380250987534, 577798262921, 877953451159, 1332429689977, 2025585486309, 3075235403997, 4670841182459, 7098036313141, 10774660869333, 16373472417844, 24867242501630],
-[1, 1, 2, 2, 4, 8, 15, 15, 20, 40, 64, 103, 129, 199, 344, 489, 784, 1128, 1645, 2692, 3977, 6191, 9185, 13504, 21765, 32235, 48818, 75034, 111235, 173499, 260651, 394232, 607576, 903715, 1392939, 2117481, 3183759, 4892056, 7354308, 11229713, 17111701, 25762592, 39478811, 59662504, 90585975, 138285206, 208735478, 318467647, 483070326, 732307701, 1117062252, 1689768746, 2571515738, 3908736946, 5922313316, 9021870573, 13676369578, 20779388602, 31602243565, 47904207890, 72892262766, 110638951238, 167964475320, 255454765938, 387496734569, 589053750223, 894735042766, 1358076447255, 2064837344148, 3133957097172, 4761329199791, 7234461209791, 10981561905798, 16690420112418, 25343538591582],
-[1, 1, 2, 2, 4, 8, 15, 15, 22, 44, 60, 96, 137, 203, 335, 480, 771, 1123, 1603, 2672, 3962, 5957, 9128, 13476, 21350, 31690, 47841, 74566, 109692, 169830, 258484, 387752, 598011, 893366, 1370398, 2090790, 3131918, 4820711, 7271105, 11038042, 16878279, 25412664, 38869178, 58883903, 89194640, 136361528, 205850696, 313536867, 476696725, 721574380, 1100657610, 1666664288, 2533152874, 3854559393, 5837047691, 8889731860, 13488203068, 20473005802, 31153946946, 47227514173, 71825269337, 109089664074, 165532034781, 251796892879, 382023490663, 580475807745, 882096126726, 1338600766454, 2035085669490, 3089619055100, 4692525259143, 7131432272928, 10824729252215, 16449976930905, 24983826436975],
-[1, 1, 2, 2, 4, 8, 15, 16, 24, 44, 60, 102, 142, 211, 344, 495, 802, 1175, 1713, 2773, 4048, 6244, 9610, 14089, 22132, 32936, 50259, 77745, 114324, 177506, 268877, 404898, 624463, 932225, 1430381, 2176570, 3272192, 5034066, 7576006, 11524412, 17601922, 26516786, 40568542, 61398716, 93119301, 142216226, 214720874, 327324626, 497134264, 752946584, 1148366820, 1738571376, 2643813550, 4020424002, 6090444585, 9276191129, 14069262691, 21364663414, 32501297863, 49271858373, 74946428316, 113799815049, 172725392704, 262702588242, 398546285195, 605698582460, 920237898947, 1396650787024, 2123329095668, 3223286834527, 4896212015157, 7440124334241, 11293793444723, 17163479541181, 26065031164713],
-[1, 1, 2, 2, 4, 8, 16, 16, 24, 48, 64, 97, 136, 216, 369, 499, 805, 1231, 1734, 2854, 4201, 6386, 9830, 14326, 23022, 34089, 50939, 80058, 117714, 182296, 276663, 414330, 643296, 956727, 1466731, 2245027, 3352395, 5168792, 7792329, 11826732, 18108711, 27198911, 41675292, 63153294, 95511883, 146226147, 220561008, 336033592, 511078659, 772930372, 1180179107, 1786072388, 2714177982, 4132853416, 6254109295, 9528605327, 14456871959, 21937863248, 33398156751, 50607350458, 76979956506, 116933787368, 177377849528, 269901682345, 409420381325, 622099296727, 945494134735, 1434491424487, 2181270720115, 3311354189600, 5028899014603, 7643822701329, 11600820866573, 17630615039902, 26777450366178],
-[1, 1, 2, 2, 4, 8, 16, 16, 26, 52, 64, 100, 153, 226, 353, 496, 838, 1299, 1758, 2884, 4321, 6489, 10086, 14672, 23342, 34760, 52043, 82261, 120275, 184982, 283308, 423668, 656327, 978011, 1495705, 2295339, 3423027, 5276436, 7972249, 12060662, 18493348, 27802157, 42540190, 64537024, 97493687, 149352559, 225390375, 342971023, 522219279, 789385241, 1204967912, 1824921241, 2771293074, 4221697599, 6387741022, 9729399343, 14770119121, 22401964589, 34110047029, 51694798414, 78607456873, 119446609832, 181152990997, 275645727225, 418208807508, 635286216973, 965737769115, 1465125964480, 2227633345835, 3382312342053, 5135867747563, 7807036593278, 11848756588797, 18005632343434, 27350384757849],
-[1, 1, 2, 2, 4, 8, 15, 15, 21, 42, 60, 98, 133, 198, 330, 468, 761, 1120, 1596, 2629, 3878, 5941, 9061, 13237, 21108, 31344, 47407, 73722, 108329, 168264, 255101, 383404, 592483, 882265, 1355085, 2067181, 3097757, 4770264, 7181866, 10920170, 16694211, 25113829, 38456885, 58211841, 88199944, 134857866, 203489402, 310169137, 471281427, 713418441, 1088695409, 1647681243, 2505198964, 3811538182, 5771267484, 8792106424, 13335592763, 20245769026, 30808420645, 46694199115, 71032041713, 107867091902, 163682927999, 249003188044, 377728213017, 574037295507, 872242170780, 1323614487691, 2012503740759, 3054987342435, 4640263716100, 7051953016791, 10703575678768, 16267085281200, 24704406553277],
-[1, 1, 2, 2, 4, 7, 13, 13, 19, 36, 52, 77, 107, 176, 278, 400, 637, 955, 1397, 2202, 3319, 5077, 7602, 11410, 18010, 26714, 40398, 62319, 93273, 143251, 216244, 328333, 502853, 753515, 1155655, 1757036, 2648593, 4052380, 6124703, 9325708, 14185071, 21437814, 32749890, 49586294, 75267705, 114715099, 173591728, 264308524, 401218686, 608709783, 926892576, 1404334723, 2135571537, 3245608427, 4921771524, 7488789294, 11362593894, 17260774406, 26238060367, 39805131652, 60525445062, 91896399298, 139533178323, 212113304585, 321926805872, 489199934054, 743086218185, 1128202908077, 1714663538255, 2603275666633, 3954657553400, 6008135733828, 9122289654047, 13861116837491, 21050370845407],
-[1, 1, 2, 2, 4, 8, 16, 16, 26, 52, 64, 100, 153, 226, 363, 514, 838, 1265, 1760, 2936, 4349, 6455, 10088, 14827, 23435, 34788, 52225, 82385, 120604, 185785, 284441, 424817, 657359, 982025, 1501992, 2300198, 3433514, 5293561, 7997563, 12099835, 18547529, 27898299, 42664658, 64724579, 97839002, 149801714, 226077847, 344054877, 523837692, 791980052, 1208580787, 1830647603, 2780255167, 4234403694, 6408235448, 9759940460, 14815802467, 22473190728, 34215032023, 51859390761, 78854067240, 119814755914, 181730655445, 276502230840, 419519503465, 637290028816, 968736256965, 1469758262200, 2234583872638, 3392893420098, 5152082742440, 7831315946777, 11886037981479, 18062107155693, 27435807207942],
-[1, 1, 2, 3, 6, 8, 14, 21, 27, 47, 73, 105, 152, 230, 380, 568, 819, 1305, 1939, 2945, 4530, 6746, 10473, 15604, 23841, 36824, 54576, 83954, 127473, 192536, 295227, 443564, 678571, 1031426, 1554596, 2384171, 3600798, 5468422, 8337852, 12599899, 19232643, 29137287, 44204661, 67411065, 101965890, 155236037, 235835699, 357482260, 544451592, 825208601, 1254419020, 1906962739, 2890998679, 4399418867, 6676347431, 10138188761, 15415727255, 23385063706, 35553259822, 53991953857, 81966223085, 124615108624, 189126791493, 287367951881, 436580318612, 662762758276, 1007298072056, 1529423894214, 2323133582945, 3529732602958, 5359043396795, 8142858552137, 12366968244241, 18781990035281, 28536480618882],
-[1, 1, 2, 3, 5, 7, 13, 18, 28, 45, 60, 99, 156, 227, 353, 515, 804, 1224, 1799, 2879, 4275, 6376, 9888, 14807, 22919, 34402, 51767, 80293, 119914, 183492, 279698, 420008, 645221, 973231, 1481532, 2259769, 3400524, 5207552, 7891451, 11953915, 18250197, 27574049, 42023375, 63826684, 96671493, 147447542, 223221570, 339354733, 516251456, 782059523, 1190777616, 1806360094, 2743014940, 4172952358, 6326037458, 9621714652, 14611643830, 22175555125, 33727098194, 51172042833, 77763005412, 118148717530, 179315301015, 272610591818, 413847678490, 628603535254, 955236158547, 1450027854566, 2203562042858, 3346518932033, 5082131903177, 7722513693058, 11725203330982, 17813551825807, 27058639680495],
-[1, 1, 2, 2, 4, 6, 11, 12, 19, 34, 47, 73, 100, 152, 244, 353, 567, 878, 1219, 1977, 2970, 4459, 6953, 10082, 15956, 23985, 35836, 56187, 82573, 127197, 194730, 290872, 449973, 672676, 1026371, 1574708, 2353714, 3619876, 5473300, 8282527, 12696985, 19100641, 29185846, 44318377, 66971341, 102492893, 154802892, 235470084, 358527030, 542113535, 827119180, 1253309936, 1902729435, 2898086779, 4386993756, 6679364630, 10141545703, 15382328098, 23417509331, 35499067109, 53968167493, 82012368977, 124392765414, 189240703473, 287165731274, 436191766533, 663059194031, 1006033711156, 1529423810733, 2322402016583, 3526389161645, 5360150869414, 8135871373971, 12362511842317, 18779052658777],
-[1, 1, 2, 2, 3, 6, 12, 14, 20, 32, 45, 72, 107, 155, 251, 379, 566, 862, 1239, 1979, 3028, 4469, 6960, 10310, 15888, 24298, 36233, 56095, 83857, 127737, 196613, 293936, 450699, 682049, 1033169, 1584005, 2381406, 3636269, 5528266, 8350525, 12775404, 19308805, 29353685, 44697960, 67592945, 103124900, 156298459, 237179376, 361329113, 547072882, 832683540, 1264535348, 1917854561, 2919628255, 4426365248, 6728259227, 10226034522, 15509535290, 23593072448, 35806631349, 54383336017, 82670961733, 125435750668, 190683128836, 289571382130, 439665890976, 668302658445, 1014419684436, 1541325847622, 2341428896242, 3554933439954, 5402397739496, 8203045615173, 12460393056273, 18930689561793],
-[1, 1, 2, 2, 3, 6, 12, 12, 18, 30, 43, 66, 91, 141, 216, 327, 516, 768, 1101, 1747, 2687, 4042, 6157, 9147, 14160, 21377, 32410, 49904, 74258, 113608, 173727, 262308, 400369, 602998, 920198, 1403323, 2115640, 3232469, 4897202, 7427410, 11327417, 17139367, 26102855, 39609261, 60057723, 91553967, 138670858, 210784251, 320460216, 485860440, 739450545, 1121787350, 1703935950, 2590878287, 3929290641, 5975510340, 9073796154, 13774530871, 20942393211, 31781811190, 48297692969, 73368082324, 111375448765, 169292093187, 257017648729, 390417732593, 593197919403, 900594586655, 1368489036872, 2078276929495, 3156428387856, 4795806061538, 7282081640415, 11063130146269, 16804133917737],
-[1, 1, 2, 2, 3, 6, 11, 12, 18, 28, 37, 65, 91, 143, 219, 333, 526, 737, 1143, 1804, 2649, 4088, 6153, 9393, 14460, 21331, 33107, 50280, 75170, 116221, 174559, 266274, 405571, 610202, 938350, 1413318, 2145989, 3283477, 4945057, 7552669, 11455287, 17361244, 26501021, 40034099, 60993119, 92717787, 140337681, 213993100, 324243792, 492678627, 749473504, 1135493407, 1728819660, 2623169332, 3981920437, 6058689416, 9186210489, 13967700243, 21216060997, 32197485107, 48966626849, 74300952590, 112896248557, 171550848770, 260352888837, 395778056982, 600907025061, 912650376485, 1386906900631, 2105358325721, 3199258792413, 4858996627865, 7378669031475, 11212254332245, 17024424406055],
-[1, 1, 2, 2, 3, 6, 12, 12, 17, 28, 41, 67, 85, 140, 230, 341, 517, 782, 1180, 1786, 2706, 4205, 6319, 9370, 14604, 22114, 33349, 50736, 76727, 117795, 177436, 269368, 412311, 620769, 946560, 1439600, 2181075, 3322154, 5026032, 7656589, 11640736, 17615954, 26848233, 40720633, 61828028, 94056580, 142588599, 216948537, 329272445, 499822314, 760458394, 1153301029, 1752853811, 2663206642, 4041951844, 6145406702, 9327421389, 14170340243, 21532840775, 32682065833, 49674175192, 75432762531, 114555605421, 174077191436, 264287774229, 401555956001, 609918390459, 926196241605, 1407326108833, 2137028247603, 3246259612805, 4931378414286, 7488741009453, 11377309436083, 17279223105786],
-[1, 1, 2, 2, 3, 6, 12, 14, 19, 31, 48, 73, 106, 153, 246, 374, 562, 873, 1251, 1969, 3029, 4535, 6999, 10262, 15929, 24463, 36403, 56192, 84155, 128245, 196907, 294974, 452642, 683553, 1035464, 1589247, 2390539, 3646598, 5541117, 8379100, 12815476, 19361864, 29446769, 44832857, 67795412, 103427756, 156775391, 237937921, 362353052, 548706728, 835302872, 1268281696, 1923665303, 2928372594, 4439782289, 6748778422, 10256264292, 15557121255, 23664383607, 35913140273, 54549464663, 82919517500, 125815107243, 191258901784, 290440024313, 441005595822, 670309663163, 1017476915471, 1546004729582, 2348459016858, 3565696549596, 5418698988472, 8227771039893, 12498131464209, 18987622296230],
-[1, 1, 2, 2, 3, 6, 12, 13, 16, 28, 42, 72, 97, 151, 240, 352, 552, 807, 1209, 1884, 2817, 4390, 6591, 9803, 15277, 22878, 34970, 53269, 79654, 123016, 185475, 281682, 430738, 646422, 990891, 1502868, 2274200, 3476361, 5243427, 7995973, 12158902, 18388301, 28068192, 42480880, 64568975, 98298380, 148788130, 226662894, 343825264, 521871172, 794456037, 1203830283, 1831107848, 2781271376, 4219592023, 6420063581, 9738640787, 14798476716, 22489431096, 34122713114, 51886106338, 78765976272, 119625290074, 181817559170, 275950466064, 419385447699, 636940205889, 967158290203, 1469830599180, 2231495928075, 3390202995979, 5150021633365, 7819917024281, 11882234380071, 18044044147615],
-[1, 1, 2, 2, 3, 6, 11, 12, 17, 32, 42, 57, 92, 153, 223, 331, 516, 771, 1137, 1778, 2802, 4087, 6084, 9539, 14620, 21845, 32845, 50620, 77103, 115992, 177087, 269341, 407116, 619105, 943221, 1433472, 2168502, 3293960, 5023698, 7615290, 11552375, 17570501, 26683325, 40531897, 61532065, 93518838, 142082861, 215568170, 327697507, 497867148, 755791367, 1148239139, 1743895664, 2649532599, 4023979452, 6110606301, 9285351374, 14100381711, 21416359284, 32536508280, 49412176197, 75060883796, 114003750630, 173163312140, 263055061531, 399492841923, 606852656155, 921791333906, 1400011933474, 2126695220185, 3230103027050, 4906395423266, 7452622484623, 11319278308860, 17194319983304],
-[1, 1, 2, 2, 3, 6, 11, 12, 20, 33, 45, 57, 91, 155, 234, 330, 506, 808, 1159, 1811, 2780, 4120, 6212, 9509, 14848, 22059, 33064, 51242, 77563, 117316, 178445, 270833, 412680, 623554, 950220, 1448608, 2183683, 3328075, 5062697, 7676939, 11672820, 17690470, 26936834, 40900387, 62004165, 94418218, 143209964, 217495164, 330660617, 501764237, 762900816, 1157864946, 1758624721, 2673761733, 4056847345, 6165398628, 9365421693, 14219115416, 21610085782, 32807857761, 49844684772, 75720413106, 114963448978, 174702517757, 265299052718, 402945906899, 612189386412, 929607566997, 1412318457108, 2145051506681, 3257838792908, 4949461486794, 7516372393620, 11417835475033, 17343550983826],
-[1, 1, 2, 2, 3, 6, 11, 11, 16, 29, 41, 64, 96, 154, 223, 336, 544, 779, 1161, 1847, 2826, 4249, 6270, 9726, 15040, 22219, 34083, 52016, 78342, 119743, 181150, 276726, 418787, 632267, 971127, 1467534, 2224107, 3391258, 5135008, 7824127, 11856923, 18004848, 27432008, 41503343, 63173179, 96019732, 145555650, 221497717, 335960254, 510682927, 776073876, 1176926014, 1790574354, 2717764818, 4126754967, 6274103552, 9520506055, 14470556580, 21975990646, 33367342536, 50720348631, 76987066745, 116968983540, 177703456768, 269793573657, 409988256610, 622561978755, 945641645613, 1436696823428, 2181463674540, 3314439505891, 5033936270302, 7645265216434, 11615269319193, 17638902562549],
-[1, 1, 2, 2, 3, 6, 12, 12, 17, 32, 42, 69, 97, 154, 234, 327, 563, 834, 1190, 1930, 2875, 4410, 6574, 9830, 15758, 23108, 34902, 54282, 80591, 124536, 187436, 283944, 437290, 651322, 1003037, 1524937, 2289942, 3520014, 5304786, 8082677, 12308198, 18552910, 28424347, 42972116, 65197868, 99547254, 150334028, 229193779, 347858436, 527318015, 804029820, 1216694367, 1851229364, 2814424535, 4264029702, 6494058919, 9847989095, 14959801845, 22750989675, 34492345116, 52474716988, 79659280636, 120926414713, 183909615733, 279003395985, 424064354857, 644179925446, 977790887429, 1486527110580, 2256407081217, 3427876358725, 5208492813175, 7906472073054, 12015973073858, 18246750821031],
-[1, 1, 2, 2, 3, 6, 12, 12, 18, 32, 41, 67, 97, 152, 240, 345, 542, 797, 1199, 1947, 2831, 4280, 6508, 9811, 15504, 22787, 34476, 53400, 79678, 123133, 185312, 279836, 430677, 645270, 990601, 1503426, 2262357, 3474171, 5240800, 7979052, 12150829, 18332080, 28040023, 42439826, 64412428, 98242217, 148465970, 226262452, 343511486, 520783771, 793576553, 1201692000, 1827824572, 2778463021, 4211291786, 6411155282, 9724420428, 14771415353, 22461753009, 34063401818, 51807016980, 78654685475, 119412510781, 181572209304, 275507547666, 418706439023, 636042589902, 965526187776, 1467681039202, 2228059424184, 3384682545429, 5142604130264, 7807194774243, 11864089433684, 18016872711816],
-[1, 1, 2, 2, 3, 6, 12, 12, 16, 30, 42, 68, 93, 150, 232, 326, 539, 781, 1158, 1859, 2768, 4244, 6295, 9517, 15057, 22170, 33715, 51872, 77580, 119747, 180026, 273461, 418946, 627236, 965036, 1462100, 2205209, 3380416, 5098315, 7775582, 11815679, 17854600, 27306303, 41282909, 62736242, 95594257, 144550782, 220325549, 334193814, 507176497, 772420290, 1169611080, 1779875751, 2703833227, 4100188628, 6241005556, 9464680388, 14382913554, 21861164466, 33160242642, 50436736273, 76554634328, 116258863804, 176738911689, 268187089521, 407629978822, 619086255099, 939965682545, 1428705322556, 2168792155331, 3295083881332, 5005761229094, 7600119551549, 11549336370135, 17537696040283],
-[1, 1, 2, 2, 3, 6, 12, 12, 18, 32, 42, 63, 87, 152, 251, 338, 537, 805, 1203, 1975, 2793, 4272, 6553, 9784, 15752, 22776, 34362, 53802, 79666, 123997, 185346, 279539, 434042, 645263, 994085, 1509193, 2262009, 3491881, 5248507, 8000725, 12200606, 18339767, 28157316, 42551281, 64532370, 98623931, 148690438, 226999227, 344520226, 521766176, 796442619, 1204185301, 1832765097, 2787244237, 4219997079, 6431516260, 9748636941, 14808649331, 22531504790, 34140467394, 51958039377, 78868704696, 119704912739, 182114848846, 276187897730, 419853631216, 637820928551, 967925256568, 1471903237872, 2233850269471, 3393648900499, 5157057236135, 7827081576557, 11897059043864, 18065105915422],
-[1, 1, 2, 2, 3, 6, 12, 13, 18, 31, 48, 74, 101, 145, 234, 353, 548, 819, 1182, 1901, 2872, 4340, 6580, 9689, 15244, 23083, 34558, 53293, 79554, 122197, 186309, 279670, 429668, 646252, 984814, 1505783, 2264299, 3463950, 5244732, 7954183, 12151566, 18340498, 27959886, 42461714, 64303213, 98123342, 148513834, 225791209, 343429540, 520227539, 792446886, 1201756624, 1824805638, 2776446598, 4208516967, 6401965353, 9721102967, 14753199963, 22440341851, 34042876647, 51738575172, 78608000472, 119295070828, 181376765242, 275331010864, 418222487505, 635542000575, 964686495619, 1466096512919, 2226460125515, 3381171216611, 5137989894354, 7800767165069, 11851689691582, 18002439964626],
-[1, 1, 2, 2, 4, 8, 16, 16, 23, 46, 64, 109, 146, 211, 356, 507, 849, 1226, 1724, 2940, 4283, 6505, 9904, 14512, 23466, 34397, 51988, 81425, 118887, 185638, 280822, 421170, 653210, 968877, 1493820, 2277435, 3401369, 5258604, 7900533, 12017905, 18387998, 27606669, 42368383, 64051509, 97028529, 148567576, 223789782, 341467874, 518798478, 784862717, 1198912873, 1812717069, 2757433032, 4196313199, 6349334037, 9680028863, 14675894347, 22280419145, 33917016866, 51379698266, 78192200831, 118722069694, 180126366427, 274114509865, 415679179074, 631821340399, 960079812412, 1456623169946, 2215279408568, 3362202092029, 5107103052996, 7762175466144, 11779564581995, 17905070739552, 27190171104653],
-[1, 1, 2, 2, 4, 8, 15, 15, 22, 44, 60, 98, 133, 200, 342, 474, 786, 1154, 1622, 2754, 4015, 6102, 9263, 13580, 22075, 32343, 48571, 76221, 111700, 174178, 263117, 394520, 612731, 908512, 1399949, 2136513, 3188477, 4927764, 7408704, 11269544, 17241129, 25874818, 39719978, 60069917, 90954867, 139284818, 209831127, 320115438, 486420335, 735823367, 1124079011, 1699543449, 2584955216, 3934548244, 5952866203, 9075055999, 13759784267, 20888363077, 31799525484, 48170797304, 73307019123, 111311579747, 168872449568, 256994992199, 389726511179, 592349804249, 900131671048, 1365642086840, 2076923291504, 3152264253484, 4788067614719, 7277468054875, 11043909086894, 16786692725696, 25492312035502],
-[1, 1, 2, 2, 4, 7, 13, 14, 21, 41, 54, 84, 118, 183, 311, 418, 680, 1030, 1464, 2431, 3549, 5361, 8244, 12124, 19463, 28655, 42934, 67511, 99195, 153762, 233194, 349185, 541845, 806201, 1237872, 1891489, 2823747, 4357879, 6567441, 9970773, 15259329, 22922127, 35134071, 53212691, 80515705, 123255390, 185857337, 283252674, 430729744, 651496402, 994725196, 1505150760, 2287894881, 3483193045, 5270980631, 8031655167, 12183866267, 18490735432, 28149025698, 42652429233, 64885154549, 98550561345, 149502465581, 227488060705, 345061186139, 524344105049, 796880933467, 1209037804670, 1838488264916, 2790850637811, 4238619759405, 6442454207371, 9777465751379, 14859977817121, 22568634722031],
-[1, 1, 2, 2, 4, 8, 16, 16, 24, 48, 64, 94, 134, 219, 377, 500, 798, 1198, 1718, 2889, 4194, 6329, 9662, 14302, 23124, 33788, 50555, 79489, 117177, 181932, 274830, 412188, 639622, 950855, 1462655, 2232116, 3332396, 5143999, 7748392, 11778585, 18008172, 27046761, 41486964, 62791295, 95059199, 145490757, 219335438, 334426912, 508305591, 769081924, 1174301789, 1776245335, 2700972509, 4111226622, 6221633204, 9481319896, 14379537467, 21827978948, 33226085117, 50342754057, 76596032181, 116317715086, 176473043636, 268527504408, 407279856981, 618959478700, 940581712165, 1427114055929, 2170179509863, 3294104899739, 5003317510358, 7604442210003, 11540896519971, 17540829902878, 26638721963032],
-[1, 1, 2, 2, 4, 8, 16, 16, 21, 42, 64, 107, 136, 204, 355, 501, 813, 1174, 1688, 2797, 4115, 6387, 9555, 13933, 22543, 33403, 50411, 77931, 114907, 179589, 270372, 407140, 629906, 935281, 1441061, 2194848, 3291082, 5068488, 7615319, 11613889, 17732286, 26652950, 40872536, 61790330, 93711097, 143243263, 216029868, 329625756, 500319361, 757732910, 1156710617, 1749308668, 2661442901, 4047752292, 6129244299, 9340381585, 14159683746, 21506306595, 32722285425, 49586047635, 75457163416, 114550504709, 173852467403, 264481230996, 401135768502, 609758197424, 926349362589, 1405772394572, 2137643025866, 3244412396637, 4928684917594, 7489803496659, 11367802226864, 17278257443497, 26237097218135],
-[1, 1, 2, 2, 4, 8, 15, 15, 20, 40, 64, 103, 129, 199, 344, 489, 779, 1119, 1645, 2702, 3966, 6149, 9178, 13519, 21718, 32114, 48734, 74956, 110974, 173157, 260295, 393381, 606246, 902344, 1390674, 2113029, 3176957, 4883987, 7341474, 11206549, 17080275, 25715882, 39403056, 59549545, 90418545, 138032477, 208330619, 317863266, 482200125, 730921490, 1114940163, 1686619676, 2566709044, 3901440713, 5911108037, 9005045399, 13650875667, 20740031177, 31543352036, 47814656305, 72755165627, 110431842104, 167649055516, 254977779483, 386769809419, 587946476067, 893064881580, 1355526674163, 2060966061763, 3128092201985, 4752394017264, 7220920879614, 10960960674089, 16659141843938, 25296107416635],
-[1, 1, 2, 2, 4, 8, 16, 16, 22, 44, 64, 106, 147, 212, 339, 498, 812, 1177, 1678, 2746, 4109, 6269, 9542, 14025, 22073, 32952, 50148, 77594, 114190, 176698, 268850, 404964, 622469, 930263, 1427170, 2174768, 3267418, 5020038, 7568138, 11501662, 17566603, 26484944, 40482930, 61297936, 92944107, 141954104, 214436317, 326629493, 496275899, 751749106, 1146125019, 1735838995, 2638879616, 4013327441, 6080401326, 9258403926, 14046491933, 21326647970, 32441494646, 49190656401, 74808812239, 113604671815, 172424122876, 262224696338, 397877379482, 604604975037, 918623946480, 1394251616438, 2119489156012, 3217753461083, 4887549175014, 7426964391594, 11274319010403, 17132741814112, 26019778062722],
-[1, 1, 2, 2, 4, 8, 15, 15, 23, 46, 60, 89, 137, 211, 334, 467, 739, 1124, 1602, 2608, 3896, 5833, 8997, 13324, 20917, 31228, 46943, 73300, 108469, 166522, 254321, 381622, 587495, 880909, 1345893, 2057398, 3083560, 4734446, 7164081, 10854695, 16594481, 25019365, 38203201, 57969938, 87739696, 134064162, 202660737, 308269248, 469025825, 710030052, 1082244328, 1640295160, 2491372612, 3791802355, 5743826272, 8741993684, 13272167294, 20139646798, 30643187192, 46470573020, 70643197116, 107326349905, 162849463935, 247667983544, 375875786993, 570982904882, 867758540300, 1316951153484, 2001783921536, 3039672594395, 4616108369473, 7015280029105, 10649578448573, 16181405735866, 24578709754403],
-[1, 1, 2, 2, 4, 8, 16, 16, 23, 46, 64, 100, 139, 214, 363, 506, 803, 1191, 1710, 2813, 4156, 6325, 9659, 14157, 22573, 33583, 50491, 78567, 115882, 179708, 272641, 409111, 632315, 943113, 1446522, 2208495, 3308259, 5090394, 7673981, 11661188, 17829011, 26822892, 41050984, 62189791, 94187504, 143997490, 217361396, 331155176, 503358520, 761918457, 1162500522, 1759892379, 2674984143, 4070592739, 6163868733, 9388167455, 14242847647, 21620071821, 32900532742, 49869903374, 75850919190, 115200414162, 174801185474, 265906850888, 403411917834, 613003313439, 931505887959, 1413551016754, 2149134275490, 3262637250382, 4955370159825, 7530962342381, 11430921465075, 17371622256940, 26383096489001],
-[1, 1, 2, 2, 4, 8, 16, 16, 22, 44, 64, 109, 147, 209, 345, 509, 830, 1189, 1694, 2817, 4199, 6388, 9677, 14199, 22581, 33670, 50991, 78932, 116093, 180404, 273981, 411693, 634339, 946574, 1454078, 2216344, 3324770, 5114522, 7703802, 11713947, 17900569, 26953753, 41236677, 62425656, 94634433, 144619290, 218306706, 332665570, 505443424, 765389542, 1167520901, 1767489774, 2687269981, 4087667292, 6191172292, 9429905660, 14304053742, 21717157486, 33041964314, 50089324714, 76188128751, 115695381422, 175580423245, 267067109929, 405169389470, 615724544610, 935544634439, 1419794811592, 2158559219718, 3276844251365, 4977316057057, 7563767095045, 11481121072018, 17448078893034, 26498111639030],
-[1, 1, 2, 2, 4, 8, 16, 16, 23, 46, 64, 106, 148, 214, 342, 494, 829, 1228, 1704, 2811, 4209, 6412, 9735, 14229, 22737, 33821, 51027, 79567, 116841, 180911, 275096, 413424, 638222, 950566, 1459455, 2229506, 3337556, 5137937, 7744535, 11763095, 17987687, 27067742, 41435554, 62743340, 95016060, 145328091, 219335401, 334142479, 507913212, 768780028, 1173070290, 1775746862, 2699254476, 4107621471, 6219141637, 9473315212, 14372113626, 21815388830, 33198574360, 50319750786, 76538841208, 116243638421, 176378742266, 268319204867, 407057696393, 618547050915, 939945910823, 1426326965936, 2168601444757, 3292138603316, 5000191205258, 7599196193338, 11534238170304, 17528871238111, 26621885173144],
-[1, 1, 2, 2, 4, 8, 16, 16, 21, 42, 64, 107, 140, 207, 340, 483, 810, 1187, 1672, 2722, 4044, 6334, 9470, 13765, 22143, 32840, 49867, 77127, 113472, 176669, 266462, 402701, 621716, 922567, 1421246, 2165161, 3249655, 5001020, 7515772, 11459360, 17488331, 26311340, 40337363, 60964126, 92471234, 141321708, 213218707, 325267703, 493634087, 747805448, 1141259380, 1726225357, 2626427310, 3993858639, 6048508400, 9216215504, 13972507784, 21223143373, 32286711438, 48931884568, 74458387076, 113032058136, 171559672620, 260971230436, 395834844113, 601693545550, 914067057421, 1387219309541, 2109314468683, 3201476583157, 4863543396932, 7390549406139, 11217580900278, 17049508145369, 25889764732655],
-[1, 1, 2, 3, 6, 7, 10, 16, 22, 41, 58, 93, 139, 197, 332, 469, 724, 1133, 1644, 2605, 3825, 5847, 9186, 13351, 20695, 31439, 47246, 73336, 108739, 166974, 255212, 381482, 589618, 885946, 1345386, 2061159, 3095463, 4747317, 7181653, 10871150, 16648984, 25089156, 38263511, 58161045, 87954016, 134347636, 203221481, 309001764, 470351112, 711632550, 1084771281, 1645115948, 2496936907, 3801409970, 5758909752, 8762638681, 13307396780, 20187863344, 30721361984, 46591422029, 70808575948, 107608839999, 163256915060, 248274935848, 376848751838, 572374468876, 869980228548, 1320260337032, 2006705005625, 3047521707137, 4627479674389, 7032920605318, 10676647826203, 16221367867909, 24641285021295],
-[1, 1, 2, 3, 6, 8, 14, 22, 28, 45, 70, 104, 153, 237, 376, 550, 814, 1288, 1941, 2880, 4422, 6760, 10297, 15423, 23546, 36193, 54176, 82595, 126014, 190626, 290004, 439369, 670099, 1016973, 1537992, 2348265, 3561196, 5400839, 8219095, 12466509, 18963994, 28772694, 43685826, 66483154, 100785620, 153188076, 232814094, 353280214, 537087722, 815224952, 1238619387, 1882093938, 2856272325, 4341846181, 6593304385, 10012086846, 15215102520, 23099824635, 35097473026, 53308389440, 80951620020, 123008549803, 186781196628, 283730170045, 431026226472, 654536748051, 994415844169, 1510259922424, 2293933082772, 3484796998417, 5292180199429, 8039481649419, 12211114870800, 18546348102877, 28173913235842],
-[1, 1, 2, 3, 6, 8, 13, 18, 25, 43, 67, 107, 144, 217, 364, 520, 790, 1196, 1822, 2835, 4176, 6456, 9859, 14575, 22665, 34225, 51765, 79225, 118853, 183037, 276527, 417534, 641593, 964664, 1471191, 2239368, 3383580, 5171507, 7813157, 11887626, 18113563, 27363102, 41738631, 63307126, 96046577, 146304996, 221521222, 337182195, 512067743, 776485830, 1182354248, 1792526993, 2724080747, 4140877410, 6280481815, 9553595871, 14499078226, 22020334841, 33477494986, 50792134335, 77210617689, 117260509992, 178029755732, 270613601894, 410770830814, 624122024418, 948128206812, 1439466885363, 2187586673605, 3321700109420, 5045486804000, 7665636373767, 11639356448612, 17684476268699, 26858611752843],
-[1, 1, 2, 2, 3, 6, 12, 13, 16, 26, 43, 70, 92, 141, 230, 344, 525, 767, 1151, 1795, 2684, 4204, 6281, 9303, 14569, 21896, 33304, 50639, 76082, 117285, 176685, 268504, 410804, 616463, 943444, 1433118, 2169425, 3311864, 4998096, 7622730, 11590787, 17528624, 26749776, 40504566, 61544925, 93681158, 141863694, 216045875, 327727455, 497472813, 757265543, 1147647169, 1745283647, 2651158144, 4022572297, 6119222292, 9283470937, 14106329944, 21436921598, 32527787520, 49456862284, 75083941086, 114030607868, 173306054735, 263052681280, 399761416676, 607145050967, 921932348108, 1401047694535, 2127158140379, 3231593489660, 4909090782023, 7454263300630, 11326240418342, 17200145712844],
-[1, 1, 2, 2, 3, 5, 9, 11, 14, 21, 38, 64, 76, 116, 191, 293, 457, 666, 973, 1502, 2331, 3665, 5271, 7842, 12483, 18846, 28498, 42873, 65058, 100237, 150353, 229975, 350087, 525156, 805652, 1223039, 1855176, 2820819, 4261412, 6517744, 9881143, 14963165, 22829607, 34547311, 52557137, 79894180, 121112870, 184415393, 279455219, 424783726, 646164731, 979252697, 1489754464, 2261606659, 3433866705, 5221654197, 7920872308, 12041715259, 18289208768, 27760597926, 42209135355, 64064956233, 97327640683, 147877584289, 224486451514, 341183188979, 518050743755, 786838110750, 1195594514876, 1815189581062, 2758006959938, 4188974951422, 6361598694006, 9665646803711, 14677483122945],
-[1, 1, 2, 2, 3, 6, 11, 13, 18, 33, 50, 74, 107, 163, 251, 407, 603, 851, 1289, 2058, 3235, 4670, 7015, 10902, 16542, 25175, 37972, 57760, 87779, 132821, 203783, 308288, 464972, 710261, 1079144, 1640253, 2486947, 3771676, 5750237, 8711898, 13237895, 20141467, 30518958, 46413806, 70488785, 107065161, 162733564, 246791209, 375302616, 570112554, 865212703, 1315373235, 1996703026, 3033457900, 4608657030, 6996610693, 10633804094, 16145320215, 24522340708, 37263220083, 56574760368, 85955732160, 130552833094, 198272268609, 301245560613, 457442156549, 694914916714, 1055583731964, 1603046888275, 2435414878782, 3698764055767, 5618176596235, 8534355186795, 12961281670940, 19689609568868],
-[1, 1, 2, 2, 3, 6, 11, 11, 15, 28, 38, 71, 98, 134, 208, 335, 519, 711, 1072, 1740, 2612, 3914, 6007, 8970, 13692, 20717, 31878, 48546, 71880, 111163, 169605, 255402, 389223, 587901, 899013, 1363456, 2061923, 3155118, 4765091, 7238827, 11036956, 16706299, 25436237, 38554994, 58575735, 89205582, 135030702, 205498259, 312170848, 473454741, 720498471, 1092809527, 1660945544, 2523660358, 3828569308, 5823906917, 8838931089, 13423419112, 20404838959, 30966030113, 47066432551, 71475124101, 108533165525, 164957006242, 250402533262, 380458660392, 577954856091, 877525368086, 1333469522102, 2024868251283, 3075774267120, 4672723818402, 7095339500314, 10780197201061, 16372588556693],
-[1, 1, 2, 2, 3, 6, 12, 12, 18, 33, 42, 70, 104, 153, 236, 358, 561, 814, 1177, 1919, 2898, 4332, 6634, 9843, 15318, 23083, 34932, 53836, 79736, 122912, 187864, 281988, 431828, 650065, 993674, 1514451, 2277679, 3490015, 5279520, 8007558, 12229394, 18469508, 28156516, 42718661, 64757924, 98805669, 149462237, 227344621, 345723116, 523788981, 797763630, 1209636380, 1837556295, 2794853993, 4236543378, 6445948472, 9785675299, 14853372321, 22590887053, 34271381444, 52091555371, 79130074299, 120102956721, 182604522599, 277169185030, 421060330991, 639805766770, 971188835703, 1476005053282, 2241366206407, 3404073773532, 5172542249168, 7853188417755, 11931859683326, 18123278842851],
-[1, 1, 2, 2, 3, 6, 12, 12, 18, 32, 41, 70, 102, 150, 227, 346, 545, 805, 1188, 1873, 2840, 4258, 6562, 9789, 14948, 22707, 34543, 53051, 78917, 120754, 185000, 278626, 425119, 642338, 978376, 1490840, 2250716, 3437288, 5208602, 7894378, 12044270, 18234695, 27739599, 42122622, 63879459, 97326901, 147468993, 224103832, 340793820, 516666341, 786085222, 1193070900, 1811786543, 2754692390, 4178626843, 6353356444, 9648967009, 14646644458, 22267439660, 33797958390, 51352165571, 78015079239, 118434033513, 180002622597, 273306713450, 415131944212, 630761153233, 957659392963, 1455087912631, 2209958059366, 3356313921904, 5099403863909, 7743464333015, 11763480983414, 17868466158544],
-[1, 1, 2, 2, 3, 6, 12, 12, 17, 30, 41, 68, 96, 146, 220, 333, 507, 761, 1152, 1757, 2604, 4015, 6226, 9155, 13979, 21272, 32505, 49565, 73905, 113703, 172645, 260822, 399036, 601865, 916797, 1394069, 2110644, 3222077, 4871243, 7403278, 11281629, 17070637, 25995488, 39443990, 59868080, 91137017, 138081865, 210071511, 319090231, 483964307, 736476932, 1117261055, 1697558964, 2579830286, 3914242815, 5952301068, 9035641920, 13722127641, 20857947260, 31655148476, 48108383574, 73067680434, 110945983859, 168611917159, 255981567116, 388902240059, 590792985742, 897041076619, 1363062921158, 2069923836638, 3144054109383, 4776508822961, 7253198878570, 11019444866535, 16736556199269],
-[1, 1, 2, 2, 3, 6, 12, 12, 17, 30, 42, 71, 96, 148, 240, 356, 546, 780, 1180, 1910, 2790, 4269, 6496, 9663, 15131, 22480, 34434, 52665, 78123, 121460, 183263, 276878, 424552, 636659, 977931, 1481851, 2236275, 3431087, 5166813, 7874636, 11994142, 18108002, 27671257, 41860805, 63611719, 96953000, 146529090, 223386547, 338970810, 514085183, 783209413, 1186135437, 1804496018, 2741658066, 4156992719, 6328525743, 9597283795, 14581318210, 22168387490, 33622521894, 51137302731, 77627303228, 117874753912, 179209891814, 271924843946, 413301184060, 627759643639, 953034563440, 1448631997586, 2199098230440, 3340937697239, 5075705722718, 7706010468695, 11710378044198, 17782648649556],
-[1, 1, 2, 2, 3, 6, 12, 12, 16, 30, 41, 72, 103, 152, 222, 341, 533, 776, 1168, 1789, 2707, 4173, 6393, 9452, 14433, 21992, 33635, 51206, 76469, 117469, 178467, 270102, 412416, 622218, 948258, 1441049, 2184050, 3331848, 5037586, 7657923, 11661904, 17661918, 26886871, 40782904, 61928407, 94239054, 142830114, 217271337, 329948921, 500619884, 761602312, 1155507111, 1755850777, 2667787522, 4048561971, 6155864731, 9344673380, 14193035823, 21569947618, 32740060781, 49755876018, 75565062469, 114750422761, 174376257406, 264745874449, 402223842106, 610991321480, 927781945863, 1409697167755, 2140755930945, 3251760756092, 4939875646657, 7501603564507, 11396629363250, 17309215911791],
-[1, 1, 2, 2, 3, 6, 11, 12, 18, 28, 36, 65, 92, 146, 219, 333, 503, 724, 1168, 1761, 2568, 3994, 6132, 9262, 14008, 21021, 32553, 49167, 73937, 113854, 171232, 261002, 397357, 600643, 917947, 1384978, 2109468, 3215934, 4853790, 7404362, 11233204, 17044585, 25958052, 39297854, 59837579, 90855579, 137749418, 209808921, 318055150, 483321886, 734694995, 1114329684, 1695230834, 2572416414, 3907006330, 5940218721, 9011666640, 13699638914, 20805569342, 31587618531, 48015609353, 72880925131, 110740092826, 168227317693, 255398401537, 388149848104, 589364802434, 895219221115, 1360119624756, 2065146712727, 3137788465507, 4765490984652, 7237694882533, 10996236507090, 16698216315382],
-[1, 1, 2, 2, 3, 6, 12, 12, 18, 30, 44, 70, 93, 142, 236, 342, 517, 779, 1150, 1824, 2677, 4152, 6386, 9292, 14643, 21952, 33203, 51154, 75908, 117742, 177639, 267510, 413098, 617698, 944809, 1439302, 2167299, 3325887, 5010891, 7625642, 11641877, 17539318, 26815936, 40630046, 61600197, 94009579, 142067442, 216480323, 328774560, 498053261, 759409335, 1150123268, 1748378915, 2658863380, 4029111277, 6134229197, 9305639973, 14131096907, 21494701816, 32591330293, 49564906943, 75268797203, 114245740754, 173734548608, 263615518621, 400599614833, 608626714979, 923779044305, 1404295571603, 2131942554650, 3238322145928, 4920727769239, 7469975525157, 11351523992156, 17239416407398],
-[1, 1, 2, 2, 3, 5, 10, 10, 14, 24, 30, 50, 76, 122, 167, 265, 414, 603, 907, 1355, 2119, 3207, 4798, 7319, 11100, 16770, 25679, 38968, 58902, 89503, 135845, 207645, 313597, 475633, 725908, 1098686, 1671645, 2537884, 3852618, 5860602, 8881366, 13513737, 20531499, 31130520, 47358286, 71886695, 109196888, 165935690, 251806874, 382853292, 581251318, 882643146, 1341690521, 2036414169, 3094256380, 4700165855, 7136968843, 10845562643, 16466553176, 25015432973, 38003271011, 57703683440, 87675698218, 133152687556, 202240280317, 307244446643, 466574837083, 708814914729, 1076593520531, 1635103463591, 2484021729092, 3772505646801, 5730483645857, 8704487436011, 13220191613768],
-[1, 1, 2, 2, 3, 6, 12, 12, 16, 30, 41, 66, 95, 151, 228, 335, 532, 758, 1127, 1813, 2695, 4124, 6213, 9312, 14535, 21542, 33052, 50633, 75179, 116212, 175729, 266633, 407271, 610712, 938700, 1422452, 2147842, 3290760, 4961538, 7560784, 11502585, 17391178, 26562154, 40171740, 61063157, 93034520, 140698462, 214392826, 325284747, 493598046, 751602866, 1138541902, 1732181533, 2631221696, 3990603040, 6073643722, 9212086084, 13997230144, 21275031905, 32275075077, 49083248448, 74506688452, 113149066695, 172000561772, 261015866880, 396706017744, 602519277041, 914818965743, 1390402981184, 2110787653561, 3206851209745, 4871694810544, 7396787504379, 11239927370716, 17068376083212],
-[1, 1, 2, 2, 3, 6, 12, 12, 17, 30, 44, 68, 92, 149, 236, 334, 537, 792, 1155, 1860, 2770, 4233, 6365, 9482, 15017, 22194, 33634, 51989, 77364, 119472, 180335, 272794, 418969, 626807, 962715, 1463031, 2201317, 3377683, 5096477, 7759387, 11817066, 17832724, 27271360, 41271077, 62633718, 95546430, 144416816, 220036471, 334054932, 506494721, 771783355, 1168741227, 1777602887, 2702143298, 4095680784, 6234915708, 9457745588, 14365902332, 21844337904, 33128362911, 50384190024, 76495765025, 116133457924, 176583707391, 267945971521, 407206298065, 618576673013, 939020951512, 1427366123955, 2166887370633, 3291736554432, 5001384776355, 7592855043343, 11538227578207, 17522185863615],
-[1, 1, 2, 2, 3, 6, 11, 11, 18, 32, 41, 58, 87, 147, 222, 331, 495, 776, 1162, 1770, 2669, 4021, 6185, 9319, 14298, 21570, 32603, 50055, 75432, 114770, 174661, 264503, 403155, 609914, 928117, 1413540, 2136905, 3254393, 4942218, 7499993, 11412164, 17294165, 26309809, 39963300, 60618500, 92245895, 139930215, 212585442, 323118245, 490297944, 745463068, 1131621627, 1718656899, 2612393498, 3964708238, 6025214769, 9151482236, 13895509540, 21117379506, 32061186491, 48708269359, 73993159261, 112350432159, 170716798829, 259250775488, 393775926559, 598231557788, 908437377047, 1380125814477, 2096176092954, 3183664917223, 4836592729930, 7345191400043, 11157741544716, 16948196399654],
-[1, 1, 2, 2, 4, 8, 15, 15, 23, 46, 63, 104, 149, 211, 335, 505, 821, 1186, 1679, 2805, 4221, 6284, 9562, 14190, 22427, 33504, 50530, 78432, 115719, 178743, 272770, 409182, 628780, 942382, 1444142, 2203385, 3303842, 5075476, 7667891, 11633199, 17779748, 26805456, 40942534, 62064172, 94031073, 143662445, 217055728, 330361765, 502379368, 760731131, 1159646731, 1756990012, 2669732702, 4061896653, 6153338721, 9367556349, 14217205695, 21578510837, 32829734113, 49783490700, 75693447098, 114974797067, 174476173547, 265352372685, 402668913399, 611775663245, 929653659595, 1410921573818, 2144724807076, 3256426140066, 4945734841240, 7515858494000, 11409315701747, 17336819990201, 26331919413410],
-[1, 1, 2, 2, 4, 8, 16, 16, 23, 46, 64, 107, 151, 214, 340, 503, 833, 1221, 1702, 2803, 4236, 6408, 9737, 14259, 22621, 33899, 51131, 79419, 116808, 180624, 275569, 413643, 636891, 951568, 1458640, 2228577, 3339737, 5133095, 7747171, 11758014, 17980878, 27084934, 41400770, 62741999, 95021909, 145256610, 219385243, 333997173, 507851749, 768814167, 1172516509, 1775945103, 2698640196, 4106491528, 6219370677, 9470196462, 14371415437, 21812087660, 33189623695, 50319545559, 76518875636, 116227739844, 176361782141, 268251583465, 407028450661, 618426874944, 939784925149, 1426187762736, 2168114374145, 3291770715215, 4999404721501, 7597764316026, 11532974695517, 17525486448772, 26618108015658],
-[1, 1, 2, 2, 4, 8, 16, 16, 22, 44, 64, 108, 139, 206, 365, 516, 817, 1188, 1727, 2869, 4170, 6424, 9790, 14207, 22832, 33983, 51218, 79360, 116677, 182447, 275531, 412960, 640335, 952275, 1463591, 2232157, 3343849, 5153945, 7746498, 11793970, 18039378, 27096344, 41528809, 62850645, 95235196, 145646104, 219619856, 334990780, 508859534, 770104975, 1175851551, 1778770215, 2704936307, 4115681979, 6230606390, 9494790408, 14397178309, 21859214234, 33268820178, 50410835894, 76700763682, 116466414814, 176724477402, 268876136946, 407813939458, 619826837424, 941802789228, 1429059487818, 2173081430129, 3298458226685, 5010211265799, 7614396129732, 11556503378551, 17564589862874, 26673752871346],
-[1, 1, 2, 2, 4, 8, 16, 16, 22, 44, 64, 104, 144, 212, 338, 488, 817, 1197, 1670, 2730, 4123, 6323, 9508, 13954, 22236, 33067, 50073, 77710, 114450, 177074, 268861, 405604, 624244, 930124, 1429349, 2180045, 3270589, 5027043, 7577101, 11523148, 17593702, 26506553, 40561756, 61385940, 93052364, 142193015, 214720273, 327137451, 496953542, 752760562, 1148065114, 1738025512, 2642692209, 4019576351, 6088484745, 9272542616, 14066076129, 21357449989, 32490497104, 49256759149, 74922033822, 113768969546, 172664332119, 262618722748, 398435603774, 605489542370, 919960354987, 1396214508263, 2122643313764, 3222320171125, 4894591271521, 7437874023680, 11290287127459, 17157827043012, 26057219339769],
-[1, 1, 2, 2, 4, 8, 16, 16, 23, 46, 64, 103, 136, 211, 370, 501, 801, 1208, 1741, 2887, 4143, 6382, 9817, 14226, 22981, 33925, 50993, 79734, 116964, 182613, 275535, 412667, 642236, 952856, 1464627, 2236651, 3342159, 5161772, 7757350, 11801557, 18065124, 27100536, 41587091, 62935843, 95273094, 145853982, 219782384, 335305414, 509509362, 770615940, 1177309669, 1780332653, 2707243731, 4120941414, 6235354896, 9504834616, 14412077726, 21877879677, 33307265820, 50454794873, 76777413345, 116590700414, 176877249472, 269168772753, 408203993504, 620416066756, 942800792701, 1430364097578, 2175328283871, 3301723664606, 5014934839627, 7622354776567, 11567435793474, 17582108209116, 26700651914223],
-[1, 1, 2, 2, 4, 8, 15, 15, 21, 42, 64, 106, 137, 201, 341, 504, 788, 1146, 1692, 2737, 4033, 6206, 9474, 13858, 21801, 32785, 49796, 76451, 113052, 175618, 266117, 400427, 616390, 922336, 1413020, 2151866, 3239415, 4970869, 7488787, 11395830, 17400653, 26227321, 40077287, 60707536, 92085212, 140533718, 212352927, 323539721, 491400720, 744507709, 1135018434, 1719114878, 2613432826, 3973937148, 6022338927, 9168960839, 13909333925, 21121786479, 32126720282, 48715714021, 74085273559, 112501679647, 170765979090, 259678165951, 394026624246, 598778303085, 909706486884, 1380789883271, 2098976607318, 3186606425473, 4840370313668, 7354942114988, 11165408608167, 16967097331727, 25767679195549],
-[1, 1, 2, 2, 4, 7, 14, 14, 21, 40, 52, 85, 126, 188, 286, 421, 679, 1023, 1469, 2323, 3503, 5323, 8195, 12090, 18766, 28216, 42827, 66312, 98161, 150641, 229868, 346422, 531506, 797989, 1218524, 1858956, 2797564, 4286373, 6481971, 9831834, 15011334, 22672209, 34585141, 52457348, 79491749, 121301594, 183524868, 279181365, 424455519, 643074568, 979566247, 1485148630, 2256240249, 3431712372, 5201552171, 7914402693, 12014836661, 18237929829, 27737960026, 42077276490, 63960183042, 97158049639, 147464083868, 224207770481, 340310010554, 516990693870, 785564842101, 1192439874925, 1812294537427, 2751968197362, 4179590032748, 6350991148324, 9642265086199, 14650260628312, 22252047607505],
-[1, 1, 2, 2, 4, 8, 16, 16, 23, 46, 64, 99, 141, 217, 349, 483, 799, 1203, 1689, 2761, 4100, 6273, 9537, 13997, 22356, 33053, 49906, 77935, 114700, 177386, 269074, 405216, 625671, 931387, 1430683, 2183849, 3270269, 5034279, 7588041, 11532480, 17620683, 26518425, 40611879, 61475306, 93118226, 142390032, 214909371, 327471827, 497630018, 753389330, 1149503994, 1739835876, 2645221676, 4024835962, 6094189652, 9283019732, 14082008891, 21378197015, 32530183807, 49306777540, 75002859073, 113900587584, 172836241063, 262923140505, 398863077437, 606127256836, 921015661438, 1397655559930, 2125015937897, 3225858140060, 4899731949505, 7446287962388, 11302255601039, 17176562993469, 26086080818675],
-[1, 1, 2, 2, 4, 8, 16, 16, 21, 42, 64, 108, 141, 204, 339, 498, 815, 1191, 1680, 2703, 4098, 6357, 9579, 13859, 22025, 33219, 50219, 77373, 114050, 176950, 268759, 404743, 623413, 929503, 1425320, 2176307, 3269923, 5018582, 7561567, 11503230, 17575773, 26473346, 40479004, 61307143, 92923020, 141947682, 214432226, 326645674, 496215318, 751580825, 1146278444, 1735766911, 2638464887, 4013290985, 6079961059, 9258176647, 14045448275, 21325082969, 32441473332, 49185288824, 74805200592, 113602514706, 172409084494, 262213713284, 397851529400, 604574532799, 918586812911, 1394138217946, 2119410115462, 3217577164276, 4887217645323, 7426667033615, 11273589739467, 17131861128596, 26018395738673],
-[1, 1, 2, 2, 4, 8, 16, 16, 25, 50, 64, 105, 155, 221, 357, 521, 852, 1248, 1744, 2946, 4387, 6501, 10026, 14788, 23476, 34902, 52431, 82199, 120516, 186325, 284850, 425563, 657213, 982320, 1505204, 2301477, 3438497, 5298468, 8000292, 12119608, 18562570, 27929834, 42710791, 64759592, 97978571, 149940290, 226284018, 344469854, 524220560, 792913796, 1209813597, 1832275182, 2783522950, 4237954062, 6415066530, 9770479586, 14828908529, 22498236005, 34247172020, 51911372431, 78939064033, 119924666597, 181924930740, 276776154996, 419927637269, 637970466045, 969659994793, 1471271191115, 2236854791413, 3396190302190, 5157483685790, 7838985708941, 11898021597841, 18080635723529, 27462641561239],
-[1, 1, 2, 2, 4, 8, 16, 16, 25, 50, 64, 102, 150, 221, 357, 506, 829, 1256, 1749, 2893, 4287, 6430, 10002, 14604, 23161, 34487, 51803, 81439, 119238, 184083, 281324, 420510, 650956, 971348, 1486398, 2276165, 3399665, 5239747, 7909784, 11978775, 18358134, 27605448, 42230710, 64047184, 96838929, 148252159, 223723481, 340574197, 518354386, 783787326, 1196233060, 1811607233, 2751740545, 4190498603, 6342045900, 9659878034, 14661706408, 22242318901, 33862125429, 51321891247, 78044065704, 118574426929, 179857450655, 273651583120, 415176092303, 630736043047, 958721869993, 1454580585173, 2211568326724, 3357800537099, 5098991320420, 7750469579119, 11763266250658, 17875989616678, 27152247187703],
-[1, 1, 2, 3, 6, 8, 12, 17, 28, 47, 62, 98, 158, 221, 354, 529, 778, 1228, 1809, 2854, 4258, 6297, 10024, 14747, 22475, 34596, 51652, 79953, 119524, 182067, 280347, 417585, 642034, 973980, 1470148, 2255234, 3392468, 5181734, 7879021, 11886422, 18206851, 27508647, 41792391, 63710495, 96283918, 146888679, 222666972, 337824865, 514913298, 779204948, 1186055215, 1801858570, 2731631933, 4159520845, 6305840803, 9584035719, 14569338499, 22091959869, 33611759486, 51011902706, 77464065300, 117780560910, 178687579733, 271637030646, 412534328925, 626296907261, 952088683649, 1445100149186, 2195661997226, 3335693419812, 5064061285128, 7696184441141, 11685971467600, 17750253124407, 26968870134266],
-[1, 1, 2, 3, 6, 8, 14, 20, 29, 55, 74, 106, 164, 246, 396, 576, 860, 1346, 1971, 3101, 4751, 6933, 10787, 16180, 24902, 38018, 56272, 87493, 131769, 199475, 306729, 459049, 703521, 1066961, 1613507, 2474696, 3721273, 5673372, 8645507, 13049648, 19945103, 30172964, 45839618, 69859870, 105604443, 161037630, 244283376, 370429646, 564488156, 854890295, 1300506971, 1975855201, 2995890855, 4561059973, 6916504189, 10508934458, 15977189130, 24230657084, 36854478509, 55946456050, 84952578846, 129156143762, 195971667050, 297869116016, 452425858304, 686842152620, 1044036060327, 1584875512873, 2407801365209, 3658057061843, 5553684096769, 8439686402849, 12815835711559, 19465589307746, 29574992816944],
-[1, 1, 2, 3, 6, 8, 13, 19, 29, 55, 70, 98, 163, 240, 377, 555, 817, 1303, 1906, 2993, 4573, 6571, 10421, 15697, 23800, 36547, 54053, 84328, 126939, 191054, 295785, 441047, 675335, 1028858, 1549497, 2380466, 3578249, 5451788, 8328262, 12532577, 19179949, 29040682, 44036674, 67231498, 101529813, 154827172, 235039925, 355979676, 543107103, 822121898, 1250079580, 1901014296, 2880084282, 4386535597, 6652193196, 10102823884, 15369228792, 23297522721, 35439530231, 53811824814, 81675571137, 124220772599, 188452883390, 286419641740, 435140120918, 660410001370, 1004056079399, 1524152667732, 2315227130514, 3518136140564, 5340370265359, 8116003373203, 12324989156769, 18717698940845, 28442439390615],
-[1, 1, 2, 2, 3, 6, 12, 12, 18, 34, 40, 64, 107, 163, 225, 344, 552, 798, 1178, 1877, 2872, 4207, 6443, 9882, 15008, 22484, 34277, 52864, 79022, 120201, 184059, 278156, 422396, 640482, 977016, 1483465, 2241445, 3422195, 5195827, 7871432, 11980880, 18180911, 27642039, 41952077, 63680035, 96931177, 146964196, 223268234, 339465509, 515076235, 782919426, 1188642363, 1805633344, 2744023113, 4164227579, 6329387574, 9613415785, 14595830432, 22181462794, 33679226778, 51165584493, 77721099673, 118017516478, 179329992252, 272320194427, 413630801108, 628402820989, 954258985158, 1449712329503, 2201865050507, 3344259477967, 5080481334873, 7715552924098, 11720473302199, 17802869816030],
-[1, 1, 2, 2, 3, 6, 12, 12, 19, 34, 42, 63, 96, 153, 242, 346, 515, 780, 1168, 1884, 2759, 4118, 6410, 9505, 14870, 22256, 33372, 51776, 77182, 118910, 180493, 270336, 416881, 627198, 956321, 1458542, 2191643, 3361935, 5085842, 7713134, 11783070, 17767519, 27117767, 41171255, 62347504, 95154890, 143906519, 218963380, 333030589, 504287093, 768430905, 1164889033, 1769348204, 2692173742, 4079642089, 6207862660, 9424030362, 14303241388, 21759424276, 33002250926, 50165477597, 76211602485, 115655448954, 175867109386, 266923781833, 405490420963, 616193536027, 935248431389, 1421510305118, 2158566509642, 3278154966470, 4981594759746, 7562819446981, 11490994616424, 17453852703751],
-[1, 1, 2, 2, 3, 6, 12, 12, 17, 32, 40, 67, 99, 157, 236, 353, 561, 812, 1195, 1905, 2861, 4326, 6589, 9822, 15275, 22872, 34809, 53483, 79483, 122607, 186083, 280941, 429949, 646474, 989951, 1503921, 2268256, 3473713, 5247803, 7977962, 12156627, 18375361, 28034191, 42475180, 64474264, 98272154, 148683686, 226342262, 343790332, 521319539, 793791713, 1203189441, 1829069977, 2780041469, 4215685816, 6414415045, 9733880438, 14782884104, 22474950167, 34097764955, 51838533478, 78718701328, 119516090238, 181685164795, 275756582186, 419004125579, 636524340617, 966362178996, 1468654514882, 2229940943425, 3387293308371, 5146336889634, 7813806577593, 11872566994678, 18031248491924],
-[1, 1, 2, 2, 3, 6, 12, 12, 19, 34, 41, 72, 115, 165, 233, 377, 601, 843, 1256, 2035, 3098, 4526, 6971, 10661, 16013, 24146, 37182, 57015, 84720, 129263, 198744, 299835, 454213, 689958, 1052527, 1596535, 2415348, 3688160, 5594461, 8472652, 12906208, 19595571, 29763713, 45168817, 68605587, 104406384, 158284555, 240473660, 365641796, 554787605, 843157624, 1280385634, 1944947488, 2955165269, 4485277025, 6817435196, 10354504227, 15720738953, 23890271668, 36276797495, 55108589621, 83709759866, 127118626707, 193148704863, 293309432170, 445514840412, 676835655241, 1027826612221, 1561425287978, 2371587575150, 3602071852382, 5471997823495, 8310315905897, 12623846466654, 19175021717080],
-[1, 1, 2, 2, 3, 6, 12, 13, 18, 31, 45, 69, 96, 156, 250, 347, 544, 818, 1228, 1948, 2852, 4349, 6607, 9976, 15664, 23016, 34898, 54072, 80928, 124358, 187103, 283810, 436268, 653430, 1001436, 1520766, 2292643, 3514162, 5304819, 8077943, 12290707, 18562270, 28377351, 42955363, 65201211, 99386018, 150320053, 229004543, 347598665, 527208861, 803030697, 1216363250, 1850031824, 2811726789, 4263021542, 6487924313, 9842238953, 14951858216, 22730988424, 34478660133, 52432608064, 79605067565, 120867632269, 183755668620, 278856192784, 423779261390, 643713930685, 977274284985, 1485394438259, 2255056685861, 3425733420732, 5204683174537, 7902017543124, 12007537197535, 18234894935095],
-[1, 1, 2, 2, 3, 6, 12, 12, 15, 28, 40, 65, 94, 156, 223, 323, 541, 787, 1156, 1775, 2701, 4235, 6232, 9360, 14575, 21634, 33381, 50884, 76030, 116846, 175984, 269694, 410125, 614227, 945399, 1430250, 2167724, 3310341, 4993217, 7626089, 11562548, 17522643, 26760160, 40426353, 61531655, 93604318, 141741170, 215980481, 327286471, 497356886, 756673597, 1146317129, 1744959666, 2648466181, 4019718302, 6115527448, 9274494578, 14100214492, 21417351743, 32502934591, 49430822693, 75012326032, 113962856274, 173178487043, 262838345393, 399522933536, 606626409674, 921319566423, 1400071469977, 2125399008320, 3229568130352, 4905244751416, 7448810641195, 11318541496740, 17186466905865],
-[1, 1, 2, 2, 3, 6, 10, 11, 17, 28, 36, 58, 91, 152, 208, 331, 507, 714, 1148, 1722, 2593, 3968, 5971, 9254, 13761, 20728, 32317, 48302, 73347, 112093, 169179, 259359, 389921, 594117, 908246, 1364890, 2088961, 3168257, 4799129, 7322296, 11066977, 16874753, 25616217, 38787333, 59184913, 89617601, 136193720, 207178643, 313925640, 477963661, 724967686, 1100953735, 1675001967, 2539021901, 3861654215, 5864879389, 8900871683, 13536585428, 20538551430, 31212372106, 47421618918, 71970457812, 109417388882, 166107811720, 252300535329, 383399914625, 582001077225, 884443253857, 1343182352857, 2039808751083, 3099593237707, 4706137838670, 7149779279566, 10860519853835, 16492371014531],
-[1, 1, 2, 2, 3, 6, 12, 12, 18, 31, 42, 64, 88, 148, 248, 347, 522, 790, 1190, 1899, 2808, 4240, 6463, 9696, 15221, 22736, 34097, 52576, 78984, 121502, 183773, 276775, 425337, 639919, 977398, 1488332, 2241195, 3429819, 5190012, 7888437, 12021657, 18150435, 27698645, 42021569, 63714526, 97124953, 146990951, 223677233, 339895130, 515251755, 784662720, 1189452678, 1807427281, 2748470366, 4167275786, 6339590179, 9622455697, 14610894913, 22217153352, 33704995838, 51235579138, 77818386998, 118129696212, 179586865040, 272591012067, 414143532129, 629205106265, 955193448792, 1451685470734, 2204311004135, 3348047216750, 5087058237651, 7723709175221, 11735247448406, 17823720535883],
-[1, 1, 2, 2, 3, 6, 12, 12, 17, 30, 43, 69, 96, 151, 232, 347, 558, 802, 1176, 1902, 2825, 4321, 6461, 9691, 15388, 22680, 34552, 53029, 78930, 122414, 184146, 279409, 428159, 640377, 986060, 1494208, 2252840, 3455316, 5205760, 7942195, 12079190, 18238454, 27899953, 42172229, 64091893, 97695561, 147650143, 225123422, 341458761, 518082578, 789303628, 1194905830, 1818465358, 2762590965, 4188644020, 6377065589, 9669649152, 14694090798, 22336923899, 33877067589, 51532316289, 78215480136, 118775257560, 180580788895, 273991638877, 416474112055, 632527266043, 960312301791, 1459731921675, 2215796694978, 3366518874254, 5114424392852, 7764739894021, 11799983412304, 17917998329505],
-[1, 1, 2, 2, 3, 6, 11, 11, 15, 28, 40, 62, 90, 151, 217, 316, 510, 744, 1108, 1706, 2622, 4053, 5916, 9053, 14048, 20801, 32012, 48645, 73451, 112219, 168986, 259576, 392915, 591419, 908691, 1373188, 2085344, 3175466, 4803084, 7332450, 11097361, 16854772, 25697423, 38848545, 59161120, 89875134, 136287820, 207482927, 314420275, 478202985, 726716828, 1101778129, 1676837089, 2544246436, 3864126682, 5874817948, 8912560586, 13551196237, 20574904884, 31240109838, 47494102652, 72076700632, 109525804670, 166381122404, 252596154876, 383904279040, 582866701807, 885425072675, 1345216527549, 2042407228422, 3103458974072, 4713158344446, 7158277083464, 10875682389946, 16514706631732],
-[1, 1, 2, 2, 3, 6, 11, 11, 18, 32, 40, 57, 93, 146, 203, 301, 493, 753, 1045, 1666, 2569, 3758, 5861, 8788, 13473, 20222, 30509, 47792, 70819, 107382, 165552, 248639, 380285, 574388, 873322, 1335564, 2005595, 3070769, 4666779, 7044105, 10766648, 16288759, 24789150, 37682937, 57012167, 87027906, 131815288, 200110634, 304794536, 461549926, 702483099, 1066408337, 1618349076, 2463063941, 3733339435, 5676616424, 8626007674, 13084550514, 19902872422, 30203724658, 45883061489, 69734734505, 105816140291, 160870853772, 244262587553, 370914165749, 563765983687, 855749155555, 1300309509456, 1975104085865, 2999006164785, 4557463609439, 6919813170024, 10511859983207, 15969453508684],
-[1, 1, 2, 2, 3, 6, 12, 12, 18, 34, 40, 61, 95, 159, 239, 330, 533, 823, 1204, 1896, 2804, 4258, 6530, 9755, 15266, 22618, 34204, 53187, 79174, 121491, 184071, 277988, 427284, 640803, 980674, 1492985, 2245326, 3443665, 5206623, 7908046, 12053696, 18198652, 27802844, 42134507, 63872174, 97449652, 147385597, 224353882, 340911723, 516667946, 787085086, 1192683435, 1812882920, 2756880527, 4178467259, 6358818323, 9650409442, 14653089238, 22283756187, 33799522630, 51388939874, 78045149400, 118466942543, 180126806458, 273369450569, 415354713786, 631058625147, 957937508288, 1455990249051, 2210673080075, 3357796110877, 5102071317754, 7745938798047, 11769769667916, 17875664547645],
-[1, 1, 2, 2, 3, 6, 12, 12, 16, 28, 41, 67, 87, 143, 232, 334, 527, 755, 1138, 1823, 2671, 4158, 6215, 9252, 14643, 21649, 33135, 50606, 75344, 117219, 175732, 266972, 409605, 611855, 942317, 1426387, 2154840, 3304551, 4969289, 7593395, 11544744, 17428017, 26669053, 40289610, 61271869, 93354075, 141075378, 215234465, 326248626, 495114795, 754405402, 1141805073, 1738166615, 2639803306, 4003033842, 6095215633, 9239647340, 14044301614, 21346933807, 32373782079, 49252568149, 74743540679, 113516895810, 172579837973, 261832991187, 398045625259, 604471925384, 917753320185, 1395084978804, 2117510389450, 3217441262710, 4887698237987, 7420551324628, 11277386053074, 17123401400412],
-[1, 1, 2, 2, 3, 5, 9, 11, 15, 24, 37, 60, 80, 120, 194, 289, 457, 674, 968, 1588, 2377, 3568, 5477, 8047, 12682, 19104, 28606, 44346, 65778, 101461, 154569, 231370, 356841, 535467, 817107, 1248794, 1873913, 2875865, 4347617, 6591784, 10081694, 15193042, 23193353, 35205305, 53291160, 81403781, 123060992, 187226968, 284814958, 431136246, 657239331, 996156438, 1512887620, 2302489368, 3488156236, 5308915402, 8059386359, 12230099684, 18608900568, 28219808142, 42898904947, 65175588237, 98894112908, 150398942257, 228254140517, 346743228788, 526958746407, 799733792240, 1215617279590, 1845889238440, 2803209111041, 4260119762456, 6467125190397, 9826445232154, 14925734148068],
-[1, 1, 2, 2, 3, 6, 12, 12, 17, 32, 41, 64, 92, 155, 244, 333, 536, 797, 1176, 1908, 2764, 4227, 6451, 9600, 15223, 22335, 33969, 52749, 77919, 120781, 182023, 274994, 424216, 632307, 972539, 1477877, 2220124, 3417169, 5142773, 7832552, 11944388, 17992421, 27560084, 41673549, 63228546, 96557018, 145746965, 222276691, 337426424, 511260943, 779760933, 1179977006, 1795220091, 2729483936, 4134738556, 6298295012, 9550409613, 14506318387, 22065323123, 33448725172, 50887845029, 77253684095, 117267236154, 178358337313, 270563051613, 411241678531, 624734127721, 948201600258, 1441616157528, 2188218423594, 3324210123507, 5051165505935, 7667354523479, 11652863284042, 17695373417706],
-[1, 1, 2, 2, 4, 8, 16, 16, 23, 46, 64, 106, 147, 214, 351, 504, 814, 1200, 1716, 2841, 4181, 6352, 9731, 14257, 22651, 33716, 50948, 79239, 116546, 180782, 274574, 411935, 636271, 949509, 1456443, 2222484, 3330112, 5126611, 7725586, 11735672, 17946635, 27006595, 41326850, 62596315, 94818840, 144965037, 218801761, 333371083, 506730768, 766994231, 1170215838, 1771644855, 2692956154, 4097670216, 6204855740, 9451004607, 14337916292, 21764277594, 33119963310, 50202798711, 76357405300, 115967998122, 175968730497, 267682576212, 406100520802, 617095277691, 937722898390, 1422984869083, 2163474999138, 3284398584239, 4988456721272, 7581209033227, 11507179421174, 17487590400256, 26559110491986],
-[1, 1, 2, 2, 4, 8, 15, 16, 24, 44, 60, 99, 143, 214, 339, 492, 805, 1155, 1678, 2769, 4098, 6205, 9405, 14074, 22174, 32745, 49876, 77179, 114044, 176565, 267539, 403621, 619798, 927371, 1425584, 2165425, 3255491, 5004063, 7543076, 11475427, 17497610, 26397417, 40366708, 61073721, 92691615, 141476878, 213700995, 325659755, 494547463, 749532477, 1142407277, 1729856268, 2631051564, 3999806272, 6061165703, 9229287421, 13999062711, 21261156682, 32334716845, 49031916230, 74575234428, 113225365910, 171882382559, 261379882763, 396579814939, 602703870299, 915606298866, 1389808589184, 2112709904957, 3207248517156, 4872082737397, 7402838165120, 11238047599491, 17078091759057, 25935153926641],
-[1, 1, 2, 2, 4, 8, 16, 16, 23, 46, 64, 100, 141, 214, 349, 494, 803, 1215, 1701, 2742, 4144, 6323, 9651, 14058, 22307, 33485, 50269, 78254, 115446, 178049, 271349, 407541, 628719, 938743, 1436281, 2197890, 3293626, 5058406, 7639437, 11591998, 17729445, 26697996, 40810137, 61884162, 93662725, 143185950, 216327586, 329257204, 500724215, 757920463, 1155963409, 1751064845, 2660204613, 4048848354, 6131864734, 9335786120, 14169488554, 21503670921, 32721990277, 49610432433, 75435197831, 114594320664, 173870075566, 264462436669, 401300272668, 609682514542, 926542355753, 1406064433063, 2137497002904, 3245410601321, 4928760081240, 7490625294641, 11370375417182, 17277941987406, 26242961873413],
-[1, 1, 2, 2, 4, 8, 16, 16, 22, 44, 64, 102, 133, 209, 366, 496, 784, 1177, 1716, 2793, 4021, 6277, 9625, 13925, 22319, 33070, 50007, 77700, 114227, 178218, 268579, 403735, 626733, 930475, 1429804, 2180592, 3268042, 5038021, 7569076, 11528273, 17625406, 26473059, 40598087, 61419604, 93064096, 142322024, 214607957, 327428707, 497235685, 752567684, 1149168046, 1738122840, 2643567006, 4022043231, 6088658330, 9279023733, 14068748284, 21362829305, 32512116559, 49261465055, 74958317839, 113813578920, 172703991778, 262761827318, 398523401687, 605736959241, 920359935857, 1396534203334, 2123674069131, 3223335514212, 4896250296365, 7441132270982, 11293439033324, 17165121721590, 26066488139467],
-[1, 1, 2, 2, 4, 8, 16, 16, 21, 42, 64, 105, 128, 201, 368, 501, 788, 1173, 1718, 2794, 4018, 6324, 9678, 13879, 22363, 33246, 50201, 77797, 114234, 178989, 269238, 404343, 628916, 932091, 1432972, 2185941, 3276119, 5052464, 7580644, 11556348, 17676689, 26524958, 40700173, 61560184, 93279906, 142683924, 215062707, 328269451, 498397835, 754203816, 1152134978, 1742062311, 2649829762, 4031689455, 6102465776, 9302101384, 14100969482, 21412931905, 32591535482, 49373467648, 75139319874, 114082292046, 173106353342, 263395965284, 399444555375, 607184118076, 922547142015, 1399778004659, 2128775536875, 3230882671483, 4907813859864, 7458855079845, 11319822845568, 17206055002139, 26127872498323],
-[1, 1, 2, 2, 4, 8, 16, 16, 24, 48, 64, 97, 145, 222, 356, 499, 811, 1198, 1693, 2831, 4224, 6306, 9575, 14287, 22764, 33571, 50462, 78935, 116605, 179873, 273560, 411033, 633211, 946203, 1452576, 2216207, 3316743, 5103320, 7707876, 11699720, 17872286, 26920990, 41184398, 62387676, 94493507, 144454033, 218119577, 332134471, 504990294, 764596857, 1166007716, 1765644461, 2683722361, 4083699788, 6184306094, 9417419954, 14290086709, 21690666106, 33003905839, 50035966568, 76094193363, 115572085239, 175372592691, 266756817649, 404739043783, 614973574233, 934505733518, 1418192527931, 2156016329482, 3273255183648, 4971451797240, 7555215611590, 11468226143437, 17427548996610, 26468735474778],
-[1, 1, 2, 2, 4, 8, 15, 15, 23, 46, 64, 99, 138, 211, 357, 502, 772, 1156, 1701, 2779, 4057, 6139, 9502, 13945, 21997, 32888, 49563, 76910, 113602, 175985, 267404, 400580, 618586, 925944, 1416621, 2162150, 3243120, 4985525, 7522310, 11420672, 17465810, 26294807, 40192602, 60944420, 92302220, 141031499, 213007869, 324382635, 493217139, 746570566, 1138612159, 1724670423, 2620609074, 3987757577, 6039974785, 9196633257, 13955508227, 21181817495, 32231599213, 48865901999, 74306429860, 112869055117, 171268442465, 260499445013, 395266389091, 600561966142, 912628285189, 1384974971185, 2105471466328, 3196671351386, 4854949617836, 7378196745353, 11199771802992, 17019144711174, 25848945778130],
-[1, 1, 2, 2, 4, 8, 15, 15, 21, 42, 63, 101, 135, 204, 341, 492, 784, 1127, 1645, 2716, 4012, 6124, 9239, 13677, 21742, 32229, 48908, 75474, 111593, 173392, 262155, 395244, 608128, 908059, 1396236, 2123443, 3191140, 4904302, 7386022, 11248161, 17158473, 25855268, 39561611, 59854853, 90825784, 138666189, 209390030, 319188755, 484629786, 734390324, 1119804364, 1695102307, 2578237111, 3919981884, 5939391000, 9045364560, 13718083699, 20834975187, 31690004831, 48046282499, 73085062351, 110960648260, 168434203841, 256158303091, 388626370812, 590648030293, 897292385915, 1361930746269, 2070488081215, 3143000515340, 4774506677183, 7254794411332, 11012816190291, 16736464797886, 25415879924842],
-[1, 1, 2, 2, 4, 7, 13, 14, 19, 37, 54, 90, 120, 175, 282, 407, 684, 999, 1402, 2287, 3434, 5297, 7955, 11608, 18563, 27685, 41913, 64912, 95549, 148165, 224434, 338637, 522032, 776718, 1193793, 1821196, 2733182, 4201212, 6325615, 9627707, 14702301, 22136976, 33890392, 51278603, 77736243, 118796922, 179349670, 273343037, 415126142, 628783694, 959241435, 1451830841, 2207758088, 3357972776, 5086056312, 7746952598, 11749994485, 17842177584, 27143811252, 41146417592, 62592576221, 95041550563, 144241882510, 219398296780, 332840120136, 505839315244, 768535519302, 1166369780942, 1773306562949, 2691873607409, 4088958531476, 6213655351561, 9431743367609, 14333891693969, 21767988110104],
-[1, 1, 2, 2, 4, 8, 15, 15, 21, 42, 63, 102, 136, 204, 339, 489, 784, 1144, 1654, 2681, 3979, 6157, 9280, 13590, 21598, 32241, 48912, 75316, 111354, 172948, 261481, 394560, 607487, 906186, 1391889, 2119555, 3187186, 4893996, 7369264, 11225008, 17126769, 25803860, 39479835, 59744882, 90636474, 138374257, 209000352, 318563347, 483649277, 732884387, 1117589764, 1691849838, 2572966776, 3912169418, 5927728651, 9027081514, 13690996341, 20793510322, 31626947587, 47950704576, 72938040597, 110742377220, 168098844128, 255644336012, 387857456268, 589468590077, 895509161757, 1359220783287, 2066353384205, 3136771056600, 4764969655422, 7240353550509, 10990957700461, 16703037676531, 25365368092391],
-[1, 1, 2, 2, 4, 8, 16, 16, 23, 46, 64, 102, 141, 214, 355, 494, 799, 1207, 1717, 2792, 4100, 6302, 9708, 14096, 22411, 33363, 50400, 78584, 115354, 178866, 271537, 407679, 630997, 939536, 1440536, 2200848, 3296194, 5076311, 7645966, 11615185, 17771210, 26723959, 40917322, 61974770, 93833784, 143519775, 216573466, 330024206, 501648831, 759099269, 1158564707, 1753689109, 2665585077, 4056716261, 6141582346, 9355892981, 14193137212, 21543401752, 32787915980, 49692343016, 75586634385, 114800685696, 174182073425, 264989004977, 401989726449, 610855503699, 928276438828, 1408558482280, 2141668173234, 3251209908212, 4937965648613, 7504802819797, 11390707438268, 17311019118319, 26290942780432],
-[1, 1, 2, 3, 6, 8, 15, 22, 27, 43, 66, 111, 166, 230, 391, 563, 838, 1365, 1969, 3045, 4509, 6912, 10999, 15802, 24382, 37535, 55865, 86838, 129463, 197572, 302688, 451653, 699746, 1053668, 1589730, 2445940, 3674935, 5622401, 8526313, 12878458, 19749459, 29763965, 45324875, 69052791, 104249647, 159238679, 241164024, 366213822, 557969492, 843785207, 1285944453, 1951914952, 2959580938, 4508395872, 6830417855, 10387274167, 15785097133, 23935556326, 36429628292, 55259058178, 83945061308, 127629826659, 193588087796, 294378798288, 446951378109, 678639941074, 1031710737728, 1565644845915, 2379369043188, 3614247954476, 5486965064786, 8339853514073, 12661381713598, 19234165387417, 29222109531082],
-[1, 1, 2, 3, 6, 8, 13, 18, 26, 46, 67, 111, 150, 212, 365, 542, 798, 1230, 1847, 2887, 4225, 6472, 10178, 14753, 22760, 34972, 52588, 80715, 120295, 185289, 282364, 422237, 651585, 982086, 1488927, 2277892, 3433168, 5251367, 7943136, 12038843, 18422605, 27786722, 42324644, 64364560, 97422452, 148603814, 224978186, 342066184, 520434777, 787881171, 1200352170, 1821118615, 2763970589, 4206139843, 6375921928, 9697943915, 14727715606, 22347780403, 33997761687, 51574647974, 78370596691, 119094770953, 180721946517, 274765018088, 417119289853, 633547074319, 962838883516, 1461408602012, 2220965140618, 3373074469281, 5122043664624, 7783711177301, 11817737291477, 17954033876256, 27272927795979],
-[1, 1, 2, 3, 6, 8, 14, 20, 27, 48, 68, 112, 166, 227, 387, 576, 844, 1335, 1947, 3047, 4562, 6849, 10904, 15766, 24177, 37502, 55728, 86318, 128717, 196473, 302481, 449888, 694517, 1050108, 1583287, 2435667, 3660240, 5592848, 8495261, 12817346, 19661803, 29660141, 45090476, 68759835, 103819918, 158519075, 240199099, 364471533, 555617875, 840294610, 1279941265, 1943983740, 2946426282, 4488421104, 6801931251, 10340423842, 15718779499, 23830716786, 36267703569, 55028255661, 83570931552, 127079654234, 192755848850, 293075130307, 445051479958, 675657454002, 1027230097848, 1558929542390, 2368867152165, 3598744994536, 5463095503372, 8303425916170, 12606965990856, 19149879039713, 29095868774014],
-[1, 1, 2, 2, 3, 6, 12, 12, 16, 28, 41, 75, 93, 133, 218, 341, 529, 748, 1120, 1784, 2670, 4106, 6254, 9116, 14180, 21573, 32917, 50067, 74136, 115251, 174882, 263409, 403884, 606175, 927408, 1410682, 2131084, 3261948, 4915047, 7481850, 11417784, 17237884, 26294562, 39843110, 60492004, 92206015, 139461561, 212406315, 322520400, 488933871, 744866560, 1128846023, 1715917749, 2608016381, 3954770058, 6018658550, 9131356262, 13868294321, 21086990303, 31986932831, 48634347966, 73852166985, 112123875725, 170455903084, 258692357777, 393106597598, 597177080888, 906570146478, 1377881117431, 2092029991728, 3177833200457, 4828185152775, 7330492553621, 11138636856320, 16916175911656],
-[1, 1, 2, 2, 3, 6, 12, 12, 15, 24, 42, 79, 85, 123, 227, 345, 517, 713, 1118, 1796, 2588, 4124, 6107, 8913, 14237, 21332, 32672, 49116, 73273, 115356, 171751, 260603, 400700, 597433, 920938, 1393100, 2109808, 3231976, 4843798, 7428336, 11293982, 17023658, 26066073, 39357767, 59918998, 91234420, 137816762, 210503379, 318725004, 483827818, 737576922, 1115713858, 1699078430, 2579484056, 3912341243, 5958730623, 9027758111, 13727772529, 20864334361, 31636127689, 48143672355, 73042996397, 110950327148, 168676103337, 255872486240, 389076052011, 590749765740, 896935695974, 1363572376998, 2069401241929, 3144708380325, 4776921659061, 7252234719516, 11022512689217, 16734685619646],
-[1, 1, 2, 2, 3, 6, 12, 13, 16, 31, 47, 71, 94, 154, 244, 343, 536, 829, 1231, 1849, 2796, 4385, 6593, 9732, 15200, 22913, 34722, 53094, 79797, 122346, 184509, 280836, 429938, 644793, 985232, 1499469, 2268758, 3460106, 5229840, 7968888, 12117032, 18327550, 27963435, 42372987, 64329362, 97934475, 148378395, 225836506, 342705021, 520142643, 791693025, 1200131898, 1824519427, 2772246278, 4206130785, 6397073992, 9707739716, 14748539041, 22413595030, 34011996172, 51706240347, 78511460225, 119224607086, 181197529844, 275058545947, 417955461573, 634827599742, 963958335668, 1464847664740, 2224185268851, 3378760895178, 5132832493978, 7794125987338, 11842033070760, 17984422261730],
-[1, 1, 2, 2, 3, 6, 12, 12, 15, 28, 40, 72, 91, 140, 228, 326, 520, 750, 1137, 1799, 2589, 4081, 6230, 9117, 14296, 21198, 32725, 50155, 73829, 115225, 173074, 262251, 403996, 601899, 926135, 1403425, 2118500, 3257292, 4886962, 7461328, 11369727, 17138495, 26244688, 39643066, 60250082, 91894937, 138733497, 211777485, 321095671, 486839975, 742405960, 1123312117, 1709890690, 2597712196, 3937111538, 5997646201, 9090511349, 13814695735, 21006169744, 31845632177, 48456473125, 73540752017, 111666968172, 169809067573, 257581008992, 391596347748, 594750331967, 902821833290, 1372595831082, 2083258686497, 3165255250823, 4808953712843, 7300136293144, 11095196339623, 16846755278964],
-[1, 1, 2, 2, 3, 6, 10, 11, 16, 24, 36, 63, 83, 140, 211, 317, 496, 703, 1135, 1696, 2475, 3939, 5888, 8987, 13655, 20253, 31745, 47506, 71704, 110817, 165137, 254161, 385210, 580777, 892973, 1339474, 2048137, 3119908, 4696328, 7197724, 10875810, 16526369, 25202533, 38041822, 58092321, 88061654, 133508435, 203658088, 308056436, 468928730, 712499247, 1079799631, 1645052421, 2492989602, 3788855960, 5761669798, 8733534266, 13290301568, 20169707205, 30625395508, 46573933438, 70643359337, 107403254866, 163119265547, 247603945211, 376466061035, 571356889810, 868112325172, 1318940596739, 2002133686597, 3043060102185, 4620437061661, 7017937762907, 10663501106947, 16189443970547],
-[1, 1, 2, 2, 3, 6, 12, 12, 18, 33, 42, 73, 108, 154, 225, 353, 572, 816, 1182, 1913, 2908, 4312, 6627, 9954, 15292, 22978, 35104, 54130, 79960, 122735, 188088, 283310, 432313, 651702, 996215, 1515673, 2284070, 3498116, 5292866, 8023671, 12244222, 18526703, 28219162, 42793085, 64919194, 98991831, 149819648, 227838842, 346414304, 525088734, 799265343, 1212326058, 1841912964, 2800360252, 4246292333, 6459498889, 9806915011, 14887435125, 22636596205, 34349304856, 52204813882, 79296324850, 120376506733, 182989215694, 277778711595, 421985716659, 641162099623, 973369135945, 1479160072650, 2246229472239, 3411616260471, 5183568373828, 7870534841019, 11957741740439, 18162439455575],
-[1, 1, 2, 2, 3, 6, 12, 12, 17, 31, 42, 73, 100, 144, 233, 345, 545, 785, 1165, 1873, 2739, 4177, 6465, 9589, 14868, 22157, 33894, 52275, 77195, 119444, 181093, 272969, 419573, 628973, 963288, 1463726, 2205905, 3387022, 5104673, 7760931, 11842522, 17873437, 27300109, 41342063, 62735816, 95702711, 144646038, 220368254, 334704704, 507207250, 772906862, 1170946146, 1780333407, 2706485554, 4102233372, 6244883425, 9474040247, 14387528348, 21880508071, 33183815563, 50460988572, 76624079099, 116320463789, 176867620981, 268385907097, 407846258522, 619606161163, 940533181322, 1429634297713, 2170477602563, 3296979553396, 5009515471973, 7605205587926, 11556693518758, 17550925104201],
-[1, 1, 2, 2, 3, 6, 12, 12, 15, 26, 44, 77, 91, 140, 227, 329, 535, 778, 1153, 1807, 2672, 4277, 6343, 9271, 14738, 21899, 33659, 51170, 76073, 118579, 177297, 270243, 414898, 617640, 951753, 1441744, 2180319, 3341927, 5017375, 7678696, 11671740, 17610809, 26970791, 40716255, 61934502, 94363838, 142587726, 217653414, 329692537, 500426861, 762744772, 1154004220, 1757190317, 2668255285, 4046182392, 6161983750, 9338525592, 14197589920, 21578670856, 32721139689, 49790590170, 75549895313, 114747595510, 174454211778, 264652446537, 402381890611, 611005511638, 927677741044, 1410256719124, 2140356951751, 3252366989127, 4940651497267, 7500782826487, 11399878276626, 17308447711756],
-[1, 1, 2, 2, 3, 5, 9, 10, 12, 23, 34, 62, 78, 115, 195, 288, 452, 627, 965, 1557, 2240, 3495, 5333, 7815, 12217, 18256, 28137, 42862, 63124, 98919, 148869, 224620, 345818, 516706, 794517, 1203053, 1817081, 2793136, 4190424, 6397060, 9754068, 14703360, 22494001, 33997843, 51684086, 78803612, 118976964, 181599306, 275399189, 417518233, 636611577, 963494225, 1466326077, 2227631837, 3376700296, 5143459263, 7796262036, 11847009158, 18014537263, 27312552227, 41554074332, 63069079210, 95767384609, 145623523075, 220906620438, 335827471548, 510059513907, 774267878811, 1177105183257, 1786639094607, 2714509620667, 4124107276496, 6260676069859, 9515108150168, 14447858268811],
-[1, 1, 2, 2, 3, 6, 12, 12, 17, 30, 40, 69, 90, 141, 235, 342, 519, 764, 1167, 1842, 2692, 4128, 6372, 9440, 14666, 21921, 33414, 51407, 76279, 117808, 178400, 269349, 413780, 620513, 949409, 1443639, 2177443, 3337327, 5033762, 7657162, 11676802, 17628904, 26916703, 40777192, 61879405, 94350637, 142685636, 217320160, 330035144, 500284082, 762162687, 1154873440, 1755711062, 2668899864, 4046205468, 6158082183, 9343264957, 14189801035, 21576858339, 32727704017, 49762925787, 75565790534, 114720107151, 174415496136, 264691392825, 402217496095, 611034300609, 927584415572, 1409860746051, 2140540308214, 3251499295152, 4940243846776, 7500423464647, 11397002972795, 17308604484095],
-[1, 1, 2, 2, 3, 6, 12, 12, 18, 30, 44, 72, 95, 137, 220, 332, 511, 760, 1116, 1793, 2580, 3960, 6264, 9062, 14013, 21079, 32247, 49800, 73126, 113286, 172112, 258435, 399212, 597906, 912022, 1390186, 2093239, 3217658, 4845758, 7354978, 11254288, 16958058, 25902405, 39268447, 59501166, 90859825, 137276181, 209115083, 317841231, 481118471, 733671430, 1111623235, 1689142339, 2569485984, 3892754826, 5926972855, 8993421997, 13651823787, 20771069735, 31493212483, 47887176300, 72736985887, 110384604674, 167876013769, 254730880111, 387050675903, 588140255524, 892589836730, 1356877855133, 2060121815713, 3128910704483, 4754850978563, 7217948860596, 10968253139439, 16658440715207],
-[1, 1, 2, 2, 3, 6, 12, 12, 15, 29, 43, 77, 97, 144, 240, 350, 569, 810, 1189, 1937, 2814, 4402, 6616, 9696, 15561, 22886, 35008, 53841, 79212, 124185, 186073, 281529, 434772, 645411, 996377, 1510929, 2273347, 3502076, 5249622, 8022140, 12229033, 18399199, 28225599, 42603877, 64726975, 98835546, 149038307, 227642125, 345124588, 523087507, 798311295, 1206927537, 1837675015, 2792639633, 4230330163, 6447544187, 9769528041, 14846406524, 22581039569, 34220305041, 52085921881, 79041493509, 120001738066, 182532245890, 276816870339, 420886962887, 639255446597, 970239380169, 1475362410249, 2238948458003, 3401873173163, 5168858790889, 7845494900177, 11925323835050, 18106458052872],
-[1, 1, 2, 2, 3, 6, 12, 12, 16, 30, 41, 67, 98, 156, 225, 333, 554, 808, 1173, 1829, 2787, 4307, 6459, 9661, 14992, 22301, 34243, 52618, 78231, 120187, 181854, 276911, 422762, 633714, 972394, 1474800, 2229714, 3411698, 5148969, 7841563, 11922643, 18046081, 27549997, 41675641, 63329880, 96460672, 145996382, 222355609, 337367250, 512044578, 779422061, 1181087859, 1796642350, 2729019571, 4139675443, 6298754476, 9555658178, 14519119735, 22064970978, 33479231180, 50907509025, 77281197946, 117366628003, 178391180448, 270747978238, 411465594009, 624935347414, 948919628786, 1442102981551, 2189411927542, 3326238545008, 5052936088810, 7672403427776, 11658066852363, 17703864627977],
-[1, 1, 2, 2, 3, 6, 12, 12, 16, 28, 44, 71, 86, 137, 232, 320, 509, 741, 1076, 1771, 2516, 3962, 6025, 8684, 14089, 20626, 31496, 48625, 71167, 112179, 167579, 252913, 392760, 580703, 897509, 1362304, 2044168, 3158703, 4725635, 7223724, 11027060, 16549214, 25440067, 38380958, 58264299, 89084144, 134148581, 205087209, 310937379, 470869756, 719415056, 1086775121, 1655028851, 2516135833, 3808781016, 5808729400, 8798755140, 13369889687, 20344141353, 30814389224, 46917484076, 71196313590, 108066186517, 164433823136, 249297971773, 379088946780, 575821035784, 873768696629, 1328978469358, 2016528754160, 3063895052591, 4655914194557, 7065766358502, 10741461075699, 16308387893680],
-[1, 1, 2, 2, 3, 6, 12, 12, 17, 30, 43, 70, 88, 138, 245, 340, 516, 773, 1149, 1838, 2634, 4106, 6372, 9207, 14592, 21807, 32987, 50909, 75072, 117178, 176607, 265169, 411207, 612863, 938759, 1430801, 2150213, 3307999, 4972940, 7572445, 11577502, 17404878, 26648464, 40357055, 61166468, 93444235, 141028763, 215079132, 326646482, 494486969, 754675590, 1142173203, 1736601035, 2641701835, 4000894134, 6094761825, 9242736040, 14034702894, 21355844217, 32366829839, 49237745224, 74768237324, 113466697917, 172598494271, 261824997880, 397925200778, 604599011685, 917498290508, 1395025538046, 2117614498287, 3216567402321, 4888137393123, 7419483479332, 11276056306025, 17124138697789],
-[1, 1, 2, 2, 4, 8, 16, 16, 20, 40, 64, 112, 137, 199, 350, 504, 821, 1150, 1676, 2817, 4096, 6364, 9533, 13927, 22495, 33155, 50475, 77827, 114231, 179431, 269671, 406346, 628353, 932093, 1439803, 2187157, 3282149, 5062297, 7589632, 11588766, 17689242, 26584693, 40794645, 61596705, 93513941, 142926901, 215401004, 328960390, 499010120, 755879292, 1154097214, 1744584322, 2655695406, 4037497975, 6113653279, 9319796017, 14122674815, 21455830980, 32643585620, 49460529814, 75284334322, 114260023677, 173434771660, 263855370720, 400120357816, 608329240176, 924070354659, 1402329815731, 2132572677349, 3236324134260, 4916932548660, 7471594853655, 11339817129731, 17237133389363, 26172420708668],
-[1, 1, 2, 2, 4, 8, 15, 16, 24, 44, 60, 103, 144, 211, 345, 504, 818, 1165, 1703, 2827, 4140, 6290, 9589, 14239, 22484, 33264, 50664, 78369, 115436, 179467, 271705, 408914, 629667, 940899, 1446532, 2197932, 3302292, 5082492, 7650839, 11641915, 17769850, 26776389, 40970287, 61982096, 94049303, 143621131, 216800461, 330529697, 501956023, 760425154, 1159611887, 1755388494, 2669942823, 4059596894, 6150083913, 9367382613, 14206279157, 21574655408, 32818450225, 49754180181, 75683511490, 114908103201, 174419510760, 265275962607, 402440949301, 611643043976, 929225178018, 1410335781303, 2144132083871, 3254778879427, 4944241005548, 7512887939388, 11404311124221, 17331686424152, 26319879649389],
-[1, 1, 2, 2, 4, 8, 15, 15, 21, 42, 60, 101, 139, 198, 323, 477, 779, 1130, 1594, 2609, 3928, 6005, 9114, 13308, 21061, 31594, 47808, 73996, 108914, 168682, 256728, 386029, 594455, 887559, 1360787, 2077086, 3117708, 4789895, 7221675, 10972818, 16771498, 25266664, 38626676, 58512410, 88666337, 135480980, 204633721, 311657166, 473644635, 717232246, 1093818667, 1656544535, 2517785038, 3830242499, 5801954658, 8834859941, 13404830119, 20349379567, 30959891445, 46939436764, 71384743620, 108415849092, 164527544803, 250236458876, 379682694174, 576930985442, 876642973379, 1330436526842, 2022555554275, 3070634174422, 4663846280456, 7087439557531, 10758529048952, 16348963477414, 24830089089586],
-[1, 1, 2, 2, 4, 7, 14, 14, 17, 32, 54, 96, 119, 168, 276, 415, 669, 960, 1402, 2236, 3294, 5268, 7906, 11364, 18025, 27077, 41580, 63364, 93344, 145940, 219420, 332513, 512388, 761667, 1171609, 1780229, 2686935, 4125430, 6187762, 9453858, 14414533, 21713888, 33250907, 50238128, 76322639, 116444905, 175821827, 268341553, 406829819, 616791130, 940836230, 1423459894, 2166328874, 3291678107, 4988702176, 7599585666, 11518945079, 17504092291, 26617669358, 40350592290, 61398536601, 93188173847, 141488938015, 215164667548, 326384398994, 496187630986, 753622515741, 1143947771628, 1739222936375, 2639727901306, 4010634339489, 6093510296216, 9249993798795, 14058655075505, 21346714821257],
-[1, 1, 2, 2, 4, 8, 16, 16, 22, 44, 64, 104, 133, 206, 371, 510, 806, 1183, 1722, 2862, 4137, 6379, 9736, 14136, 22820, 33770, 50820, 79016, 116085, 181653, 273903, 410571, 637687, 946739, 1456463, 2221335, 3324149, 5128919, 7705196, 11734319, 17949640, 26942240, 41327484, 62524613, 94725200, 144925639, 218430899, 333286709, 506240934, 766011893, 1169973570, 1769292293, 2690926913, 4094765286, 6197551348, 9446421911, 14321938095, 21745293745, 33098958976, 50145204450, 76306872666, 115862986405, 175799728810, 267498279249, 405682429153, 616619964496, 936937462000, 1421595047060, 2161898032195, 3281294157164, 4984201199027, 7575100660847, 11496265690153, 17473843947601, 26535389814227],
-[1, 1, 2, 2, 4, 8, 15, 15, 22, 44, 63, 106, 146, 206, 337, 511, 815, 1160, 1673, 2777, 4158, 6250, 9514, 14056, 22127, 33180, 50252, 77532, 114401, 177265, 270039, 405406, 622727, 933375, 1430520, 2179914, 3274969, 5028262, 7587664, 11525108, 17608425, 26551727, 40548583, 61446983, 93163160, 142254435, 214944781, 327274406, 497439698, 753467839, 1148492447, 1739999534, 2644440092, 4022151275, 6094512643, 9278085428, 14078819380, 21372990259, 32512508311, 49304261148, 74969046402, 113860797815, 172808808163, 262795242726, 398782434644, 605924958316, 920671805525, 1397375278365, 2124112607496, 3225013899376, 4898335291932, 7443383416953, 11299609626366, 17170361178489, 26077978245806],
-[1, 1, 2, 2, 4, 8, 16, 16, 22, 44, 64, 103, 135, 209, 365, 501, 791, 1174, 1712, 2815, 4078, 6312, 9600, 13966, 22470, 33333, 50224, 77877, 114808, 179207, 270130, 405681, 628933, 935029, 1437397, 2191630, 3283924, 5059500, 7606879, 11586696, 17708207, 26604518, 40785798, 61715524, 93526280, 142997505, 215666595, 328975503, 499613822, 756296101, 1154627524, 1746573244, 2656297198, 4041274960, 6118500191, 9323383424, 14136838809, 21466307039, 32667519830, 49501543389, 75318926635, 114361913315, 173541430792, 264022242552, 400456609451, 608658839389, 924788937006, 1403304611404, 2133885949069, 3238919477035, 4919911107076, 7476949987919, 11348106825765, 17247825212217, 26192320619119],
-[1, 1, 2, 2, 4, 8, 15, 15, 21, 42, 64, 99, 130, 204, 342, 474, 755, 1149, 1658, 2617, 3886, 6096, 9215, 13353, 21268, 31897, 48173, 74281, 110088, 170577, 257627, 388960, 600270, 894368, 1370970, 2092157, 3145519, 4826164, 7270661, 11072804, 16899796, 25449011, 38942448, 58968544, 89388819, 136491893, 206219310, 314246624, 477167365, 722913277, 1102554480, 1669214888, 2537862640, 3859792656, 5847904447, 8904659148, 13507249638, 20512194682, 31201655727, 47304137196, 71950543168, 109256632232, 165826544832, 252194721335, 382639929011, 581499638476, 883456615415, 1340878309127, 2038471953482, 3094554295120, 4700583518232, 7142818006382, 10842815034195, 16477538517407, 25023758917945],
-[1, 1, 2, 2, 4, 8, 15, 16, 24, 44, 60, 102, 148, 214, 331, 493, 808, 1167, 1686, 2727, 4068, 6207, 9480, 14052, 21874, 32666, 49970, 77059, 113675, 175559, 267023, 402960, 617835, 925906, 1419501, 2158507, 3250709, 4988847, 7524500, 11436559, 17447678, 26345865, 40233403, 60918698, 92440801, 141039681, 213200954, 324703622, 493219898, 747527736, 1138953558, 1725631011, 2623607927, 3988460907, 6045446390, 9202452706, 13962312551, 21202631330, 32243263035, 48903477095, 74362387400, 112919297325, 171418673904, 260640280463, 395521671692, 601024381315, 913092585584, 1386059100834, 2106781143961, 3198592832405, 4858667814156, 7382353005738, 11207675188703, 17030614062357, 25864419507341],
-[1, 1, 2, 2, 4, 8, 15, 16, 22, 40, 60, 103, 136, 204, 336, 486, 783, 1138, 1674, 2653, 3927, 6191, 9296, 13584, 21446, 32171, 49080, 74970, 111189, 172918, 260507, 394303, 606375, 905097, 1390052, 2112150, 3186742, 4886362, 7350444, 11215060, 17087763, 25770447, 39416533, 59618855, 90545581, 138071832, 208657476, 318160990, 482637418, 731862370, 1115595905, 1688925893, 2569360320, 3904455742, 5918913067, 9012286035, 13665852032, 20763230214, 31569993551, 47872520777, 72821286409, 110543918884, 167841919491, 255201824282, 387202272494, 588539365417, 893935440250, 1357042244547, 2062900881298, 3131426737052, 4757353541461, 7227896777496, 10972935973992, 16675548257956, 25322083229249],
-[1, 1, 2, 2, 4, 8, 16, 16, 23, 46, 64, 113, 155, 211, 339, 511, 860, 1254, 1727, 2837, 4281, 6550, 9985, 14436, 22933, 34484, 52145, 81001, 118499, 183757, 280336, 420595, 649141, 967239, 1482967, 2267705, 3397301, 5227281, 7876294, 11956791, 18303824, 27544170, 42131637, 63827687, 96641367, 147823628, 223132324, 339847884, 516703319, 781904183, 1193143091, 1806599214, 2745384590, 4178164608, 6326105985, 9635725733, 14620224686, 22188888079, 33769237489, 51186394878, 77849238074, 118246685650, 179408230680, 272924194894, 414063594988, 629156002199, 956121000439, 1450835331239, 2205813740494, 3348800452042, 5086020438635, 7729799060017, 11732548241404, 17829780880612, 27079738454005],
-[1, 1, 2, 2, 4, 8, 16, 16, 23, 46, 64, 106, 148, 214, 348, 504, 816, 1213, 1718, 2789, 4154, 6362, 9764, 14185, 22401, 33644, 50871, 78933, 116039, 179620, 273543, 410636, 633624, 946023, 1448405, 2213227, 3319607, 5102841, 7694501, 11682352, 17871054, 26907208, 41137785, 62347922, 94418497, 144323025, 217962586, 331925252, 504624666, 763822806, 1165124426, 1764628686, 2681455805, 4080349141, 6179620244, 9410281407, 14279332165, 21673145356, 32979624135, 49997275408, 76032097298, 115487946729, 175237819192, 266547035813, 404427651960, 614495380326, 933804988417, 1417082731402, 2154337435325, 3270786992778, 4967557014568, 7549418914082, 11459435381421, 17414017291362, 26448486963778],
-[1, 1, 2, 3, 6, 8, 14, 20, 26, 46, 68, 108, 157, 225, 384, 550, 823, 1326, 1904, 2937, 4454, 6813, 10604, 15247, 23798, 36788, 54360, 84166, 126098, 192567, 294719, 440330, 680499, 1024432, 1548278, 2383106, 3580052, 5466811, 8298609, 12548836, 19221591, 28976963, 44121915, 67216943, 101508946, 154998426, 234832217, 356513652, 543048194, 821645308, 1251896431, 1900177147, 2881325950, 4388629618, 6650456531, 10111882476, 15366681084, 23304144515, 35462193138, 53798294898, 81725104101, 124246788877, 188472613534, 286573790165, 435129678072, 660694843023, 1004357688552, 1524258393199, 2316362481127, 3518555373458, 5341859547001, 8118909654918, 12326475317821, 18725018239298, 28448297736377],
-[1, 1, 2, 3, 5, 6, 11, 18, 22, 31, 53, 81, 110, 171, 275, 421, 581, 933, 1484, 2077, 3243, 4989, 7597, 11447, 17049, 26805, 40119, 60146, 93357, 140046, 212988, 324198, 490916, 751823, 1129153, 1722370, 2632981, 3960771, 6050161, 9178872, 13925017, 21218351, 32076090, 48922463, 74248424, 112459278, 171515598, 259816711, 394876325, 600348002, 910161561, 1385565363, 2101336939, 3191720121, 4854831395, 7360905226, 11193998257, 16999269641, 25805122835, 39236473775, 59536584318, 90482400015, 137456604941, 208638785082, 317164634684, 481506650685, 731401366714, 1111328363538, 1687145391359, 2563814513863, 3893492795147, 5913154486067, 8984793033032, 13642226449309, 20725824414073],
-[1, 1, 2, 2, 3, 6, 12, 12, 18, 34, 40, 66, 106, 154, 219, 347, 546, 783, 1130, 1828, 2819, 4074, 6353, 9575, 14604, 22063, 33310, 51767, 76795, 117042, 180292, 270361, 412645, 624818, 950421, 1450246, 2182713, 3338458, 5070262, 7660014, 11704223, 17719595, 26939454, 40950415, 62021148, 94593044, 143302443, 217578890, 331300497, 501890425, 763610919, 1159412464, 1759680419, 2677120672, 4059371161, 6171603950, 9377186913, 14226514325, 21635678342, 32838940094, 49883629291, 75807610974, 115051460633, 174884852719, 265555129953, 403266626880, 612878582248, 930389805313, 1413632969295, 2147254553682, 3260576017697, 4954548611022, 7523202610962, 11428277955956, 17361193278453],
-[1, 1, 2, 2, 3, 6, 12, 12, 17, 30, 41, 66, 93, 148, 222, 332, 529, 778, 1134, 1777, 2704, 4116, 6230, 9301, 14430, 21599, 32974, 50619, 75402, 115687, 175622, 266573, 406860, 611253, 935566, 1422091, 2148473, 3283462, 4963472, 7549059, 11490389, 17389050, 26517524, 40169139, 60993362, 92913199, 140686396, 214096902, 325076018, 493227367, 750683631, 1138097113, 1730197193, 2629071717, 3988091619, 6066448287, 9206676023, 13984334838, 21255020092, 32253829661, 49031528303, 74452806614, 113054540772, 171833826802, 260834357646, 396326015913, 602023460092, 914095091316, 1389086426050, 2109190947166, 3203985236499, 4867486092565, 7390975860552, 11229592872372, 17054679773399],
-[1, 1, 2, 2, 3, 6, 12, 12, 16, 30, 41, 70, 99, 146, 216, 324, 520, 776, 1124, 1725, 2623, 4092, 6242, 9071, 14058, 21371, 32595, 49827, 73942, 113951, 173093, 261836, 401167, 601862, 919097, 1400108, 2115437, 3233899, 4882140, 7423978, 11320728, 17108771, 26092111, 39551233, 60007947, 91458958, 138449749, 210720358, 320020150, 485226588, 738943370, 1120265737, 1702513066, 2587887864, 3924620176, 5970828890, 9061699031, 13761406133, 20922001327, 31742071035, 48254172975, 73283327256, 111258593905, 169125157979, 256709574244, 390046911635, 592548645234, 899593951279, 1367152635014, 2075904149258, 3153200499377, 4790740750753, 7274008138739, 11052054223652, 16785489218726],
-[1, 1, 2, 2, 3, 5, 10, 10, 15, 22, 30, 52, 65, 104, 171, 260, 375, 550, 869, 1321, 1958, 2996, 4566, 6858, 10526, 15920, 24248, 36588, 55346, 85366, 128312, 194520, 297201, 449487, 685082, 1037333, 1576998, 2402226, 3629821, 5533014, 8407188, 12736706, 19391814, 29404969, 44713212, 67931058, 102999499, 156789346, 237861259, 361219955, 549296011, 833270724, 1266798310, 1923559439, 2920803381, 4440328061, 6738180163, 10239571957, 15555772667, 23614907668, 35890966055, 54493604978, 82778619662, 125764683153, 190945380639, 290146847926, 440637550509, 669222364441, 1016782869771, 1543978034982, 2345588056007, 3562753551972, 5410798398598, 8220309915680, 12483933591353],
-[1, 1, 2, 2, 3, 6, 12, 13, 19, 32, 44, 69, 101, 155, 236, 348, 545, 842, 1211, 1864, 2827, 4321, 6691, 9859, 15160, 22997, 34863, 53648, 79965, 122220, 186620, 281523, 431207, 649862, 988355, 1508537, 2276089, 3477322, 5267563, 7985863, 12186452, 18430620, 28067562, 42623545, 64589052, 98447374, 149152091, 226711805, 344726872, 522480201, 795278528, 1206709371, 1832429966, 2786756746, 4226244818, 6426534313, 9759697612, 14814386046, 22525536726, 34183180426, 51942706397, 78915165485, 119787438205, 182078961984, 276440649176, 419898525649, 638026715760, 968620493469, 1471852929699, 2235339529275, 3394793537957, 5158141132083, 7832260854492, 11898719707407, 18073852326880],
-[1, 1, 2, 2, 3, 6, 11, 12, 17, 28, 37, 65, 96, 149, 217, 328, 519, 732, 1128, 1751, 2588, 4038, 6122, 9137, 13961, 20926, 32530, 49215, 73236, 113403, 171073, 260235, 396507, 596703, 915386, 1382121, 2099204, 3209563, 4833453, 7374884, 11204606, 16975257, 25888791, 39145654, 59599263, 90627590, 137201803, 209118725, 317008218, 481455402, 732517441, 1110192696, 1689443533, 2564168356, 3892082528, 5921497179, 8980302781, 13650818518, 20738242390, 31472431891, 47856595353, 72632193644, 110344296838, 167676853725, 254491658077, 386822488781, 587381378630, 892046139510, 1355570802798, 2057960452623, 3126939020552, 4749446729702, 7212264353433, 10958944983813, 16640835019945],
-[1, 1, 2, 2, 3, 6, 12, 12, 14, 26, 40, 71, 92, 146, 224, 331, 536, 757, 1160, 1782, 2675, 4234, 6233, 9359, 14553, 21721, 33473, 50497, 76021, 117539, 175819, 269128, 409983, 615479, 945935, 1427333, 2170696, 3311532, 4987784, 7632306, 11563908, 17524996, 26754354, 40420993, 61584019, 93565780, 141727920, 216089530, 327218541, 497458004, 756699527, 1146384547, 1745385740, 2647960047, 4020721270, 6116421587, 9273500462, 14103359355, 21417671488, 32506133590, 49436784005, 75010811087, 113984440475, 173183873516, 262850956073, 399586710253, 606632516738, 921425029590, 1400185975683, 2125506819446, 3229987733136, 4905418080372, 7449524827979, 11319671859626, 17187226707608],
-[1, 1, 2, 2, 3, 6, 12, 12, 17, 28, 43, 69, 90, 137, 209, 313, 511, 770, 1081, 1641, 2549, 4014, 5955, 8720, 13648, 20648, 31447, 47973, 71658, 109919, 166525, 253621, 387325, 579995, 887203, 1351711, 2044194, 3118062, 4710673, 7175267, 10916597, 16515306, 25196049, 38161651, 57936595, 88244372, 133675163, 203434234, 308720996, 468537120, 713222093, 1081140940, 1643628448, 2497405326, 3788757778, 5762932429, 8745511733, 13285765754, 20191204282, 30638831245, 46579894469, 70727346768, 107400234694, 163233296277, 247782119618, 376509699789, 571888650565, 868365813546, 1319603014549, 2003634843415, 3043719338249, 4623925170837, 7021218193853, 10667821783171, 16201189504155],
-[1, 1, 2, 2, 3, 6, 12, 12, 14, 24, 43, 72, 82, 131, 216, 325, 508, 736, 1094, 1678, 2510, 4048, 6054, 8676, 13596, 20739, 31838, 48019, 71234, 111052, 167216, 253791, 389336, 581311, 891380, 1354439, 2051747, 3136871, 4713795, 7202567, 10970289, 16559001, 25298183, 38266999, 58166961, 88600685, 134007997, 204333136, 309762893, 469986168, 716158614, 1084551769, 1649895033, 2506079548, 3801157028, 5786184976, 8773549856, 13333297052, 20266828186, 30739406683, 46755370775, 70970688025, 107778323361, 163837768192, 248610380990, 377897645596, 573917450192, 871358542647, 1324456587580, 2010544313402, 3054643039446, 4640476630993, 7045549528624, 10706647148158, 16257838755713],
-[1, 1, 2, 2, 3, 6, 12, 12, 14, 26, 40, 63, 83, 146, 222, 311, 502, 727, 1099, 1670, 2527, 4028, 5907, 8823, 13847, 20568, 31570, 47924, 71996, 111078, 166134, 254907, 388908, 581223, 894451, 1352887, 2053199, 3133249, 4719008, 7223378, 10946310, 16572429, 25329598, 38254955, 58244919, 88569903, 134107115, 204482902, 309645557, 470644369, 716253460, 1084646263, 1651479139, 2506208851, 3804124483, 5788185594, 8775390427, 13345017863, 20269133466, 30756459375, 46783102076, 70984491189, 107851565446, 163891759920, 248723023109, 378117489830, 574064995195, 871880103455, 1325023685495, 2011299416539, 3056396788713, 4642082091047, 7049102517368, 10711696836702, 16264034811361],
-[1, 1, 2, 2, 3, 6, 12, 12, 18, 30, 43, 67, 92, 140, 217, 331, 506, 758, 1114, 1755, 2640, 3980, 6127, 9108, 14062, 21350, 32236, 49524, 74075, 113335, 172896, 260331, 398579, 601737, 915488, 1396494, 2106803, 3217434, 4876250, 7393565, 11279443, 17061281, 25973506, 39452673, 59795715, 91119675, 138063583, 209829905, 319077154, 483666718, 736077924, 1116974926, 1696103643, 2579353835, 3912137452, 5948330262, 9033724059, 13712536507, 20849262937, 31641721029, 48078287238, 73044221037, 110879121436, 168531035253, 255881987550, 388663873031, 590559532234, 896583292232, 1362349206703, 2069075767709, 3142286536388, 4774394583567, 7249730753208, 11013561685887, 16729416697715],
-[1, 1, 2, 2, 3, 6, 10, 11, 17, 27, 37, 56, 82, 147, 218, 311, 471, 713, 1130, 1677, 2485, 3909, 5797, 8921, 13718, 20303, 31292, 47194, 72033, 110188, 164297, 253021, 383753, 579292, 887907, 1337460, 2039955, 3100516, 4687145, 7171047, 10831290, 16461109, 25092027, 37940085, 57838355, 87698549, 133100691, 202744678, 306952666, 467201322, 709626114, 1076019276, 1638105797, 2484018319, 3775085238, 5737609094, 8702606544, 13238289153, 20091957999, 30513082441, 46387585814, 70384993752, 106988528422, 162483207183, 246699565355, 374983037128, 569201092218, 864831831585, 1313808867921, 1994683914544, 3031261076955, 4602849546756, 6991524655448, 10622083002153, 16128603320361],
-[1, 1, 2, 2, 3, 6, 12, 12, 18, 31, 42, 75, 101, 142, 228, 360, 568, 794, 1150, 1915, 2892, 4289, 6461, 9659, 15261, 22828, 34450, 52937, 78581, 121875, 185154, 278152, 426121, 640358, 982341, 1493797, 2246666, 3445550, 5203225, 7909115, 12066593, 18212734, 27800012, 42122627, 63916883, 97512716, 147402554, 224422368, 341004561, 516834300, 787384938, 1193075925, 1813554625, 2757416862, 4179742602, 6361697376, 9653086807, 14657367769, 22290777162, 33810532309, 51407267875, 78067247366, 118506354459, 180186378208, 273448736699, 415499373793, 631259267157, 958235983640, 1456465014955, 2211356007854, 3358929074004, 5103679383802, 7748345992759, 11773695832034, 17881233241873],
-[1, 1, 2, 2, 3, 6, 12, 13, 17, 27, 49, 83, 98, 133, 231, 365, 570, 805, 1161, 1930, 2897, 4420, 6577, 9646, 15489, 23293, 35007, 53522, 79695, 124095, 187255, 281857, 434067, 648937, 995208, 1516060, 2281535, 3498063, 5270055, 8029803, 12252326, 18459324, 28221610, 42739725, 64838394, 98945619, 149514161, 227822673, 345903156, 524215652, 799259238, 1210248156, 1840066173, 2797866125, 4240277461, 6455881119, 9792412709, 14872068709, 22619362215, 34298042417, 52164132517, 79207123902, 120232101298, 182832721040, 277418003787, 421597562609, 640483732178, 972172884798, 1477859522805, 2243554321830, 3408017278135, 5178390280645, 7861206634134, 11946204983120, 18142056285890],
-[1, 1, 2, 2, 3, 6, 12, 12, 16, 29, 42, 70, 95, 148, 225, 329, 547, 807, 1163, 1824, 2761, 4314, 6374, 9453, 15054, 22264, 33877, 52059, 77524, 119916, 180165, 274340, 420658, 627147, 965551, 1465707, 2210783, 3387500, 5102261, 7789486, 11842778, 17879709, 27364025, 41352379, 62829351, 95782672, 144795371, 220763768, 334746507, 507966704, 773997002, 1171572784, 1783011690, 2708769464, 4106997186, 6252719081, 9480945362, 14408333149, 21901440278, 33215410273, 50529045974, 76691191300, 116459794894, 177060382366, 268649136458, 408361937305, 620191774418, 941592268317, 1431294280110, 2172583054163, 3300906247313, 5014742378841, 7613375033352, 11570016489477, 17568630282188],
-[1, 1, 2, 2, 4, 8, 16, 16, 21, 42, 64, 109, 131, 201, 375, 511, 813, 1167, 1721, 2925, 4144, 6459, 9685, 14082, 23185, 33955, 51100, 79205, 116487, 183927, 274689, 412299, 642369, 949469, 1468202, 2233182, 3339866, 5164786, 7733027, 11818201, 18056074, 27054609, 41612125, 62824601, 95285105, 145809317, 219458417, 335442455, 508868831, 770200837, 1177289715, 1778072956, 2707021301, 4117729075, 6230787478, 9503726437, 14396882985, 21870646496, 33289607603, 50412597057, 76758595342, 116496143500, 176784738959, 269045734622, 407880287896, 620188886341, 942166129320, 1429477017316, 2174375042120, 3299324746817, 5012544999772, 7617888906775, 11559962692393, 17573877920468, 26682895387546],
-[1, 1, 2, 2, 4, 8, 14, 14, 21, 42, 63, 94, 127, 201, 336, 487, 753, 1106, 1636, 2657, 3975, 6010, 9016, 13484, 21359, 31921, 48008, 73768, 110374, 170443, 257877, 388924, 596535, 895425, 1372008, 2088284, 3143760, 4812490, 7272899, 11067737, 16862967, 25455000, 38868361, 58904237, 89370380, 136266852, 206125060, 313800651, 476623426, 722686089, 1100794079, 1667963624, 2535493969, 3854794893, 5844422241, 8893326893, 13494870180, 20494355155, 31161991612, 47270267567, 71871169927, 109140626418, 165688338603, 251899868919, 382308127247, 580911248867, 882502791357, 1339725550897, 2036219417961, 3091609295377, 4696095309986, 7135119276032, 10832952287040, 16460370680159, 24998869902934],
-[1, 1, 2, 2, 4, 8, 16, 16, 26, 52, 64, 104, 161, 226, 361, 538, 855, 1236, 1753, 3001, 4478, 6474, 10072, 15037, 23614, 35235, 52835, 82783, 121657, 187548, 288216, 429035, 660577, 992945, 1517939, 2320619, 3467725, 5337698, 8079161, 12214179, 18714290, 28193555, 43028369, 65330740, 98824305, 151161008, 228301756, 347185378, 528791917, 799806177, 1219473015, 1848475833, 2806579200, 4273529076, 6470700724, 9850509424, 14957425413, 22686890330, 34532673457, 52361695910, 79592408379, 120945918131, 183470985039, 279084949266, 423532705255, 643307321383, 977866089317, 1483807251686, 2255544304035, 3425166926330, 5200966267733, 7905024725445, 11999313029617, 18232412179045, 27695781898456],
-[1, 1, 2, 2, 4, 8, 16, 16, 21, 42, 64, 109, 138, 204, 355, 503, 816, 1169, 1694, 2821, 4098, 6367, 9610, 14005, 22553, 33292, 50566, 78246, 114873, 179865, 270754, 407683, 631118, 936506, 1443931, 2196835, 3294605, 5080761, 7624422, 11628711, 17760847, 26689841, 40944749, 61866937, 93853159, 143484111, 216289457, 330172803, 501079942, 758781678, 1158541153, 1751720038, 2665664959, 4053817153, 6137711780, 9355371812, 14179932597, 21538232369, 32772658815, 49656790163, 75573528577, 114718062438, 174109171270, 264888126166, 401712453076, 610681454186, 927735494358, 1407830888214, 2140893802266, 3249165667558, 4936065690218, 7501045154158, 11384474145771, 17304375301435, 26275921950619],
-[1, 1, 2, 2, 4, 8, 16, 16, 19, 38, 64, 106, 132, 200, 332, 472, 790, 1147, 1624, 2593, 3924, 6253, 9127, 13261, 21435, 31958, 48515, 74250, 110099, 171514, 257528, 391313, 602078, 893248, 1377760, 2095877, 3155785, 4841327, 7274707, 11121734, 16931777, 25505814, 39091194, 59041851, 89675255, 136852822, 206662898, 315316000, 478005103, 724984820, 1105779908, 1672560608, 2545797634, 3868627833, 5862765354, 8930552016, 13536812791, 20571708919, 31279695567, 47418622363, 72158150581, 109512523382, 166273509932, 252859571673, 383567989330, 583118573798, 885630467557, 1344366397301, 2043936840939, 3102141070728, 4713278843073, 7161037356863, 10870483197037, 16521589774634, 25086342122379],
-[1, 1, 2, 2, 4, 7, 12, 14, 21, 38, 52, 89, 123, 185, 311, 444, 707, 1004, 1497, 2497, 3606, 5491, 8344, 12424, 19724, 29065, 44210, 68254, 100769, 157238, 237060, 356681, 549999, 821294, 1264428, 1918709, 2882852, 4439568, 6676381, 10173014, 15519210, 23370017, 35786945, 54112998, 82145935, 125423955, 189255806, 288714277, 438262201, 664019074, 1012798775, 1532547324, 2331762381, 3544879273, 5370212969, 8181060388, 12403640581, 18840836148, 28659307074, 43443568683, 66095584932, 100335847684, 152310321201, 231658389659, 351403486769, 534139646771, 811415149840, 1231531214146, 1872416586400, 2842073482815, 4317593103828, 6560535320460, 9958427994722, 15135142421833, 22982928868429],
-[1, 1, 2, 2, 4, 8, 16, 16, 22, 44, 64, 108, 140, 206, 358, 510, 820, 1194, 1718, 2856, 4176, 6405, 9783, 14216, 22792, 33886, 51149, 79436, 116546, 181919, 275344, 412772, 639458, 951173, 1461769, 2229952, 3339390, 5147971, 7740809, 11776577, 18016823, 27071099, 41480351, 62780638, 95114519, 145487021, 219388590, 334563632, 508319920, 769231416, 1174430438, 1776834874, 2701768904, 4111101757, 6223427566, 9483552255, 14381605792, 21833598341, 33230534541, 50354525845, 76611062852, 116335805866, 176520917899, 268567541680, 407356009897, 619104081833, 940738547425, 1427433081183, 2170570241510, 3294733815529, 5004440278299, 7605721133082, 11543356342940, 17544346247949, 26643540386597],
-[1, 1, 2, 2, 4, 8, 16, 16, 23, 46, 64, 105, 140, 211, 362, 501, 818, 1223, 1734, 2878, 4188, 6436, 9846, 14276, 23050, 34102, 51282, 80150, 117483, 183155, 276952, 415089, 644832, 956989, 1471167, 2247384, 3358540, 5184040, 7794953, 11854586, 18144648, 27235135, 41775663, 63223534, 95711298, 146519363, 220838977, 336815581, 511847681, 774245753, 1182632569, 1788679587, 2719735710, 4139830805, 6264457852, 9548168303, 14479309546, 21979168616, 33459420621, 50690212247, 77130249372, 117129630347, 177697460057, 270403973113, 410100841729, 623274439634, 947149081812, 1437002898969, 2185328620284, 3317011854219, 5038109115195, 7657487579505, 11621037802394, 17663113221179, 26824096287490],
-[1, 1, 2, 2, 4, 8, 15, 16, 22, 40, 62, 110, 140, 196, 329, 493, 805, 1136, 1657, 2712, 3963, 6202, 9378, 13667, 21650, 32260, 49531, 75869, 111442, 174300, 262916, 396891, 611349, 910582, 1401947, 2128597, 3205494, 4931356, 7401537, 11291586, 17228991, 25948859, 39734219, 60042501, 91195385, 139212228, 210083779, 320595938, 486352882, 737052996, 1124341087, 1701149938, 2588719742, 3934375384, 5961180014, 9082133091, 13767259703, 20916026496, 31812116093, 48222115520, 73373444383, 111371566323, 169078748932, 257148939601, 390055995176, 592951535066, 900678026084, 1367061660336, 2078498183998, 3154726412012, 4792860133187, 7282379125201, 11054249717878, 16800995075796, 25511433713576],
-[1, 1, 2, 2, 4, 8, 14, 14, 23, 46, 56, 90, 148, 202, 294, 458, 758, 1095, 1501, 2540, 3922, 5611, 8723, 13134, 20287, 30380, 45832, 71952, 105649, 161140, 249757, 373174, 570540, 860708, 1313077, 2008634, 3006220, 4615560, 7007452, 10568647, 16183127, 24454161, 37233695, 56572373, 85576439, 130810302, 197861446, 300448930, 457861799, 692876442, 1055221925, 1601226622, 2430074321, 3699650705, 5604595617, 8526159191, 12954402006, 19645228871, 29893193195, 45351621976, 68905570549, 104726477481, 158882090563, 241610623616, 366786996921, 556983934116, 846678896820, 1284960946986, 1952772496133, 2965989818782, 4503429685702, 6844364571896, 10390868918892, 15785936862843, 23981757398035],
-[1, 1, 2, 2, 4, 8, 16, 16, 23, 46, 64, 105, 147, 214, 342, 493, 832, 1228, 1698, 2809, 4213, 6404, 9730, 14250, 22762, 33758, 50985, 79647, 116799, 180776, 275023, 413490, 638181, 950095, 1459487, 2229097, 3335999, 5137718, 7743531, 11760044, 17983282, 27062130, 41432859, 62725962, 94992215, 145312413, 219281026, 334077354, 507818944, 768620588, 1172865458, 1775322861, 2698784730, 4106887555, 6217673030, 9471648646, 14369227382, 21810978805, 33192327402, 50309006324, 76524853435, 116220093112, 176342276937, 268270186092, 406972847387, 618425960128, 939763180033, 1426036012772, 2168188961298, 3291467626320, 4999205104854, 7597728991273, 11531880922892, 17525482274510, 26616593408137],
-[1, 1, 2, 2, 4, 8, 16, 16, 21, 42, 64, 106, 141, 207, 334, 484, 820, 1190, 1656, 2694, 4075, 6327, 9463, 13820, 22074, 32788, 49849, 77192, 113488, 175993, 266583, 403172, 620609, 922528, 1419893, 2163781, 3249053, 4995169, 7517342, 11447944, 17470722, 26316656, 40299696, 60932747, 92418392, 141213053, 213178041, 324982623, 493375784, 747515970, 1140283786, 1725588462, 2624799158, 3991321677, 6045789662, 9209432304, 13966221733, 21210928291, 32264912167, 48909972051, 74409327910, 112969705524, 171467913299, 260802538523, 395637225900, 601322722368, 913536375325, 1386492800802, 2107981591294, 3199759570191, 4860732501779, 7386146336417, 11211563550687, 17039159908793, 25875316793113],
-[1, 1, 2, 3, 6, 8, 14, 20, 27, 48, 69, 108, 164, 234, 378, 564, 849, 1301, 1907, 3011, 4565, 6767, 10535, 15699, 24080, 36746, 54908, 84965, 127431, 193779, 297366, 445627, 683228, 1034463, 1566567, 2398334, 3611713, 5512013, 8378708, 12664421, 19354300, 29272746, 44500675, 67744133, 102494967, 156270464, 236930945, 359597689, 547601185, 829433248, 1262100544, 1916737166, 2907606478, 4425064958, 6710388072, 10198903641, 15499739564, 23512950825, 35760652730, 54278752145, 82439418570, 125307715039, 190155850519, 289035784793, 438943010715, 666495638010, 1012991241208, 1537765235568, 2336403147635, 3549177841651, 5388930075792, 8188955819530, 12434767093435, 18888218593115, 28695420103399],
-[1, 1, 2, 3, 5, 8, 15, 18, 24, 45, 66, 99, 143, 225, 377, 505, 804, 1249, 1798, 2888, 4222, 6496, 9979, 14697, 23312, 34473, 51838, 81058, 120219, 185112, 280054, 421814, 652841, 973739, 1490035, 2275658, 3412299, 5246936, 7916941, 12026714, 18370986, 27655957, 42330559, 64141922, 97113086, 148409417, 224169343, 341453485, 518961830, 785692221, 1198444674, 1814662988, 2758107323, 4196962200, 6356086460, 9678657719, 14685562087, 22293342208, 33920762948, 51421789007, 78204840116, 118779476932, 180239649423, 274155349628, 415961476907, 632045487069, 960423628513, 1457523302663, 2215856848594, 3364042745068, 5109359647639, 7764804437693, 11786377343334, 17911116715444, 27202814342880],
-[1, 1, 2, 2, 4, 6, 10, 12, 14, 27, 46, 80, 98, 143, 232, 335, 559, 813, 1165, 1843, 2756, 4391, 6486, 9419, 15027, 22476, 34382, 52484, 77611, 120816, 181808, 276234, 424433, 630967, 971796, 1477253, 2227850, 3416459, 5133092, 7843387, 11943639, 18005580, 27570972, 41652476, 63282815, 96530619, 145819954, 222453894, 337250882, 511534145, 779981544, 1180193737, 1796178596, 2729075585, 4136669280, 6300290711, 9550758792, 14514016702, 22067003360, 33457347811, 50906534044, 77262201744, 117315279597, 178388392402, 270621881396, 411398215828, 624819804061, 948508142265, 1441986405274, 2188652152526, 3325336800943, 5052129647941, 7669510923505, 11656138792301, 17698895689872],
-[1, 1, 2, 2, 3, 6, 12, 12, 15, 24, 41, 65, 79, 130, 208, 312, 484, 679, 1024, 1605, 2453, 3851, 5542, 8407, 13263, 19644, 30236, 45455, 68687, 106158, 158796, 244089, 369855, 554663, 855689, 1290808, 1961890, 2988052, 4504293, 6903505, 10441733, 15833998, 24177211, 36495959, 55653664, 84526668, 128054754, 195231124, 295483458, 449582848, 683636592, 1035463591, 1577090303, 2391847619, 3632750681, 5525804690, 8376977854, 12743232694, 19346719705, 29366985984, 44666457601, 67759360751, 102982692870, 156452892062, 237461626027, 361017881118, 548003801518, 832469966776, 1264962349144, 1920149963829, 2918183971057, 4431506426188, 6730114222866, 10226574585227, 15526821578632],
-[1, 1, 2, 2, 3, 6, 12, 13, 16, 30, 47, 75, 100, 160, 247, 353, 583, 838, 1260, 1993, 2991, 4635, 6803, 10387, 16286, 23909, 36760, 56140, 84311, 129742, 194589, 298044, 453913, 680098, 1047651, 1582582, 2397680, 3664901, 5526327, 8445792, 12798474, 19387665, 29627488, 44743041, 68122526, 103619190, 156853629, 239107725, 362257585, 550547675, 837663473, 1268663986, 1931670985, 2931636192, 4449348066, 6769965007, 10265106665, 15608246597, 23707911187, 35976283418, 54719096133, 83028766693, 126147446618, 191700712398, 290928488846, 442254039132, 671475605477, 1019810666881, 1549809700508, 2352577533747, 3574917726836, 5429708987076, 8245083216071, 12528956008946, 19023721901080],
-[1, 1, 2, 2, 3, 6, 12, 12, 15, 28, 40, 67, 90, 153, 240, 333, 551, 798, 1216, 1886, 2722, 4362, 6507, 9760, 15313, 22334, 34702, 52983, 78811, 122570, 182493, 279525, 428354, 638296, 986422, 1485979, 2251518, 3455733, 5184433, 7942822, 12043073, 18200347, 27890233, 42037207, 64045344, 97482223, 147285407, 225005272, 340541773, 517311916, 788059396, 1192012292, 1816603376, 2756414801, 4181177177, 6368001067, 9647227516, 14674196521, 22294574608, 33810636535, 51457161620, 78050437047, 118585986116, 180264815241, 273446801701, 415834976858, 631290057282, 958633585041, 1457276056305, 2211476833133, 3361012048755, 5104966356775, 7750650087216, 11780174055236, 17883974584631],
-[1, 1, 2, 2, 3, 6, 12, 12, 16, 29, 41, 72, 96, 146, 230, 347, 551, 805, 1199, 1856, 2781, 4328, 6490, 9698, 15140, 22512, 34525, 52707, 78726, 121621, 182908, 278482, 425754, 638248, 980018, 1483995, 2246575, 3436390, 5179598, 7903990, 12009121, 18165517, 27747109, 41960053, 63807763, 97129109, 146983489, 224016787, 339663695, 515681060, 785046239, 1189230109, 1809600910, 2747948595, 4169085296, 6344116085, 9621370978, 14623260917, 22220990568, 33713812317, 51271908980, 77820884819, 118201661121, 179654250967, 272644797314, 414406403715, 629323626263, 955623899359, 1452348795042, 2204790071638, 3349876940717, 5088568926191, 7726544107382, 11740854822083, 17828412554776],
-[1, 1, 2, 2, 3, 5, 9, 10, 14, 21, 31, 51, 69, 114, 176, 258, 401, 586, 905, 1414, 2125, 3264, 4752, 7275, 11495, 16944, 25735, 39209, 59437, 91430, 136630, 209085, 318537, 478273, 735682, 1112063, 1685340, 2570427, 3882719, 5936788, 8989317, 13617240, 20798485, 31442459, 47860817, 72754136, 110209563, 167927513, 254437767, 386784962, 588311743, 891250715, 1356661734, 2059119423, 3125948458, 4754609831, 7210590311, 10963838795, 16651650798, 25272700837, 38432841241, 58321828293, 88611434652, 134643185536, 204366980280, 310640722028, 471643848043, 716360134102, 1088566141268, 1652539479772, 2511072587069, 3813819596111, 5791687480054, 8800306645458, 13362664921498],
-[1, 1, 2, 2, 3, 6, 10, 11, 19, 28, 37, 63, 92, 128, 191, 320, 504, 679, 1012, 1684, 2537, 3708, 5628, 8668, 13231, 19748, 30457, 46349, 68956, 106143, 162205, 244975, 370820, 562368, 862148, 1303326, 1971725, 3014037, 4561565, 6929588, 10541306, 15992649, 24327547, 36854726, 56062686, 85292283, 129183603, 196518100, 298482873, 453136945, 688863915, 1045165631, 1589024734, 2413124073, 3662728166, 5569652317, 8453740855, 12841301928, 19511656883, 29622321908, 45018022541, 68353899503, 103822134098, 157758484490, 239510120320, 363906534672, 552734336520, 839405094009, 1275340151143, 1936674335563, 2942036185821, 4468952643353, 6786718291120, 10310710271092, 15659303210886],
-[1, 1, 2, 2, 3, 6, 12, 12, 18, 30, 43, 68, 92, 141, 217, 337, 526, 764, 1114, 1781, 2740, 4062, 6191, 9316, 14368, 21662, 32814, 50445, 75363, 115202, 176094, 265743, 404804, 611832, 933253, 1420878, 2144139, 3273301, 4964879, 7528281, 11471656, 17374931, 26439653, 40134744, 60880397, 92738552, 140537079, 213560417, 324694842, 492454346, 749077338, 1136791719, 1726656485, 2624915156, 3982190934, 6054234519, 9194409111, 13958433796, 21218138139, 32207657569, 48937237679, 74339761690, 112863659273, 171528209732, 260443994905, 395604198846, 601052578008, 912610603425, 1386606657749, 2105913111582, 3198424423751, 4859324613833, 7379077303149, 11209901787301, 17027284576526],
-[1, 1, 2, 2, 3, 6, 12, 12, 16, 28, 41, 76, 99, 140, 230, 366, 562, 763, 1185, 1925, 2851, 4341, 6534, 9834, 15196, 22797, 35108, 53101, 79108, 122892, 185748, 281223, 428594, 645477, 990868, 1498021, 2270195, 3471751, 5231471, 7979500, 12135232, 18367343, 28006469, 42373616, 64488222, 98115157, 148478645, 226248289, 343156184, 520982163, 792837205, 1201570636, 1828012610, 2775438878, 4211909083, 6408484559, 9719688326, 14771411422, 22445443978, 34060592959, 51790076634, 78611106889, 119409702004, 181471622073, 275426214146, 418614191626, 635719840766, 965371403531, 1467049852383, 2227266126440, 3383967008013, 5140181579169, 7805296156376, 11860045211007, 18009798555850],
-[1, 1, 2, 2, 3, 6, 12, 12, 19, 32, 42, 73, 105, 147, 221, 355, 556, 817, 1185, 1879, 2875, 4301, 6629, 9836, 15075, 22921, 34819, 53536, 79556, 121674, 186543, 281013, 428828, 647533, 985807, 1503762, 2269356, 3465678, 5252182, 7956922, 12146004, 18385730, 27968786, 42475211, 64391815, 98140749, 148698835, 225936861, 343634371, 520889876, 792605889, 1202991482, 1826619266, 2777629491, 4213004568, 6405744603, 9729221279, 14766966755, 22452035994, 34077028253, 51775366477, 78662824387, 119408958187, 181491546421, 275568058082, 418552544788, 635988141507, 965560489891, 1467108526500, 2228247004120, 3383989154404, 5141608757881, 7807426091617, 11860598806049, 18016319486039],
-[1, 1, 2, 2, 3, 6, 12, 12, 19, 30, 42, 73, 91, 131, 230, 360, 513, 738, 1133, 1838, 2697, 4008, 6228, 9256, 14332, 21694, 32792, 50123, 74825, 115617, 175787, 263815, 404445, 610599, 930568, 1416607, 2138065, 3269037, 4942884, 7506718, 11458962, 17310758, 26364698, 40027873, 60718988, 92519989, 140054148, 213058072, 323861135, 490863391, 747343244, 1133540743, 1721822205, 2618013508, 3970512954, 6039292987, 9167992670, 13918744876, 21164786733, 32114033430, 48806466717, 74138065461, 112545338743, 171078476543, 259704724567, 394535409894, 599439746552, 910022715047, 1382920469156, 2100094253133, 3189613199073, 4846254589600, 7358462903002, 11179689903299, 16980586975279],
-[1, 1, 2, 2, 3, 6, 12, 13, 19, 33, 45, 70, 107, 166, 249, 366, 579, 867, 1249, 1978, 3047, 4569, 6886, 10407, 16162, 24202, 36541, 56376, 84510, 128756, 196357, 297446, 453250, 683207, 1043554, 1589527, 2396933, 3658597, 5550593, 8423743, 12821692, 19418599, 29578483, 44872038, 68063987, 103691595, 157137518, 238819330, 362973097, 550664484, 837668837, 1270832460, 1930747609, 2935047409, 4452455700, 6769552041, 10279712869, 15608391069, 23724267047, 36009899233, 54721532598, 83117925241, 126195052207, 191792843193, 291200036793, 442347763255, 672032732374, 1020406777805, 1550435718294, 2354595018873, 3576279092024, 5433315524669, 8250611629831, 12534220777863, 19038271502585],
-[1, 1, 2, 2, 3, 6, 11, 12, 19, 30, 38, 67, 102, 150, 203, 326, 530, 758, 1139, 1762, 2684, 4032, 6212, 9443, 14047, 21262, 33056, 50315, 74900, 114118, 174879, 265491, 401146, 609323, 929334, 1406644, 2136372, 3256232, 4935651, 7486743, 11382407, 17308840, 26284476, 39857507, 60607744, 92113854, 139762254, 212401370, 322634574, 489972940, 744249021, 1130305659, 1717673318, 2608054579, 3960672674, 6018736596, 9139857827, 13882816033, 21087701178, 32028573130, 48656363843, 73892366008, 112245655364, 170507124944, 258943995114, 393363134443, 597477996364, 907495551935, 1378491319089, 2093666818742, 3180351943153, 4830640088083, 7337038923580, 11145203512879, 16927899883315],
-[1, 1, 2, 2, 3, 6, 12, 12, 18, 30, 42, 64, 87, 141, 226, 329, 515, 752, 1098, 1767, 2649, 4001, 6079, 9097, 14227, 21095, 32060, 49434, 73561, 113375, 171674, 259748, 397685, 596641, 914795, 1389920, 2095003, 3209236, 4846841, 7373877, 11230820, 16968336, 25909292, 39234940, 59558836, 90798403, 137338294, 209131908, 317564626, 481600504, 733448341, 1111338437, 1689830064, 2568363461, 3894290173, 5926236187, 8991705264, 13657210146, 20763520720, 31497947800, 47892864387, 72720168207, 110409671353, 167851319423, 254739708698, 387097121015, 588030466325, 892729397428, 1356822786777, 2060009766604, 3129285850589, 4754345246970, 7218424179795, 10968361228709, 16657459320897],
-[1, 1, 2, 2, 3, 6, 12, 12, 19, 30, 43, 72, 94, 133, 225, 347, 530, 770, 1117, 1823, 2727, 4114, 6290, 9250, 14532, 21843, 33001, 50870, 75284, 116454, 177179, 266100, 409229, 613544, 938093, 1431272, 2152079, 3300116, 4982027, 7566569, 11562703, 17434080, 26613617, 40358049, 61165776, 93374754, 141152785, 214860488, 326605791, 494687417, 753990633, 1142577710, 1736026585, 2640741790, 4001758165, 6090980540, 9243948885, 14032382325, 21345895113, 32372432154, 49218036845, 74758709422, 113459430149, 172531975856, 261833215020, 397812723051, 604472752483, 917465196993, 1394557879767, 2117452171580, 3215978013650, 4886948909509, 7418949361199, 11273046303848, 17121779105340],
-[1, 1, 2, 2, 4, 7, 14, 14, 17, 34, 52, 90, 117, 176, 285, 416, 685, 960, 1408, 2283, 3423, 5326, 7862, 11671, 18580, 27553, 41963, 64241, 95514, 148533, 223217, 338692, 519735, 775246, 1194571, 1811530, 2732081, 4190454, 6304234, 9628342, 14647638, 22101897, 33826607, 51111053, 77683596, 118437447, 178950891, 272944110, 413857312, 627900254, 956954244, 1448278994, 2204318317, 3348709147, 5076885832, 7730839835, 11720254483, 17811668908, 27077216278, 41060780365, 62468692063, 94808299197, 143971000644, 218900012095, 332104191027, 504846764300, 766723146094, 1164011493684, 1769481918056, 2685821732006, 4080758600327, 6199551050275, 9411854134791, 14303691211607, 21719132055945],
-[1, 1, 2, 2, 4, 8, 15, 16, 24, 44, 60, 95, 136, 214, 346, 482, 782, 1159, 1686, 2727, 4008, 6163, 9369, 13885, 21961, 32462, 49298, 76394, 113020, 174962, 264371, 399146, 614853, 917874, 1409875, 2144380, 3222962, 4954295, 7464314, 11361396, 17327317, 26116213, 39967352, 60474448, 91736141, 140056203, 211528973, 322420577, 489571593, 741839095, 1131097846, 1712329749, 2604351464, 3959893285, 5999668372, 9136625711, 13857600281, 21046344705, 32011373216, 48534066814, 73824700486, 112087709578, 170142321269, 258754757809, 392573726956, 596629909031, 906396192878, 1375752003177, 2091473946473, 3174902896033, 4822899263490, 7328382423139, 11124574860087, 16906100424930, 25673790185026],
-[1, 1, 2, 2, 4, 8, 16, 16, 22, 44, 64, 109, 141, 206, 358, 511, 808, 1189, 1730, 2838, 4121, 6359, 9813, 14166, 22522, 33696, 51005, 79026, 115881, 180818, 273961, 410411, 635989, 947141, 1452588, 2216837, 3323533, 5120365, 7699413, 11708608, 17921512, 26933299, 41241413, 62453635, 94607312, 144672952, 218234006, 332739680, 505617353, 765066123, 1167940273, 1767545057, 2687032844, 4088757814, 6190300496, 9431720451, 14304748583, 21715406347, 33050580610, 50085685798, 76192904360, 115711285579, 175571986914, 267108350680, 405171990201, 615750112802, 935668069031, 1419754614713, 2158797910262, 3277039926556, 4977397785052, 7564618115498, 11481292068369, 17449394673814, 26500041388239],
-[1, 1, 2, 2, 4, 8, 14, 15, 23, 42, 62, 102, 135, 199, 334, 487, 775, 1133, 1653, 2709, 3964, 6081, 9274, 13597, 21591, 32139, 48764, 75268, 111065, 172821, 261265, 393063, 606412, 905308, 1389732, 2115594, 3178726, 4888294, 7357674, 11199188, 17103257, 25751728, 39406835, 59647383, 90461745, 138159035, 208578769, 317976066, 482906102, 731429181, 1115658316, 1688834618, 2568173449, 3905617189, 5916478530, 9011350289, 13666934200, 20754186630, 31573238636, 47863185176, 72806126060, 110548627521, 167788369661, 255198655143, 387157246667, 588397444694, 893944802659, 1356733546677, 2062682629240, 3131182383966, 4756315087384, 7227576926940, 10971060237827, 16673158241542, 25320278086021],
-[1, 1, 2, 2, 4, 8, 16, 16, 23, 46, 64, 111, 158, 214, 335, 517, 870, 1217, 1688, 2867, 4360, 6485, 9798, 14559, 23045, 34270, 51958, 80758, 118459, 183295, 280126, 420715, 645694, 965712, 1483929, 2261927, 3389885, 5214681, 7868736, 11941829, 18251731, 27517443, 42054953, 63681185, 96525295, 147535387, 222771449, 339204551, 515696484, 780924944, 1190678793, 1803357316, 2741161423, 4169945745, 6316057672, 9618146884, 14594244301, 22152872063, 33703943661, 51103123853, 77714229765, 118026939370, 179113617656, 272426783574, 413350543708, 628071894810, 954376962446, 1448410141580, 2201860922234, 3342898603794, 5077367553248, 7715853683138, 11712405734107, 17798459322323, 27031809025065],
-[1, 1, 2, 2, 4, 8, 15, 15, 20, 40, 60, 103, 135, 193, 326, 479, 780, 1098, 1580, 2641, 3899, 5968, 9049, 13280, 21115, 31293, 47676, 73760, 108161, 168636, 255443, 384452, 592685, 882704, 1358728, 2067241, 3102945, 4779164, 7184998, 10938707, 16710096, 25153257, 38516212, 58242386, 88356373, 135024797, 203700798, 310663237, 471736325, 714444847, 1090086075, 1649480698, 2509082068, 3815551921, 5778692553, 8804435892, 13350321806, 20274184710, 30845336090, 46752325154, 71129712428, 107990480183, 163903581205, 249318977580, 378183483183, 574813731516, 873294630733, 1325323789809, 2015100434432, 3058701626014, 4646415423639, 7060686184437, 10717096072333, 16288254850724, 24734787411001],
-[1, 1, 2, 2, 4, 8, 16, 16, 22, 44, 64, 105, 140, 209, 350, 495, 816, 1200, 1696, 2783, 4116, 6353, 9657, 14044, 22472, 33338, 50482, 78505, 115127, 179071, 271228, 408118, 631030, 937743, 1441894, 2199668, 3295846, 5077467, 7637505, 11620576, 17764616, 26713484, 40931743, 61925155, 93836565, 143508430, 216473533, 330073991, 501392812, 759000628, 1158541750, 1752873727, 2665659172, 4055488030, 6140003438, 9355567622, 14187885187, 21541538992, 32780951957, 49678069937, 75581656311, 114766304040, 174152852436, 264946093239, 401877700409, 610784136980, 928050129902, 1408269646324, 2141342777158, 3250366731063, 4937201568937, 7503242937872, 11388177391623, 17308264635035, 26284763858226],
-[1, 1, 2, 2, 4, 8, 16, 16, 23, 46, 64, 98, 139, 217, 358, 492, 794, 1182, 1692, 2794, 4107, 6253, 9519, 14053, 22441, 33072, 49920, 77891, 114847, 177911, 269385, 405366, 626099, 932796, 1433410, 2185406, 3273376, 5040681, 7595705, 11549098, 17640797, 26546727, 40659174, 61539573, 93246402, 142552538, 215128265, 327882035, 498186824, 754299565, 1150853253, 1741741312, 2648459858, 4029337837, 6101335626, 9294204333, 14097543995, 21403655476, 32567872008, 49363649050, 75092057094, 114028989341, 173040172024, 263230091190, 399318949766, 606845559812, 922071603400, 1399282106276, 2127505057365, 3229562024565, 4905493253204, 7454888875125, 11315350981343, 17196702595121, 26116125341666],
-[1, 1, 2, 2, 4, 8, 16, 16, 26, 52, 64, 96, 145, 226, 375, 506, 827, 1264, 1762, 2960, 4320, 6457, 10027, 14729, 23663, 34751, 51938, 82260, 120438, 186321, 283525, 423596, 658197, 978994, 1501956, 2299335, 3424967, 5292114, 7981304, 12095641, 18536566, 27828328, 42656999, 64641218, 97707244, 149715464, 225682090, 343817490, 523216645, 790872480, 1207882111, 1827814019, 2777410429, 4230477093, 6399331015, 9751850369, 14796580742, 22448055018, 34183269343, 51789495419, 78779869319, 119677722173, 181511608904, 276236644396, 418998659293, 636624055270, 967680566228, 1467988973380, 2232348917180, 3388890267125, 5146379358506, 7823011620462, 11871971772106, 18043129916503, 27404711294514],
-[1, 1, 2, 2, 4, 8, 15, 16, 25, 46, 60, 106, 156, 216, 333, 509, 844, 1200, 1712, 2832, 4230, 6360, 9741, 14464, 22586, 33698, 51411, 79571, 116925, 180828, 275852, 414754, 636493, 953978, 1463108, 2226593, 3346039, 5142847, 7757903, 11777380, 17990152, 27145647, 41459323, 62791326, 95227045, 145410922, 219684956, 334538724, 508451454, 770181613, 1173847743, 1778400859, 2703320726, 4111081121, 6229132152, 9483753757, 14390251830, 21846744194, 33231608476, 50395397665, 76630850006, 116377254773, 176637178582, 268616213805, 407602147999, 619346213838, 941043927095, 1428319088511, 2171146594375, 3296355431576, 5006839712534, 7608128303344, 11549700181948, 17550621168670, 26655063089019],
-[1, 1, 2, 2, 4, 7, 14, 14, 21, 40, 53, 83, 136, 198, 271, 416, 706, 1014, 1425, 2306, 3598, 5327, 7972, 12222, 18816, 27940, 42816, 66133, 98360, 149635, 229240, 348345, 527607, 795811, 1220015, 1852732, 2795275, 4270841, 6477000, 9826805, 14943551, 22672800, 34524835, 52299895, 79430891, 120986878, 183309577, 278636017, 423365363, 642624974, 977109653, 1482359855, 2253237605, 3423512815, 5194327240, 7898183250, 11991927791, 18211584876, 27674268968, 42011680658, 63846237421, 96956786209, 147235873983, 223752119819, 339713319164, 516089191928, 783976762675, 1190500718328, 1808803073284, 2746848230471, 4172467149952, 6338531633177, 9625526418472, 14623241020024, 22210192880158],
-[1, 1, 2, 2, 4, 8, 16, 16, 24, 48, 64, 100, 146, 219, 353, 500, 830, 1222, 1703, 2862, 4268, 6371, 9746, 14436, 23029, 33922, 51091, 80222, 117670, 181884, 277157, 415781, 641716, 956853, 1469839, 2244358, 3353922, 5170038, 7800194, 11832784, 18100169, 27237275, 41694499, 63144575, 95595235, 146275746, 220698164, 336154030, 511228269, 773593177, 1180386139, 1786904269, 2716013900, 4133920636, 6257748919, 9532540494, 14463555070, 21950428155, 33408229856, 50636384681, 77016951049, 116977740718, 177477764090, 270010793234, 409620059082, 622403786130, 945879555741, 1435260060748, 2182204940670, 3312865522043, 5031465382724, 7647042332070, 11606571380910, 17638747985771, 26789511881753],
-[1, 1, 2, 3, 6, 8, 14, 20, 27, 44, 68, 113, 155, 219, 371, 552, 838, 1264, 1883, 2993, 4400, 6678, 10399, 15329, 23775, 35894, 54138, 83577, 124552, 191513, 291114, 437214, 673607, 1013428, 1541865, 2353255, 3545312, 5429739, 8209561, 12452455, 19026120, 28713511, 43784307, 66483332, 100706532, 153617257, 232472822, 353644917, 537753986, 814487054, 1240794231, 1881595736, 2857576398, 4347095426, 6589446352, 10024850701, 15219816604, 23101089024, 35137926384, 53301898524, 81013487690, 123078756733, 186795070818, 284002544598, 431090917503, 654866685255, 995113128194, 1510479272756, 2295636715019, 3486089980618, 5294260308998, 8044957100357, 12214214444115, 18557540171043, 28187552971885],
-[1, 1, 2, 3, 5, 7, 13, 18, 27, 43, 59, 99, 150, 218, 350, 499, 791, 1241, 1776, 2809, 4167, 6358, 9921, 14454, 22604, 34085, 51158, 79660, 118133, 181405, 276424, 414402, 640408, 960438, 1461494, 2237231, 3358587, 5153261, 7793945, 11805847, 18064548, 27222230, 41555490, 63112181, 95461602, 145837614, 220545254, 335456617, 510425866, 772444305, 1177581550, 1785211261, 2710537198, 4126067001, 6250316972, 9511789303, 14442480864, 21913464586, 33345133092, 50566686507, 76862694191, 116792469465, 177199343375, 269487892841, 409012167728, 621283504078, 944255560760, 1432999226950, 2178160131947, 3307652452358, 5022782936317, 7633559013713, 11588227555833, 17607136903768, 26745152066893],
-[1, 1, 2, 3, 6, 8, 15, 22, 30, 53, 75, 109, 173, 257, 391, 567, 878, 1420, 2042, 3113, 4829, 7159, 11069, 16554, 25397, 38870, 57682, 89621, 135312, 203707, 313121, 470985, 720609, 1092201, 1650084, 2532201, 3814082, 5804175, 8851133, 13363201, 20401197, 30898825, 46928270, 71512513, 108113967, 164772470, 250160032, 379216349, 577693124, 875324335, 1331045534, 2022734788, 3067012065, 4668335818, 7081301970, 10756279403, 16355150318, 24807142749, 37721707654, 57274200755, 86961518157, 132207601970, 200624728749, 304893577643, 463148894154, 703105610173, 1068683902441, 1622474389646, 2464693686818, 3744617872029, 5685240916111, 8639071270748, 13119543628966, 19925932042304, 30274466710808],
-[1, 1, 2, 2, 3, 6, 11, 12, 21, 32, 40, 68, 96, 140, 228, 367, 532, 748, 1148, 1907, 2822, 4064, 6341, 9654, 14812, 22289, 33715, 51752, 77268, 118892, 181757, 272052, 415554, 630655, 960636, 1460384, 2202336, 3368917, 5106032, 7735811, 11808076, 17865243, 27172486, 41280624, 62616937, 95394578, 144461239, 219584850, 334045086, 506294909, 770318872, 1169134642, 1775385230, 2699582293, 4094747742, 6226328422, 9455477750, 14351808058, 21822337044, 33120803481, 50321874716, 76451306856, 116056902745, 176399384382, 267825542368, 406804169492, 618130096699, 938440413258, 1425924445651, 2165673188901, 3288994195653, 4997220434193, 7588145785921, 11527691478945, 17510384355125],
-[1, 1, 2, 2, 3, 6, 11, 11, 16, 28, 41, 66, 87, 134, 213, 336, 510, 698, 1073, 1742, 2625, 3876, 5816, 8991, 13855, 20551, 31535, 47980, 71980, 110623, 167613, 254832, 386110, 583776, 895761, 1353631, 2051470, 3129993, 4735001, 7211825, 10947307, 16609616, 25291353, 38286057, 58273542, 88590796, 134233147, 204293439, 309974329, 470952917, 715885349, 1085731534, 1651454169, 2507003839, 3806302074, 5787605385, 8782157224, 13346087448, 20272512170, 30778098083, 46782787700, 71016857387, 107888193264, 163919300162, 248857857018, 378165028199, 574282209943, 872234234154, 1325219437351, 2012236510565, 3057179686106, 4643427512843, 7051950533484, 10713959403605, 16270486142256],
-[1, 1, 2, 2, 3, 6, 12, 12, 18, 30, 42, 70, 95, 141, 222, 356, 547, 763, 1133, 1861, 2871, 4173, 6294, 9676, 14892, 22380, 33861, 51819, 77892, 119072, 181920, 274628, 416724, 632117, 964955, 1465547, 2214944, 3376894, 5126443, 7777502, 11835350, 17951620, 27288367, 41420239, 62898594, 95712344, 145123374, 220483061, 335143604, 508662160, 773173495, 1173719703, 1782988521, 2709383584, 4112370244, 6250268523, 9492298891, 14413502174, 21902887299, 33257206633, 50525680475, 76744931140, 116541247229, 177079000451, 268905889190, 408459621647, 620511450499, 942306558125, 1431547824384, 2174249327956, 3302400151872, 5016743341608, 7618887655275, 11573652830453, 17579549924131],
-[1, 1, 2, 2, 3, 6, 12, 13, 22, 36, 43, 68, 106, 154, 244, 378, 577, 876, 1247, 2042, 3078, 4429, 7001, 10536, 16191, 24306, 36476, 57105, 84685, 128754, 198675, 296970, 455140, 688625, 1045298, 1600297, 2400441, 3676974, 5589295, 8429303, 12903028, 19502161, 29668088, 45141440, 68256590, 104231984, 157829850, 239558303, 365095222, 552560723, 841200763, 1277153827, 1937436210, 2949783168, 4470231593, 6797224187, 10330208203, 15665457505, 23834674720, 36167526546, 54937630566, 83510665059, 126699141868, 192636665968, 292497389065, 444119076486, 675116369675, 1024668269217, 1557023056681, 2365158283883, 3590955380707, 5457430087914, 8285986238043, 12587056554556, 19122990327153],
-[1, 1, 2, 2, 3, 6, 12, 12, 19, 34, 41, 65, 100, 156, 240, 355, 549, 810, 1197, 1928, 2901, 4302, 6601, 9979, 15399, 23029, 34962, 53892, 80255, 123143, 187984, 283201, 432165, 652331, 997112, 1516197, 2284821, 3495835, 5295381, 8033740, 12245139, 18527255, 28223914, 42814790, 64957935, 98995317, 149876371, 227917305, 346482214, 525357024, 799484482, 1212672774, 1842512395, 2801113903, 4248202276, 6461209264, 9809594212, 14893269476, 22642767764, 34361562375, 52221604277, 79319586419, 120420015870, 183041456596, 277873764925, 422128694197, 641345908306, 973709507923, 1479622307598, 2246950481237, 3412765687736, 5185130604222, 7873203688355, 11961560052001, 18168144847740],
-[1, 1, 2, 2, 3, 6, 12, 12, 20, 36, 42, 67, 107, 156, 228, 355, 568, 832, 1171, 1945, 2989, 4265, 6642, 10052, 15516, 23244, 34941, 54648, 80807, 123149, 190212, 284640, 434688, 657268, 1001698, 1530562, 2294953, 3516973, 5344320, 8065783, 12335279, 18655220, 28386499, 43144726, 65295351, 99709235, 150927918, 229141865, 349100613, 528573393, 804577705, 1221220411, 1853387101, 2820925885, 4275214975, 6501643684, 9878958013, 14983962277, 22794515538, 34589991233, 52548492124, 79863133663, 121180720168, 184243725337, 279734442198, 424784900307, 645663045787, 980016418679, 1489190824587, 2261964813187, 3434573102830, 5219481897418, 7924721043720, 12038729096722, 18288981442264],
-[1, 1, 2, 2, 3, 6, 12, 12, 19, 31, 42, 71, 96, 140, 229, 359, 536, 764, 1145, 1864, 2826, 4162, 6313, 9550, 14885, 22327, 33637, 51638, 77372, 118948, 180920, 272580, 416161, 628262, 960036, 1460224, 2202066, 3364618, 5097245, 7741179, 11793435, 17840648, 27175565, 41232790, 62567894, 95317846, 144349571, 219507031, 333612033, 505976953, 769949798, 1167843932, 1774371705, 2697346211, 4091552816, 6222248993, 9446454617, 14343437486, 21804867787, 33091645892, 50291327247, 76385626423, 115971601076, 176268802786, 267602668472, 406531410015, 617618140573, 937725936535, 1424913534353, 2163871478512, 3286636493189, 4993354906567, 7582210715721, 11519335808968, 17496299544447],
-[1, 1, 2, 2, 3, 6, 12, 12, 17, 29, 43, 63, 84, 142, 229, 317, 497, 747, 1087, 1721, 2612, 4038, 5969, 8876, 14136, 21035, 31718, 48700, 72975, 112512, 169647, 257449, 394354, 589993, 905889, 1378143, 2076602, 3176159, 4797427, 7313430, 11123608, 16798237, 25674165, 38864359, 58999816, 89929876, 136054842, 207210107, 314459855, 477108771, 726684052, 1100685978, 1673988770, 2544093527, 3857801211, 5870622288, 8906001738, 13530127144, 20567544006, 31199448869, 47445622898, 72032433626, 109371947899, 166270411218, 252334928939, 383472739472, 582469296732, 884327243438, 1344084824394, 2040547044039, 3099894163647, 4709541337657, 7150427132944, 10865276421923, 16500255793458],
-[1, 1, 2, 2, 3, 6, 12, 12, 16, 28, 41, 67, 86, 141, 227, 322, 524, 744, 1109, 1786, 2593, 4021, 6048, 9040, 14372, 20923, 32171, 49551, 73208, 114083, 170800, 259832, 399384, 594350, 918310, 1388519, 2093145, 3220486, 4834575, 7389000, 11237114, 16946217, 25979540, 39192441, 59605787, 90908723, 137201221, 209510832, 317508550, 481686512, 734425992, 1110710652, 1691717406, 2569380830, 3894211902, 5933046006, 8990467032, 13666468996, 20776904930, 31497844274, 47936681879, 72733759396, 110457244459, 167969927816, 254768979035, 387369591234, 588256301038, 893026032327, 1357739915608, 2060508123213, 3131018029690, 4756656437591, 7220721276659, 10975042506566, 16663215049033],
-[1, 1, 2, 2, 3, 6, 10, 11, 18, 26, 38, 59, 81, 135, 201, 312, 482, 690, 1065, 1650, 2454, 3769, 5686, 8653, 13240, 19729, 30457, 46126, 69374, 106642, 160698, 245247, 372385, 562315, 861374, 1300462, 1977107, 3013921, 4552814, 6945146, 10532417, 15982646, 24347103, 36844731, 56102511, 85218643, 129180997, 196716207, 298232777, 453269319, 688991055, 1044849551, 1589672576, 2412365943, 3663584982, 5570346977, 8450726542, 12846568913, 19509804885, 29620411278, 45027177517, 68342661511, 103842216328, 157754701341, 239495891520, 363979790628, 552667149107, 839474822937, 1275437356345, 1936543822434, 2942408733442, 4468790490643, 6786981388570, 10311545783835, 15658496873643],
-[1, 1, 2, 2, 3, 6, 12, 12, 19, 34, 43, 59, 98, 158, 232, 327, 521, 788, 1129, 1846, 2753, 4085, 6231, 9398, 14789, 21732, 32815, 51256, 76107, 116722, 177601, 267687, 410486, 616260, 944483, 1437978, 2157690, 3313242, 5014066, 7605917, 11599454, 17514123, 26752616, 40540233, 61445097, 93804633, 141816947, 215812499, 328105131, 497151930, 757342919, 1147647589, 1744291381, 2653057777, 4020291502, 6118580633, 9286861491, 14098264988, 21442675121, 32523404248, 49446928982, 75100205121, 113986398039, 173328419469, 263048554169, 399649568585, 607246340555, 921741654313, 1400989528451, 2127211882075, 3230900493426, 4909465488667, 7453309130966, 11325116126132, 17200837061827],
-[1, 1, 2, 2, 3, 6, 12, 12, 18, 30, 43, 70, 96, 143, 221, 339, 549, 775, 1105, 1830, 2823, 4164, 6235, 9444, 14805, 22003, 33358, 51491, 76602, 117587, 179270, 270973, 413074, 621384, 952732, 1448902, 2181586, 3339321, 5054173, 7675219, 11692001, 17688702, 26973410, 40866218, 62023251, 94568304, 143112177, 217699603, 330796811, 501710002, 763678297, 1157778647, 1759809171, 2675030038, 4056460332, 6171165660, 9367038331, 14223660230, 21623655152, 32811147321, 49876704581, 75744426696, 114996790810, 174810536894, 265346375256, 403145840063, 612461288364, 929853345266, 1413078964558, 2145685784506, 3259199905379, 4951747697197, 7518528834004, 11423415754385, 17349828550340],
-[1, 1, 2, 2, 3, 6, 10, 11, 17, 27, 39, 58, 86, 127, 189, 297, 462, 685, 982, 1576, 2453, 3557, 5440, 8297, 12684, 19237, 28874, 44530, 67014, 101352, 156097, 235013, 356978, 542655, 823470, 1257412, 1896804, 2887942, 4400158, 6649511, 10137471, 15380855, 23344554, 35520275, 53814412, 81947134, 124383437, 188635175, 287188508, 435520931, 661854319, 1005740524, 1525972635, 2320870377, 3521983666, 5350086256, 8132405537, 12339624442, 18756745865, 28485233280, 43253910129, 65737403030, 99789727554, 151627254141, 230322750521, 349709069699, 531431588034, 806950597704, 1225759294905, 1862177797848, 2827671824272, 4296186959722, 6524748024565, 9910028012204, 15055526587230],
-[1, 1, 2, 2, 3, 6, 11, 11, 18, 30, 45, 61, 86, 138, 215, 316, 484, 729, 1059, 1726, 2591, 3832, 5824, 8796, 13862, 20510, 30801, 47833, 71520, 109549, 166397, 250885, 384424, 578526, 884679, 1347379, 2025413, 3102044, 4700184, 7131650, 10869919, 16423981, 25060032, 38008689, 57613360, 87878979, 132982071, 202263591, 307498384, 466104691, 709703591, 1075946468, 1634871173, 2486382300, 3769283127, 5734124406, 8705101716, 13215561861, 20096249858, 30489024092, 46344108152, 70393111692, 106850978309, 162447375795, 246580670027, 374597833576, 569163823683, 864027210393, 1313104978118, 1993951485398, 3028437120659, 4601570982694, 6986517842992, 10614994833779, 16122804579695],
-[1, 1, 2, 2, 3, 6, 12, 12, 17, 30, 42, 68, 91, 142, 233, 332, 524, 765, 1121, 1817, 2652, 4122, 6234, 9190, 14611, 21532, 32860, 50632, 74887, 116591, 175310, 265312, 408451, 608824, 937487, 1422534, 2141434, 3292118, 4949771, 7551400, 11505164, 17337588, 26553815, 40129555, 60946202, 93002239, 140408027, 214217240, 324961802, 492647332, 751238486, 1136701693, 1729904525, 2629023424, 3983892218, 6068340983, 9199631077, 13977790242, 21256289884, 32225069138, 49030110748, 74419952976, 112987747545, 171828167140, 260656013918, 396229491214, 601839505319, 913546879832, 1388892738056, 2108087969646, 3202777564591, 4866210144258, 7386940681688, 11226843950933, 17047454801874],
-[1, 1, 2, 2, 4, 8, 15, 15, 22, 44, 60, 104, 144, 200, 333, 494, 801, 1138, 1624, 2770, 4077, 6094, 9331, 13749, 21902, 32514, 49109, 76448, 112026, 174393, 265375, 396853, 612876, 914979, 1405662, 2143514, 3207330, 4945084, 7448706, 11310525, 17309302, 26035414, 39845612, 60340846, 91408983, 139819943, 210898407, 321385358, 488632175, 739385595, 1128344496, 1707978392, 2596304976, 3950987345, 5981465504, 9112767155, 13823691299, 20981681890, 31933964517, 48399601446, 73619429001, 111808817215, 169646619748, 258093304751, 391519333734, 594949620515, 904110312968, 1371881003177, 2085899848128, 3166548097533, 4809422111622, 7309388901960, 11094054076940, 16860347696349, 25606399621273],
-[1, 1, 2, 2, 4, 7, 13, 14, 21, 41, 54, 82, 114, 184, 320, 420, 676, 1021, 1466, 2461, 3522, 5326, 8234, 12110, 19566, 28526, 42746, 67532, 98948, 153900, 232555, 348071, 542071, 804172, 1236885, 1888796, 2815970, 4356272, 6553361, 9956365, 15243694, 22865686, 35103406, 53122618, 80373395, 123125372, 185465522, 282900469, 430091728, 650295028, 993568017, 1502381072, 2284501732, 3478367951, 5261493857, 8021098897, 12163612964, 18461322398, 28110178110, 42578806725, 64792326377, 98397183127, 149258889508, 227165601969, 344492235679, 523553793223, 795676636426, 1207072185336, 1835801960247, 2786407964626, 4232049274693, 6432794678335, 9761774200025, 14837688572347, 22533503282332],
-[1, 1, 2, 2, 4, 8, 15, 15, 24, 48, 64, 86, 135, 222, 342, 461, 765, 1189, 1643, 2663, 4053, 6073, 9164, 13701, 21875, 32249, 48209, 75733, 112450, 171996, 261853, 395003, 607489, 907812, 1391580, 2127456, 3183406, 4887538, 7400743, 11224926, 17130780, 25829750, 39496319, 59867938, 90619263, 138502914, 209342700, 318482868, 484348307, 733567762, 1118159750, 1693877945, 2573884059, 3917036772, 5932971452, 9030842837, 13708600095, 20806190781, 31653341001, 47999447339, 72981856051, 110859921646, 168220390544, 255846538943, 388258523130, 589846277493, 896349712059, 1360390027689, 2067900463713, 3139792785959, 4768499805105, 7246702440806, 11000670110686, 16715588605075, 25388906275046],
-[1, 1, 2, 2, 4, 8, 15, 16, 24, 44, 60, 97, 144, 217, 331, 478, 798, 1161, 1662, 2698, 4034, 6161, 9284, 13881, 21829, 32260, 49251, 76168, 112667, 173852, 263496, 398901, 611411, 914412, 1405677, 2135550, 3213504, 4933778, 7440776, 11322477, 17249808, 26045414, 39820795, 60235616, 91437506, 139513965, 210840313, 321235239, 487714623, 739461129, 1126723421, 1706319122, 2595411578, 3944926461, 5979210182, 9102969354, 13808228350, 20973507358, 31891381219, 48366204784, 73559815660, 111679409393, 169550782425, 257807453097, 391186354457, 594505016233, 903100612133, 1370936107489, 2083894924064, 3163552409686, 4805830128439, 7301827171975, 11085225895586, 16845399109744, 25581669163277],
-[1, 1, 2, 2, 4, 8, 16, 16, 19, 38, 64, 116, 133, 191, 352, 510, 829, 1149, 1681, 2825, 4096, 6431, 9610, 13901, 22586, 33385, 50800, 78106, 114434, 180634, 270821, 407813, 632045, 935429, 1446160, 2196961, 3297089, 5088510, 7615699, 11642064, 17778515, 26690167, 40988196, 61862321, 93928165, 143597615, 216293790, 330524076, 501210140, 759092398, 1159567871, 1752119058, 2667664862, 4055672829, 6140203646, 9362965496, 14184294103, 21551524809, 32792469144, 49675712554, 75626443172, 114768830083, 174203357370, 265051384714, 401879504580, 611071062933, 928208961194, 1408527033655, 2142211075008, 3250675729489, 4938916091918, 7505134952984, 11390083927073, 17314664793821, 26288987977329],
-[1, 1, 2, 2, 4, 8, 16, 16, 22, 44, 64, 109, 146, 209, 336, 487, 836, 1230, 1693, 2770, 4144, 6425, 9727, 14086, 22555, 33490, 50863, 79201, 115756, 179788, 272756, 411104, 635174, 942541, 1450050, 2213484, 3315876, 5109117, 7683462, 11687674, 17869307, 26876041, 41187672, 62290586, 94381728, 144385113, 217784343, 332058027, 504391663, 763530083, 1165537620, 1763361911, 2681720906, 4079943625, 6176548439, 9411858540, 14273314578, 21670848343, 32977912973, 49975286327, 76036660008, 115456020427, 175196291001, 266540536021, 404287594671, 614450982507, 933628739403, 1416717809107, 2154214955509, 3269867094808, 4966840816145, 7548352348212, 11456490754816, 17412239008993, 26442610558097],
-[1, 1, 2, 2, 4, 8, 16, 16, 23, 46, 64, 105, 139, 211, 369, 507, 792, 1200, 1755, 2861, 4081, 6323, 9882, 14207, 22590, 33670, 50918, 79307, 116104, 181115, 274115, 410160, 637835, 948558, 1453669, 2220560, 3324770, 5129493, 7710069, 11719235, 17952338, 26950395, 41300062, 62552326, 94689777, 144890274, 218459090, 333154650, 506368382, 765802604, 1169596424, 1769668312, 2690069455, 4094655242, 6197053125, 9443952797, 14322954664, 21740122305, 33096077234, 50143867874, 76287311863, 115862518666, 175775131749, 267459032805, 405666485164, 616498598359, 936883251860, 1421439259708, 2161550698149, 3281132348058, 4983411737233, 7574334399936, 11495233381910, 17471197613552, 26533405982741],
-[1, 1, 2, 2, 4, 8, 16, 16, 23, 46, 64, 92, 130, 217, 365, 480, 772, 1181, 1688, 2758, 4038, 6189, 9438, 13887, 22305, 32738, 49220, 77130, 113808, 176188, 266203, 400820, 620694, 922674, 1417986, 2164033, 3237733, 4988802, 7516223, 11430721, 17462254, 26254687, 40248597, 60913597, 92250480, 141095880, 212865882, 324497138, 493042133, 746339030, 1139121719, 1723477901, 2620782868, 3988053117, 6037321966, 9198120827, 13950922002, 21180464542, 32232471500, 48846891487, 74313953127, 112848172651, 171232409514, 260509683093, 395160991477, 600539736666, 912518890244, 1384690973777, 2105476451668, 3195990973879, 4854450404696, 7377663585349, 11197548154566, 17018255308112, 25844966719448],
-[1, 1, 2, 2, 4, 8, 16, 16, 26, 52, 64, 101, 156, 226, 355, 513, 855, 1273, 1744, 2950, 4431, 6501, 10061, 14901, 23655, 35043, 52485, 82871, 121373, 186789, 286467, 427824, 660626, 987570, 1512227, 2315548, 3453644, 5323815, 8050233, 12175497, 18657353, 28076025, 42927643, 65118656, 98442414, 150735299, 227500504, 346126798, 527064775, 796971413, 1215972870, 1841964020, 2797439558, 4260546081, 6447985881, 9819855515, 14907885739, 22612133032, 34425031583, 52181660912, 79340845578, 120554549029, 182854618394, 278206778492, 422120302166, 641221523434, 974716193697, 1478863293534, 2248360811458, 3413877142535, 5183930449373, 7879661348721, 11959577295575, 18173576313103, 27605428259822],
-[1, 1, 2, 2, 4, 8, 14, 14, 22, 44, 59, 90, 137, 203, 316, 469, 743, 1082, 1554, 2575, 3889, 5716, 8780, 13202, 20551, 30655, 46335, 72031, 106673, 163624, 250571, 376341, 576140, 867313, 1325933, 2021799, 3036020, 4655785, 7051271, 10681353, 16313142, 24643863, 37574067, 57008317, 86380285, 131856794, 199447652, 303271848, 461375796, 698909239, 1064408507, 1614043558, 2451696640, 3729662084, 5652587569, 8600314782, 13058355311, 19817844740, 30143595652, 45729412396, 69504027642, 105588207092, 160248129294, 243653859559, 369839661416, 561800498372, 853720991983, 1295860887140, 1969428068842, 2990729542404, 4541988933534, 6901871881056, 10478578497868, 15920572997349, 24182491667278],
-[1, 1, 2, 2, 4, 8, 15, 15, 22, 44, 63, 100, 141, 209, 333, 489, 799, 1161, 1647, 2710, 4085, 6173, 9325, 13843, 21896, 32552, 49290, 76415, 112942, 174314, 265021, 399562, 613534, 917652, 1408220, 2145862, 3222525, 4947776, 7468539, 11349182, 17320437, 26125149, 39929116, 60467366, 91681303, 140001527, 211550672, 322136753, 489479974, 741631291, 1130405157, 1712192782, 2602878459, 3958562033, 5998014669, 9131474077, 13855343526, 21036659640, 31997488241, 48522507681, 73787973906, 112055455258, 170077511124, 258642024280, 392467101660, 596359773918, 906086165057, 1375285079475, 2090560050619, 3173921172627, 4820954705939, 7325635966452, 11120835003454, 16899031325904, 25665168033507],
-[1, 1, 2, 3, 6, 7, 10, 16, 23, 42, 56, 93, 144, 200, 333, 477, 721, 1116, 1637, 2642, 3885, 5793, 9165, 13426, 20806, 31592, 47067, 73439, 109155, 167398, 256310, 381416, 590216, 889080, 1347811, 2066793, 3099094, 4754600, 7205037, 10886899, 16687505, 25141966, 38320363, 58314979, 88109638, 134634315, 203673609, 309495498, 471501853, 713037256, 1086856476, 1648850776, 2501415860, 3809839049, 5770803182, 8779393962, 13337167088, 20226177429, 30785701329, 46690642162, 70944928824, 107841439774, 163582040862, 248781328597, 377650747929, 573496330008, 871817500102, 1322958053863, 2010744787969, 3053963083477, 4636733986482, 7047496399990, 10698700894645, 16254040478272, 24692877314811],
-[1, 1, 2, 3, 6, 8, 15, 22, 26, 46, 76, 114, 156, 237, 387, 564, 842, 1321, 1976, 2969, 4544, 6955, 10580, 15725, 24220, 37134, 55540, 84954, 128936, 195767, 297692, 450381, 688776, 1041651, 1578059, 2411717, 3650244, 5546693, 8427928, 12787524, 19473067, 29498337, 44844786, 68208420, 103362352, 157269396, 238770519, 362522000, 551138600, 836120541, 1271342257, 1930676762, 2930454138, 4455751333, 6762987544, 10274597949, 15610320565, 23698267206, 36017034192, 54686034953, 83064186947, 126213415526, 191618424142, 291148070060, 442203961050, 671569632507, 1020347524874, 1549411926070, 2353782428547, 3575361462737, 5429723749203, 8249107516903, 12528065767705, 19029455056561, 28906979367946],
-[1, 1, 2, 3, 6, 8, 13, 18, 28, 52, 67, 99, 154, 240, 400, 546, 822, 1280, 1924, 3088, 4492, 6675, 10469, 15668, 24359, 36375, 54368, 84962, 126875, 194212, 295629, 442633, 683244, 1028595, 1565124, 2391217, 3589322, 5505554, 8341926, 12631207, 19305003, 29116347, 44419324, 67494263, 102121982, 155904428, 235862785, 358637889, 545821647, 826268349, 1258896699, 1909062633, 2898520945, 4411776299, 6684777432, 10170010274, 15444083285, 23433861423, 35653449170, 54078799447, 82187975110, 124885688346, 189496304845, 288153910108, 437392822496, 664350959818, 1009686201634, 1532432724656, 2329078246045, 3537037854714, 5371097066446, 8162535715987, 12392062450281, 18827521819606, 28599454259372],
-[1, 1, 2, 2, 3, 6, 11, 12, 20, 28, 38, 68, 91, 136, 220, 337, 506, 724, 1109, 1733, 2578, 3992, 5984, 9004, 14022, 20827, 32002, 48539, 72754, 112705, 169109, 257570, 392933, 590435, 906965, 1370310, 2078282, 3175800, 4786320, 7311332, 11097364, 16798845, 25645417, 38774894, 59021869, 89760271, 135874259, 207118497, 313911465, 476842332, 725613923, 1099317705, 1673125299, 2539678728, 3854611411, 5864670284, 8893280132, 13519876003, 20539260226, 31167713917, 47398502230, 71932591733, 109279562802, 166067275755, 252038468963, 383108141928, 581722350775, 883447434542, 1342564765387, 2038118935225, 3096853853420, 4703782570892, 7142766001132, 10853614089947, 16480572628176],
-[1, 1, 2, 2, 3, 5, 9, 11, 13, 21, 39, 65, 73, 117, 202, 293, 445, 644, 995, 1532, 2268, 3633, 5367, 7842, 12418, 18724, 28601, 43102, 64556, 100620, 150462, 229085, 351265, 524692, 806031, 1221880, 1852566, 2829859, 4253884, 6512536, 9896256, 14944265, 22845819, 34535351, 52542000, 79960996, 120968763, 184523215, 279526453, 424447202, 646545045, 978865020, 1489838453, 2262033141, 3432087022, 5223875862, 7919046967, 12039505501, 18294666246, 27750840687, 42214371196, 64062679206, 97309688212, 147905738198, 224433814999, 341190111190, 518075740936, 786682928891, 1195718550986, 1814993368959, 2757850607745, 4189170032821, 6360665168279, 9666041635142, 14676696134016],
-[1, 1, 2, 2, 3, 6, 12, 12, 16, 28, 42, 75, 95, 141, 237, 350, 548, 788, 1208, 1898, 2745, 4340, 6594, 9620, 15046, 22622, 34739, 52753, 78275, 122287, 183520, 277453, 427247, 639029, 980418, 1485769, 2247671, 3447132, 5177282, 7906578, 12044754, 18166236, 27779019, 42013742, 63859565, 97295150, 147033362, 224337742, 340156422, 515875055, 786295610, 1190420111, 1811344888, 2751589343, 4172285592, 6352952186, 9631189764, 14636413020, 22251943116, 33743459910, 51330640419, 77911993460, 118313919190, 179879182363, 272912492614, 414862075152, 630074512944, 956540299738, 1454070084241, 2207163127673, 3353391170425, 5094523687613, 7734416386373, 11754179222724, 17848074475893],
-[1, 1, 2, 2, 3, 6, 12, 12, 15, 24, 41, 79, 83, 123, 231, 355, 532, 713, 1126, 1856, 2642, 4156, 6261, 9196, 14555, 21495, 33304, 50410, 74369, 117316, 175201, 265713, 408531, 608386, 939694, 1418997, 2146582, 3299320, 4936391, 7564112, 11513284, 17347411, 26578822, 40090824, 61052328, 93036542, 140373709, 214545691, 324889852, 492962116, 751797802, 1136807790, 1731758087, 2629086661, 3985992688, 6073822503, 9199983723, 13988908302, 21265613398, 32237004785, 49067535228, 74437636497, 113062677966, 171919203684, 260739641888, 396515146120, 602065022192, 914018581954, 1389705827874, 2108876666667, 3204784712344, 4868393533841, 7390442724629, 11233517224263, 17054422808022],
-[1, 1, 2, 2, 3, 6, 12, 12, 17, 28, 43, 69, 84, 138, 235, 331, 515, 776, 1143, 1803, 2673, 4189, 6238, 9201, 14714, 21784, 32990, 50670, 75705, 117284, 175711, 267098, 410984, 612354, 941294, 1430674, 2157017, 3305242, 4976955, 7600416, 11562463, 17436123, 26695492, 40363165, 61294587, 93460336, 141263147, 215407426, 326654496, 495528773, 755301875, 1143137920, 1739459224, 2643254963, 4007072992, 6100953125, 9250670955, 14057382960, 21371366750, 32407088445, 49300615377, 74832138947, 113625032331, 172764119325, 262121841304, 398435619808, 605145943312, 918685836346, 1396548098701, 2119829822337, 3220638207076, 4893035841462, 7428335375665, 11288960287949, 17142036635235],
-[1, 1, 2, 2, 3, 6, 12, 12, 19, 32, 43, 67, 93, 144, 225, 333, 531, 802, 1139, 1834, 2745, 4114, 6383, 9391, 14732, 22018, 33253, 51796, 76394, 117445, 179129, 269389, 414791, 621044, 948744, 1449869, 2176322, 3340393, 5047913, 7653707, 11702543, 17643664, 26943228, 40866988, 61874694, 94530994, 142889872, 217424354, 330678987, 500643079, 763181498, 1156572198, 1756931674, 2673514518, 4050107913, 6164751652, 9357946277, 14201896868, 21607541881, 32766740306, 49815244450, 75676784311, 114833570685, 174640629280, 265033199406, 402635395911, 611876119939, 928625643236, 1411552273781, 2143328353795, 3255040507176, 4946689245236, 7509334145684, 11410263625519, 17331017495858],
-[1, 1, 2, 2, 3, 6, 11, 11, 16, 26, 41, 69, 82, 121, 199, 311, 474, 668, 1009, 1602, 2396, 3668, 5523, 8219, 12784, 19266, 29461, 44578, 66718, 103293, 155925, 236556, 360647, 542773, 831681, 1260365, 1909893, 2915235, 4398792, 6708661, 10200335, 15437429, 23536362, 35635901, 54193595, 82449487, 124852659, 190149023, 288434652, 437923343, 666394069, 1010134457, 1536294671, 2333012090, 3540715544, 5385899412, 8170586308, 12416124594, 18866150007, 28631112286, 43529395642, 66080941886, 100369908245, 152529425351, 231524950712, 351855683817, 534359959445, 811461640538, 1233100177201, 1872200189206, 2844351709947, 4320593321443, 6560933542261, 9968753652705, 15138465728302],
-[1, 1, 2, 2, 3, 6, 12, 12, 15, 26, 44, 78, 89, 135, 228, 334, 540, 782, 1143, 1818, 2705, 4285, 6355, 9176, 14765, 22178, 33560, 51171, 75977, 118865, 178200, 269440, 415826, 618434, 951254, 1446896, 2180265, 3345384, 5024975, 7679079, 11703499, 17617715, 26987274, 40801539, 61950344, 94525204, 142744679, 217801591, 330274699, 500705346, 763808959, 1155562899, 1758310105, 2672418866, 4049929918, 6168841472, 9351258837, 14209044138, 21608442024, 32756292210, 49840900477, 75652409360, 114854683950, 174666959442, 264963170564, 402787750705, 611789068606, 928633351810, 1411872003626, 2142937820960, 3255708183936, 4946679602070, 7509046591002, 11412481886541, 17329211509553],
-[1, 1, 2, 2, 3, 6, 11, 11, 16, 26, 41, 69, 84, 127, 210, 334, 500, 697, 1066, 1717, 2574, 3882, 5847, 8796, 13645, 20448, 31340, 47472, 70942, 109826, 166042, 251866, 383336, 577593, 885588, 1340705, 2031782, 3102365, 4682132, 7137859, 10852374, 16432870, 25044833, 37912963, 57676643, 87743871, 132859334, 202328742, 306939157, 466064928, 709077947, 1074923697, 1634936257, 2482560385, 3767885976, 5731365325, 8694792084, 13212690760, 20075627702, 30468720872, 46321778291, 70318197242, 106810381923, 162312453926, 246377730192, 374426507978, 568632583246, 863527697851, 1312186885667, 1992294683738, 3026838374597, 4597706108966, 6981835197730, 10608210056958, 16109530360241],
-[1, 1, 2, 2, 3, 6, 12, 12, 18, 28, 43, 72, 90, 131, 222, 337, 517, 732, 1092, 1788, 2612, 3981, 6081, 9043, 14186, 20961, 32094, 49359, 73005, 113369, 171124, 258561, 397167, 594289, 912737, 1384568, 2087491, 3205812, 4824823, 7350686, 11205043, 16905476, 25845864, 39094366, 59380667, 90564471, 136818088, 208600210, 316584217, 479963847, 731498821, 1107602097, 1684955276, 2560595064, 3881372073, 5910056714, 8962451910, 13615295459, 20703310890, 31395384282, 47753641516, 72493637361, 110066636090, 167359875739, 253924532827, 385943167032, 586242251248, 889927249728, 1352801918640, 2053572088483, 3119769905509, 4739971883691, 7195880106112, 10935494989747, 16606049571051],
-[1, 1, 2, 2, 3, 6, 12, 14, 19, 34, 49, 73, 104, 162, 264, 375, 582, 901, 1321, 2089, 3076, 4673, 7274, 10712, 16690, 25055, 37760, 58638, 87190, 133803, 203393, 305940, 471606, 707861, 1078487, 1647296, 2477480, 3797441, 5742363, 8709341, 13305914, 20071516, 30633433, 46496054, 70404675, 107460029, 162561403, 247316278, 376087347, 569549131, 867877133, 1315694332, 1998380644, 3040515315, 4607675392, 7011100680, 10643822471, 16154966136, 24574870619, 37273313920, 56658108638, 86075263696, 130625450823, 198624901078, 301470756510, 457971601678, 695936735738, 1056301150561, 1605477096099, 2437926141773, 3702434056808, 5626297879767, 8541663674201, 12978143840014, 19712706959656],
-[1, 1, 2, 2, 3, 5, 9, 11, 14, 24, 34, 56, 78, 120, 187, 274, 432, 612, 920, 1478, 2188, 3328, 5029, 7579, 11817, 17456, 26742, 40977, 60973, 94388, 142482, 215897, 329940, 495350, 761370, 1152373, 1740300, 2667590, 4021943, 6129425, 9322547, 14094585, 21528401, 32560110, 49501916, 75402805, 114026545, 173779307, 263655537, 400086618, 609177860, 922784653, 1404024968, 2132620074, 3234526298, 4922917261, 7466363186, 11345243270, 17243919574, 26159802469, 39783450924, 60388186610, 91711229905, 139410795926, 211557874392, 321543026167, 488351903255, 741484554424, 1126958177267, 1710834325448, 2599248020394, 3948603512951, 5995270701329, 9110274217771, 13834256040265],
-[1, 1, 2, 2, 3, 6, 12, 13, 20, 35, 47, 73, 112, 162, 242, 378, 594, 870, 1259, 2061, 3111, 4517, 7065, 10668, 16344, 24509, 37122, 57760, 85625, 130714, 200774, 301194, 460705, 696546, 1060711, 1617226, 2432486, 3726318, 5652447, 8545418, 13056590, 19750576, 30060858, 45665628, 69171541, 105526938, 159765599, 242735734, 369499693, 559688423, 851869071, 1292836016, 1962788316, 2986050196, 4526862322, 6884422485, 10457472959, 15867238298, 24132966327, 36621800970, 55641364334, 84547507236, 128315196292, 195068361857, 296158152170, 449790897946, 683563192825, 1037640711947, 1576747973125, 2394793046562, 3636627319091, 5526049051834, 8390504240234, 12746668024415, 19363077243452],
-[1, 1, 2, 2, 3, 6, 12, 13, 18, 29, 48, 76, 92, 141, 245, 348, 562, 812, 1167, 1947, 2830, 4370, 6582, 9705, 15721, 22880, 34706, 54010, 79410, 123979, 186299, 281245, 435343, 645358, 996260, 1514644, 2269557, 3502659, 5257992, 8019851, 12239231, 18395094, 28240263, 42644958, 64697342, 98926155, 149094605, 227630073, 345413196, 523138240, 798736930, 1207413237, 1837836295, 2794760889, 4231059954, 6449705276, 9775054619, 14848819148, 22593117361, 34231502547, 52102435146, 79082721624, 120028301834, 182616047361, 276929426946, 421005376417, 639562317835, 970540219647, 1475940932074, 2239887060700, 3402909717206, 5171155650448, 7848240915369, 11929613046155, 18114161055543],
-[1, 1, 2, 2, 3, 6, 12, 12, 15, 27, 44, 73, 89, 140, 235, 332, 538, 774, 1147, 1820, 2676, 4262, 6331, 9287, 14795, 21955, 33604, 51321, 76105, 118711, 177824, 270235, 415508, 618169, 953081, 1444863, 2180432, 3346797, 5025362, 7684770, 11691382, 17626247, 27004009, 40774670, 61987494, 94515160, 142740324, 217879177, 330186688, 500930015, 763768476, 1155398807, 1759091670, 2672026033, 4050516798, 6169696924, 9350594071, 14212995778, 21607296042, 32759430167, 49850293893, 75647048359, 114876918033, 174677239281, 264973189219, 402854309913, 611785985612, 928760768666, 1411998564225, 2142994541109, 3256202447647, 4946860764815, 7509727967049, 11413731546794, 17329899103742],
-[1, 1, 2, 2, 4, 8, 16, 16, 22, 44, 64, 107, 135, 206, 372, 509, 827, 1180, 1720, 2958, 4176, 6406, 9803, 14358, 23340, 33825, 51364, 80410, 117131, 184336, 276884, 415421, 646635, 955929, 1478549, 2247920, 3357352, 5204194, 7792904, 11882976, 18175690, 27244540, 41898381, 63241585, 95885530, 146844315, 220901833, 337602401, 512459305, 775250031, 1185083241, 1790026923, 2725066913, 4145904457, 6271413119, 9567344355, 14495028302, 22014216932, 33514349340, 50749746933, 77269584518, 117281335425, 177956142047, 270859426794, 410609895065, 624303706244, 948523682956, 1439001545620, 2188919065748, 3321455729095, 5045928527623, 7669083994422, 11637112250186, 17691319396178, 26862038002512],
-[1, 1, 2, 2, 4, 8, 16, 16, 22, 44, 64, 95, 128, 212, 361, 477, 772, 1174, 1680, 2724, 3986, 6201, 9398, 13732, 22118, 32522, 49038, 76532, 112888, 175242, 263998, 398322, 617100, 915549, 1408498, 2148178, 3217754, 4957147, 7457468, 11358568, 17343679, 26070402, 39989232, 60477782, 91640353, 140131596, 211378025, 322407435, 489558643, 741253878, 1131545223, 1711464852, 2603359563, 3960532359, 5996095490, 9136677081, 13853953850, 21038352869, 32013133521, 48510848851, 73815234988, 112072453938, 170072456023, 258742517643, 392447462609, 596494007451, 906277641372, 1375260219480, 2091227212657, 3174101349941, 4821577735358, 7327403100151, 11121181825206, 16902977137494, 25668355937799],
-[1, 1, 2, 2, 4, 8, 16, 16, 25, 50, 64, 99, 146, 221, 366, 513, 830, 1237, 1740, 2922, 4314, 6412, 9960, 14685, 23333, 34478, 51756, 81526, 119466, 184584, 281733, 421116, 651778, 972659, 1490432, 2279375, 3403059, 5248909, 7922501, 12001751, 18385431, 27646075, 42307577, 64137201, 97006751, 148507360, 224045249, 341148611, 519167614, 785097650, 1198227480, 1814294757, 2756423883, 4197228812, 6352067742, 9676097758, 14684522442, 22278856571, 33916983997, 51403340014, 78174039509, 118761257765, 180148775579, 274100366686, 415834643048, 631770961375, 960258858174, 1456923791655, 2215184280319, 3363153177420, 5107304427678, 7762988680982, 11782139955718, 17905151412528, 27195818092859],
-[1, 1, 2, 2, 4, 8, 16, 16, 22, 44, 64, 112, 145, 206, 361, 526, 848, 1181, 1717, 2943, 4261, 6468, 9863, 14506, 23241, 34188, 51949, 80723, 118070, 184912, 279466, 419140, 648683, 964624, 1486896, 2261479, 3386883, 5230829, 7853769, 11957809, 18284132, 27476159, 42128892, 63682205, 96576529, 147718045, 222594275, 339706727, 515902076, 780866328, 1192277272, 1803090683, 2743211552, 4172723843, 6316497447, 9628352646, 14595999016, 22164276263, 33731628908, 51109047059, 77775794584, 118076262019, 179184991121, 272631293485, 413457240871, 628480056665, 954885096365, 1448923491834, 2203390313016, 3344182390411, 5080068793996, 7720312278188, 11716951342806, 17809510357255, 27044162842347],
-[1, 1, 2, 2, 4, 8, 15, 15, 20, 40, 60, 108, 136, 190, 333, 488, 809, 1116, 1600, 2756, 4001, 6130, 9233, 13539, 21910, 32108, 48848, 75845, 110740, 173990, 262146, 393978, 610152, 904319, 1397936, 2124752, 3180922, 4917113, 7370705, 11240022, 17177666, 25796385, 39605460, 59796427, 90725190, 138806471, 209032807, 319248388, 484529274, 733495571, 1120393016, 1693313990, 2577461009, 3919918372, 5932936018, 9046863213, 13709361673, 20822857070, 31689760330, 48005504960, 73074314278, 110914569928, 168326079763, 256133439001, 388369179963, 590447091200, 897015090671, 1361086296503, 2070039706428, 3141371723520, 4772421550934, 7252667876557, 11006609732212, 16731276214630, 25404876619699],
-[1, 1, 2, 2, 4, 8, 15, 15, 19, 38, 64, 106, 128, 194, 341, 494, 794, 1104, 1627, 2690, 3958, 6169, 9158, 13508, 21673, 31933, 48790, 74839, 110513, 172897, 259501, 393197, 605159, 899446, 1389242, 2106584, 3171508, 4877585, 7319921, 11188532, 17040940, 25665337, 39341901, 59387804, 90263617, 137756351, 207852250, 317334357, 481072534, 729497977, 1112776169, 1682804906, 2562161506, 3892881139, 5898848802, 8988085120, 13620734777, 20700357620, 31477945818, 47714091910, 72615742433, 110194390075, 167316453621, 254463875872, 385951775671, 586797534993, 891198625192, 1352771747975, 2056841994129, 3121532965361, 4742946822422, 7206074314770, 10938441666961, 16625819202034, 25243583854058],
-[1, 1, 2, 2, 4, 7, 14, 14, 18, 36, 52, 86, 115, 179, 294, 412, 660, 958, 1424, 2291, 3318, 5204, 7896, 11577, 18327, 27126, 41499, 63890, 94292, 146868, 220803, 334101, 515427, 767158, 1180277, 1792257, 2699254, 4150971, 6234476, 9513326, 14503043, 21844540, 33464193, 50572025, 76791890, 117203170, 176919962, 269978089, 409518360, 620701102, 946769980, 1432425330, 2179891464, 3313098760, 5019998741, 7647429393, 11592793549, 17613677218, 26787722147, 40606587212, 61785908978, 93782469642, 142378448548, 216535535496, 328459363531, 499311227724, 758424611884, 1151180451897, 1750248695591, 2656505631025, 4035955078043, 6132262244005, 9308559309754, 14147652851398, 21482435588381],
-[1, 1, 2, 2, 4, 8, 16, 16, 25, 50, 64, 101, 147, 221, 363, 503, 832, 1256, 1750, 2922, 4271, 6433, 10018, 14632, 23352, 34404, 51826, 81796, 119292, 184786, 281475, 421096, 653449, 971694, 1491036, 2280773, 3402459, 5256711, 7919701, 12006261, 18400518, 27636871, 42351757, 64151465, 97018650, 148622594, 224039058, 341386844, 519367755, 785175458, 1199097883, 1814567390, 2757666159, 4199362447, 6353101738, 9681753364, 14688731817, 22286895137, 33934648427, 51414750525, 78212268366, 118806236894, 180206038135, 274235094736, 415957156574, 632038257790, 960655760096, 1457387651019, 2216200683651, 3364309057035, 5109263894120, 7766288333112, 11786094843111, 17912769086360, 27206002742029],
-[1, 1, 2, 2, 4, 8, 16, 16, 21, 42, 64, 107, 136, 204, 359, 507, 822, 1165, 1684, 2833, 4146, 6392, 9557, 14013, 22717, 33464, 50589, 78266, 115151, 180452, 271546, 408667, 632158, 938195, 1448326, 2203063, 3301363, 5090488, 7642655, 11660222, 17802006, 26749916, 41041738, 62008981, 94078478, 143830726, 216797337, 330935445, 502234584, 760624442, 1161308113, 1755749440, 2671953791, 4063377006, 6152224376, 9377474763, 14213135737, 21589203958, 32849512438, 49773718148, 75752925069, 114986894328, 174519083013, 265512732870, 402658558817, 612124129027, 929913791017, 1411150080489, 2145945078342, 3256803013863, 4947717713099, 7518705318076, 11411277489667, 17345183258468, 26337755409184],
-[1, 1, 2, 2, 4, 8, 16, 16, 23, 46, 64, 103, 148, 217, 342, 493, 838, 1207, 1672, 2828, 4248, 6381, 9567, 14256, 22886, 33580, 50759, 79312, 116585, 180522, 274116, 412911, 635594, 946636, 1458762, 2222464, 3325518, 5123423, 7722698, 11739703, 17924277, 26991004, 41341638, 62526618, 94779321, 144933590, 218674013, 333257899, 506346325, 766824979, 1169807315, 1770247477, 2692364536, 4095621522, 6201556974, 9447605074, 14329726620, 21756792215, 33102945968, 50176647150, 76332658322, 115902857483, 175891457484, 267569332487, 405891178192, 616849851057, 937247646197, 1422344029006, 2162561157544, 3282702088114, 4986377896418, 7577664012419, 11501675600112, 17480104025640, 26546126468453],
-[1, 1, 2, 2, 4, 8, 14, 16, 25, 42, 57, 100, 140, 210, 336, 483, 800, 1139, 1689, 2775, 3984, 6148, 9371, 13978, 22048, 32228, 49618, 76641, 112740, 175802, 264384, 399924, 615940, 918187, 1416125, 2143353, 3226455, 4972323, 7465548, 11387399, 17351220, 26150321, 40071951, 60503972, 91940370, 140321876, 211701283, 323187141, 490192494, 743059691, 1133243477, 1714274499, 2609864369, 3965593165, 6008404655, 9154876842, 13875511619, 21084211958, 32064362248, 48604723704, 73962809482, 112248265401, 170429373091, 259202071969, 393144560373, 597693005820, 907812752391, 1377957041865, 2095084030328, 3179711792305, 4831148662315, 7340245980999, 11142106024638, 16935064837651, 25713904606029],
-[1, 1, 2, 3, 6, 8, 14, 20, 25, 38, 63, 117, 158, 205, 360, 548, 815, 1259, 1850, 2875, 4231, 6616, 10424, 14793, 22835, 35427, 53339, 81647, 121223, 187169, 285552, 426537, 659934, 992378, 1502046, 2302978, 3471451, 5311357, 8023172, 12158988, 18634512, 28077909, 42776921, 65065774, 98425804, 150218365, 227369374, 345746233, 526071832, 796020103, 1213388573, 1840726030, 2793139039, 4251555356, 6443281984, 9802105868, 14885659747, 22584342470, 34364814742, 52122047036, 79206436606, 120376014347, 182640460460, 277712539742, 421568281268, 640301699152, 973177547733, 1476948502805, 2244739716062, 3409150139918, 5176599800350, 7867140661467, 11943749542250, 18145884551096, 27564707284390],
-[1, 1, 2, 3, 6, 8, 13, 18, 26, 47, 69, 111, 151, 221, 379, 530, 816, 1276, 1880, 2936, 4321, 6700, 10368, 14969, 23519, 35731, 53539, 82746, 123158, 189966, 288124, 432200, 668868, 1001693, 1524709, 2333725, 3509027, 5377629, 8122045, 12329829, 18856525, 28397519, 43365313, 65847756, 99651406, 152158102, 230113419, 350190158, 532486138, 806065828, 1229037003, 1862716992, 2828783176, 4305120153, 6522733209, 9926832331, 15068723220, 22869640852, 34796444210, 52764824661, 80214234832, 121868912868, 184920463573, 281217658431, 426795781517, 648372414392, 985317068026, 1495374845216, 2273038089448, 3451509006656, 5241584613381, 7965719430775, 12092576480498, 18374022555476, 27908545136338],
-[1, 1, 2, 3, 6, 8, 14, 20, 27, 50, 72, 111, 156, 227, 389, 580, 857, 1310, 1938, 3071, 4604, 6849, 10696, 15848, 24479, 37344, 55660, 86114, 129007, 196990, 301448, 451109, 693798, 1048506, 1588568, 2432825, 3661153, 5592920, 8490352, 12842816, 19639579, 29664566, 45136026, 68698214, 103920763, 158512715, 240177971, 364720494, 555327327, 840869663, 1280195998, 1943413776, 2948384453, 4487731071, 6803684519, 10343729356, 15716469596, 23842107933, 36267695560, 55035050046, 83602492701, 127070415400, 192815765103, 293119930612, 445084174881, 675873584424, 1027257375764, 1559281413150, 2369357106480, 3598974432179, 5464591440060, 8304298887438, 12609027980504, 19154118191290, 29098554489361],
-[1, 1, 2, 2, 3, 6, 12, 12, 19, 32, 40, 71, 99, 139, 215, 346, 545, 791, 1126, 1806, 2796, 4130, 6281, 9420, 14643, 21997, 33323, 51324, 76200, 117020, 178923, 269811, 411204, 619739, 947903, 1443159, 2174255, 3324488, 5035926, 7639781, 11648575, 17624844, 26846191, 40720364, 61771357, 94170874, 142573224, 216768678, 329540843, 499670724, 760465672, 1153459352, 1752427387, 2664311478, 4040553615, 6145517732, 9330675203, 14165389898, 21536491673, 32682535251, 49670249850, 75445921375, 114534674300, 174098929790, 264299402329, 401501560708, 610018313718, 926132872987, 1407331768836, 2137180957784, 3246008807428, 4931839188180, 7488534145591, 11377117388562, 17280600356567],
-[1, 1, 2, 2, 3, 6, 11, 11, 15, 26, 39, 65, 89, 137, 193, 296, 508, 736, 1034, 1627, 2515, 3889, 5754, 8578, 13403, 20034, 30816, 47015, 69871, 107557, 163009, 248425, 378015, 566797, 871034, 1320787, 1997291, 3053465, 4608491, 7022489, 10672497, 16161775, 24666743, 37299547, 56710020, 86359438, 130725033, 199083990, 301985083, 458507807, 697804784, 1057417904, 1608695532, 2443031550, 3706497960, 5639399795, 8554990078, 12999979951, 19753569892, 29974957189, 45579507944, 69187502691, 105084012364, 159711292255, 242402880639, 368399929400, 559492668578, 849599285285, 1291121235690, 1960176717600, 2978090991532, 4523857841850, 6869245034202, 10437631344605, 15850246696973],
-[1, 1, 2, 2, 3, 6, 12, 12, 17, 29, 42, 73, 91, 134, 233, 348, 532, 750, 1126, 1833, 2718, 4152, 6246, 9254, 14674, 21899, 33113, 50746, 75569, 117467, 177049, 267200, 410613, 614503, 943487, 1433505, 2160095, 3311663, 4989812, 7606398, 11590796, 17481619, 26723260, 40447709, 61413416, 93653455, 141543189, 215721795, 327413796, 496443022, 756580663, 1145662038, 1742466974, 2648406437, 4014824209, 6112095588, 9269991611, 14081973912, 21412494537, 32472689032, 49388574002, 74981194432, 113839150614, 173090046100, 262639995462, 399165433368, 606335541316, 920442810145, 1399146579195, 2124009547471, 3226672508345, 4902425604290, 7442661762693, 11310143535349, 17175424920705],
-[1, 1, 2, 2, 3, 6, 11, 12, 18, 29, 46, 72, 100, 140, 223, 359, 549, 809, 1141, 1831, 2898, 4259, 6478, 9620, 14884, 22887, 34140, 52396, 78562, 119735, 184417, 276559, 421637, 638933, 969134, 1484291, 2236257, 3405593, 5180831, 7834328, 11968419, 18117629, 27514243, 41883297, 63404536, 96640356, 146584473, 222371511, 338599093, 513118063, 780480875, 1185570318, 1798354708, 2736342832, 4150813382, 6307385801, 9586472426, 14543189330, 22114312681, 33573179127, 50986545949, 77497561643, 117614493640, 178749748318, 271487059003, 412217421230, 626488414362, 951122140840, 1444960158316, 2195102909884, 3333014620629, 5064525964879, 7690855217887, 11681789480002, 17747385994052],
-[1, 1, 2, 2, 3, 6, 12, 12, 18, 32, 43, 71, 103, 154, 224, 347, 567, 828, 1184, 1898, 2854, 4280, 6644, 9837, 15133, 22779, 34828, 53788, 79176, 121822, 186496, 280489, 429413, 646205, 987349, 1503291, 2263368, 3471262, 5247193, 7952875, 12147718, 18359390, 27986910, 42441030, 64347779, 98181229, 148513712, 225934963, 343552029, 520477718, 792673590, 1202007000, 1826187570, 2777137901, 4209657413, 6405458827, 9723980671, 14760106134, 22448221602, 34055305092, 51763926874, 78629684932, 119348478106, 181455020466, 275417937167, 418410402520, 635771643855, 965075631446, 1466705999883, 2227220487562, 3382657642657, 5139929427408, 7803691476384, 11856744500627, 18008977792139],
-[1, 1, 2, 2, 3, 6, 12, 12, 18, 33, 42, 70, 104, 152, 233, 345, 561, 810, 1162, 1912, 2886, 4282, 6541, 9798, 15340, 22780, 34457, 53597, 79221, 121920, 185918, 279594, 428833, 643775, 986344, 1502225, 2255518, 3462985, 5236082, 7942340, 12124819, 18303641, 27943197, 42353370, 64197505, 98016471, 148165892, 225474780, 342829611, 519355972, 791277864, 1199174155, 1822350674, 2771856817, 4200280703, 6392863028, 9703133556, 14729053097, 22403754058, 33980408742, 51660839500, 78466195561, 119091157482, 181093293972, 274831439619, 417547927573, 634461168435, 963019586623, 1463741493576, 2222524044397, 3375599455867, 5129419979138, 7787162513346, 11832398552763, 17971469211984],
-[1, 1, 2, 2, 3, 5, 10, 11, 17, 24, 33, 53, 78, 113, 172, 277, 411, 617, 913, 1427, 2206, 3250, 5025, 7510, 11424, 17559, 26447, 40416, 60785, 92395, 141955, 213462, 324693, 494026, 748235, 1142024, 1727137, 2626971, 3996897, 6045665, 9221810, 13987719, 21218806, 32297735, 48949224, 74492263, 113090536, 171564673, 261113296, 395978536, 601798007, 914590431, 1387576027, 2110010177, 3202719816, 4864897198, 7394108959, 11220552759, 17054977601, 25901258939, 39329455115, 59772425791, 90740980510, 137867359693, 209424793887, 317992178424, 483210819895, 733748381881, 1114551404986, 1693233785572, 2571176817845, 3906361786269, 5932865180056, 9011019905542, 13689503573090],
-[1, 1, 2, 2, 3, 6, 11, 13, 20, 31, 42, 63, 100, 159, 224, 331, 533, 798, 1175, 1815, 2793, 4186, 6323, 9708, 14820, 22099, 33676, 51814, 78098, 118228, 179935, 274168, 416016, 629452, 960203, 1457549, 2207401, 3361822, 5105262, 7750762, 11768539, 17874234, 27190182, 41238684, 62634932, 95245770, 144532214, 219605774, 333568459, 506612969, 769754803, 1168505224, 1775715341, 2697340173, 4095148342, 6222887679, 9450395768, 14354540541, 21805801409, 33114155219, 50310916205, 76407459648, 116050632113, 176307111767, 267746244209, 406723890234, 617788642855, 938304091473, 1425379030790, 2164807375146, 3288333315672, 4994935543018, 7586245799648, 11523916595006, 17503278922408],
-[1, 1, 2, 2, 3, 6, 12, 13, 15, 28, 45, 71, 92, 153, 252, 356, 564, 821, 1239, 1930, 2843, 4483, 6723, 9961, 15666, 23224, 35555, 54456, 81095, 125673, 188485, 286788, 440450, 657515, 1010757, 1531669, 2315318, 3548699, 5338151, 8152986, 12397420, 18719340, 28639304, 43281384, 65793654, 100240991, 151539997, 231141723, 350397497, 531719771, 810141625, 1226399349, 1866600323, 2835116749, 4299289965, 6545493673, 9923636685, 15082929368, 22925627324, 34769349190, 52892833705, 80274885533, 121913300990, 185339807921, 281208518751, 427475948576, 649187007699, 985644856210, 1498242525934, 2274178659562, 3455372453146, 5249209956541, 7969520180483, 12111321252611, 18390123136951],
-[1, 1, 2, 2, 3, 6, 12, 12, 15, 26, 43, 77, 90, 137, 229, 343, 559, 787, 1151, 1850, 2761, 4370, 6398, 9408, 15039, 22392, 34316, 52059, 77374, 120785, 181002, 275446, 422335, 629069, 970314, 1469947, 2221579, 3404869, 5112829, 7824275, 11895386, 17949843, 27481058, 41486830, 63115481, 96174176, 145310679, 221773024, 335978783, 509981058, 777275684, 1176025590, 1790636229, 2719090838, 4123295894, 6279421780, 9516762288, 14467825650, 21989654896, 33345365957, 50739254180, 76990075231, 116933759927, 177778410256, 269698661981, 410047168314, 622653073475, 945358877223, 1437123157022, 2181160597880, 3314344415019, 5034812983253, 7643742462768, 11617088751011, 17638387233540],
-[1, 1, 2, 2, 3, 6, 12, 12, 15, 26, 41, 72, 88, 139, 222, 334, 530, 755, 1158, 1791, 2631, 4168, 6268, 9310, 14417, 21472, 33311, 50491, 75189, 116580, 174852, 267012, 407912, 610841, 938943, 1418427, 2153528, 3293816, 4952409, 7571698, 11496496, 17396587, 26578145, 40147487, 61130310, 92986288, 140696167, 214614082, 325103794, 493810312, 751732028, 1138470319, 1733254580, 2630503176, 3991976666, 6075547361, 9210425021, 14004199385, 21275481061, 32279133936, 49099162086, 74503371681, 113188909109, 172019811920, 261035220055, 396837887252, 602535632644, 915027754351, 1390675905385, 2110952004050, 3207722704940, 4872156960870, 7398111447064, 11242376369299, 17069903518685],
-[1, 1, 2, 2, 3, 6, 12, 12, 16, 29, 42, 66, 93, 152, 229, 321, 524, 786, 1156, 1784, 2679, 4171, 6244, 9355, 14586, 21647, 33102, 50783, 76009, 116784, 175934, 267959, 409836, 614274, 942246, 1429143, 2160702, 3304454, 4987822, 7603850, 11553049, 17477077, 26695222, 40389166, 61373841, 93450388, 141456599, 215483763, 326861223, 496169038, 755285735, 1144344515, 1740870292, 2644294536, 4011389259, 6103301061, 9258391102, 14069124552, 21380273055, 32439348829, 49328490660, 74881178853, 113725055987, 172853712549, 262342011304, 398703829733, 605526624145, 919466730612, 1397360473523, 2121433247727, 3223024083164, 4896075088031, 7434263268267, 11296308062092, 17154202003850],
-[1, 1, 2, 2, 3, 6, 12, 13, 18, 30, 45, 70, 99, 151, 235, 342, 544, 832, 1192, 1847, 2836, 4385, 6618, 9777, 15207, 22974, 34784, 53356, 79840, 122281, 185789, 281404, 430351, 647119, 987473, 1504667, 2272261, 3469136, 5250867, 7979226, 12152978, 18385149, 28024050, 42502935, 64471244, 98221371, 148803104, 226313763, 343789476, 521454621, 793662606, 1203671134, 1828928355, 2780137207, 4217101223, 6413289546, 9736069370, 14784866556, 22474397395, 34105588533, 51836878016, 78730862635, 119533269351, 181680394793, 275813201673, 419022929285, 636572330003, 966514525644, 1468686168022, 2230269550915, 3387564524874, 5146668102806, 7814982799422, 11873104418327, 18033280199870],
-[1, 1, 2, 2, 3, 6, 11, 11, 15, 26, 41, 60, 85, 142, 199, 305, 492, 699, 1031, 1604, 2521, 3853, 5534, 8525, 13320, 19811, 30303, 45714, 69503, 106290, 159833, 245795, 370872, 559318, 859752, 1298325, 1974243, 2999031, 4540858, 6939678, 10489143, 15945641, 24292153, 36729523, 55971855, 84952646, 128916435, 196200977, 297226322, 452367522, 687085630, 1041929020, 1585791233, 2405369115, 3654895190, 5554893438, 8427579781, 12816172575, 19453171053, 29544603972, 44911726571, 68153307476, 103581636864, 157320054919, 238872142261, 363043877108, 551136727920, 837343687632, 1272027695581, 1931359124997, 2934841042942, 4456676971431, 6769355130542, 10284266737231, 15616528381096],
-[1, 1, 2, 2, 3, 6, 12, 12, 15, 29, 43, 75, 99, 153, 245, 348, 578, 825, 1228, 1985, 2891, 4516, 6705, 9953, 15924, 23499, 35822, 54714, 81412, 127335, 190228, 288166, 443750, 661790, 1020736, 1544469, 2329371, 3579303, 5375335, 8219603, 12502190, 18844272, 28875862, 43604729, 66300216, 101076865, 152608584, 232994583, 353121367, 535690680, 816757590, 1235467476, 1881162419, 2857437622, 4331455274, 6597954180, 9998910701, 15198778565, 23106828359, 35031837431, 53309166242, 80893428641, 122845722274, 186798696947, 283351165723, 430803882959, 654227281805, 993182869979, 1509967531251, 2291646674565, 3482101857437, 5290045656445, 8030637463691, 12205624230618, 18532073416184],
-[1, 1, 2, 2, 4, 8, 15, 15, 19, 38, 64, 112, 131, 191, 345, 506, 819, 1117, 1661, 2818, 4066, 6323, 9364, 13817, 22411, 32844, 50151, 76896, 113236, 178529, 266938, 403287, 622682, 923572, 1430506, 2166308, 3256333, 5021270, 7518530, 11506350, 17533145, 26361010, 40473046, 61039479, 92798397, 141714915, 213548642, 326379824, 494623691, 749789917, 1144586087, 1729558990, 2634496465, 4002918754, 6063174039, 9243711780, 14001680201, 21281807630, 32369300633, 49046763909, 74669459138, 113290962929, 172009792393, 261660259542, 396760538698, 603341508636, 916299573565, 1390707731989, 2114914477452, 3209170210583, 4876406629056, 7409154919398, 11245428751429, 17094541438081, 25953329499440],
-[1, 1, 2, 2, 4, 8, 16, 16, 22, 44, 64, 108, 145, 209, 352, 514, 845, 1178, 1682, 2893, 4284, 6435, 9634, 14340, 23114, 33959, 51311, 79702, 117228, 182882, 276713, 415760, 641003, 954718, 1472921, 2240330, 3354202, 5171340, 7781114, 11846173, 18088884, 27214753, 41702966, 63048787, 95633078, 146209867, 220475458, 336254802, 510685640, 773398239, 1180181891, 1785301874, 2716002816, 4130871564, 6254937619, 9531248901, 14451750945, 21946033746, 33391839356, 50607391834, 77001059102, 116900905956, 177417229256, 269901990313, 409379501199, 622235353873, 945360911035, 1434632021582, 2181409435978, 3311031088051, 5029721645756, 7643384756975, 11601077178451, 17632267243178, 26775642680406],
-[1, 1, 2, 2, 4, 8, 16, 16, 24, 48, 64, 91, 140, 225, 348, 469, 797, 1213, 1664, 2755, 4155, 6249, 9368, 14004, 22572, 32993, 49455, 77826, 115061, 176913, 268474, 405065, 624216, 929436, 1429928, 2183207, 3262393, 5021676, 7586030, 11521762, 17583649, 26479103, 40563220, 61395588, 92970585, 142194076, 214657042, 326922946, 496920481, 752502854, 1147806396, 1737293931, 2641435142, 4019490087, 6085847411, 9269005335, 14063156978, 21348536266, 32482786913, 49239646909, 74896983884, 113741365803, 172592419967, 262550230427, 398320001583, 605262051862, 919715448377, 1395727553306, 2122000747470, 3221363170842, 4892842727283, 7435821395679, 11286533657194, 17152224083837, 26049714924654],
-[1, 1, 2, 2, 4, 8, 16, 16, 21, 42, 64, 114, 142, 201, 353, 514, 835, 1188, 1712, 2858, 4175, 6474, 9780, 14159, 22813, 33943, 51423, 79408, 116434, 182580, 275428, 413614, 640606, 951198, 1465009, 2231574, 3345618, 5158137, 7741794, 11800833, 18041036, 27101874, 41556665, 62830309, 95276068, 145691795, 219623819, 335172153, 508845349, 770309660, 1176266045, 1778832669, 2706104543, 4116173822, 6231715711, 9498283601, 14398470927, 21866247601, 33275864752, 50418855656, 76726415809, 116483088411, 176770527093, 268944673089, 407879298517, 620010763684, 941982592657, 1429381001690, 2173654941222, 3299046320522, 5011544374823, 7616060352497, 11558930956637, 17569167694376, 26679017992031],
-[1, 1, 2, 2, 4, 8, 15, 16, 22, 40, 60, 109, 141, 201, 334, 498, 832, 1150, 1671, 2797, 4117, 6342, 9460, 14006, 22417, 33058, 50506, 77652, 114262, 178849, 269320, 406627, 625896, 931715, 1438927, 2181710, 3280264, 5051482, 7583881, 11575397, 17645996, 26574989, 40725164, 61495833, 93432236, 142657554, 215186248, 328464092, 498217885, 755180022, 1152031382, 1742410381, 2652408552, 4030883015, 6106753577, 9305423254, 14103453934, 21429134347, 32591698889, 49400604197, 75177163258, 114093964071, 173218297382, 263459890837, 399594476951, 607496248241, 922727406496, 1400532508190, 2129484837957, 3231897242628, 4910352189173, 7460813925007, 11324802476990, 17212943660390, 26136003410958],
-[1, 1, 2, 2, 4, 8, 16, 16, 24, 48, 64, 112, 162, 216, 340, 530, 877, 1231, 1718, 2938, 4441, 6514, 10013, 14871, 23361, 34859, 52767, 82293, 120417, 186099, 285651, 427094, 656168, 983753, 1507775, 2301244, 3445200, 5302880, 8010039, 12129957, 18569658, 27990971, 42740055, 64796090, 98127491, 150050975, 226577033, 344794993, 524678704, 794005777, 1210653681, 1834426688, 2786834645, 4241247012, 6422881975, 9779755742, 14844509089, 22523773827, 34276733828, 51972459571, 79018711304, 120040687779, 182134324743, 277037454907, 420380502027, 638641691547, 970610739417, 1472911694158, 2239049834571, 3399735368218, 5163034513083, 7846704767890, 11910866673997, 18099111345583, 27490807171723],
-[1, 1, 2, 2, 4, 8, 16, 16, 21, 42, 64, 108, 138, 204, 354, 506, 804, 1157, 1693, 2794, 4078, 6302, 9578, 13955, 22266, 33128, 50284, 77603, 114132, 178307, 269317, 404894, 625700, 931551, 1432555, 2180903, 3274041, 5040997, 7576044, 11541740, 17633807, 26522387, 40624958, 61452693, 93205652, 142423708, 214861014, 327742290, 497622187, 753562153, 1150011730, 1739983720, 2646576389, 4025022950, 6095776923, 9287712879, 14082358718, 21386303329, 32539277315, 49315907232, 75032252915, 113917666446, 172893777423, 263000967909, 398929834210, 606355053841, 921216961570, 1398015220181, 2125690241506, 3226525903105, 4901311242198, 7448143125778, 11305044300896, 17182010871880, 26091918053087],
-[1, 1, 2, 2, 4, 8, 16, 16, 22, 44, 64, 105, 139, 209, 353, 495, 819, 1192, 1694, 2825, 4130, 6349, 9659, 14135, 22695, 33335, 50597, 78947, 115565, 180083, 272127, 409620, 634055, 940912, 1449711, 2208435, 3306351, 5102910, 7667302, 11672140, 17839637, 26812483, 41126639, 62165495, 94229666, 144155069, 217294319, 331535291, 503479199, 762120326, 1163645601, 1759778786, 2677196919, 4072713006, 6164818049, 9396379486, 14245856389, 21632230137, 32920854095, 49881012463, 75906558792, 115242425173, 174878534303, 266078879231, 403532741545, 613373714649, 931945185463, 1414124424169, 2150448021266, 3263861798095, 4957988449434, 7534868200682, 11435537023925, 17381515769609, 26394598737023],
-[1, 1, 2, 2, 4, 7, 13, 14, 21, 41, 54, 89, 128, 185, 306, 443, 710, 1026, 1458, 2480, 3681, 5427, 8319, 12357, 19659, 29174, 43759, 68355, 100643, 155853, 237709, 355114, 547917, 820020, 1257314, 1919731, 2870317, 4420660, 6674827, 10120579, 15488152, 23310084, 35642113, 54034168, 81800386, 125110804, 188837196, 287511303, 437410798, 661843352, 1009607882, 1529034678, 2323233877, 3536334750, 5354185413, 8154102789, 12374433317, 18777530692, 28579293831, 43323639079, 65880853714, 100076908517, 151834296452, 230977694930, 350447850637, 532440030467, 809195578806, 1227883808654, 1866765927193, 2834244226503, 4304313767763, 6541866814517, 9929605022087, 15089348425254, 22918551724382],
-[1, 1, 2, 2, 4, 8, 15, 15, 24, 48, 63, 96, 144, 216, 353, 515, 811, 1170, 1681, 2855, 4280, 6240, 9518, 14331, 22702, 33692, 50424, 78618, 116452, 179626, 274280, 410333, 630609, 947175, 1451213, 2214003, 3314342, 5093209, 7707302, 11682295, 17854798, 26916633, 41101969, 62345848, 94421433, 144275606, 217985309, 331649036, 504603257, 763982860, 1164431068, 1764522320, 2680630777, 4079414946, 6179452067, 9406204383, 14278476894, 21667882856, 32968410327, 49996140531, 76007795469, 115464841449, 175206631203, 266468674693, 404385595925, 614324623133, 933595253361, 1416872808531, 2153717432823, 3270259303841, 4966464051055, 7547597641706, 11457522797348, 17409544226368, 26443502699337],
-[1, 1, 2, 2, 4, 8, 16, 16, 22, 44, 64, 113, 148, 206, 351, 521, 863, 1197, 1704, 2930, 4303, 6497, 9865, 14529, 23295, 34271, 52087, 81053, 118297, 184995, 280389, 420678, 649938, 966454, 1490551, 2268080, 3394700, 5242489, 7875843, 11984103, 18326421, 27550155, 42231651, 63835974, 96799356, 148091291, 223168020, 340483734, 517184181, 782806101, 1195127834, 1807581560, 2749849168, 4183023615, 6331911645, 9651492915, 14632614775, 22217986252, 33813432737, 51235443240, 77964466256, 118367129651, 179621709224, 273295868828, 414477350872, 630003746124, 957228916963, 1452477833930, 2208748788982, 3352400781925, 5092458196237, 7739209287067, 11745673687951, 17852885161229, 27110549121822],
-[1, 1, 2, 2, 4, 8, 15, 15, 20, 40, 60, 104, 136, 193, 326, 480, 779, 1098, 1583, 2658, 3927, 6000, 9024, 13264, 21226, 31520, 47792, 73777, 108591, 169464, 256310, 385432, 594292, 885585, 1363381, 2074657, 3113209, 4792459, 7207846, 10978146, 16764639, 25230769, 38636808, 58435981, 88649365, 135453035, 204368847, 311665166, 473238868, 716792517, 1093632186, 1654817163, 2517136018, 3827872816, 5797669441, 8832807311, 13393386239, 20340116291, 30944902011, 46904152259, 71359976094, 108340023420, 164435359410, 250123478446, 379411680393, 576677512060, 876115393321, 1329623132708, 2021621485097, 3068613298209, 4661472233972, 7083520924494, 10751856284462, 16340975070353, 24814868882396],
-[1, 1, 2, 3, 6, 8, 12, 17, 26, 46, 67, 97, 140, 215, 362, 523, 764, 1194, 1792, 2789, 4169, 6298, 9729, 14416, 22310, 34046, 50812, 78099, 117780, 179960, 274010, 411522, 631969, 955299, 1448424, 2214669, 3340132, 5093824, 7731250, 11717934, 17884986, 27032030, 41135954, 62579263, 94754417, 144374060, 218870647, 332469511, 505800156, 766485534, 1166509219, 1770689250, 2687318672, 4088375324, 6200950598, 9425807776, 14318989107, 21730694673, 33044448994, 50151417105, 76188371527, 115777914688, 175723680936, 267088393305, 405572369122, 615941536628, 935994168250, 1420970776981, 2159069188070, 3279411106151, 4979903510892, 7566865502308, 11490172188025, 17454449221346, 26514829724042],
-[1, 1, 2, 3, 6, 8, 14, 20, 26, 47, 70, 108, 157, 230, 390, 557, 830, 1322, 1934, 2983, 4482, 6828, 10657, 15547, 24034, 36883, 54867, 84927, 127404, 194180, 296822, 444707, 685689, 1033912, 1563689, 2400071, 3612296, 5515008, 8370972, 12663908, 19372018, 29242949, 44511728, 67770331, 102446164, 156297896, 236872864, 359675785, 547633515, 829057443, 1262540676, 1916545078, 2907111425, 4425819181, 6709115212, 10199796118, 15498745091, 23510463299, 35765991489, 54269193618, 82439730763, 125313311164, 190132442301, 289052182068, 438909506272, 666478642828, 1013019125226, 1537605735305, 2336477311197, 3549055072011, 5388608789446, 8189145842870, 12433949587810, 18888088706403, 28694876813131],
-[1, 1, 2, 2, 3, 6, 12, 12, 19, 30, 43, 69, 93, 137, 208, 321, 522, 793, 1095, 1714, 2668, 4056, 6157, 9043, 14128, 21390, 32315, 49862, 74068, 113282, 173178, 261503, 400211, 601100, 916891, 1401395, 2110063, 3225471, 4884556, 7406968, 11305372, 17089003, 26046125, 39524384, 59881405, 91354782, 138328535, 210272277, 319719521, 484543789, 737815170, 1119040879, 1699566947, 2585054524, 3919195151, 5961223168, 9052113736, 13739827838, 20893842150, 31701848842, 48180376601, 73194992152, 111095164364, 168890295957, 256388819247, 389459017998, 591786232836, 898358530999, 1365202704731, 2073236510799, 3148648778173, 4784341310637, 7264194803447, 11036275366374, 16763543467709],
-[1, 1, 2, 2, 3, 6, 12, 13, 18, 31, 46, 69, 101, 151, 227, 349, 550, 814, 1163, 1814, 2853, 4308, 6373, 9638, 14963, 22639, 34128, 52120, 78665, 119646, 182369, 277077, 420745, 635245, 969117, 1476548, 2232752, 3395892, 5156822, 7834304, 11908525, 18061271, 27476742, 41697876, 63289543, 96301185, 146117764, 221954813, 337196446, 511978459, 778278630, 1181276236, 1794505166, 2726913129, 4139316132, 6290353667, 9553403282, 14508478218, 22043878968, 33471394516, 50854468291, 77242286111, 117298247748, 178220580685, 270650509249, 411116311071, 624511191863, 948426631564, 1440832412141, 2188308299408, 3323825577637, 5049205393562, 7668325698961, 11648631199880, 17693246715537],
-[1, 1, 2, 2, 3, 6, 12, 13, 16, 28, 43, 73, 102, 146, 226, 342, 552, 794, 1144, 1866, 2786, 4273, 6425, 9479, 15031, 22352, 33978, 52310, 77542, 120257, 181614, 274296, 421642, 630239, 967934, 1471519, 2214833, 3400354, 5122706, 7801240, 11893116, 17936211, 27432465, 41507097, 62995760, 96126552, 145236536, 221355580, 336025975, 509352568, 776416372, 1175582360, 1788010865, 2718024901, 4119270973, 6272117738, 9512924833, 14449408380, 21973866279, 33320411712, 50680638346, 76944915476, 116810291194, 177625610679, 269505325483, 409596344154, 622216043438, 944489284738, 1435765084885, 2179562416094, 3311002064133, 5030780396862, 7637179510230, 11605993031132, 17624828094558],
-[1, 1, 2, 2, 3, 6, 12, 13, 16, 28, 46, 72, 95, 151, 245, 354, 552, 811, 1211, 1864, 2804, 4434, 6553, 9747, 15249, 22868, 35005, 53013, 79711, 122837, 184557, 282051, 430400, 644810, 989138, 1500003, 2275044, 3468659, 5230901, 7994158, 12132721, 18364197, 28034591, 42402909, 64502959, 98106044, 148605345, 226408736, 343126152, 521284288, 793273413, 1201939314, 1828824609, 2776497384, 4214245676, 6410602967, 9722888734, 14780337968, 22453196847, 34073573956, 51814555265, 78642102993, 119465211097, 181539357215, 275544956877, 418810310294, 635948242391, 965810207783, 1467682764247, 2228150372042, 3385474071090, 5142243013148, 7808718756377, 11865085696728, 18016997011897],
-[1, 1, 2, 2, 3, 6, 12, 13, 16, 28, 46, 76, 97, 146, 240, 352, 548, 810, 1199, 1888, 2815, 4384, 6625, 9710, 15241, 22910, 34805, 53310, 79422, 122727, 185268, 280504, 431032, 645032, 987161, 1502462, 2268082, 3471395, 5233835, 7973047, 12149766, 18339603, 28013913, 42427219, 64391931, 98158157, 148485103, 226184778, 343315059, 520591793, 793154891, 1201611240, 1826989952, 2776901766, 4210488915, 6407918984, 9721235549, 14766533525, 22451036861, 34053459348, 51783199666, 78626977502, 119375090624, 181484581633, 275418521755, 418544218487, 635786803443, 965203340834, 1467046260145, 2227279229019, 3383406809981, 5140488496427, 7804549154406, 11859246457946, 18010063592119],
-[1, 1, 2, 2, 3, 6, 12, 12, 17, 30, 42, 64, 89, 141, 222, 319, 502, 736, 1069, 1711, 2553, 3918, 5953, 8781, 13911, 20554, 31147, 48375, 71498, 110531, 167249, 252651, 388823, 580189, 890738, 1356195, 2038084, 3131194, 4720030, 7180476, 10953895, 16512792, 25262832, 38233200, 57980871, 88537538, 133741691, 203789214, 309496281, 468950053, 714989145, 1082536960, 1646215260, 2503307632, 3792727385, 5775337530, 8760641536, 13303987481, 20235651975, 30681509517, 46666278279, 70858266745, 107553622743, 163568319398, 248171287988, 377147248303, 572982413838, 869681210385, 1322095120877, 2007040421221, 3048737854734, 4632623582198, 7032418976177, 10686975157720, 16229783714895],
-[1, 1, 2, 2, 3, 6, 12, 12, 18, 32, 42, 64, 95, 154, 223, 318, 532, 806, 1141, 1775, 2689, 4139, 6283, 9367, 14585, 21654, 33067, 51178, 75941, 116304, 176609, 268238, 410205, 614652, 942122, 1432768, 2159368, 3307490, 4998535, 7597851, 11567764, 17493629, 26712110, 40440657, 61368927, 93574480, 141589277, 215528491, 327301023, 496406008, 755866295, 1145484286, 1741703490, 2647217982, 4013778723, 6107504129, 9268212075, 14076519561, 21399534945, 32465196352, 49361177217, 74952253466, 113797868870, 172997366807, 262562389174, 398965095574, 606069881602, 920138202530, 1398424934140, 2123229735012, 3225283131803, 4900192291228, 7439979081550, 11304726344102, 17168619275795],
-[1, 1, 2, 2, 3, 6, 12, 12, 14, 26, 41, 67, 88, 138, 205, 307, 503, 702, 1030, 1604, 2452, 3868, 5643, 8383, 13207, 19689, 30189, 45795, 68428, 105891, 159156, 243161, 370608, 554175, 853285, 1291687, 1956820, 2989763, 4502986, 6884116, 10446594, 15808503, 24156655, 36490921, 55536541, 84515437, 127905878, 194991909, 295433107, 448816615, 683208923, 1034641980, 1575001065, 2390805121, 3627955433, 5520998141, 8371077077, 12726802121, 19334603165, 29335637098, 44620756309, 67711095454, 102862462480, 156329432157, 237237623440, 360635698007, 547586955768, 831579953816, 1263830132389, 1918456307380, 2915130727735, 4427842053410, 6723430572844, 10216891145960, 15513295189191],
-[1, 1, 2, 2, 3, 5, 9, 10, 12, 20, 33, 56, 66, 109, 179, 261, 406, 578, 891, 1369, 2064, 3280, 4748, 7097, 11282, 16878, 25630, 38409, 58376, 90927, 135015, 205998, 314846, 472450, 726770, 1096883, 1666391, 2541284, 3826110, 5866672, 8885618, 13441382, 20541584, 31042255, 47291602, 71845102, 108787917, 165953853, 251213734, 381903801, 581183412, 880059461, 1340031250, 2033229657, 3087069638, 4696791971, 7119327789, 10828678944, 16446160487, 24955961713, 37960258859, 57593436816, 87514875748, 132977766804, 201809306056, 306817548803, 465776252666, 707445598391, 1075124176357, 1631939964992, 2480006155944, 3766473156217, 5719647700658, 8691532566685, 13196310980547],
-[1, 1, 2, 2, 3, 6, 12, 12, 17, 28, 42, 68, 87, 138, 230, 336, 503, 736, 1129, 1783, 2608, 4065, 6116, 9027, 14283, 21402, 32360, 49309, 73987, 114854, 172280, 260543, 400728, 600544, 920187, 1397201, 2110470, 3229744, 4867338, 7427766, 11304749, 17058436, 26070279, 39473030, 59952488, 91326248, 138135906, 210540031, 319396638, 484509365, 738133494, 1117952183, 1700367227, 2583735885, 3918352513, 5963486204, 9044545967, 13742609850, 20891426490, 31687608745, 48191053716, 73159932522, 111091094396, 168881636609, 256280270243, 389506224810, 591602682087, 898183823425, 1365203714940, 2072522662101, 3148573179479, 4783400087471, 7262526961353, 11035986896370, 16758830443998],
-[1, 1, 2, 2, 3, 6, 11, 12, 16, 28, 48, 74, 94, 149, 237, 355, 549, 797, 1211, 1853, 2835, 4418, 6483, 9796, 15189, 22894, 35004, 52674, 79929, 122675, 184448, 282170, 428256, 645898, 988718, 1496025, 2276327, 3459721, 5231086, 7989784, 12104962, 18372301, 27981935, 42360622, 64493035, 97919827, 148561840, 226129294, 342711376, 521126028, 791987037, 1201167555, 1827224695, 2772768456, 4212121333, 6402436876, 9714124689, 14768672201, 22424633719, 34050676060, 51758262482, 78560606348, 119373651141, 181326835423, 275316235013, 418404131620, 635270747215, 965026604908, 1466090878547, 2226100464171, 3382373630065, 5136788993083, 7801958716565, 11853052590814, 17999525594612],
-[1, 1, 2, 2, 3, 6, 12, 12, 17, 33, 41, 72, 110, 163, 242, 362, 582, 850, 1273, 2009, 2985, 4548, 6970, 10446, 16007, 24055, 36898, 56481, 84051, 129361, 196465, 296949, 453635, 683884, 1045715, 1586226, 2398397, 3669525, 5544335, 8427903, 12833761, 19424817, 29605930, 44863189, 68141238, 103762052, 157095877, 239115482, 363124702, 550846898, 838287349, 1271181792, 1932427632, 2936128423, 4454182329, 6775414528, 10282476089, 15617954811, 23738864240, 36024113376, 54759136133, 83151892638, 126267780871, 191911674134, 291313115912, 442634533074, 672373973808, 1020912187655, 1551383822077, 2355674329035, 3578378242160, 5436215995142, 8254658572496, 12541762980493, 19047571525382],
-[1, 1, 2, 2, 3, 6, 12, 12, 19, 34, 42, 70, 106, 155, 220, 346, 574, 851, 1187, 1905, 2942, 4315, 6654, 10028, 15433, 23134, 35153, 54606, 80777, 123148, 189296, 285318, 435023, 656348, 1001704, 1526992, 2298132, 3517526, 5334955, 8071607, 12319621, 18652012, 28395390, 43095023, 65311805, 99627826, 150862051, 229213533, 348745239, 528528935, 804301761, 1220473189, 1853495384, 2818943173, 4274141919, 6500077951, 9872705373, 14982926875, 22783090995, 34575993297, 52537750388, 79819645721, 121154908825, 184170588359, 279611361680, 424690843140, 645353322560, 979716884249, 1488697052546, 2260964261800, 3433649940015, 5217304093772, 7921920099958, 12034928778768, 18281196469241],
-[1, 1, 2, 2, 3, 6, 12, 12, 18, 30, 43, 68, 98, 150, 221, 332, 534, 792, 1148, 1779, 2765, 4233, 6296, 9454, 14575, 22035, 33626, 51161, 76882, 117444, 178534, 271700, 412430, 622138, 950898, 1444793, 2188958, 3332409, 5048562, 7680997, 11669816, 17700920, 26942444, 40840059, 62057258, 94386314, 143148956, 217648440, 330401564, 501798193, 762876120, 1157491959, 1759334859, 2672195310, 4056709185, 6166441442, 9361362733, 14221558789, 21605092272, 32802553054, 49849156524, 75696006804, 114970972085, 174680909617, 265238881280, 402974871785, 612054354942, 929551118517, 1412231108101, 2144623533852, 3257863833656, 4948656655098, 7515567876244, 11417386823272, 17340441727173],
-[1, 1, 2, 2, 3, 6, 11, 12, 18, 29, 41, 67, 96, 151, 226, 332, 535, 786, 1166, 1823, 2716, 4173, 6338, 9471, 14645, 21940, 33579, 51428, 76551, 117834, 178559, 270659, 413719, 621652, 952152, 1445136, 2183842, 3341086, 5044945, 7678198, 11685989, 17677668, 26969847, 40841241, 62036359, 94489278, 143018261, 217762503, 330563728, 501529382, 763443303, 1157183890, 1759603250, 2673444607, 4055241949, 6169662577, 9361346460, 14220929160, 21615107099, 32796971398, 49862878650, 75707884534, 114965270216, 174744180293, 265228435688, 403038058280, 612192537529, 929523691130, 1412604368854, 2144774886401, 3258174126105, 4949757148997, 7515729454387, 11419628645206, 17342618975633],
-[1, 1, 2, 2, 4, 8, 16, 16, 22, 44, 64, 108, 143, 206, 353, 520, 831, 1196, 1707, 2817, 4236, 6444, 9772, 14258, 22681, 34103, 51289, 79301, 116958, 181611, 276163, 414094, 638679, 953983, 1462064, 2232686, 3349160, 5145879, 7758707, 11789700, 18028963, 27141749, 41493827, 62883149, 95264538, 145584201, 219858898, 334845326, 508976422, 770563868, 1175364852, 1779974517, 2705004143, 4115772053, 6233919210, 9492925395, 14403432113, 21863503379, 33267379835, 50434565965, 76699372226, 116494319905, 176774214039, 268876661101, 407960777472, 619886169560, 941953394415, 1429486943132, 2173192443314, 3299356864553, 5011085680668, 7615376987674, 11559675993773, 17566471438324, 26679550434455],
-[1, 1, 2, 2, 4, 8, 16, 16, 25, 50, 64, 109, 163, 221, 345, 529, 854, 1248, 1748, 2902, 4389, 6481, 10084, 14823, 23077, 34882, 52578, 81936, 120305, 185045, 284798, 425137, 653817, 982536, 1498577, 2293758, 3436432, 5278486, 7988273, 12077702, 18505803, 27903491, 42546741, 64619584, 97753949, 149444895, 225894064, 343407076, 522934688, 791134573, 1205926926, 1828655128, 2775931178, 4226195136, 6401001450, 9741807817, 14794493637, 22440568214, 34152062377, 51793815626, 78718618349, 119624674136, 181479230209, 276013272527, 418924365595, 636278973625, 967155491944, 1467668193229, 2230808534254, 3387810008810, 5144206484807, 7818425002119, 11868651924391, 18032872667783, 27393166535660],
-[1, 1, 2, 2, 4, 8, 16, 16, 23, 46, 64, 107, 143, 211, 369, 521, 834, 1200, 1735, 2937, 4243, 6452, 9895, 14482, 23247, 34266, 51779, 80739, 118201, 184755, 279442, 418499, 649029, 964811, 1484877, 2263033, 3384765, 5227371, 7855468, 11950209, 18285670, 27460544, 42107851, 63699395, 96511195, 147685610, 222557872, 339532076, 515880725, 780511901, 1191966019, 1802797342, 2741824874, 4172249358, 6314475139, 9624811415, 14593525840, 22155412607, 33724295399, 51094624790, 77747587726, 118053826592, 179121301636, 272555652934, 413355193896, 628260563523, 954660524641, 1448470795555, 2202733940542, 3343349463084, 5078380475205, 7718303635743, 11713578741350, 17804004454846, 27037190499433],
-[1, 1, 2, 2, 4, 7, 14, 14, 17, 34, 52, 92, 118, 171, 279, 412, 685, 959, 1372, 2230, 3336, 5256, 7759, 11304, 18071, 26925, 41197, 62963, 92832, 145076, 218097, 330732, 508603, 755735, 1166176, 1769963, 2667287, 4097581, 6149782, 9398628, 14315810, 21571240, 33049824, 49900141, 75834624, 115718170, 174676890, 266610140, 404164867, 612919859, 934839847, 1414032803, 2152615065, 3270523765, 4956408271, 7551039263, 11444293587, 17392252362, 26445741324, 40089944423, 61007688249, 92583970459, 140578664028, 213785285177, 324276833446, 493007499584, 748757788427, 1136590813488, 1728064776407, 2622673494638, 3984904633837, 6054293618864, 9190378056490, 13968402698823, 21209111792671],
-[1, 1, 2, 2, 4, 8, 16, 16, 23, 46, 64, 96, 136, 217, 359, 486, 788, 1193, 1693, 2755, 4070, 6258, 9513, 13960, 22327, 32974, 49679, 77581, 114496, 177078, 267943, 403736, 624196, 928737, 1426136, 2176836, 3260834, 5018699, 7563298, 11501076, 17567425, 26431952, 40490715, 61292082, 92840570, 141945023, 214248074, 326511134, 496085976, 751099354, 1146066964, 1734474803, 2637214308, 4012569877, 6075769205, 9254946243, 14038602709, 21313843197, 32431559948, 49156008335, 74776189952, 113553190497, 172312253289, 262124656814, 397647050504, 604295086824, 918207870859, 1393406621163, 2118579330942, 3216029228779, 4884871649628, 7423646791155, 11267894255093, 17124479370465, 26006651039335],
-[1, 1, 2, 2, 4, 8, 14, 14, 21, 42, 62, 93, 126, 201, 342, 487, 751, 1094, 1623, 2659, 3925, 5953, 9006, 13417, 21266, 31565, 47671, 73525, 109377, 169436, 256075, 386017, 593542, 888607, 1363581, 2074308, 3119566, 4786649, 7220714, 10991608, 16759166, 25270156, 38631692, 58499837, 88755777, 135425320, 204650620, 311788202, 473500900, 717667387, 1093803168, 1656487087, 2518784151, 3829661579, 5804101648, 8835990838, 13403968176, 20356818273, 30959153972, 46948499025, 71399564953, 108413788495, 164573306986, 250252264468, 379729167697, 577061197103, 876662836357, 1330714363109, 2022811489904, 3070918896155, 4664814642439, 7087936182259, 10760291726774, 16351461139049, 24832325797401],
-[1, 1, 2, 2, 4, 8, 16, 16, 22, 44, 64, 111, 149, 209, 343, 507, 849, 1202, 1691, 2854, 4244, 6459, 9711, 14278, 22915, 33893, 51396, 79751, 116931, 182231, 276172, 415357, 640559, 953090, 1468666, 2236630, 3350808, 5163851, 7767109, 11821441, 18061027, 27173738, 41631930, 62950717, 95458014, 145959293, 220135301, 335695857, 509852697, 772036188, 1178199910, 1782503240, 2711356876, 4124045256, 6244494892, 9515117841, 14428321986, 21909054983, 33336543751, 50523362290, 76870679776, 116710144860, 177120674894, 269450452340, 408703122748, 621190115849, 943801068442, 1432241455264, 2177761162678, 3305561923301, 5021295712291, 7630700798777, 11581819065940, 17602783473008, 26731283125839],
-[1, 1, 2, 2, 4, 8, 15, 15, 20, 40, 64, 105, 132, 199, 337, 478, 788, 1145, 1640, 2659, 3939, 6201, 9218, 13422, 21631, 32068, 48727, 75029, 110730, 172726, 259647, 393096, 606534, 900023, 1387536, 2110943, 3172971, 4879188, 7328202, 11189260, 17059469, 25672414, 39362180, 59464953, 90263128, 137859957, 208025961, 317455475, 481506632, 729765111, 1113560781, 1684119959, 2563071299, 3896235775, 5902131618, 8992914214, 13631388007, 20710450944, 31500730584, 47743371301, 72655183335, 110277967181, 167405648170, 254628790570, 386211449323, 587121252259, 891821047819, 1353570803046, 2058119078428, 3123633692819, 4745648784590, 7210887292071, 10945261413538, 16635840553706, 25260378401393],
-[1, 1, 2, 2, 4, 8, 16, 16, 23, 46, 64, 99, 142, 217, 350, 491, 805, 1196, 1685, 2763, 4129, 6281, 9540, 14061, 22364, 33129, 50027, 77999, 114947, 177574, 269761, 406207, 626096, 933643, 1433319, 2187148, 3277588, 5041301, 7604026, 11552976, 17648186, 26579400, 40673342, 61586517, 93304381, 142620596, 215344772, 328026912, 498523956, 754883143, 1151385974, 1743210607, 2650045581, 4031807785, 6106000462, 9299109594, 14108252954, 21417730889, 32586907528, 49400869815, 75136261743, 114107347763, 173159170208, 263387803862, 399606833139, 607225375937, 922676968135, 1400257667797, 2128811697702, 3231795790829, 4908700843451, 7459689442861, 11323159860028, 17207578348647, 26133707010919],
-[1, 1, 2, 2, 4, 8, 15, 16, 26, 48, 60, 98, 150, 221, 346, 505, 817, 1200, 1725, 2829, 4197, 6279, 9722, 14456, 22543, 33585, 50932, 79261, 116779, 179967, 274707, 412341, 634079, 951191, 1455593, 2218690, 3330232, 5118482, 7732149, 11723259, 17917841, 27026983, 41270192, 62566923, 94794722, 144793496, 218797993, 333024417, 506457907, 766859767, 1168811325, 1771205200, 2691299802, 4094415898, 6202946330, 9442513898, 14331648786, 21752163420, 33093014993, 50185202065, 76299758161, 115898151429, 175882778607, 267480868491, 405908936304, 616688433265, 937121753938, 1422282139705, 2161932840635, 3282619632157, 4985496522969, 7576196611168, 11501124791040, 17476072978660, 26543572285265],
-[1, 1, 2, 2, 4, 8, 16, 16, 23, 46, 64, 104, 148, 214, 340, 500, 824, 1221, 1696, 2757, 4206, 6366, 9702, 14179, 22391, 33694, 50748, 78854, 116093, 178977, 273658, 410871, 632128, 945369, 1446653, 2212657, 3317243, 5092778, 7694664, 11669121, 17848196, 26899019, 41084229, 62303633, 94324950, 144170337, 217862320, 331495924, 504200065, 763281226, 1163786144, 1763395914, 2678742586, 4076614892, 6174838912, 9400041299, 14268536889, 21653149842, 32946802458, 49958474060, 75956376437, 115388571210, 175083216631, 266287459745, 404098048379, 613907467577, 932962853406, 1415868896296, 2152266299579, 3267981601329, 4963009254822, 7542485236813, 11449524007215, 17397639089497, 26425200754802],
-[1, 1, 2, 2, 4, 8, 16, 16, 26, 52, 64, 104, 161, 226, 353, 524, 853, 1262, 1754, 2946, 4426, 6477, 10101, 14923, 23415, 35051, 52627, 82621, 121094, 186280, 286598, 427263, 658425, 987826, 1508862, 2310495, 3452137, 5313015, 8040755, 12151598, 18627368, 28057343, 42828325, 65031059, 98321281, 150447072, 227235848, 345522830, 526301932, 795886494, 1213719103, 1839750083, 2792995474, 4253491638, 6439525971, 9803333387, 14886853202, 22577853394, 34369246268, 52110812247, 79211352892, 120374701676, 182589939685, 277757453778, 421516001590, 640225935185, 973227654005, 1476708863734, 2244795105674, 3408869788810, 5176068157690, 7867458689027, 11942042676452, 18145369208166, 27564014807992],
-[1, 1, 2, 3, 6, 8, 15, 22, 27, 44, 68, 115, 167, 225, 378, 578, 874, 1355, 1933, 3049, 4642, 6976, 10901, 15864, 24565, 37834, 56255, 87081, 130007, 198408, 304756, 455225, 701278, 1058305, 1600202, 2458709, 3695907, 5646051, 8572379, 12951161, 19838750, 29942078, 45551447, 69380142, 104834824, 160039453, 242484216, 368065910, 560729018, 848520391, 1292346715, 1962124052, 2975366585, 4530918035, 6867135333, 10440539885, 15867166013, 24062760597, 36613036480, 55552890774, 84382519349, 128284572932, 194613668991, 295887859832, 449294288282, 682187712621, 1037016342169, 1573904471332, 2391654266632, 3633048606885, 5515744795910, 8382873832557, 12727723805812, 19334077866445, 29373800886898],
-[1, 1, 2, 3, 5, 6, 11, 16, 19, 29, 49, 83, 113, 166, 262, 390, 577, 915, 1408, 2042, 3061, 4868, 7444, 10882, 16557, 25640, 38943, 58651, 89129, 136073, 205556, 312350, 477392, 722139, 1093409, 1665341, 2535244, 3846372, 5826795, 8867388, 13483496, 20442448, 31073139, 47215419, 71680140, 108874320, 165376487, 251353410, 381563012, 579368462, 880687064, 1337303379, 2031008042, 3085326105, 4686037722, 7118904666, 10810730973, 16422297511, 24947641451, 37883869369, 57551423984, 87418181807, 132771732558, 201683796658, 306316301875, 465311856705, 706765681418, 1073443594163, 1630644007084, 2476683446119, 3761896620027, 5714251501855, 8679126790525, 13183517831957, 20024222973424],
-[1, 1, 2, 2, 3, 6, 10, 12, 21, 41, 48, 74, 120, 173, 285, 398, 636, 976, 1372, 2297, 3364, 4948, 7818, 11501, 18130, 27025, 40300, 63654, 93425, 143861, 220693, 327824, 508378, 761626, 1161540, 1781311, 2655769, 4095365, 6195166, 9358078, 14362804, 21591308, 32996465, 50129616, 75705090, 115938671, 174996529, 266174124, 405599174, 612853157, 935265451, 1417217905, 2151135915, 3277737876, 4959758490, 7552666368, 11468747508, 17390123444, 26482394948, 40139473264, 61020198328, 92742003641, 140641402864, 213995197451, 324711142011, 493183046346, 749802374383, 1137499348673, 1729384271423, 2626102815401, 3987214401482, 6061169756097, 9199309268585, 13978590970368, 21234829407118],
-[1, 1, 2, 2, 3, 6, 12, 12, 19, 36, 41, 67, 110, 167, 235, 348, 595, 892, 1236, 1982, 3030, 4503, 6910, 10406, 16190, 23970, 36334, 56828, 84067, 128322, 196158, 296042, 453304, 681018, 1041989, 1587793, 2384546, 3658604, 5541660, 8393869, 12805660, 19357874, 29539231, 44782715, 67852158, 103581415, 156692914, 238295652, 362413528, 549118481, 836233875, 1267829459, 1926369796, 2930110266, 4440612845, 6756621513, 10258391507, 15570602523, 23680765497, 35924480707, 54606802967, 82948670689, 125896066379, 191421745283, 290546231351, 441373165029, 670682817863, 1018063490958, 1547248981226, 2349509632165, 3568341266911, 5422223380452, 8232131529931, 12507680050953, 18997959865321],
-[1, 1, 2, 2, 3, 6, 11, 11, 16, 28, 42, 70, 90, 137, 219, 335, 528, 777, 1151, 1779, 2724, 4236, 6260, 9275, 14573, 22101, 33405, 50621, 76351, 117652, 177243, 269255, 411383, 618412, 945844, 1437623, 2177317, 3317268, 5013606, 7646474, 11620776, 17583297, 26812403, 40629267, 61727877, 93915607, 142315944, 216618243, 328631633, 498972204, 759275337, 1151086074, 1750088344, 2658461161, 4034687669, 6135659201, 9310319598, 14146733694, 21495689970, 32623316194, 49593625413, 75297776442, 114358797522, 173783426642, 263815904973, 400885787705, 608852081237, 924587099704, 1404951531778, 2133249522385, 3240752723080, 4922873442641, 7475660009696, 11358022586616, 17249017015184],
-[1, 1, 2, 2, 3, 5, 10, 10, 15, 22, 31, 52, 68, 111, 167, 258, 391, 594, 917, 1351, 2027, 3156, 4818, 7213, 10934, 16611, 25442, 38502, 58232, 88881, 134136, 204612, 311491, 471059, 716779, 1086657, 1654876, 2514648, 3806495, 5797322, 8799483, 13358715, 20317258, 30814760, 46851799, 71141306, 108003231, 164259439, 249196165, 378634263, 575363258, 873334707, 1327450943, 2015311566, 3061268621, 4651628168, 7061383288, 10730756402, 16297025619, 24748783745, 37605334198, 57101018066, 86748044208, 131766376030, 200101868698, 304023536813, 461700841441, 701311226539, 1065358533043, 1617913062002, 2457869430788, 3733066746027, 5670062847979, 8613334090788, 13081387677528],
-[1, 1, 2, 2, 3, 6, 12, 12, 16, 30, 40, 72, 99, 148, 229, 351, 572, 788, 1186, 1923, 2845, 4336, 6531, 9822, 15341, 22714, 34848, 53403, 79163, 122981, 185397, 280619, 429722, 644166, 991521, 1499504, 2264098, 3475096, 5231371, 7977002, 12137215, 18338495, 28029774, 42369458, 64425731, 98168460, 148360451, 226231170, 343164347, 520637821, 793042663, 1200867187, 1827546245, 2775841230, 4209294305, 6408449259, 9717085598, 14766186549, 22445779513, 34044943207, 51784084090, 78596961633, 119362920701, 181463367657, 275335340215, 418518654692, 635625690893, 965040163927, 1466866738622, 2226674052900, 3383075212197, 5139432559808, 7802902537564, 11857858703014, 18005836356155],
-[1, 1, 2, 2, 3, 6, 11, 12, 17, 28, 38, 64, 96, 149, 212, 321, 524, 726, 1111, 1776, 2616, 4011, 6033, 9207, 14220, 20831, 32379, 49478, 73526, 113740, 171100, 260983, 397535, 596694, 919733, 1385712, 2099734, 3218230, 4844539, 7396601, 11224066, 17000666, 25972417, 39210746, 59725813, 90872086, 137439379, 209621776, 317664024, 482538166, 734332613, 1112133009, 1693536526, 2570048358, 3899834211, 5935704568, 8998752875, 13681596422, 20785068491, 31537140349, 47969895298, 72785744090, 110582944012, 168063962310, 255026478444, 387697875378, 588665452824, 893974427144, 1358657856255, 2062347135124, 3133907735179, 4760002130031, 7227778985780, 10983603073570, 16677011483644],
-[1, 1, 2, 2, 3, 6, 12, 12, 21, 37, 43, 64, 104, 158, 237, 354, 563, 839, 1180, 1963, 2976, 4269, 6635, 10076, 15651, 23302, 34876, 54715, 81215, 123724, 190330, 284794, 436464, 658949, 1004158, 1534887, 2299261, 3526123, 5356675, 8089221, 12368513, 18687024, 28464467, 43261269, 65447667, 99963796, 151271307, 229740503, 349970787, 529805728, 806723421, 1224075655, 1857896080, 2828215057, 4285434230, 6518112850, 9902807462, 15020728797, 22852769168, 34672476383, 52680197937, 80061328738, 121474464982, 184707958185, 280415847799, 425838415485, 647266943264, 982399860956, 1492924147374, 2267517092696, 3443019965833, 5232486254768, 7944105789886, 12068645165300, 18334103091726],
-[1, 1, 2, 2, 3, 6, 12, 12, 21, 36, 41, 68, 109, 154, 221, 364, 570, 840, 1180, 1901, 2982, 4257, 6595, 10026, 15293, 23169, 34898, 54146, 80658, 122180, 189019, 283694, 431142, 655161, 995177, 1519873, 2287875, 3491521, 5317677, 8023036, 12251584, 18577499, 28201171, 42908045, 64973514, 99044383, 150207942, 227808763, 347040370, 525918483, 799554715, 1214862215, 1843073036, 2804038574, 4253306696, 6462972072, 9824367551, 14902687292, 22659716342, 34406008067, 52246469860, 79411734739, 120525353648, 183169789680, 278206700159, 422398996762, 641980293111, 974672991616, 1480654957439, 2249400897733, 3415461625557, 5189722015916, 7881120511175, 11970561071806, 18186400252570],
-[1, 1, 2, 2, 3, 6, 12, 12, 18, 34, 42, 72, 114, 163, 232, 358, 587, 854, 1234, 1978, 3014, 4498, 6856, 10322, 15865, 23831, 36344, 55982, 83123, 127385, 194823, 293829, 447826, 675805, 1033265, 1570913, 2368732, 3624169, 5488445, 8323808, 12687020, 19208482, 29254608, 44364947, 67320436, 102595730, 155346494, 236215637, 359074830, 544498703, 828525825, 1256842222, 1909694315, 2902918103, 4402838225, 6696311128, 10166768731, 15435730080, 23465990971, 35612827069, 54122490084, 82205405505, 124805638901, 189701140255, 287988150150, 437494672641, 664682920815, 1009165004923, 1533462980573, 2328726670851, 3537012875833, 5373811432166, 8159815692380, 12396911176716, 18829355054890],
-[1, 1, 2, 2, 3, 6, 12, 12, 16, 28, 42, 68, 89, 143, 231, 341, 546, 775, 1127, 1817, 2746, 4243, 6268, 9357, 14822, 22035, 33554, 51330, 76497, 118308, 178431, 271261, 414843, 620182, 954013, 1448151, 2185644, 3345477, 5043009, 7694591, 11701501, 17680143, 27023954, 40860459, 62097352, 94630036, 143113067, 218090539, 330785475, 502020767, 764629669, 1157905677, 1761776246, 2676351094, 4058816192, 6177836579, 9369218195, 14237265132, 21639677524, 32825185581, 49925773232, 75781882438, 115083086937, 174947392818, 265477314211, 403505326442, 612826716486, 930459533819, 1414237593131, 2146873673604, 3261742100011, 4955117952133, 7523287088031, 11432427049769, 17360349245270],
-[1, 1, 2, 2, 3, 6, 12, 12, 17, 30, 41, 62, 91, 147, 220, 321, 512, 751, 1090, 1722, 2647, 4012, 5943, 9031, 14096, 20891, 31800, 48860, 73197, 111997, 169764, 258571, 393138, 590920, 907224, 1376764, 2079146, 3174783, 4805626, 7315895, 11110793, 16832975, 25674909, 38865246, 59058709, 89916549, 136190645, 207236747, 314537267, 477616231, 726543363, 1101393966, 1675034853, 2544383674, 3860668035, 5871713592, 8910589997, 13538085028, 20570463577, 31220694687, 47462494128, 72057401915, 109437759288, 166315983413, 252469685164, 383636155767, 582670858149, 884830427493, 1344529473648, 2041481996148, 3101389117498, 4711197116643, 7154075445449, 10869558264938, 16507277776131],
-[1, 1, 2, 2, 3, 6, 12, 12, 18, 32, 42, 61, 89, 156, 248, 335, 519, 798, 1194, 1879, 2734, 4217, 6444, 9633, 15178, 22308, 33788, 52381, 78240, 120498, 181213, 274786, 422839, 632409, 970017, 1473707, 2219475, 3404336, 5137191, 7824350, 11908192, 17970525, 27491493, 41608757, 63139985, 96282611, 145569653, 221825031, 336696671, 510560433, 777927372, 1178033877, 1791848919, 2723647800, 4128613349, 6284507372, 9532772443, 14481434073, 22018439337, 33392747856, 50786561766, 77105046781, 117063700700, 177990625665, 270084899802, 410463551110, 623501378532, 946528890662, 1438763383967, 2184171609481, 3318044757463, 5041256849231, 7653524555379, 11630368401232, 17661919433583],
-[1, 1, 2, 2, 3, 6, 12, 13, 18, 30, 44, 70, 100, 151, 236, 347, 559, 835, 1203, 1902, 2852, 4379, 6664, 9918, 15501, 23050, 35069, 54187, 80502, 123653, 187519, 283946, 435410, 652661, 999281, 1520272, 2290578, 3510496, 5302394, 8059398, 12280962, 18555738, 28338066, 42918100, 65109174, 99297952, 150208531, 228686146, 347333796, 526603795, 802085326, 1215443238, 1847832399, 2809034108, 4258529939, 6480579481, 9834046709, 14934593850, 22707676367, 34445668691, 52373267376, 79530665431, 120738690895, 183562950436, 278586272193, 423311271630, 643086542861, 976269550675, 1483805073097, 2252853023764, 3422079485094, 5199409553181, 7894003442968, 11994787947871, 18216774551228],
-[1, 1, 2, 2, 3, 6, 10, 12, 20, 28, 38, 68, 99, 145, 214, 335, 534, 750, 1175, 1803, 2623, 4121, 6211, 9416, 14305, 21380, 33435, 50018, 75166, 116549, 174429, 266425, 405030, 611790, 938624, 1410105, 2151794, 3283884, 4942884, 7560159, 11451875, 17380777, 26496632, 40037593, 61073838, 92666276, 140414152, 214142036, 324222374, 493030530, 749474002, 1136045142, 1729815017, 2622945312, 3984650045, 6060320616, 9188125632, 13975709056, 21219047119, 32212983215, 48983056214, 74317472223, 112957300726, 171586520417, 260449382466, 395944748584, 601048751997, 913059575229, 1387316469083, 2106063331888, 3200591362813, 4860271074097, 7381656121766, 11216107889560, 17029679400955],
-[1, 1, 2, 2, 3, 6, 12, 12, 16, 30, 43, 69, 99, 149, 225, 339, 552, 766, 1120, 1863, 2759, 4178, 6309, 9368, 14754, 21891, 33484, 51524, 75869, 118043, 178703, 269389, 413622, 618611, 951935, 1444473, 2173315, 3341863, 5029865, 7658692, 11680461, 17616224, 26942373, 40743885, 61871025, 94426481, 142580785, 217383706, 330006334, 500172757, 762479291, 1154324055, 1756047374, 2669102483, 4044757206, 6160028196, 9341465580, 14189203911, 21579252369, 32720013974, 49771467861, 75558241674, 114708347794, 174438567544, 264646022009, 402236590570, 611028847386, 927484233006, 1409971141795, 2140315663200, 3251483641163, 4940331226971, 7499660495668, 11397458218940, 17307689047064],
-[1, 1, 2, 2, 4, 8, 15, 15, 21, 42, 60, 101, 139, 198, 331, 491, 792, 1103, 1584, 2699, 4027, 6026, 9072, 13507, 21530, 31901, 48207, 74672, 110077, 171141, 259864, 390170, 600255, 897059, 1379969, 2100328, 3149214, 4845885, 7303142, 11105427, 16960295, 25547541, 39081368, 59151171, 89707552, 137065070, 206887390, 315262227, 479004856, 725507985, 1106444380, 1675006297, 2547004830, 3873856277, 5867794123, 8937134436, 13556163718, 20582842301, 31313845637, 47472789036, 72207967275, 109644569980, 166408325484, 253108034937, 383994485461, 583553730437, 886633126276, 1345617024771, 2045742731414, 3105564743686, 4717271680978, 7168385571822, 10881145430077, 16536277877857, 25113106913266],
-[1, 1, 2, 2, 4, 8, 15, 15, 20, 40, 63, 100, 127, 199, 343, 483, 773, 1121, 1632, 2675, 3963, 6145, 9103, 13400, 21654, 32094, 48397, 74445, 110576, 172390, 259054, 391329, 603314, 897930, 1383349, 2104179, 3161664, 4857215, 7307148, 11153737, 16998139, 25587526, 39205807, 59274524, 89967669, 137353593, 207348088, 316278349, 479862570, 727368997, 1109514362, 1678501606, 2553958356, 3882632931, 5882548728, 8960657082, 13585035587, 20638640802, 31389798499, 47582806962, 72398707186, 109898060229, 166830475621, 253732891237, 384898460923, 585071818928, 888725333212, 1348931250606, 2050910847405, 3112918951305, 4729195954174, 7185796237017, 10907690631273, 16577841076195, 25173235822611],
-[1, 1, 2, 2, 4, 8, 15, 16, 23, 42, 60, 105, 141, 206, 339, 496, 803, 1164, 1701, 2739, 4030, 6265, 9537, 13933, 21950, 32863, 50112, 77016, 113566, 176670, 267182, 402765, 620470, 926368, 1421855, 2162762, 3255926, 5002242, 7525772, 11461531, 17494013, 26362034, 40322484, 61018074, 92591133, 141330100, 213451569, 325416429, 494027724, 748543614, 1141459623, 1728131092, 2628214181, 3995715530, 6054621850, 9220678648, 13983862203, 21238815949, 32304243874, 48977697462, 74499687595, 113111859220, 171701665826, 261118350338, 396156999239, 602096176640, 914682726848, 1388327200927, 2110598040519, 3203926750063, 4867033471209, 7395349330778, 11226301777569, 17060775602653, 25908390158702],
-[1, 1, 2, 2, 4, 8, 16, 16, 23, 46, 64, 109, 148, 211, 348, 505, 836, 1235, 1729, 2847, 4216, 6453, 9925, 14369, 22864, 34122, 51610, 80478, 117649, 182688, 277977, 416812, 645016, 959964, 1472529, 2250551, 3368787, 5191520, 7815955, 11867972, 18171799, 27321239, 41829767, 63350462, 95906187, 146750970, 221383157, 337352675, 512875627, 775907501, 1184429896, 1792771359, 2724854831, 4147296484, 6277808645, 9564613723, 14509753793, 22021774771, 33519524176, 50797751947, 77269735562, 117360284504, 178054333445, 270897926592, 410937489475, 624448870913, 948975357800, 1439892351721, 2189378126550, 3323623832430, 5047858783721, 7672061139148, 11644215745219, 17696536523269, 26876638279699],
-[1, 1, 2, 2, 4, 8, 16, 16, 22, 44, 64, 102, 140, 212, 346, 488, 814, 1194, 1671, 2749, 4128, 6347, 9475, 13935, 22422, 33166, 50031, 77731, 114734, 177864, 269012, 406004, 626012, 931021, 1432980, 2184817, 3275023, 5037471, 7587671, 11551843, 17629327, 26538527, 40655610, 61495083, 93225306, 142486059, 215065874, 327826286, 497837301, 754122724, 1150504475, 1740911376, 2647828082, 4027364886, 6099369663, 9291164218, 14091028255, 21398221235, 32553770438, 49344411688, 75069689869, 113979694646, 172984613031, 263128848187, 399162172973, 606654706540, 921688928447, 1398796333134, 2126747273398, 3228271032860, 4903856767764, 7451999177312, 11311215052651, 17190640715765, 26105886992635],
-[1, 1, 2, 2, 4, 7, 12, 14, 21, 37, 49, 81, 115, 174, 280, 395, 667, 959, 1379, 2291, 3330, 5121, 7743, 11514, 18380, 26802, 40982, 63730, 93490, 145617, 219546, 331576, 511439, 760375, 1174061, 1781657, 2672475, 4123590, 6194892, 9441082, 14399489, 21675917, 33244248, 50199603, 76201011, 116448638, 175578781, 267984714, 406683898, 616125388, 940152653, 1421774199, 2164173589, 3290111952, 4982381062, 7593317074, 11509789217, 17484510259, 26598287525, 40309691204, 61344624819, 93109632482, 141335842249, 215002442257, 326076597226, 495701527383, 753008481772, 1142808741281, 1737730778254, 2637326341626, 4006771336994, 6088422008283, 9241028094753, 14046017441676, 21327986160130],
-[1, 1, 2, 2, 4, 8, 14, 16, 26, 44, 57, 97, 144, 215, 334, 488, 787, 1153, 1702, 2728, 4013, 6131, 9391, 13985, 21774, 32471, 49539, 76264, 113071, 174764, 264785, 399459, 613214, 920236, 1409946, 2141479, 3227661, 4952006, 7469660, 11359979, 17318972, 26151653, 39939911, 60484349, 91801601, 139989486, 211652625, 322403123, 489624710, 742209892, 1130701713, 1713145184, 2604825988, 3959441342, 6002372939, 9136080146, 13860788795, 21051087793, 32010077154, 48551809891, 73827147115, 112101685570, 170190199433, 258756755657, 392670869422, 596707771729, 906487154384, 1376094976832, 2091597286104, 3175519921792, 4823736670242, 7329030774042, 11127015980196, 16907972253594, 25677757365278],
-[1, 1, 2, 2, 4, 8, 16, 16, 22, 44, 64, 108, 146, 209, 347, 512, 815, 1179, 1700, 2787, 4157, 6337, 9667, 14140, 22294, 33501, 50717, 78295, 115451, 179009, 272281, 408888, 629628, 941778, 1442679, 2200513, 3305595, 5076963, 7654994, 11631676, 17778101, 26787343, 40932889, 62025631, 94009266, 143588101, 216916147, 330358225, 502072021, 760319870, 1159345617, 1756071573, 2668889786, 4059817958, 6150592613, 9364785307, 14209183588, 21570851218, 32816849478, 49758467285, 75666684541, 114920711264, 174406395742, 265245643546, 402473649268, 611553637293, 929239600813, 1410301584519, 2143894206673, 3254931017387, 4943756381582, 7512650681779, 11404306063194, 17329972436969, 26320123062026],
-[1, 1, 2, 2, 4, 8, 16, 16, 23, 46, 64, 108, 145, 211, 364, 520, 835, 1213, 1738, 2898, 4236, 6482, 9919, 14440, 23098, 34327, 51805, 80591, 118246, 184284, 279137, 418399, 648265, 964568, 1481542, 2260830, 3385060, 5218645, 7849864, 11936615, 18264046, 27446298, 42049252, 63652900, 96416945, 147489746, 222428602, 339142304, 515360607, 779827798, 1190553986, 1801413490, 2738882018, 4167869461, 6309220839, 9613829994, 14580426678, 22133929588, 33688379940, 51049435372, 77664504785, 117941362877, 178951907347, 272266200227, 412976811863, 627622299541, 953710718951, 1447106334601, 2200454682138, 3340176298861, 5073348958642, 7710539730192, 11702476718400, 17785929021554, 27010896344416],
-[1, 1, 2, 2, 4, 8, 15, 15, 23, 46, 60, 100, 145, 205, 331, 486, 800, 1165, 1630, 2744, 4069, 6064, 9412, 13835, 21846, 32425, 49038, 76897, 112272, 173717, 265648, 397346, 613633, 916253, 1404558, 2146042, 3208464, 4947477, 7462020, 11306098, 17319802, 26061354, 39861624, 60407982, 91421868, 139913883, 211099169, 321463150, 489104798, 739785721, 1128876036, 1709472392, 2597437824, 3954101572, 5985181375, 9117101633, 13835365549, 20992435064, 31954850437, 48433790822, 73657410090, 111892223488, 169746006271, 258252834496, 391797678651, 595274095316, 904736956075, 1372754892590, 2087140534806, 3168744257288, 4812251459714, 7314182059012, 11101296664820, 16870425895267, 25623716819259],
-[1, 1, 2, 2, 4, 8, 16, 16, 25, 50, 64, 89, 144, 230, 355, 485, 787, 1206, 1686, 2755, 4161, 6198, 9460, 14158, 22366, 33069, 49583, 77856, 115436, 176526, 269436, 405496, 623508, 933802, 1429125, 2184387, 3270229, 5021707, 7605621, 11524026, 17599516, 26545240, 40556519, 61508786, 93094812, 142269667, 215068478, 327111652, 497650369, 753560331, 1148456866, 1740370312, 2643894399, 4023808901, 6095008265, 9276472131, 14083605184, 21372355243, 32516126373, 49311766357, 74966479178, 113886815198, 172808651699, 262816005563, 398861039829, 605908057813, 920809069689, 1397503168425, 2124221579409, 3225511931326, 4898459985326, 7444283902623, 11300826504512, 17171066985050, 26081648289967],
-[1, 1, 2, 2, 4, 8, 16, 16, 25, 50, 64, 98, 145, 221, 364, 508, 822, 1250, 1743, 2873, 4287, 6423, 9939, 14541, 23132, 34480, 51521, 80975, 119000, 183522, 280321, 419038, 648595, 968190, 1480950, 2269048, 3388780, 5218261, 7883806, 11940612, 18293740, 27511066, 42077833, 63837664, 96507949, 147721851, 222991123, 339371775, 516556323, 781156734, 1192060255, 1805481848, 2742124796, 4176055252, 6320732633, 9625999311, 14611692322, 22166108507, 33744661834, 51147467978, 77773555455, 118168319862, 179241292195, 272702517982, 413762118780, 628560990241, 955419341061, 1449605561939, 2203927524524, 3346305552572, 5081451450766, 7723765832483, 11723008315272, 17814328164755, 27059050352977],
-[1, 1, 2, 3, 6, 8, 13, 19, 26, 47, 71, 102, 146, 229, 381, 554, 805, 1251, 1905, 2910, 4436, 6658, 10167, 15289, 23501, 35970, 53539, 82002, 124837, 189428, 288423, 434992, 664797, 1008513, 1526895, 2332627, 3527998, 5360287, 8156560, 12364294, 18830121, 28531905, 43344211, 65975931, 99962265, 152073610, 230925858, 350458641, 533061596, 808630545, 1229200200, 1867269239, 2833508754, 4308909706, 6540500195, 9934690606, 15096996259, 22915516956, 34828353187, 52887508609, 80318682519, 122055649844, 185299775795, 281537029420, 427646457768, 649393863005, 986712604845, 1498347464585, 2276076195293, 3457586556266, 5250583867108, 7977033111903, 12115191607542, 18401461028980, 27954277522067],
-[1, 1, 2, 3, 5, 8, 15, 18, 23, 42, 64, 108, 147, 212, 364, 519, 824, 1239, 1769, 2876, 4254, 6565, 10000, 14510, 23166, 34765, 52139, 80799, 119595, 185394, 280872, 421471, 652426, 973210, 1489112, 2276670, 3414148, 5246913, 7909754, 12021824, 18384256, 27653903, 42310957, 64135427, 97102699, 148416155, 224137044, 341418809, 518933362, 785474177, 1198452358, 1814651008, 2757564569, 4196540591, 6355295415, 9678213853, 14684279521, 22289643639, 33919020398, 51415741628, 78195882589, 118771784698, 180216685748, 274130666654, 415917149136, 631978316533, 960352129837, 1457340361991, 2215644425654, 3363739627409, 5108776790449, 7764122983672, 11785102504779, 17909315810464, 27200274370720],
-[1, 1, 2, 3, 6, 8, 13, 20, 26, 40, 69, 115, 141, 212, 373, 548, 791, 1189, 1900, 2865, 4166, 6593, 10020, 14767, 22812, 34833, 52855, 79576, 120670, 186389, 279329, 423462, 649919, 979756, 1491341, 2263631, 3441020, 5239803, 7908500, 12069096, 18348591, 27755908, 42296878, 64150601, 97488377, 148155782, 224611730, 342020383, 518744796, 787554757, 1198305099, 1817231898, 2762455989, 4195585853, 6369535090, 9684665076, 14694752803, 22330874362, 33929702103, 51496701845, 78276807145, 118851810312, 180522864514, 274294446559, 416422566733, 632791404164, 961021441856, 1459445208050, 2217605396392, 3367266067471, 5115426655628, 7770298368125, 11800224540729, 17928039798187, 27226470540423],
-[1, 1, 2, 2, 3, 6, 12, 13, 17, 30, 43, 80, 113, 158, 252, 394, 607, 861, 1297, 2073, 3122, 4728, 7213, 10796, 16569, 25114, 38232, 58258, 87167, 134143, 203957, 307679, 469934, 709925, 1082589, 1644520, 2488808, 3800609, 5748154, 8734423, 13306998, 20145588, 30667031, 46522758, 70640911, 107529322, 162911177, 247809236, 376445117, 571055274, 868824097, 1318115413, 2002838438, 3043493020, 4618096977, 7022419068, 10660215006, 16189356838, 24606808954, 37347753779, 56758052238, 86202040804, 130895160080, 198923732279, 302006377530, 458823242954, 697001002266, 1058336095915, 1608106352313, 2442067612723, 3709357379208, 5635204315515, 8557287182117, 13000578358521, 19745536700605],
-[1, 1, 2, 2, 3, 6, 12, 12, 16, 30, 41, 65, 101, 158, 209, 316, 538, 795, 1140, 1724, 2699, 4172, 6171, 9347, 14335, 21473, 33120, 50468, 75664, 115057, 174661, 268029, 405404, 610320, 935138, 1417570, 2151875, 3274488, 4957896, 7551802, 11449518, 17395953, 26489855, 40089196, 60986989, 92701049, 140631155, 213896601, 324416560, 493143339, 749391446, 1136814899, 1729032076, 2624500888, 3985527457, 6058194961, 9194906196, 13974559803, 21221397333, 32224524254, 48977921308, 74351143194, 112959870603, 171600224392, 260554893627, 395918500112, 601216461538, 913237255565, 1387384988313, 2106717606809, 3200745381001, 4861287575778, 7383292132859, 11216699055830, 17034253848658],
-[1, 1, 2, 2, 3, 6, 11, 12, 18, 28, 37, 64, 90, 146, 221, 329, 515, 764, 1183, 1782, 2606, 4096, 6248, 9400, 14320, 21427, 33144, 50294, 75570, 116084, 174372, 266234, 406287, 612181, 935916, 1414028, 2151244, 3282286, 4951428, 7553733, 11462026, 17379820, 26496018, 40097294, 61022264, 92714111, 140521357, 214071604, 324497122, 492998005, 749713863, 1136732978, 1729447553, 2624828659, 3985445345, 6060727448, 9193821618, 13976157022, 21228203708, 32223188573, 48988491375, 74357026899, 112972030590, 171639773047, 260554051990, 395998320073, 601300590905, 913281955188, 1387677125363, 2106879082036, 3201185037574, 4862014198022, 7383844900903, 11218750241909, 17035936537865],
-[1, 1, 2, 2, 3, 6, 12, 12, 15, 26, 41, 74, 88, 136, 237, 348, 534, 752, 1156, 1825, 2644, 4265, 6337, 9248, 14672, 21882, 33781, 50831, 75544, 118831, 176996, 269254, 413659, 616393, 950964, 1436337, 2176479, 3336982, 4998493, 7665807, 11649349, 17568653, 26909774, 40602223, 61836304, 94144675, 142196935, 217262178, 328869545, 499298413, 761109111, 1151191016, 1753606154, 2661673691, 4037049996, 6149265870, 9315317800, 14166877249, 21529850238, 32645013015, 49683118183, 75370262695, 114494961454, 174062742952, 264030222066, 401508615500, 609591960534, 925571874333, 1407119334117, 2135394034242, 3245162594440, 4929358658541, 7483666816011, 11374541381643, 17268551243036],
-[1, 1, 2, 2, 3, 5, 9, 10, 13, 21, 32, 55, 67, 109, 175, 251, 393, 583, 884, 1336, 2002, 3177, 4732, 6988, 10849, 16390, 25110, 38043, 57002, 87785, 132212, 201681, 308107, 461776, 707053, 1073178, 1628234, 2482804, 3743482, 5715297, 8683465, 13143201, 20052834, 30346988, 46143672, 70196065, 106337478, 161970626, 245559485, 372923795, 567544955, 860139838, 1308365022, 1986690841, 3015243784, 4586535910, 6957406984, 10574390587, 16065736354, 24380365290, 37070520589, 56271928760, 85474904125, 129890165766, 197158009579, 299644260125, 455037272100, 691029520289, 1050102777515, 1594290123295, 2422232806252, 3679309876219, 5587159104198, 8489290787411, 12891404084045],
-[1, 1, 2, 2, 3, 6, 12, 12, 16, 28, 41, 72, 89, 139, 234, 340, 516, 733, 1138, 1830, 2637, 4062, 6201, 9136, 14316, 21406, 32788, 49961, 73977, 115595, 174015, 262499, 403383, 604022, 928109, 1406396, 2123254, 3258593, 4899237, 7475031, 11391455, 17180406, 26265226, 39729277, 60380651, 92035526, 139038341, 212077419, 321725992, 487847017, 743535969, 1125720289, 1712752144, 2602264592, 3945280616, 6007497219, 9108421572, 13839616016, 21042731260, 31909959281, 48539175105, 73679088788, 111877662054, 170104274055, 258082419041, 392295477713, 595841811874, 904533716634, 1375016430391, 2087219864779, 3171042747496, 4817660508335, 7313943832468, 11115115672782, 16878160734476],
-[1, 1, 2, 2, 3, 6, 12, 12, 16, 28, 40, 69, 89, 137, 223, 332, 512, 735, 1115, 1762, 2593, 4020, 6060, 9012, 14097, 20945, 32238, 49065, 73129, 113336, 170334, 259288, 396590, 593952, 912530, 1381787, 2091826, 3200836, 4818909, 7359263, 11185845, 16908955, 25836814, 39057922, 59409818, 90445641, 136814389, 208599311, 316220341, 480060682, 731010396, 1107095764, 1684850077, 2558405030, 3881227098, 5907177981, 8957097436, 13614547173, 20689278658, 31386244682, 47737797135, 72451699938, 110046623475, 167268751851, 253826719408, 385832633464, 585916772612, 889683182641, 1352211504573, 2052663743837, 3118830341303, 4737622923698, 7193434377123, 10931220234958, 16598487141611],
-[1, 1, 2, 2, 3, 6, 12, 12, 16, 28, 41, 74, 93, 147, 235, 337, 558, 807, 1221, 1922, 2794, 4436, 6642, 9842, 15472, 22872, 35337, 53912, 79959, 124472, 186353, 283876, 435624, 649519, 1001148, 1513890, 2290004, 3513683, 5275226, 8067935, 12261900, 18512761, 28344475, 42783859, 65099656, 99170875, 149835421, 228730321, 346515621, 525957129, 801494938, 1212770621, 1846846759, 2804077951, 4252261870, 6475856488, 9814227420, 14920841923, 22677303771, 34388879095, 52326602531, 79396044060, 120595026768, 183340154815, 278132539097, 422877544613, 642124240681, 974943084933, 1482078731472, 2249375901053, 3418062057011, 5192269624336, 7882874623477, 11980561819918, 18190049443032],
-[1, 1, 2, 2, 3, 6, 11, 11, 16, 26, 42, 69, 87, 134, 212, 322, 501, 729, 1097, 1705, 2529, 3988, 5935, 8745, 13807, 20624, 31571, 47829, 71534, 111198, 166515, 253424, 388048, 580828, 892392, 1351576, 2046898, 3130026, 4710612, 7201200, 10942113, 16532455, 25270783, 38202993, 58111144, 88452718, 133806326, 204057388, 309241349, 469515414, 715046294, 1082749463, 1647895850, 2502198999, 3796155543, 5777783475, 8759969368, 13316341554, 20235547716, 30696550361, 46691753597, 70860964829, 107632937286, 163599000662, 248252981651, 377377395434, 573052935771, 870157793386, 1322563370561, 2007599191112, 3050426905171, 4633663167319, 7035574989067, 10691488040965, 16234130634674],
-[1, 1, 2, 2, 3, 6, 12, 13, 16, 27, 44, 81, 92, 138, 244, 364, 546, 793, 1233, 1896, 2786, 4419, 6678, 9673, 15151, 23181, 35198, 53075, 79468, 123747, 185940, 281125, 432211, 648046, 990601, 1505448, 2280935, 3483397, 5244356, 8007549, 12195757, 18408485, 28099467, 42570581, 64673441, 98455293, 149008703, 227081554, 344423953, 522516770, 795945086, 1205965651, 1833749187, 2785972787, 4226429617, 6431085486, 9754316921, 14821456554, 22529314348, 34176420200, 51969498642, 78900958640, 119815775760, 182120032612, 276398023812, 420072013784, 638012492927, 968693125694, 1472273951089, 2235191194560, 3395662910576, 5158600462272, 7832627563543, 11901774638839, 18073808350942],
-[1, 1, 2, 2, 3, 6, 12, 12, 14, 26, 42, 77, 92, 144, 241, 345, 557, 802, 1227, 1909, 2732, 4469, 6632, 9638, 15339, 22772, 35149, 53174, 79057, 124159, 184086, 280818, 432908, 642892, 992344, 1498837, 2270899, 3485646, 5215610, 8004045, 12157586, 18324548, 28107327, 42379416, 64528558, 98275492, 148387039, 226843431, 343235955, 521075178, 794611758, 1201313748, 1830513481, 2778427531, 4213209539, 6419156744, 9722095194, 14787521472, 22474029248, 34069702470, 51862310994, 78667822134, 119502773333, 181694123258, 275569645458, 419099424697, 636276428806, 966050527170, 1468793909120, 2228780443762, 3387231475133, 5145240314590, 7810999672473, 11872795235177, 18024177290180],
-[1, 1, 2, 2, 3, 6, 11, 12, 17, 26, 39, 68, 93, 143, 209, 326, 523, 727, 1099, 1732, 2579, 4016, 6008, 9044, 13999, 20750, 32258, 48804, 72741, 112744, 169416, 258784, 393746, 591475, 910194, 1372045, 2083773, 3186642, 4796200, 7329603, 11119444, 16848019, 25721848, 38844640, 59186902, 89986863, 136191178, 207694889, 314628781, 478097387, 727410806, 1101909471, 1677791144, 2545626844, 3864159293, 5880021421, 8914568564, 13555293924, 20589491052, 31245097185, 47521754717, 72105469930, 109560600175, 166485104612, 252656037566, 384093820938, 583154039200, 885680548915, 1345956492223, 2043135196927, 3104767674704, 4715475498644, 7160629937760, 10881137110750, 16521386656244],
-[1, 1, 2, 2, 3, 6, 12, 12, 20, 34, 43, 67, 99, 151, 243, 355, 535, 798, 1180, 1931, 2837, 4186, 6514, 9790, 15255, 22646, 34115, 53107, 79032, 121276, 184575, 277306, 426128, 641441, 979243, 1492043, 2241964, 3439072, 5206691, 7891900, 12047396, 18189123, 27751438, 42110823, 63797789, 97358116, 147263535, 224012259, 340712626, 516105454, 786114130, 1191848833, 1810571790, 2754349716, 4174385992, 6351414865, 9642607761, 14635657392, 22261540285, 33769023751, 51328778296, 77974254416, 118341239608, 179937630931, 273114597435, 414890805216, 630454703049, 956962747743, 1454425614733, 2208579954475, 3354205601481, 5096938210171, 7738218090491, 11757259520377, 17858247504777],
-[1, 1, 2, 2, 3, 6, 12, 12, 16, 28, 44, 75, 96, 140, 215, 331, 556, 795, 1126, 1806, 2706, 4230, 6357, 9247, 14576, 21855, 33547, 51406, 75564, 117416, 177849, 269084, 413096, 616237, 947130, 1439942, 2170159, 3331407, 5010384, 7634146, 11644616, 17564581, 26859710, 40616004, 61671221, 94111215, 142167023, 216737672, 328911331, 498585507, 760111207, 1150797599, 1750561946, 2660555586, 4032293916, 6140613414, 9312126599, 14145568909, 21511040128, 32617429991, 49615717503, 75322116481, 114352381000, 173887389416, 263818937774, 400984124212, 609107836909, 924593876376, 1405551684280, 2133614036160, 3241333195600, 4924827727994, 7476270594793, 11361770947907, 17253424045953],
-[1, 1, 2, 2, 3, 5, 8, 9, 12, 22, 33, 57, 69, 100, 168, 252, 422, 580, 848, 1391, 2020, 3202, 4791, 6940, 11120, 16568, 25396, 38718, 56878, 89637, 134436, 202798, 313182, 465344, 718382, 1088887, 1639845, 2525476, 3781630, 5782930, 8819733, 13266544, 20343025, 30709516, 46675088, 71249619, 107434786, 164130676, 248799809, 377085495, 575526696, 870146276, 1324852775, 2013095022, 3049811796, 4648440146, 7042787616, 10703157419, 16279208367, 24670245998, 37550129377, 56981921226, 86513885211, 131590681027, 199560602758, 303433031714, 460851214752, 699466918280, 1063623477762, 1614103181283, 2452511561896, 3726321244128, 5655998886721, 8597292916209, 13053270212790],
-[1, 1, 2, 2, 4, 8, 15, 16, 23, 42, 60, 101, 137, 209, 348, 492, 809, 1138, 1674, 2807, 4064, 6251, 9356, 13982, 22416, 32675, 49888, 77114, 113755, 177744, 266772, 403341, 621754, 924826, 1429440, 2165455, 3253377, 5015102, 7529493, 11496009, 17512264, 26366502, 40442726, 61043616, 92745508, 141619130, 213564081, 326094543, 494543590, 749647538, 1143689625, 1729303465, 2633120388, 4001511206, 6061564287, 9237630354, 13998942027, 21272481732, 32353496861, 49035075845, 74629468907, 113253022572, 171944552561, 261536047515, 396647223455, 603050897080, 915946229898, 1390230953910, 2113917743814, 3208091795092, 4874347040383, 7406101320037, 11241477188469, 17086898942302, 25943870772485],
-[1, 1, 2, 2, 4, 8, 16, 16, 21, 42, 64, 106, 134, 204, 356, 496, 811, 1184, 1692, 2765, 4057, 6376, 9601, 13894, 22402, 33163, 50255, 77861, 114422, 178696, 268933, 405721, 628560, 931254, 1434490, 2185791, 3278601, 5051880, 7583289, 11566170, 17664918, 26544652, 40725618, 61546863, 93329007, 142696460, 215164465, 328388885, 498366467, 754671735, 1152318403, 1742377244, 2651134755, 4032120630, 6104794635, 9304478813, 14104033714, 21422287027, 32596109400, 49389454705, 75164900429, 114103759751, 173170123572, 263457931786, 399557109460, 607384794506, 922744014827, 1400253193130, 2129350627268, 3231702619193, 4909435285043, 7460673516643, 11323242846552, 17211032099950, 26134586001251],
-[1, 1, 2, 2, 4, 8, 16, 16, 22, 44, 64, 105, 138, 209, 352, 487, 800, 1204, 1710, 2773, 4046, 6329, 9671, 13947, 22355, 33168, 50257, 78214, 114660, 178503, 269616, 405862, 629368, 933553, 1434771, 2189953, 3281209, 5057901, 7600534, 11570148, 17692107, 26582041, 40762551, 61659999, 93410845, 142889893, 215480731, 328689801, 499196666, 755520165, 1153660191, 1745015759, 2653922040, 4037970196, 6112460410, 9315222823, 14124779585, 21446547427, 32639612974, 49455501298, 75252214721, 114263202027, 173381206197, 263794394072, 400095302873, 608109140846, 923987144981, 1402028949518, 2132009317567, 3236031439999, 4915469563814, 7470424390856, 11337869411419, 17232497971639, 26169151929533],
-[1, 1, 2, 2, 4, 8, 16, 16, 24, 48, 64, 106, 149, 216, 367, 528, 849, 1216, 1739, 2959, 4328, 6481, 9981, 14711, 23434, 34601, 52247, 81686, 119571, 186024, 282823, 423120, 654575, 975808, 1499358, 2286827, 3419425, 5276934, 7945795, 12064443, 18467021, 27762907, 42515174, 64371281, 97489153, 149174545, 224935510, 342847708, 521275920, 788620963, 1203779203, 1821788027, 2769503173, 4215024330, 6379937326, 9721322183, 14745890365, 22380901681, 34067010267, 51625859665, 78533448102, 119270924562, 180958409427, 275326897302, 417631939709, 634645888187, 964456622961, 1463372827475, 2225136645453, 3377801926968, 5130268309312, 7797262834216, 11834050938727, 17985519600307, 27315010926157],
-[1, 1, 2, 2, 4, 8, 15, 15, 21, 42, 60, 104, 137, 195, 335, 486, 794, 1127, 1614, 2736, 3983, 6063, 9275, 13560, 21713, 32041, 48637, 75717, 110545, 172839, 261789, 392501, 607667, 903194, 1390992, 2118821, 3171113, 4896975, 7356087, 11191484, 17122986, 25727051, 39441425, 59643529, 90399938, 138319957, 208448005, 318020070, 483122997, 731065366, 1116338919, 1688452596, 2568194329, 3907382358, 5914113825, 9014678485, 13667827605, 20751341698, 31584565654, 47855041332, 72819175331, 110564243676, 167767817190, 255270287201, 387139911209, 588432885368, 894108629446, 1356640658257, 2063052340053, 3131308132224, 4756435350904, 7228797645087, 10970840224700, 16675121612967, 25322498780131],
-[1, 1, 2, 2, 4, 8, 16, 16, 21, 42, 64, 108, 136, 204, 356, 498, 807, 1178, 1701, 2784, 4045, 6362, 9623, 13908, 22377, 33159, 50344, 77885, 114399, 178921, 269141, 405663, 628780, 932074, 1435382, 2186203, 3280219, 5055800, 7585958, 11571280, 17675887, 26557129, 40744023, 61576318, 93383836, 142768223, 215252017, 328572571, 498628404, 755021938, 1152904871, 1743247440, 2652504507, 4034086943, 6107800884, 9309412033, 14110824137, 21432930856, 32612929430, 49413827729, 75202897878, 114160289206, 173257393243, 263591840134, 399753026392, 607693275059, 923207984808, 1400947789801, 2130428758257, 3233313138960, 4911906182679, 7464417678997, 11328881847353, 17219740918980, 26147629501116],
-[1, 1, 2, 2, 4, 8, 15, 15, 21, 42, 60, 103, 137, 195, 330, 483, 780, 1127, 1614, 2678, 3939, 6005, 9260, 13471, 21324, 31807, 48266, 75001, 109714, 170715, 259596, 389110, 601465, 896574, 1375572, 2098720, 3145574, 4846762, 7293868, 11080352, 16959811, 25510594, 39041202, 59118292, 89559619, 136965227, 206635754, 314937685, 478693621, 724371843, 1105510893, 1673446770, 2543831823, 3870758155, 5860473205, 8928351636, 13543313581, 20557696499, 31287174784, 47418923573, 72129257058, 109544006036, 166215566550, 252864484323, 383587646098, 582914126555, 885794337817, 1344109767742, 2043671173273, 3102411211507, 4712081986734, 7161343883691, 10869460854708, 16519040528749, 25087727836348],
-[1, 1, 2, 2, 4, 8, 16, 16, 22, 44, 64, 106, 133, 206, 373, 504, 809, 1190, 1733, 2901, 4108, 6401, 9799, 14181, 23030, 33733, 51025, 79632, 116445, 182894, 274547, 412078, 642215, 949526, 1464580, 2231177, 3335755, 5161002, 7733223, 11791905, 18038993, 27039063, 41560908, 62788103, 95147163, 145670833, 219290306, 334996460, 508545806, 769322465, 1175916437, 1776730777, 2703854599, 4114184886, 6224345792, 9492990971, 14385336643, 21846011177, 33257411915, 50365398533, 76673061544, 116392055688, 176599941569, 268774542280, 407498728963, 619516858077, 941282487619, 1428044726965, 2172124610167, 3296210463952, 5007312439534, 7610444079380, 11548576888525, 17555744840187, 26657284526181],
-[1, 1, 2, 2, 4, 8, 15, 16, 21, 38, 62, 112, 130, 188, 351, 507, 819, 1115, 1666, 2818, 3987, 6302, 9446, 13755, 22297, 32638, 50105, 76840, 112423, 178222, 265924, 401228, 622041, 919851, 1425701, 2158147, 3243106, 5012698, 7485183, 11463429, 17487795, 26252138, 40356893, 60821661, 92464936, 141297341, 212694825, 325394030, 492996224, 746930726, 1141118498, 1723308544, 2625693892, 3989816883, 6040947775, 9214629179, 13952484085, 21208085954, 32265146688, 48871016379, 74422992141, 112905770277, 171411215943, 260803601517, 395367755227, 601312874528, 913224339736, 1385858707561, 2107897329801, 3198123618858, 4859788237060, 7384313010710, 11206519547314, 17037197498669, 25864819211502],
-[1, 1, 2, 2, 4, 7, 14, 14, 22, 42, 52, 85, 133, 187, 286, 445, 704, 995, 1417, 2373, 3662, 5309, 8098, 12230, 19003, 28549, 42923, 66598, 98879, 151371, 232775, 348954, 532775, 804350, 1228192, 1874443, 2814657, 4309654, 6539544, 9894883, 15114070, 22853208, 34795195, 52841563, 80047439, 122160966, 184909206, 280914002, 427586414, 647776448, 986071126, 1496114301, 2271581197, 3456026337, 5238978479, 7968059486, 12102762328, 18363963081, 27930494937, 42382687583, 64399617952, 97850751050, 148501689013, 225767257153, 342754671180, 520572482760, 791121995440, 1200898513285, 1824883571792, 2771581649728, 4208864182677, 6395639481083, 9710641532550, 14752486919146, 22409815586799],
-[1, 1, 2, 2, 4, 8, 16, 16, 25, 50, 64, 98, 148, 224, 366, 514, 831, 1218, 1723, 2928, 4325, 6391, 9818, 14648, 23333, 34297, 51521, 80985, 119071, 184018, 280349, 419749, 648142, 968105, 1486233, 2268615, 3388829, 5224955, 7887889, 11960533, 18296686, 27532210, 42136112, 63839524, 96635891, 147855706, 223090561, 339751933, 516825946, 781992220, 1193087715, 1806361000, 2745273879, 4178805882, 6325725094, 9635384154, 14620783153, 22187457709, 33770194841, 51186708840, 77848307606, 118247089214, 179399433200, 272932920196, 414066851083, 629132050260, 956134531129, 1450812937782, 2205812193863, 3348808630819, 5085916614084, 7729875959457, 11732381735213, 17829630992631, 27079963123973],
-[1, 1, 2, 3, 6, 8, 14, 20, 27, 47, 68, 107, 162, 233, 373, 552, 846, 1314, 1905, 2962, 4519, 6785, 10489, 15548, 23951, 36578, 54605, 84472, 126854, 192738, 295147, 443435, 680289, 1027930, 1557166, 2385243, 3593235, 5480938, 8328841, 12598995, 19244201, 29103031, 44265881, 67364484, 101919886, 155389551, 235617732, 357656994, 544461096, 824820649, 1255197492, 1905906590, 2891561692, 4400368977, 6673033727, 10142412815, 15412567214, 23383461166, 35561476368, 53974423432, 81984127887, 124607684123, 189099219139, 287428683965, 436491335432, 662805189774, 1007335418792, 1529206252864, 2323441241881, 3529357543024, 5359004577676, 8143366991780, 12365506583381, 18783314668048, 28535382175284],
-[1, 1, 2, 3, 5, 7, 12, 18, 27, 39, 55, 99, 148, 213, 331, 497, 774, 1151, 1732, 2706, 3993, 6148, 9434, 14033, 21597, 32610, 49707, 75847, 113544, 174789, 264639, 399768, 612416, 923471, 1408219, 2138892, 3236505, 4946847, 7474932, 11366164, 17312108, 26188428, 39912300, 60524795, 91887211, 139888755, 211870534, 322460121, 489687528, 742761386, 1130450423, 1714407848, 2605571521, 3959463947, 6006968507, 9136187532, 13866258293, 21060703019, 32012805370, 48579640086, 73839682128, 112136123907, 170273275048, 258788684164, 392849802196, 596889335195, 906711710287, 1376711868036, 2092037315975, 3176722747582, 4825413897319, 7330806279093, 11131637874928, 16912519584724, 25686101160043],
-[1, 1, 2, 3, 6, 8, 14, 20, 28, 50, 69, 104, 161, 240, 391, 548, 839, 1369, 1968, 3030, 4558, 6896, 10833, 15845, 24492, 37406, 55572, 86688, 129680, 196933, 301761, 451901, 697378, 1050866, 1588984, 2441734, 3668068, 5606127, 8516253, 12865408, 19692287, 29720056, 45249961, 68909093, 104085648, 158913560, 240786519, 365512472, 556809744, 842604579, 1283371948, 1948208250, 2954622046, 4499637309, 6818955479, 10367414363, 15756055656, 23895241649, 36357921663, 55162927128, 83794614790, 127388300005, 193251241977, 293826241843, 446152706754, 677422827508, 1029769382884, 1562901131678, 2374981489480, 3607635932761, 5477199787357, 8324372316889, 12638731256301, 19199102529501, 29168553901552],
-[1, 1, 2, 2, 3, 6, 12, 12, 17, 32, 41, 67, 96, 151, 235, 342, 546, 806, 1152, 1837, 2788, 4276, 6426, 9462, 14978, 22334, 33901, 52283, 77360, 119467, 181168, 274317, 420261, 627853, 964610, 1468671, 2209523, 3386933, 5110876, 7778362, 11854696, 17892347, 27348285, 41391362, 62803689, 95839383, 144874426, 220651669, 335044637, 507963030, 774049321, 1172322519, 1782710207, 2710143953, 4107713279, 6253043552, 9486410914, 14407642159, 21908439820, 33226685561, 50531043875, 76724332302, 116473642028, 177101587054, 268742133490, 408395414234, 620411173283, 941788532765, 1431548054857, 2173311769200, 3301390008334, 5016152411437, 7615271740517, 11572062660680, 17574036433482],
-[1, 1, 2, 2, 3, 6, 12, 12, 17, 31, 42, 69, 99, 152, 225, 323, 543, 799, 1135, 1815, 2747, 4185, 6347, 9398, 14734, 21862, 33260, 51723, 76359, 117407, 178338, 269904, 414205, 618855, 949410, 1446085, 2174430, 3337562, 5037456, 7654611, 11673746, 17622277, 26935298, 40774282, 61830872, 94395116, 142682705, 217271888, 330052237, 500197094, 762264703, 1154643823, 1755538186, 2669394194, 4045135158, 6157943137, 9343543443, 14187990551, 21577324540, 32723204628, 49762309115, 75565582731, 114702786153, 174419939283, 264673239520, 402183976909, 611031250001, 927496181404, 1409832929267, 2140414990484, 3251242025017, 4940205793884, 7499797779764, 11396470995028, 17307975356581],
-[1, 1, 2, 2, 3, 6, 12, 12, 17, 28, 43, 75, 96, 136, 222, 350, 559, 776, 1139, 1870, 2777, 4239, 6408, 9464, 14888, 22305, 33981, 51962, 76953, 119742, 181139, 273024, 419082, 628008, 963742, 1463383, 2206569, 3385259, 5097962, 7764323, 11840667, 17866609, 27295214, 41313000, 62737559, 95676145, 144589728, 220357855, 334507399, 507111189, 772793517, 1170459875, 1780048401, 2705367210, 4101215256, 6243786306, 9470136992, 14384808232, 21873650869, 33173242691, 50451134648, 76597058604, 116292374773, 176818158066, 268299146350, 407758419499, 619406196403, 940274857080, 1429267516490, 2169789335696, 3296173479307, 5008032485307, 7603022810503, 11553732706268, 17545535187100],
-[1, 1, 2, 2, 3, 6, 11, 11, 15, 28, 36, 64, 92, 135, 197, 307, 508, 722, 1047, 1657, 2517, 3851, 5821, 8613, 13366, 20140, 30785, 47107, 69785, 107799, 163775, 247609, 378411, 568288, 870988, 1322978, 1997587, 3057730, 4614608, 7020211, 10696291, 16173114, 24671790, 37362189, 56740309, 86471063, 130830620, 199200091, 302458768, 458737795, 698499881, 1058685691, 1609643226, 2445991552, 3709531467, 5644580289, 8564647807, 13008752244, 19775772219, 30003249527, 45616490917, 69264131155, 105169793090, 159869939089, 242640832240, 368709253759, 560085143142, 850342247579, 1292328429165, 1962156153122, 2980662376310, 4528378633610, 6875618969367, 10447200598926, 15866033105199],
-[1, 1, 2, 2, 3, 6, 12, 12, 15, 28, 41, 71, 92, 143, 229, 339, 534, 777, 1154, 1801, 2686, 4220, 6387, 9292, 14513, 21940, 33553, 51068, 75765, 117612, 177531, 268909, 412658, 617355, 945927, 1437556, 2173450, 3326523, 5008033, 7636515, 11635546, 17567492, 26831779, 40611675, 61685874, 94002820, 142176561, 216670697, 328722220, 498575377, 759613875, 1150657115, 1749979144, 2658979308, 4032278711, 6137402855, 9308784461, 14142697524, 21500227782, 32611349041, 49595074492, 75293807099, 114328497298, 173804576444, 263749466204, 400853845138, 608858683810, 924361264702, 1404983759576, 2132943996885, 3240330474490, 4922855032060, 7474200369846, 11357613802973, 17247413984317],
-[1, 1, 2, 2, 3, 6, 12, 12, 17, 28, 41, 73, 89, 131, 227, 350, 531, 737, 1103, 1792, 2700, 4113, 6136, 9070, 14359, 21572, 32601, 49704, 74132, 115176, 173857, 262514, 402318, 603010, 925276, 1406179, 2121924, 3246536, 4895031, 7462800, 11368499, 17161178, 26204294, 39678694, 60264813, 91848674, 138905838, 211606363, 321158204, 487128745, 742076865, 1124119699, 1709410355, 2597757438, 3939387348, 5995483647, 9094467401, 13815651116, 21003844997, 31860193386, 48449292867, 73557853881, 111687646441, 169792250255, 257672017082, 391592616224, 594813518827, 903032759496, 1372540236632, 2083761951952, 3165515059407, 4809262688588, 7301761596110, 11095363412298, 16849616443941],
-[1, 1, 2, 2, 3, 6, 11, 12, 20, 30, 41, 70, 101, 134, 199, 336, 516, 727, 1082, 1758, 2629, 3894, 6026, 9026, 13829, 20945, 31983, 48794, 72605, 111799, 170551, 256596, 391580, 592481, 903699, 1372482, 2074731, 3172024, 4798134, 7281046, 11104293, 16810204, 25580764, 38814144, 58924442, 89734459, 135900392, 206693565, 314141745, 476361713, 724799482, 1099741169, 1670787281, 2539319528, 3852395223, 5858579766, 8894294590, 13504755451, 20529600920, 31158428606, 47349445900, 71918754551, 109196432689, 165959809463, 251956493121, 382764270156, 581510246886, 882916050894, 1341579570457, 2037378345548, 3094514442208, 4701355342185, 7138999486584, 10845844674642, 16473365637824],
-[1, 1, 2, 2, 3, 6, 12, 12, 17, 32, 42, 67, 102, 160, 235, 344, 560, 806, 1175, 1889, 2874, 4308, 6480, 9815, 15324, 22696, 34504, 53272, 79306, 121877, 184991, 280158, 427838, 642652, 986190, 1497211, 2256549, 3456054, 5224986, 7944798, 12091118, 18289109, 27913844, 42263660, 64172166, 97810758, 147992777, 225277337, 342111262, 518975819, 790045221, 1197347448, 1820679736, 2766874111, 4195884007, 6384198928, 9687698984, 14714501119, 22367785038, 33937231865, 51597381095, 78344001780, 118956616765, 180829593644, 274456582140, 417042682693, 633506980571, 961835647033, 1461751157760, 2219394981766, 3371432756507, 5122053726670, 7777045252928, 11816790894035, 17946146462960],
-[1, 1, 2, 2, 3, 6, 12, 12, 17, 30, 41, 69, 100, 148, 215, 339, 542, 780, 1129, 1757, 2768, 4192, 6256, 9393, 14482, 21963, 33331, 50847, 76250, 116572, 177733, 269649, 409373, 618200, 944191, 1435537, 2172630, 3308855, 5017096, 7623168, 11592519, 17581404, 26747501, 40570482, 61612310, 93748101, 142173354, 216079686, 328211198, 498279408, 757559019, 1149669668, 1746891505, 2654037538, 4028558415, 6123413242, 9297919813, 14121621201, 21456655122, 32577194929, 49500850270, 75178860621, 114170859317, 173474362890, 263417410407, 400165533182, 607854883099, 923115889018, 1402442565238, 2129889328835, 3235234414346, 4914558185673, 7463666171532, 11338237294205, 17221125431097],
-[1, 1, 2, 2, 3, 5, 10, 10, 14, 23, 31, 56, 76, 119, 172, 267, 445, 633, 921, 1416, 2171, 3411, 4999, 7462, 11680, 17422, 26856, 40580, 60737, 93912, 140908, 215841, 328193, 491920, 757306, 1143784, 1736934, 2650883, 3993248, 6107142, 9257179, 14028033, 21420996, 32351512, 49277725, 74919073, 113446303, 172948349, 261933535, 398152785, 605748975, 917577089, 1397041578, 2119734483, 3217987361, 4895968925, 7423262509, 11288292472, 17144133140, 26018247111, 39571809676, 60043270033, 91233108714, 138628307152, 210394377544, 319839558334, 485586040209, 737527876557, 1120778315989, 1701337732200, 2585373692340, 3926576680620, 5962797771144, 9060726585502, 13757455297248],
-[1, 1, 2, 2, 3, 6, 12, 12, 17, 28, 41, 66, 83, 133, 225, 330, 485, 720, 1098, 1681, 2521, 3949, 5935, 8682, 13624, 20707, 31193, 47270, 71227, 110218, 165731, 250992, 384985, 578211, 883117, 1343372, 2033705, 3100959, 4682086, 7142143, 10867714, 16418716, 25046457, 37971665, 57661399, 87761233, 132926046, 202395947, 307104766, 466028736, 709515851, 1075447098, 1634843035, 2484090410, 3769096496, 5732885030, 8698422559, 13215525290, 20085805768, 30476936322, 46333648089, 70353392466, 106834993401, 162371609729, 246470665878, 374532181271, 568867267296, 863769790834, 1312654778700, 1993063617012, 3027664640691, 4599496868363, 6984154718783, 10611630468122, 16115580304050],
-[1, 1, 2, 2, 3, 6, 11, 11, 17, 30, 41, 66, 86, 130, 215, 307, 482, 730, 1059, 1662, 2500, 3842, 5776, 8530, 13530, 20133, 30339, 46944, 69882, 107726, 162767, 246280, 378764, 565678, 867342, 1321916, 1988375, 3047362, 4599951, 7001687, 10670955, 16094190, 24609289, 37269213, 56513365, 86237764, 130383251, 198577751, 301551604, 457093376, 696646833, 1055078807, 1604146898, 2439227508, 3696820914, 5627203024, 8537444733, 12965791829, 19717625150, 29902059664, 45473931403, 69051591735, 104817919345, 159383552637, 241859403568, 367529564371, 558351363884, 847553145026, 1288329995823, 1955911380930, 2971033186942, 4514357348652, 6853395047047, 10414242374996, 15815983586931],
-[1, 1, 2, 2, 3, 6, 12, 12, 16, 26, 41, 74, 90, 137, 227, 355, 533, 749, 1169, 1826, 2724, 4239, 6314, 9439, 14683, 22111, 33797, 50977, 76747, 118943, 178551, 271362, 414244, 623330, 955402, 1445352, 2194369, 3347482, 5047058, 7710597, 11707821, 17722451, 27030547, 40908745, 62257656, 94641933, 143345645, 218420902, 331089886, 502931858, 765211903, 1159719253, 1764399485, 2678443977, 4066052127, 6184797711, 9380333463, 14259172378, 21661976981, 32875304601, 49986982303, 75870612787, 115260117369, 175140010798, 265840578369, 404053447069, 613543754603, 931795392493, 1415946820799, 2149686103348, 3266215570697, 4961012258123, 7533704023907, 11447019246107, 17382303450197],
-[1, 1, 2, 2, 3, 6, 12, 12, 16, 28, 42, 59, 85, 146, 209, 294, 488, 736, 1055, 1613, 2509, 3886, 5720, 8610, 13537, 20077, 30425, 46835, 70526, 107525, 162142, 248045, 378210, 566754, 869222, 1320590, 1995867, 3045196, 4608806, 7022548, 10655552, 16140417, 24642095, 37292724, 56646916, 86238027, 130667932, 198860498, 301682014, 458183862, 697084568, 1056514100, 1606956168, 2440955129, 3703754440, 5632895892, 8547680694, 12988676180, 19734049916, 29949488990, 45534223794, 69126713091, 104988575548, 159552901799, 242200152910, 368046892925, 558965523626, 848851472338, 1289883623052, 1958433449910, 2975303383819, 4519622670644, 6863162289942, 10427672479857, 15835854511122],
-[1, 1, 2, 2, 3, 6, 11, 11, 16, 28, 42, 68, 83, 130, 217, 310, 487, 725, 1056, 1660, 2513, 3898, 5851, 8512, 13539, 20450, 30752, 47257, 70271, 108691, 164488, 248393, 382291, 570731, 873927, 1334187, 2008086, 3074235, 4638057, 7062000, 10773319, 16239958, 24819313, 37607804, 57014831, 87006230, 131563136, 200345856, 304257190, 461113516, 702899573, 1064672389, 1618282155, 2461016257, 3729958234, 5677421288, 8614097210, 13081020336, 19894337497, 30169619367, 45878375140, 69672121709, 105753228894, 160805565912, 244025384394, 370807972257, 563353605695, 855119728720, 1299830423250, 1973434526891, 2997536568627, 4554732508759, 6914682772815, 10507207812457, 15957513003265],
-[1, 1, 2, 2, 4, 8, 14, 14, 18, 36, 59, 98, 123, 183, 310, 457, 725, 1040, 1522, 2443, 3669, 5730, 8518, 12453, 19796, 29787, 45204, 68922, 102444, 159360, 240399, 363563, 558294, 833815, 1280720, 1948086, 2937569, 4499555, 6774634, 10336071, 15749495, 23752406, 36313929, 54947661, 83440885, 127239977, 192328612, 293149584, 444777854, 674500690, 1028047448, 1556589490, 2367600748, 3598199164, 5454989037, 8304813867, 12594652853, 19134188735, 29092848147, 44119525451, 67106765736, 101875758671, 154676325701, 235177256632, 356844318894, 542363194938, 823819250120, 1250613497898, 1901047710357, 2885862106658, 4384154529377, 6660926357770, 10112374773484, 15367291002131, 23336101415165],
-[1, 1, 2, 2, 4, 7, 13, 14, 18, 36, 56, 95, 121, 180, 309, 434, 708, 1061, 1501, 2390, 3566, 5598, 8509, 12226, 19485, 29247, 44143, 68438, 100749, 156241, 236685, 356497, 551701, 819899, 1256583, 1921143, 2882946, 4431571, 6670536, 10148163, 15516090, 23338429, 35732514, 54109991, 81951938, 125284598, 189150109, 288256297, 437906172, 662922719, 1011711616, 1531350152, 2327834369, 3541889676, 5363544357, 8169768703, 12392707943, 18814451866, 28629208074, 43392442382, 66006264041, 100242071531, 152109226571, 231384614293, 351024249626, 533438611026, 810553820018, 1230021154304, 1870153057319, 2838978714811, 4312074277727, 6553200835215, 9946771995915, 15116492905926, 22957378598543],
-[1, 1, 2, 2, 4, 8, 16, 16, 22, 44, 64, 108, 139, 206, 365, 516, 833, 1194, 1718, 2894, 4216, 6472, 9798, 14294, 23112, 34129, 51470, 79981, 117373, 183734, 277161, 415856, 644837, 957183, 1474718, 2247256, 3363168, 5190123, 7795332, 11874702, 18156686, 27264170, 41824250, 63242178, 95853481, 146644936, 221000852, 337243218, 512155013, 775128273, 1183790616, 1790025207, 2722994419, 4142858386, 6270663299, 9558345785, 14490380190, 22003098198, 33488574427, 50736789907, 77211277659, 117225508684, 177879307912, 270657333543, 410463575802, 623916673451, 947978160200, 1438392804423, 2187438890247, 3319965843808, 5043149734438, 7664463844526, 11631957818640, 17680339439518, 26848324867330],
-[1, 1, 2, 2, 4, 8, 15, 16, 23, 42, 63, 108, 139, 202, 342, 488, 809, 1176, 1680, 2775, 4033, 6309, 9514, 13824, 22295, 32880, 50008, 77447, 113664, 177799, 267342, 403176, 624495, 925760, 1427321, 2171906, 3257823, 5024613, 7537865, 11498328, 17558949, 26385991, 40493056, 61169328, 92790165, 141867519, 213850087, 326488329, 495411998, 750218309, 1145551663, 1731912007, 2635745040, 4008209976, 6068454338, 9250239522, 14019928096, 21296208007, 32403970368, 49096291049, 74724399226, 113425558636, 172148740619, 261908878114, 397183701219, 603813392815, 917287010090, 1391974359600, 2116816278708, 3212550220599, 4880513675508, 7416615158087, 11256239196039, 17109679609522, 25979961265637],
-[1, 1, 2, 2, 4, 8, 15, 15, 20, 40, 64, 104, 131, 199, 339, 490, 798, 1131, 1648, 2738, 4051, 6223, 9200, 13707, 22113, 32504, 49240, 75904, 112608, 175417, 263442, 398711, 613730, 913583, 1409886, 2140303, 3217061, 4945978, 7438198, 11355688, 17292600, 26049103, 39921487, 60307816, 91594711, 139814513, 211028793, 321973945, 488371381, 740485661, 1129322477, 1708255120, 2600114225, 3951714048, 5987637642, 9121417322, 13826850225, 21009331396, 31949516648, 48432978387, 73698124020, 111854416976, 169820079907, 258271718469, 391766900113, 595557357773, 904583318131, 1373071942517, 2087607781190, 3168473331229, 4813909325200, 7314164723466, 11102664516130, 16874558050755, 25622791143673],
-[1, 1, 2, 2, 4, 8, 16, 16, 21, 42, 64, 108, 146, 207, 327, 492, 833, 1188, 1645, 2707, 4175, 6377, 9425, 13896, 22205, 33191, 50183, 77400, 114392, 177023, 268951, 406179, 622874, 929551, 1429511, 2178793, 3272753, 5021113, 7573506, 11524981, 17578869, 26513006, 40538069, 61347755, 93057235, 142099744, 214706813, 327016937, 496647471, 752743050, 1147463788, 1737532347, 2642040246, 4017375089, 6087545198, 9268678525, 14060923466, 21352633290, 32474664763, 49244643254, 74896377649, 113723256156, 172621511905, 262507911054, 398313485239, 605299853739, 919591679957, 1395828429732, 2121842326922, 3221218459431, 4893101323707, 7435010332498, 11286838633498, 17151824224674, 26047964657446],
-[1, 1, 2, 2, 4, 8, 16, 16, 23, 46, 64, 111, 150, 211, 356, 521, 839, 1212, 1737, 2911, 4267, 6478, 9925, 14482, 23085, 34423, 51991, 80742, 118450, 184523, 280159, 419377, 648718, 967274, 1484763, 2265539, 3392795, 5228192, 7869864, 11958827, 18301116, 27516638, 42123971, 63792557, 96631470, 147789398, 222941476, 339803290, 516497750, 781563598, 1192892365, 1805516375, 2744615003, 4176596468, 6323194926, 9633548154, 14612610338, 22180812961, 33758662924, 51162560219, 77825885637, 118195139548, 179338068274, 272836316883, 413877035222, 628946011820, 955747924279, 1450235109436, 2205076738285, 3347396384904, 5084162973927, 7726911668097, 11727726330894, 17823566581658, 27068880044975],
-[1, 1, 2, 2, 4, 8, 15, 16, 22, 40, 62, 109, 133, 193, 350, 508, 809, 1129, 1677, 2801, 4022, 6288, 9467, 13807, 22216, 32870, 50093, 76846, 113038, 177968, 266915, 401842, 621894, 923764, 1425444, 2163298, 3251211, 5012306, 7510340, 11474593, 17513171, 26320639, 40377424, 60978287, 92607830, 141463811, 213231715, 325713202, 493978124, 748337267, 1142480343, 1727171212, 2629230687, 3996634400, 6052902716, 9226574414, 13980443139, 21241942175, 32315886964, 48966253765, 74530401482, 113112384024, 171705792182, 261206580979, 396111764916, 602252102704, 914795712954, 1388309120870, 2111205934037, 3203902179653, 4867775530721, 7396642211490, 11226375130940, 17064592951778, 25910061783531],
-[1, 1, 2, 2, 4, 8, 15, 15, 23, 46, 64, 92, 139, 217, 339, 490, 783, 1153, 1652, 2713, 4140, 6111, 9173, 13937, 21985, 32607, 48934, 76006, 113572, 173852, 264752, 399409, 610824, 918479, 1406902, 2143662, 3220192, 4930665, 7472990, 11341021, 17280844, 26118022, 39849871, 60423636, 91604155, 139744337, 211466456, 321585694, 488898531, 741189028, 1128476888, 1710756116, 2599590201, 3953440297, 5993344821, 9117561761, 13841703777, 21013805025, 31953192093, 48479435546, 73691095396, 111927658834, 169899343372, 258299516063, 392077893254, 595633848566, 904994900881, 1373861507529, 2087906911400, 3170477535433, 4815414258399, 7316735498248, 11108959508882, 16878434305656, 25636103885896],
-[1, 1, 2, 2, 4, 8, 16, 16, 25, 50, 64, 107, 159, 221, 359, 539, 864, 1220, 1739, 2999, 4449, 6479, 10050, 15008, 23591, 34986, 52818, 82661, 121103, 187328, 287044, 428283, 659511, 989096, 1515766, 2312617, 3458563, 5329792, 8050375, 12188646, 18664849, 28113865, 42948076, 65121879, 98594660, 150802097, 227623224, 346459494, 527307091, 797752209, 1216627283, 1843199408, 2800170328, 4262253315, 6453458633, 9827529216, 14916329784, 22631393658, 34445420286, 52221830567, 79401413288, 120624633478, 183007385969, 278390872516, 422408256301, 641725376610, 975336610568, 1479992517134, 2249934372244, 3416194674661, 5187937773708, 7884857535201, 11968303767734, 18186837279371, 27624077209652],
-[1, 1, 2, 2, 4, 8, 16, 16, 22, 44, 64, 102, 140, 212, 346, 488, 807, 1189, 1674, 2744, 4109, 6317, 9470, 13911, 22313, 33077, 49919, 77498, 114415, 177321, 268350, 404755, 624068, 928928, 1428516, 2178300, 3266316, 5022722, 7567115, 11517211, 17579749, 26467015, 40531878, 61326919, 92963401, 142068872, 214470589, 326874013, 496448741, 751986221, 1147150757, 1736110022, 2640206480, 4015874006, 6082282267, 9264370701, 14051462991, 21337140772, 32460888512, 49205909822, 74853819497, 113657626978, 172494529593, 262376120503, 398036652475, 604922336479, 919073751061, 1394835303235, 2120671025897, 3219146862090, 4889898884042, 7430796495655, 11279196495385, 17141631434057, 26031885935658],
-[1, 1, 2, 3, 6, 7, 10, 16, 22, 40, 56, 92, 135, 190, 322, 454, 712, 1109, 1597, 2544, 3766, 5724, 8906, 13018, 20384, 30825, 45982, 71583, 106449, 163473, 249390, 372703, 576398, 865487, 1315966, 2016150, 3023806, 4638963, 7021498, 10630971, 16273665, 24515036, 37410390, 56856495, 85965115, 131342453, 198639718, 302043658, 459764480, 695627332, 1060483150, 1607939159, 2440718903, 3716181755, 5629126115, 8565701036, 13007858760, 19733613482, 30031116389, 45541696132, 69217686623, 105188441601, 159579776322, 242696500510, 368367827732, 559500131380, 850414050266, 1290541750487, 1961599544722, 2978943006764, 4523366602765, 6874795896573, 10436355158382, 15856570771674, 24086985170126],
-[1, 1, 2, 3, 6, 8, 15, 22, 26, 48, 80, 118, 152, 238, 413, 587, 862, 1343, 2049, 3099, 4702, 7225, 10901, 16175, 25210, 38580, 57394, 87664, 133372, 203537, 307963, 465518, 713828, 1077200, 1634883, 2497578, 3777760, 5744126, 8716871, 13248227, 20169552, 30513485, 46443555, 70605210, 107013979, 162854587, 247098152, 375427459, 570511063, 865494350, 1316624931, 1998289495, 3034003043, 4613158247, 7000786855, 10638972452, 16158617846, 24534783845, 37291122612, 56607741991, 86003592366, 130661368933, 198371325505, 301441348450, 457767704811, 695299438709, 1056341133284, 1603994003417, 2436974718615, 3701329626820, 5621335544857, 8540290465472, 12969531720278, 19701519480213, 29926161749053],
-[1, 1, 2, 3, 6, 8, 12, 17, 25, 46, 62, 98, 145, 211, 364, 525, 785, 1197, 1766, 2852, 4246, 6299, 9824, 14516, 22641, 34350, 50987, 79275, 118411, 181532, 277498, 413910, 638741, 962824, 1461671, 2239465, 3360459, 5146827, 7805480, 11807510, 18072550, 27249514, 41521869, 63172341, 95511271, 145847075, 220735338, 335380956, 510743326, 772887972, 1177594057, 1786567375, 2710817100, 4127559466, 6254151478, 9512815472, 14450863629, 21919763414, 33354685504, 50596005635, 76877472763, 116846286906, 177269609517, 269559361150, 409221889313, 621458957047, 944624666136, 1433613467450, 2178774823934, 3309170140271, 5024497674425, 7636252421782, 11593229469876, 17612653744881, 26756234602811],
-[1, 1, 2, 2, 3, 6, 12, 13, 15, 27, 45, 80, 99, 147, 241, 367, 603, 850, 1221, 1957, 2979, 4666, 6773, 10013, 16058, 23959, 36447, 55413, 82655, 128561, 192938, 293628, 449704, 670499, 1033493, 1567590, 2367513, 3624202, 5452522, 8337631, 12672075, 19132055, 29274539, 44222018, 67245737, 102466736, 154900608, 236243008, 358033203, 543505607, 828125684, 1253350900, 1907831939, 2897518626, 4394213196, 6690185723, 10142164152, 15416434959, 23430530955, 35535717424, 54062921601, 82043657018, 124603797461, 189428367131, 287409565549, 436920650447, 663495034463, 1007395943797, 1531315251258, 2324310867698, 3531669721020, 5364997100445, 8145325987706, 12378643927311, 18795654335863],
-[1, 1, 2, 2, 3, 5, 8, 10, 13, 19, 30, 52, 67, 112, 160, 232, 373, 541, 852, 1265, 1859, 2980, 4414, 6699, 10249, 15197, 23846, 35682, 53818, 83085, 123735, 190863, 289279, 435155, 669673, 1005289, 1536151, 2340413, 3521820, 5399639, 8156919, 12392168, 18910833, 28530018, 43563283, 66057296, 100133637, 152767358, 231030497, 351695443, 534470292, 809796668, 1233887234, 1869906644, 2841567124, 4321660037, 6550220707, 9968359375, 15128303248, 22968635737, 34933712771, 52984430890, 80554310557, 122348531494, 185705865207, 282366821336, 428536361752, 651102189752, 989274026237, 1501636535832, 2282402642219, 3465518421631, 5263602894614, 7998081217944, 12142533856571],
-[1, 1, 2, 2, 3, 6, 11, 11, 16, 29, 42, 60, 89, 146, 218, 320, 503, 756, 1119, 1717, 2634, 3989, 5995, 9085, 14052, 21026, 31898, 48948, 73791, 112482, 170226, 259125, 394764, 595234, 909295, 1380933, 2090145, 3185635, 4828209, 7343445, 11153911, 16909075, 25759927, 39050701, 59307291, 90225522, 136807841, 208047115, 315857853, 479606182, 729273207, 1106288566, 1681605750, 2554601714, 3877377369, 5894331544, 8948054265, 13593392758, 20652979308, 31353072611, 47649215618, 72356369818, 109891309132, 166977697939, 253527946606, 385183742999, 585050311135, 888496555433, 1349927764872, 2049954772543, 3114013437910, 4730322871619, 7183717967798, 10913498419998, 16575130571589],
-[1, 1, 2, 2, 3, 6, 12, 12, 18, 30, 41, 70, 96, 138, 211, 336, 526, 760, 1094, 1712, 2664, 4027, 6033, 8976, 13988, 21200, 32047, 48861, 73223, 112466, 170937, 258691, 394383, 594428, 907816, 1381668, 2088263, 3184285, 4821996, 7330638, 11159625, 16893774, 25728228, 39025812, 59236757, 90193840, 136679698, 207837711, 315695424, 479026838, 728805995, 1105533575, 1679826975, 2552899322, 3873605310, 5889858437, 8941593481, 13579532737, 20638759543, 31325723510, 47607543404, 72304284328, 109788895786, 166848732212, 253317637228, 384844924841, 584615161805, 887702954419, 1348826745526, 2048321555126, 3111279212932, 4726629451850, 7177592052652, 10904389032013, 16561913284569],
-[1, 1, 2, 2, 3, 6, 12, 12, 15, 27, 44, 76, 95, 141, 225, 335, 547, 787, 1147, 1828, 2725, 4307, 6406, 9346, 14903, 22213, 33958, 51838, 76714, 119774, 179867, 272712, 419498, 624433, 961786, 1459717, 2201634, 3379609, 5075635, 7755343, 11810609, 17800920, 27260768, 41183130, 62581006, 95451858, 144148703, 219970543, 333487478, 505767507, 771232841, 1166873586, 1776067661, 2698414184, 4090055590, 6229863951, 9443083234, 14350767746, 21819764728, 33080847515, 50335363733, 76392842993, 115996886472, 176388196554, 267575125265, 406780190827, 617801698381, 937839671403, 1425803768615, 2164043159594, 3287986095232, 4995382830194, 7583265147762, 11525296978293, 17499968998676],
-[1, 1, 2, 2, 3, 6, 12, 12, 17, 31, 43, 60, 90, 150, 217, 304, 508, 767, 1066, 1705, 2628, 3949, 5964, 8920, 14109, 20864, 31399, 49061, 72853, 111307, 169454, 256608, 393177, 588288, 901614, 1376000, 2065600, 3165246, 4792533, 7273716, 11087670, 16746610, 25581190, 38763416, 58732683, 89670543, 135639650, 206333639, 313630547, 475349661, 724103937, 1097293824, 1667631992, 2536516431, 3843999603, 5849433801, 8879269534, 13479937116, 20499990312, 31095502983, 47275410891, 71803482333, 108982388237, 165711097188, 251504428550, 382100288502, 580571354040, 881284303016, 1339453589395, 2033809884829, 3089031474772, 4693846572814, 7126125619001, 10827695849429, 16445510285970],
-[1, 1, 2, 2, 3, 6, 12, 12, 18, 31, 41, 65, 89, 148, 238, 327, 515, 790, 1175, 1827, 2680, 4145, 6316, 9420, 14818, 21825, 33101, 51295, 76584, 117716, 177152, 268976, 413715, 618779, 948041, 1441154, 2172372, 3329491, 5024702, 7651498, 11646407, 17581916, 26885579, 40699206, 61756984, 94158808, 142404577, 216957982, 329310935, 499390282, 760814032, 1152334776, 1752557579, 2663851483, 4038394428, 6146447427, 9324190866, 14164317462, 21535189267, 32662359558, 49672157148, 75416259075, 114501208082, 174084779159, 264173462020, 401466055777, 609835829490, 925806786366, 1407208972375, 2136339984171, 3245342009150, 4930744580477, 7485940842478, 11375401107232, 17274975119271],
-[1, 1, 2, 2, 3, 6, 12, 12, 15, 26, 43, 69, 83, 135, 223, 312, 505, 734, 1092, 1696, 2534, 4048, 5904, 8688, 13904, 20678, 31552, 47948, 71707, 111451, 166475, 254336, 389779, 580224, 894908, 1355994, 2050733, 3137598, 4716826, 7224365, 10966141, 16556216, 25351417, 38274057, 58230792, 88680324, 134073649, 204586146, 309844506, 470547094, 716938072, 1084778996, 1651844742, 2508002065, 3804093964, 5791880533, 8778287281, 13347415065, 20282365441, 30760528383, 46804561195, 71016352829, 107871585215, 163981706037, 248789041148, 378255327344, 574333630655, 872081997330, 1325647248597, 2011989697193, 3057375585245, 4644204746956, 7051115705581, 10716079889526, 16270244385846],
-[1, 1, 2, 2, 3, 6, 12, 12, 19, 31, 43, 69, 96, 142, 230, 350, 527, 783, 1160, 1852, 2789, 4199, 6393, 9502, 14831, 22398, 33696, 51767, 77534, 119147, 181162, 272548, 417681, 629604, 960116, 1463691, 2205922, 3371633, 5105776, 7751242, 11821798, 17863229, 27222027, 41327350, 62652644, 95494160, 144600540, 219892381, 334276279, 506706254, 771413751, 1170007380, 1777166276, 2702579999, 4098397093, 6233214254, 9463736803, 14367154892, 21845956605, 33148239762, 50377750049, 76527586294, 116167171101, 176586496693, 268075873434, 407231963257, 618744109234, 939335636645, 1427447468386, 2167740296259, 3292292951260, 5002348594105, 7595472076804, 11539582042806, 17527561809305],
-[1, 1, 2, 2, 3, 6, 12, 13, 18, 29, 45, 74, 91, 137, 238, 372, 554, 787, 1179, 1902, 2883, 4334, 6542, 9800, 15282, 23055, 34848, 53181, 79678, 122709, 186482, 281350, 429608, 647861, 989748, 1505394, 2273675, 3471856, 5253064, 7986014, 12166388, 18404289, 28034127, 42522220, 64551195, 98309642, 148890937, 226479847, 344061217, 521912925, 794253125, 1204612233, 1830451029, 2782038722, 4220577983, 6418718740, 9743312484, 14796388311, 22491590340, 34133528930, 51878186115, 78789512496, 119630404802, 181822033767, 276026999986, 419359583339, 637064238273, 967280283223, 1469832579467, 2232011180894, 3390279593136, 5150642085676, 7821129934371, 11882502399617, 18047315345913],
-[1, 1, 2, 2, 3, 6, 11, 11, 14, 24, 38, 67, 85, 126, 204, 311, 485, 683, 1029, 1605, 2376, 3757, 5633, 8320, 12890, 19442, 30000, 45238, 67515, 104834, 157649, 239798, 366375, 549804, 843247, 1275996, 1937187, 2959711, 4453579, 6804064, 10341519, 15645907, 23878414, 36110974, 54957697, 83593997, 126531233, 192898932, 292355194, 443950134, 675784632, 1023873313, 1558002426, 2365115557, 3589450289, 5461769281, 8282105511, 12589755235, 19128479537, 29024292512, 44139243373, 66990666084, 101764985422, 154653397785, 234709942166, 356769316615, 541750093510, 822695530961, 1250280687169, 1898032149124, 2883921880670, 4380498864186, 6651701260237, 10107512337437, 15347766900986],
-[1, 1, 2, 2, 3, 5, 8, 9, 13, 23, 32, 49, 63, 100, 171, 234, 367, 561, 821, 1259, 1872, 2968, 4499, 6485, 10333, 15501, 23379, 36046, 53501, 82918, 124950, 188969, 291536, 433978, 665658, 1015766, 1527706, 2342068, 3528483, 5378489, 8202956, 12351955, 18906571, 28625320, 43398877, 66250732, 100108791, 152569756, 231600596, 350961090, 535251104, 810252014, 1232057663, 1873620775, 2838952634, 4322761905, 6556590485, 9958195233, 15146272362, 22963187363, 34929133143, 53036246149, 80501552489, 122425226458, 185748199533, 282293859640, 428857025530, 650928138999, 989572366919, 1502208740059, 2281909059080, 3467400778838, 5263591863998, 7998990422825, 12147475360628],
-[1, 1, 2, 2, 3, 6, 12, 12, 18, 29, 42, 71, 90, 135, 225, 344, 524, 761, 1113, 1781, 2719, 4117, 6212, 9143, 14398, 21836, 32815, 50196, 74939, 115691, 175892, 264673, 405645, 609953, 931687, 1421019, 2140939, 3272719, 4949626, 7522036, 11476330, 17329399, 26421760, 40089674, 60788837, 92685672, 140284434, 213406669, 324321867, 491606614, 748701649, 1135150950, 1724513934, 2622424094, 3976377465, 6049095817, 9182280059, 13940894140, 21198925929, 32161811131, 48886661816, 74255786186, 112717303846, 171355639601, 260107614649, 395163008508, 600389230455, 911434160650, 1385150657327, 2103362014588, 3194632436572, 4854007169198, 7369911877564, 11197473130165, 17007316621906],
-[1, 1, 2, 2, 3, 6, 11, 11, 17, 29, 41, 69, 95, 135, 206, 322, 507, 739, 1060, 1703, 2584, 3879, 6002, 8846, 13660, 20683, 31444, 48389, 71584, 110078, 168516, 253277, 387605, 583987, 890558, 1357740, 2045657, 3132066, 4738323, 7181282, 10970745, 16581931, 25259051, 38334328, 58107191, 88632726, 134145169, 203984884, 310217460, 470008646, 715685326, 1085593356, 1648801100, 2507593035, 3801755283, 5783307519, 8781127915, 13327974839, 20269343711, 30753627971, 46738256837, 71003643821, 107771497437, 163840075469, 248711188234, 377805582259, 574086992577, 871466452413, 1324353825074, 2011200829231, 3054437789740, 4641184584571, 7046771416205, 10706119525390, 16261931868997],
-[1, 1, 2, 2, 3, 6, 10, 10, 16, 29, 42, 64, 87, 128, 204, 321, 500, 694, 1015, 1673, 2538, 3806, 5718, 8544, 13402, 20124, 30564, 46695, 69397, 107328, 163317, 246321, 375900, 565204, 866747, 1317721, 1986322, 3039420, 4591496, 6984319, 10644912, 16087883, 24535317, 37170992, 56443050, 86035002, 130156331, 198113866, 300908808, 456363057, 694875828, 1053223792, 1601058494, 2433395302, 3690272645, 5615010240, 8520556668, 12940173607, 19673027033, 29847824262, 45377570105, 68905914756, 104618858921, 159037088456, 241384079912, 366778795354, 557179323245, 845908532049, 1285584452128, 1951978237039, 2965089702192, 4504835021764, 6839828846611, 10392668434213, 15783642888402],
-[1, 1, 2, 2, 4, 8, 16, 16, 20, 40, 64, 117, 146, 199, 336, 509, 843, 1169, 1674, 2793, 4151, 6437, 9608, 14006, 22430, 33425, 50980, 78279, 114923, 179888, 271675, 409576, 630998, 938476, 1446840, 2199721, 3306061, 5087943, 7639255, 11652295, 17786369, 26771470, 41010138, 61969681, 94081916, 143715424, 216806468, 330809338, 501965852, 760555952, 1160507916, 1755531539, 2671290523, 4060884616, 6151373157, 9373069775, 14208623988, 21583826882, 32832482342, 49763382023, 75722429987, 114941810508, 174478518436, 265390941089, 402539751494, 611915929229, 929541046622, 1410769818010, 2145062715620, 3255721970045, 4946150205679, 7515690890276, 11407863696814, 17338755058162, 26328365435040],
-[1, 1, 2, 2, 4, 8, 14, 16, 23, 38, 58, 102, 139, 199, 304, 466, 762, 1094, 1618, 2510, 3757, 5957, 8907, 13119, 20317, 30705, 47458, 71660, 106662, 165219, 249373, 379409, 578901, 868558, 1332476, 2018334, 3061155, 4677197, 7043021, 10751189, 16344214, 24734482, 37744561, 57083663, 86846853, 132144646, 200035169, 304850142, 462152295, 701727717, 1068131415, 1618495117, 2462515198, 3738680544, 5673556736, 8632678859, 13092318379, 19899249907, 30235419405, 45878457073, 69766792974, 105893282601, 160851722061, 244453193905, 371006117662, 563911123234, 856338240191, 1300401451120, 1976237462606, 3000191809772, 4558418497637, 6924127818282, 10514105579436, 15976291467960, 24259913826300],
-[1, 1, 2, 2, 4, 8, 15, 15, 24, 48, 64, 90, 132, 216, 369, 510, 773, 1163, 1715, 2828, 4167, 6156, 9492, 14220, 22503, 33383, 49795, 77761, 115688, 178186, 270897, 405703, 625699, 938983, 1435788, 2191920, 3283618, 5043556, 7631362, 11572851, 17684350, 26644820, 40704116, 61766526, 93506758, 142849500, 215877183, 328487861, 499722676, 756540112, 1153239915, 1747478838, 2654573487, 4040122125, 6119929482, 9315096938, 14140038573, 21458768158, 32650871151, 49511810376, 75272236005, 114350963742, 173512718053, 263892554227, 400477772116, 608389026671, 924572351142, 1403168907238, 2132918112700, 3238661925847, 4918429735183, 7474672673477, 11346802734592, 17241290607363, 26187926096611],
-[1, 1, 2, 2, 4, 8, 16, 16, 24, 48, 64, 110, 158, 216, 348, 530, 870, 1230, 1725, 2927, 4394, 6508, 10026, 14825, 23312, 34728, 52600, 82091, 120090, 185749, 284458, 425726, 655280, 980674, 1503279, 2294473, 3435621, 5290354, 7983982, 12098336, 18521000, 27902694, 42633069, 64613305, 97849475, 149642950, 225906910, 343904676, 523190871, 791710448, 1207465309, 1829117714, 2779186758, 4229557755, 6404563413, 9753335893, 14802158255, 22461401220, 34183033519, 51824013005, 78802566895, 119704936720, 181624304524, 276276486595, 419192751246, 636880273129, 967909757080, 1468775306023, 2232888460838, 3390195549363, 5148682593835, 7824947438794, 11877481489335, 18049062627680, 27414005323992],
-[1, 1, 2, 2, 4, 8, 16, 16, 22, 44, 64, 107, 142, 209, 352, 501, 823, 1187, 1696, 2849, 4171, 6374, 9653, 14172, 22784, 33563, 50833, 79073, 116023, 180961, 273507, 411176, 635820, 945093, 1456029, 2217411, 3320389, 5121410, 7699048, 11721154, 17910850, 26926005, 41282083, 62418287, 94628359, 144722177, 218195708, 332852756, 505506646, 765289214, 1168255086, 1767023101, 2688012209, 4088982485, 6190315435, 9434037488, 14303779740, 21720218637, 33052840041, 50085769078, 76212404031, 115708785180, 175592114025, 267148442809, 405178421887, 615857888658, 935710409603, 1419887218939, 2159120583618, 3277126028106, 4978111356182, 7565300526428, 11482069244198, 17451808102437, 26501613069503],
-[1, 1, 2, 2, 4, 8, 16, 16, 22, 44, 64, 102, 141, 212, 347, 496, 808, 1171, 1670, 2756, 4115, 6265, 9480, 14007, 22237, 32934, 49933, 77513, 114236, 176921, 268437, 404571, 622687, 928969, 1427644, 2174676, 3263432, 5018477, 7563205, 11502083, 17559833, 26457731, 40482624, 61264665, 92894790, 141922656, 214289507, 326530261, 496027520, 751374854, 1145855823, 1734705593, 2637839043, 4011853430, 6077074716, 9255267134, 14039159725, 21317417962, 32429056421, 49164384331, 74781373698, 113551758326, 172341714313, 262123926974, 397681106599, 604354384498, 918218388773, 1393589071546, 2118643702272, 3216228907864, 4885408591094, 7423805052695, 11269006703085, 17125568399355, 26007971040776],
-[1, 1, 2, 2, 4, 7, 14, 14, 18, 34, 54, 90, 114, 171, 286, 414, 667, 946, 1396, 2289, 3326, 5194, 7867, 11468, 18258, 27091, 41381, 63627, 93648, 146469, 220361, 332516, 513578, 763987, 1176052, 1786438, 2687960, 4137617, 6209648, 9474899, 14458378, 21758917, 33341338, 50385020, 76495972, 116810015, 176232041, 268983657, 408067668, 618286806, 943436493, 1427113941, 2171735686, 3301222296, 5000905755, 7619979163, 11550224113, 17547345574, 26691603035, 40454683505, 61559390805, 93440407493, 141845981492, 215750506147, 327241036890, 497469108075, 755663334821, 1146892193685, 1743852558331, 2646725227174, 4021025839146, 6109869109220, 9274065318205, 14095721998028, 21403542869636],
-[1, 1, 2, 2, 4, 8, 15, 15, 24, 48, 63, 89, 129, 216, 371, 492, 766, 1168, 1702, 2830, 4072, 6121, 9498, 14080, 22494, 32913, 49395, 77725, 114615, 177412, 268468, 402528, 624322, 930431, 1427574, 2178636, 3256137, 5022986, 7572719, 11498584, 17585131, 26430556, 40500556, 61344546, 92852611, 142058822, 214276676, 326569035, 496528623, 751224375, 1146628394, 1735247771, 2637844394, 4015234059, 6077405917, 9259028216, 14045859751, 21318919643, 32449914289, 49175131062, 74803662324, 113610871180, 172366768219, 262253172804, 397815286109, 604510344637, 918665259364, 1393910889669, 2119498223631, 3217473131924, 4886674655580, 7427102899108, 11272344042025, 17131558316027, 26018392054124],
-[1, 1, 2, 2, 4, 8, 16, 16, 24, 48, 64, 107, 154, 216, 353, 529, 850, 1216, 1725, 2917, 4372, 6450, 9939, 14708, 23171, 34632, 52167, 81303, 119383, 184657, 282751, 422387, 650330, 974784, 1492617, 2279590, 3412484, 5250629, 7931649, 12016376, 18396755, 27712223, 42324014, 64191660, 97183378, 148599134, 224395595, 341494963, 519654929, 786322485, 1199107741, 1816835204, 2759849444, 4200696384, 6361293323, 9685771710, 14701806685, 22307074501, 33948821339, 51472505882, 78258770698, 118890748836, 180382164436, 274377847535, 416344812361, 632505444322, 961298060029, 1458747956730, 2217559482724, 3367107825542, 5113394869135, 7771401972052, 11796457153289, 17925270626390, 27226902750541],
-[1, 1, 2, 2, 4, 8, 16, 16, 24, 48, 64, 110, 158, 216, 354, 540, 865, 1209, 1727, 2965, 4431, 6500, 9977, 14852, 23409, 34913, 52671, 81932, 120345, 186492, 285349, 426150, 655518, 983239, 1507240, 2298809, 3442297, 5297968, 8000141, 12125765, 18556940, 27959113, 42702018, 64741237, 98066492, 149915971, 226353919, 344551297, 524200551, 793349239, 1209720086, 1832759124, 2784570959, 4237544843, 6417635898, 9772016735, 14830929296, 22505546193, 34248366297, 51927669656, 78954672567, 119936677649, 181983659091, 276806634612, 420018970345, 638122970790, 969781438469, 1471668931037, 2237203317095, 3396834590197, 5158759229760, 7840082531738, 11900812100580, 18084133340843, 27467436203641],
-[1, 1, 2, 2, 4, 8, 15, 15, 21, 42, 60, 102, 140, 198, 321, 474, 782, 1126, 1591, 2644, 3940, 5984, 9112, 13358, 21192, 31553, 47886, 74322, 108976, 169192, 257410, 386734, 595892, 888891, 1365457, 2081948, 3121374, 4804636, 7237093, 10997293, 16812962, 25314852, 38730977, 58631312, 88862169, 135842253, 205024136, 312407857, 474757277, 718779204, 1096518144, 1660012312, 2523736937, 3839304720, 5814241662, 8856361603, 13434641098, 20395247947, 31033232826, 47042240742, 71553220319, 108661342448, 164896069064, 250828211120, 380525988728, 578262248542, 878662668433, 1333418950924, 2027273037434, 3077558342443, 4674512889682, 7103812958963, 10782724268801, 16386782383291, 24886645434666],
-[1, 1, 2, 2, 4, 8, 15, 15, 22, 44, 63, 93, 134, 212, 332, 464, 760, 1161, 1636, 2600, 3929, 6043, 9123, 13439, 21281, 31707, 47895, 74330, 110249, 169470, 257207, 388981, 597839, 892949, 1368393, 2088506, 3137745, 4811082, 7267205, 11046274, 16848919, 25415206, 38851589, 58852499, 89193212, 136180447, 205886697, 313440192, 476208151, 721622345, 1099840881, 1666014872, 2532409627, 3851640538, 5836426699, 8883945553, 13481208152, 20469148944, 31132117139, 47212281268, 71792568572, 109030159692, 165484530371, 251645477608, 381875011397, 580243817432, 881595318572, 1338151388546, 2034052699987, 3088200820697, 4690687465155, 7127664203939, 10820549162918, 16442271071475, 24971815355955],
-[1, 1, 2, 3, 6, 8, 13, 18, 28, 54, 69, 102, 163, 242, 390, 545, 845, 1346, 1925, 3043, 4624, 6840, 10695, 15761, 24493, 37280, 55146, 86368, 128896, 195997, 301332, 449733, 693225, 1045271, 1583481, 2433594, 3646605, 5579770, 8483440, 12803627, 19608578, 29583905, 45043307, 68601330, 103591429, 158264744, 239685156, 363759768, 554434926, 838762024, 1277658617, 1939422278, 2941031317, 4480058153, 6787567468, 10320878941, 15686581914, 23785042204, 36196402385, 54914452070, 83416255102, 126821266504, 192368542470, 292516696379, 444149925249, 674346712873, 1025183371262, 1555825289012, 2364306262977, 3591465412172, 5452409701307, 8287153667956, 12581684669562, 19112609712322, 29037942236253],
-[1, 1, 2, 3, 6, 8, 15, 22, 30, 52, 72, 111, 182, 250, 382, 586, 882, 1421, 2024, 3104, 4828, 7060, 11215, 16619, 25024, 38903, 57687, 89730, 134885, 202397, 314297, 469312, 718395, 1094185, 1642847, 2529452, 3808093, 5792850, 8851478, 13309521, 20383649, 30879929, 46782507, 71469380, 107877876, 164491351, 249889005, 378261209, 577198558, 873657425, 1328268610, 2020822843, 3060377458, 4661533771, 7070177042, 10735007523, 16335241250, 24758410341, 37660830946, 57191899988, 86790424592, 132021092675, 200277842634, 304366330429, 462465325714, 701805880838, 1067050850095, 1619804384961, 2460341075014, 3738976964017, 5675256998140, 8625023346147, 13098520159444, 19891187081634, 30227135440283],
-[1, 1, 2, 2, 3, 6, 12, 12, 19, 36, 40, 62, 106, 162, 221, 338, 571, 836, 1130, 1870, 2921, 4191, 6457, 9736, 15154, 22582, 34072, 53486, 78541, 119838, 185139, 277856, 424065, 638337, 976840, 1492495, 2234120, 3428636, 5203747, 7860290, 12017535, 18164931, 27681292, 42013794, 63595233, 97189854, 146993219, 223297022, 340059543, 514894192, 784119677, 1189374224, 1805924094, 2748525852, 4164152638, 6335457850, 9623483579, 14598381096, 22209171423, 33693693544, 51202499707, 77802808677, 118053199976, 179516770443, 272503780948, 413865816594, 629036820731, 954726383355, 1450942661532, 2203578124107, 3346162168141, 5085207679091, 7720229438321, 11729173985374, 17817580971057],
-[1, 1, 2, 2, 3, 6, 12, 12, 18, 32, 42, 69, 100, 150, 224, 337, 543, 818, 1176, 1838, 2786, 4250, 6534, 9651, 14877, 22428, 34226, 52772, 78103, 119644, 182804, 276356, 422572, 634843, 969275, 1477803, 2228809, 3409153, 5155899, 7823615, 11935997, 18050826, 27511915, 41720507, 63265197, 96471060, 146048340, 222124114, 337598461, 511760595, 779162363, 1181639058, 1795270261, 2729600130, 4138957516, 6296117185, 9558504049, 14511613756, 22064599541, 33478834326, 50885533084, 77292137842, 117331676184, 178361407126, 270751394056, 411321327916, 624943471982, 948745611702, 1441777755822, 2189405326601, 3325336168654, 5052505316294, 7671478727611, 11655404573902, 17703025391964],
-[1, 1, 2, 2, 3, 6, 12, 12, 20, 32, 44, 62, 89, 140, 217, 318, 495, 773, 1087, 1748, 2661, 3944, 6043, 8961, 14241, 21269, 31527, 49380, 73683, 112585, 171554, 257789, 397010, 595617, 908243, 1391403, 2084357, 3193245, 4843663, 7335661, 11207954, 16901378, 25799472, 39187473, 59261109, 90538600, 136978005, 208192849, 316852283, 479730981, 731029340, 1108310769, 1682718120, 2561720295, 3880931493, 5904445483, 8967161246, 13605419175, 20700063885, 31396440771, 47718908965, 72510110479, 110014406771, 167302005745, 253949494018, 385711594527, 586221124821, 889707223821, 1352256842660, 2053570272405, 3118373719648, 4739182949110, 7194668895740, 10931049514342, 16604716889314],
-[1, 1, 2, 2, 3, 6, 12, 12, 20, 34, 40, 67, 99, 146, 225, 354, 539, 811, 1170, 1837, 2846, 4173, 6457, 9690, 14844, 22579, 33932, 52429, 78334, 119101, 183139, 275380, 419831, 635914, 966059, 1476158, 2224425, 3392340, 5158326, 7800243, 11906186, 18032960, 27403214, 41679268, 63123160, 96203440, 145879479, 221396903, 337028208, 510816860, 776914797, 1179912316, 1790499636, 2723763686, 4131579659, 6279033368, 9541797943, 14477764302, 22013003453, 33418312932, 50757314714, 77137806232, 117079718588, 177938492791, 270233221819, 410349890504, 623608631722, 946777846822, 1438394164406, 2184988139028, 3317872696609, 5041349852067, 7655599318820, 11628679890173, 17665912377590],
-[1, 1, 2, 2, 3, 6, 11, 11, 17, 31, 41, 61, 91, 149, 222, 319, 518, 782, 1143, 1798, 2691, 4103, 6231, 9342, 14572, 21610, 32934, 50808, 75655, 116289, 176015, 266789, 408286, 613004, 939584, 1426418, 2151412, 3295463, 4979071, 7573147, 11526367, 17427750, 26609372, 40290793, 61168724, 93221502, 141047475, 214769494, 326093218, 494626640, 753093740, 1141260787, 1735455278, 2637342327, 3999365416, 6085463854, 9233687657, 14025707645, 21321258232, 32347052232, 49181960005, 74675729868, 113386762277, 172364999061, 261600728475, 397521199284, 603845396042, 916789472053, 1393329868458, 2115462090420, 3213576136369, 4882247658993, 7412860562033, 11263602158612, 17105785354484],
-[1, 1, 2, 2, 3, 6, 12, 12, 20, 33, 44, 71, 101, 141, 230, 356, 544, 802, 1149, 1880, 2846, 4202, 6451, 9609, 15024, 22614, 33858, 52553, 78208, 119774, 183296, 274941, 421921, 635187, 967894, 1480091, 2222860, 3403398, 5158677, 7810865, 11938526, 18025914, 27470474, 41728492, 63168010, 96432464, 145941227, 221789668, 337535752, 511171417, 778562148, 1180936519, 1792886049, 2728402868, 4135080658, 6290044659, 9553093391, 14495091494, 22049901853, 33451760778, 50834914946, 77243477833, 117213983395, 178220046834, 270548208970, 410918782909, 624500439953, 947891165651, 1440542381952, 2187765442740, 3322222982999, 5048613260979, 7664999153099, 11645137779084, 17689465863669],
-[1, 1, 2, 2, 3, 5, 10, 10, 18, 24, 32, 51, 62, 101, 170, 265, 370, 574, 842, 1295, 2013, 2955, 4607, 6797, 10499, 16121, 23856, 36722, 55346, 84292, 129190, 193512, 296246, 449247, 679200, 1041258, 1569158, 2388097, 3635487, 5496417, 8399431, 12704004, 19293413, 29402599, 44475479, 67778859, 102834301, 155984303, 237572342, 359910113, 547504389, 831782917, 1261305397, 1919735294, 2911976291, 4424247928, 6725228133, 10201439916, 15514083294, 23552049391, 35764223337, 54368400269, 82504327009, 125391259134, 190455616237, 289159284526, 439493706833, 667204826541, 1013627106060, 1539917880063, 2338035949402, 3552802312010, 5395187734625, 8194628912330, 12450033205963],];
+        // Decimal seeds and the base 3 stones they turn into share map keys
+        let base3 = StoneRules {
+            base: 3,
+            ..StoneRules::PUZZLE
+        };
+        let seeds = [decimal, binary];
+        let counts = stone_distribution(&seeds, 0, &base3).unwrap();
+        assert_eq!(counts.len(), 1);
+        let big = analyze_stones(&["1".parse::<BigStone>().unwrap()], 100, &base3);
+        let wide = analyze_stones(&[1u128], 100, &base3);
+        let (big, wide) = (big.unwrap().unwrap(), wide.unwrap().unwrap());
+        assert_eq!((big.settled_at, big.period), (wide.settled_at, wide.period));
+        assert_eq!(big.distinct, wide.distinct);
+        let decimal = |v: &[BigStone]| v.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let expected = wide
+            .reached
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(decimal(&big.reached), expected);
+    }
+
+    #[test]
+    fn custom_rules() {
+        let times3 = StoneRules {
+            multiplier: 3,
+            ..StoneRules::PUZZLE
+        };
+        // 0 -> 1 -> 3 -> 9 -> 27 -> 2 7 -> 6 21 -> 18 2 1 -> 1 8 6 3
+        let counts = (0..=8).map(|b| blink_count_with(&[0usize], b, &times3).unwrap());
+        assert!(counts.eq([1, 1, 1, 1, 1, 2, 2, 3, 4]));
+
+        // In binary, 1 -> 11 -> 1 1, so the stones double every other blink
+        let binary = StoneRules {
+            zero: 1,
+            base: 2,
+            multiplier: 3,
+        };
+        let counts = (0..=9).map(|b| blink_count_with(&[0usize], b, &binary).unwrap());
+        assert!(counts.eq([1, 1, 1, 2, 2, 4, 4, 8, 8, 16]));
+
+        // 0 -> 7 -> 7 * 2024 = 14168 -> 14168 * 2024 = 28676032 -> 2867 6032 -> 28 67 60 32
+        let sevens = StoneRules {
+            zero: 7,
+            ..StoneRules::PUZZLE
+        };
+        let counts = (0..=5).map(|b| blink_count_with(&[0usize], b, &sevens).unwrap());
+        assert!(counts.eq([1, 1, 1, 1, 2, 4]));
+
+        for rules in [times3, binary, sevens] {
+            let lut = generate_lut(10, 20, &rules);
+            for (n, row) in lut.iter().enumerate() {
+                for (i, &count) in row.iter().enumerate() {
+                    let expected = blink_count_with(&[n], i + 1, &rules).unwrap();
+                    assert_eq!(count as u128, expected, "{rules:?} lut[{n}][{i}]");
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "base must be at least 2")]
+    fn base_below_two() {
+        let unary = StoneRules {
+            base: 1,
+            ..StoneRules::PUZZLE
+        };
+        let _ = blink_count_with(&[5usize], 3, &unary);
+    }
+
+    /// Checksums and spot values of the 1000 x 75 table that used to be a literal in this file
+    #[test]
+    fn generated_lut_matches_old_literal() {
+        let lut = generate_lut(1000, 75, &StoneRules::PUZZLE);
+        assert_eq!(lut.len(), 1000);
+        assert!(lut.iter().all(|row| row.len() == 75));
+
+        let flat = || lut.iter().flatten().map(|&v| v as u64);
+        let hash = flat().fold(0u64, |acc, v| acc.wrapping_mul(31).wrapping_add(v));
+        assert_eq!(hash, 1494748370611611047);
+        assert_eq!(flat().sum::<u64>(), 64499086296430895);
+        assert_eq!(
+            lut.iter().map(|row| row[74] as u64).sum::<u64>(),
+            22034215354647600
+        );
+
+        for (n, blink, expected) in [
+            (0, 0, 1),
+            (0, 74, 22938365706844),
+            (1, 24, 29165),
+            (17, 74, 42760419959276),
+            (125, 5, 7),
+            (512, 40, 18176350),
+            (999, 74, 12450033205963),
+        ] {
+            assert_eq!(lut[n][blink], expected, "lut[{n}][{blink}]");
+        }
+    }
+
+    #[test]
+    fn built_lut_matches_generator() {
+        let lut = generate_lut(LUT_VALUES, LUT_BLINKS, &StoneRules::PUZZLE);
+        assert!(lut.iter().zip(LUT.iter()).all(|(a, b)| a[..] == b[..]));
+    }
+}
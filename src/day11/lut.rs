@@ -0,0 +1,39 @@
+//! Generator for the stone count lookup table. This file is also compiled into `build.rs`, so it
+//! may only depend on `rules`.
+
+use super::rules::{Blink, StoneRules};
+
+/// `lut[n][i]` is the number of stones that a single stone engraved with `n` turns into after
+/// `i + 1` blinks under `rules`, for every `n < values` and `i < blinks`.
+///
+/// Panics if a stone's engraving overflows a `usize`, or if `rules` aren't valid.
+pub fn generate(values: usize, blinks: usize, rules: &StoneRules) -> Vec<Vec<usize>> {
+    rules.validate();
+    let mut lut = vec![vec![0; blinks]; values];
+
+    // Fill one column at a time. Stones that leave the table's value range are expanded until they
+    // come back into range, where the previous columns already have the answer.
+    for b in 1..=blinks {
+        for n in 0..values {
+            let mut stack = vec![(b, n)];
+            let mut count = 0;
+            while let Some((i, n)) = stack.pop() {
+                if i == 0 {
+                    count += 1;
+                } else if n < values && i < b {
+                    count += lut[n][i - 1];
+                } else {
+                    match rules.blink(n).expect("stone engraving overflowed") {
+                        Blink::One(n) => stack.push((i - 1, n)),
+                        Blink::Split(left, right) => {
+                            stack.push((i - 1, left));
+                            stack.push((i - 1, right));
+                        }
+                    }
+                }
+            }
+            lut[n][b - 1] = count;
+        }
+    }
+    lut
+}
@@ -0,0 +1,52 @@
+//! Stone transformation rules. This file is also compiled into `build.rs`, so it must not depend
+//! on anything else in the crate.
+
+/// How a stone changes every blink. The first rule that applies wins:
+/// 1. A stone engraved with 0 is replaced by a stone engraved with `zero`.
+/// 2. A stone with an even number of digits in `base` (at least 2) is split into its left and right
+///    halves.
+/// 3. Any other stone has its engraving multiplied by `multiplier`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StoneRules {
+    pub zero: usize,
+    /// Must be at least 2, see [`StoneRules::validate`]
+    pub base: usize,
+    pub multiplier: usize,
+}
+
+/// What a single stone turns into after one blink
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Blink<T = usize> {
+    One(T),
+    Split(T, T),
+}
+
+impl StoneRules {
+    /// The rules from the puzzle: 0 becomes 1, even digit counts split, otherwise multiply by 2024
+    pub const PUZZLE: StoneRules = StoneRules {
+        zero: 1,
+        base: 10,
+        multiplier: 2024,
+    };
+
+    /// Panics if `base` is below 2, since numbers have no digits to count in such a base. The
+    /// counting functions and the LUT generator call this up front, `blink` doesn't.
+    pub fn validate(&self) {
+        assert!(self.base >= 2, "base must be at least 2, got {}", self.base);
+    }
+
+    /// Applies the rules to a stone engraved with `n`. Returns `None` if the new engraving does
+    /// not fit in a `usize`.
+    #[inline(always)]
+    pub fn blink(&self, n: usize) -> Option<Blink> {
+        if n == 0 {
+            return Some(Blink::One(self.zero));
+        }
+        let digits = n.ilog(self.base) + 1;
+        if digits.is_multiple_of(2) {
+            let mag = self.base.pow(digits / 2);
+            return Some(Blink::Split(n / mag, n % mag));
+        }
+        n.checked_mul(self.multiplier).map(Blink::One)
+    }
+}
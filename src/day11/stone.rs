@@ -0,0 +1,232 @@
+use std::{fmt, hash::Hash, str::FromStr};
+
+use super::rules::{Blink, StoneRules};
+
+/// An engraving that can be blinked at. `usize` is fastest, `u128` covers seeds up to ~10^38 and
+/// [`BigStone`] never overflows.
+pub trait Stone: Clone + Eq + Hash + fmt::Display {
+    /// Applies `rules` to this stone. Returns `None` if the new engraving doesn't fit in `Self`.
+    fn blink(&self, rules: &StoneRules) -> Option<Blink<Self>>;
+}
+
+/// Powers of 10 up to the largest that fits in a `u128`
+const POW10: [u128; 39] = {
+    let mut pow = [1; 39];
+    let mut i = 1;
+    while i < 39 {
+        pow[i] = pow[i - 1] * 10;
+        i += 1;
+    }
+    pow
+};
+
+/// Splits a number in half if it has an even number of digits
+#[inline(always)]
+pub fn split_usize(n: usize) -> Option<(usize, usize)> {
+    // 0 has no digits to split
+    let digits = n.checked_ilog10()? + 1;
+    if digits % 2 == 1 {
+        return None;
+    }
+    let mag = POW10[digits as usize / 2] as usize;
+    Some((n / mag, n % mag))
+}
+
+/// Splits a number in half if it has an even number of digits
+#[inline(always)]
+pub fn split_u128(n: u128) -> Option<(u128, u128)> {
+    let digits = n.checked_ilog10()? + 1;
+    if digits % 2 == 1 {
+        return None;
+    }
+    let mag = POW10[digits as usize / 2];
+    Some((n / mag, n % mag))
+}
+
+impl Stone for usize {
+    #[inline(always)]
+    fn blink(&self, rules: &StoneRules) -> Option<Blink<Self>> {
+        if rules.base != 10 {
+            return rules.blink(*self);
+        }
+        if *self == 0 {
+            return Some(Blink::One(rules.zero));
+        }
+        if let Some((a, b)) = split_usize(*self) {
+            return Some(Blink::Split(a, b));
+        }
+        self.checked_mul(rules.multiplier).map(Blink::One)
+    }
+}
+
+impl Stone for u128 {
+    #[inline(always)]
+    fn blink(&self, rules: &StoneRules) -> Option<Blink<Self>> {
+        let n = *self;
+        if n == 0 {
+            return Some(Blink::One(rules.zero as u128));
+        }
+        let split = if rules.base == 10 {
+            split_u128(n)
+        } else {
+            let base = rules.base as u128;
+            let digits = n.ilog(base) + 1;
+            digits.is_multiple_of(2).then(|| {
+                let mag = base.pow(digits / 2);
+                (n / mag, n % mag)
+            })
+        };
+        if let Some((a, b)) = split {
+            return Some(Blink::Split(a, b));
+        }
+        n.checked_mul(rules.multiplier as u128).map(Blink::One)
+    }
+}
+
+/// Arbitrary precision engraving. The digits are kept in the base of the rules it is blinked with,
+/// so splitting is just slicing the digit list. Stones compare and hash by value, whatever base
+/// they are written in.
+#[derive(Debug, Clone)]
+pub struct BigStone {
+    base: usize,
+    // Least significant digit first, without leading zeros. Zero has no digits.
+    digits: Vec<usize>,
+}
+
+impl BigStone {
+    /// `n` written in `base`
+    pub fn new(mut n: u128, base: usize) -> Self {
+        assert!(base >= 2, "base must be at least 2");
+        let mut digits = vec![];
+        while n > 0 {
+            digits.push((n % base as u128) as usize);
+            n /= base as u128;
+        }
+        BigStone { base, digits }
+    }
+
+    /// Same value written in another base
+    fn rebase(&self, base: usize) -> Self {
+        let mut result = BigStone::new(0, base);
+        for &d in self.digits.iter().rev() {
+            result = result.mul_add(self.base, d);
+        }
+        result
+    }
+
+    /// `self * m + add`
+    fn mul_add(&self, m: usize, add: usize) -> Self {
+        let base = self.base as u128;
+        let mut digits = Vec::with_capacity(self.digits.len() + 2);
+        let mut carry = add as u128;
+        for &d in &self.digits {
+            let x = d as u128 * m as u128 + carry;
+            digits.push((x % base) as usize);
+            carry = x / base;
+        }
+        while carry > 0 {
+            digits.push((carry % base) as usize);
+            carry /= base;
+        }
+        Self::from_digits(self.base, digits)
+    }
+
+    fn from_digits(base: usize, mut digits: Vec<usize>) -> Self {
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        BigStone { base, digits }
+    }
+}
+
+impl Stone for BigStone {
+    fn blink(&self, rules: &StoneRules) -> Option<Blink<Self>> {
+        if self.base != rules.base {
+            return self.rebase(rules.base).blink(rules);
+        }
+        if self.digits.is_empty() {
+            return Some(Blink::One(BigStone::new(rules.zero as u128, self.base)));
+        }
+        if self.digits.len().is_multiple_of(2) {
+            let (right, left) = self.digits.split_at(self.digits.len() / 2);
+            return Some(Blink::Split(
+                Self::from_digits(self.base, left.to_vec()),
+                Self::from_digits(self.base, right.to_vec()),
+            ));
+        }
+        Some(Blink::One(self.mul_add(rules.multiplier, 0)))
+    }
+}
+
+impl Ord for BigStone {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        if self.base != other.base {
+            return self.cmp(&other.rebase(self.base));
+        }
+        self.digits
+            .len()
+            .cmp(&other.digits.len())
+            .then_with(|| self.digits.iter().rev().cmp(other.digits.iter().rev()))
+    }
+}
+
+impl PartialEq for BigStone {
+    fn eq(&self, other: &Self) -> bool {
+        if self.base == other.base {
+            return self.digits == other.digits;
+        }
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Eq for BigStone {}
+
+impl Hash for BigStone {
+    /// Hashes the value modulo a prime, which doesn't depend on the base like the digits do
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        const P: u128 = (1 << 61) - 1;
+        let value = self
+            .digits
+            .iter()
+            .rev()
+            .fold(0u128, |acc, &d| (acc * self.base as u128 + d as u128) % P);
+        state.write_u64(value as u64);
+    }
+}
+
+impl PartialOrd for BigStone {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl FromStr for BigStone {
+    type Err = ();
+
+    /// Parses a decimal number of any length
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(());
+        }
+        let digits = s.bytes().rev().map(|b| (b - b'0') as usize).collect();
+        Ok(Self::from_digits(10, digits))
+    }
+}
+
+impl fmt::Display for BigStone {
+    /// Always written in decimal
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let decimal = if self.base == 10 {
+            self.clone()
+        } else {
+            self.rebase(10)
+        };
+        if decimal.digits.is_empty() {
+            return write!(f, "0");
+        }
+        for d in decimal.digits.iter().rev() {
+            write!(f, "{d}")?;
+        }
+        Ok(())
+    }
+}
@@ -1,158 +1,660 @@
+use std::collections::VecDeque;
+
 pub fn part1(input: &str) -> impl std::fmt::Display {
-    // Didn't spend any time on optimizing today
-    
-    let dim = input.find('\n').unwrap() + 1;
-    let input = input.as_bytes();
-    
-    let mut visited = vec![false; dim * (dim - 1)];
+    flood_fill(input, false)
+        .iter()
+        .map(Region::price)
+        .sum::<usize>()
+}
 
-    let north = -(dim as i32);
-    let south = dim as i32;
-    let west = -1;
-    let east = 1;
+pub fn part2(input: &str) -> impl std::fmt::Display {
+    flood_fill(input, false)
+        .iter()
+        .map(Region::bulk_price)
+        .sum::<usize>()
+}
 
-    let dirs = [north, east, south, west];
+/// A connected group of plots growing the same plant
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    pub plant: u8,
+    pub area: usize,
+    pub perimeter: usize,
+    pub sides: usize,
+    pub bounds: BoundingBox,
+    /// `(row, col)` of every plot in the region, in the order they were visited
+    pub cells: Vec<(usize, usize)>,
+}
 
-    // If we just moved north we don't have to check south...
-    let dirs_opp = [south, west, north, east];
+impl Region {
+    /// Fence price using the perimeter (part 1)
+    pub fn price(&self) -> usize {
+        self.area * self.perimeter
+    }
 
-    let max_index = input.len() as i32 - 1;
-    let mut result = 0;
+    /// Fence price using the number of sides (part 2)
+    pub fn bulk_price(&self) -> usize {
+        self.area * self.sides
+    }
+}
 
-    for (i, &plant) in input.iter().enumerate() {
-        if visited[i] {
-            continue;
+/// Inclusive range of rows and columns covered by a region
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoundingBox {
+    pub min_row: usize,
+    pub min_col: usize,
+    pub max_row: usize,
+    pub max_col: usize,
+}
+
+impl BoundingBox {
+    fn new(row: usize, col: usize) -> Self {
+        BoundingBox {
+            min_row: row,
+            min_col: col,
+            max_row: row,
+            max_col: col,
         }
-        if plant == b'\n' {
-            continue;
+    }
+
+    fn extend(&mut self, row: usize, col: usize) {
+        self.min_row = self.min_row.min(row);
+        self.min_col = self.min_col.min(col);
+        self.max_row = self.max_row.max(row);
+        self.max_col = self.max_col.max(col);
+    }
+}
+
+/// Garden plots addressed by row and column. Out of bounds plots read as `None`, so nothing wraps
+/// around onto the neighboring row.
+#[derive(Debug, Clone, Copy)]
+pub struct Garden<'a> {
+    plots: &'a [u8],
+    pub width: usize,
+    pub height: usize,
+}
+
+impl<'a> Garden<'a> {
+    pub fn new(input: &'a str) -> Self {
+        let width = input.find('\n').unwrap_or(input.len());
+        Garden {
+            plots: input.as_bytes(),
+            width,
+            height: input.lines().count(),
+        }
+    }
+
+    /// Plant growing at `(row, col)`
+    #[inline(always)]
+    pub fn get(&self, row: isize, col: isize) -> Option<u8> {
+        if row < 0 || col < 0 || row as usize >= self.height || col as usize >= self.width {
+            return None;
+        }
+        Some(self.plots[row as usize * (self.width + 1) + col as usize])
+    }
+
+    /// Number of fence segments around the plot at `(row, col)`
+    #[inline(always)]
+    pub fn fences(&self, row: isize, col: isize) -> usize {
+        let plant = self.get(row, col);
+        DIRS.iter()
+            .filter(|(dr, dc)| self.get(row + dr, col + dc) != plant)
+            .count()
+    }
+
+    /// Number of region corners at the plot at `(row, col)`. Every side of a region, including the
+    /// sides of holes, starts and ends at a corner, so summing this over a region gives its number
+    /// of sides.
+    #[inline(always)]
+    pub fn corners(&self, row: isize, col: isize) -> usize {
+        (0..4).filter(|&d| self.is_corner(row, col, d)).count()
+    }
+
+    /// Whether the region has a corner at the plot at `(row, col)` between `DIRS[d]` and the next
+    /// direction clockwise, e.g. north and east for the top right corner
+    #[inline(always)]
+    fn is_corner(&self, row: isize, col: isize, d: usize) -> bool {
+        let plant = self.get(row, col);
+        let same = |(dr, dc): (isize, isize)| self.get(row + dr, col + dc) == plant;
+        let a = DIRS[d];
+        let b = DIRS[(d + 1) % 4];
+        match (same(a), same(b)) {
+            // Outer corner, like the top right of a single plot
+            (false, false) => true,
+            // Inner corner, where the region bends around a plot of another plant. If both
+            // neighbors are in the region, a diagonal plot of the same plant is connected to them,
+            // so comparing plants is the same as comparing regions.
+            (true, true) => !same((a.0 + b.0, a.1 + b.1)),
+            _ => false,
         }
-        visited[i] = true;
-
-        // Valid plant that we have not visited, traverse region
-
-        // stack elements -> (index, direction that can be skipped when checking neighbors)
-        let mut stack = [(0, 0); 128];
-        stack[0] = (i as i32, 0);
-        let mut stack_len = 1;
-        
-        let mut area = 1;
-        let mut peri = 0;
-
-        while stack_len > 0 {
-            stack_len -= 1;
-            let (ii, dir_skip) = stack[stack_len];
-            for (dir_index, &dir) in dirs.iter().enumerate().filter(|(_, &d)| d != dir_skip) {
-                // Get neighbor index
-                let n = ii + dir;
-
-                // Bounds check
-                if n < 0 || n > max_index {
-                    peri += 1;
-                    continue;
+    }
+}
+
+/// North, east, south, west as `(row, col)` offsets
+const DIRS: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Every region in the garden, ordered by the first plot found in row-major order
+pub fn regions(input: &str) -> Vec<Region> {
+    flood_fill(input, true)
+}
+
+/// [`regions`], leaving `cells` empty unless `keep_cells` is set. The parts don't need them, and
+/// they take 16 bytes per plot.
+fn flood_fill(input: &str, keep_cells: bool) -> Vec<Region> {
+    let garden = Garden::new(input);
+    let mut visited = vec![false; garden.width * garden.height];
+    let mut regions = vec![];
+
+    // Breadth first, so the queue only holds the frontier of the region instead of up to half of
+    // its plots. Shared by all regions so it only has to grow once for the largest frontier.
+    let mut queue = VecDeque::with_capacity(128);
+
+    for row in 0..garden.height {
+        for col in 0..garden.width {
+            if visited[row * garden.width + col] {
+                continue;
+            }
+            visited[row * garden.width + col] = true;
+            let plant = garden.get(row as isize, col as isize).unwrap();
+
+            // Valid plant that we have not visited, traverse region
+            queue.push_back((row as isize, col as isize));
+
+            let mut region = Region {
+                plant,
+                area: 0,
+                perimeter: 0,
+                sides: 0,
+                bounds: BoundingBox::new(row, col),
+                cells: vec![],
+            };
+
+            while let Some((r, c)) = queue.pop_front() {
+                region.area += 1;
+                region.perimeter += garden.fences(r, c);
+                region.sides += garden.corners(r, c);
+                region.bounds.extend(r as usize, c as usize);
+                if keep_cells {
+                    region.cells.push((r as usize, c as usize));
                 }
 
-                if input[n as usize] == plant {
-                    if !visited[n as usize] {
-                        // Same plant type, mark as visited and increase area
-                        area += 1;
-                        visited[n as usize] = true;
-                        stack[stack_len] = (n, dirs_opp[dir_index]);
-                        stack_len += 1;
+                for (dr, dc) in DIRS {
+                    let (nr, nc) = (r + dr, c + dc);
+                    if garden.get(nr, nc) != Some(plant) {
+                        continue;
+                    }
+                    let n = nr as usize * garden.width + nc as usize;
+                    if !visited[n] {
+                        visited[n] = true;
+                        queue.push_back((nr, nc));
                     }
-                } else {
-                    // Different plant type
-                    peri += 1;
                 }
             }
+            regions.push(region);
         }
-        result += area * peri;
     }
-    result
+    regions
 }
 
-pub fn part2(input: &str) -> impl std::fmt::Display {
-    let dim = input.find('\n').unwrap() + 1;
-    let input = input.as_bytes();
+/// Region label of every plot, found with two-pass connected component labelling
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Labels {
+    pub width: usize,
+    pub height: usize,
+    /// Label of each plot in row-major order. Labels are numbered from 0 in the order their first
+    /// plot appears, so they match the order of [`regions`].
+    pub labels: Vec<u32>,
+    /// Number of distinct labels
+    pub count: usize,
+}
 
-    let mut visited = vec![false; dim * (dim - 1)];
+impl Labels {
+    /// Labels the connected groups of equal plots. Works for any grid of bytes laid out like the
+    /// puzzle input.
+    pub fn new(garden: &Garden) -> Self {
+        let (width, height) = (garden.width, garden.height);
+        let mut labels = vec![0u32; width * height];
+        let mut parent: Vec<u32> = vec![];
 
-    let north = -(dim as i32);
-    let south = dim as i32;
-    let west = -1;
-    let east = 1;
+        // First pass: give each plot the label of its north or west neighbor, and record that
+        // the two labels are equivalent when both neighbors match but were labelled differently
+        for row in 0..height {
+            for col in 0..width {
+                let i = row * width + col;
+                let plant = garden.get(row as isize, col as isize);
+                let north = (row > 0 && garden.get(row as isize - 1, col as isize) == plant)
+                    .then(|| labels[i - width]);
+                let west = (col > 0 && garden.get(row as isize, col as isize - 1) == plant)
+                    .then(|| labels[i - 1]);
 
-    let dirs = [north, east, south, west];
-    let clockwise = [east, south, west, north];
+                labels[i] = match (north, west) {
+                    (Some(n), Some(w)) => union(&mut parent, n, w),
+                    (Some(l), None) | (None, Some(l)) => l,
+                    (None, None) => {
+                        parent.push(parent.len() as u32);
+                        parent.len() as u32 - 1
+                    }
+                };
+            }
+        }
 
-    let max_index = input.len() as i32 - 1;
-    let mut result = 0;
-    let mut i = 0;
+        // Second pass: replace every label with its root, renumbered in order of appearance
+        let mut compact = vec![u32::MAX; parent.len()];
+        let mut count = 0;
+        for l in labels.iter_mut() {
+            let root = find(&mut parent, *l) as usize;
+            if compact[root] == u32::MAX {
+                compact[root] = count;
+                count += 1;
+            }
+            *l = compact[root];
+        }
 
-    loop {
-        if visited[i] {
-            i += 1;
-            continue;
+        Labels {
+            width,
+            height,
+            labels,
+            count: count as usize,
         }
-        if input[i] == b'\n' {
-            if i == input.len() - 1 {
-                break;
-            }
-            i += 1;
-            continue;
+    }
+
+    /// Label of the plot at `(row, col)`
+    #[inline(always)]
+    pub fn get(&self, row: usize, col: usize) -> u32 {
+        self.labels[row * self.width + col]
+    }
+}
+
+/// Root of the set containing `x`, halving the path along the way
+#[inline(always)]
+fn find(parent: &mut [u32], mut x: u32) -> u32 {
+    while parent[x as usize] != x {
+        parent[x as usize] = parent[parent[x as usize] as usize];
+        x = parent[x as usize];
+    }
+    x
+}
+
+/// Merges the sets containing `a` and `b`, returning the new root. The smaller root wins so labels
+/// keep pointing at the earliest plot.
+#[inline(always)]
+fn union(parent: &mut [u32], a: u32, b: u32) -> u32 {
+    let a = find(parent, a);
+    let b = find(parent, b);
+    let (root, child) = if a < b { (a, b) } else { (b, a) };
+    parent[child as usize] = root;
+    root
+}
+
+/// Same as [`regions`], but built from a [`Labels`] map in a single row-major pass over the plots
+/// instead of a flood fill. `cells` are in row-major order.
+///
+/// `cargo run --release --example day12_labels` compares the two on generated gardens. The flood
+/// fill is slightly faster, ~2.5 ms vs ~2.8 ms for 140x140 and ~470 ms vs ~610 ms for 2000x2000.
+/// Most of that is spent building the region catalogue, labelling alone takes ~0.4 ms and ~90 ms.
+pub fn regions_by_label(input: &str) -> Vec<Region> {
+    let garden = Garden::new(input);
+    let labels = Labels::new(&garden);
+
+    let mut regions: Vec<Option<Region>> = vec![None; labels.count];
+    for row in 0..garden.height {
+        for col in 0..garden.width {
+            let (r, c) = (row as isize, col as isize);
+            let region = regions[labels.get(row, col) as usize].get_or_insert_with(|| Region {
+                plant: garden.get(r, c).unwrap(),
+                area: 0,
+                perimeter: 0,
+                sides: 0,
+                bounds: BoundingBox::new(row, col),
+                cells: vec![],
+            });
+            region.area += 1;
+            region.perimeter += garden.fences(r, c);
+            region.sides += garden.corners(r, c);
+            region.bounds.extend(row, col);
+            region.cells.push((row, col));
         }
-        visited[i] = true;
-        let plant = input[i];
-
-        // Valid plant that we have not visited, traverse region
-
-        let mut stack = [0; 128];
-        stack[0] = i as i32;
-        let mut stack_len = 1;
-
-        let mut area = 1;
-        let mut sides = 0; // Number of sides is equal to number of corners
-
-        while stack_len > 0 {
-            stack_len -= 1;
-            let ii = stack[stack_len];
-            for (dir_index, &dir) in dirs.iter().enumerate() {
-                // Get neighbor index
-                let n = ii + dir;
-
-                // Bounds check
-                if n < 0 || n > max_index {
-                    // Check if on corner
-                    let cw = ii + clockwise[dir_index];
-                    if cw < 0 || cw > max_index || input[cw as usize] != plant {
-                        sides += 1;
+    }
+    regions.into_iter().map(Option::unwrap).collect()
+}
+
+/// Fence outline of a region. Points are `(x, y)` grid corners, where plot `(row, col)` spans
+/// `(col, row)` to `(col + 1, row + 1)`. Only the corners are listed, straight runs of fence are
+/// merged into a single edge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outline {
+    pub plant: u8,
+    /// Outer boundary, clockwise on screen (y pointing down)
+    pub outer: Vec<(usize, usize)>,
+    /// Boundaries of any holes, counter-clockwise on screen
+    pub holes: Vec<Vec<(usize, usize)>>,
+}
+
+/// Traces the fences of every region, in the same order as [`regions`]. The polygons have exactly
+/// as many corners as [`Garden::corners`] counts, so a region's corner count across its outer
+/// boundary and holes is its number of sides.
+pub fn outlines(input: &str) -> Vec<Outline> {
+    let garden = Garden::new(input);
+    let width = garden.width;
+
+    // Side `k` (index into `DIRS`) of each plot that has been walked along already
+    let mut walked = vec![false; width * garden.height * 4];
+
+    // Corner that side `k` of a plot starts at when walking clockwise around the plot
+    let start = |r: isize, c: isize, k: usize| -> (usize, usize) {
+        let (dx, dy) = [(0, 0), (1, 0), (1, 1), (0, 1)][k];
+        ((c + dx) as usize, (r + dy) as usize)
+    };
+
+    regions(input)
+        .into_iter()
+        .map(|region| {
+            let mut outline = Outline {
+                plant: region.plant,
+                outer: vec![],
+                holes: vec![],
+            };
+            let plant = Some(region.plant);
+
+            for &(row, col) in &region.cells {
+                let (row, col) = (row as isize, col as isize);
+                for k in 0..4 {
+                    let (dr, dc) = DIRS[k];
+                    if garden.get(row + dr, col + dc) == plant || walked[edge(width, row, col, k)] {
+                        continue;
                     }
-                    continue;
-                }
-                if input[n as usize] == plant {
-                    if !visited[n as usize] {
-                        // Same plant type, mark as visited and increase area
-                        area += 1;
-                        visited[n as usize] = true;
-                        stack[stack_len] = n;
-                        stack_len += 1;
+
+                    // Walk the fence with the region on the right until we are back at this side,
+                    // recording a point whenever the fence turns. Every point is a corner that
+                    // `Garden::corners` counts: an outer corner of the current plot where the fence
+                    // turns right, or an inner corner of the plot ahead where it turns left.
+                    let mut polygon = vec![];
+                    let (mut r, mut c, mut k) = (row, col, k);
+                    loop {
+                        walked[edge(width, r, c, k)] = true;
+                        let (fr, fc) = DIRS[k];
+                        let (ar, ac) = DIRS[(k + 1) % 4];
+                        if garden.is_corner(r, c, k) {
+                            polygon.push(start(r, c, (k + 1) % 4));
+                            k = (k + 1) % 4;
+                        } else if garden.is_corner(r + ar, c + ac, (k + 3) % 4) {
+                            polygon.push(start(r, c, (k + 1) % 4));
+                            (r, c, k) = (r + ar + fr, c + ac + fc, (k + 3) % 4);
+                        } else {
+                            // Straight on
+                            (r, c) = (r + ar, c + ac);
+                        }
+                        if walked[edge(width, r, c, k)] {
+                            break;
+                        }
                     }
-                } else {
-                    // Different plant type, check if on corner
-                    let cw = ii + clockwise[dir_index];
-                    let diag = cw + dir;
-                    if cw < 0
-                        || cw > max_index
-                        || input[cw as usize] != plant
-                        || (diag >= 0 && input[diag as usize] == plant)
-                    {
-                        sides += 1;
+                    if signed_area(&polygon) > 0 {
+                        outline.outer = polygon;
+                    } else {
+                        outline.holes.push(polygon);
                     }
                 }
             }
+            outline
+        })
+        .collect()
+}
+
+/// Index into the `walked` list of `outlines`
+#[inline(always)]
+fn edge(width: usize, row: isize, col: isize, k: usize) -> usize {
+    (row as usize * width + col as usize) * 4 + k
+}
+
+/// Twice the signed area of a polygon, positive when clockwise on screen
+fn signed_area(polygon: &[(usize, usize)]) -> i64 {
+    polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .map(|(&(x0, y0), &(x1, y1))| x0 as i64 * y1 as i64 - x1 as i64 * y0 as i64)
+        .sum()
+}
+
+/// SVG image of the outlines, one filled path per region with holes cut out. Each plot is
+/// `scale` units wide.
+pub fn outlines_to_svg(outlines: &[Outline], width: usize, height: usize, scale: usize) -> String {
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {width} {height}\">\n",
+        width * scale,
+        height * scale,
+    );
+    for outline in outlines {
+        let mut d = String::new();
+        for polygon in std::iter::once(&outline.outer).chain(&outline.holes) {
+            for (i, (x, y)) in polygon.iter().enumerate() {
+                d.push_str(&format!("{}{x} {y} ", if i == 0 { 'M' } else { 'L' }));
+            }
+            d.push_str("Z ");
         }
-        i += 1;
-        result += area * sides;
+        // Spread plant letters around the color wheel so neighbors are easy to tell apart
+        let hue = outline.plant as usize * 137 % 360;
+        svg.push_str(&format!(
+            "<path d=\"{}\" fill=\"hsl({hue}, 60%, 70%)\" fill-rule=\"evenodd\" stroke=\"black\" stroke-width=\"0.05\"><title>{}</title></path>\n",
+            d.trim_end(),
+            match outline.plant {
+                b'<' => "&lt;".to_string(),
+                b'&' => "&amp;".to_string(),
+                plant => (plant as char).to_string(),
+            },
+        ));
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// JSON list of the outlines, e.g. `[{"plant":"A","outer":[[0,0],[4,0],[4,1],[0,1]],"holes":[]}]`
+pub fn outlines_to_json(outlines: &[Outline]) -> String {
+    let polygon = |p: &[(usize, usize)]| {
+        let points = p
+            .iter()
+            .map(|(x, y)| format!("[{x},{y}]"))
+            .collect::<Vec<_>>();
+        format!("[{}]", points.join(","))
+    };
+    let regions = outlines
+        .iter()
+        .map(|o| {
+            let holes = o.holes.iter().map(|h| polygon(h)).collect::<Vec<_>>();
+            let plant = match o.plant {
+                b'"' => "\\\"".to_string(),
+                b'\\' => "\\\\".to_string(),
+                c if c < 0x20 => format!("\\u{c:04x}"),
+                c => (c as char).to_string(),
+            };
+            format!(
+                "{{\"plant\":\"{plant}\",\"outer\":{},\"holes\":[{}]}}",
+                polygon(&o.outer),
+                holes.join(","),
+            )
+        })
+        .collect::<Vec<_>>();
+    format!("[{}]", regions.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `size` x `size` garden of a single plant
+    fn uniform(size: usize) -> String {
+        ("A".repeat(size) + "\n").repeat(size)
     }
 
-    result
-}
\ No newline at end of file
+    #[test]
+    fn region_larger_than_old_stack() {
+        let regions = regions(&uniform(300));
+        assert_eq!(regions.len(), 1);
+        assert_eq!(regions[0].area, 300 * 300);
+        assert_eq!(regions[0].perimeter, 4 * 300);
+        assert_eq!(regions[0].sides, 4);
+    }
+
+    #[test]
+    fn region_covering_5000x5000() {
+        let regions = flood_fill(&uniform(5000), false);
+        assert_eq!(regions.len(), 1);
+        assert_eq!(regions[0].area, 5000 * 5000);
+        assert_eq!(regions[0].perimeter, 4 * 5000);
+        assert_eq!(regions[0].sides, 4);
+        assert!(regions[0].cells.is_empty());
+    }
+
+    /// `(plant, area, sides)` of every region
+    fn sides(input: &str) -> Vec<(char, usize, usize)> {
+        regions(input)
+            .iter()
+            .map(|r| (r.plant as char, r.area, r.sides))
+            .collect()
+    }
+
+    #[test]
+    fn sides_at_grid_edge() {
+        let input = "AAAA\nBBCD\nBBCC\nEEEC\n";
+        assert_eq!(
+            sides(input),
+            [
+                ('A', 4, 4),
+                ('B', 4, 4),
+                ('C', 4, 8),
+                ('D', 1, 4),
+                ('E', 3, 4)
+            ]
+        );
+        assert_eq!(part2(input).to_string(), "80");
+        // The row ends don't wrap around, so the fences along the left and right edge count
+        assert_eq!(sides("AAA\nAAA\n"), [('A', 6, 4)]);
+    }
+
+    #[test]
+    fn sides_with_holes() {
+        let input = "OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO\n";
+        let regions = sides(input);
+        // 4 outer sides plus 4 for each of the 4 holes
+        assert_eq!(regions[0], ('O', 21, 20));
+        assert!(regions[1..].iter().all(|&r| r == ('X', 1, 4)));
+        assert_eq!(part2(input).to_string(), "436");
+
+        let input = "EEEEE\nEXXXX\nEEEEE\nEXXXX\nEEEEE\n";
+        assert_eq!(sides(input), [('E', 17, 12), ('X', 4, 4), ('X', 4, 4)]);
+        assert_eq!(part2(input).to_string(), "236");
+    }
+
+    #[test]
+    fn sides_touching_diagonally() {
+        // The two B regions only touch at a corner, which is also an inner corner of the A region
+        let input = "AAAAAA\nAAABBA\nAAABBA\nABBAAA\nABBAAA\nAAAAAA\n";
+        assert_eq!(sides(input), [('A', 28, 12), ('B', 4, 4), ('B', 4, 4)]);
+        assert_eq!(part2(input).to_string(), "368");
+
+        let input = "AB\nBA\n";
+        assert_eq!(
+            sides(input),
+            [('A', 1, 4), ('B', 1, 4), ('B', 1, 4), ('A', 1, 4)]
+        );
+        assert_eq!(sides("ABA\nBAB\nABA\n").len(), 9);
+    }
+
+    #[test]
+    fn corners_of_single_plots() {
+        let garden = Garden::new("AB\nBA\n");
+        for (row, col) in [(0, 0), (0, 1), (1, 0), (1, 1)] {
+            assert_eq!(garden.corners(row, col), 4);
+        }
+        // Outer corner plus the inner corner where the region bends around the B
+        let garden = Garden::new("AA\nAB\n");
+        assert_eq!(garden.corners(0, 0), 2);
+        assert_eq!(garden.corners(1, 0), 2);
+        assert_eq!(garden.corners(0, 1), 2);
+    }
+
+    #[test]
+    fn outline_corners_are_sides() {
+        let mut inputs = vec![
+            "OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO\n".to_string(),
+            "AAAAAA\nAAABBA\nAAABBA\nABBAAA\nABBAAA\nAAAAAA\n".to_string(),
+            "ABA\nBAB\nABA\n".to_string(),
+        ];
+        // Random gardens of 3 plants, mostly copying a neighbor so regions get holes and odd shapes
+        let mut seed = 0x2545f4914f6cdd1du64;
+        for (width, height) in [(1, 1), (7, 3), (20, 20), (50, 9)] {
+            let mut plots = vec![b'A'; width * height];
+            for i in 0..plots.len() {
+                seed ^= seed << 13;
+                seed ^= seed >> 7;
+                seed ^= seed << 17;
+                plots[i] = match seed % 10 {
+                    0..=3 if i >= width => plots[i - width],
+                    4..=6 if i % width > 0 => plots[i - 1],
+                    _ => b'A' + (seed / 10 % 3) as u8,
+                };
+            }
+            let rows = plots
+                .chunks(width)
+                .map(|row| String::from_utf8_lossy(row) + "\n");
+            inputs.push(rows.collect());
+        }
+
+        for input in &inputs {
+            let regions = regions(input);
+            let outlines = outlines(input);
+            assert_eq!(regions.len(), outlines.len());
+            for (region, outline) in regions.iter().zip(&outlines) {
+                assert_eq!(region.plant, outline.plant);
+                let corners =
+                    outline.outer.len() + outline.holes.iter().map(Vec::len).sum::<usize>();
+                assert_eq!(corners, region.sides, "{input}");
+                assert!(signed_area(&outline.outer) > 0);
+                assert!(outline.holes.iter().all(|h| signed_area(h) < 0));
+            }
+        }
+    }
+
+    #[test]
+    fn outline_with_hole() {
+        let outlines = outlines("AAA\nABA\nAAA\n");
+        assert_eq!(
+            outlines[0],
+            Outline {
+                plant: b'A',
+                // Clockwise on screen: down the right side first
+                outer: vec![(3, 0), (3, 3), (0, 3), (0, 0)],
+                // Counter-clockwise on screen: down the left side first
+                holes: vec![vec![(1, 1), (1, 2), (2, 2), (2, 1)]],
+            }
+        );
+        assert_eq!(outlines[1].outer, [(2, 1), (2, 2), (1, 2), (1, 1)]);
+        assert!(outlines[1].holes.is_empty());
+
+        let svg = outlines_to_svg(&outlines, 3, 3, 10);
+        assert!(
+            svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"30\" height=\"30\"")
+        );
+        assert!(svg.contains("<path d=\"M3 0 L3 3 L0 3 L0 0 Z M1 1 L1 2 L2 2 L2 1 Z\""));
+        assert_eq!(svg.matches("<path").count(), 2);
+        assert!(svg.ends_with("</svg>\n"));
+
+        assert_eq!(
+            outlines_to_json(&outlines),
+            concat!(
+                r#"[{"plant":"A","outer":[[3,0],[3,3],[0,3],[0,0]],"holes":[[[1,1],[1,2],[2,2],[2,1]]]},"#,
+                r#"{"plant":"B","outer":[[2,1],[2,2],[1,2],[1,1]],"holes":[]}]"#,
+            )
+        );
+    }
+
+    #[test]
+    fn outlines_escape_plants() {
+        let outlines = outlines("\"\\<&\n");
+        let json = outlines_to_json(&outlines);
+        assert!(json.starts_with(r#"[{"plant":"\"","#));
+        assert!(json.contains(r#"{"plant":"\\","#));
+        let svg = outlines_to_svg(&outlines, 4, 1, 1);
+        assert!(svg.contains("<title>&lt;</title>"));
+        assert!(svg.contains("<title>&amp;</title>"));
+    }
+}
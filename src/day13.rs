@@ -1,76 +1,184 @@
+use std::cmp::Ordering;
+
 pub fn part1(input: &str) -> impl std::fmt::Display {
-    let input = input.as_bytes();
-    let mut result = 0;
-    
-    let mut i = 0;    
-    loop {
-        if i == input.len() + 1 {
-            break;
-        }
-        let m = parse_machine_p1(input, &mut i);
-        i += 2;
+    total_tokens(input, &ClawConfig::PART1)
+}
+// 35729
 
-        // This formula only works if vectors A and B are not integer multiples of each other.
-        // `a` and `b` must be integers, so we multiply by 10 in the formula, then check that there
-        // is no remainder when dividing by 10, which tells us that the value will be an integer.
-        let mut b = (m.ax * m.cy - m.ay * m.cx) * 10 / (m.ax * m.by - m.ay * m.bx);
+pub fn part2(input: &str) -> impl std::fmt::Display {
+    total_tokens(input, &ClawConfig::PART2)
+}
+// 88584689879723
 
-        // `a` and `b` cannot be > 100
-        if !(0..=1000).contains(&b) || b % 10 != 0 {
-            continue;
-        }
-        b /= 10;
-        let a = (m.cx - b * m.bx) * 10 / m.ax;
-        if !(0..=1000).contains(&a) || a % 10 != 0 {
-            continue;
-        }
-        result += (a / 10) * 3 + b;
+/// Rules that differ between the two parts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClawConfig {
+    /// Added to both prize coordinates
+    pub offset: i64,
+    /// Tokens it costs to press button A, must not be negative
+    pub cost_a: i64,
+    /// Tokens it costs to press button B, must not be negative
+    pub cost_b: i64,
+    /// Most times each button may be pressed, `None` for no limit
+    pub max_presses: Option<i64>,
+}
+
+impl ClawConfig {
+    pub const PART1: ClawConfig = ClawConfig {
+        offset: 0,
+        cost_a: 3,
+        cost_b: 1,
+        max_presses: Some(100),
+    };
+
+    pub const PART2: ClawConfig = ClawConfig {
+        offset: 10000000000000,
+        cost_a: 3,
+        cost_b: 1,
+        max_presses: None,
+    };
+
+    /// Tokens it costs to press A `a` times and B `b` times, or `Skip::Overflow` if that doesn't
+    /// fit in an `i64`
+    pub fn tokens(&self, (a, b): (i64, i64)) -> Result<i64, Skip> {
+        a.checked_mul(self.cost_a)
+            .zip(b.checked_mul(self.cost_b))
+            .and_then(|(a, b)| a.checked_add(b))
+            .ok_or(Skip::Overflow)
     }
+}
 
-    result
+/// Fewest tokens needed to win every prize that can be won. Each machine's tokens fit in an `i64`,
+/// the total is an `i128` so it can't overflow.
+pub fn total_tokens(input: &str, config: &ClawConfig) -> i128 {
+    machine_reports(input, config)
+        .map(|r| r.tokens as i128)
+        .sum()
 }
-// 35729
 
-#[derive(Debug)]
-struct MachineP1 {
-    ax: i32,
-    ay: i32,
-    bx: i32,
-    by: i32,
-    cx: i32,
-    cy: i32,
+/// Why a prize can't be won
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Skip {
+    /// No whole number of presses lands exactly on the prize
+    NotInteger,
+    /// Landing on the prize would take a negative number of presses
+    Negative,
+    /// Every way to land on the prize presses a button more than `max_presses` times
+    OverLimit,
+    /// The prize plus `offset`, the number of presses needed or their cost in tokens doesn't fit in
+    /// an `i64`
+    Overflow,
 }
 
-#[inline(always)]
-fn parse_machine_p1(input: &[u8], i: &mut usize) -> MachineP1 {
-    *i += 12;
-    let ax = ((input[*i] - b'0') * 10 + input[*i + 1] - b'0') as i32;
-    *i += 6;
-    let ay = ((input[*i] - b'0') * 10 + input[*i + 1] - b'0') as i32;
-    *i += 15;
-    let bx = ((input[*i] - b'0') * 10 + input[*i + 1] - b'0') as i32;
-    *i += 6;
-    let by = ((input[*i] - b'0') * 10 + input[*i + 1] - b'0') as i32;
+/// How a single machine went, see [`report`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MachineReport {
+    pub machine: ClawMachine,
+    /// `(a, b)` presses of the cheapest win, or why the prize was skipped
+    pub presses: Result<(i64, i64), Skip>,
+    /// Tokens spent on this machine, 0 if skipped
+    pub tokens: i64,
+}
 
-    *i += 12;
-    let mut cx = 0;
-    loop {
-        cx = cx * 10 + (input[*i] - b'0') as i32;
-        *i += 1;
-        if input[*i] == b',' {
-            break;
+/// Totals over all machines, see [`report`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ReportSummary {
+    pub won: usize,
+    pub tokens: i128,
+    pub not_integer: usize,
+    pub negative: usize,
+    pub over_limit: usize,
+    pub overflow: usize,
+}
+
+/// Outcome of every machine, in input order, along with totals. `summary.tokens` is the puzzle
+/// answer for `config`.
+pub fn report(input: &str, config: &ClawConfig) -> (Vec<MachineReport>, ReportSummary) {
+    let reports = machine_reports(input, config).collect::<Vec<_>>();
+    let mut summary = ReportSummary::default();
+    for r in &reports {
+        match r.presses {
+            Ok(_) => {
+                summary.won += 1;
+                summary.tokens += r.tokens as i128;
+            }
+            Err(Skip::NotInteger) => summary.not_integer += 1,
+            Err(Skip::Negative) => summary.negative += 1,
+            Err(Skip::OverLimit) => summary.over_limit += 1,
+            Err(Skip::Overflow) => summary.overflow += 1,
         }
     }
-    *i += 4;
-    let mut cy = 0;
-    loop {
-        cy = cy * 10 + (input[*i] - b'0') as i32;
-        *i += 1;
-        if input[*i] == b'\n' {
-            break;
+    (reports, summary)
+}
+
+/// Parses and solves the machines one by one
+fn machine_reports<'a>(
+    input: &'a str,
+    config: &'a ClawConfig,
+) -> impl Iterator<Item = MachineReport> + 'a {
+    let bytes = input.as_bytes();
+    let mut i = 0;
+    std::iter::from_fn(move || {
+        if i >= bytes.len() {
+            return None;
         }
+        let machine = parse_machine(bytes, &mut i);
+        i += 2;
+
+        let mut presses = machine.solve(config);
+        let mut tokens = 0;
+        if let Ok(p) = presses {
+            // Winning with this many presses costs more tokens than fit in an `i64`
+            presses = config.tokens(p).map(|t| tokens = t).and(presses);
+        }
+        Some(MachineReport {
+            machine,
+            presses,
+            tokens,
+        })
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClawMachine {
+    pub ax: i64,
+    pub ay: i64,
+    pub bx: i64,
+    pub by: i64,
+    pub cx: i64,
+    pub cy: i64,
+}
+
+impl ClawMachine {
+    /// Cheapest `(a, b)` presses that win the prize under `config`, or why it can't be won
+    pub fn solve(&self, config: &ClawConfig) -> Result<(i64, i64), Skip> {
+        let offset = |c: i64| c.checked_add(config.offset).ok_or(Skip::Overflow);
+        solve(
+            (self.ax, self.ay),
+            (self.bx, self.by),
+            (offset(self.cx)?, offset(self.cy)?),
+            config,
+        )
     }
-    MachineP1 {
+}
+
+/// Parses one machine starting at `i`, leaving `i` on the newline after the prize
+#[inline(always)]
+fn parse_machine(input: &[u8], i: &mut usize) -> ClawMachine {
+    *i += 12;
+    let ax = parse_num(input, i, b',');
+    *i += 4;
+    let ay = parse_num(input, i, b'\n');
+    *i += 13;
+    let bx = parse_num(input, i, b',');
+    *i += 4;
+    let by = parse_num(input, i, b'\n');
+    *i += 10;
+    let cx = parse_num(input, i, b',');
+    *i += 4;
+    let cy = parse_num(input, i, b'\n');
+
+    ClawMachine {
         ax,
         ay,
         bx,
@@ -80,86 +188,860 @@ fn parse_machine_p1(input: &[u8], i: &mut usize) -> MachineP1 {
     }
 }
 
-pub fn part2(input: &str) -> impl std::fmt::Display {
-    let input = input.as_bytes();
-    let mut result = 0;
-    
-    let mut i = 0;    
+/// Parses digits until `end` is reached, leaving `i` on `end`
+#[inline(always)]
+fn parse_num(input: &[u8], i: &mut usize, end: u8) -> i64 {
+    let mut n = 0;
     loop {
-        if i == input.len() + 1 {
+        n = n * 10 + (input[*i] - b'0') as i64;
+        *i += 1;
+        if *i == input.len() || input[*i] == end {
             break;
         }
-        let m = parse_machine_p2(input, &mut i);
-        i += 2;
+    }
+    n
+}
 
-        // This formula only works if vectors A and B are not integer multiples of each other.
-        // `a` and `b` must be integers, so we multiply by 10 in the formula, then check that there
-        // is no remainder when dividing by 10, which tells us that the value will be an integer.
-        let mut b = (m.ax * m.cy - m.ay * m.cx) * 10 / (m.ax * m.by - m.ay * m.bx);
+/// Cheapest `(a, b)` presses of buttons `a` and `b` that move the claw exactly onto `prize`, or
+/// why there are none. All products are done in `i128`, so they can't overflow for inputs that
+/// fit in `i64`, but the press counts themselves might not fit back into an `i64`.
+fn solve(
+    a: (i64, i64),
+    b: (i64, i64),
+    prize: (i64, i64),
+    config: &ClawConfig,
+) -> Result<(i64, i64), Skip> {
+    let (ax, ay, bx, by) = (a.0 as i128, a.1 as i128, b.0 as i128, b.1 as i128);
+    let (cx, cy) = (prize.0 as i128, prize.1 as i128);
 
-        if b < 0 || b % 10 != 0 {
-            continue;
+    // Cramer's rule only works if vectors A and B are not integer multiples of each other
+    let det = ax * by - ay * bx;
+    if det == 0 {
+        return solve_collinear(a, b, prize, config);
+    }
+
+    // Press counts must be integers, so the numerators have to divide exactly
+    let num_a = cx * by - cy * bx;
+    let num_b = ax * cy - ay * cx;
+    if num_a % det != 0 || num_b % det != 0 {
+        return Err(Skip::NotInteger);
+    }
+    let (pa, pb) = (num_a / det, num_b / det);
+
+    if pa < 0 || pb < 0 {
+        return Err(Skip::Negative);
+    }
+    if config
+        .max_presses
+        .is_some_and(|m| pa > m as i128 || pb > m as i128)
+    {
+        return Err(Skip::OverLimit);
+    }
+    presses_to_i64(pa, pb)
+}
+
+/// Converts press counts found in `i128` back, or `Skip::Overflow` if they don't fit
+fn presses_to_i64(pa: i128, pb: i128) -> Result<(i64, i64), Skip> {
+    let convert = |p: i128| i64::try_from(p).map_err(|_| Skip::Overflow);
+    Ok((convert(pa)?, convert(pb)?))
+}
+
+/// Cheapest `(a, b)` presses reaching `prize` when buttons `a` and `b` move along the same line, so
+/// Cramer's rule has nothing to divide by. Along that line the machine is a single linear
+/// Diophantine equation `a * p + b * q = t`, whose solutions are
+///     a = a0 + k * q / g,  b = b0 - k * p / g
+/// for `g = gcd(p, q)` and any integer `k`. The token cost is linear in `k`, so the cheapest
+/// solution sits at one end of the range of `k` that keeps the press counts in bounds.
+fn solve_collinear(
+    a: (i64, i64),
+    b: (i64, i64),
+    prize: (i64, i64),
+    config: &ClawConfig,
+) -> Result<(i64, i64), Skip> {
+    // The prize has to be on the line the buttons move along, which is where the claw ends up on
+    // the other axis. Along that line, solving one axis solves both.
+    let dir = if a != (0, 0) { a } else { b };
+    if dir.0 as i128 * prize.1 as i128 != dir.1 as i128 * prize.0 as i128 {
+        return Err(Skip::NotInteger);
+    }
+
+    // Solve along whichever axis the buttons actually move in
+    let (p, q, t) = if (a.0, b.0) != (0, 0) {
+        (a.0 as i128, b.0 as i128, prize.0 as i128)
+    } else if (a.1, b.1) != (0, 0) {
+        (a.1 as i128, b.1 as i128, prize.1 as i128)
+    } else {
+        // Neither button moves the claw
+        return if prize == (0, 0) {
+            Ok((0, 0))
+        } else {
+            Err(Skip::NotInteger)
+        };
+    };
+
+    let (g, x, y) = ext_gcd(p, q);
+    if t % g != 0 {
+        return Err(Skip::NotInteger);
+    }
+    // Can be far outside the `i64` range even when a small solution exists, so the press counts
+    // are only converted once `k` is picked
+    let (a0, b0) = (x * (t / g), y * (t / g));
+    let (u, v) = (q / g, p / g);
+
+    // a >= 0, b >= 0
+    let mut range = KRange::default();
+    if !(range.constrain(-u, a0) && range.constrain(v, b0)) {
+        return Err(Skip::Negative);
+    }
+    if let Some(max) = config.max_presses {
+        let max = max as i128;
+        if !(range.constrain(u, max - a0) && range.constrain(-v, max - b0)) {
+            return Err(Skip::OverLimit);
         }
-        b /= 10;
-        let a = (m.cx - b * m.bx) * 10 / m.ax;
-        if a < 0 || a % 10 != 0 {
-            continue;
+    }
+
+    // Cost is `cost_a * a + cost_b * b`, which changes by `cost_a * u - cost_b * v` for every step
+    // of `k`
+    let k = range.cheapest(config.cost_a as i128 * u - config.cost_b as i128 * v);
+    presses_to_i64(a0 + k * u, b0 - k * v)
+}
+
+/// Range of an integer `k` satisfying a set of constraints `k * coef <= rhs`. `None` means
+/// unbounded on that side.
+#[derive(Debug, Default, Clone, Copy)]
+struct KRange {
+    lo: Option<i128>,
+    hi: Option<i128>,
+}
+
+impl KRange {
+    /// Adds the constraint `k * coef <= rhs`. Returns false if that leaves no valid `k`.
+    fn constrain(&mut self, coef: i128, rhs: i128) -> bool {
+        match coef.cmp(&0) {
+            Ordering::Greater => {
+                let h = rhs.div_euclid(coef);
+                self.hi = Some(self.hi.map_or(h, |hi| hi.min(h)));
+            }
+            Ordering::Less => {
+                let l = -(rhs.div_euclid(-coef));
+                self.lo = Some(self.lo.map_or(l, |lo| lo.max(l)));
+            }
+            Ordering::Equal => return rhs >= 0,
         }
-        result += (a / 10) * 3 + b;
+        self.lo.zip(self.hi).is_none_or(|(lo, hi)| lo <= hi)
     }
 
-    result
+    /// The `k` with the lowest cost, for a cost that changes by `step` every time `k` goes up by
+    /// one. With non-negative token costs the cost can't keep dropping forever, so that end of the
+    /// range is always bounded.
+    fn cheapest(&self, step: i128) -> i128 {
+        match step.cmp(&0) {
+            Ordering::Greater => self.lo,
+            Ordering::Less => self.hi,
+            Ordering::Equal => self.lo.or(self.hi).or(Some(0)),
+        }
+        .expect("token costs must not be negative")
+    }
 }
-// 88584689879723
 
-#[derive(Debug)]
-struct MachineP2 {
-    ax: i64,
-    ay: i64,
-    bx: i64,
-    by: i64,
-    cx: i64,
-    cy: i64,
+/// Returns `(g, x, y)` such that `a * x + b * y == g == gcd(a, b)`, with `g >= 0`
+fn ext_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        return if a < 0 { (-a, -1, 0) } else { (a, 1, 0) };
+    }
+    let (g, x, y) = ext_gcd(b, a.rem_euclid(b));
+    (g, y, x - a.div_euclid(b) * y)
 }
 
-#[inline(always)]
-fn parse_machine_p2(input: &[u8], i: &mut usize) -> MachineP2 {
-    *i += 12;
-    let ax = ((input[*i] - b'0') * 10 + input[*i + 1] - b'0') as i64;
-    *i += 6;
-    let ay = ((input[*i] - b'0') * 10 + input[*i + 1] - b'0') as i64;
-    *i += 15;
-    let bx = ((input[*i] - b'0') * 10 + input[*i + 1] - b'0') as i64;
-    *i += 6;
-    let by = ((input[*i] - b'0') * 10 + input[*i + 1] - b'0') as i64;
+/// Claw machine with any number of buttons, for puzzle variants
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultiMachine {
+    /// `(x, y)` movement of each button, in input order
+    pub buttons: Vec<(i64, i64)>,
+    pub prize: (i64, i64),
+}
 
-    *i += 12;
-    let mut cx = 0;
-    loop {
-        cx = cx * 10 + (input[*i] - b'0') as i64;
-        *i += 1;
-        if input[*i] == b',' {
-            break;
+/// Why [`MultiMachine::solve`] found no presses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MultiSkip {
+    /// No combination of presses lands on the prize within `max_presses`
+    Unreachable,
+    /// `costs` needs one non-negative cost per button
+    BadCosts,
+    /// The prize plus `offset`, or the presses of the cheapest win or their cost in tokens, don't
+    /// fit in an `i64`
+    Overflow,
+    /// Solving would take more than [`SEARCH_LIMIT`] steps, see [`MultiMachine::solve`]
+    SearchTooLarge,
+}
+
+/// Most steps [`MultiMachine::solve`] takes: the number of presses it tries for each searched
+/// button multiplied together, times the determinant it walks through for the last three buttons
+pub const SEARCH_LIMIT: i64 = 1_000_000;
+
+impl MultiMachine {
+    /// Cheapest number of presses of each button that wins the prize, where pressing button `i`
+    /// costs `costs[i]` tokens. Only `offset` and `max_presses` are used from `config`.
+    ///
+    /// Two buttons use the same closed form as [`ClawMachine::solve`]. Three buttons are solved
+    /// exactly in time proportional to the determinant of two of them. Every further button (or
+    /// the third one if all three are collinear) is searched press by press. That needs either
+    /// `max_presses` or buttons that never move the claw backwards to know when to stop. Without a
+    /// press limit, or when all the searching together would take more than [`SEARCH_LIMIT`]
+    /// steps, it fails with [`MultiSkip::SearchTooLarge`] instead of running for hours on part 2
+    /// sized prizes.
+    pub fn solve(&self, costs: &[i64], config: &ClawConfig) -> Result<Vec<i64>, MultiSkip> {
+        if costs.len() != self.buttons.len() || costs.iter().any(|&c| c < 0) {
+            return Err(MultiSkip::BadCosts);
         }
+        let offset = |c: i64| c.checked_add(config.offset).ok_or(MultiSkip::Overflow);
+        let prize = (offset(self.prize.0)?, offset(self.prize.1)?);
+        solve_buttons(
+            &self.buttons,
+            costs,
+            prize,
+            config.max_presses,
+            SEARCH_LIMIT,
+        )
+        .map(|(presses, _)| presses)
     }
-    cx += 10000000000000;
-    *i += 4;
-    let mut cy = 0;
-    loop {
-        cy = cy * 10 + (input[*i] - b'0') as i64;
-        *i += 1;
-        if input[*i] == b'\n' {
-            break;
+}
+
+/// Parses machines in the puzzle format with any number of `Button X: X+.., Y+..` lines before
+/// each prize. Movements may also be negative, e.g. `X-5`.
+pub fn parse_multi_machines(input: &str) -> Vec<MultiMachine> {
+    // Numbers come in pairs, after the first '+', '-' or '=' and after the ", Y"
+    let coords = |line: &str| -> (i64, i64) {
+        let (x, y) = line.split_once(", Y").unwrap();
+        let num = |s: &str| -> i64 {
+            let s = s.trim_start_matches(|c: char| !matches!(c, '+' | '-' | '=' | '0'..='9'));
+            s.trim_start_matches(['+', '=']).parse().unwrap()
+        };
+        (num(&x[x.find('X').unwrap() + 1..]), num(y))
+    };
+
+    let mut machines = vec![];
+    let mut buttons = vec![];
+    for line in input.lines() {
+        if line.starts_with("Button") {
+            buttons.push(coords(line));
+        } else if line.starts_with("Prize") {
+            machines.push(MultiMachine {
+                buttons: std::mem::take(&mut buttons),
+                prize: coords(line),
+            });
         }
     }
-    cy += 10000000000000;
+    machines
+}
 
-    MachineP2 {
-        ax,
-        ay,
-        bx,
-        by,
-        cx,
-        cy,
+/// Fewest tokens needed to win every prize that can be won, see [`MultiMachine::solve`]. Machines
+/// with fewer buttons than `costs` use the first costs. Fails on the first machine that can't be
+/// solved for any reason other than [`MultiSkip::Unreachable`], including one with more buttons
+/// than `costs`.
+pub fn total_tokens_multi(
+    input: &str,
+    costs: &[i64],
+    config: &ClawConfig,
+) -> Result<i64, MultiSkip> {
+    let mut total: i64 = 0;
+    for m in parse_multi_machines(input) {
+        let costs = costs.get(..m.buttons.len()).ok_or(MultiSkip::BadCosts)?;
+        match m.solve(costs, config) {
+            Ok(presses) => {
+                total = total
+                    .checked_add(cost(&presses, costs)?)
+                    .ok_or(MultiSkip::Overflow)?
+            }
+            Err(MultiSkip::Unreachable) => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(total)
+}
+
+/// Tokens it costs to press each button `presses[i]` times
+fn cost(presses: &[i64], costs: &[i64]) -> Result<i64, MultiSkip> {
+    presses
+        .iter()
+        .zip(costs)
+        .try_fold(0i64, |sum, (p, c)| sum.checked_add(p.checked_mul(*c)?))
+        .ok_or(MultiSkip::Overflow)
+}
+
+/// Cheapest presses and their total cost, taking at most `budget` steps
+fn solve_buttons(
+    buttons: &[(i64, i64)],
+    costs: &[i64],
+    prize: (i64, i64),
+    max_presses: Option<i64>,
+    budget: i64,
+) -> Result<(Vec<i64>, i64), MultiSkip> {
+    use MultiSkip::Unreachable;
+    match buttons.len() {
+        0 => (prize == (0, 0)).then(|| (vec![], 0)).ok_or(Unreachable),
+        1 => {
+            let (vx, vy) = buttons[0];
+            // Same number of presses has to work on both axes
+            let presses = match (vx, vy) {
+                (0, 0) => (prize == (0, 0)).then_some(0),
+                (0, _) => (prize.0 == 0 && prize.1 % vy == 0).then(|| prize.1 / vy),
+                _ => (prize.0 % vx == 0).then(|| prize.0 / vx),
+            }
+            .ok_or(Unreachable)?;
+            let in_range = presses >= 0 && max_presses.is_none_or(|m| presses <= m);
+            let reaches = |v: i64, p: i64| presses.checked_mul(v) == Some(p);
+            if !(in_range && reaches(vx, prize.0) && reaches(vy, prize.1)) {
+                return Err(Unreachable);
+            }
+            Ok((vec![presses], cost(&[presses], costs)?))
+        }
+        2 => {
+            // Fast path
+            let config = ClawConfig {
+                offset: 0,
+                cost_a: costs[0],
+                cost_b: costs[1],
+                max_presses,
+            };
+            let (a, b) = solve(buttons[0], buttons[1], prize, &config).map_err(|e| match e {
+                Skip::Overflow => MultiSkip::Overflow,
+                _ => Unreachable,
+            })?;
+            Ok((vec![a, b], cost(&[a, b], costs)?))
+        }
+        3 => {
+            // Pick two buttons that aren't collinear to solve for, and let the third one vary
+            let cross = |(ax, ay): (i64, i64), (bx, by): (i64, i64)| {
+                ax as i128 * by as i128 - ay as i128 * bx as i128
+            };
+            let [i, j, free] = [[0, 1, 2], [0, 2, 1], [1, 2, 0]]
+                .into_iter()
+                .find(|&[i, j, _]| cross(buttons[i], buttons[j]) != 0)
+                .unwrap_or([0, 1, 2]);
+            let det = cross(buttons[i], buttons[j]).abs();
+            if det == 0 {
+                return search_last_button(buttons, costs, prize, max_presses, budget);
+            }
+            if det > budget as i128 {
+                return Err(MultiSkip::SearchTooLarge);
+            }
+            let (pi, pj, pf) = solve_three(
+                [buttons[i], buttons[j], buttons[free]],
+                [costs[i], costs[j], costs[free]],
+                prize,
+                max_presses,
+            )?;
+            let mut presses = vec![0; 3];
+            (presses[i], presses[j], presses[free]) = (pi, pj, pf);
+            let c = cost(&presses, costs)?;
+            Ok((presses, c))
+        }
+        _ => search_last_button(buttons, costs, prize, max_presses, budget),
     }
-}
\ No newline at end of file
+}
+
+/// Tries every number of presses for the last button and solves the rest for what's left, each
+/// with an equal share of `budget`
+fn search_last_button(
+    buttons: &[(i64, i64)],
+    costs: &[i64],
+    prize: (i64, i64),
+    max_presses: Option<i64>,
+    budget: i64,
+) -> Result<(Vec<i64>, i64), MultiSkip> {
+    let n = buttons.len() - 1;
+    let (vx, vy) = buttons[n];
+
+    // If no button moves the claw backwards along an axis, the last button can't be pressed more
+    // often than it takes to pass the prize on that axis
+    let limit = |axis: fn(&(i64, i64)) -> i64, v: i64, p: i64| {
+        (v > 0 && buttons.iter().all(|b| axis(b) >= 0)).then(|| p.max(0) / v)
+    };
+    let bound = [
+        max_presses,
+        limit(|b| b.0, vx, prize.0),
+        limit(|b| b.1, vy, prize.1),
+    ]
+    .into_iter()
+    .flatten()
+    .min()
+    .filter(|&bound| bound < budget)
+    .ok_or(MultiSkip::SearchTooLarge)?;
+    let budget = budget / (bound + 1);
+
+    // Wins whose cost doesn't fit are never the cheapest, unless there are no others
+    let mut overflow = false;
+    let mut best: Option<(Vec<i64>, i64)> = None;
+    for p in 0..=bound {
+        let rest = p
+            .checked_mul(vx)
+            .and_then(|dx| prize.0.checked_sub(dx))
+            .zip(p.checked_mul(vy).and_then(|dy| prize.1.checked_sub(dy)))
+            .ok_or(MultiSkip::Overflow)?;
+        match solve_buttons(&buttons[..n], &costs[..n], rest, max_presses, budget) {
+            Ok((mut presses, c)) => {
+                let Some(c) = p.checked_mul(costs[n]).and_then(|pc| c.checked_add(pc)) else {
+                    overflow = true;
+                    continue;
+                };
+                if best.as_ref().is_none_or(|&(_, b)| c < b) {
+                    presses.push(p);
+                    best = Some((presses, c));
+                }
+            }
+            Err(MultiSkip::Unreachable) => {}
+            Err(MultiSkip::Overflow) => overflow = true,
+            Err(e) => return Err(e),
+        }
+    }
+    best.ok_or(match overflow {
+        true => MultiSkip::Overflow,
+        false => MultiSkip::Unreachable,
+    })
+}
+
+/// Cheapest presses `(a, b, c)` of three buttons where `a` and `b` are not collinear.
+///
+/// For `c` presses of the third button, Cramer's rule gives `a` and `b` as linear functions of `c`
+/// divided by the determinant `d` of `a` and `b`. Whether both divide exactly only depends on
+/// `c mod |d|`, so for every residue that works, `c = r + |d| * m` and everything including the
+/// cost is linear in `m`. The cheapest `m` is then at one end of its valid range, the same way as
+/// for collinear buttons.
+fn solve_three(
+    buttons: [(i64, i64); 3],
+    costs: [i64; 3],
+    prize: (i64, i64),
+    max_presses: Option<i64>,
+) -> Result<(i64, i64, i64), MultiSkip> {
+    let cross = |(ax, ay): (i64, i64), (bx, by): (i64, i64)| {
+        ax as i128 * by as i128 - ay as i128 * bx as i128
+    };
+    let [a, b, c] = buttons;
+    let d = cross(a, b);
+    let period = d.abs();
+
+    // a = (na - c * sa) / d, b = (nb - c * sb) / d
+    let (na, sa) = (cross(prize, b), cross(c, b));
+    let (nb, sb) = (cross(a, prize), cross(a, c));
+    let (cost_a, cost_b, cost_c) = (costs[0] as i128, costs[1] as i128, costs[2] as i128);
+
+    let mut best: Option<(i128, (i128, i128, i128))> = None;
+    for r in 0..period {
+        if (na - r * sa) % d != 0 || (nb - r * sb) % d != 0 {
+            continue;
+        }
+        // With c = r + period * m: a = a0 - m * ua, b = b0 - m * ub
+        let (a0, b0) = ((na - r * sa) / d, (nb - r * sb) / d);
+        let (ua, ub) = (sa * period / d, sb * period / d);
+
+        // m >= 0 keeps c >= 0
+        let mut range = KRange::default();
+        let mut ok = range.constrain(-1, 0) && range.constrain(ua, a0) && range.constrain(ub, b0);
+        if let Some(max) = max_presses {
+            let max = max as i128;
+            ok = ok
+                && range.constrain(-ua, max - a0)
+                && range.constrain(-ub, max - b0)
+                && range.constrain(period, max - r);
+        }
+        if !ok {
+            continue;
+        }
+
+        let m = range.cheapest(-ua * cost_a - ub * cost_b + period * cost_c);
+        let presses = (a0 - m * ua, b0 - m * ub, r + period * m);
+        // Saturating only ranks wins too expensive for an `i64` last, converting the cheapest one
+        // back catches them
+        let cost = presses
+            .0
+            .saturating_mul(cost_a)
+            .saturating_add(presses.1.saturating_mul(cost_b))
+            .saturating_add(presses.2.saturating_mul(cost_c));
+        if best.is_none_or(|(c, _)| cost < c) {
+            best = Some((cost, presses));
+        }
+    }
+    let (_, (a, b, c)) = best.ok_or(MultiSkip::Unreachable)?;
+    let fit = |p: i128| i64::try_from(p).map_err(|_| MultiSkip::Overflow);
+    Ok((fit(a)?, fit(b)?, fit(c)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+";
+
+    fn machine([ax, ay, bx, by, cx, cy]: [i64; 6]) -> ClawMachine {
+        ClawMachine {
+            ax,
+            ay,
+            bx,
+            by,
+            cx,
+            cy,
+        }
+    }
+
+    #[test]
+    fn example() {
+        assert_eq!(part1(EXAMPLE).to_string(), "480");
+        assert_eq!(part2(EXAMPLE).to_string(), "875318608908");
+    }
+
+    /// Machines the old "multiply by 10 and check `% 10`" trick accepted. The first ones need a
+    /// press count with a fractional part below 0.1, which got truncated away, the last two need
+    /// a press count just below zero, which got truncated to 0. None of them can be won.
+    #[test]
+    fn adversarial_fractions() {
+        for m in [
+            [34, 70, 79, 80, 5992, 8203],
+            [29, 39, 91, 29, 6375, 3992],
+            [37, 43, 96, 65, 11346, 9456],
+            [84, 39, 53, 97, 2108, 3514],
+            [71, 91, 71, 21, 3692, 4170],
+            [12, 47, 64, 63, 501, 1021],
+            [15, 58, 85, 52, 4026, 5878],
+            [12, 57, 42, 90, 2293, 6727],
+            [84, 26, 90, 55, 764, 236],
+            [73, 43, 50, 55, 881, 518],
+        ] {
+            let m = machine(m);
+            assert_eq!(m.solve(&ClawConfig::PART1), Err(Skip::NotInteger), "{m:?}");
+            // Brute force agrees there is no way to reach the prize
+            let reachable = (0..=100)
+                .flat_map(|a| (0..=100).map(move |b| (a, b)))
+                .any(|(a, b)| a * m.ax + b * m.bx == m.cx && a * m.ay + b * m.by == m.cy);
+            assert!(!reachable, "{m:?}");
+        }
+    }
+
+    /// Coordinates that overflowed the old `i32` part 1 parser
+    #[test]
+    fn large_coordinates() {
+        let m = machine([94, 34, 22, 67, 8400 * 1_000_000, 5400 * 1_000_000]);
+        assert_eq!(m.solve(&ClawConfig::PART1), Err(Skip::OverLimit));
+        let unlimited = ClawConfig {
+            max_presses: None,
+            ..ClawConfig::PART1
+        };
+        assert_eq!(m.solve(&unlimited), Ok((80_000_000, 40_000_000)));
+    }
+
+    #[test]
+    fn overflow() {
+        let m = machine([1, 0, 1, 1, i64::MAX, 1]);
+        assert_eq!(m.solve(&ClawConfig::PART2), Err(Skip::Overflow));
+
+        // a - b = x and b = y, so a = x + y is out of range
+        let m = machine([1, 0, -1, 1, i64::MAX, i64::MAX]);
+        let no_offset = ClawConfig {
+            offset: 0,
+            ..ClawConfig::PART2
+        };
+        assert_eq!(m.solve(&no_offset), Err(Skip::Overflow));
+    }
+
+    #[test]
+    fn example_report() {
+        let (machines, summary) = report(EXAMPLE, &ClawConfig::PART1);
+        let outcomes = machines
+            .iter()
+            .map(|m| (m.presses, m.tokens))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            outcomes,
+            [
+                (Ok((80, 40)), 280),
+                (Err(Skip::NotInteger), 0),
+                (Ok((38, 86)), 200),
+                (Err(Skip::NotInteger), 0),
+            ]
+        );
+        assert_eq!(machines[1].machine, machine([26, 66, 67, 21, 12748, 12176]));
+        assert_eq!(
+            summary,
+            ReportSummary {
+                won: 2,
+                tokens: 480,
+                not_integer: 2,
+                ..ReportSummary::default()
+            }
+        );
+
+        // In part 2 it's the other two machines that can be won
+        let (machines, summary) = report(EXAMPLE, &ClawConfig::PART2);
+        let won = machines
+            .iter()
+            .map(|m| m.presses.is_ok())
+            .collect::<Vec<_>>();
+        assert_eq!(won, [false, true, false, true]);
+        assert_eq!(summary.tokens, 875318608908);
+        assert_eq!((summary.won, summary.not_integer), (2, 2));
+    }
+
+    /// Wins that take more tokens than fit in an `i64`
+    #[test]
+    fn token_overflow() {
+        let no_offset = ClawConfig {
+            offset: 0,
+            ..ClawConfig::PART2
+        };
+        let m = machine([1, 0, 0, 1, i64::MAX / 2, 0]);
+        assert_eq!(m.solve(&no_offset), Ok((i64::MAX / 2, 0)));
+        assert_eq!(no_offset.tokens((i64::MAX / 2, 0)), Err(Skip::Overflow));
+
+        let input = format!(
+            "Button A: X+1, Y+0\nButton B: X+0, Y+1\nPrize: X={}, Y=0\n",
+            i64::MAX / 2
+        );
+        assert_eq!(total_tokens(&input, &no_offset), 0);
+        let (machines, summary) = report(&input, &no_offset);
+        assert_eq!(machines[0].presses, Err(Skip::Overflow));
+        assert_eq!((summary.won, summary.overflow), (0, 1));
+
+        // Each machine fits, the total doesn't
+        let machine = format!(
+            "Button A: X+1, Y+0\nButton B: X+0, Y+1\nPrize: X={}, Y=0\n",
+            i64::MAX / 4
+        );
+        let input = format!("{machine}\n{machine}");
+        let expected = 2 * 3 * (i64::MAX / 4) as i128;
+        assert_eq!(total_tokens(&input, &no_offset), expected);
+        assert_eq!(report(&input, &no_offset).1.tokens, expected);
+    }
+
+    /// Buttons moving along the same line, where the determinant is 0
+    #[test]
+    fn collinear_buttons() {
+        let unlimited = ClawConfig {
+            max_presses: None,
+            offset: 0,
+            ..ClawConfig::PART1
+        };
+        // B moves twice as far for a third of the price
+        let m = machine([1, 1, 2, 2, 10, 10]);
+        assert_eq!(m.solve(&ClawConfig::PART1), Ok((0, 5)));
+        let m = machine([1, 1, 2, 2, 11, 11]);
+        assert_eq!(m.solve(&ClawConfig::PART1), Ok((1, 5)));
+
+        // Off the line, or on it between two reachable points
+        let m = machine([1, 1, 2, 2, 10, 11]);
+        assert_eq!(m.solve(&ClawConfig::PART1), Err(Skip::NotInteger));
+        let m = machine([2, 2, 4, 4, 3, 3]);
+        assert_eq!(m.solve(&ClawConfig::PART1), Err(Skip::NotInteger));
+        // Only moving along y
+        let m = machine([0, 3, 0, 5, 0, 13]);
+        assert_eq!(m.solve(&ClawConfig::PART1), Ok((1, 2)));
+        let m = machine([0, 3, 0, 5, 1, 13]);
+        assert_eq!(m.solve(&ClawConfig::PART1), Err(Skip::NotInteger));
+
+        // Neither button moves the claw
+        let m = machine([0, 0, 0, 0, 0, 0]);
+        assert_eq!(m.solve(&ClawConfig::PART1), Ok((0, 0)));
+        let m = machine([0, 0, 0, 0, 1, 0]);
+        assert_eq!(m.solve(&ClawConfig::PART1), Err(Skip::NotInteger));
+        // Only one of them does
+        let m = machine([0, 0, 3, 1, 9, 3]);
+        assert_eq!(m.solve(&ClawConfig::PART1), Ok((0, 3)));
+
+        // 100 presses of each are fine, one more step along the line isn't
+        let m = machine([1, 1, 2, 2, 300, 300]);
+        assert_eq!(m.solve(&ClawConfig::PART1), Ok((100, 100)));
+        let m = machine([1, 1, 2, 2, 301, 301]);
+        assert_eq!(m.solve(&ClawConfig::PART1), Err(Skip::OverLimit));
+        assert_eq!(m.solve(&unlimited), Ok((1, 150)));
+        let m = machine([1, 1, 2, 2, 100, 100]);
+        let negative = ClawConfig {
+            offset: -101,
+            ..ClawConfig::PART1
+        };
+        assert_eq!(m.solve(&negative), Err(Skip::Negative));
+
+        // The particular solution from the extended gcd is ~5 * 10^23 presses, but there is a
+        // small one
+        let (a, b) = (1_000_000_007, 1_000_000_009);
+        let m = machine([a, 0, b, 0, 1_000_002 * a + 3 * b, 0]);
+        assert_eq!(m.solve(&unlimited), Ok((1_000_002, 3)));
+    }
+
+    /// Cheapest presses by trying every combination up to `max` presses per button
+    fn brute_force(m: &MultiMachine, costs: &[i64], max: i64) -> Option<i64> {
+        let mut presses = vec![0; m.buttons.len()];
+        let mut best = None;
+        loop {
+            let reach = presses
+                .iter()
+                .zip(&m.buttons)
+                .fold((0, 0), |(x, y), (p, b)| (x + p * b.0, y + p * b.1));
+            if reach == m.prize {
+                let cost = presses.iter().zip(costs).map(|(p, c)| p * c).sum::<i64>();
+                best = Some(best.map_or(cost, |b: i64| b.min(cost)));
+            }
+            // Next combination, like counting in base `max + 1`
+            let Some(i) = presses.iter().position(|&p| p < max) else {
+                return best;
+            };
+            presses[i] += 1;
+            presses[..i].fill(0);
+        }
+    }
+
+    #[test]
+    fn multi_button_matches_brute_force() {
+        let config = ClawConfig {
+            max_presses: Some(8),
+            ..ClawConfig::PART1
+        };
+        let machines = [
+            (vec![(3, 1), (1, 3), (2, 2)], (14, 10)),
+            (vec![(2, 1), (4, 2), (6, 3)], (16, 8)),
+            (vec![(5, -1), (-2, 3), (1, 1), (0, 2)], (9, 11)),
+            (vec![(1, 0), (0, 1), (1, 1), (2, 1)], (12, 9)),
+        ];
+        for (buttons, prize) in machines {
+            let m = MultiMachine { buttons, prize };
+            for costs in [vec![3, 1, 2, 5], vec![1, 1, 1, 1], vec![0, 4, 1, 2]] {
+                let costs = &costs[..m.buttons.len()];
+                let cost = m
+                    .solve(costs, &config)
+                    .ok()
+                    .map(|p| p.iter().zip(costs).map(|(p, c)| p * c).sum::<i64>());
+                assert_eq!(cost, brute_force(&m, costs, 8), "{m:?} {costs:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn multi_button_errors() {
+        let unlimited = ClawConfig {
+            max_presses: None,
+            ..ClawConfig::PART1
+        };
+        // A button moving backwards means nothing bounds the search for the fourth button
+        let m = MultiMachine {
+            buttons: vec![(5, -1), (-2, 3), (1, 1), (0, 2)],
+            prize: (9, 11),
+        };
+        assert_eq!(
+            m.solve(&[1, 1, 1, 1], &unlimited),
+            Err(MultiSkip::SearchTooLarge)
+        );
+
+        // Three collinear buttons with a part 2 sized prize
+        let m = MultiMachine {
+            buttons: vec![(2, 1), (4, 2), (6, 3)],
+            prize: (16, 8),
+        };
+        assert_eq!(
+            m.solve(&[1, 1, 1], &ClawConfig::PART2),
+            Err(MultiSkip::SearchTooLarge)
+        );
+        assert_eq!(m.solve(&[1, 1], &unlimited), Err(MultiSkip::BadCosts));
+        assert_eq!(m.solve(&[1, -1, 1], &unlimited), Err(MultiSkip::BadCosts));
+        // Without an offset the buttons never move backwards, which bounds the search
+        assert_eq!(m.solve(&[1, 1, 1], &unlimited), Ok(vec![0, 1, 2]));
+
+        // Each level is within the limit, 555556 presses of the fourth button times a determinant
+        // of 119999 for the other three isn't
+        let m = MultiMachine {
+            buttons: vec![(400, 1), (1, 300), (3, 5), (2, 9)],
+            prize: (5_000_000, 5_000_000),
+        };
+        let limited = ClawConfig {
+            max_presses: Some(SEARCH_LIMIT),
+            ..unlimited
+        };
+        assert_eq!(
+            m.solve(&[1, 1, 1, 1], &limited),
+            Err(MultiSkip::SearchTooLarge)
+        );
+
+        assert_eq!(
+            total_tokens_multi(EXAMPLE, &[3, 1], &ClawConfig::PART1),
+            Ok(480)
+        );
+        assert_eq!(
+            total_tokens_multi(EXAMPLE, &[3], &ClawConfig::PART1),
+            Err(MultiSkip::BadCosts)
+        );
+    }
+
+    #[test]
+    fn multi_button_overflow() {
+        let unlimited = ClawConfig {
+            max_presses: None,
+            offset: 0,
+            ..ClawConfig::PART1
+        };
+        let half = i64::MAX / 2;
+        let m = |buttons: Vec<(i64, i64)>, prize| MultiMachine { buttons, prize };
+
+        // Presses fit, tokens don't, with one, two and three buttons
+        let one = m(vec![(1, 0)], (half, 0));
+        assert_eq!(one.solve(&[3], &unlimited), Err(MultiSkip::Overflow));
+        assert_eq!(one.solve(&[1], &unlimited), Ok(vec![half]));
+        let two = m(vec![(1, 0), (0, 1)], (half, 0));
+        assert_eq!(two.solve(&[3, 1], &unlimited), Err(MultiSkip::Overflow));
+        let three = m(vec![(1, 0), (0, 1), (1, 1)], (half, half));
+        assert_eq!(
+            three.solve(&[3, 3, 5], &unlimited),
+            Err(MultiSkip::Overflow)
+        );
+        assert_eq!(three.solve(&[1, 1, 1], &unlimited), Ok(vec![0, 0, half]));
+
+        // a - b = MAX and b + c = MAX, so without pressing C it takes 2 * MAX presses of A, which
+        // used to be truncated to -2
+        let presses = m(vec![(1, 0), (-1, 1), (0, 1)], (i64::MAX, i64::MAX));
+        assert_eq!(
+            presses.solve(&[0, 0, 1], &unlimited),
+            Err(MultiSkip::Overflow)
+        );
+        assert_eq!(
+            presses.solve(&[1, 1, 0], &unlimited),
+            Ok(vec![i64::MAX, 0, i64::MAX])
+        );
+
+        // Searching the fourth button skips the wins that cost too much, and only fails if
+        // there's nothing else
+        let limited = ClawConfig {
+            max_presses: Some(8),
+            ..unlimited
+        };
+        let four = m(vec![(1, 0), (0, 1), (1, 1), (2, 2)], (1, 1));
+        assert_eq!(four.solve(&[i64::MAX; 4], &limited), Ok(vec![0, 0, 1, 0]));
+        let four = m(vec![(1, 0), (0, 1), (1, 1), (2, 2)], (3, 1));
+        assert_eq!(
+            four.solve(&[i64::MAX; 4], &limited),
+            Err(MultiSkip::Overflow)
+        );
+
+        let machine = "Button A: X+1, Y+0\nButton B: X+0, Y+1\nPrize: X=4611686018427387904, Y=0\n";
+        let input = format!("{machine}\n{machine}");
+        assert_eq!(
+            total_tokens_multi(&input, &[1, 1], &unlimited),
+            Err(MultiSkip::Overflow)
+        );
+        assert_eq!(
+            total_tokens_multi(machine, &[1, 1], &unlimited),
+            Ok(1 << 62)
+        );
+    }
+}
@@ -6,10 +6,6 @@ const AIR: u8 = b'.';
 const BOX_LEFT: u8 = b'[';
 const BOX_RIGHT: u8 = b']';
 
-// Hardcoded dimensions
-const HEIGHT: usize = 50;
-const WIDTH: usize = HEIGHT * 2;
-
 pub fn part1(input: &str) -> impl std::fmt::Display {
     unsafe { inner_part1(input.as_bytes()) }
 }
@@ -20,25 +16,34 @@ pub fn part2(input: &str) -> impl std::fmt::Display {
 }
 // 1492011
 
+/// Width and height of the warehouse map at the start of the input
+fn dimensions(input: &[u8]) -> (usize, usize) {
+    let width = input.iter().position(|&b| b == b'\n').unwrap();
+    let map_len = input.windows(2).position(|w| w == b"\n\n").unwrap() + 1;
+    (width, map_len / (width + 1))
+}
+
 unsafe fn inner_part1(input: &[u8]) -> usize {
-    const DIM: usize = HEIGHT + 1;
-    
+    let (width, height) = dimensions(input);
+    // Row length including the newline
+    let dim = width + 1;
+
     let mut rob = input.iter().position(|b| b == &ROBOT).unwrap() as i32;
 
-    const NORTH: i32 = -(DIM as i32);
-    const SOUTH: i32 = DIM as i32;
+    let north: i32 = -(dim as i32);
+    let south: i32 = dim as i32;
     const WEST: i32 = -1;
     const EAST: i32 = 1;
 
     // Faster direction lookup
-    let mut move_map = [0; 167];
+    let mut move_map = [0; b'v' as usize + 1];
     move_map[b'<' as usize] = WEST;
     move_map[b'>' as usize] = EAST;
-    move_map[b'^' as usize] = NORTH;
-    move_map[b'v' as usize] = SOUTH;
+    move_map[b'^' as usize] = north;
+    move_map[b'v' as usize] = south;
 
-    let mut grid = input[..DIM * (DIM - 1)].to_vec();
-    let moves = &input[DIM * (DIM - 1) + 1..];
+    let mut grid = input[..dim * height].to_vec();
+    let moves = &input[dim * height + 1..];
 
     for &m in moves.iter().filter(|&m| *m != b'\n') {
         let dir = move_map.get_unchecked(m as usize);
@@ -67,34 +72,38 @@ unsafe fn inner_part1(input: &[u8]) -> usize {
         .enumerate()
         .filter(|(_, &t)| t == BOX)
         .fold(0, |acc, (i, _)| {
-            let y_score = 100 * (i / DIM);
-            let x_score = i % DIM;
+            let y_score = 100 * (i / dim);
+            let x_score = i % dim;
             acc + y_score + x_score
         })
 }
 
 unsafe fn inner_part2(input: &[u8]) -> usize {
+    let (map_width, height) = dimensions(input);
+    // Every tile is twice as wide in part 2
+    let width = map_width * 2;
+
     let mut rob = 0; // Robot position
 
-    const NORTH: i32 = -(WIDTH as i32);
-    const SOUTH: i32 = WIDTH as i32;
+    let north: i32 = -(width as i32);
+    let south: i32 = width as i32;
     const WEST: i32 = -1;
     const EAST: i32 = 1;
 
     // Faster direction lookup
-    let mut move_map = [0; 167];
+    let mut move_map = [0; b'v' as usize + 1];
     move_map[b'<' as usize] = WEST;
     move_map[b'>' as usize] = EAST;
-    move_map[b'^' as usize] = NORTH;
-    move_map[b'v' as usize] = SOUTH;
+    move_map[b'^' as usize] = north;
+    move_map[b'v' as usize] = south;
 
     // Mutable version of traversable area
-    let mut grid = [0; HEIGHT * WIDTH];
+    let mut grid = vec![0; height * width];
 
     // Build grid from input
     let mut gi = 0;
     let mut i = 0;
-    let i_max = (HEIGHT + 1) * HEIGHT;
+    let i_max = (map_width + 1) * height;
     loop {
         match input[i] {
             AIR => {
@@ -126,7 +135,10 @@ unsafe fn inner_part2(input: &[u8]) -> usize {
         }
     }
 
-    let moves = &input[HEIGHT * (HEIGHT + 1) + 1..];
+    let moves = &input[i_max + 1..];
+
+    // Left parts of the boxes pushed by a vertical move, reused between moves
+    let mut swaps = Vec::new();
 
     // Process moves
     for &m in moves.iter().filter(|&m| *m != b'\n') {
@@ -164,8 +176,8 @@ unsafe fn inner_part2(input: &[u8]) -> usize {
                 true
             }
             WALL => false,
-            BOX_LEFT => move_boxes(&mut grid, i, i, dir),
-            BOX_RIGHT => move_boxes(&mut grid, i - 1, i, dir),
+            BOX_LEFT => move_boxes(&mut grid, &mut swaps, i, i, dir),
+            BOX_RIGHT => move_boxes(&mut grid, &mut swaps, i - 1, i, dir),
             _ => unreachable!(),
         } {
             // Success! Update robot position
@@ -177,22 +189,22 @@ unsafe fn inner_part2(input: &[u8]) -> usize {
         .enumerate()
         .filter(|(_, &t)| t == BOX_LEFT)
         .fold(0, |acc, (i, _)| {
-            let y_score = 100 * (i / WIDTH);
-            let x_score = i % WIDTH;
+            let y_score = 100 * (i / width);
+            let x_score = i % width;
             acc + y_score + x_score
         })
 }
 
 /// Returns true if boxes were moved.
 #[inline(always)]
-unsafe fn move_boxes(grid: &mut [u8], left: i32, i: i32, dir: i32) -> bool {
-    // Store indices of left part of boxes to be moved
-    let mut swaps = [0; 32];
-    let mut s_len = 0;
+unsafe fn move_boxes(grid: &mut [u8], swaps: &mut Vec<i32>, left: i32, i: i32, dir: i32) -> bool {
+    // Store indices of left part of boxes to be moved. Any number of boxes can be pushed at once
+    // in a big enough warehouse, so this grows as needed.
+    swaps.clear();
 
-    if rec(grid, &mut swaps, &mut s_len, left, dir) {
+    if rec(grid, swaps, left, dir) {
         // Check was successful. Now the boxes get moved.
-        for &s in swaps.iter().take(s_len) {
+        for &s in swaps.iter() {
             grid.swap(s as usize, (s - dir) as usize);
             grid.swap((s + 1) as usize, (s + 1 - dir) as usize);
         }
@@ -205,12 +217,12 @@ unsafe fn move_boxes(grid: &mut [u8], left: i32, i: i32, dir: i32) -> bool {
 
 /// Recursive call that returns true if a move can be made.
 #[inline(always)]
-unsafe fn rec(grid: &mut [u8], swaps: &mut [i32], s_len: &mut usize, left: i32, dir: i32) -> bool {
+unsafe fn rec(grid: &mut [u8], swaps: &mut Vec<i32>, left: i32, dir: i32) -> bool {
     // Step in direction
     let left = left + dir;
 
     // It's possible we have already checked this location for this move
-    if swaps[..*s_len].contains(&left) {
+    if swaps.contains(&left) {
         return true;
     }
     // Make appropriate call based on what is in the path of movement
@@ -220,19 +232,109 @@ unsafe fn rec(grid: &mut [u8], swaps: &mut [i32], s_len: &mut usize, left: i32,
     ) {
         (AIR, AIR) => true,
         (WALL, _) | (_, WALL) => false,
-        (BOX_LEFT, _) => rec(grid, swaps, s_len, left, dir),
-        (AIR, BOX_LEFT) => rec(grid, swaps, s_len, left + 1, dir),
-        (BOX_RIGHT, AIR) => rec(grid, swaps, s_len, left - 1, dir),
-        (BOX_RIGHT, BOX_LEFT) => {
-            rec(grid, swaps, s_len, left - 1, dir) && rec(grid, swaps, s_len, left + 1, dir)
-        }
+        (BOX_LEFT, _) => rec(grid, swaps, left, dir),
+        (AIR, BOX_LEFT) => rec(grid, swaps, left + 1, dir),
+        (BOX_RIGHT, AIR) => rec(grid, swaps, left - 1, dir),
+        (BOX_RIGHT, BOX_LEFT) => rec(grid, swaps, left - 1, dir) && rec(grid, swaps, left + 1, dir),
         (a, b) => unreachable!("Unexpected pattern: \"{}{}\"", a as char, b as char),
     } {
         // Track the left part of the box so we can move it later
-        *swaps.get_unchecked_mut(*s_len) = left;
-        *s_len += 1;
+        swaps.push(left);
         return true;
     }
     // Reached a wall! `swaps` might have some indices, but if any call returns false then the move fails.
     false
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LARGE: &str = "\
+##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
+";
+
+    const SMALL: &str = "\
+########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<
+";
+
+    const SMALL_WIDE: &str = "\
+#######
+#...#.#
+#.....#
+#..OO@#
+#..O..#
+#.....#
+#######
+
+<vv<<^^<<^^
+";
+
+    #[test]
+    fn examples() {
+        assert_eq!(part1(LARGE).to_string(), "10092");
+        assert_eq!(part2(LARGE).to_string(), "9021");
+        assert_eq!(part1(SMALL).to_string(), "2028");
+        assert_eq!(part2(SMALL_WIDE).to_string(), "618");
+    }
+
+    #[test]
+    fn rectangular() {
+        assert_eq!(dimensions(LARGE.as_bytes()), (10, 10));
+
+        let tiny = "\
+#######
+#.@O.O#
+#..O..#
+#######
+
+>>v<<^>
+";
+        assert_eq!(dimensions(tiny.as_bytes()), (7, 4));
+        // The first move pushes a box against another, then only the robot moves
+        assert_eq!(part1(tiny).to_string(), "412");
+        assert_eq!(part2(tiny).to_string(), "423");
+
+        let wide = "\
+##############
+#..O......O..#
+#.@O..#...O..#
+#............#
+##############
+
+>>>vv^^<<>>>>>>>>>v^
+";
+        assert_eq!(dimensions(wide.as_bytes()), (14, 5));
+        assert_eq!(part1(wide).to_string(), "628");
+        assert_eq!(part2(wide).to_string(), "656");
+    }
+}